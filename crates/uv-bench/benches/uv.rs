@@ -86,8 +86,8 @@ mod resolver {
     use uv_cache::Cache;
     use uv_client::RegistryClient;
     use uv_configuration::{
-        BuildOptions, Concurrency, ConfigSettings, Constraints, IndexStrategy, PreviewMode,
-        SourceStrategy,
+        BuildOptions, Concurrency, ConfigSettings, Constraints, IndexStrategy, PackageBuildEnv,
+        PackageConfigSettings, PreviewMode, SourceStrategy,
     };
     use uv_dispatch::{BuildDispatch, SharedState};
     use uv_distribution::DistributionDatabase;
@@ -143,6 +143,8 @@ mod resolver {
         let build_options = BuildOptions::default();
         let concurrency = Concurrency::default();
         let config_settings = ConfigSettings::default();
+        let config_settings_package = PackageConfigSettings::default();
+        let build_env = PackageBuildEnv::default();
         let exclude_newer = Some(
             jiff::civil::date(2024, 8, 8)
                 .to_zoned(jiff::tz::TimeZone::UTC)
@@ -182,6 +184,8 @@ mod resolver {
             state,
             IndexStrategy::default(),
             &config_settings,
+            &config_settings_package,
+            &build_env,
             build_isolation,
             LinkMode::default(),
             &build_options,
@@ -6,6 +6,7 @@ use std::process::ExitStatus;
 use std::sync::LazyLock;
 
 use crate::PythonRunnerOutput;
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use regex::Regex;
 use thiserror::Error;
@@ -54,6 +55,10 @@ static TORCH_NOT_FOUND_RE: LazyLock<Regex> =
 static DISTUTILS_NOT_FOUND_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"ModuleNotFoundError: No module named 'distutils'").unwrap());
 
+/// The number of trailing lines of `stderr` to include in an error when the full build output was
+/// captured in a log file.
+const LOG_TAIL_LINES: usize = 10;
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error(transparent)]
@@ -86,8 +91,8 @@ pub enum Error {
     NoSourceDistBuild(PackageName),
     #[error("Building source distributions is disabled")]
     NoSourceDistBuilds,
-    #[error("Cyclic build dependency detected for `{0}`")]
-    CyclicBuildDependency(PackageName),
+    #[error(transparent)]
+    CyclicBuildDependency(#[from] CyclicBuildDependencyError),
 }
 
 impl IsBuildBackendError for Error {
@@ -254,6 +259,7 @@ pub struct BuildBackendError {
     exit_code: ExitStatus,
     stdout: Vec<String>,
     stderr: Vec<String>,
+    log: Option<PathBuf>,
 }
 
 impl Display for BuildBackendError {
@@ -272,6 +278,16 @@ impl Display for BuildBackendError {
             non_empty = true;
         }
 
+        if let Some(log) = &self.log {
+            write!(
+                f,
+                "\n\n{} {}",
+                "The full build output was written to:".red(),
+                log.user_display()
+            )?;
+            non_empty = true;
+        }
+
         if non_empty {
             writeln!(f)?;
         }
@@ -287,12 +303,54 @@ impl Display for BuildBackendError {
     }
 }
 
+/// A build that requires building the package itself, either directly or through a cycle of other
+/// source distributions.
+#[derive(Debug, Error)]
+pub struct CyclicBuildDependencyError {
+    cycle: Vec<PackageName>,
+}
+
+impl CyclicBuildDependencyError {
+    /// Create a [`CyclicBuildDependencyError`] from the packages in the cycle, starting and
+    /// ending with the same package, e.g., `[a, b, a]`.
+    pub fn new(cycle: Vec<PackageName>) -> Self {
+        Self { cycle }
+    }
+}
+
+impl Display for CyclicBuildDependencyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Cyclic build dependency detected: {}",
+            self.cycle
+                .iter()
+                .map(|name| format!("`{}`", name.cyan()))
+                .join(" -> ")
+        )?;
+
+        if let Some(first) = self.cycle.first() {
+            write!(
+                f,
+                "\n\n{}{} Building `{}` from source requires a pre-built wheel for at least one of the packages in the cycle. Consider allowing pre-built wheels for these packages (e.g., by removing them from `--no-binary`), or installing the build requirements of `{}` into the environment and re-running with `--no-build-isolation`.",
+                "hint".bold().cyan(),
+                ":".bold(),
+                first.cyan(),
+                first.cyan(),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Error)]
 pub struct MissingHeaderError {
     message: String,
     exit_code: ExitStatus,
     stdout: Vec<String>,
     stderr: Vec<String>,
+    log: Option<PathBuf>,
     cause: MissingHeaderCause,
 }
 
@@ -308,6 +366,15 @@ impl Display for MissingHeaderError {
             write!(f, "\n\n{}\n{}", "[stderr]".red(), self.stderr.join("\n"))?;
         }
 
+        if let Some(log) = &self.log {
+            write!(
+                f,
+                "\n\n{} {}",
+                "The full build output was written to:".red(),
+                log.user_display()
+            )?;
+        }
+
         write!(
             f,
             "\n\n{}{} {}",
@@ -357,6 +424,15 @@ impl Error {
             }
         });
 
+        // If the output was captured in a log file, include only the tail of `stderr` in the error
+        // and refer to the log for the rest.
+        let (stdout, stderr) = if output.log.is_some() {
+            let tail = output.stderr.len().saturating_sub(LOG_TAIL_LINES);
+            (vec![], output.stderr[tail..].to_vec())
+        } else {
+            (output.stdout.clone(), output.stderr.clone())
+        };
+
         if let Some(missing_library) = missing_library {
             return match level {
                 BuildOutput::Stderr | BuildOutput::Quiet => {
@@ -365,6 +441,7 @@ impl Error {
                         exit_code: output.status,
                         stdout: vec![],
                         stderr: vec![],
+                        log: output.log.clone(),
                         cause: MissingHeaderCause {
                             missing_library,
                             package_name: name.cloned(),
@@ -376,8 +453,9 @@ impl Error {
                 BuildOutput::Debug => Self::MissingHeader(MissingHeaderError {
                     message,
                     exit_code: output.status,
-                    stdout: output.stdout.clone(),
-                    stderr: output.stderr.clone(),
+                    stdout,
+                    stderr,
+                    log: output.log.clone(),
                     cause: MissingHeaderCause {
                         missing_library,
                         package_name: name.cloned(),
@@ -394,12 +472,14 @@ impl Error {
                 exit_code: output.status,
                 stdout: vec![],
                 stderr: vec![],
+                log: output.log.clone(),
             }),
             BuildOutput::Debug => Self::BuildBackend(BuildBackendError {
                 message,
                 exit_code: output.status,
-                stdout: output.stdout.clone(),
-                stderr: output.stderr.clone(),
+                stdout,
+                stderr,
+                log: output.log.clone(),
             }),
         }
     }
@@ -407,8 +487,9 @@ impl Error {
 
 #[cfg(test)]
 mod test {
-    use crate::{Error, PythonRunnerOutput};
+    use crate::{CyclicBuildDependencyError, Error, PythonRunnerOutput};
     use indoc::indoc;
+    use std::path::PathBuf;
     use std::process::ExitStatus;
     use std::str::FromStr;
     use uv_configuration::BuildOutput;
@@ -439,6 +520,7 @@ mod test {
                 error: command '/usr/bin/gcc' failed with exit code 1
                 "#
             ).lines().map(ToString::to_string).collect(),
+            log: None,
         };
 
         let err = Error::from_command_output(
@@ -498,6 +580,7 @@ mod test {
             .lines()
             .map(ToString::to_string)
             .collect(),
+            log: None,
         };
 
         let err = Error::from_command_output(
@@ -546,6 +629,7 @@ mod test {
             .lines()
             .map(ToString::to_string)
             .collect(),
+            log: None,
         };
 
         let err = Error::from_command_output(
@@ -592,6 +676,7 @@ mod test {
             .lines()
             .map(ToString::to_string)
             .collect(),
+            log: None,
         };
 
         let err = Error::from_command_output(
@@ -619,4 +704,66 @@ mod test {
         hint: `distutils` was removed from the standard library in Python 3.12. Consider adding a constraint (like `pygraphviz >1.11`) to avoid building a version of `pygraphviz` that depends on `distutils`.
         "###);
     }
+
+    #[test]
+    fn build_log() {
+        let output = PythonRunnerOutput {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            stdout: vec!["running bdist_wheel".to_string()],
+            stderr: (1..=15).map(|line| format!("line {line}")).collect(),
+            log: Some(PathBuf::from("build-logs/foo-1.0.log")),
+        };
+
+        let err = Error::from_command_output(
+            "Failed building wheel through setup.py".to_string(),
+            &output,
+            BuildOutput::Debug,
+            Some(&PackageName::from_str("foo").unwrap()),
+            Some(&Version::new([1, 0])),
+            Some("foo-1.0"),
+        );
+        assert!(matches!(err, Error::BuildBackend { .. }));
+        // Unix uses exit status, Windows uses exit code.
+        let formatted = std::error::Error::source(&err)
+            .unwrap()
+            .to_string()
+            .replace("exit status: ", "exit code: ")
+            .replace('\\', "/");
+        let formatted = anstream::adapter::strip_str(&formatted);
+        insta::assert_snapshot!(formatted, @r###"
+        Failed building wheel through setup.py (exit code: 0)
+
+        [stderr]
+        line 6
+        line 7
+        line 8
+        line 9
+        line 10
+        line 11
+        line 12
+        line 13
+        line 14
+        line 15
+
+        The full build output was written to: build-logs/foo-1.0.log
+
+        hint: This usually indicates a problem with the package or the build environment.
+        "###);
+    }
+
+    #[test]
+    fn cyclic_build_dependency() {
+        let err = CyclicBuildDependencyError::new(vec![
+            PackageName::from_str("flit-core").unwrap(),
+            PackageName::from_str("tomli").unwrap(),
+            PackageName::from_str("flit-core").unwrap(),
+        ]);
+        let formatted = err.to_string();
+        let formatted = anstream::adapter::strip_str(&formatted);
+        insta::assert_snapshot!(formatted, @r###"
+        Cyclic build dependency detected: `flit-core` -> `tomli` -> `flit-core`
+
+        hint: Building `flit-core` from source requires a pre-built wheel for at least one of the packages in the cycle. Consider allowing pre-built wheels for these packages (e.g., by removing them from `--no-binary`), or installing the build requirements of `flit-core` into the environment and re-running with `--no-build-isolation`.
+        "###);
+    }
 }
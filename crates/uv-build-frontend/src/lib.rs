@@ -3,6 +3,7 @@
 //! <https://packaging.python.org/en/latest/specifications/source-distribution-format/>
 
 mod error;
+mod scheduler;
 
 use std::ffi::OsString;
 use std::fmt::Formatter;
@@ -22,11 +23,12 @@ use rustc_hash::FxHashMap;
 use serde::de::{value, IntoDeserializer, SeqAccess, Visitor};
 use serde::{de, Deserialize, Deserializer};
 use tempfile::TempDir;
-use tokio::io::AsyncBufReadExt;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
 use tokio::process::Command;
-use tokio::sync::{Mutex, Semaphore};
+use tokio::sync::Mutex;
 use tracing::{debug, info_span, instrument, Instrument};
 
+use uv_cache_key::hash_digest;
 use uv_configuration::{BuildKind, BuildOutput, ConfigSettings, SourceStrategy};
 use uv_distribution::BuildRequires;
 use uv_distribution_types::{IndexLocations, Resolution};
@@ -36,10 +38,13 @@ use uv_pep508::PackageName;
 use uv_pypi_types::{Requirement, VerbatimParsedUrl};
 use uv_python::{Interpreter, PythonEnvironment};
 use uv_static::EnvVars;
-use uv_types::{AnyErrorBuild, BuildContext, BuildIsolation, BuildStack, SourceBuildTrait};
+use uv_types::{
+    AnyErrorBuild, BuildContext, BuildIsolation, BuildOutputCallback, BuildStack, SourceBuildTrait,
+};
 use uv_warnings::warn_user_once;
 
-pub use crate::error::{Error, MissingHeaderCause};
+pub use crate::error::{CyclicBuildDependencyError, Error, MissingHeaderCause};
+use crate::scheduler::{estimate_cost, BuildScheduler};
 
 /// The default backend to use when PEP 517 is used without a `build-system` section.
 static DEFAULT_BACKEND: LazyLock<Pep517Backend> = LazyLock::new(|| Pep517Backend {
@@ -202,10 +207,22 @@ impl Pep517Backend {
 }
 
 /// Uses an [`Rc`] internally, clone freely.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct SourceBuildContext {
     /// An in-memory resolution of the default backend's requirements for PEP 517 builds.
     default_resolution: Rc<Mutex<Option<Resolution>>>,
+    /// The limit on the number of build scripts running at once, shared across all builds.
+    scheduler: Rc<BuildScheduler>,
+}
+
+impl SourceBuildContext {
+    /// Create a [`SourceBuildContext`] that runs up to `concurrent_builds` build scripts at once.
+    pub fn new(concurrent_builds: usize) -> Self {
+        Self {
+            default_resolution: Rc::default(),
+            scheduler: Rc::new(BuildScheduler::new(concurrent_builds)),
+        }
+    }
 }
 
 /// Holds the state through a series of PEP 517 frontend to backend calls or a single `setup.py`
@@ -275,7 +292,6 @@ impl SourceBuild {
         build_kind: BuildKind,
         mut environment_variables: FxHashMap<OsString, OsString>,
         level: BuildOutput,
-        concurrent_builds: usize,
     ) -> Result<Self, Error> {
         let temp_dir = build_context.cache().venv_dir()?;
 
@@ -310,6 +326,29 @@ impl SourceBuild {
             .or(fallback_package_version)
             .cloned();
 
+        // Apply any environment variables configured for this package.
+        if let Some(variables) = package_name
+            .as_ref()
+            .and_then(|name| build_context.build_env().get(name))
+        {
+            debug!(
+                "Setting build environment variables: {}",
+                variables.keys().join(", ")
+            );
+            environment_variables.extend(
+                variables
+                    .iter()
+                    .map(|(key, value)| (OsString::from(key), OsString::from(value))),
+            );
+        }
+
+        // Apply any config settings configured for this package, which take precedence over the
+        // global config settings.
+        let config_settings = build_context
+            .config_settings_package()
+            .for_package(package_name.as_ref(), &config_settings)
+            .into_owned();
+
         // Create a virtual environment, or install into the shared environment if requested.
         let venv = if let Some(venv) = build_isolation.shared_environment(package_name.as_ref()) {
             venv.clone()
@@ -332,7 +371,7 @@ impl SourceBuild {
 
             let resolved_requirements = Self::get_resolved_requirements(
                 build_context,
-                source_build_context,
+                source_build_context.clone(),
                 &default_backend,
                 &pep517_backend,
                 build_stack,
@@ -378,9 +417,28 @@ impl SourceBuild {
             OsString::from(venv.scripts())
         };
 
+        // If requested, capture the build output in a per-package log file.
+        let log = build_context
+            .build_log_dir()
+            .map(|dir| {
+                create_build_log(
+                    dir,
+                    source,
+                    package_name.as_ref(),
+                    package_version.as_ref(),
+                    version_id,
+                )
+            })
+            .transpose()?;
+
         // Create the PEP 517 build environment. If build isolation is disabled, we assume the build
         // environment is already setup.
-        let runner = PythonRunner::new(concurrent_builds, level);
+        let runner = PythonRunner::new(
+            source_build_context.scheduler.clone(),
+            estimate_cost(&source_tree).await?,
+            level,
+            log,
+        );
         if build_isolation.is_isolated(package_name.as_ref()) {
             debug!("Creating PEP 517 build environment");
 
@@ -836,6 +894,10 @@ impl SourceBuildTrait for SourceBuild {
     async fn wheel<'a>(&'a self, wheel_dir: &'a Path) -> Result<String, AnyErrorBuild> {
         Ok(self.build(wheel_dir).await?)
     }
+
+    fn on_output(&mut self, callback: BuildOutputCallback) {
+        self.runner.output = Some(callback);
+    }
 }
 
 fn escape_path_for_python(path: &Path) -> String {
@@ -998,12 +1060,51 @@ async fn create_pep517_build_environment(
     Ok(())
 }
 
+/// Create an empty log file to capture the output of the build for the given package.
+///
+/// The file is named after the package and a digest of the source being built (e.g.,
+/// `foo-1.2.3-0123456789abcdef.log`), such that builds of distinct sources for the same package
+/// don't overwrite each other's logs. The file is truncated if it already exists.
+fn create_build_log(
+    dir: &Path,
+    source: &Path,
+    package_name: Option<&PackageName>,
+    package_version: Option<&Version>,
+    version_id: Option<&str>,
+) -> Result<PathBuf, Error> {
+    let stem = match (package_name, package_version) {
+        (Some(name), Some(version)) => format!("{name}-{version}"),
+        (Some(name), None) => name.to_string(),
+        (None, _) => version_id
+            .unwrap_or("unknown")
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect(),
+    };
+    let digest = hash_digest(&(source, version_id));
+    fs::create_dir_all(dir)?;
+    let path = dir.join(format!("{stem}-{digest}.log"));
+    fs::File::create(&path)?;
+    Ok(path)
+}
+
 /// A runner that manages the execution of external python processes with a
 /// concurrency limit.
-#[derive(Debug)]
 struct PythonRunner {
-    control: Semaphore,
+    scheduler: Rc<BuildScheduler>,
+    /// The estimated cost of the build, used to prioritize cheaper builds.
+    cost: u64,
     level: BuildOutput,
+    /// The file to which the output of each script is appended, if any.
+    log: Option<PathBuf>,
+    /// The callback to which each line of output is forwarded, if any.
+    output: Option<BuildOutputCallback>,
 }
 
 #[derive(Debug)]
@@ -1011,14 +1112,24 @@ struct PythonRunnerOutput {
     stdout: Vec<String>,
     stderr: Vec<String>,
     status: ExitStatus,
+    /// The file containing the full output of the script, if any.
+    log: Option<PathBuf>,
 }
 
 impl PythonRunner {
-    /// Create a `PythonRunner` with the provided concurrency limit and output level.
-    fn new(concurrency: usize, level: BuildOutput) -> Self {
+    /// Create a `PythonRunner` with the provided scheduler, build cost, output level, and log file.
+    fn new(
+        scheduler: Rc<BuildScheduler>,
+        cost: u64,
+        level: BuildOutput,
+        log: Option<PathBuf>,
+    ) -> Self {
         Self {
-            control: Semaphore::new(concurrency),
+            scheduler,
+            cost,
             level,
+            log,
+            output: None,
         }
     }
 
@@ -1036,18 +1147,30 @@ impl PythonRunner {
         environment_variables: &FxHashMap<OsString, OsString>,
         modified_path: &OsString,
     ) -> Result<PythonRunnerOutput, Error> {
-        /// Read lines from a reader and store them in a buffer.
+        /// Read lines from a reader and store them in a buffer, appending them to the log file and
+        /// forwarding them to the output callback, if any.
         async fn read_from(
             mut reader: tokio::io::Split<tokio::io::BufReader<impl tokio::io::AsyncRead + Unpin>>,
             mut printer: Printer,
             buffer: &mut Vec<String>,
+            log: Option<&Mutex<fs::tokio::File>>,
+            output: Option<&BuildOutputCallback>,
         ) -> io::Result<()> {
             loop {
                 match reader.next_segment().await? {
                     Some(line_buf) => {
                         let line_buf = line_buf.strip_suffix(b"\r").unwrap_or(&line_buf);
-                        let line = String::from_utf8_lossy(line_buf).into();
+                        let line: String = String::from_utf8_lossy(line_buf).into();
                         let _ = write!(printer, "{line}");
+                        if let Some(output) = output {
+                            output(&line);
+                        }
+                        if let Some(log) = log {
+                            log.lock()
+                                .await
+                                .write_all(format!("{line}\n").as_bytes())
+                                .await?;
+                        }
                         buffer.push(line);
                     }
                     None => return Ok(()),
@@ -1055,7 +1178,7 @@ impl PythonRunner {
             }
         }
 
-        let _permit = self.control.acquire().await.unwrap();
+        let _permit = self.scheduler.acquire(self.cost).await;
 
         let mut child = Command::new(venv.python_executable())
             .args(["-c", script])
@@ -1078,11 +1201,38 @@ impl PythonRunner {
         let stdout_reader = tokio::io::BufReader::new(child.stdout.take().unwrap()).split(b'\n');
         let stderr_reader = tokio::io::BufReader::new(child.stderr.take().unwrap()).split(b'\n');
 
+        // Open the log file, to which both `stdout` and `stderr` are appended as they're read.
+        let log = match self.log.as_ref() {
+            Some(path) => Some(Mutex::new(
+                fs::tokio::OpenOptions::new()
+                    .append(true)
+                    .create(true)
+                    .open(path)
+                    .await
+                    .map_err(|err| {
+                        Error::CommandFailed(venv.python_executable().to_path_buf(), err)
+                    })?,
+            )),
+            None => None,
+        };
+
         // Asynchronously read from the in-memory pipes.
         let printer = Printer::from(self.level);
         let result = tokio::join!(
-            read_from(stdout_reader, printer, &mut stdout_buf),
-            read_from(stderr_reader, printer, &mut stderr_buf),
+            read_from(
+                stdout_reader,
+                printer,
+                &mut stdout_buf,
+                log.as_ref(),
+                self.output.as_ref()
+            ),
+            read_from(
+                stderr_reader,
+                printer,
+                &mut stderr_buf,
+                log.as_ref(),
+                self.output.as_ref()
+            ),
         );
         match result {
             (Ok(()), Ok(())) => {}
@@ -1094,6 +1244,14 @@ impl PythonRunner {
             }
         }
 
+        // Ensure that all writes to the log file have completed.
+        if let Some(log) = log {
+            log.into_inner()
+                .flush()
+                .await
+                .map_err(|err| Error::CommandFailed(venv.python_executable().to_path_buf(), err))?;
+        }
+
         // Wait for the child process to finish.
         let status = child
             .wait()
@@ -1104,6 +1262,7 @@ impl PythonRunner {
             stdout: stdout_buf,
             stderr: stderr_buf,
             status,
+            log: self.log.clone(),
         })
     }
 }
@@ -0,0 +1,233 @@
+//! Limit the number of build scripts running at once, across all source distribution builds.
+
+use std::cell::RefCell;
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::io;
+use std::path::Path;
+use std::rc::Rc;
+
+use tokio::sync::oneshot;
+use tracing::trace;
+use walkdir::WalkDir;
+
+use uv_fs::Simplified;
+
+/// A shared limit on the number of concurrently running build scripts.
+///
+/// Unlike a plain semaphore, waiting scripts are granted a slot in order of the estimated cost of
+/// their build (cheapest first), such that a handful of expensive builds can't starve the rest of
+/// the resolution.
+#[derive(Debug)]
+pub(crate) struct BuildScheduler {
+    state: RefCell<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    /// The number of slots that are currently free.
+    available: usize,
+    /// A counter used to break ties between waiters of equal cost, in arrival order.
+    sequence: u64,
+    /// The scripts waiting for a slot.
+    waiters: BinaryHeap<Reverse<Waiter>>,
+}
+
+#[derive(Debug)]
+struct Waiter {
+    cost: u64,
+    sequence: u64,
+    sender: oneshot::Sender<()>,
+}
+
+impl PartialEq for Waiter {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Waiter {}
+
+impl PartialOrd for Waiter {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Waiter {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.cost, self.sequence).cmp(&(other.cost, other.sequence))
+    }
+}
+
+impl BuildScheduler {
+    /// Create a [`BuildScheduler`] that allows up to `concurrency` scripts to run at once.
+    pub(crate) fn new(concurrency: usize) -> Self {
+        Self {
+            state: RefCell::new(State {
+                available: concurrency,
+                sequence: 0,
+                waiters: BinaryHeap::new(),
+            }),
+        }
+    }
+
+    /// Wait for a free slot for a build with the given estimated cost.
+    ///
+    /// The slot is released when the returned [`BuildPermit`] is dropped.
+    pub(crate) async fn acquire(self: &Rc<Self>, cost: u64) -> BuildPermit {
+        let receiver = {
+            let mut state = self.state.borrow_mut();
+            if state.available > 0 {
+                state.available -= 1;
+                return BuildPermit {
+                    scheduler: self.clone(),
+                };
+            }
+            let (sender, receiver) = oneshot::channel();
+            let sequence = state.sequence;
+            state.sequence += 1;
+            state.waiters.push(Reverse(Waiter {
+                cost,
+                sequence,
+                sender,
+            }));
+            receiver
+        };
+
+        let mut pending = Pending {
+            scheduler: self.clone(),
+            receiver: Some(receiver),
+        };
+        if let Some(receiver) = pending.receiver.as_mut() {
+            // The scheduler outlives the sender, so the slot is always handed over.
+            let _ = receiver.await;
+        }
+        pending.receiver = None;
+
+        BuildPermit {
+            scheduler: self.clone(),
+        }
+    }
+
+    /// Hand a slot over to the cheapest waiting build, or mark it as free.
+    fn release(&self) {
+        let mut state = self.state.borrow_mut();
+        while let Some(Reverse(waiter)) = state.waiters.pop() {
+            // If the waiter was cancelled, move on to the next one.
+            if waiter.sender.send(()).is_ok() {
+                return;
+            }
+        }
+        state.available += 1;
+    }
+}
+
+/// A slot in the [`BuildScheduler`], held while a build script runs.
+#[derive(Debug)]
+pub(crate) struct BuildPermit {
+    scheduler: Rc<BuildScheduler>,
+}
+
+impl Drop for BuildPermit {
+    fn drop(&mut self) {
+        self.scheduler.release();
+    }
+}
+
+/// A request for a slot that hasn't been granted yet.
+///
+/// If the request is cancelled after the slot was handed over, but before it was received, the
+/// slot is released again rather than leaked.
+struct Pending {
+    scheduler: Rc<BuildScheduler>,
+    receiver: Option<oneshot::Receiver<()>>,
+}
+
+impl Drop for Pending {
+    fn drop(&mut self) {
+        if let Some(mut receiver) = self.receiver.take() {
+            receiver.close();
+            if receiver.try_recv().is_ok() {
+                self.scheduler.release();
+            }
+        }
+    }
+}
+
+/// Estimate the cost of building the given source tree, as the total size of its files in bytes.
+///
+/// Version control metadata is ignored, as it doesn't contribute to the build. The source tree is
+/// walked on a blocking thread, as it may contain many files.
+pub(crate) async fn estimate_cost(source_tree: &Path) -> io::Result<u64> {
+    let source_tree = source_tree.to_path_buf();
+    Ok(tokio::task::spawn_blocking(move || estimate_cost_blocking(&source_tree)).await?)
+}
+
+fn estimate_cost_blocking(source_tree: &Path) -> u64 {
+    let cost = WalkDir::new(source_tree)
+        .into_iter()
+        .filter_entry(|entry| entry.file_name() != ".git")
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| entry.metadata().ok())
+        .map(|metadata| metadata.len())
+        .sum();
+    trace!(
+        "Estimated build cost of `{}`: {cost}",
+        source_tree.user_display()
+    );
+    cost
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use futures::FutureExt;
+
+    use super::BuildScheduler;
+
+    #[tokio::test]
+    async fn cheapest_first() {
+        let scheduler = Rc::new(BuildScheduler::new(1));
+        let order = Rc::new(RefCell::new(Vec::new()));
+
+        // Occupy the only slot, then queue up builds of varying cost.
+        let permit = scheduler.acquire(0).await;
+        let mut waiting = [30, 10, 20]
+            .into_iter()
+            .map(|cost| {
+                let scheduler = scheduler.clone();
+                let order = order.clone();
+                async move {
+                    let _permit = scheduler.acquire(cost).await;
+                    order.borrow_mut().push(cost);
+                }
+                .boxed_local()
+            })
+            .collect::<Vec<_>>();
+        for future in &mut waiting {
+            assert!(future.now_or_never().is_none());
+        }
+
+        drop(permit);
+        futures::future::join_all(waiting).await;
+        assert_eq!(*order.borrow(), vec![10, 20, 30]);
+    }
+
+    #[tokio::test]
+    async fn cancelled_waiter() {
+        let scheduler = Rc::new(BuildScheduler::new(1));
+
+        // A waiter that gives up shouldn't hold on to the slot.
+        let permit = scheduler.acquire(0).await;
+        let mut cancelled = scheduler.acquire(10).boxed_local();
+        assert!((&mut cancelled).now_or_never().is_none());
+        drop(permit);
+        drop(cancelled);
+
+        assert!(scheduler.acquire(20).now_or_never().is_some());
+    }
+}
@@ -144,6 +144,8 @@ pub struct Cache {
     root: PathBuf,
     /// The refresh strategy to use when reading from the cache.
     refresh: Refresh,
+    /// The packages whose cached builds should be discarded.
+    rebuild: Vec<PackageName>,
     /// A temporary cache directory, if the user requested `--no-cache`.
     ///
     /// Included to ensure that the temporary directory exists for the length of the operation, but
@@ -157,6 +159,7 @@ impl Cache {
         Self {
             root: root.into(),
             refresh: Refresh::None(Timestamp::now()),
+            rebuild: Vec::new(),
             temp_dir: None,
         }
     }
@@ -167,6 +170,7 @@ impl Cache {
         Ok(Self {
             root: temp_dir.path().to_path_buf(),
             refresh: Refresh::None(Timestamp::now()),
+            rebuild: Vec::new(),
             temp_dir: Some(Arc::new(temp_dir)),
         })
     }
@@ -177,6 +181,12 @@ impl Cache {
         Self { refresh, ..self }
     }
 
+    /// Set the packages whose cached builds should be discarded.
+    #[must_use]
+    pub fn with_rebuild(self, rebuild: Vec<PackageName>) -> Self {
+        Self { rebuild, ..self }
+    }
+
     /// Return the root of the cache.
     pub fn root(&self) -> &Path {
         &self.root
@@ -233,6 +243,22 @@ impl Cache {
         }
     }
 
+    /// Returns `true` if a cached build of the given package must be discarded.
+    ///
+    /// Only builds that predate the [`Refresh`] timestamp are discarded, such that a package is
+    /// rebuilt at most once per invocation.
+    pub fn must_rebuild(&self, package: &PackageName, path: &Path) -> io::Result<bool> {
+        if !self.rebuild.contains(package) {
+            return Ok(false);
+        }
+
+        match fs_err::metadata(path) {
+            Ok(metadata) => Ok(Timestamp::from_metadata(&metadata) < self.refresh.timestamp()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
     /// Returns the [`Freshness`] for a cache entry, validating it against the [`Refresh`] policy.
     ///
     /// A cache entry is considered fresh if it was created after the cache itself was
@@ -926,16 +952,29 @@ pub enum CacheBucket {
     /// without the shim itself changing, we only cache when the path equals `sys.executable`, i.e.
     /// the path we're running is the python executable itself and not a shim.
     ///
-    /// Cache structure: `interpreter-v0/<digest(path)>.msgpack`
+    /// Entries are invalidated when the modification time or size of the (canonicalized)
+    /// executable changes. Concurrent processes querying the same interpreter are serialized by a
+    /// lock file alongside the entry, such that only one of them runs the interpreter.
+    ///
+    /// Cache structure:
+    ///  * `interpreter-v0/<arch>/<digest(path)>.msgpack`
+    ///  * `interpreter-v0/<arch>/<digest(path)>.lock`
     ///
     /// # Example
     ///
-    /// The contents of each of the `MsgPack` files has a timestamp field in unix time, the [PEP 508]
-    /// markers and some information from the `sys`/`sysconfig` modules.
+    /// The contents of each of the `MsgPack` files has a fingerprint of the executable (its
+    /// timestamp, i.e., its change time on Unix and its modification time elsewhere, and its size
+    /// in bytes), the [PEP 508] markers and some information from the `sys`/`sysconfig` modules.
     ///
     /// ```json
     /// {
-    ///   "timestamp": 1698047994491,
+    ///   "fingerprint": {
+    ///     "timestamp": {
+    ///       "secs_since_epoch": 1698047994,
+    ///       "nanos_since_epoch": 491000000
+    ///     },
+    ///     "size": 17856
+    ///   },
     ///   "data": {
     ///     "markers": {
     ///       "implementation_name": "cpython",
@@ -977,6 +1016,22 @@ pub enum CacheBucket {
     Builds,
     /// Reusable virtual environments used to invoke Python tools.
     Environments,
+    /// Checkpoints of in-progress resolutions, used to resume an interrupted resolution.
+    ///
+    /// Cache structure:
+    ///  * `resolutions-v0/<digest(inputs)>.txt`
+    Resolutions,
+    /// Distribution metadata for registry distributions, keyed by index, package name, and
+    /// version, such that the metadata fetched for any wheel of a given version (e.g., via PEP 658,
+    /// or from the wheel itself) is reused for all other wheels of that version, across projects
+    /// and platforms.
+    ///
+    /// Cache structure:
+    ///  * `metadata-v0/pypi/foo/1.0.0.msgpack`
+    ///  * `metadata-v0/index/<digest(index-url)>/foo/1.0.0.msgpack`
+    ///
+    /// Each entry is stored as a `ResolutionMetadata`.
+    Metadata,
 }
 
 impl CacheBucket {
@@ -987,10 +1042,10 @@ impl CacheBucket {
             Self::SourceDistributions => "sdists-v8",
             Self::FlatIndex => "flat-index-v2",
             Self::Git => "git-v0",
-            Self::Interpreter => "interpreter-v4",
+            Self::Interpreter => "interpreter-v8",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/it/cache_clean.rs`.
-            Self::Simple => "simple-v15",
+            Self::Simple => "simple-v16",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/it/cache_prune.rs`.
             Self::Wheels => "wheels-v5",
@@ -999,6 +1054,8 @@ impl CacheBucket {
             Self::Archive => "archive-v0",
             Self::Builds => "builds-v0",
             Self::Environments => "environments-v2",
+            Self::Resolutions => "resolutions-v0",
+            Self::Metadata => "metadata-v0",
         }
     }
 
@@ -1037,6 +1094,13 @@ impl CacheBucket {
                 for directory in directories(root) {
                     summary += rm_rf(directory.join(name.to_string()))?;
                 }
+
+                // For wheels added via `uv cache add`, we expect a directory for every digest,
+                // followed by a directory per package (indexed by name).
+                let root = cache.bucket(self).join(WheelCacheKind::Sha256);
+                for directory in directories(root) {
+                    summary += rm_rf(directory.join(name.to_string()))?;
+                }
             }
             Self::SourceDistributions => {
                 // For `pypi` wheels, we expect a directory per package (indexed by name).
@@ -1081,6 +1145,13 @@ impl CacheBucket {
                         }
                     }
                 }
+
+                // For source distributions added via `uv cache add`, we expect a directory for
+                // every digest, followed by a directory per package (indexed by name).
+                let root = cache.bucket(self).join(WheelCacheKind::Sha256);
+                for directory in directories(root) {
+                    summary += rm_rf(directory.join(name.to_string()))?;
+                }
             }
             Self::Simple => {
                 // For `pypi` wheels, we expect a rkyv file per package, indexed by name.
@@ -1115,6 +1186,21 @@ impl CacheBucket {
             Self::Environments => {
                 // Nothing to do.
             }
+            Self::Resolutions => {
+                // Nothing to do.
+            }
+            Self::Metadata => {
+                // For `pypi` metadata, we expect a directory per package (indexed by name).
+                let root = cache.bucket(self).join(WheelCacheKind::Pypi);
+                summary += rm_rf(root.join(name.to_string()))?;
+
+                // For alternate indices, we expect a directory for every index (under an `index`
+                // subdirectory), followed by a directory per package (indexed by name).
+                let root = cache.bucket(self).join(WheelCacheKind::Index);
+                for directory in directories(root) {
+                    summary += rm_rf(directory.join(name.to_string()))?;
+                }
+            }
         }
         Ok(summary)
     }
@@ -1131,6 +1217,8 @@ impl CacheBucket {
             Self::Archive,
             Self::Builds,
             Self::Environments,
+            Self::Resolutions,
+            Self::Metadata,
         ]
         .iter()
         .copied()
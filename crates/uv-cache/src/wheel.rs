@@ -21,6 +21,9 @@ pub enum WheelCache<'a> {
     /// Note that this variant only exists for source distributions; wheels can't be delivered
     /// through Git.
     Git(&'a Url, &'a str),
+    /// A distribution that was added to the cache directly (via `uv cache add`), which we key by
+    /// the SHA-256 digest of its contents.
+    Sha256(&'a str),
 }
 
 impl WheelCache<'_> {
@@ -44,6 +47,7 @@ impl WheelCache<'_> {
                 .root()
                 .join(cache_digest(&CanonicalUrl::new(url)))
                 .join(sha),
+            WheelCache::Sha256(digest) => WheelCacheKind::Sha256.root().join(digest),
         }
     }
 
@@ -67,6 +71,8 @@ pub(crate) enum WheelCacheKind {
     Editable,
     /// A cache of data from a Git repository.
     Git,
+    /// A cache of data added directly, keyed by its SHA-256 digest.
+    Sha256,
 }
 
 impl WheelCacheKind {
@@ -78,6 +84,7 @@ impl WheelCacheKind {
             Self::Path => "path",
             Self::Editable => "editable",
             Self::Git => "git",
+            Self::Sha256 => "sha256",
         }
     }
 
@@ -163,24 +163,19 @@ impl CompatArgs for PipCompileCompatArgs {
 ///
 /// These represent a subset of the `pip list` interface that uv supports by default.
 #[derive(Args)]
-#[allow(clippy::struct_excessive_bools)]
 pub struct PipListCompatArgs {
-    #[clap(long, hide = true)]
-    disable_pip_version_check: bool,
+    #[clap(flatten)]
+    global: PipGlobalCompatArgs,
 }
 
 impl CompatArgs for PipListCompatArgs {
     /// Validate the arguments passed for `pip list` compatibility.
     ///
     /// This method will warn when an argument is passed that has no effect but matches uv's
-    /// behavior. If an argument is passed that does _not_ match uv's behavior (e.g.,
-    /// `--disable-pip-version-check`), this method will return an error.
+    /// behavior. If an argument is passed that does _not_ match uv's behavior, this method will
+    /// return an error.
     fn validate(&self) -> Result<()> {
-        if self.disable_pip_version_check {
-            warn_user!("pip's `--disable-pip-version-check` has no effect");
-        }
-
-        Ok(())
+        self.global.validate()
     }
 }
 
@@ -339,11 +334,38 @@ impl CompatArgs for VenvCompatArgs {
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PipInstallCompatArgs {
-    #[clap(long, hide = true)]
-    disable_pip_version_check: bool,
+    #[clap(flatten)]
+    global: PipGlobalCompatArgs,
 
     #[clap(long, hide = false)]
     user: bool,
+
+    #[clap(long, hide = true)]
+    root: Option<String>,
+
+    #[clap(long, hide = true)]
+    ignore_installed: bool,
+
+    #[clap(long, hide = true)]
+    prefer_binary: bool,
+
+    #[clap(long, hide = true)]
+    use_pep517: bool,
+
+    #[clap(long, hide = true)]
+    no_use_pep517: bool,
+
+    #[clap(long, hide = true)]
+    no_warn_script_location: bool,
+
+    #[clap(long, hide = true)]
+    no_warn_conflicts: bool,
+
+    #[clap(long, hide = true)]
+    no_clean: bool,
+
+    #[clap(long, hide = true)]
+    progress_bar: Option<ProgressBar>,
 }
 
 impl CompatArgs for PipInstallCompatArgs {
@@ -353,9 +375,7 @@ impl CompatArgs for PipInstallCompatArgs {
     /// behavior. If an argument is passed that does _not_ match uv's behavior, this method will
     /// return an error.
     fn validate(&self) -> Result<()> {
-        if self.disable_pip_version_check {
-            warn_user!("pip's `--disable-pip-version-check` has no effect");
-        }
+        self.global.validate()?;
 
         if self.user {
             return Err(anyhow!(
@@ -363,18 +383,124 @@ impl CompatArgs for PipInstallCompatArgs {
             ));
         }
 
+        if self.root.is_some() {
+            return Err(anyhow!(
+                "pip's `--root` is unsupported (use `--target` or `--prefix` instead)"
+            ));
+        }
+
+        if self.ignore_installed {
+            return Err(anyhow!(
+                "pip's `--ignore-installed` is unsupported (use `--reinstall` instead)"
+            ));
+        }
+
+        if self.prefer_binary {
+            return Err(anyhow!(
+                "pip's `--prefer-binary` is unsupported (use `--only-binary` for the packages that should not be built from source)"
+            ));
+        }
+
+        if self.use_pep517 {
+            warn_user!("pip's `--use-pep517` has no effect (uv always builds with PEP 517)");
+        }
+
+        if self.no_use_pep517 {
+            return Err(anyhow!(
+                "pip's `--no-use-pep517` is unsupported (uv always builds with PEP 517)"
+            ));
+        }
+
+        if self.no_warn_script_location {
+            warn_user!(
+                "pip's `--no-warn-script-location` has no effect (uv doesn't warn about script locations)"
+            );
+        }
+
+        if self.no_warn_conflicts {
+            warn_user!(
+                "pip's `--no-warn-conflicts` has no effect (uv only checks for conflicts with `--strict`)"
+            );
+        }
+
+        if self.no_clean {
+            warn_user!("pip's `--no-clean` has no effect (uv always cleans up build directories)");
+        }
+
+        if let Some(progress_bar) = self.progress_bar {
+            match progress_bar {
+                ProgressBar::On => {
+                    warn_user!(
+                        "pip's `--progress-bar=on` has no effect (uv displays progress bars by default)"
+                    );
+                }
+                ProgressBar::Off | ProgressBar::Raw => {
+                    return Err(anyhow!(
+                        "pip's `--progress-bar` is unsupported (use `--no-progress` to hide progress bars)"
+                    ));
+                }
+            }
+        }
+
         Ok(())
     }
 }
 
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum ProgressBar {
+    On,
+    Off,
+    Raw,
+}
+
 /// Arguments for generic `pip` command compatibility.
 ///
-/// These represent a subset of the `pip` interface that exists on all commands.
+/// These represent a subset of the `pip` interface that exists on all commands (i.e., pip's
+/// "General Options").
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PipGlobalCompatArgs {
     #[clap(long, hide = true)]
     disable_pip_version_check: bool,
+
+    #[clap(long, hide = true)]
+    no_python_version_warning: bool,
+
+    #[clap(long, hide = true)]
+    no_input: bool,
+
+    #[clap(long, hide = true)]
+    require_virtualenv: bool,
+
+    #[clap(long, hide = true)]
+    root_user_action: Option<String>,
+
+    #[clap(long, hide = true)]
+    exists_action: Option<String>,
+
+    #[clap(long, hide = true)]
+    use_deprecated: Vec<String>,
+
+    #[clap(long, hide = true)]
+    use_feature: Vec<String>,
+
+    #[clap(long, hide = true)]
+    timeout: Option<String>,
+
+    #[clap(long, hide = true)]
+    retries: Option<String>,
+
+    #[clap(long, hide = true)]
+    proxy: Option<String>,
+
+    #[clap(long, hide = true)]
+    cert: Option<String>,
+
+    #[clap(long, hide = true)]
+    client_cert: Option<String>,
+
+    #[clap(long, hide = true)]
+    log: Option<String>,
 }
 
 impl CompatArgs for PipGlobalCompatArgs {
@@ -382,12 +508,108 @@ impl CompatArgs for PipGlobalCompatArgs {
     ///
     /// This method will warn when an argument is passed that has no effect but matches uv's
     /// behavior. If an argument is passed that does _not_ match uv's behavior, this method will
-    /// return an error.
+    /// return an error, suggesting the uv equivalent (if any).
     fn validate(&self) -> Result<()> {
         if self.disable_pip_version_check {
             warn_user!("pip's `--disable-pip-version-check` has no effect");
         }
 
+        if self.no_python_version_warning {
+            warn_user!("pip's `--no-python-version-warning` has no effect");
+        }
+
+        if self.no_input {
+            warn_user!("pip's `--no-input` has no effect (uv never prompts for input)");
+        }
+
+        if self.require_virtualenv {
+            warn_user!(
+                "pip's `--require-virtualenv` has no effect (uv requires a virtual environment unless `--system` is provided)"
+            );
+        }
+
+        if self.root_user_action.is_some() {
+            warn_user!(
+                "pip's `--root-user-action` has no effect (uv doesn't warn when running as root)"
+            );
+        }
+
+        if self.exists_action.is_some() {
+            warn_user!(
+                "pip's `--exists-action` has no effect (uv never prompts for existing paths)"
+            );
+        }
+
+        if let Some(deprecated) = self.use_deprecated.first() {
+            if deprecated == "legacy-resolver" {
+                return Err(anyhow!(
+                    "pip's `--use-deprecated=legacy-resolver` is unsupported (uv always backtracks)"
+                ));
+            }
+            return Err(anyhow!(
+                "pip's `--use-deprecated={deprecated}` is unsupported (uv doesn't implement pip's deprecated behaviors)"
+            ));
+        }
+
+        for feature in &self.use_feature {
+            match feature.as_str() {
+                "2020-resolver" => {
+                    warn_user!(
+                        "pip's `--use-feature=2020-resolver` has no effect (uv always backtracks)"
+                    );
+                }
+                "fast-deps" => {
+                    warn_user!(
+                        "pip's `--use-feature=fast-deps` has no effect (uv fetches metadata lazily by default)"
+                    );
+                }
+                "truststore" => {
+                    return Err(anyhow!(
+                        "pip's `--use-feature=truststore` is unsupported (use `--native-tls` instead)"
+                    ));
+                }
+                _ => {
+                    return Err(anyhow!("pip's `--use-feature={feature}` is unsupported"));
+                }
+            }
+        }
+
+        if self.timeout.is_some() {
+            return Err(anyhow!(
+                "pip's `--timeout` is unsupported (set `UV_HTTP_TIMEOUT` instead)"
+            ));
+        }
+
+        if self.retries.is_some() {
+            return Err(anyhow!(
+                "pip's `--retries` is unsupported (uv retries failed requests automatically)"
+            ));
+        }
+
+        if self.proxy.is_some() {
+            return Err(anyhow!(
+                "pip's `--proxy` is unsupported (set `HTTPS_PROXY` or `HTTP_PROXY` instead)"
+            ));
+        }
+
+        if self.cert.is_some() {
+            return Err(anyhow!(
+                "pip's `--cert` is unsupported (set `SSL_CERT_FILE` instead)"
+            ));
+        }
+
+        if self.client_cert.is_some() {
+            return Err(anyhow!(
+                "pip's `--client-cert` is unsupported (set `SSL_CLIENT_CERT` instead)"
+            ));
+        }
+
+        if self.log.is_some() {
+            return Err(anyhow!(
+                "pip's `--log` is unsupported (use `--verbose` and redirect the output instead)"
+            ));
+        }
+
         Ok(())
     }
 }
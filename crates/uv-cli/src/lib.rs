@@ -1,4 +1,5 @@
 use std::ffi::OsString;
+use std::num::{NonZeroU32, NonZeroUsize};
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -11,15 +12,21 @@ use clap::{Args, Parser, Subcommand};
 use url::Url;
 use uv_cache::CacheArgs;
 use uv_configuration::{
-    ConfigSettingEntry, ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
+    ByteSize, ConfigSettingEntry, ConfigSettingPackageEntry, EmbeddedIndexStrategy, ExportFormat,
+    IndexStrategy, IndexUnavailablePolicy, KeyringProviderType, PackageNameSpecifier,
     ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
+    WheelOptimization,
 };
 use uv_distribution_types::{Index, IndexUrl, Origin, PipExtraIndex, PipFindLinks, PipIndex};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep508::Requirement;
 use uv_pypi_types::VerbatimParsedUrl;
-use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
-use uv_resolver::{AnnotationStyle, ExcludeNewer, ForkStrategy, PrereleaseMode, ResolutionMode};
+use uv_python::{PythonDownloads, PythonPreference, PythonVersion, SchemePath};
+use uv_resolver::{
+    AnnotationStyle, ExcludeNewer, ExcludeNewerPackageEntry, ForkStrategy, InvalidMetadataPolicy,
+    LocalVersionPreference, PackageProvider, PackageSourcePolicy, PrereleaseMode, ResolutionMode,
+    ResolutionPackageEntry, WheelPreference, YankedPolicy,
+};
 use uv_static::EnvVars;
 
 pub mod comma;
@@ -35,6 +42,15 @@ pub enum VersionFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum TagsFormat {
+    /// Display the tags as plain text, one per line.
+    #[default]
+    Text,
+    /// Display the tags as a JSON array.
+    Json,
+}
+
 #[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
 pub enum PythonListFormat {
     /// Plain text (for humans).
@@ -44,6 +60,15 @@ pub enum PythonListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum PipCompileFormat {
+    /// Output the resolution in `requirements.txt` format.
+    #[default]
+    RequirementsTxt,
+    /// Output the resolution as a GitHub Dependency Submission API snapshot, in JSON.
+    GithubSnapshot,
+}
+
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
 pub enum ListFormat {
     /// Display the list of packages in a human-readable table.
@@ -270,6 +295,40 @@ pub struct GlobalArgs {
     #[arg(global = true, long, hide = true, env = EnvVars::UV_NO_INSTALLER_METADATA, value_parser = clap::builder::BoolishValueParser::new())]
     pub no_installer_metadata: bool,
 
+    /// Record statistics about the command (e.g., cache hit rates and bytes downloaded) in the
+    /// local state directory, for display via `uv stats`.
+    #[arg(global = true, long, hide = true, env = EnvVars::UV_STATS, value_parser = clap::builder::BoolishValueParser::new())]
+    pub stats: bool,
+
+    /// Print every URL fetched during the command, along with its size and SHA-256 hash.
+    ///
+    /// Each download is printed on its own line, as `<url> <size> sha256:<hash>`, once the command
+    /// completes. Responses served from the cache are not included. Responses whose body was not
+    /// read to completion are marked as `(incomplete)`.
+    ///
+    /// If a file path is provided (e.g., `--print-downloads=downloads.txt`), the log is written to
+    /// that file instead of stderr.
+    #[arg(global = true, long, require_equals = true, num_args = 0..=1, value_name = "FILE")]
+    pub print_downloads: Option<Option<PathBuf>>,
+
+    /// The maximum number of requests per second to send to any one host.
+    ///
+    /// Useful for indexes that enforce rate limits, such as Azure Artifacts. Regardless of this
+    /// setting, uv slows down when an index throttles requests (via `Retry-After`), or advertises
+    /// that its rate limit is nearly exhausted (via `X-RateLimit-*` headers).
+    #[arg(global = true, long, env = EnvVars::UV_MAX_REQUESTS_PER_SECOND, value_name = "N")]
+    pub max_requests_per_second: Option<NonZeroU32>,
+
+    /// The maximum number of source distributions to build concurrently.
+    ///
+    /// When more builds are pending than can run at once, uv schedules the cheapest builds (by the
+    /// size of their source tree) first, such that a single large build doesn't hold up the rest
+    /// of the resolution.
+    ///
+    /// Defaults to the number of available CPU cores.
+    #[arg(global = true, long, value_name = "N")]
+    pub build_jobs: Option<NonZeroUsize>,
+
     /// Change to the given directory prior to running the command.
     ///
     /// Relative paths are resolved with the given directory as the base.
@@ -465,8 +524,37 @@ pub enum Commands {
     /// Clear the cache, removing all entries or those linked to specific packages.
     #[command(hide = true)]
     Clean(CleanArgs),
+    /// Display the wheel tags supported by a Python interpreter.
+    ///
+    /// Tags are displayed from highest to lowest priority, in the order uv uses to select a wheel
+    /// when multiple wheels are compatible. A wheel is compatible if any of its tags appear in the
+    /// list.
+    ///
+    /// The Python interpreter is discovered as in `uv python find`.
+    Tags(TagsArgs),
+    /// Check that uv can operate in the current environment.
+    ///
+    /// Validates that the cache is writable and supports the configured link mode, that a Python
+    /// interpreter can be discovered, that each configured index is reachable (and accepts any
+    /// provided credentials), that any proxy and TLS configuration is valid, and that Git is
+    /// available.
+    ///
+    /// Each failed check is reported alongside a suggested remediation. Exits with a non-zero
+    /// status code if any check fails.
+    Doctor(DoctorArgs),
+    /// Display the statistics recorded for previous invocations.
+    ///
+    /// Statistics are only recorded if enabled via `UV_STATS=1`, and are stored locally, in uv's
+    /// state directory. For each command, reports the number of invocations, the fraction of HTTP
+    /// requests served from the cache, the number of bytes downloaded, the number of source
+    /// distribution builds, and the number of times the resolver backtracked.
+    Stats(StatsArgs),
     /// Display uv's version
     Version {
+        #[command(subcommand)]
+        command: Option<VersionCommand>,
+
+        /// The format in which to display the version, or the result of `compare` or `sort`.
         #[arg(long, value_enum, default_value = "text")]
         output_format: VersionFormat,
     },
@@ -491,6 +579,150 @@ pub enum Commands {
     Help(HelpArgs),
 }
 
+#[derive(Args)]
+pub struct TagsArgs {
+    /// The Python interpreter for which the tags should be displayed.
+    ///
+    /// See `uv help python` to view supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// The Python version for which the tags should be displayed, in lieu of that of the
+    /// interpreter.
+    ///
+    /// For example, `3.8` or `3.8.17`.
+    #[arg(long, help_heading = "Python options")]
+    pub python_version: Option<PythonVersion>,
+
+    /// The platform for which the tags should be displayed, in lieu of that of the interpreter.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    #[arg(long, alias = "platform")]
+    pub python_platform: Option<TargetTriple>,
+
+    /// Only use system Python interpreters.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    /// The format in which to display the tags.
+    #[arg(long, value_enum, default_value_t = TagsFormat::default())]
+    pub output_format: TagsFormat,
+}
+
+#[derive(Args)]
+pub struct DoctorArgs {
+    /// The Python interpreter to check.
+    ///
+    /// See `uv help python` to view supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Only use system Python interpreters.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    #[command(flatten)]
+    pub index_args: IndexArgs,
+
+    /// Attempt to use `keyring` for authentication for index URLs.
+    ///
+    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
+    /// the `keyring` CLI to handle authentication.
+    ///
+    /// Defaults to `disabled`.
+    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
+    pub keyring_provider: Option<KeyringProviderType>,
+
+    /// The method to use when installing packages from the global cache.
+    ///
+    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
+    /// Windows.
+    #[arg(long, value_enum, env = EnvVars::UV_LINK_MODE)]
+    pub link_mode: Option<uv_install_wheel::LinkMode>,
+}
+
+#[derive(Args)]
+pub struct StatsArgs {
+    /// Remove all recorded statistics.
+    #[arg(long)]
+    pub clear: bool,
+}
+
+#[derive(Subcommand)]
+pub enum VersionCommand {
+    /// Compare a version against a version specifier or another version.
+    ///
+    /// If a specifier is provided (e.g., `<2.0`), exits with a status code of zero if the version
+    /// satisfies the specifier, and a non-zero status code otherwise.
+    ///
+    /// If another version is provided (e.g., `2.0`), displays `<`, `==`, or `>` to indicate how
+    /// the first version orders relative to the second.
+    ///
+    /// Comparisons follow the PEP 440 semantics used by the resolver, including the handling of
+    /// pre-releases and local versions.
+    Compare(VersionCompareArgs),
+    /// Sort versions in PEP 440 order.
+    ///
+    /// Versions are read from the command line, or from stdin (one per line) if none are
+    /// provided.
+    Sort(VersionSortArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct VersionCompareArgs {
+    /// The version to compare.
+    pub version: String,
+
+    /// The version specifier (e.g., `>=1.0,<2.0`) or version (e.g., `1.2.3`) to compare against.
+    pub other: String,
+}
+
+#[derive(Args, Debug)]
+pub struct VersionSortArgs {
+    /// The versions to sort.
+    pub versions: Vec<String>,
+
+    /// Sort versions in descending order.
+    #[arg(long)]
+    pub reverse: bool,
+
+    /// Only display versions that satisfy the given specifier (e.g., `>=1.0,<2.0`).
+    #[arg(long)]
+    pub filter: Option<String>,
+}
+
 #[derive(Args, Debug)]
 pub struct HelpArgs {
     /// Disable pager when printing help
@@ -536,6 +768,24 @@ pub enum CacheCommand {
     Clean(CleanArgs),
     /// Prune all unreachable objects from the cache.
     Prune(PruneArgs),
+    /// Add the wheels and source distributions in a local directory to the cache.
+    ///
+    /// Each distribution is stored in the cache under the SHA-256 digest of its contents, along
+    /// with its metadata. Subsequent operations that know a distribution's digest (e.g., from the
+    /// index, the lockfile, or `--hash`) reuse the cached copy and its metadata rather than
+    /// downloading it, regardless of where it's fetched from.
+    ///
+    /// This is useful for pre-populating the cache with artifacts that were built in a separate
+    /// stage of a pipeline.
+    Add(CacheAddArgs),
+    /// Remove corrupt distribution metadata from the cache.
+    ///
+    /// uv caches the metadata for every distribution it resolves, such that it can be reused
+    /// across projects and commands without re-fetching or re-parsing archives. Entries that can't
+    /// be read are ignored and rewritten on next use; this command removes them eagerly.
+    ///
+    /// With `--all`, all cached metadata is removed, while retaining any cached archives.
+    GcMetadata(GcMetadataArgs),
     /// Show the cache directory.
     ///
     ///
@@ -560,6 +810,20 @@ pub struct CleanArgs {
     pub package: Vec<PackageName>,
 }
 
+#[derive(Args, Debug)]
+pub struct CacheAddArgs {
+    /// The directories containing the wheels and source distributions to add to the cache.
+    #[arg(required = true)]
+    pub paths: Vec<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct GcMetadataArgs {
+    /// Remove all cached distribution metadata, rather than only corrupt entries.
+    #[arg(long)]
+    pub all: bool,
+}
+
 #[derive(Args, Debug)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PruneArgs {
@@ -644,6 +908,42 @@ pub enum PipCommand {
         after_long_help = ""
     )]
     Check(PipCheckArgs),
+    /// Lint requirements files for common mistakes.
+    #[command(
+        after_help = "Use `uv help pip lint` for more details.",
+        after_long_help = ""
+    )]
+    Lint(PipLintArgs),
+    /// Merge multiple requirements files into one.
+    ///
+    /// Requirements for the same package and markers are combined into a single entry by
+    /// intersecting their version specifiers and unioning their extras. Requirements with different
+    /// markers are kept as separate entries.
+    #[command(
+        after_help = "Use `uv help pip merge` for more details.",
+        after_long_help = ""
+    )]
+    Merge(PipMergeArgs),
+    /// Rebuild editable installations in place, refreshing their metadata and entry points.
+    ///
+    /// Useful when the metadata of an editable source tree changes, e.g., when entry points are
+    /// added or removed during plugin development. Any newly-added dependencies of the editable
+    /// packages are installed, but existing dependencies are left as-is.
+    #[command(
+        after_help = "Use `uv help pip refresh-editables` for more details.",
+        after_long_help = ""
+    )]
+    RefreshEditables(PipRefreshEditablesArgs),
+    /// Verify the integrity of installed packages against their `RECORD` files.
+    ///
+    /// Each file installed by a package is re-hashed and compared against the hash recorded at
+    /// installation time, to detect files that were modified (e.g., by manual edits or bit rot),
+    /// deleted, or added to a package's directories.
+    #[command(
+        after_help = "Use `uv help pip verify` for more details.",
+        after_long_help = ""
+    )]
+    Verify(PipVerifyArgs),
 }
 
 #[derive(Subcommand)]
@@ -972,6 +1272,17 @@ pub struct PipCompileArgs {
     #[arg(long, short, alias = "constraint", env = EnvVars::UV_CONSTRAINT, value_delimiter = ' ', value_parser = parse_maybe_file_path)]
     pub constraints: Vec<Maybe<PathBuf>>,
 
+    /// Constrain versions to those installed in the current environment.
+    ///
+    /// Each package installed in the target environment is treated as if it were pinned in a
+    /// constraints file (e.g., `anyio==4.3.0`), such that the resolution remains compatible with
+    /// what's already deployed. This is useful for generating an incremental upgrade plan, rather
+    /// than a resolution from scratch.
+    ///
+    /// Editable and direct URL installations are not used as constraints.
+    #[arg(long)]
+    pub constraint_from_env: bool,
+
     /// Override versions using the given requirements files.
     ///
     /// Overrides files are `requirements.txt`-like files that force a specific version of a
@@ -1008,6 +1319,14 @@ pub struct PipCompileArgs {
     #[arg(long, overrides_with("all_extras"), hide = true)]
     pub no_all_extras: bool,
 
+    /// Include dependencies from the specified dependency group; may be provided more than once.
+    ///
+    /// Dependency groups are defined via `dependency-groups` in a `pyproject.toml`.
+    ///
+    /// Only applies to `pyproject.toml` sources.
+    #[arg(long)]
+    pub group: Vec<GroupName>,
+
     #[command(flatten)]
     pub resolver: ResolverArgs,
 
@@ -1029,6 +1348,261 @@ pub struct PipCompileArgs {
     #[arg(long, short)]
     pub output_file: Option<PathBuf>,
 
+    /// Sign the compiled requirements file with the given Ed25519 private key.
+    ///
+    /// The key must be PKCS#8 PEM-encoded, e.g., as generated by
+    /// `openssl genpkey -algorithm ed25519`.
+    ///
+    /// A digest of the requirements is embedded in a header at the top of the output file, and a
+    /// detached signature in the minisign format is written alongside the output file, with a
+    /// `.minisig` suffix. The signature can be verified with `uv pip sync --verify-signature`.
+    ///
+    /// Requires `--output-file`.
+    #[arg(long, requires = "output_file")]
+    pub sign: Option<PathBuf>,
+
+    /// Write the resolved dependency graph to the given file.
+    ///
+    /// The graph includes a node for each resolved package (and for each of its enabled extras and
+    /// dependency groups), and an edge for each dependency between them, annotated with the
+    /// environment markers under which the dependency applies.
+    ///
+    /// If the file has a `.dot` or `.gv` extension, the graph is written in the Graphviz DOT
+    /// language. Otherwise, it's written as JSON.
+    #[arg(long)]
+    pub emit_graph: Option<PathBuf>,
+
+    /// The format in which the resolution should be output.
+    ///
+    /// Supports `requirements-txt` (the default) and `github-snapshot`, which emits a snapshot
+    /// for the GitHub Dependency Submission API, such that the resolved packages can populate the
+    /// repository's dependency graph and Dependabot alerts.
+    ///
+    /// When running in GitHub Actions, the snapshot's `job`, `sha`, and `ref` fields are populated
+    /// from the `GITHUB_*` environment variables.
+    #[arg(long, value_enum, default_value_t = PipCompileFormat::default(), conflicts_with = "output_per_extra")]
+    pub format: PipCompileFormat,
+
+    /// Write a separate output file for each requested extra and dependency group.
+    ///
+    /// In addition to the output file, which includes all requested extras and groups, a file is
+    /// written alongside it with the base requirements alone, along with a file for each extra (or
+    /// group) containing the base requirements and that extra (or group) alone. For example, with
+    /// `--output-file requirements.txt` and `--extra dev`, the base requirements are written to
+    /// `requirements-base.txt`, and the `dev` extra to `requirements-dev.txt`.
+    ///
+    /// Each file is derived from a single resolution across all requested extras and groups, such
+    /// that packages are pinned consistently across all files.
+    ///
+    /// Requires an output file (e.g., `--output-file requirements.txt`).
+    #[arg(long)]
+    pub output_per_extra: bool,
+
+    /// Verify that the resolution is deterministic.
+    ///
+    /// Resolves the requirements a second time with the inputs presented in a different order and
+    /// verifies that both resolutions produce identical output. If the outputs diverge, uv will
+    /// report the differing lines and exit with an error.
+    ///
+    /// Divergence usually stems from iteration over unordered collections or from the order in
+    /// which packages are merged across indexes.
+    #[arg(long)]
+    pub verify_determinism: bool,
+
+    /// The seed with which to shuffle the inputs when verifying that the resolution is
+    /// deterministic.
+    ///
+    /// By default, a random seed is used, and reported if the resolutions diverge, such that a
+    /// failing ordering can be reproduced.
+    #[arg(long, requires = "verify_determinism")]
+    pub seed: Option<u64>,
+
+    /// Resume an interrupted resolution from its last checkpoint.
+    ///
+    /// When `--resume` is provided, uv periodically records its progress in the cache while
+    /// resolving, and flushes it if the resolution fails or is interrupted (e.g., by CTRL-C). A
+    /// subsequent invocation with `--resume` and the same inputs (including the index URLs,
+    /// resolution strategy, and `--exclude-newer` cutoff) will revisit the previously decided
+    /// versions first, reusing their cached metadata rather than exploring the resolution from
+    /// scratch.
+    ///
+    /// If no checkpoint exists for the given inputs, the resolution starts from scratch.
+    #[arg(long)]
+    pub resume: bool,
+
+    /// Restrict the resolution to the package versions listed in the given file.
+    ///
+    /// The file must contain one `name==version` pair per line (e.g., an organization-approved
+    /// catalog). Any package that is not listed, or any version of a listed package that is not
+    /// listed explicitly, is considered unavailable; if the requirements can't be satisfied from
+    /// the allowed versions, uv will report the chain of dependencies that required them.
+    ///
+    /// Packages provided via direct URL or local path are not subject to the allow-list.
+    #[arg(long, value_name = "FILE")]
+    pub allowed_packages: Option<PathBuf>,
+
+    /// When upgrading, change as few of the existing pins in the output file as possible.
+    ///
+    /// Packages that aren't being upgraded retain their pinned versions wherever a valid solution
+    /// exists. If a pinned version must change, uv selects the closest compatible version (the
+    /// smallest upgrade, then the smallest downgrade) rather than the latest.
+    ///
+    /// When combined with `--upgrade`, only the direct requirements are upgraded; their
+    /// dependencies retain their pinned versions where possible.
+    #[arg(long)]
+    pub minimal_changes: bool,
+
+    /// Report any direct requirements whose declared lower bounds can't be satisfied.
+    ///
+    /// Requires `--resolution lowest` or `--resolution lowest-direct`. After resolving, each
+    /// direct requirement is compared against the lowest version it resolved to: if that version
+    /// is higher than the declared lower bound (e.g., because older versions don't support the
+    /// target Python version, or conflict with other requirements), the lower bound is untested
+    /// and likely wrong. Requirements without a lower bound are reported too.
+    ///
+    /// The report is written to stderr, and doesn't affect the output file.
+    #[arg(long)]
+    pub report_bounds: bool,
+
+    /// Write pinned versions in canonical form, with trailing zeros removed (e.g., `==1.0.0.0` as
+    /// `==1`).
+    ///
+    /// Trailing zeros are insignificant when comparing versions, so the output matches the same
+    /// distributions, but is consistent across tools that canonicalize versions.
+    #[arg(long)]
+    pub normalize_specifiers: bool,
+
+    /// Include the derivation tree in the error report when resolution fails.
+    ///
+    /// The tree shows each incompatibility that led to the failure on its own line, along with the
+    /// requirements and incompatibilities from which it was derived. Direct requirements are
+    /// annotated with the file and line that declared them (e.g., `requirements.in:2`).
+    #[arg(long)]
+    pub show_derivation_tree: bool,
+
+    /// Explain why each package was pinned to its selected version.
+    ///
+    /// After resolution, a report is written to stderr listing, for each package, the requirements
+    /// that requested it (including any constraints or overrides), along with the newer versions
+    /// (or, with `--resolution lowest`, the older versions) that were rejected and why (e.g.,
+    /// excluded by a specifier, yanked, a pre-release, no compatible wheels, or an incompatible
+    /// `Requires-Python`). Any dependency cycles between the resolved packages are listed at the
+    /// end.
+    #[arg(long)]
+    pub explain: bool,
+
+    /// How to handle indexes declared within requirements files (e.g., via `--index-url` or
+    /// `--extra-index-url`).
+    ///
+    /// By default, uv merges such indexes with those provided on the command line, and uses them
+    /// for all packages (`merge`). Use `scope` to pin the requirements listed in each file to the
+    /// index declared in that file, such that other packages are resolved from the remaining
+    /// indexes; a file may declare at most one index under `scope`. Use `forbid` to reject any
+    /// requirements file that declares an index.
+    #[arg(long, value_enum, env = EnvVars::UV_EMBEDDED_INDEX_STRATEGY)]
+    pub embedded_index_strategy: Option<EmbeddedIndexStrategy>,
+
+    /// How to handle files on an index with an unparsable filename or version.
+    ///
+    /// Some indexes list files whose filenames or metadata (e.g., `requires-python`) can't be
+    /// parsed. By default, uv skips such files silently (`skip`). Use `warn` to skip them with a
+    /// warning that names each file, or `error` to fail the operation instead.
+    #[arg(long, value_enum, env = EnvVars::UV_INVALID_METADATA)]
+    pub invalid_metadata: Option<InvalidMetadataPolicy>,
+
+    /// How to handle versions that are marked as yanked by the index.
+    ///
+    /// By default, uv only selects a yanked version if it's pinned with an exact (`==` or `===`)
+    /// requirement, or by an existing output file (`allow-if-pinned`), and warns when it does so.
+    /// Use `allow` to treat yanked versions as if they weren't yanked, `warn` to allow them with a
+    /// warning, or `deny` to reject them even if they're pinned.
+    #[arg(long, value_enum, env = EnvVars::UV_YANKED)]
+    pub yanked: Option<YankedPolicy>,
+
+    /// How to handle an extra index that can't be reached.
+    ///
+    /// By default, uv fails if any index can't be reached (`error`), e.g., due to a connection
+    /// failure, a timeout, or a server error. Use `warn` to continue with the remaining indexes
+    /// with a warning.
+    ///
+    /// The default index (`--index-url`), and any index that's the sole source of a package, is
+    /// always required. Unavailable indexes are only skipped under the `unsafe-best-match` and
+    /// `unsafe-first-match` index strategies, as skipping an index under `first-index` would allow
+    /// a later index to supply its packages.
+    #[arg(long, value_enum, env = EnvVars::UV_INDEX_UNAVAILABLE)]
+    pub index_unavailable: Option<IndexUnavailablePolicy>,
+
+    /// Prefer specific wheel variants when multiple wheels for a version are compatible.
+    ///
+    /// By default, uv selects the most specific compatible wheel for the target platform. Use
+    /// this option to prefer `manylinux` or `musllinux` wheels, to prefer or avoid wheels built
+    /// against the stable ABI (`abi3` or `no-abi3`), or to prefer the smallest wheel based on the
+    /// file size reported by the index (`smallest`).
+    ///
+    /// May be provided multiple times; earlier preferences take precedence over later ones.
+    #[arg(long, value_enum, value_delimiter = ',', env = EnvVars::UV_WHEEL_PREFERENCE)]
+    pub wheel_preference: Option<Vec<WheelPreference>>,
+
+    /// Prefer specific local version labels when multiple variants of a version are available.
+    ///
+    /// Local versions (like `2.1.0+cu118`) share a public version (`2.1.0`) with other variants,
+    /// which may be served by different indexes. By default, such variants are ranked by version
+    /// order. Use this option to prefer a given label (e.g., `cu118` or `cpu`), or `none` to prefer
+    /// the variant without a local version label.
+    ///
+    /// Only variants of the selected public version are reordered; a newer public version is
+    /// always preferred over an older one.
+    ///
+    /// May be provided multiple times; earlier preferences take precedence over later ones.
+    #[arg(long, value_delimiter = ',', env = EnvVars::UV_LOCAL_VERSION_PREFERENCE)]
+    pub local_version_preference: Option<Vec<LocalVersionPreference>>,
+
+    /// Abort the resolution if it takes longer than the given number of seconds.
+    ///
+    /// On timeout, uv reports the packages involved in the most conflicts, which are typically
+    /// the best candidates for additional constraints.
+    ///
+    /// By default, the resolution is not limited.
+    #[arg(long, env = EnvVars::UV_RESOLUTION_TIMEOUT, value_name = "SECONDS")]
+    pub resolution_timeout: Option<u64>,
+
+    /// Abort the resolution after the given number of backtracking steps.
+    ///
+    /// Each conflict between the versions selected for two packages causes the resolver to
+    /// backtrack. When the limit is exceeded, uv reports the packages involved in the most
+    /// conflicts, which are typically the best candidates for additional constraints.
+    ///
+    /// By default, the resolution is not limited.
+    #[arg(long, env = EnvVars::UV_MAX_BACKTRACKS)]
+    pub max_backtracks: Option<usize>,
+
+    /// Restrict the sources from which a package may be installed.
+    ///
+    /// Accepts a `<package>=<policy>` pair, where the policy is one of `binary` (only install
+    /// pre-built wheels), `git` (only install from a Git repository), or `index:<url>` (only
+    /// install from the given index, ignoring all other indexes and `--find-links` entries).
+    ///
+    /// If a requirement cannot be satisfied under its policy, resolution fails with an error
+    /// naming the violated policy.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE=POLICY")]
+    pub source_policy: Option<Vec<PackageSourcePolicy>>,
+
+    /// Declare that requirements on a package are satisfied by another package.
+    ///
+    /// Accepts a `<package>=<provider>` pair, e.g., `opencv-python=opencv-python-headless` or
+    /// `pil=pillow`. If the provider is required directly, any requirement on the package, whether
+    /// direct or transitive, is replaced by a requirement on its provider, such that the two are
+    /// never installed alongside each other.
+    ///
+    /// Since the packages are versioned independently, any version specifier on the replaced
+    /// requirement is ignored. Replaced requirements can't be requested from a URL.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE=PROVIDER")]
+    pub provided_by: Option<Vec<PackageProvider>>,
+
     /// Include extras in the output file.
     ///
     /// By default, uv strips extras, as any packages pulled in by the extras are already included
@@ -1122,6 +1696,17 @@ pub struct PipCompileArgs {
     #[arg(long, overrides_with("generate_hashes"), hide = true)]
     pub no_generate_hashes: bool,
 
+    /// Only select distributions for which the index provides a hash.
+    ///
+    /// Wheels and source distributions that are listed on the index without a hash are treated
+    /// as unavailable, such that every pinned distribution can be verified by hash. Combine with
+    /// `--generate-hashes` to produce an output file that is fully hash-pinned.
+    #[arg(long, overrides_with("no_only_hashed"))]
+    pub only_hashed: bool,
+
+    #[arg(long, overrides_with("only_hashed"), hide = true)]
+    pub no_only_hashed: bool,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary Python code. The cached wheels of
@@ -1178,8 +1763,13 @@ pub struct PipCompileArgs {
     ///
     /// If a patch version is omitted, the minimum patch version is assumed. For
     /// example, `3.8` is mapped to `3.8.0`.
+    ///
+    /// May be provided multiple times to resolve for several Python versions at once, e.g.,
+    /// `--python-version 3.9 --python-version 3.12`. In that case, a universal resolution is
+    /// performed across the requested versions, and the output includes markers to distinguish
+    /// between them.
     #[arg(long, help_heading = "Python options")]
-    pub python_version: Option<PythonVersion>,
+    pub python_version: Vec<PythonVersion>,
 
     /// The platform for which requirements should be resolved.
     ///
@@ -1192,6 +1782,14 @@ pub struct PipCompileArgs {
     #[arg(long)]
     pub python_platform: Option<TargetTriple>,
 
+    /// Enable a user-defined feature for marker evaluation; may be provided more than once.
+    ///
+    /// Features are matched by `uv_features` markers, e.g., `torch ; 'gpu' in uv_features`.
+    /// Requirements guarded by a disabled feature are omitted. In universal mode, feature markers
+    /// are preserved in the output instead.
+    #[arg(long)]
+    pub feature: Option<Vec<String>>,
+
     /// Perform a universal resolution, attempting to generate a single `requirements.txt` output
     /// file that is compatible with all operating systems, architectures, and Python
     /// implementations.
@@ -1258,14 +1856,39 @@ pub struct PipCompileArgs {
     #[arg(long, overrides_with("emit_index_annotation"), hide = true)]
     pub no_emit_index_annotation: bool,
 
-    #[command(flatten)]
-    pub compat_args: compat::PipCompileCompatArgs,
-}
+    /// Include a comment in the header of the output file indicating the range of Python versions
+    /// supported by all of the pinned dependencies (i.e., the intersection of their
+    /// `Requires-Python` metadata).
+    #[arg(long, overrides_with("no_emit_requires_python"))]
+    pub emit_requires_python: bool,
 
-#[derive(Args)]
-#[allow(clippy::struct_excessive_bools)]
-pub struct PipSyncArgs {
-    /// Include all packages listed in the given `requirements.txt` files.
+    #[arg(long, overrides_with("emit_requires_python"), hide = true)]
+    pub no_emit_requires_python: bool,
+
+    /// Respect pip's environment variables for configuring indexes and builds.
+    ///
+    /// When enabled, `PIP_INDEX_URL`, `PIP_EXTRA_INDEX_URL`, `PIP_FIND_LINKS`, and `PIP_NO_BINARY`
+    /// are mapped onto the equivalent uv settings, allowing uv to be used in place of pip in
+    /// environments that configure pip exclusively via environment variables.
+    ///
+    /// Command-line arguments and uv's own environment variables (e.g., `UV_INDEX_URL`) take
+    /// precedence over pip's environment variables, which in turn take precedence over persistent
+    /// configuration.
+    #[arg(
+        long,
+        env = EnvVars::UV_PIP_ENV,
+        value_parser = clap::builder::BoolishValueParser::new(),
+    )]
+    pub pip_env: bool,
+
+    #[command(flatten)]
+    pub compat_args: compat::PipCompileCompatArgs,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct PipSyncArgs {
+    /// Include all packages listed in the given `requirements.txt` files.
     ///
     /// If a `pyproject.toml`, `setup.py`, or `setup.cfg` file is provided, uv will
     /// extract the requirements for the relevant project.
@@ -1293,6 +1916,16 @@ pub struct PipSyncArgs {
     #[arg(long, short, alias = "build-constraint", env = EnvVars::UV_BUILD_CONSTRAINT, value_delimiter = ' ', value_parser = parse_maybe_file_path)]
     pub build_constraints: Vec<Maybe<PathBuf>>,
 
+    /// Sync only the given packages, along with their transitive dependencies.
+    ///
+    /// The dependencies of each package are resolved to the versions pinned in the requirements
+    /// files, and must themselves be pinned. Any other installed packages are left untouched,
+    /// rather than removed, as are any other packages listed in the requirements files.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE")]
+    pub only: Vec<PackageName>,
+
     #[command(flatten)]
     pub installer: InstallerArgs,
 
@@ -1341,6 +1974,32 @@ pub struct PipSyncArgs {
     )]
     pub no_verify_hashes: bool,
 
+    /// Verify the signature of each requirements file against the given Ed25519 public key
+    /// before installing.
+    ///
+    /// The key may be PEM-encoded (e.g., as generated by `openssl pkey -pubout`), or in the
+    /// minisign public key format. Each requirements file must be accompanied by a detached
+    /// signature with a `.minisig` suffix (e.g., `requirements.txt.minisig`), as generated by
+    /// `uv pip compile --sign`, and must contain a matching digest header.
+    ///
+    /// The signature must have been created for a file with the same name, and must not predate
+    /// that of the last file of the same name synced into the environment. Constraints files, and
+    /// any files included via `-r` or `-c`, are verified in the same way.
+    #[arg(long, value_name = "PUBLIC_KEY")]
+    pub verify_signature: Option<PathBuf>,
+
+    /// Append a JSON Lines record of the changes made to the environment to the given file.
+    ///
+    /// Each line is a JSON object with an `event` field. The log records every distribution that
+    /// was installed or uninstalled (along with its source and hashes), every file that was
+    /// written or removed, and the timing of the sync, for use in deployment auditing. Each event
+    /// is timestamped as it happens, and the SHA-256 hash of every installed wheel is recorded.
+    ///
+    /// If the sync fails partway, the changes made before the failure are recorded, followed by a
+    /// `failed` event.
+    #[arg(long, value_name = "PATH")]
+    pub audit_log: Option<PathBuf>,
+
     /// The Python interpreter into which packages should be installed.
     ///
     /// By default, syncing requires a virtual environment. A path to an alternative Python can be
@@ -1358,6 +2017,19 @@ pub struct PipSyncArgs {
     )]
     pub python: Option<Maybe<String>>,
 
+    /// The path to the Python environment into which packages should be installed.
+    ///
+    /// Unlike `--python`, the environment is used as-is: uv will not search for an interpreter,
+    /// and the environment does not need to be activated. The path should point to the root of a
+    /// virtual environment (i.e., the directory containing `pyvenv.cfg`).
+    #[arg(
+        long,
+        conflicts_with_all = ["python", "system", "target", "prefix"],
+        help_heading = "Python options",
+        value_parser = parse_file_path,
+    )]
+    pub environment: Option<PathBuf>,
+
     /// Install packages into the system Python environment.
     ///
     /// By default, uv installs into the virtual environment in the current working directory or any
@@ -1409,6 +2081,28 @@ pub struct PipSyncArgs {
     #[arg(long, conflicts_with = "target")]
     pub prefix: Option<PathBuf>,
 
+    /// Install packages into a `__pypackages__/<major>.<minor>/lib` directory within the project
+    /// directory (i.e., the current working directory, or the directory passed to `--project`),
+    /// rather than into the virtual or system Python environment.
+    ///
+    /// This is a project-local layout in the style of PEP 582, which avoids the need to create or
+    /// activate a virtual environment. Use `uv run --pypackages` to run commands with the
+    /// directory on `sys.path`.
+    #[arg(long, conflicts_with_all = ["target", "prefix"])]
+    pub pypackages: bool,
+
+    /// Install the contents of a wheel's `.data` directories into custom locations within the
+    /// `--target` or `--prefix` directory, specified as `KEY=PATH` pairs (e.g., `scripts=usr/bin`).
+    ///
+    /// Supported keys are `scripts`, `headers`, and `data`. Relative paths are resolved against
+    /// the `--target` or `--prefix` directory. As with the default layout, headers are installed
+    /// into a subdirectory named after the package.
+    ///
+    /// Useful for staging files into an operating system package layout (e.g., for a `.deb` or
+    /// `.rpm`).
+    #[arg(long, value_name = "KEY=PATH")]
+    pub scheme_path: Vec<SchemePath>,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary Python code. The cached wheels of
@@ -1483,22 +2177,224 @@ pub struct PipSyncArgs {
     /// platform. Conversely, any distributions that are built from source may be incompatible with
     /// the _target_ platform, as they will be built for the _current_ platform. The
     /// `--python-platform` option is intended for advanced use cases.
+    ///
+    /// If the target platform uses a different operating system than the current platform, entry
+    /// point scripts are not generated and bytecode is not compiled. Combine with `--target` and
+    /// `--only-binary :all:` to stage the packages for another platform (e.g., when bundling an
+    /// application).
     #[arg(long)]
     pub python_platform: Option<TargetTriple>,
 
+    /// Enable a user-defined feature for marker evaluation; may be provided more than once.
+    ///
+    /// Features are matched by `uv_features` markers, e.g., `torch ; 'gpu' in uv_features`.
+    /// Requirements guarded by a disabled feature are omitted.
+    #[arg(long)]
+    pub feature: Option<Vec<String>>,
+
     /// Validate the Python environment after completing the installation, to detect packages with
     /// missing dependencies or other issues.
+    ///
+    /// Also fails the installation if a requirement requests an extra that isn't provided by the
+    /// resolved distribution (e.g., `package[typo]`), rather than emitting a warning.
     #[arg(long, overrides_with("no_strict"))]
     pub strict: bool,
 
     #[arg(long, overrides_with("strict"), hide = true)]
     pub no_strict: bool,
 
+    /// Before installing, verify that each wheel is compatible with the target interpreter.
+    ///
+    /// In addition to the tags in the wheel's filename, checks that the tags declared in its
+    /// `WHEEL` file are supported by the interpreter (including free-threaded and debug builds),
+    /// and, on glibc-based Linux, that any bundled shared libraries don't require a newer glibc
+    /// than the target provides. Fails with the incompatible wheels, rather than installing them.
+    #[arg(long, overrides_with("no_check_abi"))]
+    pub check_abi: bool,
+
+    #[arg(long, overrides_with("check_abi"), hide = true)]
+    pub no_check_abi: bool,
+
+    /// How to handle indexes declared within requirements files (e.g., via `--index-url` or
+    /// `--extra-index-url`).
+    ///
+    /// By default, uv merges such indexes with those provided on the command line, and uses them
+    /// for all packages (`merge`). Use `scope` to pin the requirements listed in each file to the
+    /// index declared in that file, such that other packages are resolved from the remaining
+    /// indexes; a file may declare at most one index under `scope`. Use `forbid` to reject any
+    /// requirements file that declares an index.
+    #[arg(long, value_enum, env = EnvVars::UV_EMBEDDED_INDEX_STRATEGY)]
+    pub embedded_index_strategy: Option<EmbeddedIndexStrategy>,
+
+    /// How to handle files on an index with an unparsable filename or version.
+    ///
+    /// Some indexes list files whose filenames or metadata (e.g., `requires-python`) can't be
+    /// parsed. By default, uv skips such files silently (`skip`). Use `warn` to skip them with a
+    /// warning that names each file, or `error` to fail the operation instead.
+    #[arg(long, value_enum, env = EnvVars::UV_INVALID_METADATA)]
+    pub invalid_metadata: Option<InvalidMetadataPolicy>,
+
+    /// How to handle versions that are marked as yanked by the index.
+    ///
+    /// By default, uv only selects a yanked version if it's pinned with an exact (`==` or `===`)
+    /// requirement, or by an existing output file (`allow-if-pinned`), and warns when it does so.
+    /// Use `allow` to treat yanked versions as if they weren't yanked, `warn` to allow them with a
+    /// warning, or `deny` to reject them even if they're pinned.
+    #[arg(long, value_enum, env = EnvVars::UV_YANKED)]
+    pub yanked: Option<YankedPolicy>,
+
+    /// How to handle an extra index that can't be reached.
+    ///
+    /// By default, uv fails if any index can't be reached (`error`), e.g., due to a connection
+    /// failure, a timeout, or a server error. Use `warn` to continue with the remaining indexes
+    /// with a warning.
+    ///
+    /// The default index (`--index-url`), and any index that's the sole source of a package, is
+    /// always required. Unavailable indexes are only skipped under the `unsafe-best-match` and
+    /// `unsafe-first-match` index strategies, as skipping an index under `first-index` would allow
+    /// a later index to supply its packages.
+    #[arg(long, value_enum, env = EnvVars::UV_INDEX_UNAVAILABLE)]
+    pub index_unavailable: Option<IndexUnavailablePolicy>,
+
+    /// Prefer specific wheel variants when multiple wheels for a version are compatible.
+    ///
+    /// By default, uv selects the most specific compatible wheel for the target platform. Use
+    /// this option to prefer `manylinux` or `musllinux` wheels, to prefer or avoid wheels built
+    /// against the stable ABI (`abi3` or `no-abi3`), or to prefer the smallest wheel based on the
+    /// file size reported by the index (`smallest`).
+    ///
+    /// May be provided multiple times; earlier preferences take precedence over later ones.
+    #[arg(long, value_enum, value_delimiter = ',', env = EnvVars::UV_WHEEL_PREFERENCE)]
+    pub wheel_preference: Option<Vec<WheelPreference>>,
+
+    /// Prefer specific local version labels when multiple variants of a version are available.
+    ///
+    /// Local versions (like `2.1.0+cu118`) share a public version (`2.1.0`) with other variants,
+    /// which may be served by different indexes. By default, such variants are ranked by version
+    /// order. Use this option to prefer a given label (e.g., `cu118` or `cpu`), or `none` to prefer
+    /// the variant without a local version label.
+    ///
+    /// Only variants of the selected public version are reordered; a newer public version is
+    /// always preferred over an older one.
+    ///
+    /// May be provided multiple times; earlier preferences take precedence over later ones.
+    #[arg(long, value_delimiter = ',', env = EnvVars::UV_LOCAL_VERSION_PREFERENCE)]
+    pub local_version_preference: Option<Vec<LocalVersionPreference>>,
+
+    /// Abort the resolution if it takes longer than the given number of seconds.
+    ///
+    /// On timeout, uv reports the packages involved in the most conflicts, which are typically
+    /// the best candidates for additional constraints.
+    ///
+    /// By default, the resolution is not limited.
+    #[arg(long, env = EnvVars::UV_RESOLUTION_TIMEOUT, value_name = "SECONDS")]
+    pub resolution_timeout: Option<u64>,
+
+    /// Abort the resolution after the given number of backtracking steps.
+    ///
+    /// Each conflict between the versions selected for two packages causes the resolver to
+    /// backtrack. When the limit is exceeded, uv reports the packages involved in the most
+    /// conflicts, which are typically the best candidates for additional constraints.
+    ///
+    /// By default, the resolution is not limited.
+    #[arg(long, env = EnvVars::UV_MAX_BACKTRACKS)]
+    pub max_backtracks: Option<usize>,
+
+    /// Restrict the sources from which a package may be installed.
+    ///
+    /// Accepts a `<package>=<policy>` pair, where the policy is one of `binary` (only install
+    /// pre-built wheels), `git` (only install from a Git repository), or `index:<url>` (only
+    /// install from the given index, ignoring all other indexes and `--find-links` entries).
+    ///
+    /// If a requirement cannot be satisfied under its policy, resolution fails with an error
+    /// naming the violated policy.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE=POLICY")]
+    pub source_policy: Option<Vec<PackageSourcePolicy>>,
+
+    /// Declare that requirements on a package are satisfied by another package.
+    ///
+    /// Accepts a `<package>=<provider>` pair, e.g., `opencv-python=opencv-python-headless` or
+    /// `pil=pillow`. If the provider is required directly, any requirement on the package, whether
+    /// direct or transitive, is replaced by a requirement on its provider, such that the two are
+    /// never installed alongside each other.
+    ///
+    /// Since the packages are versioned independently, any version specifier on the replaced
+    /// requirement is ignored. Replaced requirements can't be requested from a URL.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE=PROVIDER")]
+    pub provided_by: Option<Vec<PackageProvider>>,
+
+    /// Only install distributions for which the index provides a hash.
+    ///
+    /// Wheels and source distributions that are listed on the index without a hash are treated
+    /// as unavailable, such that every installed distribution can be verified by hash.
+    #[arg(long, overrides_with("no_only_hashed"))]
+    pub only_hashed: bool,
+
+    #[arg(long, overrides_with("only_hashed"), hide = true)]
+    pub no_only_hashed: bool,
+
+    /// Fail if the total size of the distributions selected for installation exceeds the given
+    /// limit.
+    ///
+    /// Accepts a size with an optional unit (e.g., `500MB` or `1GiB`). The check is performed after
+    /// resolution, before any distributions are downloaded, using the file sizes reported by the
+    /// index (per PEP 700) or, if unavailable, `HEAD` requests.
+    ///
+    /// Packages that are already installed or cached are not counted.
+    #[arg(long, env = EnvVars::UV_MAX_TOTAL_DOWNLOAD_SIZE, value_name = "SIZE")]
+    pub max_total_download_size: Option<ByteSize>,
+
+    /// Fail if any distribution selected for installation exceeds the given size.
+    ///
+    /// Accepts a size with an optional unit (e.g., `100MB` or `1GiB`). The check is performed after
+    /// resolution, before any distributions are downloaded, using the file sizes reported by the
+    /// index (per PEP 700) or, if unavailable, `HEAD` requests.
+    #[arg(long, env = EnvVars::UV_MAX_PACKAGE_SIZE, value_name = "SIZE")]
+    pub max_package_size: Option<ByteSize>,
+
+    /// Reinstall any packages that were installed from a VCS repository, regardless of whether
+    /// they're already installed.
+    ///
+    /// The source of each installed package is determined from its `direct_url.json`. Useful for
+    /// picking up changes to a branch or tag without a version bump. Implies `--refresh-package`
+    /// for the affected packages.
+    #[arg(long, help_heading = "Installer options")]
+    pub reinstall_vcs: bool,
+
+    /// Reinstall any packages that were installed from a local directory or archive, regardless
+    /// of whether they're already installed.
+    ///
+    /// The source of each installed package is determined from its `direct_url.json`. Useful for
+    /// picking up changes to local sources without a version bump. Implies `--refresh-package` for
+    /// the affected packages.
+    #[arg(long, help_heading = "Installer options")]
+    pub reinstall_local: bool,
+
     /// Perform a dry run, i.e., don't actually install anything but resolve the dependencies and
     /// print the resulting plan.
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Respect pip's environment variables for configuring indexes and builds.
+    ///
+    /// When enabled, `PIP_INDEX_URL`, `PIP_EXTRA_INDEX_URL`, `PIP_FIND_LINKS`, and `PIP_NO_BINARY`
+    /// are mapped onto the equivalent uv settings, allowing uv to be used in place of pip in
+    /// environments that configure pip exclusively via environment variables.
+    ///
+    /// Command-line arguments and uv's own environment variables (e.g., `UV_INDEX_URL`) take
+    /// precedence over pip's environment variables, which in turn take precedence over persistent
+    /// configuration.
+    #[arg(
+        long,
+        env = EnvVars::UV_PIP_ENV,
+        value_parser = clap::builder::BoolishValueParser::new(),
+    )]
+    pub pip_env: bool,
+
     #[command(flatten)]
     pub compat_args: compat::PipSyncCompatArgs,
 }
@@ -1526,6 +2422,16 @@ pub struct PipInstallArgs {
     #[arg(long, short, group = "sources")]
     pub editable: Vec<String>,
 
+    /// Install the given packages alongside the other requirements.
+    ///
+    /// The packages are resolved jointly with the requirements, such that their versions remain
+    /// consistent, but aren't written to any file; e.g., `uv pip install -r requirements.txt --with
+    /// ipython` installs `ipython` into an environment synced from `requirements.txt`.
+    ///
+    /// May be provided more than once, or as a comma-separated list.
+    #[arg(long, group = "sources")]
+    pub with: Vec<comma::CommaSeparatedRequirements>,
+
     /// Constrain versions using the given requirements files.
     ///
     /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
@@ -1572,6 +2478,14 @@ pub struct PipInstallArgs {
     #[arg(long, overrides_with("all_extras"), hide = true)]
     pub no_all_extras: bool,
 
+    /// Include dependencies from the specified dependency group; may be provided more than once.
+    ///
+    /// Dependency groups are defined via `dependency-groups` in a `pyproject.toml`.
+    ///
+    /// Only applies to `pyproject.toml` sources.
+    #[arg(long)]
+    pub group: Vec<GroupName>,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -1634,6 +2548,9 @@ pub struct PipInstallArgs {
     /// be provided, but it is only recommended in continuous integration (CI) environments and
     /// should be used with caution, as it can modify the system Python installation.
     ///
+    /// To install into a Python environment in a running Docker or Podman container, provide the
+    /// container and the path to its interpreter, e.g., `docker://<container>#/usr/bin/python3`.
+    ///
     /// See `uv help python` for details on Python discovery and supported request formats.
     #[arg(
         long,
@@ -1645,6 +2562,19 @@ pub struct PipInstallArgs {
     )]
     pub python: Option<Maybe<String>>,
 
+    /// The path to the Python environment into which packages should be installed.
+    ///
+    /// Unlike `--python`, the environment is used as-is: uv will not search for an interpreter,
+    /// and the environment does not need to be activated. The path should point to the root of a
+    /// virtual environment (i.e., the directory containing `pyvenv.cfg`).
+    #[arg(
+        long,
+        conflicts_with_all = ["python", "system", "target", "prefix"],
+        help_heading = "Python options",
+        value_parser = parse_file_path,
+    )]
+    pub environment: Option<PathBuf>,
+
     /// Install packages into the system Python environment.
     ///
     /// By default, uv installs into the virtual environment in the current working directory or any
@@ -1664,6 +2594,27 @@ pub struct PipInstallArgs {
     #[arg(long, overrides_with("system"), hide = true)]
     pub no_system: bool,
 
+    /// Create a virtual environment for the project if no environment is found.
+    ///
+    /// By default, uv errors if no virtual environment is active or present in the current
+    /// working directory or any parent directory. With `--create-venv`, uv will instead create a
+    /// virtual environment at `.venv` in the root of the project in the current directory (as in
+    /// `uv sync`), respecting any `.python-version` file and the project's `requires-python`, and
+    /// install into it.
+    ///
+    /// Has no effect when installing into the system Python environment, or with `--environment`,
+    /// `--target`, or `--prefix`.
+    #[arg(
+        long,
+        env = EnvVars::UV_PIP_CREATE_VENV,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_create_venv")
+    )]
+    pub create_venv: bool,
+
+    #[arg(long, overrides_with("create_venv"), hide = true)]
+    pub no_create_venv: bool,
+
     /// Allow uv to modify an `EXTERNALLY-MANAGED` Python installation.
     ///
     /// WARNING: `--break-system-packages` is intended for use in continuous integration (CI)
@@ -1696,6 +2647,28 @@ pub struct PipInstallArgs {
     #[arg(long, conflicts_with = "target")]
     pub prefix: Option<PathBuf>,
 
+    /// Install packages into a `__pypackages__/<major>.<minor>/lib` directory within the project
+    /// directory (i.e., the current working directory, or the directory passed to `--project`),
+    /// rather than into the virtual or system Python environment.
+    ///
+    /// This is a project-local layout in the style of PEP 582, which avoids the need to create or
+    /// activate a virtual environment. Use `uv run --pypackages` to run commands with the
+    /// directory on `sys.path`.
+    #[arg(long, conflicts_with_all = ["target", "prefix"])]
+    pub pypackages: bool,
+
+    /// Install the contents of a wheel's `.data` directories into custom locations within the
+    /// `--target` or `--prefix` directory, specified as `KEY=PATH` pairs (e.g., `scripts=usr/bin`).
+    ///
+    /// Supported keys are `scripts`, `headers`, and `data`. Relative paths are resolved against
+    /// the `--target` or `--prefix` directory. As with the default layout, headers are installed
+    /// into a subdirectory named after the package.
+    ///
+    /// Useful for staging files into an operating system package layout (e.g., for a `.deb` or
+    /// `.rpm`).
+    #[arg(long, value_name = "KEY=PATH")]
+    pub scheme_path: Vec<SchemePath>,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary Python code. The cached wheels of
@@ -1763,9 +2736,21 @@ pub struct PipInstallArgs {
     /// platform. Conversely, any distributions that are built from source may be incompatible with
     /// the _target_ platform, as they will be built for the _current_ platform. The
     /// `--python-platform` option is intended for advanced use cases.
+    ///
+    /// If the target platform uses a different operating system than the current platform, entry
+    /// point scripts are not generated and bytecode is not compiled. Combine with `--target` and
+    /// `--only-binary :all:` to stage the packages for another platform (e.g., when bundling an
+    /// application).
     #[arg(long)]
     pub python_platform: Option<TargetTriple>,
 
+    /// Enable a user-defined feature for marker evaluation; may be provided more than once.
+    ///
+    /// Features are matched by `uv_features` markers, e.g., `torch ; 'gpu' in uv_features`.
+    /// Requirements guarded by a disabled feature are omitted.
+    #[arg(long)]
+    pub feature: Option<Vec<String>>,
+
     /// Do not remove extraneous packages present in the environment.
     #[arg(long, overrides_with("exact"), alias = "no-exact", hide = true)]
     pub inexact: bool,
@@ -1780,17 +2765,254 @@ pub struct PipInstallArgs {
 
     /// Validate the Python environment after completing the installation, to detect packages with
     /// missing dependencies or other issues.
+    ///
+    /// Also fails the installation if a requirement requests an extra that isn't provided by the
+    /// resolved distribution (e.g., `package[typo]`), rather than emitting a warning.
     #[arg(long, overrides_with("no_strict"))]
     pub strict: bool,
 
     #[arg(long, overrides_with("strict"), hide = true)]
     pub no_strict: bool,
 
+    /// Before installing, verify that each wheel is compatible with the target interpreter.
+    ///
+    /// In addition to the tags in the wheel's filename, checks that the tags declared in its
+    /// `WHEEL` file are supported by the interpreter (including free-threaded and debug builds),
+    /// and, on glibc-based Linux, that any bundled shared libraries don't require a newer glibc
+    /// than the target provides. Fails with the incompatible wheels, rather than installing them.
+    #[arg(long, overrides_with("no_check_abi"))]
+    pub check_abi: bool,
+
+    #[arg(long, overrides_with("check_abi"), hide = true)]
+    pub no_check_abi: bool,
+
+    /// How to handle indexes declared within requirements files (e.g., via `--index-url` or
+    /// `--extra-index-url`).
+    ///
+    /// By default, uv merges such indexes with those provided on the command line, and uses them
+    /// for all packages (`merge`). Use `scope` to pin the requirements listed in each file to the
+    /// index declared in that file, such that other packages are resolved from the remaining
+    /// indexes; a file may declare at most one index under `scope`. Use `forbid` to reject any
+    /// requirements file that declares an index.
+    #[arg(long, value_enum, env = EnvVars::UV_EMBEDDED_INDEX_STRATEGY)]
+    pub embedded_index_strategy: Option<EmbeddedIndexStrategy>,
+
+    /// How to handle files on an index with an unparsable filename or version.
+    ///
+    /// Some indexes list files whose filenames or metadata (e.g., `requires-python`) can't be
+    /// parsed. By default, uv skips such files silently (`skip`). Use `warn` to skip them with a
+    /// warning that names each file, or `error` to fail the operation instead.
+    #[arg(long, value_enum, env = EnvVars::UV_INVALID_METADATA)]
+    pub invalid_metadata: Option<InvalidMetadataPolicy>,
+
+    /// How to handle versions that are marked as yanked by the index.
+    ///
+    /// By default, uv only selects a yanked version if it's pinned with an exact (`==` or `===`)
+    /// requirement, or by an existing output file (`allow-if-pinned`), and warns when it does so.
+    /// Use `allow` to treat yanked versions as if they weren't yanked, `warn` to allow them with a
+    /// warning, or `deny` to reject them even if they're pinned.
+    #[arg(long, value_enum, env = EnvVars::UV_YANKED)]
+    pub yanked: Option<YankedPolicy>,
+
+    /// How to handle an extra index that can't be reached.
+    ///
+    /// By default, uv fails if any index can't be reached (`error`), e.g., due to a connection
+    /// failure, a timeout, or a server error. Use `warn` to continue with the remaining indexes
+    /// with a warning.
+    ///
+    /// The default index (`--index-url`), and any index that's the sole source of a package, is
+    /// always required. Unavailable indexes are only skipped under the `unsafe-best-match` and
+    /// `unsafe-first-match` index strategies, as skipping an index under `first-index` would allow
+    /// a later index to supply its packages.
+    #[arg(long, value_enum, env = EnvVars::UV_INDEX_UNAVAILABLE)]
+    pub index_unavailable: Option<IndexUnavailablePolicy>,
+
+    /// Prefer specific wheel variants when multiple wheels for a version are compatible.
+    ///
+    /// By default, uv selects the most specific compatible wheel for the target platform. Use
+    /// this option to prefer `manylinux` or `musllinux` wheels, to prefer or avoid wheels built
+    /// against the stable ABI (`abi3` or `no-abi3`), or to prefer the smallest wheel based on the
+    /// file size reported by the index (`smallest`).
+    ///
+    /// May be provided multiple times; earlier preferences take precedence over later ones.
+    #[arg(long, value_enum, value_delimiter = ',', env = EnvVars::UV_WHEEL_PREFERENCE)]
+    pub wheel_preference: Option<Vec<WheelPreference>>,
+
+    /// Prefer specific local version labels when multiple variants of a version are available.
+    ///
+    /// Local versions (like `2.1.0+cu118`) share a public version (`2.1.0`) with other variants,
+    /// which may be served by different indexes. By default, such variants are ranked by version
+    /// order. Use this option to prefer a given label (e.g., `cu118` or `cpu`), or `none` to prefer
+    /// the variant without a local version label.
+    ///
+    /// Only variants of the selected public version are reordered; a newer public version is
+    /// always preferred over an older one.
+    ///
+    /// May be provided multiple times; earlier preferences take precedence over later ones.
+    #[arg(long, value_delimiter = ',', env = EnvVars::UV_LOCAL_VERSION_PREFERENCE)]
+    pub local_version_preference: Option<Vec<LocalVersionPreference>>,
+
+    /// Abort the resolution if it takes longer than the given number of seconds.
+    ///
+    /// On timeout, uv reports the packages involved in the most conflicts, which are typically
+    /// the best candidates for additional constraints.
+    ///
+    /// By default, the resolution is not limited.
+    #[arg(long, env = EnvVars::UV_RESOLUTION_TIMEOUT, value_name = "SECONDS")]
+    pub resolution_timeout: Option<u64>,
+
+    /// Abort the resolution after the given number of backtracking steps.
+    ///
+    /// Each conflict between the versions selected for two packages causes the resolver to
+    /// backtrack. When the limit is exceeded, uv reports the packages involved in the most
+    /// conflicts, which are typically the best candidates for additional constraints.
+    ///
+    /// By default, the resolution is not limited.
+    #[arg(long, env = EnvVars::UV_MAX_BACKTRACKS)]
+    pub max_backtracks: Option<usize>,
+
+    /// Restrict the sources from which a package may be installed.
+    ///
+    /// Accepts a `<package>=<policy>` pair, where the policy is one of `binary` (only install
+    /// pre-built wheels), `git` (only install from a Git repository), or `index:<url>` (only
+    /// install from the given index, ignoring all other indexes and `--find-links` entries).
+    ///
+    /// If a requirement cannot be satisfied under its policy, resolution fails with an error
+    /// naming the violated policy.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE=POLICY")]
+    pub source_policy: Option<Vec<PackageSourcePolicy>>,
+
+    /// Declare that requirements on a package are satisfied by another package.
+    ///
+    /// Accepts a `<package>=<provider>` pair, e.g., `opencv-python=opencv-python-headless` or
+    /// `pil=pillow`. If the provider is required directly, any requirement on the package, whether
+    /// direct or transitive, is replaced by a requirement on its provider, such that the two are
+    /// never installed alongside each other.
+    ///
+    /// Since the packages are versioned independently, any version specifier on the replaced
+    /// requirement is ignored. Replaced requirements can't be requested from a URL.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE=PROVIDER")]
+    pub provided_by: Option<Vec<PackageProvider>>,
+
+    /// Only install distributions for which the index provides a hash.
+    ///
+    /// Wheels and source distributions that are listed on the index without a hash are treated
+    /// as unavailable, such that every installed distribution can be verified by hash.
+    #[arg(long, overrides_with("no_only_hashed"))]
+    pub only_hashed: bool,
+
+    #[arg(long, overrides_with("only_hashed"), hide = true)]
+    pub no_only_hashed: bool,
+
+    /// Fail if the total size of the distributions selected for installation exceeds the given
+    /// limit.
+    ///
+    /// Accepts a size with an optional unit (e.g., `500MB` or `1GiB`). The check is performed after
+    /// resolution, before any distributions are downloaded, using the file sizes reported by the
+    /// index (per PEP 700) or, if unavailable, `HEAD` requests.
+    ///
+    /// Packages that are already installed or cached are not counted.
+    #[arg(long, env = EnvVars::UV_MAX_TOTAL_DOWNLOAD_SIZE, value_name = "SIZE")]
+    pub max_total_download_size: Option<ByteSize>,
+
+    /// Fail if any distribution selected for installation exceeds the given size.
+    ///
+    /// Accepts a size with an optional unit (e.g., `100MB` or `1GiB`). The check is performed after
+    /// resolution, before any distributions are downloaded, using the file sizes reported by the
+    /// index (per PEP 700) or, if unavailable, `HEAD` requests.
+    #[arg(long, env = EnvVars::UV_MAX_PACKAGE_SIZE, value_name = "SIZE")]
+    pub max_package_size: Option<ByteSize>,
+
+    /// Reinstall any packages that were installed from a VCS repository, regardless of whether
+    /// they're already installed.
+    ///
+    /// The source of each installed package is determined from its `direct_url.json`. Useful for
+    /// picking up changes to a branch or tag without a version bump. Implies `--refresh-package`
+    /// for the affected packages.
+    #[arg(long, help_heading = "Installer options")]
+    pub reinstall_vcs: bool,
+
+    /// Reinstall any packages that were installed from a local directory or archive, regardless
+    /// of whether they're already installed.
+    ///
+    /// The source of each installed package is determined from its `direct_url.json`. Useful for
+    /// picking up changes to local sources without a version bump. Implies `--refresh-package` for
+    /// the affected packages.
+    #[arg(long, help_heading = "Installer options")]
+    pub reinstall_local: bool,
+
     /// Perform a dry run, i.e., don't actually install anything but resolve the dependencies and
     /// print the resulting plan.
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Capture the output of each source distribution build in a per-package log file within the
+    /// given directory.
+    ///
+    /// Each build writes its `stdout` and `stderr` to `<DIR>/<name>-<version>-<digest>.log` as it
+    /// runs, where the digest identifies the source being built. If a build fails, the error
+    /// includes only the tail of the build output, along with the path to the full log.
+    #[arg(long, env = EnvVars::UV_BUILD_LOG_DIR, value_name = "DIR")]
+    pub build_log_dir: Option<PathBuf>,
+
+    /// Optimize wheels built from source distributions before caching them.
+    ///
+    /// `recompress` rewrites each built wheel with the highest deflate compression level; `strip`
+    /// removes debug symbols from any bundled shared libraries (`.so` files) using the `strip`
+    /// executable on the `PATH`, updating the wheel's `RECORD` accordingly. Mach-O libraries are
+    /// not stripped, as doing so would invalidate their code signature. Both optimizations reduce
+    /// the size of the cache and of the installed environment, at the cost of additional time spent
+    /// after each build.
+    ///
+    /// Optimized wheels are cached separately from those built without optimizations.
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        env = EnvVars::UV_OPTIMIZE_WHEELS,
+        value_name = "OPTIMIZATION"
+    )]
+    pub optimize_wheels: Vec<WheelOptimization>,
+
+    /// Include the derivation tree in the error report when resolution fails.
+    ///
+    /// The tree shows each incompatibility that led to the failure on its own line, along with the
+    /// requirements and incompatibilities from which it was derived. Direct requirements are
+    /// annotated with the file and line that declared them (e.g., `requirements.in:2`).
+    #[arg(long)]
+    pub show_derivation_tree: bool,
+
+    /// Explain why each package was pinned to its selected version.
+    ///
+    /// After resolution, a report is written to stderr listing, for each package, the requirements
+    /// that requested it (including any constraints or overrides), along with the newer versions
+    /// (or, with `--resolution lowest`, the older versions) that were rejected and why (e.g.,
+    /// excluded by a specifier, yanked, a pre-release, no compatible wheels, or an incompatible
+    /// `Requires-Python`). Any dependency cycles between the resolved packages are listed at the
+    /// end.
+    #[arg(long)]
+    pub explain: bool,
+
+    /// Respect pip's environment variables for configuring indexes and builds.
+    ///
+    /// When enabled, `PIP_INDEX_URL`, `PIP_EXTRA_INDEX_URL`, `PIP_FIND_LINKS`, and `PIP_NO_BINARY`
+    /// are mapped onto the equivalent uv settings, allowing uv to be used in place of pip in
+    /// environments that configure pip exclusively via environment variables.
+    ///
+    /// Command-line arguments and uv's own environment variables (e.g., `UV_INDEX_URL`) take
+    /// precedence over pip's environment variables, which in turn take precedence over persistent
+    /// configuration.
+    #[arg(
+        long,
+        env = EnvVars::UV_PIP_ENV,
+        value_parser = clap::builder::BoolishValueParser::new(),
+    )]
+    pub pip_env: bool,
+
     #[command(flatten)]
     pub compat_args: compat::PipInstallCompatArgs,
 }
@@ -1824,6 +3046,19 @@ pub struct PipUninstallArgs {
     )]
     pub python: Option<Maybe<String>>,
 
+    /// The path to the Python environment from which packages should be uninstalled.
+    ///
+    /// Unlike `--python`, the environment is used as-is: uv will not search for an interpreter,
+    /// and the environment does not need to be activated. The path should point to the root of a
+    /// virtual environment (i.e., the directory containing `pyvenv.cfg`).
+    #[arg(
+        long,
+        conflicts_with_all = ["python", "system", "target", "prefix"],
+        help_heading = "Python options",
+        value_parser = parse_file_path,
+    )]
+    pub environment: Option<PathBuf>,
+
     /// Attempt to use `keyring` for authentication for remote requirements files.
     ///
     /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
@@ -1835,12 +3070,237 @@ pub struct PipUninstallArgs {
 
     /// Use the system Python to uninstall packages.
     ///
-    /// By default, uv uninstalls from the virtual environment in the current working directory or
-    /// any parent directory. The `--system` option instructs uv to instead use the first Python
-    /// found in the system `PATH`.
+    /// By default, uv uninstalls from the virtual environment in the current working directory or
+    /// any parent directory. The `--system` option instructs uv to instead use the first Python
+    /// found in the system `PATH`.
+    ///
+    /// WARNING: `--system` is intended for use in continuous integration (CI) environments and
+    /// should be used with caution, as it can modify the system Python installation.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    /// Allow uv to modify an `EXTERNALLY-MANAGED` Python installation.
+    ///
+    /// WARNING: `--break-system-packages` is intended for use in continuous integration (CI)
+    /// environments, when installing into Python installations that are managed by an external
+    /// package manager, like `apt`. It should be used with caution, as such Python installations
+    /// explicitly recommend against modifications by other package managers (like uv or `pip`).
+    #[arg(
+        long,
+        env = EnvVars::UV_BREAK_SYSTEM_PACKAGES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_break_system_packages")
+    )]
+    pub break_system_packages: bool,
+
+    #[arg(long, overrides_with("break_system_packages"))]
+    pub no_break_system_packages: bool,
+
+    /// Uninstall packages from the specified `--target` directory.
+    #[arg(long, conflicts_with = "prefix")]
+    pub target: Option<PathBuf>,
+
+    /// Uninstall packages from the specified `--prefix` directory.
+    #[arg(long, conflicts_with = "target")]
+    pub prefix: Option<PathBuf>,
+
+    /// Perform a dry run, i.e., don't actually uninstall anything but print the resulting plan.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    #[command(flatten)]
+    pub compat_args: compat::PipGlobalCompatArgs,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct PipFreezeArgs {
+    /// Exclude any editable packages from output.
+    #[arg(long)]
+    pub exclude_editable: bool,
+
+    /// Validate the Python environment, to detect packages with missing dependencies and other
+    /// issues.
+    #[arg(long, overrides_with("no_strict"))]
+    pub strict: bool,
+
+    #[arg(long, overrides_with("strict"), hide = true)]
+    pub no_strict: bool,
+
+    /// The Python interpreter for which packages should be listed.
+    ///
+    /// By default, uv lists packages in a virtual environment but will show packages in a system
+    /// Python environment if no virtual environment is found.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// The path to the Python environment for which packages should be listed.
+    ///
+    /// Unlike `--python`, the environment is used as-is: uv will not search for an interpreter,
+    /// and the environment does not need to be activated. The path should point to the root of a
+    /// virtual environment (i.e., the directory containing `pyvenv.cfg`).
+    #[arg(
+        long,
+        conflicts_with_all = ["python", "system"],
+        help_heading = "Python options",
+        value_parser = parse_file_path,
+    )]
+    pub environment: Option<PathBuf>,
+
+    /// Restrict to the specified installation path for listing packages (can be used multiple times).
+    #[arg(long("path"), value_parser = parse_file_path)]
+    pub paths: Option<Vec<PathBuf>>,
+
+    /// List packages in the system Python environment.
+    ///
+    /// Disables discovery of virtual environments.
+    ///
+    /// See `uv help python` for details on Python discovery.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    #[command(flatten)]
+    pub compat_args: compat::PipGlobalCompatArgs,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct PipListArgs {
+    /// Only include editable projects.
+    #[arg(short, long)]
+    pub editable: bool,
+
+    /// Exclude any editable packages from output.
+    #[arg(long, conflicts_with = "editable")]
+    pub exclude_editable: bool,
+
+    /// Exclude the specified package(s) from the output.
+    #[arg(long)]
+    pub r#exclude: Vec<PackageName>,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = ListFormat::default())]
+    pub format: ListFormat,
+
+    /// List outdated packages.
+    ///
+    /// The latest version of each package will be shown alongside the installed version. Up-to-date
+    /// packages will be omitted from the output.
+    #[arg(long, overrides_with("no_outdated"))]
+    pub outdated: bool,
+
+    #[arg(long, overrides_with("outdated"), hide = true)]
+    pub no_outdated: bool,
+
+    /// Validate the Python environment, to detect packages with missing dependencies and other
+    /// issues.
+    #[arg(long, overrides_with("no_strict"))]
+    pub strict: bool,
+
+    #[arg(long, overrides_with("strict"), hide = true)]
+    pub no_strict: bool,
+
+    #[command(flatten)]
+    pub fetch: FetchArgs,
+
+    /// The Python interpreter for which packages should be listed.
+    ///
+    /// By default, uv lists packages in a virtual environment but will show packages in a system
+    /// Python environment if no virtual environment is found.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// The path to the Python environment for which packages should be listed.
+    ///
+    /// Unlike `--python`, the environment is used as-is: uv will not search for an interpreter,
+    /// and the environment does not need to be activated. The path should point to the root of a
+    /// virtual environment (i.e., the directory containing `pyvenv.cfg`).
+    #[arg(
+        long,
+        conflicts_with_all = ["python", "system"],
+        help_heading = "Python options",
+        value_parser = parse_file_path,
+    )]
+    pub environment: Option<PathBuf>,
+
+    /// List packages in the system Python environment.
+    ///
+    /// Disables discovery of virtual environments.
+    ///
+    /// See `uv help python` for details on Python discovery.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    #[command(flatten)]
+    pub compat_args: compat::PipListCompatArgs,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct PipCheckArgs {
+    /// The Python interpreter for which packages should be checked.
+    ///
+    /// By default, uv checks packages in a virtual environment but will check packages in a system
+    /// Python environment if no virtual environment is found.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Check packages in the system Python environment.
+    ///
+    /// Disables discovery of virtual environments.
     ///
-    /// WARNING: `--system` is intended for use in continuous integration (CI) environments and
-    /// should be used with caution, as it can modify the system Python installation.
+    /// See `uv help python` for details on Python discovery.
     #[arg(
         long,
         env = EnvVars::UV_SYSTEM_PYTHON,
@@ -1851,59 +3311,42 @@ pub struct PipUninstallArgs {
 
     #[arg(long, overrides_with("system"), hide = true)]
     pub no_system: bool,
+}
 
-    /// Allow uv to modify an `EXTERNALLY-MANAGED` Python installation.
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct PipLintArgs {
+    /// Lint all packages listed in the given `requirements.txt` or `pyproject.toml` files.
     ///
-    /// WARNING: `--break-system-packages` is intended for use in continuous integration (CI)
-    /// environments, when installing into Python installations that are managed by an external
-    /// package manager, like `apt`. It should be used with caution, as such Python installations
-    /// explicitly recommend against modifications by other package managers (like uv or `pip`).
-    #[arg(
-        long,
-        env = EnvVars::UV_BREAK_SYSTEM_PACKAGES,
-        value_parser = clap::builder::BoolishValueParser::new(),
-        overrides_with("no_break_system_packages")
-    )]
-    pub break_system_packages: bool,
-
-    #[arg(long, overrides_with("break_system_packages"))]
-    pub no_break_system_packages: bool,
-
-    /// Uninstall packages from the specified `--target` directory.
-    #[arg(long, conflicts_with = "prefix")]
-    pub target: Option<PathBuf>,
+    /// For `pyproject.toml` files, the `project.dependencies` and
+    /// `project.optional-dependencies` tables are linted.
+    #[arg(required(true), value_parser = parse_file_path)]
+    pub src_file: Vec<PathBuf>,
 
-    /// Uninstall packages from the specified `--prefix` directory.
-    #[arg(long, conflicts_with = "target")]
-    pub prefix: Option<PathBuf>,
+    /// Lint the given constraints files alongside the requirements.
+    ///
+    /// The requirements are resolved along with the constraints; constraints that don't apply to
+    /// any package in the resolution, including transitive dependencies, are reported as unused.
+    #[arg(long, short, alias = "constraint", env = EnvVars::UV_CONSTRAINT, value_delimiter = ' ', value_parser = parse_maybe_file_path)]
+    pub constraints: Vec<Maybe<PathBuf>>,
 
-    /// Perform a dry run, i.e., don't actually uninstall anything but print the resulting plan.
+    /// Automatically fix mechanical issues, like exact duplicate requirements and requirements
+    /// with markers that can never be satisfied.
+    ///
+    /// Only `requirements.txt`-style files are rewritten; `pyproject.toml` files are left
+    /// untouched.
     #[arg(long)]
-    pub dry_run: bool,
+    pub fix: bool,
 
     #[command(flatten)]
-    pub compat_args: compat::PipGlobalCompatArgs,
-}
-
-#[derive(Args)]
-#[allow(clippy::struct_excessive_bools)]
-pub struct PipFreezeArgs {
-    /// Exclude any editable packages from output.
-    #[arg(long)]
-    pub exclude_editable: bool,
-
-    /// Validate the Python environment, to detect packages with missing dependencies and other
-    /// issues.
-    #[arg(long, overrides_with("no_strict"))]
-    pub strict: bool,
-
-    #[arg(long, overrides_with("strict"), hide = true)]
-    pub no_strict: bool,
+    pub fetch: FetchArgs,
 
-    /// The Python interpreter for which packages should be listed.
+    /// The Python interpreter to use when resolving the requirements to detect unused
+    /// constraints.
     ///
-    /// By default, uv lists packages in a virtual environment but will show packages in a system
-    /// Python environment if no virtual environment is found.
+    /// By default, uv uses the Python interpreter in a virtual environment, or a system Python
+    /// interpreter if no virtual environment is found. If no interpreter can be found, unused
+    /// constraints are not reported.
     ///
     /// See `uv help python` for details on Python discovery and supported request formats.
     #[arg(
@@ -1916,11 +3359,8 @@ pub struct PipFreezeArgs {
     )]
     pub python: Option<Maybe<String>>,
 
-    /// Restrict to the specified installation path for listing packages (can be used multiple times).
-    #[arg(long("path"), value_parser = parse_file_path)]
-    pub paths: Option<Vec<PathBuf>>,
-
-    /// List packages in the system Python environment.
+    /// Use the system Python interpreter when resolving the requirements to detect unused
+    /// constraints.
     ///
     /// Disables discovery of virtual environments.
     ///
@@ -1935,55 +3375,45 @@ pub struct PipFreezeArgs {
 
     #[arg(long, overrides_with("system"), hide = true)]
     pub no_system: bool,
-
-    #[command(flatten)]
-    pub compat_args: compat::PipGlobalCompatArgs,
 }
 
 #[derive(Args)]
-#[allow(clippy::struct_excessive_bools)]
-pub struct PipListArgs {
-    /// Only include editable projects.
-    #[arg(short, long)]
-    pub editable: bool,
-
-    /// Exclude any editable packages from output.
-    #[arg(long, conflicts_with = "editable")]
-    pub exclude_editable: bool,
-
-    /// Exclude the specified package(s) from the output.
-    #[arg(long)]
-    pub r#exclude: Vec<PackageName>,
-
-    /// Select the output format.
-    #[arg(long, value_enum, default_value_t = ListFormat::default())]
-    pub format: ListFormat,
-
-    /// List outdated packages.
+pub struct PipMergeArgs {
+    /// Merge all packages listed in the given `requirements.txt` or `pyproject.toml` files.
     ///
-    /// The latest version of each package will be shown alongside the installed version. Up-to-date
-    /// packages will be omitted from the output.
-    #[arg(long, overrides_with("no_outdated"))]
-    pub outdated: bool,
+    /// For `pyproject.toml` files, the `project.dependencies` and
+    /// `project.optional-dependencies` tables are merged.
+    ///
+    /// Only requirements are merged; options like `--index-url` and `-c` are not carried over to
+    /// the output.
+    #[arg(required(true), value_parser = parse_file_path)]
+    pub src_file: Vec<PathBuf>,
 
-    #[arg(long, overrides_with("outdated"), hide = true)]
-    pub no_outdated: bool,
+    /// Write the merged requirements to the given `requirements.txt` file.
+    ///
+    /// If not provided, the merged requirements are written to stdout.
+    #[arg(long, short)]
+    pub output_file: Option<PathBuf>,
+}
 
-    /// Validate the Python environment, to detect packages with missing dependencies and other
-    /// issues.
-    #[arg(long, overrides_with("no_strict"))]
-    pub strict: bool,
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct PipRefreshEditablesArgs {
+    /// Refresh only the given editable packages.
+    ///
+    /// By default, all editable packages in the environment are refreshed.
+    pub package: Vec<PackageName>,
 
-    #[arg(long, overrides_with("strict"), hide = true)]
-    pub no_strict: bool,
+    #[command(flatten)]
+    pub installer: InstallerArgs,
 
     #[command(flatten)]
-    pub fetch: FetchArgs,
+    pub refresh: RefreshArgs,
 
-    /// The Python interpreter for which packages should be listed.
+    /// The Python interpreter in which editable packages should be refreshed.
     ///
-    /// By default, uv lists packages in a virtual environment but will show packages in a system
-    /// Python environment if no virtual environment is found.
+    /// By default, uv refreshes editable packages in a virtual environment but it will also
+    /// refresh them in a system Python environment if no virtual environment is found.
     ///
     /// See `uv help python` for details on Python discovery and supported request formats.
     #[arg(
@@ -1996,11 +3426,23 @@ pub struct PipListArgs {
     )]
     pub python: Option<Maybe<String>>,
 
-    /// List packages in the system Python environment.
+    /// The path to the Python environment in which editable packages should be refreshed.
     ///
-    /// Disables discovery of virtual environments.
+    /// Unlike `--python`, the environment is used as-is: uv will not search for an interpreter,
+    /// and the environment does not need to be activated. The path should point to the root of a
+    /// virtual environment (i.e., the directory containing `pyvenv.cfg`).
+    #[arg(
+        long,
+        conflicts_with_all = ["python", "system"],
+        help_heading = "Python options",
+        value_parser = parse_file_path,
+    )]
+    pub environment: Option<PathBuf>,
+
+    /// Refresh editable packages in the system Python environment.
     ///
-    /// See `uv help python` for details on Python discovery.
+    /// WARNING: `--system` is intended for use in continuous integration (CI) environments and
+    /// should be used with caution, as it can modify the system Python installation.
     #[arg(
         long,
         env = EnvVars::UV_SYSTEM_PYTHON,
@@ -2012,17 +3454,58 @@ pub struct PipListArgs {
     #[arg(long, overrides_with("system"), hide = true)]
     pub no_system: bool,
 
-    #[command(flatten)]
-    pub compat_args: compat::PipListCompatArgs,
+    /// Allow uv to modify an `EXTERNALLY-MANAGED` Python installation.
+    ///
+    /// WARNING: `--break-system-packages` is intended for use in continuous integration (CI)
+    /// environments, when installing into Python installations that are managed by an external
+    /// package manager, like `apt`. It should be used with caution, as such Python installations
+    /// explicitly recommend against modifications by other package managers (like uv or `pip`).
+    #[arg(
+        long,
+        env = EnvVars::UV_BREAK_SYSTEM_PACKAGES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_break_system_packages")
+    )]
+    pub break_system_packages: bool,
+
+    #[arg(long, overrides_with("break_system_packages"))]
+    pub no_break_system_packages: bool,
+
+    /// Perform a dry run, i.e., don't actually refresh anything but print the resulting plan.
+    #[arg(long)]
+    pub dry_run: bool,
 }
 
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
-pub struct PipCheckArgs {
-    /// The Python interpreter for which packages should be checked.
+pub struct PipVerifyArgs {
+    /// Verify only the given packages.
     ///
-    /// By default, uv checks packages in a virtual environment but will check packages in a system
-    /// Python environment if no virtual environment is found.
+    /// By default, all packages in the environment are verified.
+    pub package: Vec<PackageName>,
+
+    /// Reinstall any package with missing or modified files.
+    ///
+    /// Packages are reinstalled at their installed versions, from the cache where possible.
+    /// Unrecorded files are reported, but left in place unless `--remove-extra` is provided.
+    /// Editable packages can't be repaired; use `uv pip refresh-editables` instead.
+    #[arg(long)]
+    pub repair: bool,
+
+    /// Remove any unrecorded files from the verified packages' directories.
+    #[arg(long, requires = "repair")]
+    pub remove_extra: bool,
+
+    #[command(flatten)]
+    pub installer: InstallerArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter for which packages should be verified.
+    ///
+    /// By default, uv verifies packages in a virtual environment but will verify packages in a
+    /// system Python environment if no virtual environment is found.
     ///
     /// See `uv help python` for details on Python discovery and supported request formats.
     #[arg(
@@ -2035,7 +3518,20 @@ pub struct PipCheckArgs {
     )]
     pub python: Option<Maybe<String>>,
 
-    /// Check packages in the system Python environment.
+    /// The path to the Python environment in which packages should be verified.
+    ///
+    /// Unlike `--python`, the environment is used as-is: uv will not search for an interpreter,
+    /// and the environment does not need to be activated. The path should point to the root of a
+    /// virtual environment (i.e., the directory containing `pyvenv.cfg`).
+    #[arg(
+        long,
+        conflicts_with_all = ["python", "system"],
+        help_heading = "Python options",
+        value_parser = parse_file_path,
+    )]
+    pub environment: Option<PathBuf>,
+
+    /// Verify packages in the system Python environment.
     ///
     /// Disables discovery of virtual environments.
     ///
@@ -2050,6 +3546,23 @@ pub struct PipCheckArgs {
 
     #[arg(long, overrides_with("system"), hide = true)]
     pub no_system: bool,
+
+    /// Allow uv to modify an `EXTERNALLY-MANAGED` Python installation when repairing packages.
+    ///
+    /// WARNING: `--break-system-packages` is intended for use in continuous integration (CI)
+    /// environments, when installing into Python installations that are managed by an external
+    /// package manager, like `apt`. It should be used with caution, as such Python installations
+    /// explicitly recommend against modifications by other package managers (like uv or `pip`).
+    #[arg(
+        long,
+        env = EnvVars::UV_BREAK_SYSTEM_PACKAGES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_break_system_packages")
+    )]
+    pub break_system_packages: bool,
+
+    #[arg(long, overrides_with("break_system_packages"))]
+    pub no_break_system_packages: bool,
 }
 
 #[derive(Args)]
@@ -2791,6 +4304,14 @@ pub struct RunArgs {
     #[arg(long, value_parser = clap::builder::BoolishValueParser::new(), env = EnvVars::UV_NO_ENV_FILE)]
     pub no_env_file: bool,
 
+    /// Add the `__pypackages__/<major>.<minor>/lib` directory within the project directory (i.e.,
+    /// the current working directory, or the directory passed to `--project`) to `sys.path`, and
+    /// its `bin` directory to the `PATH`.
+    ///
+    /// Packages can be installed into the directory with `uv pip install --pypackages`.
+    #[arg(long)]
+    pub pypackages: bool,
+
     /// The command to run.
     ///
     /// If the path to a Python script (i.e., ending in `.py`), it will be
@@ -3224,6 +4745,26 @@ pub struct LockArgs {
     #[arg(long, conflicts_with = "check_exists", conflicts_with = "check")]
     pub dry_run: bool,
 
+    /// Verify that the resolution is deterministic.
+    ///
+    /// Resolves the project a second time, with the inputs presented in a different order, and
+    /// verifies that both resolutions produce an identical lockfile before writing it. Both
+    /// resolutions respect the existing lockfile. If the lockfiles diverge, uv will report the
+    /// differing lines and exit with an error, leaving the existing lockfile untouched.
+    ///
+    /// Divergence usually stems from iteration over unordered collections or from the order in
+    /// which packages are merged across indexes.
+    #[arg(long, conflicts_with = "check_exists")]
+    pub verify_determinism: bool,
+
+    /// The seed with which to shuffle the inputs when verifying that the resolution is
+    /// deterministic.
+    ///
+    /// By default, a random seed is used, and reported if the resolutions diverge, such that a
+    /// failing ordering can be reproduced.
+    #[arg(long, requires = "verify_determinism")]
+    pub seed: Option<u64>,
+
     /// Lock the specified Python script, rather than the current project.
     ///
     /// If provided, uv will lock the script (based on its inline metadata table, in adherence with
@@ -3313,6 +4854,14 @@ pub struct AddArgs {
     )]
     pub raw_sources: bool,
 
+    /// Normalize and simplify the version specifiers written to the `pyproject.toml`.
+    ///
+    /// Trailing zeros are removed from each version (e.g., `==1.0.0.0` is written as `==1`), and
+    /// redundant clauses are dropped (e.g., `>=1.0,>=1.2,<2` is written as `>=1.2,<2`), such that
+    /// the requirements are written in a canonical form.
+    #[arg(long)]
+    pub normalize_specifiers: bool,
+
     /// Commit to use when adding a dependency from Git.
     #[arg(long, group = "git-ref", action = clap::ArgAction::Set)]
     pub rev: Option<String>,
@@ -4268,6 +5817,19 @@ pub struct ToolUpgradeArgs {
     )]
     pub config_setting: Option<Vec<ConfigSettingEntry>>,
 
+    /// Settings to pass to the PEP 517 build backend for a specific package, specified as
+    /// `PACKAGE:KEY=VALUE` pairs.
+    ///
+    /// Unlike `--config-setting`, the setting is only passed to the build backend when building
+    /// the named package. Settings provided for a package take precedence over any global settings
+    /// with the same key.
+    #[arg(
+        long,
+        alias = "config-settings-package",
+        help_heading = "Build options"
+    )]
+    pub config_setting_package: Option<Vec<ConfigSettingPackageEntry>>,
+
     /// Disable isolation when building source distributions.
     ///
     /// Assumes that build dependencies specified by PEP 518 are already installed.
@@ -4401,6 +5963,17 @@ pub enum PythonCommand {
     /// See `uv help python` to view supported request formats and details on discovery behavior.
     Find(PythonFindArgs),
 
+    /// Show information about a Python installation.
+    ///
+    /// Displays the marker environment, compatible wheel tags, installation scheme, and other
+    /// information uv queries from the Python interpreter, as JSON. This is the same information
+    /// uv uses when resolving and installing packages into an environment.
+    ///
+    /// The Python installation is discovered as in `uv python find`.
+    ///
+    /// See `uv help python` to view supported request formats and details on discovery behavior.
+    Inspect(PythonFindArgs),
+
     /// Pin to a specific Python version.
     ///
     /// Writes the pinned Python version to a `.python-version` file, which is used by other uv
@@ -4758,6 +6331,11 @@ pub struct IndexArgs {
 
     /// Ignore the registry index (e.g., PyPI), instead relying on direct URL dependencies and those
     /// provided via `--find-links`.
+    ///
+    /// Before resolving, uv verifies that each direct requirement is satisfied by a `--find-links`
+    /// source or an already-installed package, and reports any that are missing. When installing
+    /// with `--offline`, uv additionally verifies that each resolved distribution is either cached
+    /// or available in a local `--find-links` directory before fetching any.
     #[arg(long, help_heading = "Index options")]
     pub no_index: bool,
 }
@@ -4786,6 +6364,14 @@ pub struct RefreshArgs {
     /// Refresh cached data for a specific package.
     #[arg(long, help_heading = "Cache options")]
     pub refresh_package: Vec<PackageName>,
+
+    /// Rebuild a specific package from source, ignoring any previously cached builds.
+    ///
+    /// Cached wheels that were built for the package before the current invocation are discarded,
+    /// such that the package is rebuilt at most once. To install the rebuilt wheel into an existing
+    /// environment, combine with `--reinstall-package`.
+    #[arg(long, help_heading = "Cache options")]
+    pub rebuild_package: Vec<PackageName>,
 }
 
 #[derive(Args)]
@@ -4904,6 +6490,19 @@ pub struct InstallerArgs {
     )]
     pub config_setting: Option<Vec<ConfigSettingEntry>>,
 
+    /// Settings to pass to the PEP 517 build backend for a specific package, specified as
+    /// `PACKAGE:KEY=VALUE` pairs.
+    ///
+    /// Unlike `--config-setting`, the setting is only passed to the build backend when building
+    /// the named package. Settings provided for a package take precedence over any global settings
+    /// with the same key.
+    #[arg(
+        long,
+        alias = "config-settings-package",
+        help_heading = "Build options"
+    )]
+    pub config_setting_package: Option<Vec<ConfigSettingPackageEntry>>,
+
     /// Disable isolation when building source distributions.
     ///
     /// Assumes that build dependencies specified by PEP 518 are already installed.
@@ -5049,6 +6648,16 @@ pub struct ResolverArgs {
     )]
     pub resolution: Option<ResolutionMode>,
 
+    /// The strategy to use when selecting between the different compatible versions of a specific
+    /// package.
+    ///
+    /// Accepts package-strategy pairs in the format `PACKAGE=MODE` (e.g., `numpy=lowest`).
+    ///
+    /// Takes precedence over `--resolution` for the given package. Can be provided multiple times
+    /// for different packages.
+    #[arg(long, help_heading = "Resolver options")]
+    pub resolution_package: Option<Vec<ResolutionPackageEntry>>,
+
     /// The strategy to use when considering pre-release versions.
     ///
     /// By default, uv will accept pre-releases for packages that _only_ publish pre-releases, along
@@ -5065,6 +6674,12 @@ pub struct ResolverArgs {
     #[arg(long, hide = true, help_heading = "Resolver options")]
     pub pre: bool,
 
+    /// Allow pre-release versions for a specific package, regardless of `--prerelease`.
+    ///
+    /// Can be provided multiple times for different packages.
+    #[arg(long, help_heading = "Resolver options")]
+    pub prerelease_package: Vec<PackageName>,
+
     /// The strategy to use when selecting multiple versions of a given package across Python
     /// versions and platforms.
     ///
@@ -5092,6 +6707,19 @@ pub struct ResolverArgs {
     )]
     pub config_setting: Option<Vec<ConfigSettingEntry>>,
 
+    /// Settings to pass to the PEP 517 build backend for a specific package, specified as
+    /// `PACKAGE:KEY=VALUE` pairs.
+    ///
+    /// Unlike `--config-setting`, the setting is only passed to the build backend when building
+    /// the named package. Settings provided for a package take precedence over any global settings
+    /// with the same key.
+    #[arg(
+        long,
+        alias = "config-settings-package",
+        help_heading = "Build options"
+    )]
+    pub config_setting_package: Option<Vec<ConfigSettingPackageEntry>>,
+
     /// Disable isolation when building source distributions.
     ///
     /// Assumes that build dependencies specified by PEP 518 are already installed.
@@ -5125,6 +6753,18 @@ pub struct ResolverArgs {
     #[arg(long, env = EnvVars::UV_EXCLUDE_NEWER, help_heading = "Resolver options")]
     pub exclude_newer: Option<ExcludeNewer>,
 
+    /// Limit candidate packages for a specific package to those that were uploaded prior to the
+    /// given date.
+    ///
+    /// Accepts package-date pairs in the format `PACKAGE=DATE`, where `DATE` is an RFC 3339
+    /// timestamp (e.g., `2006-12-02T02:07:43Z`) or local date (e.g., `2006-12-02`) in your
+    /// system's configured time zone.
+    ///
+    /// Takes precedence over `--exclude-newer` for the given package. Can be provided multiple
+    /// times for different packages.
+    #[arg(long, help_heading = "Resolver options")]
+    pub exclude_newer_package: Option<Vec<ExcludeNewerPackageEntry>>,
+
     /// The method to use when installing packages from the global cache.
     ///
     /// This option is only used when building source distributions.
@@ -5239,6 +6879,16 @@ pub struct ResolverInstallerArgs {
     )]
     pub resolution: Option<ResolutionMode>,
 
+    /// The strategy to use when selecting between the different compatible versions of a specific
+    /// package.
+    ///
+    /// Accepts package-strategy pairs in the format `PACKAGE=MODE` (e.g., `numpy=lowest`).
+    ///
+    /// Takes precedence over `--resolution` for the given package. Can be provided multiple times
+    /// for different packages.
+    #[arg(long, help_heading = "Resolver options")]
+    pub resolution_package: Option<Vec<ResolutionPackageEntry>>,
+
     /// The strategy to use when considering pre-release versions.
     ///
     /// By default, uv will accept pre-releases for packages that _only_ publish pre-releases, along
@@ -5255,6 +6905,12 @@ pub struct ResolverInstallerArgs {
     #[arg(long, hide = true)]
     pub pre: bool,
 
+    /// Allow pre-release versions for a specific package, regardless of `--prerelease`.
+    ///
+    /// Can be provided multiple times for different packages.
+    #[arg(long, help_heading = "Resolver options")]
+    pub prerelease_package: Vec<PackageName>,
+
     /// The strategy to use when selecting multiple versions of a given package across Python
     /// versions and platforms.
     ///
@@ -5282,6 +6938,19 @@ pub struct ResolverInstallerArgs {
     )]
     pub config_setting: Option<Vec<ConfigSettingEntry>>,
 
+    /// Settings to pass to the PEP 517 build backend for a specific package, specified as
+    /// `PACKAGE:KEY=VALUE` pairs.
+    ///
+    /// Unlike `--config-setting`, the setting is only passed to the build backend when building
+    /// the named package. Settings provided for a package take precedence over any global settings
+    /// with the same key.
+    #[arg(
+        long,
+        alias = "config-settings-package",
+        help_heading = "Build options"
+    )]
+    pub config_setting_package: Option<Vec<ConfigSettingPackageEntry>>,
+
     /// Disable isolation when building source distributions.
     ///
     /// Assumes that build dependencies specified by PEP 518 are already installed.
@@ -5315,6 +6984,18 @@ pub struct ResolverInstallerArgs {
     #[arg(long, env = EnvVars::UV_EXCLUDE_NEWER, help_heading = "Resolver options")]
     pub exclude_newer: Option<ExcludeNewer>,
 
+    /// Limit candidate packages for a specific package to those that were uploaded prior to the
+    /// given date.
+    ///
+    /// Accepts package-date pairs in the format `PACKAGE=DATE`, where `DATE` is an RFC 3339
+    /// timestamp (e.g., `2006-12-02T02:07:43Z`) or local date (e.g., `2006-12-02`) in your
+    /// system's configured time zone.
+    ///
+    /// Takes precedence over `--exclude-newer` for the given package. Can be provided multiple
+    /// times for different packages.
+    #[arg(long, help_heading = "Resolver options")]
+    pub exclude_newer_package: Option<Vec<ExcludeNewerPackageEntry>>,
+
     /// The method to use when installing packages from the global cache.
     ///
     /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
@@ -1,6 +1,6 @@
 use uv_cache::Refresh;
-use uv_configuration::ConfigSettings;
-use uv_resolver::PrereleaseMode;
+use uv_configuration::{ConfigSettings, PackageConfigSettings};
+use uv_resolver::{ExcludeNewerPackage, PrereleaseMode, ResolutionPackage};
 use uv_settings::{Combine, PipOptions, ResolverInstallerOptions, ResolverOptions};
 
 use crate::{
@@ -24,6 +24,7 @@ impl From<RefreshArgs> for Refresh {
             refresh,
             no_refresh,
             refresh_package,
+            rebuild_package: _,
         } = value;
 
         Self::from_args(flag(refresh, no_refresh), refresh_package)
@@ -40,14 +41,18 @@ impl From<ResolverArgs> for PipOptions {
             index_strategy,
             keyring_provider,
             resolution,
+            resolution_package,
             prerelease,
             pre,
+            prerelease_package,
             fork_strategy,
             config_setting,
+            config_setting_package,
             no_build_isolation,
             no_build_isolation_package,
             build_isolation,
             exclude_newer,
+            exclude_newer_package,
             link_mode,
             no_sources,
         } = args;
@@ -58,17 +63,28 @@ impl From<ResolverArgs> for PipOptions {
             index_strategy,
             keyring_provider,
             resolution,
+            resolution_package: resolution_package
+                .map(|entries| entries.into_iter().collect::<ResolutionPackage>()),
             fork_strategy,
             prerelease: if pre {
                 Some(PrereleaseMode::Allow)
             } else {
                 prerelease
             },
+            prerelease_package: Some(prerelease_package),
             config_settings: config_setting
                 .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
+            config_settings_package: config_setting_package.map(|config_settings| {
+                config_settings
+                    .into_iter()
+                    .collect::<PackageConfigSettings>()
+            }),
+            build_env: None,
             no_build_isolation: flag(no_build_isolation, build_isolation),
             no_build_isolation_package: Some(no_build_isolation_package),
             exclude_newer,
+            exclude_newer_package: exclude_newer_package
+                .map(|entries| entries.into_iter().collect::<ExcludeNewerPackage>()),
             link_mode,
             no_sources: if no_sources { Some(true) } else { None },
             ..PipOptions::from(index_args)
@@ -86,6 +102,7 @@ impl From<InstallerArgs> for PipOptions {
             index_strategy,
             keyring_provider,
             config_setting,
+            config_setting_package,
             no_build_isolation,
             build_isolation,
             exclude_newer,
@@ -102,6 +119,12 @@ impl From<InstallerArgs> for PipOptions {
             keyring_provider,
             config_settings: config_setting
                 .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
+            config_settings_package: config_setting_package.map(|config_settings| {
+                config_settings
+                    .into_iter()
+                    .collect::<PackageConfigSettings>()
+            }),
+            build_env: None,
             no_build_isolation: flag(no_build_isolation, build_isolation),
             exclude_newer,
             link_mode,
@@ -125,14 +148,18 @@ impl From<ResolverInstallerArgs> for PipOptions {
             index_strategy,
             keyring_provider,
             resolution,
+            resolution_package,
             prerelease,
             pre,
+            prerelease_package,
             fork_strategy,
             config_setting,
+            config_setting_package,
             no_build_isolation,
             no_build_isolation_package,
             build_isolation,
             exclude_newer,
+            exclude_newer_package,
             link_mode,
             compile_bytecode,
             no_compile_bytecode,
@@ -147,17 +174,28 @@ impl From<ResolverInstallerArgs> for PipOptions {
             index_strategy,
             keyring_provider,
             resolution,
+            resolution_package: resolution_package
+                .map(|entries| entries.into_iter().collect::<ResolutionPackage>()),
             prerelease: if pre {
                 Some(PrereleaseMode::Allow)
             } else {
                 prerelease
             },
+            prerelease_package: Some(prerelease_package),
             fork_strategy,
             config_settings: config_setting
                 .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
+            config_settings_package: config_setting_package.map(|config_settings| {
+                config_settings
+                    .into_iter()
+                    .collect::<PackageConfigSettings>()
+            }),
+            build_env: None,
             no_build_isolation: flag(no_build_isolation, build_isolation),
             no_build_isolation_package: Some(no_build_isolation_package),
             exclude_newer,
+            exclude_newer_package: exclude_newer_package
+                .map(|entries| entries.into_iter().collect::<ExcludeNewerPackage>()),
             link_mode,
             compile_bytecode: flag(compile_bytecode, no_compile_bytecode),
             no_sources: if no_sources { Some(true) } else { None },
@@ -234,14 +272,18 @@ pub fn resolver_options(
         index_strategy,
         keyring_provider,
         resolution,
+        resolution_package,
         prerelease,
         pre,
+        prerelease_package,
         fork_strategy,
         config_setting,
+        config_setting_package,
         no_build_isolation,
         no_build_isolation_package,
         build_isolation,
         exclude_newer,
+        exclude_newer_package,
         link_mode,
         no_sources,
     } = resolver_args;
@@ -288,18 +330,29 @@ pub fn resolver_options(
         index_strategy,
         keyring_provider,
         resolution,
+        resolution_package: resolution_package
+            .map(|entries| entries.into_iter().collect::<ResolutionPackage>()),
         prerelease: if pre {
             Some(PrereleaseMode::Allow)
         } else {
             prerelease
         },
+        prerelease_package: Some(prerelease_package),
         fork_strategy,
         dependency_metadata: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
+        config_settings_package: config_setting_package.map(|config_settings| {
+            config_settings
+                .into_iter()
+                .collect::<PackageConfigSettings>()
+        }),
+        build_env: None,
         no_build_isolation: flag(no_build_isolation, build_isolation),
         no_build_isolation_package: Some(no_build_isolation_package),
         exclude_newer,
+        exclude_newer_package: exclude_newer_package
+            .map(|entries| entries.into_iter().collect::<ExcludeNewerPackage>()),
         link_mode,
         no_build: flag(no_build, build),
         no_build_package: Some(no_build_package),
@@ -325,14 +378,18 @@ pub fn resolver_installer_options(
         index_strategy,
         keyring_provider,
         resolution,
+        resolution_package,
         prerelease,
         pre,
+        prerelease_package,
         fork_strategy,
         config_setting,
+        config_setting_package,
         no_build_isolation,
         no_build_isolation_package,
         build_isolation,
         exclude_newer,
+        exclude_newer_package,
         link_mode,
         compile_bytecode,
         no_compile_bytecode,
@@ -391,15 +448,24 @@ pub fn resolver_installer_options(
         index_strategy,
         keyring_provider,
         resolution,
+        resolution_package: resolution_package
+            .map(|entries| entries.into_iter().collect::<ResolutionPackage>()),
         prerelease: if pre {
             Some(PrereleaseMode::Allow)
         } else {
             prerelease
         },
+        prerelease_package: Some(prerelease_package),
         fork_strategy,
         dependency_metadata: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
+        config_settings_package: config_setting_package.map(|config_settings| {
+            config_settings
+                .into_iter()
+                .collect::<PackageConfigSettings>()
+        }),
+        build_env: None,
         no_build_isolation: flag(no_build_isolation, build_isolation),
         no_build_isolation_package: if no_build_isolation_package.is_empty() {
             None
@@ -407,6 +473,8 @@ pub fn resolver_installer_options(
             Some(no_build_isolation_package)
         },
         exclude_newer,
+        exclude_newer_package: exclude_newer_package
+            .map(|entries| entries.into_iter().collect::<ExcludeNewerPackage>()),
         link_mode,
         compile_bytecode: flag(compile_bytecode, no_compile_bytecode),
         no_build: flag(no_build, build),
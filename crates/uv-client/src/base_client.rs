@@ -23,9 +23,10 @@ use uv_version::version;
 use uv_warnings::warn_user_once;
 
 use crate::linehaul::LineHaul;
-use crate::middleware::OfflineMiddleware;
+use crate::middleware::{DownloadLogMiddleware, OfflineMiddleware, RateLimitMiddleware};
 use crate::tls::read_identity;
 use crate::Connectivity;
+use crate::{download_log, stats};
 
 pub const DEFAULT_RETRIES: u32 = 3;
 
@@ -311,6 +312,11 @@ impl<'a> BaseClientBuilder<'a> {
             Connectivity::Online => {
                 let mut client = reqwest_middleware::ClientBuilder::new(client);
 
+                // Record the final response body of each request, after any retries.
+                if download_log::is_enabled() || stats::is_enabled() {
+                    client = client.with(DownloadLogMiddleware);
+                }
+
                 // Avoid uncloneable errors with a streaming body during publish.
                 if self.retries > 0 {
                     // Initialize the retry strategy.
@@ -339,6 +345,9 @@ impl<'a> BaseClientBuilder<'a> {
                     }
                 }
 
+                // Pace each request (including any retries) to respect rate-limited hosts.
+                client = client.with(RateLimitMiddleware);
+
                 // When supplied add the extra middleware
                 if let Some(extra_middleware) = &self.extra_middleware {
                     for middleware in &extra_middleware.0 {
@@ -18,7 +18,7 @@ use crate::BaseClient;
 use crate::{
     httpcache::{AfterResponse, BeforeRequest, CachePolicy, CachePolicyBuilder},
     rkyvutil::OwnedArchive,
-    Error, ErrorKind,
+    stats, Error, ErrorKind,
 };
 
 /// A trait the generalizes (de)serialization at a high level.
@@ -165,6 +165,9 @@ pub enum CacheControl {
     MustRevalidate,
     /// Allow the client to return stale responses.
     AllowStale,
+    /// Consider the cached response fresh for the given duration, ignoring the `cache-control`
+    /// header from the response, and revalidate it thereafter.
+    Override(Duration),
 }
 
 impl From<Freshness> for CacheControl {
@@ -280,6 +283,12 @@ impl CachedClient {
                 cache_policy,
             }
         };
+        match &cached_response {
+            CachedResponse::FreshCache(_) | CachedResponse::NotModified { .. } => {
+                stats::record_cache_hit();
+            }
+            CachedResponse::ModifiedOrNew { .. } => stats::record_cache_miss(),
+        }
         match cached_response {
             CachedResponse::FreshCache(cached) => match Payload::from_aligned_bytes(cached.data) {
                 Ok(payload) => Ok(payload),
@@ -356,6 +365,7 @@ impl CachedClient {
         Callback: FnOnce(Response) -> CallbackReturn,
         CallbackReturn: Future<Output = Result<Payload, CallBackError>>,
     {
+        stats::record_cache_miss();
         let (response, cache_policy) = self.fresh_request(req).await?;
 
         let payload = self
@@ -462,6 +472,19 @@ impl CachedClient {
         // Apply the cache control header, if necessary.
         match cache_control {
             CacheControl::None | CacheControl::AllowStale => {}
+            CacheControl::Override(ttl) => {
+                if cached.cache_policy.is_fresh_within(&req, ttl) {
+                    debug!(
+                        "Found fresh response (per configured TTL) for: {}",
+                        req.url()
+                    );
+                    return Ok(CachedResponse::FreshCache(cached));
+                }
+                req.headers_mut().insert(
+                    http::header::CACHE_CONTROL,
+                    http::HeaderValue::from_static("no-cache"),
+                );
+            }
             CacheControl::MustRevalidate => {
                 req.headers_mut().insert(
                     http::header::CACHE_CONTROL,
@@ -475,7 +498,7 @@ impl CachedClient {
                 CachedResponse::FreshCache(cached)
             }
             BeforeRequest::Stale(new_cache_policy_builder) => match cache_control {
-                CacheControl::None | CacheControl::MustRevalidate => {
+                CacheControl::None | CacheControl::MustRevalidate | CacheControl::Override(_) => {
                     debug!("Found stale response for: {}", req.url());
                     self.send_cached_handle_stale(req, cached, new_cache_policy_builder)
                         .await?
@@ -0,0 +1,71 @@
+use std::fmt::{Display, Formatter};
+use std::sync::{Mutex, OnceLock};
+
+use url::Url;
+
+use uv_pypi_types::redact_credentials;
+
+use crate::stats;
+
+/// The log of every response body fetched during the current invocation, if enabled.
+static DOWNLOAD_LOG: OnceLock<Mutex<Vec<Download>>> = OnceLock::new();
+
+/// A response body fetched from a remote server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Download {
+    /// The URL that was fetched, with any credentials redacted.
+    pub url: Url,
+    /// The number of bytes received.
+    pub size: u64,
+    /// The hex-encoded SHA-256 digest of the bytes received.
+    pub sha256: String,
+    /// Whether the response body was read to completion. If not (e.g., the body was abandoned
+    /// after a partial read), the size and digest only reflect the bytes that were received.
+    pub complete: bool,
+}
+
+impl Display for Download {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} sha256:{}", self.url, self.size, self.sha256)?;
+        if !self.complete {
+            write!(f, " (incomplete)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Enable the download log, recording every response body fetched by a client from this point
+/// onwards.
+pub fn enable_download_log() {
+    DOWNLOAD_LOG.get_or_init(Mutex::default);
+}
+
+/// Return the downloads recorded so far, in the order in which they completed.
+///
+/// Returns an empty list if the download log is not enabled.
+pub fn downloads() -> Vec<Download> {
+    DOWNLOAD_LOG
+        .get()
+        .map(|log| log.lock().unwrap().clone())
+        .unwrap_or_default()
+}
+
+/// Returns `true` if the download log is enabled.
+pub(crate) fn is_enabled() -> bool {
+    DOWNLOAD_LOG.get().is_some()
+}
+
+/// Record a download, if the download log is enabled.
+pub(crate) fn record(mut url: Url, size: u64, sha256: String, complete: bool) {
+    stats::record_download(size);
+    let Some(log) = DOWNLOAD_LOG.get() else {
+        return;
+    };
+    redact_credentials(&mut url);
+    log.lock().unwrap().push(Download {
+        url,
+        size,
+        sha256,
+        complete,
+    });
+}
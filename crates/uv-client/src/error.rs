@@ -203,6 +203,14 @@ pub enum ErrorKind {
     #[error("Failed to read zip with range requests: `{0}`")]
     AsyncHttpRangeReader(Url, #[source] AsyncHttpRangeReaderError),
 
+    #[error("Invalid file `{filename}` for package `{package}` on {index}: {reason}")]
+    InvalidFile {
+        package: String,
+        filename: String,
+        index: String,
+        reason: String,
+    },
+
     #[error("{0} is not a valid wheel filename")]
     WheelFilename(#[source] WheelFilenameError),
 
@@ -312,6 +320,23 @@ impl WrappedReqwestError {
         false
     }
 
+    /// Returns `true` if the server couldn't be reached, or failed to respond successfully (e.g.,
+    /// due to a timeout, a connection failure, or a `5xx` status code).
+    pub(crate) fn is_unavailable(&self) -> bool {
+        if let Some(reqwest_err) = self.inner() {
+            if reqwest_err.is_timeout() || reqwest_err.is_connect() {
+                return true;
+            }
+            if reqwest_err
+                .status()
+                .is_some_and(|status| status.is_server_error())
+            {
+                return true;
+            }
+        }
+        false
+    }
+
     /// Check if the error chain contains a `reqwest` error that looks like this:
     /// * invalid peer certificate: `UnknownIssuer`
     fn is_ssl(&self) -> bool {
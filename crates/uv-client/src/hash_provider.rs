@@ -0,0 +1,120 @@
+use std::future::Future;
+use std::str::FromStr;
+
+use reqwest::StatusCode;
+use tracing::debug;
+use url::Url;
+
+use uv_distribution_types::IndexHashProvider;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pypi_types::{HashDigest, HashDigests, HashError};
+
+use crate::{Error, ErrorKind, RegistryClient};
+
+/// A source of hashes for the distributions of a pinned package version, used in lieu of the
+/// digests reported by the index (e.g., an internal attestation or TUF service).
+pub trait HashProvider {
+    /// Fetch the hashes of every distribution for the given package version.
+    ///
+    /// Implementations should return an error, rather than an empty set of hashes, if the
+    /// provider has no record of the version.
+    fn hashes<'a>(
+        &'a self,
+        name: &'a PackageName,
+        version: &'a Version,
+    ) -> impl Future<Output = Result<HashDigests, HashProviderError>> + 'a;
+}
+
+/// A [`HashProvider`] that fetches hashes over HTTP, as configured via an index's
+/// `hash-provider` setting.
+#[derive(Debug, Clone)]
+pub struct HttpHashProvider<'a> {
+    client: &'a RegistryClient,
+    provider: &'a IndexHashProvider,
+}
+
+/// The response from an [`HttpHashProvider`].
+#[derive(Debug, serde::Deserialize)]
+struct HashProviderResponse {
+    hashes: Vec<String>,
+}
+
+impl<'a> HttpHashProvider<'a> {
+    /// Create a new [`HttpHashProvider`] for the given [`IndexHashProvider`].
+    pub fn new(client: &'a RegistryClient, provider: &'a IndexHashProvider) -> Self {
+        Self { client, provider }
+    }
+
+    /// Return the URL from which to fetch the hashes for the given package version.
+    fn url(&self, name: &PackageName, version: &Version) -> Result<Url, HashProviderError> {
+        let url = self
+            .provider
+            .url
+            .replace("{name}", name.as_str())
+            .replace("{version}", &version.to_string());
+        Url::parse(&url).map_err(|err| HashProviderError::InvalidUrl(url, err))
+    }
+}
+
+impl HashProvider for HttpHashProvider<'_> {
+    async fn hashes<'a>(
+        &'a self,
+        name: &'a PackageName,
+        version: &'a Version,
+    ) -> Result<HashDigests, HashProviderError> {
+        let package = format!("{name}=={version}");
+        let url = self.url(name, version)?;
+        debug!("Fetching hashes for `{package}` from: {url}");
+
+        let response = self
+            .client
+            .uncached_client(&url)
+            .get(url.clone())
+            .send()
+            .await
+            .map_err(|err| {
+                HashProviderError::Fetch(
+                    package.clone(),
+                    ErrorKind::from_reqwest_middleware(url.clone(), err).into(),
+                )
+            })?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(HashProviderError::Missing(package, url));
+        }
+        let bytes = response
+            .error_for_status()
+            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))
+            .map_err(|err| HashProviderError::Fetch(package.clone(), err.into()))?
+            .bytes()
+            .await
+            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))
+            .map_err(|err| HashProviderError::Fetch(package.clone(), err.into()))?;
+
+        let HashProviderResponse { hashes } = serde_json::from_slice(&bytes)
+            .map_err(|err| HashProviderError::Decode(url.clone(), err))?;
+        let hashes = hashes
+            .iter()
+            .map(|hash| HashDigest::from_str(hash))
+            .collect::<Result<HashDigests, _>>()
+            .map_err(|err| HashProviderError::InvalidHash(url.clone(), err))?;
+        if hashes.is_empty() {
+            return Err(HashProviderError::Missing(package, url));
+        }
+        Ok(hashes)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum HashProviderError {
+    #[error("Invalid hash provider URL: `{0}`")]
+    InvalidUrl(String, #[source] url::ParseError),
+    #[error("Failed to fetch hashes for `{0}` from the hash provider")]
+    Fetch(String, #[source] Error),
+    #[error("The hash provider has no hashes for `{0}` (at: `{1}`)")]
+    Missing(String, Url),
+    #[error("Failed to parse the response from the hash provider at: `{0}`")]
+    Decode(Url, #[source] serde_json::Error),
+    #[error("The hash provider at `{0}` returned an invalid hash")]
+    InvalidHash(Url, #[source] HashError),
+}
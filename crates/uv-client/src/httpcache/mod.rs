@@ -39,7 +39,8 @@ two fold:
 * Most other assets, like wheels, are forever immutable. They will never
   change. So servers will typically set a very high `max-age`, which means we
   will almost never need to ask the server for permission to reuse our cached
-  wheel.
+  wheel. If the server also sets the `immutable` directive, we never ask at all
+  (short of an explicit `--refresh`).
 
 When a cached response exceeds the `max-age` configured on a response, then
 we call that response stale. Generally speaking, we won't return responses
@@ -369,6 +370,32 @@ impl ArchivedCachePolicy {
         BeforeRequest::Stale(self.new_cache_policy_builder(request))
     }
 
+    /// Returns `true` if the cached response matches the given request and is
+    /// younger than the given freshness lifetime.
+    ///
+    /// This is used to apply a user-configured freshness lifetime (e.g., the
+    /// `metadata-ttl` of an index) in lieu of the one advertised by the
+    /// server. Unlike [`ArchivedCachePolicy::before_request`], it ignores the
+    /// `cache-control` directives on both the cached response and the request.
+    pub fn is_fresh_within(&self, request: &reqwest::Request, ttl: Duration) -> bool {
+        if !self.is_storable() || self.request.uri != request.url().as_str() {
+            return false;
+        }
+        let age = self.age(SystemTime::now());
+        if age > ttl {
+            tracing::trace!(
+                "Request {} does not have a fresh cache because its age is {} \
+                 seconds, which exceeds the configured freshness lifetime of {} \
+                 seconds",
+                request.url(),
+                age.as_secs(),
+                ttl.as_secs(),
+            );
+            return false;
+        }
+        true
+    }
+
     /// This implements the logic for handling the response to a request that
     /// may be a revalidation request, as per [RFC 9111 S4.3.3] and [RFC 9111
     /// S4.3.4]. That is, the cache policy builder given here should be the one
@@ -740,78 +767,81 @@ impl ArchivedCachePolicy {
         let freshness_lifetime = self.freshness_lifetime().as_secs();
         let age = self.age(now).as_secs();
 
+        let reqcc = request
+            .headers()
+            .get_all("cache-control")
+            .iter()
+            .collect::<CacheControl>();
+
         // Per RFC 8246, the `immutable` directive means that a reload from an
-        // end user should not result in a revalidation request. Indeed, the
-        // `immutable` directive seems to imply that clients should never talk
-        // to the origin server until the cached response is stale with respect
-        // to its freshness lifetime (as set by the server).
-        //
-        // A *force* reload from an end user should override this, but we
-        // currently have no path for that in this implementation. Instead, we
-        // just interpret `immutable` as meaning that any directives on the
-        // new request that would otherwise result in sending a revalidation
-        // request are ignored.
+        // end user should not result in a revalidation request. The resources
+        // uv fetches that are marked `immutable` (e.g., distributions and
+        // metadata files served from content-addressed URLs) never change, so
+        // we treat them as fresh permanently, regardless of their freshness
+        // lifetime and of any directives on the new request (including the
+        // `no-cache` that uv sets for `--refresh` or an expired metadata TTL).
         //
         // [RFC 8246]: https://httpwg.org/specs/rfc8246.html
-        if !self.response.headers.cc.immutable {
-            let reqcc = request
-                .headers()
-                .get_all("cache-control")
-                .iter()
-                .collect::<CacheControl>();
-
-            // As per [RFC 9111 S5.2.1.4], if the request has `no-cache`, then we should
-            // respect that.
-            //
-            // [RFC 9111 S5.2.1.4]: https://www.rfc-editor.org/rfc/rfc9111.html#section-5.2.1.4
-            if reqcc.no_cache {
+        if self.response.headers.cc.immutable {
+            tracing::trace!(
+                "Request {} has a fresh cache because the cached response \
+                 has an 'immutable' cache-control directive",
+                request.url(),
+            );
+            return true;
+        }
+
+        // As per [RFC 9111 S5.2.1.4], if the request has `no-cache`, then we should
+        // respect that.
+        //
+        // [RFC 9111 S5.2.1.4]: https://www.rfc-editor.org/rfc/rfc9111.html#section-5.2.1.4
+        if reqcc.no_cache {
+            tracing::trace!(
+                "Request {} does not have a fresh cache because \
+                 it has a 'no-cache' cache-control directive",
+                request.url(),
+            );
+            return false;
+        }
+
+        // If the request has a max-age directive, then we should respect that
+        // as per [RFC 9111 S5.2.1.1].
+        //
+        // [RFC 9111 S5.2.1.1]: https://www.rfc-editor.org/rfc/rfc9111.html#section-5.2.1.1
+        if let Some(&max_age) = reqcc.max_age_seconds.as_ref() {
+            if age > max_age {
                 tracing::trace!(
                     "Request {} does not have a fresh cache because \
-                 it has a 'no-cache' cache-control directive",
+                     the cached response's age is {} seconds and the max age \
+                     allowed by the request is {} seconds",
                     request.url(),
+                    age,
+                    max_age,
                 );
                 return false;
             }
+        }
 
-            // If the request has a max-age directive, then we should respect that
-            // as per [RFC 9111 S5.2.1.1].
-            //
-            // [RFC 9111 S5.2.1.1]: https://www.rfc-editor.org/rfc/rfc9111.html#section-5.2.1.1
-            if let Some(&max_age) = reqcc.max_age_seconds.as_ref() {
-                if age > max_age {
-                    tracing::trace!(
-                        "Request {} does not have a fresh cache because \
-                     the cached response's age is {} seconds and the max age \
-                     allowed by the request is {} seconds",
-                        request.url(),
-                        age,
-                        max_age,
-                    );
-                    return false;
-                }
-            }
-
-            // If the request has a min-fresh directive, then we only consider a
-            // cached response fresh if the remaining time it has to live exceeds
-            // the threshold provided, as per [RFC 9111 S5.2.1.3].
-            //
-            // [RFC 9111 S5.2.1.3]: https://www.rfc-editor.org/rfc/rfc9111.html#section-5.2.1.3
-            if let Some(&min_fresh) = reqcc.min_fresh_seconds.as_ref() {
-                let time_to_live = freshness_lifetime.saturating_sub(unix_timestamp(now));
-                if time_to_live < min_fresh {
-                    tracing::trace!(
-                        "Request {} does not have a fresh cache because \
+        // If the request has a min-fresh directive, then we only consider a
+        // cached response fresh if the remaining time it has to live exceeds
+        // the threshold provided, as per [RFC 9111 S5.2.1.3].
+        //
+        // [RFC 9111 S5.2.1.3]: https://www.rfc-editor.org/rfc/rfc9111.html#section-5.2.1.3
+        if let Some(&min_fresh) = reqcc.min_fresh_seconds.as_ref() {
+            let time_to_live = freshness_lifetime.saturating_sub(unix_timestamp(now));
+            if time_to_live < min_fresh {
+                tracing::trace!(
+                    "Request {} does not have a fresh cache because \
                      the request set a 'min-fresh' cache-control directive, \
                      and its time-to-live is {} seconds but it needs to be \
                      at least {} seconds",
-                        request.url(),
-                        time_to_live,
-                        min_fresh,
-                    );
-                    // Note that S5.2.1.3 does not say that max-stale overrides
-                    // this, so we ignore it here.
-                    return false;
-                }
+                    request.url(),
+                    time_to_live,
+                    min_fresh,
+                );
+                // Note that S5.2.1.3 does not say that max-stale overrides
+                // this, so we ignore it here.
+                return false;
             }
         }
         if age > freshness_lifetime {
@@ -1398,3 +1428,74 @@ fn parse_seconds(value: &[u8]) -> Option<u64> {
     }
     std::str::from_utf8(value).ok()?.parse().ok()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URL: &str = "https://files.pythonhosted.org/packages/example.whl.metadata";
+
+    fn request(cache_control: Option<&'static str>) -> reqwest::Request {
+        let mut request = reqwest::Request::new(http::Method::GET, URL.parse().unwrap());
+        if let Some(cache_control) = cache_control {
+            request.headers_mut().insert(
+                http::header::CACHE_CONTROL,
+                HeaderValue::from_static(cache_control),
+            );
+        }
+        request
+    }
+
+    fn cache_policy(headers: &[(&'static str, &'static str)]) -> OwnedArchive<CachePolicy> {
+        let mut response = http::Response::builder().status(200);
+        for (name, value) in headers {
+            response = response.header(*name, *value);
+        }
+        let response = reqwest::Response::from(response.body("").unwrap());
+        CachePolicyBuilder::new(&request(None))
+            .build(&response)
+            .to_archived()
+    }
+
+    #[test]
+    fn immutable_fresh_past_max_age() {
+        let later = SystemTime::now() + Duration::from_secs(24 * 60 * 60);
+
+        let policy = cache_policy(&[("cache-control", "max-age=60, immutable")]);
+        assert!(policy.is_fresh(later, &request(None)));
+
+        // Without the `immutable` directive, the response is stale past its max age.
+        let policy = cache_policy(&[("cache-control", "max-age=60")]);
+        assert!(!policy.is_fresh(later, &request(None)));
+    }
+
+    #[test]
+    fn immutable_ignores_no_cache() {
+        // Neither `--refresh` nor an expired metadata TTL (which both set `no-cache`) should
+        // trigger a revalidation of an immutable response.
+        let policy = cache_policy(&[("cache-control", "max-age=31536000, immutable")]);
+        assert!(policy.is_fresh(SystemTime::now(), &request(None)));
+        assert!(policy.is_fresh(SystemTime::now(), &request(Some("no-cache"))));
+
+        // Mutable responses are still revalidated.
+        let policy = cache_policy(&[("cache-control", "max-age=31536000")]);
+        assert!(!policy.is_fresh(SystemTime::now(), &request(Some("no-cache"))));
+    }
+
+    #[test]
+    fn override_ttl_expires() {
+        // The response is two minutes old, but the server considers it fresh for a year.
+        let policy = cache_policy(&[("cache-control", "max-age=31536000"), ("age", "120")]);
+        assert!(policy.is_fresh_within(&request(None), Duration::from_secs(3600)));
+        assert!(!policy.is_fresh_within(&request(None), Duration::from_secs(60)));
+
+        // Once the configured lifetime expires, the request is sent with `no-cache`, but an
+        // immutable response remains fresh.
+        let policy = cache_policy(&[
+            ("cache-control", "max-age=31536000, immutable"),
+            ("age", "120"),
+        ]);
+        assert!(!policy.is_fresh_within(&request(None), Duration::from_secs(60)));
+        assert!(policy.is_fresh(SystemTime::now(), &request(Some("no-cache"))));
+    }
+}
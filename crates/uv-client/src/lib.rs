@@ -3,24 +3,32 @@ pub use base_client::{
     UvRetryableStrategy, DEFAULT_RETRIES,
 };
 pub use cached_client::{CacheControl, CachedClient, CachedClientError, DataWithCachePolicy};
+pub use download_log::{downloads, enable_download_log, Download};
 pub use error::{Error, ErrorKind, WrappedReqwestError};
 pub use flat_index::{FlatIndexClient, FlatIndexEntries, FlatIndexError};
+pub use hash_provider::{HashProvider, HashProviderError, HttpHashProvider};
 pub use linehaul::LineHaul;
+pub use rate_limit::set_max_requests_per_second;
 pub use registry_client::{
-    Connectivity, RegistryClient, RegistryClientBuilder, SimpleMetadata, SimpleMetadatum,
-    VersionFiles,
+    Connectivity, InvalidFile, RegistryClient, RegistryClientBuilder, SimpleMetadata,
+    SimpleMetadatum, VersionFiles,
 };
 pub use rkyvutil::{Deserializer, OwnedArchive, Serializer, Validator};
+pub use stats::{enable_stats, record_backtrack, record_build, stats, Stats};
 
 mod base_client;
 mod cached_client;
+mod download_log;
 mod error;
 mod flat_index;
+mod hash_provider;
 mod html;
 mod httpcache;
 mod linehaul;
 mod middleware;
+mod rate_limit;
 mod registry_client;
 mod remote_metadata;
 mod rkyvutil;
+mod stats;
 mod tls;
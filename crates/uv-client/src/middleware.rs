@@ -1,10 +1,14 @@
 use http::Extensions;
 use std::fmt::Debug;
 
-use reqwest::{Request, Response};
+use futures::StreamExt;
+use reqwest::{Request, Response, ResponseBuilderExt};
 use reqwest_middleware::{Middleware, Next};
+use sha2::{Digest, Sha256};
 use url::Url;
 
+use crate::{download_log, rate_limit};
+
 /// A custom error type for the offline middleware.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct OfflineError {
@@ -45,3 +49,103 @@ impl Middleware for OfflineMiddleware {
         ))
     }
 }
+
+/// A middleware that paces requests to each host, based on the configured budget and any rate
+/// limits advertised by the host.
+pub(crate) struct RateLimitMiddleware;
+
+#[async_trait::async_trait]
+impl Middleware for RateLimitMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let url = req.url().clone();
+        rate_limit::acquire(&url).await;
+        let response = next.run(req, extensions).await?;
+        rate_limit::update(&url, response.status(), response.headers());
+        Ok(response)
+    }
+}
+
+/// A middleware that records the URL, size, and SHA-256 digest of every successful response body
+/// in the download log, and its size in the statistics for the invocation.
+pub(crate) struct DownloadLogMiddleware;
+
+#[async_trait::async_trait]
+impl Middleware for DownloadLogMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let response = next.run(req, extensions).await?;
+        if !response.status().is_success() {
+            return Ok(response);
+        }
+
+        let url = response.url().clone();
+        let mut builder = http::Response::builder()
+            .status(response.status())
+            .version(response.version())
+            .url(url.clone());
+        if let Some(headers) = builder.headers_mut() {
+            headers.clone_from(response.headers());
+        }
+
+        // Hash the body as it's consumed; the entry is recorded once the body is dropped, such
+        // that partially-read bodies (e.g., for range requests, or interrupted downloads) are
+        // recorded too, but marked as incomplete.
+        let tap = DownloadTap {
+            url: Some(url),
+            size: 0,
+            hasher: Sha256::new(),
+            complete: false,
+        };
+        let stream = futures::stream::unfold(
+            (response.bytes_stream(), tap),
+            |(mut stream, mut tap)| async move {
+                match stream.next().await {
+                    Some(Ok(bytes)) => {
+                        tap.size += bytes.len() as u64;
+                        tap.hasher.update(&bytes);
+                        Some((Ok(bytes), (stream, tap)))
+                    }
+                    Some(Err(err)) => Some((Err(err), (stream, tap))),
+                    None => {
+                        // Record the entry for the fully-consumed body.
+                        tap.complete = true;
+                        drop(tap);
+                        None
+                    }
+                }
+            },
+        );
+
+        let response = builder
+            .body(reqwest::Body::wrap_stream(stream))
+            .map_err(|err| reqwest_middleware::Error::Middleware(err.into()))?;
+        Ok(Response::from(response))
+    }
+}
+
+/// The in-progress state of a response body tracked by the [`DownloadLogMiddleware`].
+struct DownloadTap {
+    url: Option<Url>,
+    size: u64,
+    hasher: Sha256,
+    /// Whether the body was read to completion.
+    complete: bool,
+}
+
+impl Drop for DownloadTap {
+    fn drop(&mut self) {
+        if let Some(url) = self.url.take() {
+            let sha256 = format!("{:x}", self.hasher.clone().finalize());
+            download_log::record(url, self.size, sha256, self.complete);
+        }
+    }
+}
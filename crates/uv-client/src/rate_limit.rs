@@ -0,0 +1,397 @@
+use std::collections::HashMap;
+use std::num::NonZeroU32;
+use std::sync::{LazyLock, Mutex, OnceLock};
+use std::time::{Duration, SystemTime};
+
+use http::HeaderMap;
+use reqwest::StatusCode;
+use tokio::time::Instant;
+use tracing::debug;
+use url::Url;
+
+use uv_warnings::warn_user;
+
+/// The minimum interval between requests to the same host, if a budget was set via
+/// [`set_max_requests_per_second`].
+static MIN_INTERVAL: OnceLock<Duration> = OnceLock::new();
+
+/// The pacing state for each host to which requests have been made.
+static HOSTS: LazyLock<Mutex<HashMap<String, HostBudget>>> = LazyLock::new(Mutex::default);
+
+/// The longest that a single rate-limit header is allowed to pause requests to a host.
+const MAX_PAUSE: Duration = Duration::from_secs(300);
+
+/// The shortest pause that's surfaced to the user, rather than only logged.
+const NOTIFY_PAUSE: Duration = Duration::from_secs(5);
+
+/// The number of remaining requests below which requests are paced, if the host doesn't advertise
+/// the size of its budget via `X-RateLimit-Limit`.
+const LOW_REMAINING: u64 = 10;
+
+/// Limit the number of requests sent to any one host to the given number per second, for the
+/// remainder of the invocation.
+pub fn set_max_requests_per_second(max_requests_per_second: NonZeroU32) {
+    MIN_INTERVAL.get_or_init(|| Duration::from_secs(1) / max_requests_per_second.get());
+}
+
+/// The pacing state for a single host.
+#[derive(Debug)]
+struct HostBudget {
+    /// The earliest time at which the next request may be sent.
+    next: Instant,
+    /// The interval to leave between requests, as advertised by the host, along with the time
+    /// at which the host's rate-limit window resets.
+    advertised: Option<(Duration, Instant)>,
+}
+
+/// Wait until a request to the host of the given URL fits within its budget.
+///
+/// Each caller reserves the next available slot, such that concurrent requests are spread out
+/// rather than released at once.
+pub(crate) async fn acquire(url: &Url) {
+    let Some(key) = host_key(url) else {
+        return;
+    };
+
+    let slot = {
+        let mut hosts = HOSTS.lock().unwrap();
+        let now = Instant::now();
+        let Some(budget) = hosts.get_mut(&key) else {
+            // Without a configured budget, there's no need to track hosts until they advertise
+            // a rate limit.
+            if let Some(interval) = MIN_INTERVAL.get() {
+                hosts.insert(
+                    key,
+                    HostBudget {
+                        next: now + *interval,
+                        advertised: None,
+                    },
+                );
+            }
+            return;
+        };
+
+        let interval = budget.interval(now);
+        let slot = budget.next.max(now);
+        budget.next = slot + interval;
+        slot
+    };
+
+    if slot > Instant::now() {
+        tokio::time::sleep_until(slot).await;
+    }
+}
+
+/// Update the budget for the host of the given URL based on the rate-limit headers of its
+/// response.
+pub(crate) fn update(url: &Url, status: StatusCode, headers: &HeaderMap) {
+    let Some(key) = host_key(url) else {
+        return;
+    };
+    let Some(limit) = RateLimit::from_headers(status, headers, SystemTime::now()) else {
+        return;
+    };
+
+    let now = Instant::now();
+    let mut hosts = HOSTS.lock().unwrap();
+    let budget = hosts.entry(key).or_insert_with(|| HostBudget {
+        next: now,
+        advertised: None,
+    });
+
+    if let Some(pause) = limit.pause {
+        let host = url.host_str().unwrap_or_default();
+        if pause > MAX_PAUSE {
+            warn_user!(
+                "`{host}` asked uv to pause requests for {}s due to rate limiting; waiting {}s instead",
+                pause.as_secs(),
+                MAX_PAUSE.as_secs()
+            );
+        } else if pause >= NOTIFY_PAUSE {
+            warn_user!(
+                "`{host}` is rate limiting requests; waiting {}s before sending more",
+                pause.as_secs()
+            );
+        } else {
+            debug!(
+                "Pausing requests to `{host}` for {:.1}s due to rate limiting",
+                pause.as_secs_f32()
+            );
+        }
+        budget.next = budget.next.max(now + pause.min(MAX_PAUSE));
+    }
+
+    if let Some((interval, reset)) = limit.interval {
+        let reset = reset.min(MAX_PAUSE);
+        budget.advertised = Some((interval.min(reset), now + reset));
+    }
+}
+
+impl HostBudget {
+    /// Return the interval to leave between requests at the given time.
+    fn interval(&self, now: Instant) -> Duration {
+        let min = MIN_INTERVAL.get().copied().unwrap_or_default();
+        match self.advertised {
+            Some((interval, reset)) if now < reset => interval.max(min),
+            _ => min,
+        }
+    }
+}
+
+/// The rate limit advertised by a host in the headers of a response.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct RateLimit {
+    /// The duration for which no further requests should be sent.
+    pause: Option<Duration>,
+    /// The interval at which to send requests to spread the remaining budget over the current
+    /// window, along with the time remaining until the window resets.
+    interval: Option<(Duration, Duration)>,
+}
+
+impl RateLimit {
+    /// Parse the rate limit from the `Retry-After` and `X-RateLimit-*` headers of a response, as
+    /// sent by, e.g., Azure Artifacts, GitHub, and Artifactory.
+    ///
+    /// Returns `None` if requests to the host don't need to be slowed down.
+    fn from_headers(status: StatusCode, headers: &HeaderMap, now: SystemTime) -> Option<Self> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::trim)
+        };
+
+        let mut limit = Self::default();
+
+        // Respect `Retry-After` on responses that indicate that the host is overloaded.
+        let throttled = matches!(
+            status,
+            StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+        );
+        if throttled {
+            limit.pause = header("retry-after").and_then(|value| parse_retry_after(value, now));
+        }
+
+        // Hosts tend to advertise their budget on every response, so only pace requests once the
+        // budget is running low (below a tenth of the total, if known), or the host is
+        // throttling requests.
+        let remaining = header("x-ratelimit-remaining").and_then(|value| value.parse::<u64>().ok());
+        let total = header("x-ratelimit-limit").and_then(|value| value.parse::<u64>().ok());
+        let reset = header("x-ratelimit-reset").and_then(|value| parse_reset(value, now));
+        let low = |remaining: u64| match total {
+            Some(total) => remaining.saturating_mul(10) < total,
+            None => remaining < LOW_REMAINING,
+        };
+        match (remaining, reset) {
+            (Some(0), Some(reset)) => {
+                limit.pause = Some(limit.pause.unwrap_or_default().max(reset));
+            }
+            (Some(remaining), Some(reset)) if throttled || low(remaining) => {
+                let interval = reset / u32::try_from(remaining).unwrap_or(u32::MAX);
+                limit.interval = Some((interval, reset));
+            }
+            _ => {}
+        }
+
+        // Azure DevOps advertises the delay it has applied to the request, in seconds.
+        if let Some(delay) = header("x-ratelimit-delay")
+            .and_then(|value| value.parse::<f64>().ok())
+            .and_then(|value| Duration::try_from_secs_f64(value).ok())
+            .filter(|delay| !delay.is_zero())
+        {
+            limit.pause = Some(limit.pause.unwrap_or_default().max(delay));
+        }
+
+        if limit == Self::default() {
+            None
+        } else {
+            Some(limit)
+        }
+    }
+}
+
+/// Parse a `Retry-After` header, which is either a number of seconds or an HTTP date.
+fn parse_retry_after(value: &str, now: SystemTime) -> Option<Duration> {
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = jiff::fmt::rfc2822::parse(value).ok()?;
+    SystemTime::from(date.timestamp()).duration_since(now).ok()
+}
+
+/// Parse an `X-RateLimit-Reset` header, which is either a number of seconds until the window
+/// resets, or the Unix timestamp at which it resets.
+fn parse_reset(value: &str, now: SystemTime) -> Option<Duration> {
+    let seconds = value.parse::<u64>().ok()?;
+
+    // Values beyond a day are assumed to be timestamps rather than durations.
+    if seconds > 24 * 60 * 60 {
+        let reset = SystemTime::UNIX_EPOCH + Duration::from_secs(seconds);
+        Some(reset.duration_since(now).unwrap_or_default())
+    } else {
+        Some(Duration::from_secs(seconds))
+    }
+}
+
+/// Return the key under which to track the budget for the host of the given URL.
+fn host_key(url: &Url) -> Option<String> {
+    let host = url.host_str()?;
+    Some(match url.port_or_known_default() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use http::{HeaderMap, HeaderValue};
+    use reqwest::StatusCode;
+
+    use super::RateLimit;
+
+    fn headers(headers: &[(&'static str, &'static str)]) -> HeaderMap {
+        headers
+            .iter()
+            .map(|(name, value)| (*name, HeaderValue::from_static(value)))
+            .map(|(name, value)| (name.parse().unwrap(), value))
+            .collect()
+    }
+
+    #[test]
+    fn retry_after() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+
+        let limit = RateLimit::from_headers(
+            StatusCode::TOO_MANY_REQUESTS,
+            &headers(&[("retry-after", "30")]),
+            now,
+        );
+        assert_eq!(
+            limit,
+            Some(RateLimit {
+                pause: Some(Duration::from_secs(30)),
+                interval: None,
+            })
+        );
+
+        let limit = RateLimit::from_headers(
+            StatusCode::TOO_MANY_REQUESTS,
+            &headers(&[("retry-after", "Sun, 26 Nov 2023 12:00:00 GMT")]),
+            now,
+        );
+        assert_eq!(
+            limit,
+            Some(RateLimit {
+                pause: Some(Duration::from_secs(1_000_000)),
+                interval: None,
+            })
+        );
+
+        // `Retry-After` is ignored on successful responses.
+        let limit =
+            RateLimit::from_headers(StatusCode::OK, &headers(&[("retry-after", "30")]), now);
+        assert_eq!(limit, None);
+    }
+
+    #[test]
+    fn remaining() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+
+        // Plenty of budget remains, so requests aren't paced.
+        let limit = RateLimit::from_headers(
+            StatusCode::OK,
+            &headers(&[("x-ratelimit-remaining", "20"), ("x-ratelimit-reset", "10")]),
+            now,
+        );
+        assert_eq!(limit, None);
+
+        let limit = RateLimit::from_headers(
+            StatusCode::OK,
+            &headers(&[
+                ("x-ratelimit-limit", "1000"),
+                ("x-ratelimit-remaining", "500"),
+                ("x-ratelimit-reset", "10"),
+            ]),
+            now,
+        );
+        assert_eq!(limit, None);
+
+        // Once the budget runs low, spread the remainder over the window.
+        let limit = RateLimit::from_headers(
+            StatusCode::OK,
+            &headers(&[("x-ratelimit-remaining", "5"), ("x-ratelimit-reset", "10")]),
+            now,
+        );
+        assert_eq!(
+            limit,
+            Some(RateLimit {
+                pause: None,
+                interval: Some((Duration::from_secs(2), Duration::from_secs(10))),
+            })
+        );
+
+        let limit = RateLimit::from_headers(
+            StatusCode::OK,
+            &headers(&[
+                ("x-ratelimit-limit", "1000"),
+                ("x-ratelimit-remaining", "20"),
+                ("x-ratelimit-reset", "10"),
+            ]),
+            now,
+        );
+        assert_eq!(
+            limit,
+            Some(RateLimit {
+                pause: None,
+                interval: Some((Duration::from_millis(500), Duration::from_secs(10))),
+            })
+        );
+
+        // If the host is throttling requests, pace them regardless.
+        let limit = RateLimit::from_headers(
+            StatusCode::TOO_MANY_REQUESTS,
+            &headers(&[("x-ratelimit-remaining", "20"), ("x-ratelimit-reset", "10")]),
+            now,
+        );
+        assert_eq!(
+            limit,
+            Some(RateLimit {
+                pause: None,
+                interval: Some((Duration::from_millis(500), Duration::from_secs(10))),
+            })
+        );
+
+        // Wait for the window to reset, given as a timestamp, once the budget is exhausted.
+        let limit = RateLimit::from_headers(
+            StatusCode::OK,
+            &headers(&[
+                ("x-ratelimit-remaining", "0"),
+                ("x-ratelimit-reset", "1700000060"),
+            ]),
+            now,
+        );
+        assert_eq!(
+            limit,
+            Some(RateLimit {
+                pause: Some(Duration::from_secs(60)),
+                interval: None,
+            })
+        );
+
+        // Azure DevOps reports the delay it has applied.
+        let limit = RateLimit::from_headers(
+            StatusCode::OK,
+            &headers(&[("x-ratelimit-delay", "1.5")]),
+            now,
+        );
+        assert_eq!(
+            limit,
+            Some(RateLimit {
+                pause: Some(Duration::from_millis(1500)),
+                interval: None,
+            })
+        );
+    }
+}
@@ -20,9 +20,9 @@ use crate::html::SimpleHtml;
 use crate::remote_metadata::wheel_metadata_from_remote_zip;
 use crate::rkyvutil::OwnedArchive;
 use crate::{BaseClient, CachedClient, CachedClientError, Error, ErrorKind};
-use uv_cache::{Cache, CacheBucket, CacheEntry, WheelCache};
+use uv_cache::{Cache, CacheBucket, CacheEntry, Freshness, WheelCache};
 use uv_configuration::KeyringProviderType;
-use uv_configuration::{IndexStrategy, TrustedHost};
+use uv_configuration::{IndexStrategy, IndexUnavailablePolicy, TrustedHost};
 use uv_distribution_filename::{DistFilename, SourceDistFilename, WheelFilename};
 use uv_distribution_types::{
     BuiltDist, File, FileLocation, Index, IndexCapabilities, IndexUrl, IndexUrls, Name,
@@ -34,12 +34,14 @@ use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Platform;
 use uv_pypi_types::{ResolutionMetadata, SimpleJson};
 use uv_small_str::SmallString;
+use uv_warnings::warn_user_once;
 
 /// A builder for an [`RegistryClient`].
 #[derive(Debug, Clone)]
 pub struct RegistryClientBuilder<'a> {
     index_urls: IndexUrls,
     index_strategy: IndexStrategy,
+    index_unavailable: IndexUnavailablePolicy,
     cache: Cache,
     base_client_builder: BaseClientBuilder<'a>,
 }
@@ -49,6 +51,7 @@ impl RegistryClientBuilder<'_> {
         Self {
             index_urls: IndexUrls::default(),
             index_strategy: IndexStrategy::default(),
+            index_unavailable: IndexUnavailablePolicy::default(),
             cache,
             base_client_builder: BaseClientBuilder::new(),
         }
@@ -68,6 +71,12 @@ impl<'a> RegistryClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn index_unavailable(mut self, index_unavailable: IndexUnavailablePolicy) -> Self {
+        self.index_unavailable = index_unavailable;
+        self
+    }
+
     #[must_use]
     pub fn keyring(mut self, keyring_type: KeyringProviderType) -> Self {
         self.base_client_builder = self.base_client_builder.keyring(keyring_type);
@@ -145,6 +154,7 @@ impl<'a> RegistryClientBuilder<'a> {
         RegistryClient {
             index_urls: self.index_urls,
             index_strategy: self.index_strategy,
+            index_unavailable: self.index_unavailable,
             cache: self.cache,
             connectivity,
             client,
@@ -166,6 +176,7 @@ impl<'a> RegistryClientBuilder<'a> {
         RegistryClient {
             index_urls: self.index_urls,
             index_strategy: self.index_strategy,
+            index_unavailable: self.index_unavailable,
             cache: self.cache,
             connectivity,
             client,
@@ -181,6 +192,7 @@ impl<'a> TryFrom<BaseClientBuilder<'a>> for RegistryClientBuilder<'a> {
         Ok(Self {
             index_urls: IndexUrls::default(),
             index_strategy: IndexStrategy::default(),
+            index_unavailable: IndexUnavailablePolicy::default(),
             cache: Cache::temp()?,
             base_client_builder: value,
         })
@@ -194,6 +206,8 @@ pub struct RegistryClient {
     index_urls: IndexUrls,
     /// The strategy to use when fetching across multiple indexes.
     index_strategy: IndexStrategy,
+    /// The policy to apply when an extra index can't be reached.
+    index_unavailable: IndexUnavailablePolicy,
     /// The underlying HTTP client.
     client: CachedClient,
     /// Used for the remote wheel METADATA cache.
@@ -246,9 +260,19 @@ impl RegistryClient {
         let indexes = if let Some(index) = index {
             Either::Left(std::iter::once(index))
         } else {
-            Either::Right(self.index_urls.indexes().map(Index::url))
+            Either::Right(
+                self.index_urls
+                    .indexes()
+                    .map(Index::url)
+                    // Skip any indexes that were previously found to be unreachable.
+                    .filter(|index| !capabilities.unavailable(index)),
+            )
         };
 
+        // Unreachable indexes may only be skipped when searching across multiple indexes, rather
+        // than fetching from an index to which the package is pinned (or the only index).
+        let skippable = index.is_none() && self.index_urls.indexes().nth(1).is_some();
+
         let mut it = indexes.peekable();
         if it.peek().is_none() {
             return Err(ErrorKind::NoIndex(package_name.to_string()).into());
@@ -261,10 +285,19 @@ impl RegistryClient {
             IndexStrategy::FirstIndex => {
                 for index in it {
                     let _permit = download_concurrency.acquire().await;
-                    if let Some(metadata) = self
+                    let metadata = match self
                         .simple_single_index(package_name, index, capabilities)
-                        .await?
+                        .await
                     {
+                        Ok(metadata) => metadata,
+                        Err(err)
+                            if skippable && self.skip_unavailable(index, &err, capabilities) =>
+                        {
+                            None
+                        }
+                        Err(err) => return Err(err),
+                    };
+                    if let Some(metadata) = metadata {
                         results.push((index, metadata));
                         break;
                     }
@@ -276,9 +309,19 @@ impl RegistryClient {
                 results = futures::stream::iter(it)
                     .map(|index| async move {
                         let _permit = download_concurrency.acquire().await;
-                        let metadata = self
+                        let metadata = match self
                             .simple_single_index(package_name, index, capabilities)
-                            .await?;
+                            .await
+                        {
+                            Ok(metadata) => metadata,
+                            Err(err)
+                                if skippable
+                                    && self.skip_unavailable(index, &err, capabilities) =>
+                            {
+                                None
+                            }
+                            Err(err) => return Err(err),
+                        };
                         Ok((index, metadata))
                     })
                     .buffered(8)
@@ -306,6 +349,47 @@ impl RegistryClient {
         Ok(results)
     }
 
+    /// Returns `true` if the error indicates that an extra index couldn't be reached, and the
+    /// [`IndexUnavailablePolicy`] permits continuing without it.
+    ///
+    /// The index is marked as unavailable, such that it's skipped (with a warning) for the
+    /// remainder of the operation.
+    fn skip_unavailable(
+        &self,
+        index: &IndexUrl,
+        err: &Error,
+        capabilities: &IndexCapabilities,
+    ) -> bool {
+        if self.index_unavailable == IndexUnavailablePolicy::Error
+            || self.index_urls.is_default(index)
+        {
+            return false;
+        }
+        let ErrorKind::WrappedReqwestError(_, err) = err.kind() else {
+            return false;
+        };
+        if !err.is_unavailable() {
+            return false;
+        }
+
+        // Under `first-index`, skipping an index would allow a later index to supply a package
+        // that's meant to come from the unavailable index.
+        if self.index_strategy == IndexStrategy::FirstIndex {
+            warn_user_once!(
+                "Index `{}` is unavailable, but can't be skipped under `--index-strategy first-index`, since a later index could supply its packages",
+                index.redacted()
+            );
+            return false;
+        }
+
+        warn_user_once!(
+            "Index `{}` is unavailable; continuing with the remaining indexes ({err})",
+            index.redacted()
+        );
+        capabilities.set_unavailable(index.clone());
+        true
+    }
+
     /// Fetch the [`SimpleMetadata`] from a single index for a given package.
     ///
     /// The index can either be a PEP 503-compatible remote repository, or a local directory laid
@@ -336,11 +420,18 @@ impl RegistryClient {
             format!("{package_name}.rkyv"),
         );
         let cache_control = match self.connectivity {
-            Connectivity::Online => CacheControl::from(
-                self.cache
+            Connectivity::Online => {
+                let freshness = self
+                    .cache
                     .freshness(&cache_entry, Some(package_name))
-                    .map_err(ErrorKind::Io)?,
-            ),
+                    .map_err(ErrorKind::Io)?;
+                // Respect any freshness lifetime configured for the index, unless the user
+                // requested a refresh.
+                match self.index_urls.metadata_ttl(index) {
+                    Some(ttl) if freshness != Freshness::Stale => CacheControl::Override(ttl),
+                    _ => CacheControl::from(freshness),
+                }
+            }
             Connectivity::Offline => CacheControl::AllowStale,
         };
 
@@ -877,7 +968,13 @@ pub struct VersionSourceDist {
 
 #[derive(Default, Debug, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
 #[rkyv(derive(Debug))]
-pub struct SimpleMetadata(Vec<SimpleMetadatum>);
+pub struct SimpleMetadata {
+    /// The valid files on the index, grouped by version.
+    metadata: Vec<SimpleMetadatum>,
+    /// The files on the index that could not be parsed, e.g., due to an invalid filename or
+    /// version specifier.
+    invalid: Vec<InvalidFile>,
+}
 
 #[derive(Debug, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
 #[rkyv(derive(Debug))]
@@ -886,13 +983,29 @@ pub struct SimpleMetadatum {
     pub files: VersionFiles,
 }
 
+/// A file listed on an index that could not be parsed.
+#[derive(Debug, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
+#[rkyv(derive(Debug))]
+pub struct InvalidFile {
+    /// The name of the file, as listed on the index.
+    pub filename: String,
+    /// The reason the file could not be parsed.
+    pub reason: String,
+}
+
 impl SimpleMetadata {
     pub fn iter(&self) -> impl DoubleEndedIterator<Item = &SimpleMetadatum> {
-        self.0.iter()
+        self.metadata.iter()
+    }
+
+    /// Returns the files on the index that could not be parsed.
+    pub fn invalid(&self) -> &[InvalidFile] {
+        &self.invalid
     }
 
     fn from_files(files: Vec<uv_pypi_types::File>, package_name: &PackageName, base: &Url) -> Self {
         let mut map: BTreeMap<Version, VersionFiles> = BTreeMap::default();
+        let mut invalid = Vec::new();
 
         // Convert to a reference-counted string.
         let base = SmallString::from(base.as_str());
@@ -901,18 +1014,25 @@ impl SimpleMetadata {
         for file in files {
             let Some(filename) = DistFilename::try_from_filename(&file.filename, package_name)
             else {
-                warn!("Skipping file for {package_name}: {}", file.filename);
+                invalid.push(InvalidFile {
+                    filename: file.filename.to_string(),
+                    reason: format!("not a valid distribution filename for `{package_name}`"),
+                });
                 continue;
             };
             let version = match filename {
                 DistFilename::SourceDistFilename(ref inner) => &inner.version,
                 DistFilename::WheelFilename(ref inner) => &inner.version,
             };
+            let raw_filename = file.filename.clone();
             let file = match File::try_from(file, &base) {
                 Ok(file) => file,
                 Err(err) => {
-                    // Ignore files with unparsable version specifiers.
-                    warn!("Skipping file for {package_name}: {err}");
+                    // Record files with unparsable version specifiers.
+                    invalid.push(InvalidFile {
+                        filename: raw_filename.to_string(),
+                        reason: err.to_string(),
+                    });
                     continue;
                 }
             };
@@ -927,11 +1047,13 @@ impl SimpleMetadata {
                 }
             }
         }
-        Self(
-            map.into_iter()
+        Self {
+            metadata: map
+                .into_iter()
                 .map(|(version, files)| SimpleMetadatum { version, files })
                 .collect(),
-        )
+            invalid,
+        }
     }
 
     /// Read the [`SimpleMetadata`] from an HTML index.
@@ -952,17 +1074,22 @@ impl IntoIterator for SimpleMetadata {
     type IntoIter = std::vec::IntoIter<SimpleMetadatum>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        self.metadata.into_iter()
     }
 }
 
 impl ArchivedSimpleMetadata {
     pub fn iter(&self) -> impl DoubleEndedIterator<Item = &rkyv::Archived<SimpleMetadatum>> {
-        self.0.iter()
+        self.metadata.iter()
     }
 
     pub fn datum(&self, i: usize) -> Option<&rkyv::Archived<SimpleMetadatum>> {
-        self.0.get(i)
+        self.metadata.get(i)
+    }
+
+    /// Returns the files on the index that could not be parsed.
+    pub fn invalid(&self) -> &[rkyv::Archived<InvalidFile>] {
+        &self.invalid
     }
 }
 
@@ -1068,6 +1195,12 @@ mod tests {
             .map(|SimpleMetadatum { version, .. }| version.to_string())
             .collect();
         assert_eq!(versions, ["1.7.8".to_string()]);
+        let invalid: Vec<&str> = simple_metadata
+            .invalid()
+            .iter()
+            .map(|file| file.filename.as_str())
+            .collect();
+        assert_eq!(invalid, ["pyflyby-1.7.7.tar.gz"]);
     }
 
     /// Test for AWS Code Artifact registry
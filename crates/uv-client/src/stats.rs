@@ -0,0 +1,115 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+
+/// The counters for the current invocation, if statistics are enabled.
+static COUNTERS: OnceLock<Counters> = OnceLock::new();
+
+#[derive(Debug, Default)]
+struct Counters {
+    cache_hits: AtomicU64,
+    cache_misses: AtomicU64,
+    bytes_downloaded: AtomicU64,
+    builds: AtomicU64,
+    backtracks: AtomicU64,
+}
+
+/// Statistics about the work performed during an invocation.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct Stats {
+    /// The number of HTTP requests served from the cache, including those that were revalidated
+    /// with the server.
+    pub cache_hits: u64,
+    /// The number of HTTP requests that required a fresh response from the server.
+    pub cache_misses: u64,
+    /// The number of bytes received from remote servers.
+    pub bytes_downloaded: u64,
+    /// The number of PEP 517 builds, including builds run to extract metadata.
+    pub builds: u64,
+    /// The number of conflicts encountered during resolution, each of which causes the resolver
+    /// to backtrack.
+    pub backtracks: u64,
+}
+
+impl Stats {
+    /// Return the fraction of HTTP requests that were served from the cache, if any requests
+    /// were made.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn cache_hit_rate(&self) -> Option<f64> {
+        let requests = self.cache_hits + self.cache_misses;
+        if requests == 0 {
+            None
+        } else {
+            Some(self.cache_hits as f64 / requests as f64)
+        }
+    }
+}
+
+impl std::ops::AddAssign for Stats {
+    fn add_assign(&mut self, other: Self) {
+        self.cache_hits += other.cache_hits;
+        self.cache_misses += other.cache_misses;
+        self.bytes_downloaded += other.bytes_downloaded;
+        self.builds += other.builds;
+        self.backtracks += other.backtracks;
+    }
+}
+
+/// Enable statistics, counting the work performed by this invocation from this point onwards.
+pub fn enable_stats() {
+    COUNTERS.get_or_init(Counters::default);
+}
+
+/// Return the statistics recorded so far.
+///
+/// Returns `None` if statistics are not enabled.
+pub fn stats() -> Option<Stats> {
+    let counters = COUNTERS.get()?;
+    Some(Stats {
+        cache_hits: counters.cache_hits.load(Ordering::Relaxed),
+        cache_misses: counters.cache_misses.load(Ordering::Relaxed),
+        bytes_downloaded: counters.bytes_downloaded.load(Ordering::Relaxed),
+        builds: counters.builds.load(Ordering::Relaxed),
+        backtracks: counters.backtracks.load(Ordering::Relaxed),
+    })
+}
+
+/// Returns `true` if statistics are enabled.
+pub(crate) fn is_enabled() -> bool {
+    COUNTERS.get().is_some()
+}
+
+/// Record a PEP 517 build, if statistics are enabled.
+pub fn record_build() {
+    if let Some(counters) = COUNTERS.get() {
+        counters.builds.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Record a resolver backtrack, if statistics are enabled.
+pub fn record_backtrack() {
+    if let Some(counters) = COUNTERS.get() {
+        counters.backtracks.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Record an HTTP request served from the cache, if statistics are enabled.
+pub(crate) fn record_cache_hit() {
+    if let Some(counters) = COUNTERS.get() {
+        counters.cache_hits.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Record an HTTP request that required a fresh response, if statistics are enabled.
+pub(crate) fn record_cache_miss() {
+    if let Some(counters) = COUNTERS.get() {
+        counters.cache_misses.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Record the bytes received for a response body, if statistics are enabled.
+pub(crate) fn record_download(size: u64) {
+    if let Some(counters) = COUNTERS.get() {
+        counters.bytes_downloaded.fetch_add(size, Ordering::Relaxed);
+    }
+}
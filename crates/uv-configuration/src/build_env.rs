@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+
+use uv_pep508::PackageName;
+
+/// Environment variables to set when building source distributions for specific packages,
+/// structured as a map from package name to a map of variable names to values.
+///
+/// For example, `{ flash-attn = { MAX_JOBS = "4" } }` sets `MAX_JOBS=4` when (and only when)
+/// building `flash-attn` from source.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PackageBuildEnv(BTreeMap<PackageName, BTreeMap<String, String>>);
+
+impl FromIterator<(PackageName, BTreeMap<String, String>)> for PackageBuildEnv {
+    fn from_iter<T: IntoIterator<Item = (PackageName, BTreeMap<String, String>)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl PackageBuildEnv {
+    /// Returns the environment variables to set when building the given package, if any.
+    pub fn get(&self, package: &PackageName) -> Option<&BTreeMap<String, String>> {
+        self.0.get(package)
+    }
+
+    /// Returns `true` if no environment variables are configured for any package.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Merge two sets of package build environments, with the values in `self` taking
+    /// precedence.
+    ///
+    /// Variables are merged on a per-package basis, such that a variable defined for a package in
+    /// `other` is retained unless `self` defines the same variable for the same package.
+    #[must_use]
+    pub fn merge(self, other: PackageBuildEnv) -> PackageBuildEnv {
+        let mut env = other.0;
+        for (package, variables) in self.0 {
+            env.entry(package).or_default().extend(variables);
+        }
+        Self(env)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::str::FromStr;
+
+    use uv_pep508::PackageName;
+
+    use super::PackageBuildEnv;
+
+    #[test]
+    fn merge() {
+        let foo = PackageName::from_str("foo").unwrap();
+        let bar = PackageName::from_str("bar").unwrap();
+
+        let a = PackageBuildEnv::from_iter([(
+            foo.clone(),
+            BTreeMap::from([("MAX_JOBS".to_string(), "4".to_string())]),
+        )]);
+        let b = PackageBuildEnv::from_iter([
+            (
+                foo.clone(),
+                BTreeMap::from([
+                    ("MAX_JOBS".to_string(), "1".to_string()),
+                    ("CMAKE_ARGS".to_string(), "-DFOO=ON".to_string()),
+                ]),
+            ),
+            (
+                bar.clone(),
+                BTreeMap::from([("CC".to_string(), "clang".to_string())]),
+            ),
+        ]);
+
+        let merged = a.merge(b);
+        assert_eq!(
+            merged.get(&foo),
+            Some(&BTreeMap::from([
+                ("CMAKE_ARGS".to_string(), "-DFOO=ON".to_string()),
+                ("MAX_JOBS".to_string(), "4".to_string()),
+            ]))
+        );
+        assert_eq!(
+            merged.get(&bar),
+            Some(&BTreeMap::from([("CC".to_string(), "clang".to_string())]))
+        );
+    }
+}
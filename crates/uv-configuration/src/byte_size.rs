@@ -0,0 +1,179 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer};
+
+/// A size in bytes, parsed from a human-readable string (e.g., `500MB` or `1.5GiB`).
+///
+/// Decimal units (`kB`, `MB`, `GB`, `TB`) are powers of 1000, while binary units (`KiB`, `MiB`,
+/// `GiB`, `TiB`) are powers of 1024. Units are case-insensitive, and a bare number is interpreted as
+/// a number of bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteSize(u64);
+
+impl ByteSize {
+    /// Create a [`ByteSize`] from an exact number of bytes, e.g., as read from a file's metadata or
+    /// a `Content-Length` header.
+    pub fn from_bytes(bytes: u64) -> Self {
+        Self(bytes)
+    }
+
+    /// Return the size as a number of bytes, with any unit already applied (e.g., `1KiB` is
+    /// `1024`).
+    pub fn as_bytes(self) -> u64 {
+        self.0
+    }
+}
+
+/// An error that occurs when parsing a [`ByteSize`] from a string.
+#[derive(Debug, thiserror::Error)]
+pub enum ByteSizeError {
+    #[error("`{0}` is not a valid size (expected, e.g., `500MB` or `1GiB`)")]
+    Invalid(String),
+    #[error("`{0}` uses an unknown unit (expected one of: `B`, `kB`, `MB`, `GB`, `TB`, `KiB`, `MiB`, `GiB`, or `TiB`)")]
+    UnknownUnit(String),
+    #[error("`{0}` is too large")]
+    Overflow(String),
+}
+
+impl FromStr for ByteSize {
+    type Err = ByteSizeError;
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let split = trimmed
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(trimmed.len());
+        let (quantity, unit) = trimmed.split_at(split);
+
+        let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+            "" | "b" => 1,
+            "k" | "kb" => 1000,
+            "m" | "mb" => 1000u64.pow(2),
+            "g" | "gb" => 1000u64.pow(3),
+            "t" | "tb" => 1000u64.pow(4),
+            "kib" => 1024,
+            "mib" => 1024u64.pow(2),
+            "gib" => 1024u64.pow(3),
+            "tib" => 1024u64.pow(4),
+            _ => return Err(ByteSizeError::UnknownUnit(s.to_string())),
+        };
+
+        // Parse integers exactly, to avoid losing precision for large sizes.
+        if let Ok(quantity) = quantity.parse::<u64>() {
+            return quantity
+                .checked_mul(multiplier)
+                .map(Self)
+                .ok_or_else(|| ByteSizeError::Overflow(s.to_string()));
+        }
+
+        let quantity = quantity
+            .parse::<f64>()
+            .map_err(|_| ByteSizeError::Invalid(s.to_string()))?;
+        let bytes = (quantity * multiplier as f64).round();
+        if !bytes.is_finite() || bytes >= u64::MAX as f64 {
+            return Err(ByteSizeError::Overflow(s.to_string()));
+        }
+        Ok(Self(bytes as u64))
+    }
+}
+
+impl std::fmt::Display for ByteSize {
+    /// Display the size in the largest unit that represents it exactly.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [(u64, &str); 8] = [
+            (1024u64.pow(4), "TiB"),
+            (1000u64.pow(4), "TB"),
+            (1024u64.pow(3), "GiB"),
+            (1000u64.pow(3), "GB"),
+            (1024u64.pow(2), "MiB"),
+            (1000u64.pow(2), "MB"),
+            (1024, "KiB"),
+            (1000, "kB"),
+        ];
+        for (multiplier, unit) in UNITS {
+            if self.0 >= multiplier && self.0 % multiplier == 0 {
+                return write!(f, "{}{unit}", self.0 / multiplier);
+            }
+        }
+        write!(f, "{}B", self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for ByteSize {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        serde_untagged::UntaggedEnumVisitor::new()
+            .string(|string| ByteSize::from_str(string).map_err(serde::de::Error::custom))
+            .u64(|bytes| Ok(ByteSize(bytes)))
+            .deserialize(deserializer)
+    }
+}
+
+impl serde::Serialize for ByteSize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ByteSize {
+    fn schema_name() -> String {
+        "ByteSize".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(vec![
+                schemars::schema::InstanceType::String,
+                schemars::schema::InstanceType::Integer,
+            ]
+            .into()),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some(
+                    "A size in bytes, either as an integer or as a string with a unit (e.g., `500MB` or `1GiB`).".to_string(),
+                ),
+                ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ByteSize;
+
+    #[test]
+    fn parse() {
+        assert_eq!("1024".parse::<ByteSize>().unwrap().as_bytes(), 1024);
+        assert_eq!("500MB".parse::<ByteSize>().unwrap().as_bytes(), 500_000_000);
+        assert_eq!(
+            "500 mb".parse::<ByteSize>().unwrap().as_bytes(),
+            500_000_000
+        );
+        assert_eq!("1GiB".parse::<ByteSize>().unwrap().as_bytes(), 1 << 30);
+        assert_eq!("1.5KiB".parse::<ByteSize>().unwrap().as_bytes(), 1536);
+        assert!("MB".parse::<ByteSize>().is_err());
+        assert!("10XB".parse::<ByteSize>().is_err());
+        assert!("99999999999TB".parse::<ByteSize>().is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(ByteSize::from_bytes(500_000_000).to_string(), "500MB");
+        assert_eq!(ByteSize::from_bytes(1 << 30).to_string(), "1GiB");
+        assert_eq!(ByteSize::from_bytes(1536).to_string(), "1536B");
+        assert_eq!(ByteSize::from_bytes(0).to_string(), "0B");
+    }
+}
@@ -1,8 +1,10 @@
 use std::{
+    borrow::Cow,
     collections::{btree_map::Entry, BTreeMap},
     str::FromStr,
 };
 use uv_cache_key::CacheKeyHasher;
+use uv_normalize::PackageName;
 
 #[derive(Debug, Clone)]
 pub struct ConfigSettingEntry {
@@ -28,6 +30,32 @@ impl FromStr for ConfigSettingEntry {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct ConfigSettingPackageEntry {
+    /// The package to which the setting applies. For example, given `numpy:key=value`, this would
+    /// be `numpy`.
+    package: PackageName,
+    /// The setting itself. For example, given `numpy:key=value`, this would be `key=value`.
+    setting: ConfigSettingEntry,
+}
+
+impl FromStr for ConfigSettingPackageEntry {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((package, setting)) = s.split_once(':') else {
+            return Err(format!(
+                "Invalid config setting: {s} (expected `PACKAGE:KEY=VALUE`)"
+            ));
+        };
+        let package = PackageName::from_str(package.trim())
+            .map_err(|err| format!("Invalid config setting: {s} ({err})"))?;
+        let setting = ConfigSettingEntry::from_str(setting)
+            .map_err(|_| format!("Invalid config setting: {s} (expected `PACKAGE:KEY=VALUE`)"))?;
+        Ok(Self { package, setting })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 enum ConfigSettingValue {
@@ -212,6 +240,82 @@ impl<'de> serde::Deserialize<'de> for ConfigSettings {
     }
 }
 
+/// Settings to pass to the PEP 517 build backend for specific packages, structured as a map from
+/// package name to [`ConfigSettings`].
+///
+/// For example, `{ numpy = { setup-args = "-Dallow-noblas=true" } }` passes
+/// `setup-args=-Dallow-noblas=true` to the build backend when (and only when) building `numpy`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PackageConfigSettings(BTreeMap<PackageName, ConfigSettings>);
+
+impl FromIterator<ConfigSettingPackageEntry> for PackageConfigSettings {
+    fn from_iter<T: IntoIterator<Item = ConfigSettingPackageEntry>>(iter: T) -> Self {
+        let mut entries: BTreeMap<PackageName, Vec<ConfigSettingEntry>> = BTreeMap::default();
+        for entry in iter {
+            entries
+                .entry(entry.package)
+                .or_default()
+                .push(entry.setting);
+        }
+        Self(
+            entries
+                .into_iter()
+                .map(|(package, settings)| (package, settings.into_iter().collect()))
+                .collect(),
+        )
+    }
+}
+
+impl PackageConfigSettings {
+    /// Returns the config settings configured for the given package, if any.
+    pub fn get(&self, package: &PackageName) -> Option<&ConfigSettings> {
+        self.0.get(package)
+    }
+
+    /// Returns `true` if no config settings are configured for any package.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the config settings to use when building the given package: the global settings,
+    /// with any settings configured for the package itself taking precedence.
+    pub fn for_package<'a>(
+        &'a self,
+        package: Option<&PackageName>,
+        global: &'a ConfigSettings,
+    ) -> Cow<'a, ConfigSettings> {
+        match package.and_then(|package| self.0.get(package)) {
+            Some(settings) => {
+                let mut config = global.0.clone();
+                config.extend(settings.0.clone());
+                Cow::Owned(ConfigSettings(config))
+            }
+            None => Cow::Borrowed(global),
+        }
+    }
+
+    /// Merge two sets of package config settings, with the values in `self` taking precedence.
+    ///
+    /// Settings are merged on a per-package basis, as in [`ConfigSettings::merge`].
+    #[must_use]
+    pub fn merge(self, other: PackageConfigSettings) -> PackageConfigSettings {
+        let mut config = self.0;
+        for (package, settings) in other.0 {
+            match config.entry(package) {
+                Entry::Vacant(vacant) => {
+                    vacant.insert(settings);
+                }
+                Entry::Occupied(mut occupied) => {
+                    let existing = std::mem::take(occupied.get_mut());
+                    occupied.insert(existing.merge(settings));
+                }
+            }
+        }
+        Self(config)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -291,4 +395,49 @@ mod tests {
         );
         assert_eq!(settings.escape_for_python(), r#"{"key":"val\\1 {}value"}"#);
     }
+
+    #[test]
+    fn package_config_settings() {
+        let settings: PackageConfigSettings = vec![
+            ConfigSettingPackageEntry::from_str("numpy:setup-args=-Dallow-noblas=true").unwrap(),
+            ConfigSettingPackageEntry::from_str("numpy:key=value").unwrap(),
+            ConfigSettingPackageEntry::from_str("scipy:key=value2").unwrap(),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut global = ConfigSettings::default();
+        global.0.insert(
+            "key".to_string(),
+            ConfigSettingValue::String("global".to_string()),
+        );
+        global.0.insert(
+            "other".to_string(),
+            ConfigSettingValue::String("global".to_string()),
+        );
+
+        let numpy = PackageName::from_str("numpy").unwrap();
+        let resolved = settings.for_package(Some(&numpy), &global);
+        assert_eq!(
+            resolved.0.get("key"),
+            Some(&ConfigSettingValue::String("value".to_string()))
+        );
+        assert_eq!(
+            resolved.0.get("other"),
+            Some(&ConfigSettingValue::String("global".to_string()))
+        );
+        assert_eq!(
+            resolved.0.get("setup-args"),
+            Some(&ConfigSettingValue::String(
+                "-Dallow-noblas=true".to_string()
+            ))
+        );
+
+        let pandas = PackageName::from_str("pandas").unwrap();
+        assert_eq!(*settings.for_package(Some(&pandas), &global), global);
+        assert_eq!(*settings.for_package(None, &global), global);
+
+        assert!(ConfigSettingPackageEntry::from_str("numpy").is_err());
+        assert!(ConfigSettingPackageEntry::from_str("numpy:key").is_err());
+    }
 }
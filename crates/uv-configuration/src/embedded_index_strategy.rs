@@ -0,0 +1,28 @@
+/// The strategy to apply to indexes declared within a `requirements.txt` file (e.g., via
+/// `--index-url` or `--extra-index-url`).
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum EmbeddedIndexStrategy {
+    /// Merge the indexes declared in each file with those provided on the command line, and
+    /// use them for all packages.
+    #[default]
+    Merge,
+    /// Use the index declared in each file for that file's requirements only.
+    Scope,
+    /// Reject any file that declares an index.
+    Forbid,
+}
+
+impl std::fmt::Display for EmbeddedIndexStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Merge => write!(f, "merge"),
+            Self::Scope => write!(f, "scope"),
+            Self::Forbid => write!(f, "forbid"),
+        }
+    }
+}
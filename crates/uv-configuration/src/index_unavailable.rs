@@ -0,0 +1,26 @@
+/// The policy to apply when an extra index can't be reached (e.g., due to a timeout, a connection
+/// failure, or a server error).
+///
+/// Unavailable indexes are only ever skipped when searching across all indexes for the best match
+/// (i.e., under `unsafe-best-match` or `unsafe-first-match`), since under `first-index`, a later
+/// index could otherwise supply a package that's meant to come from the unavailable index.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum IndexUnavailablePolicy {
+    /// Fail the operation.
+    #[default]
+    Error,
+    /// Continue with the remaining indexes, emitting a warning.
+    Warn,
+}
+
+impl std::fmt::Display for IndexUnavailablePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warn => write!(f, "warn"),
+        }
+    }
+}
@@ -1,14 +1,18 @@
 pub use authentication::*;
+pub use build_env::*;
 pub use build_options::*;
+pub use byte_size::*;
 pub use concurrency::*;
 pub use config_settings::*;
 pub use constraints::*;
 pub use dev::*;
 pub use dry_run::*;
 pub use editable::*;
+pub use embedded_index_strategy::*;
 pub use export_format::*;
 pub use extras::*;
 pub use hash::*;
+pub use index_unavailable::*;
 pub use install_options::*;
 pub use name_specifiers::*;
 pub use overrides::*;
@@ -22,18 +26,23 @@ pub use target_triple::*;
 pub use trusted_host::*;
 pub use trusted_publishing::*;
 pub use vcs::*;
+pub use wheel_optimization::*;
 
 mod authentication;
+mod build_env;
 mod build_options;
+mod byte_size;
 mod concurrency;
 mod config_settings;
 mod constraints;
 mod dev;
 mod dry_run;
 mod editable;
+mod embedded_index_strategy;
 mod export_format;
 mod extras;
 mod hash;
+mod index_unavailable;
 mod install_options;
 mod name_specifiers;
 mod overrides;
@@ -47,3 +56,4 @@ mod target_triple;
 mod trusted_host;
 mod trusted_publishing;
 mod vcs;
+mod wheel_optimization;
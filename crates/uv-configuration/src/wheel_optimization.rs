@@ -0,0 +1,20 @@
+/// An optimization to apply to wheels after they're built from a source distribution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum WheelOptimization {
+    /// Recompress the wheel using the highest deflate compression level.
+    Recompress,
+    /// Strip debug symbols from any shared libraries bundled in the wheel.
+    Strip,
+}
+
+impl std::fmt::Display for WheelOptimization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Recompress => write!(f, "recompress"),
+            Self::Strip => write!(f, "strip"),
+        }
+    }
+}
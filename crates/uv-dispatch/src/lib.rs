@@ -3,7 +3,7 @@
 //! implementing [`BuildContext`].
 
 use std::ffi::{OsStr, OsString};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use futures::FutureExt;
@@ -12,12 +12,12 @@ use rustc_hash::FxHashMap;
 use thiserror::Error;
 use tracing::{debug, instrument, trace};
 use uv_build_backend::check_direct_build;
-use uv_build_frontend::{SourceBuild, SourceBuildContext};
+use uv_build_frontend::{CyclicBuildDependencyError, SourceBuild, SourceBuildContext};
 use uv_cache::Cache;
 use uv_client::RegistryClient;
 use uv_configuration::{
-    BuildKind, BuildOptions, ConfigSettings, Constraints, IndexStrategy, PreviewMode, Reinstall,
-    SourceStrategy,
+    BuildKind, BuildOptions, ConfigSettings, Constraints, IndexStrategy, PackageBuildEnv,
+    PackageConfigSettings, PreviewMode, Reinstall, SourceStrategy, WheelOptimization,
 };
 use uv_configuration::{BuildOutput, Concurrency};
 use uv_distribution::DistributionDatabase;
@@ -31,8 +31,8 @@ use uv_installer::{Installer, Plan, Planner, Preparer, SitePackages};
 use uv_pypi_types::{Conflicts, Requirement};
 use uv_python::{Interpreter, PythonEnvironment};
 use uv_resolver::{
-    ExcludeNewer, FlatIndex, Flexibility, InMemoryIndex, Manifest, OptionsBuilder,
-    PythonRequirement, Resolver, ResolverEnvironment,
+    ExcludeNewer, ExcludeNewerPackage, FlatIndex, Flexibility, InMemoryIndex, Manifest,
+    OptionsBuilder, PythonRequirement, Resolver, ResolverEnvironment,
 };
 use uv_types::{
     AnyErrorBuild, BuildContext, BuildIsolation, BuildStack, EmptyInstalledPackages, HashStrategy,
@@ -89,10 +89,15 @@ pub struct BuildDispatch<'a> {
     link_mode: uv_install_wheel::LinkMode,
     build_options: &'a BuildOptions,
     config_settings: &'a ConfigSettings,
+    config_settings_package: &'a PackageConfigSettings,
+    build_env: &'a PackageBuildEnv,
     hasher: &'a HashStrategy,
     exclude_newer: Option<ExcludeNewer>,
+    exclude_newer_package: ExcludeNewerPackage,
     source_build_context: SourceBuildContext,
     build_extra_env_vars: FxHashMap<OsString, OsString>,
+    build_log_dir: Option<PathBuf>,
+    wheel_optimizations: Vec<WheelOptimization>,
     sources: SourceStrategy,
     concurrency: Concurrency,
     preview: PreviewMode,
@@ -110,6 +115,8 @@ impl<'a> BuildDispatch<'a> {
         shared_state: SharedState,
         index_strategy: IndexStrategy,
         config_settings: &'a ConfigSettings,
+        config_settings_package: &'a PackageConfigSettings,
+        build_env: &'a PackageBuildEnv,
         build_isolation: BuildIsolation<'a>,
         link_mode: uv_install_wheel::LinkMode,
         build_options: &'a BuildOptions,
@@ -130,13 +137,18 @@ impl<'a> BuildDispatch<'a> {
             dependency_metadata,
             index_strategy,
             config_settings,
+            config_settings_package,
+            build_env,
             build_isolation,
             link_mode,
             build_options,
             hasher,
             exclude_newer,
-            source_build_context: SourceBuildContext::default(),
+            exclude_newer_package: ExcludeNewerPackage::default(),
+            source_build_context: SourceBuildContext::new(concurrency.builds),
             build_extra_env_vars: FxHashMap::default(),
+            build_log_dir: None,
+            wheel_optimizations: Vec::new(),
 
             sources,
             concurrency,
@@ -158,6 +170,30 @@ impl<'a> BuildDispatch<'a> {
             .collect();
         self
     }
+
+    /// Set the directory in which to capture the output of each source distribution build.
+    #[must_use]
+    pub fn with_build_log_dir(mut self, build_log_dir: Option<PathBuf>) -> Self {
+        self.build_log_dir = build_log_dir;
+        self
+    }
+
+    /// Set the optimizations to apply to wheels after they're built from a source distribution.
+    #[must_use]
+    pub fn with_wheel_optimizations(mut self, wheel_optimizations: Vec<WheelOptimization>) -> Self {
+        self.wheel_optimizations = wheel_optimizations;
+        self
+    }
+
+    /// Set the per-package upload-time cutoffs to apply when resolving build dependencies.
+    #[must_use]
+    pub fn with_exclude_newer_package(
+        mut self,
+        exclude_newer_package: ExcludeNewerPackage,
+    ) -> Self {
+        self.exclude_newer_package = exclude_newer_package;
+        self
+    }
 }
 
 #[allow(refining_impl_trait)]
@@ -192,6 +228,22 @@ impl BuildContext for BuildDispatch<'_> {
         self.config_settings
     }
 
+    fn config_settings_package(&self) -> &PackageConfigSettings {
+        self.config_settings_package
+    }
+
+    fn build_env(&self) -> &PackageBuildEnv {
+        self.build_env
+    }
+
+    fn build_log_dir(&self) -> Option<&Path> {
+        self.build_log_dir.as_deref()
+    }
+
+    fn wheel_optimizations(&self) -> &[WheelOptimization] {
+        &self.wheel_optimizations
+    }
+
     fn sources(&self) -> SourceStrategy {
         self.sources
     }
@@ -213,6 +265,7 @@ impl BuildContext for BuildDispatch<'_> {
             Manifest::simple(requirements.to_vec()).with_constraints(self.constraints.clone()),
             OptionsBuilder::new()
                 .exclude_newer(self.exclude_newer)
+                .exclude_newer_package(self.exclude_newer_package.clone())
                 .index_strategy(self.index_strategy)
                 .build_options(self.build_options.clone())
                 .flexibility(Flexibility::Fixed)
@@ -282,6 +335,9 @@ impl BuildContext for BuildDispatch<'_> {
             self.hasher,
             self.index_locations,
             self.config_settings,
+            self.config_settings_package,
+            self.build_env,
+            &self.wheel_optimizations,
             self.cache(),
             venv,
             tags,
@@ -296,9 +352,9 @@ impl BuildContext for BuildDispatch<'_> {
         // Verify that none of the missing distributions are already in the build stack.
         for dist in &remote {
             let id = dist.distribution_id();
-            if build_stack.contains(&id) {
+            if let Some(cycle) = build_stack.cycle(&id, dist.name()) {
                 return Err(BuildDispatchError::BuildFrontend(
-                    uv_build_frontend::Error::CyclicBuildDependency(dist.name().clone()).into(),
+                    uv_build_frontend::Error::from(CyclicBuildDependencyError::new(cycle)).into(),
                 ));
             }
         }
@@ -400,11 +456,18 @@ impl BuildContext for BuildDispatch<'_> {
             return Err(err);
         }
 
-        // Push the current distribution onto the build stack, to prevent cyclic dependencies.
+        // Push the current distribution onto the build stack, to prevent cyclic dependencies. If
+        // the distribution is already being built further up the stack, building it again would
+        // recurse indefinitely.
         if let Some(dist) = dist {
-            build_stack.insert(dist.distribution_id());
+            let id = dist.distribution_id();
+            if let Some(cycle) = build_stack.cycle(&id, dist.name()) {
+                return Err(CyclicBuildDependencyError::new(cycle).into());
+            }
+            build_stack.insert(id, dist.name().clone());
         }
 
+        uv_client::record_build();
         let builder = SourceBuild::setup(
             source,
             subdirectory,
@@ -423,7 +486,6 @@ impl BuildContext for BuildDispatch<'_> {
             build_kind,
             self.build_extra_env_vars.clone(),
             build_output,
-            self.concurrency.builds,
         )
         .boxed_local()
         .await?;
@@ -68,7 +68,8 @@ impl SourceDistExtension {
             return Err(ExtensionError::SourceDist);
         };
 
-        match extension {
+        // Legacy source distributions occasionally use uppercase extensions (e.g., `.ZIP`).
+        match extension.to_ascii_lowercase().as_str() {
             "zip" => Ok(Self::Zip),
             "tar" => Ok(Self::Tar),
             "tgz" => Ok(Self::TarGz),
@@ -38,14 +38,12 @@ impl SourceDistFilename {
         package_name: &PackageName,
     ) -> Result<Self, SourceDistFilenameError> {
         // Drop the extension (e.g., given `tar.gz`, drop `.tar.gz`).
-        if filename.len() <= extension.name().len() + 1 {
+        let Some(stem) = strip_extension(filename, extension) else {
             return Err(SourceDistFilenameError {
                 filename: filename.to_string(),
                 kind: SourceDistFilenameErrorKind::Extension,
             });
-        }
-
-        let stem = &filename[..(filename.len() - (extension.name().len() + 1))];
+        };
 
         if stem.len() <= package_name.as_ref().len() + "-".len() {
             return Err(SourceDistFilenameError {
@@ -94,14 +92,12 @@ impl SourceDistFilename {
         };
 
         // Drop the extension (e.g., given `tar.gz`, drop `.tar.gz`).
-        if filename.len() <= extension.name().len() + 1 {
+        let Some(stem) = strip_extension(filename, extension) else {
             return Err(SourceDistFilenameError {
                 filename: filename.to_string(),
                 kind: SourceDistFilenameErrorKind::Extension,
             });
-        }
-
-        let stem = &filename[..(filename.len() - (extension.name().len() + 1))];
+        };
 
         let Some((package_name, version)) = stem.rsplit_once('-') else {
             return Err(SourceDistFilenameError {
@@ -129,6 +125,33 @@ impl SourceDistFilename {
     }
 }
 
+/// Strip the extension from a source distribution filename, accounting for both the long (e.g.,
+/// `.tar.gz`) and short (e.g., `.tgz`) forms of each extension.
+///
+/// Returns `None` if nothing would remain after stripping the extension.
+fn strip_extension(filename: &str, extension: SourceDistExtension) -> Option<&str> {
+    let (stem, _) = filename.rsplit_once('.')?;
+    let stem = match extension {
+        SourceDistExtension::Zip | SourceDistExtension::Tar => stem,
+        SourceDistExtension::TarGz
+        | SourceDistExtension::TarBz2
+        | SourceDistExtension::TarXz
+        | SourceDistExtension::TarZst
+        | SourceDistExtension::TarLzma => {
+            let split = stem.len().saturating_sub(".tar".len());
+            match stem.get(split..) {
+                Some(suffix) if suffix.eq_ignore_ascii_case(".tar") => &stem[..split],
+                _ => stem,
+            }
+        }
+    };
+    if stem.is_empty() {
+        None
+    } else {
+        Some(stem)
+    }
+}
+
 impl Display for SourceDistFilename {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -206,6 +229,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn legacy_extensions() {
+        for (filename, extension) in [
+            ("foo_lib-1.2.3.tgz", SourceDistExtension::TarGz),
+            ("foo_lib-1.2.3.tbz", SourceDistExtension::TarBz2),
+            ("foo_lib-1.2.3.txz", SourceDistExtension::TarXz),
+            ("foo_lib-1.2.3.tar.xz", SourceDistExtension::TarXz),
+            ("foo_lib-1.2.3.tar.lz", SourceDistExtension::TarLzma),
+            ("foo_lib-1.2.3.ZIP", SourceDistExtension::Zip),
+            ("foo_lib-1.2.3.TAR.BZ2", SourceDistExtension::TarBz2),
+        ] {
+            let ext = SourceDistExtension::from_path(filename).unwrap();
+            assert_eq!(ext, extension, "{filename}");
+
+            let parsed = SourceDistFilename::parse(
+                filename,
+                ext,
+                &PackageName::from_str("foo_lib").unwrap(),
+            )
+            .unwrap();
+            assert_eq!(parsed.version.to_string(), "1.2.3", "{filename}");
+
+            let parsed = SourceDistFilename::parsed_normalized_filename(filename).unwrap();
+            assert_eq!(parsed.name.as_ref(), "foo-lib", "{filename}");
+            assert_eq!(parsed.version.to_string(), "1.2.3", "{filename}");
+        }
+    }
+
     #[test]
     fn errors() {
         for invalid in ["b-1.2.3.zip", "a-1.2.3-gamma.3.zip"] {
@@ -19,6 +19,12 @@ pub trait IsBuildBackendError: std::error::Error + Send + Sync + 'static {
     fn is_build_backend_error(&self) -> bool;
 }
 
+impl IsBuildBackendError for std::convert::Infallible {
+    fn is_build_backend_error(&self) -> bool {
+        match *self {}
+    }
+}
+
 /// The operation(s) that failed when reporting an error with a distribution.
 #[derive(Debug)]
 pub enum DistErrorKind {
@@ -82,6 +82,62 @@ pub struct Index {
     /// publish-url = "https://upload.pypi.org/legacy/"
     /// ```
     pub publish_url: Option<Url>,
+    /// The caching policy to apply to responses from the index.
+    ///
+    /// By default, uv respects the `Cache-Control` headers returned by the index. For example, to
+    /// consider the package metadata served by an internal mirror fresh for an hour, regardless of
+    /// the headers it returns:
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.internal.example.com/simple"
+    /// cache = { metadata-ttl = 3600 }
+    /// ```
+    #[serde(default)]
+    pub cache: IndexCacheControl,
+    /// A service from which to fetch the hashes of the distributions resolved from the index,
+    /// rather than trusting the digests reported by the index itself (e.g., an internal
+    /// attestation or TUF service).
+    ///
+    /// When generating hashes with `uv pip compile --generate-hashes`, uv will request the hashes
+    /// for each pinned version from the provider, replacing any hashes reported by the index:
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.internal.example.com/simple"
+    /// hash-provider = { url = "https://attest.internal.example.com/{name}/{version}" }
+    /// ```
+    pub hash_provider: Option<IndexHashProvider>,
+}
+
+/// The caching policy for an [`Index`].
+#[derive(Debug, Default, Clone, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct IndexCacheControl {
+    /// The number of seconds for which package metadata (i.e., the Simple API response for a
+    /// package) fetched from the index is considered fresh.
+    ///
+    /// When set, this overrides the freshness lifetime advertised by the index's `Cache-Control`
+    /// headers. Within the lifetime, uv will reuse the cached response without contacting the
+    /// index; once it expires, uv will send a conditional request to revalidate it.
+    pub metadata_ttl: Option<u64>,
+}
+
+/// A service that provides the hashes for the distributions resolved from an [`Index`].
+#[derive(Debug, Clone, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct IndexHashProvider {
+    /// The URL from which to fetch the hashes for a given package version.
+    ///
+    /// The `{name}` and `{version}` placeholders are replaced with the normalized package name and
+    /// the pinned version, respectively. The provider is expected to respond with a JSON object
+    /// listing the hashes of every distribution for the version, as in
+    /// `{"hashes": ["sha256:..."]}`.
+    pub url: String,
 }
 
 // #[derive(
@@ -106,6 +162,8 @@ impl Index {
             default: true,
             origin: None,
             publish_url: None,
+            cache: IndexCacheControl::default(),
+            hash_provider: None,
         }
     }
 
@@ -118,6 +176,8 @@ impl Index {
             default: false,
             origin: None,
             publish_url: None,
+            cache: IndexCacheControl::default(),
+            hash_provider: None,
         }
     }
 
@@ -130,6 +190,8 @@ impl Index {
             default: false,
             origin: None,
             publish_url: None,
+            cache: IndexCacheControl::default(),
+            hash_provider: None,
         }
     }
 
@@ -216,6 +278,8 @@ impl FromStr for Index {
                     default: false,
                     origin: None,
                     publish_url: None,
+                    cache: IndexCacheControl::default(),
+                    hash_provider: None,
                 });
             }
         }
@@ -229,6 +293,8 @@ impl FromStr for Index {
             default: false,
             origin: None,
             publish_url: None,
+            cache: IndexCacheControl::default(),
+            hash_provider: None,
         })
     }
 }
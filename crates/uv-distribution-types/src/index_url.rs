@@ -4,6 +4,7 @@ use std::ops::Deref;
 use std::path::Path;
 use std::str::FromStr;
 use std::sync::{Arc, LazyLock, RwLock};
+use std::time::Duration;
 
 use itertools::Either;
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -12,7 +13,7 @@ use url::{ParseError, Url};
 
 use uv_pep508::{split_scheme, Scheme, VerbatimUrl, VerbatimUrlError};
 
-use crate::{Index, Verbatim};
+use crate::{Index, IndexHashProvider, Verbatim};
 
 static PYPI_URL: LazyLock<Url> = LazyLock::new(|| Url::parse("https://pypi.org/simple").unwrap());
 
@@ -425,6 +426,12 @@ impl<'a> IndexUrls {
         }
     }
 
+    /// Returns `true` if the given [`IndexUrl`] is the default index.
+    pub fn is_default(&'a self, index_url: &IndexUrl) -> bool {
+        self.default_index()
+            .is_some_and(|index| index.url == *index_url)
+    }
+
     /// Return an iterator over the implicit [`Index`] entries.
     ///
     /// Default and explicit indexes are excluded.
@@ -492,6 +499,25 @@ impl<'a> IndexUrls {
             )
         }
     }
+
+    /// Return the configured freshness lifetime for package metadata fetched from the given
+    /// [`IndexUrl`], if any.
+    pub fn metadata_ttl(&self, index_url: &IndexUrl) -> Option<Duration> {
+        self.indexes
+            .iter()
+            .find(|index| index.url == *index_url)
+            .and_then(|index| index.cache.metadata_ttl)
+            .map(Duration::from_secs)
+    }
+
+    /// Return the configured [`IndexHashProvider`] for distributions resolved from the given
+    /// [`IndexUrl`], if any.
+    pub fn hash_provider(&self, index_url: &IndexUrl) -> Option<&IndexHashProvider> {
+        self.indexes
+            .iter()
+            .find(|index| index.url == *index_url)
+            .and_then(|index| index.hash_provider.as_ref())
+    }
 }
 
 bitflags::bitflags! {
@@ -503,6 +529,8 @@ bitflags::bitflags! {
         const UNAUTHORIZED      = 1 << 2;
         /// Whether the index returned a `403 Forbidden` status code.
         const FORBIDDEN         = 1 << 1;
+        /// Whether the index could not be reached.
+        const UNAVAILABLE       = 1 << 3;
     }
 }
 
@@ -572,4 +600,24 @@ impl IndexCapabilities {
             .or_insert(Flags::empty())
             .insert(Flags::FORBIDDEN);
     }
+
+    /// Returns `true` if the given [`IndexUrl`] could not be reached.
+    pub fn unavailable(&self, index_url: &IndexUrl) -> bool {
+        self.0
+            .read()
+            .unwrap()
+            .get(index_url)
+            .is_some_and(|flags| flags.intersects(Flags::UNAVAILABLE))
+    }
+
+    /// Mark an [`IndexUrl`] as unreachable, such that it's skipped for the remainder of the
+    /// operation.
+    pub fn set_unavailable(&self, index_url: IndexUrl) {
+        self.0
+            .write()
+            .unwrap()
+            .entry(index_url)
+            .or_insert(Flags::empty())
+            .insert(Flags::UNAVAILABLE);
+    }
 }
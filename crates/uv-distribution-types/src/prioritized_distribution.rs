@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 
 use arcstr::ArcStr;
@@ -125,9 +126,11 @@ impl IncompatibleDist {
                 },
                 IncompatibleWheel::RequiresPython(..) => format!("requires {self}"),
                 IncompatibleWheel::MissingPlatform(_) => format!("has {self}"),
+                IncompatibleWheel::MissingHash => format!("has {self}"),
             },
             Self::Source(incompatibility) => match incompatibility {
                 IncompatibleSource::NoBuild => format!("has {self}"),
+                IncompatibleSource::MissingHash => format!("has {self}"),
                 IncompatibleSource::Yanked(_) => format!("was {self}"),
                 IncompatibleSource::ExcludeNewer(ts) => match ts {
                     Some(_) => format!("was {self}"),
@@ -153,9 +156,11 @@ impl IncompatibleDist {
                 },
                 IncompatibleWheel::RequiresPython(..) => format!("require {self}"),
                 IncompatibleWheel::MissingPlatform(_) => format!("have {self}"),
+                IncompatibleWheel::MissingHash => format!("have {self}"),
             },
             Self::Source(incompatibility) => match incompatibility {
                 IncompatibleSource::NoBuild => format!("have {self}"),
+                IncompatibleSource::MissingHash => format!("have {self}"),
                 IncompatibleSource::Yanked(_) => format!("were {self}"),
                 IncompatibleSource::ExcludeNewer(ts) => match ts {
                     Some(_) => format!("were {self}"),
@@ -198,6 +203,7 @@ impl IncompatibleDist {
                 IncompatibleWheel::ExcludeNewer(..) => None,
                 IncompatibleWheel::RequiresPython(..) => None,
                 IncompatibleWheel::MissingPlatform(..) => None,
+                IncompatibleWheel::MissingHash => None,
             },
             Self::Source(..) => None,
             Self::Unavailable => None,
@@ -247,9 +253,13 @@ impl Display for IncompatibleDist {
                         write!(f, "no compatible wheels")
                     }
                 }
+                IncompatibleWheel::MissingHash => f.write_str("no wheels with a hash"),
             },
             Self::Source(incompatibility) => match incompatibility {
                 IncompatibleSource::NoBuild => f.write_str("no usable wheels"),
+                IncompatibleSource::MissingHash => {
+                    f.write_str("no usable distributions with a hash")
+                }
                 IncompatibleSource::Yanked(yanked) => match yanked {
                     Yanked::Bool(_) => f.write_str("yanked"),
                     Yanked::Reason(reason) => write!(
@@ -301,6 +311,8 @@ pub enum IncompatibleWheel {
     NoBinary,
     /// Wheels are not available for the current platform.
     MissingPlatform(MarkerTree),
+    /// The index did not provide a hash for the wheel, and hashes are required.
+    MissingHash,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -314,6 +326,8 @@ pub enum IncompatibleSource {
     ExcludeNewer(Option<i64>),
     RequiresPython(VersionSpecifiers, PythonRequirementKind),
     Yanked(Yanked),
+    /// The index did not provide a hash for the source distribution, and hashes are required.
+    MissingHash,
     NoBuild,
 }
 
@@ -383,6 +397,42 @@ impl PrioritizedDist {
         self.0.wheels.push((dist, compatibility));
     }
 
+    /// Re-select the highest-priority wheel, ranking compatible wheels by the given comparison
+    /// ahead of their tag priority and build tag.
+    ///
+    /// A wheel with a better hash comparison is always preferred, and incompatible wheels are
+    /// never selected over compatible ones.
+    pub fn prefer_wheels(
+        &mut self,
+        compare: impl Fn(&RegistryBuiltWheel, &RegistryBuiltWheel) -> Ordering,
+    ) {
+        let Some(mut best) = self.0.best_wheel_index else {
+            return;
+        };
+        for (index, (wheel, compatibility)) in self.0.wheels.iter().enumerate() {
+            let WheelCompatibility::Compatible(hash, tag_priority, build_tag) = compatibility
+            else {
+                continue;
+            };
+            let (
+                best_wheel,
+                WheelCompatibility::Compatible(best_hash, best_tag_priority, best_build_tag),
+            ) = &self.0.wheels[best]
+            else {
+                // The best wheel is incompatible, so no wheel is compatible.
+                return;
+            };
+            let ordering = hash
+                .cmp(best_hash)
+                .then_with(|| compare(wheel, best_wheel))
+                .then_with(|| (tag_priority, build_tag).cmp(&(best_tag_priority, best_build_tag)));
+            if ordering.is_gt() {
+                best = index;
+            }
+        }
+        self.0.best_wheel_index = Some(best);
+    }
+
     /// Insert the given source distribution into the [`PrioritizedDist`].
     pub fn insert_source(
         &mut self,
@@ -675,18 +725,28 @@ impl IncompatibleSource {
             Self::ExcludeNewer(timestamp_self) => match other {
                 // Smaller timestamps are closer to the cut-off time
                 Self::ExcludeNewer(timestamp_other) => timestamp_other < timestamp_self,
-                Self::NoBuild | Self::RequiresPython(_, _) | Self::Yanked(_) => true,
+                Self::NoBuild
+                | Self::RequiresPython(_, _)
+                | Self::Yanked(_)
+                | Self::MissingHash => true,
             },
             Self::RequiresPython(_, _) => match other {
                 Self::ExcludeNewer(_) => false,
                 // Version specifiers cannot be reasonably compared
                 Self::RequiresPython(_, _) => false,
-                Self::NoBuild | Self::Yanked(_) => true,
+                Self::NoBuild | Self::Yanked(_) | Self::MissingHash => true,
             },
             Self::Yanked(_) => match other {
                 Self::ExcludeNewer(_) | Self::RequiresPython(_, _) => false,
                 // Yanks with a reason are more helpful for errors
                 Self::Yanked(yanked_other) => matches!(yanked_other, Yanked::Reason(_)),
+                Self::NoBuild | Self::MissingHash => true,
+            },
+            Self::MissingHash => match other {
+                Self::ExcludeNewer(_)
+                | Self::RequiresPython(_, _)
+                | Self::Yanked(_)
+                | Self::MissingHash => false,
                 Self::NoBuild => true,
             },
             Self::NoBuild => false,
@@ -708,6 +768,7 @@ impl IncompatibleWheel {
                     }
                 },
                 Self::MissingPlatform(_)
+                | Self::MissingHash
                 | Self::NoBinary
                 | Self::RequiresPython(_, _)
                 | Self::Tag(_)
@@ -717,6 +778,7 @@ impl IncompatibleWheel {
                 Self::ExcludeNewer(_) => false,
                 Self::Tag(tag_other) => tag_self > tag_other,
                 Self::MissingPlatform(_)
+                | Self::MissingHash
                 | Self::NoBinary
                 | Self::RequiresPython(_, _)
                 | Self::Yanked(_) => true,
@@ -725,19 +787,30 @@ impl IncompatibleWheel {
                 Self::ExcludeNewer(_) | Self::Tag(_) => false,
                 // Version specifiers cannot be reasonably compared
                 Self::RequiresPython(_, _) => false,
-                Self::MissingPlatform(_) | Self::NoBinary | Self::Yanked(_) => true,
+                Self::MissingPlatform(_) | Self::MissingHash | Self::NoBinary | Self::Yanked(_) => {
+                    true
+                }
             },
             Self::Yanked(_) => match other {
                 Self::ExcludeNewer(_) | Self::Tag(_) | Self::RequiresPython(_, _) => false,
                 // Yanks with a reason are more helpful for errors
                 Self::Yanked(yanked_other) => matches!(yanked_other, Yanked::Reason(_)),
+                Self::MissingPlatform(_) | Self::MissingHash | Self::NoBinary => true,
+            },
+            Self::MissingHash => match other {
+                Self::ExcludeNewer(_)
+                | Self::Tag(_)
+                | Self::RequiresPython(_, _)
+                | Self::Yanked(_)
+                | Self::MissingHash => false,
                 Self::MissingPlatform(_) | Self::NoBinary => true,
             },
             Self::NoBinary => match other {
                 Self::ExcludeNewer(_)
                 | Self::Tag(_)
                 | Self::RequiresPython(_, _)
-                | Self::Yanked(_) => false,
+                | Self::Yanked(_)
+                | Self::MissingHash => false,
                 Self::NoBinary => false,
                 Self::MissingPlatform(_) => true,
             },
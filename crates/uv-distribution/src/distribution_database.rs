@@ -1,6 +1,6 @@
 use std::future::Future;
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
@@ -25,12 +25,15 @@ use uv_distribution_types::{
 };
 use uv_extract::hash::Hasher;
 use uv_fs::write_atomic;
+use uv_metadata::read_metadata_async_seek;
 use uv_platform_tags::Tags;
-use uv_pypi_types::{HashDigest, HashDigests};
+use uv_pypi_types::{HashAlgorithm, HashDigest, HashDigests, ResolutionMetadata};
 use uv_types::{BuildContext, BuildStack};
 
 use crate::archive::Archive;
+use crate::ingest;
 use crate::metadata::{ArchiveMetadata, Metadata};
+use crate::metadata_store;
 use crate::source::SourceDistributionBuilder;
 use crate::{Error, LocalWheel, Reporter, RequiresDist};
 
@@ -175,6 +178,23 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         dist: &BuiltDist,
         hashes: HashPolicy<'_>,
     ) -> Result<LocalWheel, Error> {
+        // If the wheel was added to the cache via `uv cache add`, reuse it, wherever it's
+        // being fetched from.
+        if let Some(digest) = ingest::known_sha256(dist.file(), hashes) {
+            let filename = wheel_filename(dist);
+            if let Some(archive) =
+                ingest::ingested_wheel(self.build_context.cache(), digest, filename, hashes)?
+            {
+                return Ok(LocalWheel {
+                    dist: Dist::Built(dist.clone()),
+                    archive: self.build_context.cache().archive(&archive.id),
+                    hashes: archive.hashes,
+                    filename: filename.clone(),
+                    cache: CacheInfo::default(),
+                });
+            }
+        }
+
         match dist {
             BuiltDist::Registry(wheels) => {
                 let wheel = wheels.best_wheel();
@@ -410,6 +430,43 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             return Ok(ArchiveMetadata::from_metadata23(metadata.clone()));
         }
 
+        // If the wheel was added to the cache via `uv cache add`, use its registered metadata.
+        if let Some(digest) = ingest::known_sha256(dist.file(), hashes) {
+            if let Some(metadata) = ingest::ingested_wheel_metadata(
+                self.build_context.cache(),
+                digest,
+                wheel_filename(dist),
+            )? {
+                return Ok(ArchiveMetadata {
+                    metadata: Metadata::from_metadata23(metadata),
+                    hashes: HashDigests::from(HashDigest {
+                        algorithm: HashAlgorithm::Sha256,
+                        digest: digest.into(),
+                    }),
+                    cached: true,
+                });
+            }
+        }
+
+        // If the metadata for this version was fetched from the same index before (e.g., for a
+        // wheel built for another platform), reuse it.
+        if let BuiltDist::Registry(wheels) = dist {
+            if !hashes.is_generate(dist) {
+                if let Some(metadata) = metadata_store::read(
+                    self.build_context.cache(),
+                    &wheels.best_wheel().index,
+                    dist.name(),
+                    dist.version(),
+                )? {
+                    return Ok(ArchiveMetadata {
+                        metadata: Metadata::from_metadata23(metadata),
+                        hashes: HashDigests::empty(),
+                        cached: true,
+                    });
+                }
+            }
+        }
+
         // If hash generation is enabled, and the distribution isn't hosted on a registry, get the
         // entire wheel to ensure that the hashes are included in the response. If the distribution
         // is hosted on an index, the hashes will be included in the simple metadata response.
@@ -425,12 +482,21 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         //
         // TODO(charlie): Request the hashes via a separate method, to reduce the coupling in this API.
         if hashes.is_generate(dist) {
+            // If the wheel is available locally, hash it in place and read the metadata from its
+            // central directory, rather than unzipping the entire wheel into the cache.
+            if let Some((path, filename)) = local_wheel_path(dist)? {
+                return self
+                    .read_local_wheel_metadata(&path, filename, hashes)
+                    .await;
+            }
+
             let wheel = self.get_wheel(dist, hashes).await?;
             let metadata = wheel.metadata()?;
             let hashes = wheel.hashes;
             return Ok(ArchiveMetadata {
                 metadata: Metadata::from_metadata23(metadata),
                 hashes,
+                cached: false,
             });
         }
 
@@ -445,6 +511,18 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
         match result {
             Ok(metadata) => {
+                // Register the metadata for reuse by any other wheel of the same version.
+                if let BuiltDist::Registry(wheels) = dist {
+                    metadata_store::write(
+                        self.build_context.cache(),
+                        &wheels.best_wheel().index,
+                        dist.name(),
+                        dist.version(),
+                        &metadata,
+                    )
+                    .await?;
+                }
+
                 // Validate that the metadata is consistent with the distribution.
                 Ok(ArchiveMetadata::from_metadata23(metadata))
             }
@@ -459,6 +537,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 Ok(ArchiveMetadata {
                     metadata: Metadata::from_metadata23(metadata),
                     hashes,
+                    cached: false,
                 })
             }
             Err(err) => Err(err.into()),
@@ -489,6 +568,28 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             }
         }
 
+        // If the source distribution was added to the cache via `uv cache add` with static
+        // metadata, use it.
+        if let BuildableSource::Dist(SourceDist::Registry(dist)) = source {
+            if let Some(digest) = ingest::known_sha256(Some(&dist.file), hashes) {
+                if let Some(metadata) = ingest::ingested_source_dist_metadata(
+                    self.build_context.cache(),
+                    digest,
+                    &dist.name,
+                    dist.ext,
+                )? {
+                    return Ok(ArchiveMetadata {
+                        metadata: Metadata::from_metadata23(metadata),
+                        hashes: HashDigests::from(HashDigest {
+                            algorithm: HashAlgorithm::Sha256,
+                            digest: digest.into(),
+                        }),
+                        cached: true,
+                    });
+                }
+            }
+        }
+
         let metadata = self
             .builder
             .download_and_build_metadata(source, hashes, &self.client)
@@ -530,6 +631,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
         let download = |response: reqwest::Response| {
             async {
+                let expected = size;
                 let size = size.or_else(|| content_length(&response));
 
                 let progress = self
@@ -542,28 +644,32 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .map_err(|err| self.handle_response_errors(err))
                     .into_async_read();
 
+                // Verify the download against the size declared by the index, if any.
+                let mut reader = SizeReader::new(reader.compat(), expected);
+
                 // Create a hasher for each hash algorithm.
                 let algorithms = hashes.algorithms();
                 let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
-                let mut hasher = uv_extract::hash::HashReader::new(reader.compat(), &mut hashers);
+                let mut hasher = uv_extract::hash::HashReader::new(&mut reader, &mut hashers);
 
                 // Download and unzip the wheel to a temporary directory.
                 let temp_dir = tempfile::tempdir_in(self.build_context.cache().root())
                     .map_err(Error::CacheWrite)?;
 
-                match progress {
+                let result = match progress {
                     Some((reporter, progress)) => {
                         let mut reader = ProgressReader::new(&mut hasher, progress, &**reporter);
-                        uv_extract::stream::unzip(&mut reader, temp_dir.path()).await?;
-                    }
-                    None => {
-                        uv_extract::stream::unzip(&mut hasher, temp_dir.path()).await?;
+                        uv_extract::stream::unzip(&mut reader, temp_dir.path()).await
                     }
+                    None => uv_extract::stream::unzip(&mut hasher, temp_dir.path()).await,
+                };
+                if let Err(err) = result {
+                    return Err(reader.extract_error(err));
                 }
 
-                // If necessary, exhaust the reader to compute the hash.
-                if !hashes.is_none() {
-                    hasher.finish().await.map_err(Error::HashExhaustion)?;
+                // If necessary, exhaust the reader to compute the hash and verify the size.
+                if !hashes.is_none() || expected.is_some() {
+                    hasher.finish().await.map_err(exhaustion_error)?;
                 }
 
                 // Persist the temporary directory to the directory store.
@@ -663,6 +769,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
         let download = |response: reqwest::Response| {
             async {
+                let expected = size;
                 let size = size.or_else(|| content_length(&response));
 
                 let progress = self
@@ -675,6 +782,9 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .map_err(|err| self.handle_response_errors(err))
                     .into_async_read();
 
+                // Verify the download against the size declared by the index, if any.
+                let mut reader = SizeReader::new(reader.compat(), expected);
+
                 // Download the wheel to a temporary file.
                 let temp_file = tempfile::tempfile_in(self.build_context.cache().root())
                     .map_err(Error::CacheWrite)?;
@@ -685,17 +795,16 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                         // Wrap the reader in a progress reporter. This will report 100% progress
                         // after the download is complete, even if we still have to unzip and hash
                         // part of the file.
-                        let mut reader =
-                            ProgressReader::new(reader.compat(), progress, &**reporter);
+                        let mut reader = ProgressReader::new(reader, progress, &**reporter);
 
                         tokio::io::copy(&mut reader, &mut writer)
                             .await
-                            .map_err(Error::CacheWrite)?;
+                            .map_err(download_error)?;
                     }
                     None => {
-                        tokio::io::copy(&mut reader.compat(), &mut writer)
+                        tokio::io::copy(&mut reader, &mut writer)
                             .await
-                            .map_err(Error::CacheWrite)?;
+                            .map_err(download_error)?;
                     }
                 }
 
@@ -805,6 +914,50 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         Ok(archive)
     }
 
+    /// Read the metadata from a local wheel, computing its hashes without unzipping it.
+    async fn read_local_wheel_metadata(
+        &self,
+        path: &Path,
+        filename: &WheelFilename,
+        hashes: HashPolicy<'_>,
+    ) -> Result<ArchiveMetadata, Error> {
+        // Read the `METADATA` file via the zip central directory.
+        let file = fs_err::tokio::File::open(path)
+            .await
+            .map_err(|err| Error::LocalWheel(path.to_path_buf(), err))?;
+        let contents = read_metadata_async_seek(filename, tokio::io::BufReader::new(file))
+            .await
+            .map_err(|err| Error::WheelMetadata(path.to_path_buf(), Box::new(err)))?;
+        let metadata = ResolutionMetadata::parse_metadata(&contents).map_err(|err| {
+            Error::WheelMetadata(
+                path.to_path_buf(),
+                Box::new(uv_metadata::Error::InvalidMetadata(
+                    filename.to_string(),
+                    Box::new(err),
+                )),
+            )
+        })?;
+
+        // Stream the file through the hashers.
+        let file = fs_err::tokio::File::open(path)
+            .await
+            .map_err(|err| Error::LocalWheel(path.to_path_buf(), err))?;
+        let mut hashers = hashes
+            .algorithms()
+            .into_iter()
+            .map(Hasher::from)
+            .collect::<Vec<_>>();
+        let mut hasher = uv_extract::hash::HashReader::new(file, &mut hashers);
+        hasher.finish().await.map_err(Error::HashExhaustion)?;
+        let hashes = hashers.into_iter().map(HashDigest::from).collect();
+
+        Ok(ArchiveMetadata {
+            metadata: Metadata::from_metadata23(metadata),
+            hashes,
+            cached: false,
+        })
+    }
+
     /// Load a wheel from a local path.
     async fn load_wheel(
         &self,
@@ -1012,6 +1165,171 @@ fn content_length(response: &reqwest::Response) -> Option<u64> {
         .and_then(|val| val.parse::<u64>().ok())
 }
 
+/// Return the path to a built distribution, if it's available on the local filesystem (e.g., a
+/// path requirement, or a `--find-links` directory), along with its filename.
+fn local_wheel_path(dist: &BuiltDist) -> Result<Option<(PathBuf, &WheelFilename)>, Error> {
+    match dist {
+        BuiltDist::Path(wheel) => Ok(Some((wheel.install_path.clone(), &wheel.filename))),
+        BuiltDist::Registry(wheels) => {
+            let wheel = wheels.best_wheel();
+            let url = match &wheel.file.url {
+                FileLocation::RelativeUrl(base, url) => {
+                    uv_pypi_types::base_url_join_relative(base, url)?
+                }
+                FileLocation::AbsoluteUrl(url) => url.to_url()?,
+            };
+            if url.scheme() != "file" {
+                return Ok(None);
+            }
+            let path = url
+                .to_file_path()
+                .map_err(|()| Error::NonFileUrl(url.clone()))?;
+            Ok(Some((path, &wheel.filename)))
+        }
+        BuiltDist::DirectUrl(_) => Ok(None),
+    }
+}
+
+/// Return the filename of a built distribution.
+fn wheel_filename(dist: &BuiltDist) -> &WheelFilename {
+    match dist {
+        BuiltDist::Registry(wheels) => &wheels.best_wheel().filename,
+        BuiltDist::DirectUrl(wheel) => &wheel.filename,
+        BuiltDist::Path(wheel) => &wheel.filename,
+    }
+}
+
+/// Map an I/O error encountered while downloading a file to disk, distinguishing truncated (or
+/// oversized) downloads from failures to write to the cache.
+fn download_error(err: io::Error) -> Error {
+    if SizeMismatch::is_size_mismatch(&err) {
+        Error::SizeMismatch(err)
+    } else {
+        Error::CacheWrite(err)
+    }
+}
+
+/// Map an I/O error encountered while exhausting a reader to compute its hash, distinguishing
+/// truncated (or oversized) downloads from other failures.
+pub(crate) fn exhaustion_error(err: io::Error) -> Error {
+    if SizeMismatch::is_size_mismatch(&err) {
+        Error::SizeMismatch(err)
+    } else {
+        Error::HashExhaustion(err)
+    }
+}
+
+/// The number of bytes received for a file did not match the size declared by the index.
+#[derive(Debug, thiserror::Error)]
+#[error("Expected {expected} bytes, but received {actual}")]
+struct SizeMismatch {
+    expected: u64,
+    actual: u64,
+}
+
+impl SizeMismatch {
+    /// Returns `true` if the [`io::Error`] was caused by a [`SizeMismatch`].
+    fn is_size_mismatch(err: &io::Error) -> bool {
+        matches!(err.get_ref(), Some(inner) if inner.is::<Self>())
+    }
+}
+
+impl From<SizeMismatch> for io::Error {
+    fn from(err: SizeMismatch) -> Self {
+        // A truncated download can be retried, while an oversized download is invalid.
+        let kind = if err.actual < err.expected {
+            io::ErrorKind::UnexpectedEof
+        } else {
+            io::ErrorKind::InvalidData
+        };
+        io::Error::new(kind, err)
+    }
+}
+
+/// An asynchronous reader that verifies the number of bytes read against an expected size (e.g.,
+/// the size declared by the index, per PEP 700).
+///
+/// If the underlying reader is exhausted before the expected number of bytes are read, an
+/// [`io::ErrorKind::UnexpectedEof`] error is returned, such that the download can be retried. If
+/// more bytes than expected are read, an [`io::ErrorKind::InvalidData`] error is returned.
+pub(crate) struct SizeReader<R> {
+    reader: R,
+    expected: Option<u64>,
+    read: u64,
+    exhausted: bool,
+}
+
+impl<R> SizeReader<R> {
+    /// Create a new [`SizeReader`] that wraps another reader.
+    pub(crate) fn new(reader: R, expected: Option<u64>) -> Self {
+        Self {
+            reader,
+            expected,
+            read: 0,
+            exhausted: false,
+        }
+    }
+
+    /// Return the [`SizeMismatch`], if the underlying reader was exhausted before the expected
+    /// number of bytes were read, or if more bytes than expected were read.
+    fn mismatch(&self) -> Option<SizeMismatch> {
+        let expected = self.expected?;
+        if self.read > expected || (self.exhausted && self.read < expected) {
+            Some(SizeMismatch {
+                expected,
+                actual: self.read,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Map an error encountered while extracting an archive from this reader.
+    ///
+    /// A truncated (or oversized) download typically surfaces as an invalid archive, rather than
+    /// as the error returned by this reader, so the size mismatch is reported instead, if any.
+    pub(crate) fn extract_error(&self, err: uv_extract::Error) -> Error {
+        match self.mismatch() {
+            Some(mismatch) => Error::SizeMismatch(mismatch.into()),
+            None => Error::Extract(err),
+        }
+    }
+}
+
+impl<R> AsyncRead for SizeReader<R>
+where
+    R: AsyncRead + Unpin,
+{
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let Some(expected) = self.expected else {
+            return Pin::new(&mut self.as_mut().reader).poll_read(cx, buf);
+        };
+
+        let before = buf.filled().len();
+        match Pin::new(&mut self.as_mut().reader).poll_read(cx, buf) {
+            Poll::Ready(Ok(())) => {
+                let n = (buf.filled().len() - before) as u64;
+                self.read += n;
+                if n == 0 && buf.remaining() > 0 {
+                    self.exhausted = true;
+                }
+                if let Some(mismatch) = self.mismatch() {
+                    // Discard the bytes from this read, since a failed read must not fill the
+                    // buffer.
+                    buf.set_filled(before);
+                    return Poll::Ready(Err(mismatch.into()));
+                }
+                Poll::Ready(Ok(()))
+            }
+            poll => poll,
+        }
+    }
+}
+
 /// An asynchronous reader that reports progress as bytes are read.
 struct ProgressReader<'a, R> {
     reader: R,
@@ -1122,3 +1440,72 @@ impl LocalArchivePointer {
         CacheInfo::from_timestamp(self.timestamp)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use tokio::io::AsyncReadExt;
+
+    use uv_distribution_filename::SourceDistExtension;
+
+    use super::{SizeMismatch, SizeReader};
+    use crate::Error;
+
+    #[tokio::test]
+    async fn size_reader() {
+        // A matching size.
+        let mut reader = SizeReader::new(&b"hello world"[..], Some(11));
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).await.unwrap();
+        assert_eq!(buf, b"hello world");
+
+        // No declared size.
+        let mut reader = SizeReader::new(&b"hello world"[..], None);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).await.unwrap();
+        assert_eq!(buf, b"hello world");
+
+        // A truncated download.
+        let mut reader = SizeReader::new(&b"hello"[..], Some(11));
+        let err = reader.read_to_end(&mut Vec::new()).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        assert!(SizeMismatch::is_size_mismatch(&err));
+        assert_eq!(err.to_string(), "Expected 11 bytes, but received 5");
+
+        // An oversized download.
+        let mut reader = SizeReader::new(&b"hello world"[..], Some(5));
+        let err = reader.read_to_end(&mut Vec::new()).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(SizeMismatch::is_size_mismatch(&err));
+    }
+
+    /// A truncated source distribution should be reported as a size mismatch, rather than as an
+    /// invalid archive.
+    #[tokio::test]
+    async fn size_reader_truncated_archive() {
+        let mut writer = zip::ZipWriter::new(io::Cursor::new(Vec::new()));
+        let options =
+            zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Stored);
+        writer.start_file("foo-1.0.0/foo.py", options).unwrap();
+        writer.write_all(&[b'#'; 4096]).unwrap();
+        let archive = writer.finish().unwrap().into_inner();
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut reader = SizeReader::new(&archive[..archive.len() / 2], Some(archive.len() as u64));
+        let err =
+            uv_extract::stream::archive(&mut reader, SourceDistExtension::Zip, temp_dir.path())
+                .await
+                .unwrap_err();
+        assert!(matches!(reader.extract_error(err), Error::SizeMismatch(_)));
+
+        // An invalid archive of the expected size is reported as such.
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut reader = SizeReader::new(&b"hello world"[..], Some(11));
+        let err =
+            uv_extract::stream::archive(&mut reader, SourceDistExtension::Zip, temp_dir.path())
+                .await
+                .unwrap_err();
+        assert!(matches!(reader.extract_error(err), Error::Extract(_)));
+    }
+}
@@ -41,6 +41,8 @@ pub enum Error {
     CacheRead(#[source] std::io::Error),
     #[error("Failed to write to the distribution cache")]
     CacheWrite(#[source] std::io::Error),
+    #[error("The downloaded file does not match the size declared by the index")]
+    SizeMismatch(#[source] std::io::Error),
     #[error("Failed to deserialize cache entry")]
     CacheDecode(#[from] rmp_serde::decode::Error),
     #[error("Failed to serialize cache entry")]
@@ -80,6 +82,10 @@ pub enum Error {
     Metadata(#[from] uv_pypi_types::MetadataError),
     #[error("Failed to read metadata: `{}`", _0.user_display())]
     WheelMetadata(PathBuf, #[source] Box<uv_metadata::Error>),
+    #[error("Failed to read local wheel: `{}`", _0.user_display())]
+    LocalWheel(PathBuf, #[source] std::io::Error),
+    #[error("Failed to read local source distribution: `{}`", _0.user_display())]
+    LocalSourceDist(PathBuf, #[source] std::io::Error),
     #[error("Failed to read metadata from installed package `{0}`")]
     ReadInstalled(Box<InstalledDist>, #[source] InstalledDistError),
     #[error("Failed to read zip archive from built wheel")]
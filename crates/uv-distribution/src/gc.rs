@@ -0,0 +1,101 @@
+use std::path::Path;
+
+use tracing::debug;
+
+use uv_cache::{Cache, CacheBucket, Removal};
+use uv_client::DataWithCachePolicy;
+use uv_pypi_types::ResolutionMetadata;
+
+use crate::error::Error;
+use crate::source::METADATA;
+
+/// Remove cached distribution metadata from the cache.
+///
+/// Distribution metadata is cached in three places: the metadata for pre-built wheels (whether
+/// fetched via PEP 658 or read from the wheel itself) is stored alongside the wheels, as
+/// `{filename}.msgpack` entries that include the HTTP cache policy; the metadata for source
+/// distributions is stored alongside the built wheels, as `metadata.msgpack` entries; and the
+/// metadata for each version of a registry package is registered in the metadata store, as
+/// `{version}.msgpack` entries.
+///
+/// By default, only entries that can no longer be deserialized (e.g., due to a partial write or
+/// disk corruption) are removed. If `all` is `true`, all metadata entries are removed, such that
+/// metadata is re-fetched (or re-built) on next use, while retaining any cached archives.
+pub fn gc_metadata(cache: &Cache, all: bool) -> Result<Removal, Error> {
+    let mut removal = Removal::default();
+
+    // Remove the wheel metadata entries.
+    let bucket = cache.bucket(CacheBucket::Wheels);
+    if bucket.is_dir() {
+        for entry in walkdir::WalkDir::new(bucket) {
+            let entry = entry.map_err(Error::CacheWalk)?;
+            if !entry.file_type().is_file()
+                || entry.path().extension().is_none_or(|ext| ext != "msgpack")
+            {
+                continue;
+            }
+            if all || !is_valid_wheel_metadata(entry.path()) {
+                debug!("Removing wheel metadata: {}", entry.path().display());
+                removal += uv_cache::rm_rf(entry.path()).map_err(Error::CacheWrite)?;
+            }
+        }
+    }
+
+    // Remove the source distribution metadata entries.
+    let bucket = cache.bucket(CacheBucket::SourceDistributions);
+    if bucket.is_dir() {
+        for entry in walkdir::WalkDir::new(bucket) {
+            let entry = entry.map_err(Error::CacheWalk)?;
+            if !entry.file_type().is_file() || entry.file_name() != METADATA {
+                continue;
+            }
+            if all || !is_valid_metadata(entry.path()) {
+                debug!(
+                    "Removing source distribution metadata: {}",
+                    entry.path().display()
+                );
+                removal += uv_cache::rm_rf(entry.path()).map_err(Error::CacheWrite)?;
+            }
+        }
+    }
+
+    // Remove the metadata store entries.
+    let bucket = cache.bucket(CacheBucket::Metadata);
+    if bucket.is_dir() {
+        for entry in walkdir::WalkDir::new(bucket) {
+            let entry = entry.map_err(Error::CacheWalk)?;
+            if !entry.file_type().is_file()
+                || entry.path().extension().is_none_or(|ext| ext != "msgpack")
+            {
+                continue;
+            }
+            if all || !is_valid_metadata(entry.path()) {
+                debug!("Removing stored metadata: {}", entry.path().display());
+                removal += uv_cache::rm_rf(entry.path()).map_err(Error::CacheWrite)?;
+            }
+        }
+    }
+
+    Ok(removal)
+}
+
+/// Returns `true` if the given wheel metadata entry (including its cache policy) can be
+/// deserialized.
+fn is_valid_wheel_metadata(path: &Path) -> bool {
+    let Ok(file) = fs_err::File::open(path) else {
+        return false;
+    };
+    let Ok(cached) = DataWithCachePolicy::from_reader(file) else {
+        return false;
+    };
+    rmp_serde::from_slice::<ResolutionMetadata>(&cached.data).is_ok()
+}
+
+/// Returns `true` if the given source distribution (or metadata store) entry can be
+/// deserialized.
+fn is_valid_metadata(path: &Path) -> bool {
+    let Ok(contents) = fs_err::read(path) else {
+        return false;
+    };
+    rmp_serde::from_slice::<ResolutionMetadata>(&contents).is_ok()
+}
@@ -1,7 +1,7 @@
 use uv_cache::{Cache, CacheBucket, CacheShard, WheelCache};
 use uv_cache_info::CacheInfo;
 use uv_cache_key::cache_digest;
-use uv_configuration::ConfigSettings;
+use uv_configuration::{ConfigSettings, PackageBuildEnv, PackageConfigSettings, WheelOptimization};
 use uv_distribution_types::{
     DirectUrlSourceDist, DirectorySourceDist, GitSourceDist, Hashed, PathSourceDist,
 };
@@ -9,7 +9,9 @@ use uv_platform_tags::Tags;
 use uv_types::HashStrategy;
 
 use crate::index::cached_wheel::CachedWheel;
-use crate::source::{HttpRevisionPointer, LocalRevisionPointer, HTTP_REVISION, LOCAL_REVISION};
+use crate::source::{
+    build_env_shard, HttpRevisionPointer, LocalRevisionPointer, HTTP_REVISION, LOCAL_REVISION,
+};
 use crate::Error;
 
 /// A local index of built distributions for a specific source distribution.
@@ -19,6 +21,9 @@ pub struct BuiltWheelIndex<'a> {
     tags: &'a Tags,
     hasher: &'a HashStrategy,
     build_configuration: &'a ConfigSettings,
+    build_configuration_package: &'a PackageConfigSettings,
+    build_env: &'a PackageBuildEnv,
+    wheel_optimizations: &'a [WheelOptimization],
 }
 
 impl<'a> BuiltWheelIndex<'a> {
@@ -28,12 +33,18 @@ impl<'a> BuiltWheelIndex<'a> {
         tags: &'a Tags,
         hasher: &'a HashStrategy,
         build_configuration: &'a ConfigSettings,
+        build_configuration_package: &'a PackageConfigSettings,
+        build_env: &'a PackageBuildEnv,
+        wheel_optimizations: &'a [WheelOptimization],
     ) -> Self {
         Self {
             cache,
             tags,
             hasher,
             build_configuration,
+            build_configuration_package,
+            build_env,
+            wheel_optimizations,
         }
     }
 
@@ -63,11 +74,19 @@ impl<'a> BuiltWheelIndex<'a> {
         let cache_shard = cache_shard.shard(revision.id());
 
         // If there are build settings, we need to scope to a cache shard.
-        let cache_shard = if self.build_configuration.is_empty() {
+        let build_configuration = self
+            .build_configuration_package
+            .for_package(Some(&source_dist.name), self.build_configuration);
+        let cache_shard = if build_configuration.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(self.build_configuration))
+            cache_shard.shard(cache_digest(&*build_configuration))
         };
+        let cache_shard = build_env_shard(
+            cache_shard,
+            self.build_env.get(&source_dist.name),
+            self.wheel_optimizations,
+        );
 
         Ok(self.find(&cache_shard))
     }
@@ -100,11 +119,19 @@ impl<'a> BuiltWheelIndex<'a> {
         let cache_shard = cache_shard.shard(revision.id());
 
         // If there are build settings, we need to scope to a cache shard.
-        let cache_shard = if self.build_configuration.is_empty() {
+        let build_configuration = self
+            .build_configuration_package
+            .for_package(Some(&source_dist.name), self.build_configuration);
+        let cache_shard = if build_configuration.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(self.build_configuration))
+            cache_shard.shard(cache_digest(&*build_configuration))
         };
+        let cache_shard = build_env_shard(
+            cache_shard,
+            self.build_env.get(&source_dist.name),
+            self.wheel_optimizations,
+        );
 
         Ok(self
             .find(&cache_shard)
@@ -148,11 +175,19 @@ impl<'a> BuiltWheelIndex<'a> {
         let cache_shard = cache_shard.shard(revision.id());
 
         // If there are build settings, we need to scope to a cache shard.
-        let cache_shard = if self.build_configuration.is_empty() {
+        let build_configuration = self
+            .build_configuration_package
+            .for_package(Some(&source_dist.name), self.build_configuration);
+        let cache_shard = if build_configuration.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(self.build_configuration))
+            cache_shard.shard(cache_digest(&*build_configuration))
         };
+        let cache_shard = build_env_shard(
+            cache_shard,
+            self.build_env.get(&source_dist.name),
+            self.wheel_optimizations,
+        );
 
         Ok(self
             .find(&cache_shard)
@@ -174,11 +209,19 @@ impl<'a> BuiltWheelIndex<'a> {
         );
 
         // If there are build settings, we need to scope to a cache shard.
-        let cache_shard = if self.build_configuration.is_empty() {
+        let build_configuration = self
+            .build_configuration_package
+            .for_package(Some(&source_dist.name), self.build_configuration);
+        let cache_shard = if build_configuration.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(self.build_configuration))
+            cache_shard.shard(cache_digest(&*build_configuration))
         };
+        let cache_shard = build_env_shard(
+            cache_shard,
+            self.build_env.get(&source_dist.name),
+            self.wheel_optimizations,
+        );
 
         self.find(&cache_shard)
     }
@@ -215,6 +258,15 @@ impl<'a> BuiltWheelIndex<'a> {
             match CachedWheel::from_built_source(&wheel_dir, self.cache) {
                 None => {}
                 Some(dist_info) => {
+                    // Ignore any wheels that must be rebuilt.
+                    if matches!(
+                        self.cache
+                            .must_rebuild(&dist_info.filename.name, &wheel_dir),
+                        Ok(true)
+                    ) {
+                        continue;
+                    }
+
                     // Pick the wheel with the highest priority
                     let compatibility = dist_info.filename.compatibility(self.tags);
 
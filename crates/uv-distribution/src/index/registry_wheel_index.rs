@@ -4,7 +4,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 use uv_cache::{Cache, CacheBucket, WheelCache};
 use uv_cache_key::cache_digest;
-use uv_configuration::ConfigSettings;
+use uv_configuration::{ConfigSettings, PackageBuildEnv, PackageConfigSettings, WheelOptimization};
 use uv_distribution_types::{CachedRegistryDist, Hashed, Index, IndexLocations, IndexUrl};
 use uv_fs::{directories, files};
 use uv_normalize::PackageName;
@@ -12,7 +12,9 @@ use uv_platform_tags::Tags;
 use uv_types::HashStrategy;
 
 use crate::index::cached_wheel::CachedWheel;
-use crate::source::{HttpRevisionPointer, LocalRevisionPointer, HTTP_REVISION, LOCAL_REVISION};
+use crate::source::{
+    build_env_shard, HttpRevisionPointer, LocalRevisionPointer, HTTP_REVISION, LOCAL_REVISION,
+};
 
 /// An entry in the [`RegistryWheelIndex`].
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -34,6 +36,9 @@ pub struct RegistryWheelIndex<'a> {
     hasher: &'a HashStrategy,
     index: FxHashMap<&'a PackageName, Vec<IndexEntry<'a>>>,
     build_configuration: &'a ConfigSettings,
+    build_configuration_package: &'a PackageConfigSettings,
+    build_env: &'a PackageBuildEnv,
+    wheel_optimizations: &'a [WheelOptimization],
 }
 
 impl<'a> RegistryWheelIndex<'a> {
@@ -44,6 +49,9 @@ impl<'a> RegistryWheelIndex<'a> {
         index_locations: &'a IndexLocations,
         hasher: &'a HashStrategy,
         build_configuration: &'a ConfigSettings,
+        build_configuration_package: &'a PackageConfigSettings,
+        build_env: &'a PackageBuildEnv,
+        wheel_optimizations: &'a [WheelOptimization],
     ) -> Self {
         Self {
             cache,
@@ -51,6 +59,9 @@ impl<'a> RegistryWheelIndex<'a> {
             index_locations,
             hasher,
             build_configuration,
+            build_configuration_package,
+            build_env,
+            wheel_optimizations,
             index: FxHashMap::default(),
         }
     }
@@ -73,6 +84,9 @@ impl<'a> RegistryWheelIndex<'a> {
                 self.index_locations,
                 self.hasher,
                 self.build_configuration,
+                self.build_configuration_package,
+                self.build_env,
+                self.wheel_optimizations,
             )),
         };
         versions
@@ -86,6 +100,9 @@ impl<'a> RegistryWheelIndex<'a> {
         index_locations: &'index IndexLocations,
         hasher: &HashStrategy,
         build_configuration: &ConfigSettings,
+        build_configuration_package: &PackageConfigSettings,
+        build_env: &PackageBuildEnv,
+        wheel_optimizations: &[WheelOptimization],
     ) -> Vec<IndexEntry<'index>> {
         let mut entries = vec![];
 
@@ -199,11 +216,15 @@ impl<'a> RegistryWheelIndex<'a> {
                     let cache_shard = cache_shard.shard(revision.id());
 
                     // If there are build settings, we need to scope to a cache shard.
+                    let build_configuration =
+                        build_configuration_package.for_package(Some(package), build_configuration);
                     let cache_shard = if build_configuration.is_empty() {
                         cache_shard
                     } else {
-                        cache_shard.shard(cache_digest(build_configuration))
+                        cache_shard.shard(cache_digest(&*build_configuration))
                     };
+                    let cache_shard =
+                        build_env_shard(cache_shard, build_env.get(package), wheel_optimizations);
 
                     for wheel_dir in uv_fs::entries(cache_shard) {
                         // Ignore any `.lock` files.
@@ -214,6 +235,11 @@ impl<'a> RegistryWheelIndex<'a> {
                             continue;
                         }
 
+                        // Ignore any wheels that must be rebuilt.
+                        if matches!(cache.must_rebuild(package, &wheel_dir), Ok(true)) {
+                            continue;
+                        }
+
                         if let Some(wheel) = CachedWheel::from_built_source(wheel_dir, cache) {
                             if wheel.filename.compatibility(tags).is_compatible() {
                                 // Enforce hash-checking based on the source distribution.
@@ -0,0 +1,275 @@
+use std::path::{Path, PathBuf};
+
+use tracing::debug;
+
+use uv_cache::{Cache, CacheBucket, CacheEntry, WheelCache};
+use uv_distribution_filename::{SourceDistExtension, SourceDistFilename, WheelFilename};
+use uv_distribution_types::{File, HashPolicy, Hashed};
+use uv_extract::hash::Hasher;
+use uv_fs::write_atomic;
+use uv_normalize::PackageName;
+use uv_pypi_types::{HashAlgorithm, HashDigest, HashDigests, ResolutionMetadata};
+
+use crate::archive::Archive;
+use crate::source::read_pkg_info;
+use crate::Error;
+
+/// Add a local wheel to the cache, keyed by the SHA-256 digest of its contents.
+///
+/// The wheel is unzipped into the archive bucket, and its metadata is registered alongside it,
+/// such that any subsequent operation that knows the wheel's digest (e.g., from the index, a
+/// lockfile, or `--hash`) can reuse the unzipped wheel and its metadata, regardless of where
+/// the wheel is fetched from.
+///
+/// Returns `false` if the wheel was already present in the cache.
+pub async fn ingest_wheel(
+    cache: &Cache,
+    path: &Path,
+    filename: &WheelFilename,
+) -> Result<bool, Error> {
+    let digest = sha256(path, |err| Error::LocalWheel(path.to_path_buf(), err)).await?;
+    let wheel_entry = wheel_entry(cache, &digest.digest, filename);
+
+    // If the cache already contains the wheel, there's nothing to do.
+    if read_archive(cache, &wheel_entry, filename)?.is_some() {
+        return Ok(false);
+    }
+
+    // Unzip the wheel to a temporary directory.
+    let file = fs_err::tokio::File::open(path)
+        .await
+        .map_err(|err| Error::LocalWheel(path.to_path_buf(), err))?;
+    let temp_dir = tempfile::tempdir_in(cache.root()).map_err(Error::CacheWrite)?;
+    uv_extract::stream::unzip(file, temp_dir.path()).await?;
+
+    // Read the metadata from the unzipped wheel.
+    let metadata = uv_metadata::read_flat_wheel_metadata(filename, temp_dir.path())
+        .map_err(|err| Error::WheelMetadata(path.to_path_buf(), Box::new(err)))?;
+
+    // Persist the temporary directory to the directory store.
+    let id = cache
+        .persist(temp_dir.into_path(), wheel_entry.path())
+        .await
+        .map_err(Error::CacheWrite)?;
+
+    // Register the metadata, then the archive itself.
+    let archive = Archive::new(id, HashDigests::from(digest), filename.clone());
+    write_atomic(
+        wheel_metadata_entry(&wheel_entry, filename).path(),
+        rmp_serde::to_vec(&metadata)?,
+    )
+    .await
+    .map_err(Error::CacheWrite)?;
+    write_atomic(
+        wheel_pointer_entry(&wheel_entry, filename).path(),
+        rmp_serde::to_vec(&archive)?,
+    )
+    .await
+    .map_err(Error::CacheWrite)?;
+
+    Ok(true)
+}
+
+/// Add a local source distribution to the cache, keyed by the SHA-256 digest of its contents.
+///
+/// The archive is copied into the cache as-is, such that any subsequent operation that knows
+/// the source distribution's digest can build it without downloading it. If the source
+/// distribution includes static metadata (i.e., a `PKG-INFO` file with Metadata 2.2 or later and
+/// no dynamic dependencies), the metadata is registered alongside it; otherwise, the metadata is
+/// built on first use.
+///
+/// Returns `false` if the source distribution was already present in the cache.
+pub async fn ingest_source_dist(
+    cache: &Cache,
+    path: &Path,
+    filename: &SourceDistFilename,
+) -> Result<bool, Error> {
+    let digest = sha256(path, |err| Error::LocalSourceDist(path.to_path_buf(), err)).await?;
+    let sdist_entry = source_dist_entry(cache, &digest.digest, &filename.name, filename.extension);
+
+    // If the cache already contains the source distribution, there's nothing to do.
+    if sdist_entry.path().is_file() {
+        return Ok(false);
+    }
+
+    // Extract the archive to a temporary directory, to read its `PKG-INFO`.
+    let file = fs_err::tokio::File::open(path)
+        .await
+        .map_err(|err| Error::LocalSourceDist(path.to_path_buf(), err))?;
+    let temp_dir = tempfile::tempdir_in(cache.root()).map_err(Error::CacheWrite)?;
+    uv_extract::stream::archive(file, filename.extension, temp_dir.path()).await?;
+    let source_tree = uv_extract::strip_component(temp_dir.path())
+        .unwrap_or_else(|_| temp_dir.path().to_path_buf());
+
+    match read_pkg_info(&source_tree, None).await {
+        Ok(metadata) => {
+            write_atomic(
+                source_dist_metadata_entry(&sdist_entry).path(),
+                rmp_serde::to_vec(&metadata)?,
+            )
+            .await
+            .map_err(Error::CacheWrite)?;
+        }
+        Err(err @ (Error::MissingPkgInfo | Error::PkgInfo(_))) => {
+            debug!(
+                "No static metadata available for {filename}; metadata will be built on first use: {err}"
+            );
+        }
+        Err(err) => return Err(err),
+    }
+
+    // Copy the archive into the cache.
+    fs_err::tokio::create_dir_all(sdist_entry.dir())
+        .await
+        .map_err(Error::CacheWrite)?;
+    let temp_file =
+        tempfile::NamedTempFile::new_in(sdist_entry.dir()).map_err(Error::CacheWrite)?;
+    fs_err::tokio::copy(path, temp_file.path())
+        .await
+        .map_err(Error::CacheWrite)?;
+    temp_file
+        .persist(sdist_entry.path())
+        .map_err(|err| Error::CacheWrite(err.error))?;
+
+    Ok(true)
+}
+
+/// Return the SHA-256 digest of a distribution, if it's known without reading the distribution
+/// itself (i.e., if it was provided by the index or required by the user).
+pub(crate) fn known_sha256<'a>(file: Option<&'a File>, hashes: HashPolicy<'a>) -> Option<&'a str> {
+    let from_file = file.into_iter().flat_map(|file| file.hashes.iter());
+    let from_policy = match hashes {
+        HashPolicy::Validate(digests) => digests,
+        HashPolicy::None | HashPolicy::Generate(_) => &[],
+    };
+    from_file
+        .chain(from_policy)
+        .find(|digest| digest.algorithm == HashAlgorithm::Sha256)
+        .map(|digest| digest.digest.as_ref())
+}
+
+/// Return the unzipped wheel with the given digest, if it was added to the cache and satisfies
+/// the given hash policy.
+pub(crate) fn ingested_wheel(
+    cache: &Cache,
+    digest: &str,
+    filename: &WheelFilename,
+    hashes: HashPolicy<'_>,
+) -> Result<Option<Archive>, Error> {
+    let wheel_entry = wheel_entry(cache, digest, filename);
+    Ok(read_archive(cache, &wheel_entry, filename)?.filter(|archive| archive.has_digests(hashes)))
+}
+
+/// Return the metadata for the wheel with the given digest, if it was added to the cache.
+pub(crate) fn ingested_wheel_metadata(
+    cache: &Cache,
+    digest: &str,
+    filename: &WheelFilename,
+) -> Result<Option<ResolutionMetadata>, Error> {
+    let wheel_entry = wheel_entry(cache, digest, filename);
+    read_metadata(&wheel_metadata_entry(&wheel_entry, filename))
+}
+
+/// Return the path to the source distribution with the given digest, if it was added to the
+/// cache.
+pub(crate) fn ingested_source_dist(
+    cache: &Cache,
+    digest: &str,
+    name: &PackageName,
+    ext: SourceDistExtension,
+) -> Option<PathBuf> {
+    let sdist_entry = source_dist_entry(cache, digest, name, ext);
+    sdist_entry
+        .path()
+        .is_file()
+        .then(|| sdist_entry.into_path_buf())
+}
+
+/// Return the metadata for the source distribution with the given digest, if it was added to the
+/// cache with static metadata.
+pub(crate) fn ingested_source_dist_metadata(
+    cache: &Cache,
+    digest: &str,
+    name: &PackageName,
+    ext: SourceDistExtension,
+) -> Result<Option<ResolutionMetadata>, Error> {
+    let sdist_entry = source_dist_entry(cache, digest, name, ext);
+    read_metadata(&source_dist_metadata_entry(&sdist_entry))
+}
+
+/// The cache entry for an unzipped wheel with the given digest.
+fn wheel_entry(cache: &Cache, digest: &str, filename: &WheelFilename) -> CacheEntry {
+    cache.entry(
+        CacheBucket::Wheels,
+        WheelCache::Sha256(digest).wheel_dir(filename.name.as_ref()),
+        filename.cache_key(),
+    )
+}
+
+/// The cache entry for a source distribution archive with the given digest.
+fn source_dist_entry(
+    cache: &Cache,
+    digest: &str,
+    name: &PackageName,
+    ext: SourceDistExtension,
+) -> CacheEntry {
+    cache.entry(
+        CacheBucket::SourceDistributions,
+        WheelCache::Sha256(digest).wheel_dir(name.as_ref()),
+        format!("source.{ext}"),
+    )
+}
+
+/// The `.rev` entry pointing to the unzipped archive for a wheel with the given digest.
+fn wheel_pointer_entry(wheel_entry: &CacheEntry, filename: &WheelFilename) -> CacheEntry {
+    wheel_entry.with_file(format!("{}.rev", filename.cache_key()))
+}
+
+/// The `.msgpack` entry holding the metadata for a wheel with the given digest.
+fn wheel_metadata_entry(wheel_entry: &CacheEntry, filename: &WheelFilename) -> CacheEntry {
+    wheel_entry.with_file(format!("{}.msgpack", filename.cache_key()))
+}
+
+/// The `.msgpack` entry holding the metadata for a source distribution with the given digest.
+fn source_dist_metadata_entry(sdist_entry: &CacheEntry) -> CacheEntry {
+    sdist_entry.with_file("source.msgpack")
+}
+
+/// Read the [`Archive`] for a wheel entry, if it exists and the archive is still present.
+fn read_archive(
+    cache: &Cache,
+    wheel_entry: &CacheEntry,
+    filename: &WheelFilename,
+) -> Result<Option<Archive>, Error> {
+    let archive = match fs_err::read(wheel_pointer_entry(wheel_entry, filename).path()) {
+        Ok(cached) => rmp_serde::from_slice::<Archive>(&cached)?,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(Error::CacheRead(err)),
+    };
+    Ok(Some(archive).filter(|archive| archive.exists(cache)))
+}
+
+/// Read the [`ResolutionMetadata`] from a metadata entry, if it exists.
+fn read_metadata(entry: &CacheEntry) -> Result<Option<ResolutionMetadata>, Error> {
+    match fs_err::read(entry.path()) {
+        Ok(cached) => Ok(Some(rmp_serde::from_slice::<ResolutionMetadata>(&cached)?)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(Error::CacheRead(err)),
+    }
+}
+
+/// Compute the SHA-256 digest of a local file.
+async fn sha256(
+    path: &Path,
+    on_open: impl FnOnce(std::io::Error) -> Error,
+) -> Result<HashDigest, Error> {
+    let file = fs_err::tokio::File::open(path).await.map_err(on_open)?;
+    let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+    let mut hasher = uv_extract::hash::HashReader::new(file, &mut hashers);
+    hasher.finish().await.map_err(Error::HashExhaustion)?;
+    Ok(hashers
+        .into_iter()
+        .map(HashDigest::from)
+        .next()
+        .expect("a SHA-256 hasher"))
+}
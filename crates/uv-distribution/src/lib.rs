@@ -1,7 +1,9 @@
 pub use distribution_database::{DistributionDatabase, HttpArchivePointer, LocalArchivePointer};
 pub use download::LocalWheel;
 pub use error::Error;
+pub use gc::gc_metadata;
 pub use index::{BuiltWheelIndex, RegistryWheelIndex};
+pub use ingest::{ingest_source_dist, ingest_wheel};
 pub use metadata::{
     ArchiveMetadata, BuildRequires, FlatRequiresDist, LoweredRequirement, LoweringError, Metadata,
     MetadataError, RequiresDist,
@@ -13,7 +15,10 @@ mod archive;
 mod distribution_database;
 mod download;
 mod error;
+mod gc;
 mod index;
+mod ingest;
 mod metadata;
+mod metadata_store;
 mod reporter;
 mod source;
@@ -123,6 +123,8 @@ pub struct ArchiveMetadata {
     pub metadata: Metadata,
     /// The hashes of the source or built archive.
     pub hashes: HashDigests,
+    /// Whether the metadata was read from the cache, rather than fetched or built.
+    pub cached: bool,
 }
 
 impl ArchiveMetadata {
@@ -132,12 +134,26 @@ impl ArchiveMetadata {
         Self {
             metadata: Metadata::from_metadata23(metadata),
             hashes: HashDigests::empty(),
+            cached: false,
         }
     }
 
     /// Create an [`ArchiveMetadata`] with the given metadata and hashes.
     pub fn with_hashes(metadata: Metadata, hashes: HashDigests) -> Self {
-        Self { metadata, hashes }
+        Self {
+            metadata,
+            hashes,
+            cached: false,
+        }
+    }
+
+    /// Mark the metadata as having been read from the cache.
+    #[must_use]
+    pub fn into_cached(self) -> Self {
+        Self {
+            cached: true,
+            ..self
+        }
     }
 }
 
@@ -146,6 +162,7 @@ impl From<Metadata> for ArchiveMetadata {
         Self {
             metadata,
             hashes: HashDigests::empty(),
+            cached: false,
         }
     }
 }
@@ -0,0 +1,91 @@
+use tracing::debug;
+
+use uv_cache::{Cache, CacheBucket, CacheEntry, WheelCache};
+use uv_distribution_types::IndexUrl;
+use uv_fs::write_atomic;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pypi_types::ResolutionMetadata;
+
+use crate::Error;
+
+/// Read the metadata for the given version of a package on the given index from the metadata
+/// store, if it's present and fresh.
+///
+/// Entries that can't be deserialized, or that don't match the requested package and version
+/// (e.g., due to an interrupted write or disk corruption), are removed, such that the metadata is
+/// re-fetched and the entry rewritten.
+pub(crate) fn read(
+    cache: &Cache,
+    index: &IndexUrl,
+    name: &PackageName,
+    version: &Version,
+) -> Result<Option<ResolutionMetadata>, Error> {
+    let entry = metadata_entry(cache, index, name, version);
+    if !cache
+        .freshness(&entry, Some(name))
+        .map_err(Error::CacheRead)?
+        .is_fresh()
+    {
+        return Ok(None);
+    }
+
+    let contents = match fs_err::read(entry.path()) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(Error::CacheRead(err)),
+    };
+
+    match rmp_serde::from_slice::<ResolutionMetadata>(&contents) {
+        Ok(metadata) if metadata.name == *name && metadata.version == *version => {
+            debug!("Using stored metadata for: {name}=={version}");
+            Ok(Some(metadata))
+        }
+        Ok(_) => {
+            debug!("Removing mismatched stored metadata for: {name}=={version}");
+            remove(&entry);
+            Ok(None)
+        }
+        Err(err) => {
+            debug!("Removing corrupt stored metadata for: {name}=={version} ({err})");
+            remove(&entry);
+            Ok(None)
+        }
+    }
+}
+
+/// Write the metadata for the given version of a package on the given index to the metadata
+/// store.
+pub(crate) async fn write(
+    cache: &Cache,
+    index: &IndexUrl,
+    name: &PackageName,
+    version: &Version,
+    metadata: &ResolutionMetadata,
+) -> Result<(), Error> {
+    let entry = metadata_entry(cache, index, name, version);
+    write_atomic(entry.path(), rmp_serde::to_vec(metadata)?)
+        .await
+        .map_err(Error::CacheWrite)
+}
+
+/// The cache entry for the metadata of the given version of a package on the given index.
+fn metadata_entry(
+    cache: &Cache,
+    index: &IndexUrl,
+    name: &PackageName,
+    version: &Version,
+) -> CacheEntry {
+    cache.entry(
+        CacheBucket::Metadata,
+        WheelCache::Index(index).wheel_dir(name.as_ref()),
+        format!("{version}.msgpack"),
+    )
+}
+
+/// Remove a stored metadata entry, ignoring any errors.
+fn remove(entry: &CacheEntry) {
+    if let Err(err) = fs_err::remove_file(entry.path()) {
+        debug!("Failed to remove stored metadata: {err}");
+    }
+}
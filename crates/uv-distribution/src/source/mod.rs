@@ -9,26 +9,29 @@
 #![allow(clippy::redundant_closure_for_method_calls)]
 
 use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
 use std::ops::Bound;
 use std::path::Path;
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::SystemTime;
 
 use fs_err::tokio as fs;
 use futures::{FutureExt, TryStreamExt};
 use reqwest::{Response, StatusCode};
+use rustc_hash::FxHashMap;
 use tokio_util::compat::FuturesAsyncReadCompatExt;
 use tracing::{debug, info_span, instrument, warn, Instrument};
 use url::Url;
 use zip::ZipArchive;
 
-use uv_cache::{Cache, CacheBucket, CacheEntry, CacheShard, Removal, WheelCache};
+use uv_cache::{rm_rf, Cache, CacheBucket, CacheEntry, CacheShard, Removal, WheelCache};
 use uv_cache_info::CacheInfo;
 use uv_cache_key::cache_digest;
 use uv_client::{
     CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
 };
-use uv_configuration::{BuildKind, BuildOutput, SourceStrategy};
+use uv_configuration::{BuildKind, BuildOutput, ConfigSettings, SourceStrategy, WheelOptimization};
 use uv_distribution_filename::{SourceDistExtension, WheelFilename};
 use uv_distribution_types::{
     BuildableSource, DirectorySourceUrl, FileLocation, GitSourceUrl, HashPolicy, Hashed,
@@ -42,18 +45,24 @@ use uv_normalize::PackageName;
 use uv_pep440::{release_specifiers_to_ranges, Version};
 use uv_platform_tags::Tags;
 use uv_pypi_types::{HashAlgorithm, HashDigest, HashDigests, PyProjectToml, ResolutionMetadata};
+use uv_static::EnvVars;
 use uv_types::{BuildContext, BuildStack, SourceBuildTrait};
 use uv_workspace::pyproject::ToolUvSources;
 
-use crate::distribution_database::ManagedClient;
+use crate::distribution_database::{exhaustion_error, ManagedClient, SizeReader};
 use crate::error::Error;
+use crate::ingest;
 use crate::metadata::{ArchiveMetadata, GitWorkspaceMember, Metadata};
 use crate::source::built_wheel_metadata::BuiltWheelMetadata;
+use crate::source::optimize::optimize_wheel;
 use crate::source::revision::Revision;
+use crate::source::root::detect_subdirectory;
 use crate::{Reporter, RequiresDist};
 
 mod built_wheel_metadata;
+mod optimize;
 mod revision;
+mod root;
 
 /// Fetch and build a source distribution from a remote source, or from a local cache.
 pub(crate) struct SourceDistributionBuilder<'a, T: BuildContext> {
@@ -74,6 +83,40 @@ pub(crate) const METADATA: &str = "metadata.msgpack";
 /// The directory within each entry under which to store the unpacked source distribution.
 pub(crate) const SOURCE: &str = "src";
 
+/// Environment variables that commonly affect the output of a native build, like the choice of
+/// compiler and its flags, or the build parallelism and CUDA toolkit used by extension modules.
+///
+/// Built wheels are cached separately for each combination of values. Only the values themselves
+/// are considered; the compilers are identified separately (see [`BUILD_COMPILERS`]).
+const BUILD_INPUT_ENV_VARS: &[&str] = &[
+    EnvVars::CC,
+    EnvVars::CXX,
+    EnvVars::CFLAGS,
+    EnvVars::CXXFLAGS,
+    EnvVars::LDFLAGS,
+    EnvVars::MAX_JOBS,
+    EnvVars::CUDA_HOME,
+];
+
+/// The compilers invoked by native builds, as the environment variable that selects each, along
+/// with the program used if the variable is unset.
+///
+/// Built wheels are cached separately for each compiler that's found, as identified by its
+/// resolved path, size, and modification time, such that replacing or upgrading a compiler in place
+/// triggers a rebuild.
+const BUILD_COMPILERS: &[(&str, &str)] = &[(EnvVars::CC, "cc"), (EnvVars::CXX, "c++")];
+
+/// The values of the [`BUILD_INPUT_ENV_VARS`] in the current environment.
+static BUILD_INPUT_ENV: LazyLock<BTreeMap<String, String>> = LazyLock::new(|| {
+    BUILD_INPUT_ENV_VARS
+        .iter()
+        .filter_map(|name| {
+            let value = std::env::var_os(name)?;
+            Some(((*name).to_string(), value.to_string_lossy().into_owned()))
+        })
+        .collect()
+});
+
 impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
     /// Initialize a [`SourceDistributionBuilder`] from a [`BuildContext`].
     pub(crate) fn new(build_context: &'a T) -> Self {
@@ -128,11 +171,24 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     FileLocation::AbsoluteUrl(url) => url.to_url()?,
                 };
 
-                // If the URL is a file URL, use the local path directly.
-                if url.scheme() == "file" {
-                    let path = url
-                        .to_file_path()
-                        .map_err(|()| Error::NonFileUrl(url.clone()))?;
+                // If the URL is a file URL, use the local path directly. Likewise, if the source
+                // distribution was added to the cache via `uv cache add`, use the cached copy.
+                let path = if url.scheme() == "file" {
+                    Some(
+                        url.to_file_path()
+                            .map_err(|()| Error::NonFileUrl(url.clone()))?,
+                    )
+                } else {
+                    ingest::known_sha256(Some(&dist.file), hashes).and_then(|digest| {
+                        ingest::ingested_source_dist(
+                            self.build_context.cache(),
+                            digest,
+                            &dist.name,
+                            dist.ext,
+                        )
+                    })
+                };
+                if let Some(path) = path {
                     return self
                         .archive(
                             source,
@@ -277,11 +333,24 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     FileLocation::AbsoluteUrl(url) => url.to_url()?,
                 };
 
-                // If the URL is a file URL, use the local path directly.
-                if url.scheme() == "file" {
-                    let path = url
-                        .to_file_path()
-                        .map_err(|()| Error::NonFileUrl(url.clone()))?;
+                // If the URL is a file URL, use the local path directly. Likewise, if the source
+                // distribution was added to the cache via `uv cache add`, use the cached copy.
+                let path = if url.scheme() == "file" {
+                    Some(
+                        url.to_file_path()
+                            .map_err(|()| Error::NonFileUrl(url.clone()))?,
+                    )
+                } else {
+                    ingest::known_sha256(Some(&dist.file), hashes).and_then(|digest| {
+                        ingest::ingested_source_dist(
+                            self.build_context.cache(),
+                            digest,
+                            &dist.name,
+                            dist.ext,
+                        )
+                    })
+                };
+                if let Some(path) = path {
                     return self
                         .archive_metadata(
                             source,
@@ -416,17 +485,16 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let source_dist_entry = cache_shard.entry(SOURCE);
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
+        let config_settings = self.config_settings_for(source);
         let cache_shard = if config_settings.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(config_settings))
+            cache_shard.shard(cache_digest(&*config_settings))
         };
+        let cache_shard = self.build_env_shard(source, cache_shard);
 
         // If the cache contains a compatible wheel, return it.
-        if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard)
-            .filter(|built_wheel| built_wheel.matches(source.name(), source.version()))
-        {
+        if let Some(built_wheel) = self.find_built_wheel(source, tags, &cache_shard)? {
             return Ok(built_wheel.with_hashes(revision.into_hashes()));
         }
 
@@ -456,6 +524,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        // If necessary, detect a project root nested within the archive.
+        let subdirectory = detect_subdirectory(source_dist_entry.path(), subdirectory).await?;
+
         let task = self
             .reporter
             .as_ref()
@@ -466,9 +537,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .build_distribution(
                 source,
                 source_dist_entry.path(),
-                subdirectory,
+                subdirectory.as_deref(),
                 &cache_shard,
                 SourceStrategy::Disabled,
+                task,
             )
             .await?;
 
@@ -529,17 +601,25 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let source_dist_entry = cache_shard.entry(SOURCE);
 
         // If the metadata is static, return it.
-        let dynamic =
-            match StaticMetadata::read(source, source_dist_entry.path(), subdirectory).await? {
-                StaticMetadata::Some(metadata) => {
-                    return Ok(ArchiveMetadata {
-                        metadata: Metadata::from_metadata23(metadata),
-                        hashes: revision.into_hashes(),
-                    });
-                }
-                StaticMetadata::Dynamic => true,
-                StaticMetadata::None => false,
-            };
+        let dynamic = match StaticMetadata::read(
+            source,
+            source_dist_entry.path(),
+            detect_subdirectory(source_dist_entry.path(), subdirectory)
+                .await?
+                .as_deref(),
+        )
+        .await?
+        {
+            StaticMetadata::Some(metadata) => {
+                return Ok(ArchiveMetadata {
+                    metadata: Metadata::from_metadata23(metadata),
+                    hashes: revision.into_hashes(),
+                    cached: false,
+                });
+            }
+            StaticMetadata::Dynamic => true,
+            StaticMetadata::None => false,
+        };
 
         // If the cache contains compatible metadata, return it.
         let metadata_entry = cache_shard.entry(METADATA);
@@ -550,6 +630,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     return Ok(ArchiveMetadata {
                         metadata: Metadata::from_metadata23(metadata.into()),
                         hashes: revision.into_hashes(),
+                        cached: true,
                     });
                 }
                 debug!("Cached metadata does not match expected name and version for: {source}");
@@ -586,13 +667,17 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        // If necessary, detect a project root nested within the archive.
+        let subdirectory = detect_subdirectory(source_dist_entry.path(), subdirectory).await?;
+
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
+        let config_settings = self.config_settings_for(source);
         let cache_shard = if config_settings.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(config_settings))
+            cache_shard.shard(cache_digest(&*config_settings))
         };
+        let cache_shard = self.build_env_shard(source, cache_shard);
 
         // Otherwise, we either need to build the metadata.
         // If the backend supports `prepare_metadata_for_build_wheel`, use it.
@@ -600,7 +685,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .build_metadata(
                 source,
                 source_dist_entry.path(),
-                subdirectory,
+                subdirectory.as_deref(),
                 SourceStrategy::Disabled,
             )
             .boxed_local()
@@ -627,6 +712,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             return Ok(ArchiveMetadata {
                 metadata: Metadata::from_metadata23(metadata),
                 hashes: revision.into_hashes(),
+                cached: false,
             });
         }
 
@@ -640,9 +726,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .build_distribution(
                 source,
                 source_dist_entry.path(),
-                subdirectory,
+                subdirectory.as_deref(),
                 &cache_shard,
                 SourceStrategy::Disabled,
+                task,
             )
             .await?;
 
@@ -670,6 +757,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         Ok(ArchiveMetadata {
             metadata: Metadata::from_metadata23(metadata),
             hashes: revision.into_hashes(),
+            cached: false,
         })
     }
 
@@ -786,17 +874,16 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let source_entry = cache_shard.entry(SOURCE);
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
+        let config_settings = self.config_settings_for(source);
         let cache_shard = if config_settings.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(config_settings))
+            cache_shard.shard(cache_digest(&*config_settings))
         };
+        let cache_shard = self.build_env_shard(source, cache_shard);
 
         // If the cache contains a compatible wheel, return it.
-        if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard)
-            .filter(|built_wheel| built_wheel.matches(source.name(), source.version()))
-        {
+        if let Some(built_wheel) = self.find_built_wheel(source, tags, &cache_shard)? {
             return Ok(built_wheel);
         }
 
@@ -808,6 +895,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 .await?
         };
 
+        // If necessary, detect a project root nested within the archive.
+        let subdirectory = detect_subdirectory(source_entry.path(), None).await?;
+
         let task = self
             .reporter
             .as_ref()
@@ -817,9 +907,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .build_distribution(
                 source,
                 source_entry.path(),
-                None,
+                subdirectory.as_deref(),
                 &cache_shard,
                 SourceStrategy::Disabled,
+                task,
             )
             .await?;
 
@@ -877,11 +968,20 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let source_entry = cache_shard.entry(SOURCE);
 
         // If the metadata is static, return it.
-        let dynamic = match StaticMetadata::read(source, source_entry.path(), None).await? {
+        let dynamic = match StaticMetadata::read(
+            source,
+            source_entry.path(),
+            detect_subdirectory(source_entry.path(), None)
+                .await?
+                .as_deref(),
+        )
+        .await?
+        {
             StaticMetadata::Some(metadata) => {
                 return Ok(ArchiveMetadata {
                     metadata: Metadata::from_metadata23(metadata),
                     hashes: revision.into_hashes(),
+                    cached: false,
                 });
             }
             StaticMetadata::Dynamic => true,
@@ -897,6 +997,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     return Ok(ArchiveMetadata {
                         metadata: Metadata::from_metadata23(metadata.into()),
                         hashes: revision.into_hashes(),
+                        cached: true,
                     });
                 }
                 debug!("Cached metadata does not match expected name and version for: {source}");
@@ -915,9 +1016,17 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 .await?
         };
 
+        // If necessary, detect a project root nested within the archive.
+        let subdirectory = detect_subdirectory(source_entry.path(), None).await?;
+
         // If the backend supports `prepare_metadata_for_build_wheel`, use it.
         if let Some(metadata) = self
-            .build_metadata(source, source_entry.path(), None, SourceStrategy::Disabled)
+            .build_metadata(
+                source,
+                source_entry.path(),
+                subdirectory.as_deref(),
+                SourceStrategy::Disabled,
+            )
             .boxed_local()
             .await?
         {
@@ -942,16 +1051,18 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             return Ok(ArchiveMetadata {
                 metadata: Metadata::from_metadata23(metadata),
                 hashes: revision.into_hashes(),
+                cached: false,
             });
         }
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
+        let config_settings = self.config_settings_for(source);
         let cache_shard = if config_settings.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(config_settings))
+            cache_shard.shard(cache_digest(&*config_settings))
         };
+        let cache_shard = self.build_env_shard(source, cache_shard);
 
         // Otherwise, we need to build a wheel.
         let task = self
@@ -963,9 +1074,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .build_distribution(
                 source,
                 source_entry.path(),
-                None,
+                subdirectory.as_deref(),
                 &cache_shard,
                 SourceStrategy::Disabled,
+                task,
             )
             .await?;
 
@@ -993,6 +1105,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         Ok(ArchiveMetadata {
             metadata: Metadata::from_metadata23(metadata),
             hashes: revision.into_hashes(),
+            cached: false,
         })
     }
 
@@ -1088,17 +1201,16 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let cache_shard = cache_shard.shard(revision.id());
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
+        let config_settings = self.config_settings_for(source);
         let cache_shard = if config_settings.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(config_settings))
+            cache_shard.shard(cache_digest(&*config_settings))
         };
+        let cache_shard = self.build_env_shard(source, cache_shard);
 
         // If the cache contains a compatible wheel, return it.
-        if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard)
-            .filter(|built_wheel| built_wheel.matches(source.name(), source.version()))
-        {
+        if let Some(built_wheel) = self.find_built_wheel(source, tags, &cache_shard)? {
             return Ok(built_wheel);
         }
 
@@ -1115,6 +1227,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 None,
                 &cache_shard,
                 self.build_context.sources(),
+                task,
             )
             .await?;
 
@@ -1219,7 +1332,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                             self.build_context.sources(),
                         )
                         .await?,
-                    ));
+                    )
+                    .into_cached());
                 }
                 debug!("Cached metadata does not match expected name and version for: {source}");
             }
@@ -1271,12 +1385,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
+        let config_settings = self.config_settings_for(source);
         let cache_shard = if config_settings.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(config_settings))
+            cache_shard.shard(cache_digest(&*config_settings))
         };
+        let cache_shard = self.build_env_shard(source, cache_shard);
 
         // Otherwise, we need to build a wheel.
         let task = self
@@ -1291,6 +1406,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 None,
                 &cache_shard,
                 self.build_context.sources(),
+                task,
             )
             .await?;
 
@@ -1465,17 +1581,16 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
+        let config_settings = self.config_settings_for(source);
         let cache_shard = if config_settings.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(config_settings))
+            cache_shard.shard(cache_digest(&*config_settings))
         };
+        let cache_shard = self.build_env_shard(source, cache_shard);
 
         // If the cache contains a compatible wheel, return it.
-        if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard)
-            .filter(|built_wheel| built_wheel.matches(source.name(), source.version()))
-        {
+        if let Some(built_wheel) = self.find_built_wheel(source, tags, &cache_shard)? {
             return Ok(built_wheel);
         }
 
@@ -1491,6 +1606,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 resource.subdirectory,
                 &cache_shard,
                 self.build_context.sources(),
+                task,
             )
             .await?;
 
@@ -1562,6 +1678,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                                 return Ok(ArchiveMetadata {
                                     metadata: Metadata::from_metadata23(metadata),
                                     hashes: HashDigests::empty(),
+                                    cached: false,
                                 });
                             }
                             Err(err) => {
@@ -1679,7 +1796,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                                 self.build_context.sources(),
                             )
                             .await?,
-                        ));
+                        )
+                        .into_cached());
                     }
                     debug!(
                         "Cached metadata does not match expected name and version for: {source}"
@@ -1734,12 +1852,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
+        let config_settings = self.config_settings_for(source);
         let cache_shard = if config_settings.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(config_settings))
+            cache_shard.shard(cache_digest(&*config_settings))
         };
+        let cache_shard = self.build_env_shard(source, cache_shard);
 
         // Otherwise, we need to build a wheel.
         let task = self
@@ -1754,6 +1873,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 resource.subdirectory,
                 &cache_shard,
                 self.build_context.sources(),
+                task,
             )
             .await?;
 
@@ -2054,22 +2174,31 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err))
             .into_async_read();
 
+        // Verify the download against the size declared by the index, if any.
+        let expected = source
+            .as_dist()
+            .and_then(SourceDist::file)
+            .and_then(|file| file.size);
+        let mut reader = SizeReader::new(reader.compat(), expected);
+
         // Create a hasher for each hash algorithm.
         let mut hashers = algorithms
             .iter()
             .copied()
             .map(Hasher::from)
             .collect::<Vec<_>>();
-        let mut hasher = uv_extract::hash::HashReader::new(reader.compat(), &mut hashers);
+        let mut hasher = uv_extract::hash::HashReader::new(&mut reader, &mut hashers);
 
         // Download and unzip the source distribution into a temporary directory.
         let span = info_span!("download_source_dist", source_dist = %source);
-        uv_extract::stream::archive(&mut hasher, ext, temp_dir.path()).await?;
+        if let Err(err) = uv_extract::stream::archive(&mut hasher, ext, temp_dir.path()).await {
+            return Err(reader.extract_error(err));
+        }
         drop(span);
 
-        // If necessary, exhaust the reader to compute the hash.
-        if !algorithms.is_empty() {
-            hasher.finish().await.map_err(Error::HashExhaustion)?;
+        // If necessary, exhaust the reader to compute the hash and verify the size.
+        if !algorithms.is_empty() || expected.is_some() {
+            hasher.finish().await.map_err(exhaustion_error)?;
         }
 
         let hashes = hashers.into_iter().map(HashDigest::from).collect();
@@ -2158,6 +2287,62 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         Ok(hashes)
     }
 
+    /// Return the config settings to use when building the given source, including any settings
+    /// configured for the package itself.
+    fn config_settings_for(&self, source: &BuildableSource<'_>) -> Cow<'_, ConfigSettings> {
+        self.build_context
+            .config_settings_package()
+            .for_package(source.name(), self.build_context.config_settings())
+    }
+
+    /// Scope the cache shard to the environment variables and wheel optimizations that affect the
+    /// package's build.
+    fn build_env_shard(&self, source: &BuildableSource<'_>, cache_shard: CacheShard) -> CacheShard {
+        build_env_shard(
+            cache_shard,
+            source
+                .name()
+                .and_then(|name| self.build_context.build_env().get(name)),
+            self.build_context.wheel_optimizations(),
+        )
+    }
+
+    /// Return a compatible wheel from the cache, if one was built for the source distribution.
+    ///
+    /// If the package was marked for a rebuild, any cached wheel that predates the current
+    /// invocation is removed instead.
+    fn find_built_wheel(
+        &self,
+        source: &BuildableSource<'_>,
+        tags: &Tags,
+        cache_shard: &CacheShard,
+    ) -> Result<Option<BuiltWheelMetadata>, Error> {
+        let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, cache_shard)
+            .filter(|built_wheel| built_wheel.matches(source.name(), source.version()))
+        else {
+            return Ok(None);
+        };
+
+        if let Some(name) = source.name() {
+            if self
+                .build_context
+                .cache()
+                .must_rebuild(name, &built_wheel.path)
+                .map_err(Error::CacheRead)?
+            {
+                debug!(
+                    "Discarding cached wheel for rebuild: {}",
+                    built_wheel.filename
+                );
+                rm_rf(&built_wheel.target).map_err(Error::CacheWrite)?;
+                rm_rf(&built_wheel.path).map_err(Error::CacheWrite)?;
+                return Ok(None);
+            }
+        }
+
+        Ok(Some(built_wheel))
+    }
+
     /// Build a source distribution, storing the built wheel in the cache.
     ///
     /// Returns the un-normalized disk filename, the parsed, normalized filename and the metadata
@@ -2169,6 +2354,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         subdirectory: Option<&Path>,
         cache_shard: &CacheShard,
         source_strategy: SourceStrategy,
+        task: Option<usize>,
     ) -> Result<(String, WheelFilename, ResolutionMetadata), Error> {
         debug!("Building: {source}");
 
@@ -2216,7 +2402,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             // In the uv build backend, the normalized filename and the disk filename are the same.
             name.to_string()
         } else {
-            self.build_context
+            let mut builder = self
+                .build_context
                 .setup_build(
                     source_root,
                     subdirectory,
@@ -2233,10 +2420,16 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     self.build_stack.cloned().unwrap_or_default(),
                 )
                 .await
-                .map_err(|err| Error::Build(err.into()))?
-                .wheel(temp_dir.path())
-                .await
-                .map_err(Error::Build)?
+                .map_err(|err| Error::Build(err.into()))?;
+
+            // Forward the build output to the reporter, to display the progress of the build.
+            if let (Some(task), Some(reporter)) = (task, self.reporter.clone()) {
+                builder.on_output(Arc::new(move |line: &str| {
+                    reporter.on_build_output(task, line);
+                }));
+            }
+
+            builder.wheel(temp_dir.path()).await.map_err(Error::Build)?
         };
 
         // Read the metadata from the wheel.
@@ -2247,6 +2440,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         validate_metadata(source, &metadata)?;
         validate_filename(&filename, &metadata)?;
 
+        // Apply any post-build optimizations (e.g., recompression) before caching the wheel.
+        let optimizations = self.build_context.wheel_optimizations().to_vec();
+        if !optimizations.is_empty() {
+            let wheel = temp_dir.path().join(&disk_filename);
+            tokio::task::spawn_blocking(move || optimize_wheel(&wheel, &optimizations)).await??;
+        }
+
         // Move the wheel to the cache.
         rename_with_retry(
             temp_dir.path().join(&disk_filename),
@@ -2347,6 +2547,84 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
     }
 }
 
+/// Scope the cache shard to the environment variables that affect a package's build, i.e., those
+/// configured for the package, along with any [`BUILD_INPUT_ENV_VARS`], and to the optimizations
+/// applied to the built wheel.
+pub(crate) fn build_env_shard(
+    cache_shard: CacheShard,
+    configured: Option<&BTreeMap<String, String>>,
+    wheel_optimizations: &[WheelOptimization],
+) -> CacheShard {
+    let mut variables = BUILD_INPUT_ENV.clone();
+    if let Some(configured) = configured {
+        variables.extend(
+            configured
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone())),
+        );
+    }
+
+    // Identify the compilers that the build would invoke, respecting any configured overrides.
+    let compilers = BUILD_COMPILERS
+        .iter()
+        .filter_map(|(variable, default)| {
+            // Ex) `CC="ccache gcc"` invokes `ccache`.
+            let program = variables
+                .get(*variable)
+                .and_then(|value| value.split_whitespace().next())
+                .unwrap_or(default);
+            let identity = compiler_identity(program)?;
+            Some((format!("{variable}:compiler"), identity))
+        })
+        .collect::<Vec<_>>();
+    variables.extend(compilers);
+    let cache_shard = if variables.is_empty() {
+        cache_shard
+    } else {
+        cache_shard.shard(cache_digest(&variables))
+    };
+
+    // Optimized wheels differ from those produced by the build backend, so they're stored apart.
+    let optimizations = wheel_optimizations
+        .iter()
+        .map(ToString::to_string)
+        .collect::<BTreeSet<_>>();
+    if optimizations.is_empty() {
+        cache_shard
+    } else {
+        cache_shard.shard(cache_digest(&optimizations))
+    }
+}
+
+/// Identify a compiler on the `PATH` by its resolved path, size, and modification time, or return
+/// `None` if it can't be found.
+///
+/// Each program is only resolved once per process.
+fn compiler_identity(program: &str) -> Option<String> {
+    static IDENTITIES: LazyLock<Mutex<FxHashMap<String, Option<String>>>> =
+        LazyLock::new(Mutex::default);
+
+    let mut identities = IDENTITIES.lock().unwrap();
+    identities
+        .entry(program.to_string())
+        .or_insert_with(|| {
+            // Follow any symlinks (e.g., `/usr/bin/cc` to `/usr/bin/gcc-13`), such that switching
+            // between compilers is detected.
+            let path = which::which(program).ok()?;
+            let path = fs_err::canonicalize(&path).unwrap_or(path);
+            let metadata = fs_err::metadata(&path).ok()?;
+            let modified = metadata
+                .modified()
+                .ok()?
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .ok()?
+                .as_nanos();
+            debug!("Found compiler for `{program}` at: {}", path.display());
+            Some(format!("{}:{}:{modified}", path.display(), metadata.len()))
+        })
+        .clone()
+}
+
 /// Prune any unused source distributions from the cache.
 pub fn prune(cache: &Cache) -> Result<Removal, Error> {
     let mut removal = Removal::default();
@@ -2683,7 +2961,7 @@ impl LocalRevisionPointer {
 /// Read the [`ResolutionMetadata`] from a source distribution's `PKG-INFO` file, if it uses Metadata 2.2
 /// or later _and_ none of the required fields (`Requires-Python`, `Requires-Dist`, and
 /// `Provides-Extra`) are marked as dynamic.
-async fn read_pkg_info(
+pub(crate) async fn read_pkg_info(
     source_tree: &Path,
     subdirectory: Option<&Path>,
 ) -> Result<ResolutionMetadata, Error> {
@@ -0,0 +1,280 @@
+//! Post-build optimizations for wheels built from source distributions.
+
+use std::io::{BufReader, Read, Write};
+use std::path::Path;
+use std::process::Command;
+
+use data_encoding::BASE64URL_NOPAD;
+use rustc_hash::FxHashMap;
+use sha2::{Digest, Sha256};
+use tracing::debug;
+use zip::write::FileOptions;
+use zip::{CompressionMethod, DateTime, ZipArchive, ZipWriter};
+
+use uv_configuration::WheelOptimization;
+use uv_fs::Simplified;
+use uv_warnings::warn_user_once;
+
+use crate::error::Error;
+
+/// The highest compression level supported by deflate.
+const DEFLATE_LEVEL: i32 = 9;
+
+/// An entry in a wheel archive, read into memory.
+struct WheelEntry {
+    name: String,
+    contents: Vec<u8>,
+    compression: CompressionMethod,
+    last_modified: DateTime,
+    unix_mode: Option<u32>,
+}
+
+/// Apply the given optimizations to the wheel at the given path, in-place.
+///
+/// When stripping debug symbols, the `RECORD` file is updated to reflect the new hash and size of
+/// each modified shared library. Mach-O libraries are left untouched, since stripping them would
+/// invalidate their code signature.
+///
+/// This function performs blocking I/O and spawns `strip` synchronously, so it should be run on a
+/// blocking thread.
+pub(crate) fn optimize_wheel(
+    wheel: &Path,
+    optimizations: &[WheelOptimization],
+) -> Result<(), Error> {
+    if optimizations.is_empty() {
+        return Ok(());
+    }
+    let recompress = optimizations.contains(&WheelOptimization::Recompress);
+    let strip = optimizations.contains(&WheelOptimization::Strip);
+
+    let file = fs_err::File::open(wheel).map_err(Error::CacheRead)?;
+    let original_size = file.metadata().map_err(Error::CacheRead)?.len();
+    let mut archive = ZipArchive::new(BufReader::new(file))?;
+
+    let mut entries = Vec::with_capacity(archive.len());
+    for index in 0..archive.len() {
+        let mut file = archive.by_index(index)?;
+        if file.is_dir() {
+            continue;
+        }
+        let mut contents = Vec::with_capacity(usize::try_from(file.size()).unwrap_or_default());
+        file.read_to_end(&mut contents).map_err(Error::CacheRead)?;
+        entries.push(WheelEntry {
+            name: file.name().to_string(),
+            contents,
+            compression: file.compression(),
+            last_modified: file.last_modified(),
+            unix_mode: file.unix_mode(),
+        });
+    }
+
+    // Strip debug symbols from any bundled shared libraries, tracking the modified entries.
+    let mut modified = FxHashMap::default();
+    if strip {
+        for entry in &mut entries {
+            if !is_shared_library(&entry.name) {
+                continue;
+            }
+            if is_mach_o(&entry.contents) {
+                debug!(
+                    "Skipping `{}`: stripping a Mach-O library invalidates its code signature",
+                    entry.name
+                );
+                continue;
+            }
+            if let Some(stripped) = strip_debug_symbols(&entry.name, &entry.contents)? {
+                debug!(
+                    "Stripped debug symbols from `{}` ({} → {} bytes)",
+                    entry.name,
+                    entry.contents.len(),
+                    stripped.len()
+                );
+                entry.contents = stripped;
+                modified.insert(entry.name.clone(), record_entry(&entry.contents));
+            }
+        }
+    }
+
+    // If nothing would change, leave the wheel as-is.
+    if !recompress && modified.is_empty() {
+        return Ok(());
+    }
+
+    // Update the `RECORD` to reflect the modified entries.
+    if !modified.is_empty() {
+        if let Some(record) = entries.iter_mut().find(|entry| is_record(&entry.name)) {
+            let contents = String::from_utf8_lossy(&record.contents);
+            record.contents = update_record(&contents, &modified).into_bytes();
+        }
+    }
+
+    // Write the optimized wheel alongside the original, then replace it.
+    let temp = wheel.with_extension("whl.tmp");
+    {
+        let file = fs_err::File::create(&temp).map_err(Error::CacheWrite)?;
+        let mut writer = ZipWriter::new(file);
+        for entry in &entries {
+            let mut options = FileOptions::default().last_modified_time(entry.last_modified);
+            options = if recompress {
+                options
+                    .compression_method(CompressionMethod::Deflated)
+                    .compression_level(Some(DEFLATE_LEVEL))
+            } else {
+                options.compression_method(entry.compression)
+            };
+            if let Some(mode) = entry.unix_mode {
+                options = options.unix_permissions(mode);
+            }
+            writer.start_file(entry.name.as_str(), options)?;
+            writer
+                .write_all(&entry.contents)
+                .map_err(Error::CacheWrite)?;
+        }
+        writer.finish()?;
+    }
+    fs_err::rename(&temp, wheel).map_err(Error::CacheWrite)?;
+
+    let optimized_size = fs_err::metadata(wheel).map_err(Error::CacheRead)?.len();
+    debug!(
+        "Optimized wheel `{}` ({original_size} → {optimized_size} bytes)",
+        wheel.user_display()
+    );
+
+    Ok(())
+}
+
+/// Returns `true` if the archive entry is a shared library that may contain debug symbols.
+fn is_shared_library(name: &str) -> bool {
+    let path = Path::new(name);
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("so") || ext.eq_ignore_ascii_case("dylib"))
+        || path
+            .file_name()
+            .and_then(|filename| filename.to_str())
+            .is_some_and(|filename| filename.contains(".so."))
+}
+
+/// Returns `true` if the contents are a Mach-O binary (including universal binaries), which are
+/// code-signed on macOS.
+fn is_mach_o(contents: &[u8]) -> bool {
+    const MAGIC: [[u8; 4]; 6] = [
+        // `MH_MAGIC` and `MH_MAGIC_64`, in either byte order.
+        [0xfe, 0xed, 0xfa, 0xce],
+        [0xce, 0xfa, 0xed, 0xfe],
+        [0xfe, 0xed, 0xfa, 0xcf],
+        [0xcf, 0xfa, 0xed, 0xfe],
+        // `FAT_MAGIC`, in either byte order.
+        [0xca, 0xfe, 0xba, 0xbe],
+        [0xbe, 0xba, 0xfe, 0xca],
+    ];
+    contents
+        .get(..4)
+        .is_some_and(|magic| MAGIC.iter().any(|candidate| candidate == magic))
+}
+
+/// Returns `true` if the archive entry is the `RECORD` file in the `.dist-info` directory.
+fn is_record(name: &str) -> bool {
+    name.strip_suffix("/RECORD")
+        .is_some_and(|dist_info| dist_info.ends_with(".dist-info") && !dist_info.contains('/'))
+}
+
+/// Strip debug symbols from a shared library, returning the stripped contents, or `None` if the
+/// library could not be stripped.
+fn strip_debug_symbols(name: &str, contents: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+    let temp_dir = tempfile::tempdir().map_err(Error::CacheWrite)?;
+    let path = temp_dir.path().join("library");
+    fs_err::write(&path, contents).map_err(Error::CacheWrite)?;
+
+    // `-S` removes debug symbols with both GNU and LLVM/Apple `strip`.
+    let output = match Command::new("strip").arg("-S").arg(&path).output() {
+        Ok(output) => output,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            warn_user_once!(
+                "Unable to strip debug symbols from built wheels: `strip` was not found in `PATH`"
+            );
+            return Ok(None);
+        }
+        Err(err) => {
+            debug!("Failed to run `strip` on `{name}`: {err}");
+            return Ok(None);
+        }
+    };
+    if !output.status.success() {
+        debug!(
+            "Failed to strip debug symbols from `{name}`: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        return Ok(None);
+    }
+
+    let stripped = fs_err::read(&path).map_err(Error::CacheRead)?;
+    if stripped.len() >= contents.len() {
+        return Ok(None);
+    }
+    Ok(Some(stripped))
+}
+
+/// Compute the hash and size of a file, as formatted in a `RECORD` entry.
+fn record_entry(contents: &[u8]) -> (String, usize) {
+    let hash = format!(
+        "sha256={}",
+        BASE64URL_NOPAD.encode(&Sha256::digest(contents))
+    );
+    (hash, contents.len())
+}
+
+/// Replace the hash and size of each modified file in a `RECORD`.
+fn update_record(record: &str, modified: &FxHashMap<String, (String, usize)>) -> String {
+    let mut output = String::with_capacity(record.len());
+    for line in record.lines() {
+        let updated = line.rsplitn(3, ',').nth(2).and_then(|path| {
+            modified
+                .get(path)
+                .map(|(hash, size)| format!("{path},{hash},{size}"))
+        });
+        output.push_str(updated.as_deref().unwrap_or(line));
+        output.push('\n');
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use rustc_hash::FxHashMap;
+
+    use super::{is_mach_o, is_record, is_shared_library, update_record};
+
+    #[test]
+    fn shared_library() {
+        assert!(is_shared_library(
+            "foo/_speedups.cpython-312-x86_64-linux-gnu.so"
+        ));
+        assert!(is_shared_library("foo.libs/libgfortran-040039e1.so.5.0.0"));
+        assert!(is_shared_library("foo/.dylibs/libomp.dylib"));
+        assert!(!is_shared_library("foo/__init__.py"));
+        assert!(!is_shared_library("foo/source.sol"));
+    }
+
+    #[test]
+    fn mach_o() {
+        assert!(is_mach_o(&[0xcf, 0xfa, 0xed, 0xfe, 0x07, 0x00, 0x00, 0x01]));
+        assert!(is_mach_o(&[0xca, 0xfe, 0xba, 0xbe, 0x00, 0x00, 0x00, 0x02]));
+        assert!(!is_mach_o(b"\x7fELF\x02\x01\x01"));
+        assert!(!is_mach_o(&[0xcf, 0xfa]));
+    }
+
+    #[test]
+    fn record() {
+        assert!(is_record("foo-1.0.0.dist-info/RECORD"));
+        assert!(!is_record("foo/RECORD"));
+        assert!(!is_record("foo/bar-1.0.0.dist-info/RECORD"));
+
+        let record = "foo/__init__.py,sha256=abc,10\nfoo/_ext.so,sha256=def,1000\nfoo-1.0.0.dist-info/RECORD,,\n";
+        let modified =
+            FxHashMap::from_iter([("foo/_ext.so".to_string(), ("sha256=ghi".to_string(), 500))]);
+        assert_eq!(
+            update_record(record, &modified),
+            "foo/__init__.py,sha256=abc,10\nfoo/_ext.so,sha256=ghi,500\nfoo-1.0.0.dist-info/RECORD,,\n"
+        );
+    }
+}
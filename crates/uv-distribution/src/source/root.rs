@@ -0,0 +1,187 @@
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
+use tracing::debug;
+
+use uv_static::EnvVars;
+
+use crate::Error;
+
+/// The maximum depth at which to search for a nested project root within a source archive.
+const MAX_DEPTH: usize = 4;
+
+/// Determine the subdirectory containing the project within an unpacked source archive.
+///
+/// If a subdirectory was provided (e.g., via a `#subdirectory=` URL fragment), it's returned
+/// as-is. Otherwise, if the archive root doesn't contain a `pyproject.toml`, `setup.py`, or
+/// `setup.cfg`, the shallowest directory that does is returned instead, as long as it's
+/// unambiguous. This handles archives with non-standard layouts, like the auto-generated tarballs
+/// for GitHub repositories in which the project lives in a nested directory.
+///
+/// The search can be disabled by setting `UV_NO_DETECT_SOURCE_ROOT`.
+pub(crate) async fn detect_subdirectory<'a>(
+    root: &Path,
+    subdirectory: Option<&'a Path>,
+) -> Result<Option<Cow<'a, Path>>, Error> {
+    if let Some(subdirectory) = subdirectory {
+        return Ok(Some(Cow::Borrowed(subdirectory)));
+    }
+
+    if std::env::var_os(EnvVars::UV_NO_DETECT_SOURCE_ROOT).is_some() {
+        return Ok(None);
+    }
+
+    let root = root.to_path_buf();
+    let subdirectory = tokio::task::spawn_blocking(move || find_nested_root(&root)).await?;
+    Ok(subdirectory.map(Cow::Owned))
+}
+
+/// Find the shallowest unambiguous project root nested within the given directory, if the
+/// directory itself isn't a project root.
+fn find_nested_root(root: &Path) -> Option<PathBuf> {
+    if !root.is_dir() || is_project_root(root) {
+        return None;
+    }
+
+    let mut level = vec![root.to_path_buf()];
+    for _ in 0..MAX_DEPTH {
+        let next = level
+            .iter()
+            .flat_map(|dir| child_directories(dir))
+            .collect::<Vec<_>>();
+
+        let candidates = next
+            .iter()
+            .filter(|dir| is_project_root(dir))
+            .collect::<Vec<_>>();
+        match candidates.as_slice() {
+            [] => {}
+            [candidate] => {
+                let subdirectory = candidate.strip_prefix(root).ok()?;
+                debug!(
+                    "Using nested project root in source archive: {}",
+                    subdirectory.display()
+                );
+                return Some(subdirectory.to_path_buf());
+            }
+            _ => {
+                debug!(
+                    "Found multiple candidate project roots in source archive: {}",
+                    candidates
+                        .iter()
+                        .filter_map(|dir| dir.strip_prefix(root).ok())
+                        .map(|dir| dir.display().to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                return None;
+            }
+        }
+
+        if next.is_empty() {
+            break;
+        }
+        level = next;
+    }
+
+    None
+}
+
+/// Returns `true` if the directory contains a `pyproject.toml`, `setup.py`, or `setup.cfg`.
+fn is_project_root(dir: &Path) -> bool {
+    dir.join("pyproject.toml").is_file()
+        || dir.join("setup.py").is_file()
+        || dir.join("setup.cfg").is_file()
+}
+
+/// Return the non-hidden child directories of the given directory, in sorted order.
+fn child_directories(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs_err::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut children = entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_dir()))
+        .filter(|entry| !entry.file_name().to_string_lossy().starts_with('.'))
+        .map(|entry| entry.path())
+        .collect::<Vec<_>>();
+    children.sort();
+    children
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{detect_subdirectory, find_nested_root};
+
+    fn touch(path: &Path) {
+        fs_err::create_dir_all(path.parent().unwrap()).unwrap();
+        fs_err::write(path, "").unwrap();
+    }
+
+    #[test]
+    fn top_level() {
+        let root = tempfile::tempdir().unwrap();
+        touch(&root.path().join("pyproject.toml"));
+        touch(&root.path().join("nested").join("setup.py"));
+        assert_eq!(find_nested_root(root.path()), None);
+    }
+
+    #[test]
+    fn setup_cfg() {
+        let root = tempfile::tempdir().unwrap();
+        touch(&root.path().join("setup.cfg"));
+        touch(&root.path().join("docs").join("pyproject.toml"));
+        touch(&root.path().join("tests").join("fixture").join("setup.py"));
+        assert_eq!(find_nested_root(root.path()), None);
+    }
+
+    #[tokio::test]
+    async fn explicit() {
+        let root = tempfile::tempdir().unwrap();
+        touch(&root.path().join("a").join("pyproject.toml"));
+        let subdirectory = Path::new("b");
+        assert_eq!(
+            detect_subdirectory(root.path(), Some(subdirectory))
+                .await
+                .unwrap()
+                .as_deref(),
+            Some(subdirectory)
+        );
+    }
+
+    #[test]
+    fn nested() {
+        let root = tempfile::tempdir().unwrap();
+        touch(&root.path().join("README.md"));
+        touch(&root.path().join("docs").join("conf.py"));
+        touch(
+            &root
+                .path()
+                .join("bindings")
+                .join("python")
+                .join("pyproject.toml"),
+        );
+        touch(
+            &root
+                .path()
+                .join("bindings")
+                .join("python")
+                .join("tests")
+                .join("setup.py"),
+        );
+        assert_eq!(
+            find_nested_root(root.path()),
+            Some(Path::new("bindings").join("python"))
+        );
+    }
+
+    #[test]
+    fn ambiguous() {
+        let root = tempfile::tempdir().unwrap();
+        touch(&root.path().join("a").join("pyproject.toml"));
+        touch(&root.path().join("b").join("setup.py"));
+        assert_eq!(find_nested_root(root.path()), None);
+    }
+}
@@ -1,8 +1,9 @@
+use std::collections::hash_map::Entry;
 use std::path::{Component, Path, PathBuf};
 use std::pin::Pin;
 
 use futures::StreamExt;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tokio_util::compat::{FuturesAsyncReadCompatExt, TokioAsyncReadCompatExt};
 use tracing::warn;
 
@@ -47,6 +48,12 @@ pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
 
     let mut directories = FxHashSet::default();
 
+    // The names of the files in the archive, grouped by their case-folded form, along with any
+    // files that were staged under a temporary name since they collide, by case, with an earlier
+    // file on a case-insensitive filesystem.
+    let mut files: FxHashMap<String, Vec<String>> = FxHashMap::default();
+    let mut staged: FxHashMap<String, PathBuf> = FxHashMap::default();
+
     while let Some(mut entry) = zip.next_with_entry().await? {
         // Construct the (expected) path to the file on-disk.
         let path = entry.reader().entry().filename().as_str()?;
@@ -60,6 +67,7 @@ pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
             zip = entry.skip().await?;
             continue;
         };
+        let name = path.to_string_lossy().into_owned();
         let path = target.join(path);
         let is_dir = entry.reader().entry().dir()?;
 
@@ -75,6 +83,35 @@ pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
                 }
             }
 
+            // If an earlier entry differs from this one only by case and the file already
+            // exists, we're on a case-insensitive filesystem; stage this entry under a temporary
+            // name rather than overwriting the earlier entry. Since the mangled names are
+            // assigned based on all entries that collide (see `uv_fs::case_mangled_indices`),
+            // they're only known once the entire archive has been read.
+            let path = match files.entry(name.to_lowercase()) {
+                Entry::Occupied(mut entry) => {
+                    let names = entry.get_mut();
+                    if names.contains(&name) {
+                        path
+                    } else {
+                        names.push(name.clone());
+                        if path.exists() {
+                            let mut temporary = path.clone().into_os_string();
+                            temporary.push(format!(".uv-case-staged-{}", staged.len()));
+                            let temporary = PathBuf::from(temporary);
+                            staged.insert(name, temporary.clone());
+                            temporary
+                        } else {
+                            path
+                        }
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(vec![name]);
+                    path
+                }
+            };
+
             // We don't know the file permissions here, because we haven't seen the central directory yet.
             let file = fs_err::tokio::File::create(&path).await?;
             let size = entry.reader().entry().uncompressed_size();
@@ -92,6 +129,51 @@ pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
         zip = entry.skip().await?;
     }
 
+    // Move any staged entries to their case-mangled names, keeping the first entry (in sorted
+    // order) of each group under its own name.
+    let mut mangled = FxHashMap::default();
+    for names in files.values() {
+        if !names.iter().any(|name| staged.contains_key(name)) {
+            continue;
+        }
+        let indices = uv_fs::case_mangled_indices(names.iter().map(String::as_str));
+        warn!(
+            "Archive contains files that differ only by case, which can't be extracted side-by-side on a case-insensitive filesystem; storing {} under case-mangled names",
+            names
+                .iter()
+                .filter(|name| indices.contains_key(name.as_str()))
+                .map(|name| format!("`{name}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+
+        // The first entry to be extracted occupies the unmangled name, so move it first.
+        let [first, rest @ ..] = names.as_slice() else {
+            continue;
+        };
+        if let Some(index) = indices.get(first.as_str()) {
+            let path = target.join(first);
+            fs_err::tokio::rename(&path, uv_fs::case_mangled_path(&path, *index)).await?;
+        }
+        for name in rest {
+            let Some(temporary) = staged.get(name) else {
+                continue;
+            };
+            let path = target.join(name);
+            if let Some(index) = indices.get(name.as_str()) {
+                fs_err::tokio::rename(temporary, uv_fs::case_mangled_path(&path, *index)).await?;
+            } else {
+                fs_err::tokio::rename(temporary, &path).await?;
+            }
+        }
+
+        mangled.extend(
+            indices
+                .into_iter()
+                .map(|(name, index)| (name.to_string(), index)),
+        );
+    }
+
     // On Unix, we need to set file permissions, which are stored in the central directory, at the
     // end of the archive. The `ZipFileReader` reads until it sees a central directory signature,
     // which indicates the first entry in the central directory. So we continue reading from there.
@@ -119,7 +201,10 @@ pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
                 let Some(path) = enclosed_name(path) else {
                     continue;
                 };
-                let path = target.join(path);
+                let path = match mangled.get(&*path.to_string_lossy()) {
+                    Some(index) => uv_fs::case_mangled_path(&target.join(path), *index),
+                    None => target.join(path),
+                };
 
                 let permissions = fs_err::tokio::metadata(&path).await?.permissions();
                 if permissions.mode() & 0o111 != 0o111 {
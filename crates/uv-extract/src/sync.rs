@@ -4,11 +4,45 @@ use std::sync::{LazyLock, Mutex};
 use crate::vendor::{CloneableSeekableReader, HasLength};
 use crate::Error;
 use rayon::prelude::*;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::warn;
 use uv_configuration::RAYON_INITIALIZE;
 use zip::ZipArchive;
 
+/// Return the indices of any archive entries that collide, by case, with another entry, if the
+/// target directory is on a case-insensitive filesystem, mapped to the index to use when mangling
+/// their names (see [`uv_fs::case_mangled_indices`]).
+fn case_collisions_to_mangle<R: std::io::Read + std::io::Seek + Clone>(
+    archive: &ZipArchive<R>,
+    target: &Path,
+) -> Result<FxHashMap<usize, usize>, Error> {
+    let mut archive = archive.clone();
+    let names = (0..archive.len())
+        .map(|index| Ok(archive.by_index_raw(index)?.name().to_string()))
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let indices = uv_fs::case_mangled_indices(names.iter().map(String::as_str));
+    if indices.is_empty() || !uv_fs::is_case_insensitive(target) {
+        return Ok(FxHashMap::default());
+    }
+
+    let mut mangled_names = indices
+        .keys()
+        .map(|name| format!("`{name}`"))
+        .collect::<Vec<_>>();
+    mangled_names.sort_unstable();
+    warn!(
+        "Archive contains files that differ only by case, which can't be extracted side-by-side on a case-insensitive filesystem; storing {} under case-mangled names",
+        mangled_names.join(", ")
+    );
+
+    Ok(names
+        .iter()
+        .enumerate()
+        .filter_map(|(index, name)| Some((index, *indices.get(name.as_str())?)))
+        .collect())
+}
+
 /// Unzip a `.zip` archive into the target directory.
 pub fn unzip<R: Send + std::io::Read + std::io::Seek + HasLength>(
     reader: R,
@@ -18,6 +52,12 @@ pub fn unzip<R: Send + std::io::Read + std::io::Seek + HasLength>(
     let reader = std::io::BufReader::new(reader);
     let archive = ZipArchive::new(CloneableSeekableReader::new(reader))?;
     let directories = Mutex::new(FxHashSet::default());
+
+    // On case-insensitive filesystems, entries whose names differ only by case would overwrite
+    // one another (non-deterministically, since we extract in parallel). Keep the first such
+    // entry (in sorted order) under its own name, and store the rest under case-mangled names.
+    let mangled = case_collisions_to_mangle(&archive, target)?;
+
     // Initialize the threadpool with the user settings.
     LazyLock::force(&RAYON_INITIALIZE);
     (0..archive.len())
@@ -49,6 +89,13 @@ pub fn unzip<R: Send + std::io::Read + std::io::Seek + HasLength>(
                 }
             }
 
+            // Store any file that collides, by case, with another file under a mangled name.
+            let path = if let Some(index) = mangled.get(&file_number) {
+                uv_fs::case_mangled_path(&path, *index)
+            } else {
+                path
+            };
+
             // Copy the file contents.
             let outfile = fs_err::File::create(&path)?;
             let size = file.size();
@@ -2,13 +2,16 @@ use std::fmt::Display;
 use std::path::{Path, PathBuf};
 
 use fs2::FileExt;
+use rustc_hash::FxHashMap;
 use tempfile::NamedTempFile;
 use tracing::{debug, error, info, trace, warn};
 
 pub use crate::path::*;
+pub use crate::text::*;
 
 pub mod cachedir;
 mod path;
+mod text;
 pub mod which;
 
 /// Attempt to check if the two paths refer to the same file.
@@ -580,6 +583,91 @@ pub fn is_temporary(path: impl AsRef<Path>) -> bool {
         .is_some_and(|name| name.starts_with(".tmp"))
 }
 
+/// Group the given paths by their case-folded form, returning each group of two or more paths
+/// that differ only by case.
+///
+/// On case-insensitive filesystems (the default on macOS and Windows), the paths within each
+/// group resolve to the same file, so writing all of them would silently overwrite one with
+/// another. Groups are returned in the order in which their first path appears.
+pub fn case_collisions<'a>(paths: impl IntoIterator<Item = &'a str>) -> Vec<Vec<&'a str>> {
+    let mut groups: Vec<Vec<&'a str>> = Vec::new();
+    let mut index: FxHashMap<String, usize> = FxHashMap::default();
+    for path in paths {
+        let folded = path.to_lowercase();
+        if let Some(&position) = index.get(&folded) {
+            if !groups[position].contains(&path) {
+                groups[position].push(path);
+            }
+        } else {
+            index.insert(folded, groups.len());
+            groups.push(vec![path]);
+        }
+    }
+    groups.retain(|group| group.len() > 1);
+    groups
+}
+
+/// Return the index of the case-mangled name (see [`case_mangled_path`]) under which to store
+/// each of the given paths that differs only by case from another.
+///
+/// Within each group of colliding paths, the indices are assigned in sorted order, such that the
+/// assignment doesn't depend on the order in which the paths are provided (e.g., the order of the
+/// entries in an archive, as opposed to the order of the entries in its `RECORD`). The first path
+/// in each group retains its own name, and so is omitted.
+pub fn case_mangled_indices<'a>(
+    paths: impl IntoIterator<Item = &'a str>,
+) -> FxHashMap<&'a str, usize> {
+    let mut indices = FxHashMap::default();
+    for mut group in case_collisions(paths) {
+        group.sort_unstable();
+        indices.extend(group.into_iter().enumerate().skip(1).map(|(i, p)| (p, i)));
+    }
+    indices
+}
+
+/// Return the path under which to store the `index`-th file that differs only by case from an
+/// earlier file (e.g., `pkg/readme.txt.uv-case-1` for `pkg/readme.txt`), such that both files can
+/// be stored side-by-side on a case-insensitive filesystem.
+pub fn case_mangled_path(path: &Path, index: usize) -> PathBuf {
+    let mut path = path.as_os_str().to_os_string();
+    path.push(format!(".uv-case-{index}"));
+    PathBuf::from(path)
+}
+
+/// Returns `true` if the filesystem containing the given directory is case-insensitive.
+///
+/// The check creates a temporary file in the directory and tests whether it can be found under
+/// a case-swapped name. If the probe fails, the filesystem is assumed to be case-sensitive.
+pub fn is_case_insensitive(dir: &Path) -> bool {
+    let probe = match tempfile::Builder::new()
+        .prefix(".tmpCaseProbe")
+        .tempfile_in(dir)
+    {
+        Ok(probe) => probe,
+        Err(err) => {
+            debug!(
+                "Failed to probe case sensitivity of `{}`: {err}",
+                dir.user_display()
+            );
+            return false;
+        }
+    };
+    let Some(name) = probe.path().file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    let swapped: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_uppercase() {
+                c.to_ascii_lowercase()
+            } else {
+                c.to_ascii_uppercase()
+            }
+        })
+        .collect();
+    dir.join(swapped).exists()
+}
+
 /// A file lock that is automatically released when dropped.
 #[derive(Debug)]
 pub struct LockedFile(fs_err::File);
@@ -761,3 +849,59 @@ pub fn copy_dir_all(src: impl AsRef<Path>, dst: impl AsRef<Path>) -> std::io::Re
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_case_collisions() {
+        let paths = [
+            "pkg/__init__.py",
+            "pkg/Readme.txt",
+            "pkg/README.txt",
+            "pkg/readme.txt",
+            "pkg/Data/file.txt",
+            "pkg/data/file.txt",
+            "pkg/__init__.py",
+        ];
+        assert_eq!(
+            case_collisions(paths),
+            vec![
+                vec!["pkg/Readme.txt", "pkg/README.txt", "pkg/readme.txt"],
+                vec!["pkg/Data/file.txt", "pkg/data/file.txt"],
+            ]
+        );
+        assert!(case_collisions(["a.py", "b.py"]).is_empty());
+    }
+
+    #[test]
+    fn test_case_mangled_indices() {
+        let archive = [
+            "pkg/readme.txt",
+            "pkg/README.txt",
+            "pkg/Readme.txt",
+            "pkg/a.py",
+        ];
+        let record = [
+            "pkg/a.py",
+            "pkg/Readme.txt",
+            "pkg/readme.txt",
+            "pkg/README.txt",
+        ];
+        let indices = case_mangled_indices(archive);
+        assert_eq!(indices, case_mangled_indices(record));
+        assert_eq!(indices.get("pkg/README.txt"), None);
+        assert_eq!(indices.get("pkg/Readme.txt"), Some(&1));
+        assert_eq!(indices.get("pkg/readme.txt"), Some(&2));
+        assert_eq!(indices.get("pkg/a.py"), None);
+    }
+
+    #[test]
+    fn test_case_mangled_path() {
+        assert_eq!(
+            case_mangled_path(Path::new("pkg/README.txt"), 2),
+            PathBuf::from("pkg/README.txt.uv-case-2")
+        );
+    }
+}
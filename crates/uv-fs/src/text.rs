@@ -0,0 +1,179 @@
+use std::io::Read;
+
+use encoding_rs_io::DecodeReaderBytes;
+
+/// The byte-level conventions of an existing text file: its encoding, line endings, and whether
+/// it ends with a newline.
+///
+/// Used to rewrite a file (e.g., a `requirements.txt` produced on Windows) without changing its
+/// conventions, and thus without producing a whole-file diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextFormat {
+    pub encoding: TextEncoding,
+    pub line_ending: LineEnding,
+    /// Whether the file ends with a newline.
+    pub final_newline: bool,
+}
+
+impl Default for TextFormat {
+    /// UTF-8 with `\n` line endings and a final newline, as used for newly created files.
+    fn default() -> Self {
+        Self {
+            encoding: TextEncoding::default(),
+            line_ending: LineEnding::default(),
+            final_newline: true,
+        }
+    }
+}
+
+/// The encoding of a text file, as determined by its byte order mark.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TextEncoding {
+    /// UTF-8, without a byte order mark.
+    #[default]
+    Utf8,
+    /// UTF-8, with a byte order mark.
+    Utf8Bom,
+    /// Little-endian UTF-16, with a byte order mark (e.g., as written by PowerShell redirects).
+    Utf16Le,
+    /// Big-endian UTF-16, with a byte order mark.
+    Utf16Be,
+}
+
+/// The line endings used in a text file.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    #[default]
+    Lf,
+    CrLf,
+}
+
+impl TextFormat {
+    /// Detect the format of the given file contents.
+    ///
+    /// The line ending is determined by the first line break in the file. Files without any line
+    /// breaks are assumed to use `\n`; empty files are assumed to end with a newline.
+    pub fn detect(raw: &[u8]) -> Self {
+        let encoding = if raw.starts_with(&[0xEF, 0xBB, 0xBF]) {
+            TextEncoding::Utf8Bom
+        } else if raw.starts_with(&[0xFF, 0xFE]) {
+            TextEncoding::Utf16Le
+        } else if raw.starts_with(&[0xFE, 0xFF]) {
+            TextEncoding::Utf16Be
+        } else {
+            TextEncoding::Utf8
+        };
+
+        let mut text = String::new();
+        if DecodeReaderBytes::new(raw)
+            .read_to_string(&mut text)
+            .is_err()
+        {
+            return Self {
+                encoding,
+                ..Self::default()
+            };
+        }
+
+        let line_ending = match text.find('\n') {
+            Some(index) if text[..index].ends_with('\r') => LineEnding::CrLf,
+            _ => LineEnding::Lf,
+        };
+        let final_newline = text.is_empty() || text.ends_with('\n');
+
+        Self {
+            encoding,
+            line_ending,
+            final_newline,
+        }
+    }
+
+    /// Encode the given `\n`-delimited text in this format.
+    pub fn encode(&self, text: &str) -> Vec<u8> {
+        let text = if self.final_newline {
+            text
+        } else {
+            text.strip_suffix('\n').unwrap_or(text)
+        };
+        let text = match self.line_ending {
+            LineEnding::Lf => text.to_string(),
+            LineEnding::CrLf => text.replace("\r\n", "\n").replace('\n', "\r\n"),
+        };
+
+        match self.encoding {
+            TextEncoding::Utf8 => text.into_bytes(),
+            TextEncoding::Utf8Bom => [&[0xEF, 0xBB, 0xBF][..], text.as_bytes()].concat(),
+            TextEncoding::Utf16Le => [0xFF, 0xFE]
+                .into_iter()
+                .chain(text.encode_utf16().flat_map(u16::to_le_bytes))
+                .collect(),
+            TextEncoding::Utf16Be => [0xFE, 0xFF]
+                .into_iter()
+                .chain(text.encode_utf16().flat_map(u16::to_be_bytes))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_default() {
+        assert_eq!(TextFormat::detect(b""), TextFormat::default());
+        assert_eq!(TextFormat::detect(b"flask==3.0.0\n"), TextFormat::default());
+    }
+
+    #[test]
+    fn detect_crlf_without_final_newline() {
+        let format = TextFormat::detect(b"flask==3.0.0\r\nanyio==4.0.0");
+        assert_eq!(
+            format,
+            TextFormat {
+                encoding: TextEncoding::Utf8,
+                line_ending: LineEnding::CrLf,
+                final_newline: false,
+            }
+        );
+        assert_eq!(
+            format.encode("flask==3.1.0\nanyio==4.0.0\n"),
+            b"flask==3.1.0\r\nanyio==4.0.0"
+        );
+    }
+
+    #[test]
+    fn roundtrip_utf8_bom() {
+        let raw = b"\xEF\xBB\xBFflask==3.0.0\r\n";
+        let format = TextFormat::detect(raw);
+        assert_eq!(format.encoding, TextEncoding::Utf8Bom);
+        assert_eq!(format.encode("flask==3.0.0\n"), raw);
+    }
+
+    #[test]
+    fn roundtrip_utf16() {
+        for (encoding, bom, encode) in [
+            (
+                TextEncoding::Utf16Le,
+                [0xFF, 0xFE],
+                u16::to_le_bytes as fn(u16) -> [u8; 2],
+            ),
+            (TextEncoding::Utf16Be, [0xFE, 0xFF], u16::to_be_bytes),
+        ] {
+            let raw: Vec<u8> = bom
+                .into_iter()
+                .chain("flask==3.0.0\r\n".encode_utf16().flat_map(encode))
+                .collect();
+            let format = TextFormat::detect(&raw);
+            assert_eq!(
+                format,
+                TextFormat {
+                    encoding,
+                    line_ending: LineEnding::CrLf,
+                    final_newline: true,
+                }
+            );
+            assert_eq!(format.encode("flask==3.0.0\n"), raw);
+        }
+    }
+}
@@ -138,6 +138,7 @@ impl From<GitUrl> for Url {
                 | GitReference::Tag(rev)
                 | GitReference::BranchOrTag(rev)
                 | GitReference::NamedRef(rev)
+                | GitReference::VersionRange(rev)
                 | GitReference::BranchOrTagOrCommit(rev) => {
                     url.set_path(&format!("{}@{}", url.path(), rev));
                 }
@@ -1,6 +1,9 @@
 use std::fmt::Display;
 use std::str;
 
+/// The prefix for a [`GitReference::VersionRange`], as in `semver:^2.3`.
+const VERSION_RANGE_PREFIX: &str = "semver:";
+
 /// A reference to commit or commit-ish.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum GitReference {
@@ -14,6 +17,8 @@ pub enum GitReference {
     BranchOrTagOrCommit(String),
     /// From a named reference, like `refs/pull/493/head`.
     NamedRef(String),
+    /// From a range of version tags, like `semver:^2.3`, resolved to the highest matching tag.
+    VersionRange(String),
     /// The default branch of the repository, the reference named `HEAD`.
     DefaultBranch,
 }
@@ -24,6 +29,8 @@ impl GitReference {
     pub fn from_rev(rev: String) -> Self {
         if rev.starts_with("refs/") {
             Self::NamedRef(rev)
+        } else if rev.starts_with(VERSION_RANGE_PREFIX) {
+            Self::VersionRange(rev)
         } else if looks_like_commit_hash(&rev) {
             Self::BranchOrTagOrCommit(rev)
         } else {
@@ -39,6 +46,7 @@ impl GitReference {
             Self::BranchOrTag(rev) => Some(rev),
             Self::BranchOrTagOrCommit(rev) => Some(rev),
             Self::NamedRef(rev) => Some(rev),
+            Self::VersionRange(rev) => Some(rev),
             Self::DefaultBranch => None,
         }
     }
@@ -51,6 +59,7 @@ impl GitReference {
             Self::BranchOrTag(rev) => rev,
             Self::BranchOrTagOrCommit(rev) => rev,
             Self::NamedRef(rev) => rev,
+            Self::VersionRange(rev) => rev,
             Self::DefaultBranch => "HEAD",
         }
     }
@@ -63,9 +72,19 @@ impl GitReference {
             Self::BranchOrTag(_) => "branch or tag",
             Self::BranchOrTagOrCommit(_) => "branch, tag, or commit",
             Self::NamedRef(_) => "ref",
+            Self::VersionRange(_) => "version range",
             Self::DefaultBranch => "default branch",
         }
     }
+
+    /// Returns the version range (e.g., `^2.3` in `semver:^2.3`), if this is a
+    /// [`GitReference::VersionRange`].
+    pub fn version_range(&self) -> Option<&str> {
+        match self {
+            Self::VersionRange(rev) => rev.strip_prefix(VERSION_RANGE_PREFIX),
+            _ => None,
+        }
+    }
 }
 
 impl Display for GitReference {
@@ -91,6 +91,11 @@ impl ReferenceOrOid<'_> {
             // Resolve a named reference.
             Self::Reference(GitReference::NamedRef(s)) => repo.rev_parse(&format!("{s}^0")),
 
+            // Version ranges are resolved to a tag prior to fetching.
+            Self::Reference(GitReference::VersionRange(s)) => Err(anyhow::format_err!(
+                "version range `{s}` must be resolved to a tag"
+            )),
+
             // Resolve a specific commit.
             Self::Oid(s) => repo.rev_parse(&format!("{s}^0")),
         };
@@ -231,6 +236,17 @@ impl GitRemote {
         client: &ClientWithMiddleware,
         disable_ssl: bool,
     ) -> Result<(GitDatabase, GitOid)> {
+        // If the reference is a version range, resolve it to the highest matching tag, unless
+        // we've already locked a revision.
+        let selected;
+        let reference = match reference.version_range() {
+            Some(range) if locked_rev.is_none() => {
+                selected = GitReference::Tag(self.select_tag(range, disable_ssl)?);
+                &selected
+            }
+            _ => reference,
+        };
+
         let reference = locked_rev
             .map(ReferenceOrOid::Oid)
             .unwrap_or(ReferenceOrOid::Reference(reference));
@@ -279,6 +295,43 @@ impl GitRemote {
         Ok((GitDatabase { repo }, rev))
     }
 
+    /// Lists the tags of this remote, and selects the one with the highest version that satisfies
+    /// the given version range (e.g., `^2.3`).
+    fn select_tag(&self, range: &str, disable_ssl: bool) -> Result<String> {
+        let specifiers = crate::range::parse_range(range)?;
+
+        debug!("Listing Git tags for: {}", self.url);
+        let mut cmd = ProcessBuilder::new(GIT.as_ref()?);
+        // See `fetch_with_cli` for the rationale behind these environment variables.
+        cmd.env(EnvVars::GIT_TERMINAL_PROMPT, "0");
+        if disable_ssl {
+            cmd.env(EnvVars::GIT_SSL_NO_VERIFY, "true");
+        }
+        cmd.arg("ls-remote")
+            .arg("--tags")
+            .arg("--refs")
+            .arg(self.url.as_str())
+            .env_remove(EnvVars::GIT_DIR)
+            .env_remove(EnvVars::GIT_WORK_TREE)
+            .env_remove(EnvVars::GIT_INDEX_FILE)
+            .env_remove(EnvVars::GIT_OBJECT_DIRECTORY)
+            .env_remove(EnvVars::GIT_ALTERNATE_OBJECT_DIRECTORIES);
+        let output = cmd
+            .exec_with_output()
+            .with_context(|| format!("failed to list tags for: {}", self.url))?;
+        let output = String::from_utf8(output.stdout)?;
+
+        // Each line is of the form `<oid>\trefs/tags/<tag>`.
+        let tags = output
+            .lines()
+            .filter_map(|line| line.split_once('\t'))
+            .filter_map(|(_, name)| name.strip_prefix("refs/tags/"));
+
+        crate::range::select_tag(tags, &specifiers)
+            .map(ToString::to_string)
+            .ok_or_else(|| anyhow::format_err!("no tag matches the version range `{specifiers}`"))
+    }
+
     /// Creates a [`GitDatabase`] of this remote at `db_path`.
     #[allow(clippy::unused_self)]
     pub(crate) fn db_at(&self, db_path: &Path) -> Result<GitDatabase> {
@@ -509,6 +562,12 @@ fn fetch(
             refspecs.push(format!("+{rev}:{rev}"));
         }
 
+        // Version ranges are resolved to a tag prior to fetching, but fall back to fetching all
+        // tags.
+        ReferenceOrOid::Reference(GitReference::VersionRange(_)) => {
+            refspecs.push(String::from("+refs/tags/*:refs/remotes/origin/tags/*"));
+        }
+
         ReferenceOrOid::Oid(rev) => {
             refspecs.push(format!("+{rev}:refs/commit/{rev}"));
         }
@@ -709,6 +768,9 @@ fn github_fast_path(
         ReferenceOrOid::Reference(GitReference::Tag(tag)) => tag,
         ReferenceOrOid::Reference(GitReference::BranchOrTag(branch_or_tag)) => branch_or_tag,
         ReferenceOrOid::Reference(GitReference::NamedRef(rev)) => rev,
+        ReferenceOrOid::Reference(GitReference::VersionRange(_)) => {
+            return Ok(FastPathRev::Indeterminate);
+        }
         ReferenceOrOid::Reference(GitReference::BranchOrTagOrCommit(rev)) => {
             // `revparse_single` (used by `resolve`) is the only way to turn
             // short hash -> long hash, but it also parses other things,
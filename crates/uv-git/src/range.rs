@@ -0,0 +1,183 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context, Result};
+use tracing::debug;
+
+use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
+
+/// Parse a version range, like `^2.3`, into [`VersionSpecifiers`].
+///
+/// The range is a comma-separated list of requirements, each of which is either a Cargo-style
+/// requirement (`^2.3`, `~2.3`, or `2.3`, which is equivalent to `^2.3`), or a PEP 440 specifier
+/// (`>=2.3`).
+pub(crate) fn parse_range(range: &str) -> Result<VersionSpecifiers> {
+    // Comparison operators are percent-encoded when the range is embedded in a URL.
+    let range = percent_encoding::percent_decode_str(range).decode_utf8_lossy();
+
+    let mut specifiers = Vec::new();
+    for requirement in range.split(',').map(str::trim) {
+        if let Some(version) = requirement.strip_prefix('^') {
+            let version = parse_version(version)?;
+            specifiers.push(VersionSpecifier::greater_than_equal_version(
+                version.clone(),
+            ));
+            specifiers.push(VersionSpecifier::less_than_version(caret_upper_bound(
+                &version,
+            )));
+        } else if let Some(version) = requirement
+            .strip_prefix('~')
+            .filter(|version| !version.starts_with('='))
+        {
+            let version = parse_version(version)?;
+            specifiers.push(VersionSpecifier::greater_than_equal_version(
+                version.clone(),
+            ));
+            specifiers.push(VersionSpecifier::less_than_version(tilde_upper_bound(
+                &version,
+            )));
+        } else if requirement.starts_with(|c: char| c.is_ascii_digit()) {
+            let version = parse_version(requirement)?;
+            specifiers.push(VersionSpecifier::greater_than_equal_version(
+                version.clone(),
+            ));
+            specifiers.push(VersionSpecifier::less_than_version(caret_upper_bound(
+                &version,
+            )));
+        } else {
+            specifiers.push(
+                VersionSpecifier::from_str(requirement)
+                    .with_context(|| format!("Invalid version range: `{range}`"))?,
+            );
+        }
+    }
+
+    Ok(specifiers.into_iter().collect())
+}
+
+/// Select the tag with the highest version that satisfies the given [`VersionSpecifiers`].
+///
+/// Tags are parsed as PEP 440 versions, with an optional leading `v` (as in `v2.3.1`). Tags that
+/// aren't valid versions are ignored, as are pre-releases, unless the range itself references a
+/// pre-release.
+pub(crate) fn select_tag<'a>(
+    tags: impl IntoIterator<Item = &'a str>,
+    specifiers: &VersionSpecifiers,
+) -> Option<&'a str> {
+    let allow_prerelease = specifiers.iter().any(VersionSpecifier::any_prerelease);
+
+    let mut best: Option<(Version, &str)> = None;
+    for tag in tags {
+        let Ok(version) = Version::from_str(tag.strip_prefix(['v', 'V']).unwrap_or(tag)) else {
+            continue;
+        };
+        if version.any_prerelease() && !allow_prerelease {
+            continue;
+        }
+        if !specifiers.contains(&version) {
+            continue;
+        }
+        // If multiple tags map to the same version (e.g., `2.3.0` and `v2.3.0`), prefer the
+        // lexicographically smallest, for determinism.
+        let better = match &best {
+            None => true,
+            Some((best_version, best_tag)) => {
+                version > *best_version || (version == *best_version && tag < *best_tag)
+            }
+        };
+        if better {
+            best = Some((version, tag));
+        }
+    }
+
+    if let Some((version, tag)) = &best {
+        debug!("Selected tag `{tag}` ({version}) for version range `{specifiers}`");
+    }
+
+    best.map(|(_, tag)| tag)
+}
+
+/// Parse a bare version within a range.
+fn parse_version(version: &str) -> Result<Version> {
+    Version::from_str(version.trim()).map_err(|err| anyhow!("Invalid version `{version}`: {err}"))
+}
+
+/// Return the exclusive upper bound for a caret requirement, which allows any change that doesn't
+/// modify the left-most non-zero release segment (e.g., `^1.2` is `<2`, and `^0.2` is `<0.3`).
+fn caret_upper_bound(version: &Version) -> Version {
+    let release = version.release();
+    let index = release
+        .iter()
+        .position(|segment| *segment != 0)
+        .unwrap_or(release.len().saturating_sub(1));
+    bump(&release, index)
+}
+
+/// Return the exclusive upper bound for a tilde requirement, which allows patch-level changes if a
+/// minor version is specified (e.g., `~1.2` is `<1.3`), or minor-level changes otherwise (e.g.,
+/// `~1` is `<2`).
+fn tilde_upper_bound(version: &Version) -> Version {
+    let release = version.release();
+    let index = usize::from(release.len() > 1);
+    bump(&release, index)
+}
+
+/// Increment the release segment at the given index, truncating all subsequent segments.
+fn bump(release: &[u64], index: usize) -> Version {
+    let mut segments = release[..=index].to_vec();
+    if let Some(segment) = segments.last_mut() {
+        *segment += 1;
+    }
+    Version::new(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_range, select_tag};
+
+    #[test]
+    fn caret() {
+        assert_eq!(parse_range("^2.3").unwrap().to_string(), ">=2.3, <3");
+        assert_eq!(parse_range("^0.2.1").unwrap().to_string(), ">=0.2.1, <0.3");
+        assert_eq!(
+            parse_range("^0.0.3").unwrap().to_string(),
+            ">=0.0.3, <0.0.4"
+        );
+        assert_eq!(parse_range("2.3").unwrap().to_string(), ">=2.3, <3");
+    }
+
+    #[test]
+    fn tilde() {
+        assert_eq!(parse_range("~2.3.1").unwrap().to_string(), ">=2.3.1, <2.4");
+        assert_eq!(parse_range("~2").unwrap().to_string(), ">=2, <3");
+        assert_eq!(parse_range("~=2.3").unwrap().to_string(), "~=2.3");
+    }
+
+    #[test]
+    fn pep440() {
+        assert_eq!(
+            parse_range("%3E=2.3,%3C2.5").unwrap().to_string(),
+            ">=2.3, <2.5"
+        );
+    }
+
+    #[test]
+    fn select() {
+        let tags = [
+            "v2.2.0",
+            "v2.3.0",
+            "v2.4.1",
+            "v2.5.0rc1",
+            "v3.0.0",
+            "latest",
+            "2.4.1",
+        ];
+        let range = parse_range("^2.3").unwrap();
+        assert_eq!(select_tag(tags, &range), Some("2.4.1"));
+
+        let range = parse_range(">=2.5.0rc1").unwrap();
+        assert_eq!(select_tag(tags, &range), Some("v3.0.0"));
+
+        let range = parse_range("^4").unwrap();
+        assert_eq!(select_tag(tags, &range), None);
+    }
+}
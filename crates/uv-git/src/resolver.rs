@@ -67,6 +67,12 @@ impl GitResolver {
             return Ok(None);
         };
 
+        // Version ranges require listing the repository's tags, which isn't supported by the
+        // GitHub API.
+        if url.reference().version_range().is_some() {
+            return Ok(None);
+        }
+
         // Determine the Git reference.
         let rev = url.reference().as_rev();
 
@@ -12,6 +12,7 @@ use uv_cache_info::CacheInfo;
 use uv_distribution_filename::WheelFilename;
 use uv_pep440::Version;
 use uv_pypi_types::{DirectUrl, Metadata10};
+use uv_warnings::warn_user;
 
 use crate::linker::{LinkMode, Locks};
 use crate::wheel::{
@@ -24,6 +25,10 @@ use crate::{Error, Layout};
 ///
 /// The caller must ensure that the wheel is compatible to the environment.
 ///
+/// If `entry_points` is `false`, no scripts are generated for the wheel's console and GUI entry
+/// points (e.g., when staging wheels for another platform, for which the scripts can't be
+/// generated).
+///
 /// <https://packaging.python.org/en/latest/specifications/binary-distribution-format/#installing-a-wheel-distribution-1-0-py32-none-any-whl>
 ///
 /// Wheel 1.0: <https://www.python.org/dev/peps/pep-0427/>
@@ -37,6 +42,7 @@ pub fn install_wheel(
     cache_info: Option<&CacheInfo>,
     installer: Option<&str>,
     installer_metadata: bool,
+    entry_points: bool,
     link_mode: LinkMode,
     locks: &Locks,
 ) -> Result<(), Error> {
@@ -86,11 +92,51 @@ pub fn install_wheel(
     )?;
     let mut record = read_record_file(&mut record_file)?;
 
+    // If the wheel contains files that differ only by case, any that were stored under
+    // case-mangled names in the cache (see `uv_extract`) must be restored to their original names.
+    // On a case-insensitive filesystem, the files clobber one another, so warn and retain the first
+    // (in sorted order).
+    let collisions = uv_fs::case_collisions(record.iter().map(|entry| entry.path.as_str()));
+    if !collisions.is_empty() {
+        let case_insensitive = uv_fs::is_case_insensitive(site_packages);
+        for group in &collisions {
+            if case_insensitive {
+                warn_user!(
+                    "`{name}` contains files that differ only by case, which collide on a case-insensitive filesystem: {}",
+                    group
+                        .iter()
+                        .map(|path| format!("`{path}`"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+            }
+        }
+
+        // Files are stored under the mangled names assigned by `uv_fs::case_mangled_indices`,
+        // which depend only on the set of colliding paths, and not on their order in the RECORD
+        // (which needn't match their order in the archive).
+        let indices = uv_fs::case_mangled_indices(collisions.iter().flatten().copied());
+        for (path, index) in indices {
+            let path = site_packages.join(path);
+            let mangled = uv_fs::case_mangled_path(&path, index);
+            if fs::symlink_metadata(&mangled).is_err() {
+                continue;
+            }
+            if case_insensitive {
+                fs::remove_file(&mangled)?;
+            } else {
+                fs::rename(&mangled, &path)?;
+            }
+        }
+    }
+
     let (console_scripts, gui_scripts) =
         parse_scripts(&wheel, &dist_info_prefix, None, layout.python_version.1)?;
 
     if console_scripts.is_empty() && gui_scripts.is_empty() {
         trace!(?name, "No entrypoints");
+    } else if !entry_points {
+        trace!(?name, "Skipping entrypoints");
     } else {
         trace!(?name, "Writing entrypoints");
 
@@ -160,3 +206,94 @@ pub fn install_wheel(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use anyhow::Result;
+    use assert_fs::prelude::*;
+    use indoc::indoc;
+
+    use uv_distribution_filename::WheelFilename;
+    use uv_pypi_types::Scheme;
+
+    use crate::linker::{LinkMode, Locks};
+    use crate::Layout;
+
+    use super::install_wheel;
+
+    /// Restore case-mangled files to their original names, even if the `RECORD` lists them in a
+    /// different order than the archive from which they were extracted.
+    #[test]
+    fn restore_case_mangled_files() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+
+        // The files as extracted (on a case-insensitive filesystem) from an archive that lists
+        // `readme.txt`, `README.txt`, and `Readme.txt`, in that order.
+        let wheel = temp_dir.child("wheel");
+        wheel
+            .child("case_collision/README.txt")
+            .write_str("upper")?;
+        wheel
+            .child("case_collision/Readme.txt.uv-case-1")
+            .write_str("title")?;
+        wheel
+            .child("case_collision/readme.txt.uv-case-2")
+            .write_str("lower")?;
+        wheel
+            .child("case_collision-1.0.0.dist-info/METADATA")
+            .write_str("Metadata-Version: 2.1\nName: case-collision\nVersion: 1.0.0\n")?;
+        wheel
+            .child("case_collision-1.0.0.dist-info/WHEEL")
+            .write_str("Wheel-Version: 1.0\nRoot-Is-Purelib: true\nTag: py3-none-any\n")?;
+        wheel
+            .child("case_collision-1.0.0.dist-info/RECORD")
+            .write_str(indoc! {"
+                case_collision/Readme.txt,,
+                case_collision/readme.txt,,
+                case_collision/README.txt,,
+                case_collision-1.0.0.dist-info/METADATA,,
+                case_collision-1.0.0.dist-info/WHEEL,,
+                case_collision-1.0.0.dist-info/RECORD,,
+            "})?;
+
+        let site_packages = temp_dir.child("site-packages");
+        site_packages.create_dir_all()?;
+        let layout = Layout {
+            sys_executable: temp_dir.join("bin/python"),
+            python_version: (3, 12),
+            os_name: "posix".to_string(),
+            scheme: Scheme {
+                purelib: site_packages.to_path_buf(),
+                platlib: site_packages.to_path_buf(),
+                scripts: temp_dir.join("bin"),
+                data: temp_dir.to_path_buf(),
+                include: temp_dir.join("include"),
+            },
+        };
+
+        install_wheel(
+            &layout,
+            false,
+            wheel.path(),
+            &WheelFilename::from_str("case_collision-1.0.0-py3-none-any.whl")?,
+            None,
+            None,
+            None,
+            false,
+            false,
+            LinkMode::Copy,
+            &Locks::default(),
+        )?;
+
+        let package = site_packages.child("case_collision");
+        package.child("README.txt").assert("upper");
+        package.child("Readme.txt").assert("title");
+        package.child("readme.txt").assert("lower");
+        assert!(!package.child("Readme.txt.uv-case-1").exists());
+        assert!(!package.child("readme.txt.uv-case-2").exists());
+
+        Ok(())
+    }
+}
@@ -13,13 +13,15 @@ use uv_pypi_types::Scheme;
 pub use install::install_wheel;
 pub use linker::{LinkMode, Locks};
 pub use uninstall::{uninstall_egg, uninstall_legacy_editable, uninstall_wheel, Uninstall};
-pub use wheel::{parse_wheel_file, read_record_file, LibKind};
+pub use verify::{recorded_files, verify_wheel, Verification};
+pub use wheel::{parse_wheel_file, read_record_file, read_wheel_tags, LibKind};
 
 mod install;
 mod linker;
 mod record;
 mod script;
 mod uninstall;
+mod verify;
 mod wheel;
 
 /// The layout of the target environment into which a wheel can be installed.
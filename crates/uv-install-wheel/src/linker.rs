@@ -62,6 +62,31 @@ impl LinkMode {
         }
     }
 
+    /// Check that the link mode is supported from the `from` directory (e.g., the cache) into
+    /// the `to` directory (e.g., a virtual environment).
+    ///
+    /// The link is created between temporary files in the `from` directory, such that nothing is
+    /// written to the `to` directory; for link modes that can't cross filesystems, the `to`
+    /// directory must also reside on the same device as the `from` directory.
+    ///
+    /// If the link mode isn't supported, installation falls back to copying.
+    pub fn check(self, from: impl AsRef<Path>, to: impl AsRef<Path>) -> std::io::Result<()> {
+        let temp_dir = tempdir_in(from.as_ref())?;
+        let source = temp_dir.path().join("source");
+        let link = temp_dir.path().join("link");
+        fs::write(&source, b"")?;
+        match self {
+            Self::Clone => reflink::reflink(&source, &link)?,
+            Self::Copy => fs::copy(&source, &link).map(|_| ())?,
+            Self::Hardlink => fs::hard_link(&source, &link)?,
+            Self::Symlink => create_symlink(&source, &link)?,
+        }
+        if matches!(self, Self::Clone | Self::Hardlink) {
+            same_device(from.as_ref(), to.as_ref())?;
+        }
+        Ok(())
+    }
+
     /// Returns `true` if the link mode is [`LinkMode::Symlink`].
     pub fn is_symlink(&self) -> bool {
         matches!(self, Self::Symlink)
@@ -491,6 +516,31 @@ fn synchronized_copy(from: &Path, to: &Path, locks: &Locks) -> std::io::Result<(
     Ok(())
 }
 
+/// Check that two paths reside on the same device.
+#[cfg(unix)]
+fn same_device(from: &Path, to: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::MetadataExt;
+
+    if fs::metadata(from)?.dev() == fs::metadata(to)?.dev() {
+        Ok(())
+    } else {
+        Err(std::io::Error::other(format!(
+            "`{}` and `{}` are on different filesystems",
+            from.display(),
+            to.display()
+        )))
+    }
+}
+
+/// Check that two paths reside on the same device.
+///
+/// Device identifiers aren't available on this platform, so the check always passes.
+#[cfg(not(unix))]
+#[allow(clippy::unnecessary_wraps)]
+fn same_device(_from: &Path, _to: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
 #[cfg(unix)]
 fn create_symlink<P: AsRef<Path>, Q: AsRef<Path>>(original: P, link: Q) -> std::io::Result<()> {
     std::os::unix::fs::symlink(original, link)
@@ -0,0 +1,242 @@
+use std::collections::BTreeSet;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+
+use data_encoding::BASE64URL_NOPAD;
+use fs_err as fs;
+use rustc_hash::FxHashSet;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use walkdir::WalkDir;
+
+use uv_fs::Simplified;
+
+use crate::record::RecordEntry;
+use crate::uninstall::normalize_path;
+use crate::wheel::read_record_file;
+use crate::Error;
+
+/// The result of verifying an installed wheel against its `RECORD` file.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Verification {
+    /// Files whose contents don't match the hash or size recorded in `RECORD`.
+    pub modified: BTreeSet<String>,
+    /// Files listed in `RECORD` that don't exist.
+    pub missing: BTreeSet<String>,
+    /// Files within the wheel's top-level directories that aren't listed in any `RECORD`.
+    pub extra: BTreeSet<String>,
+}
+
+impl Verification {
+    /// Returns `true` if the installed wheel matches its `RECORD` file.
+    pub fn is_empty(&self) -> bool {
+        self.modified.is_empty() && self.missing.is_empty() && self.extra.is_empty()
+    }
+}
+
+/// Read the paths of the files recorded in the `RECORD` file of the given `.dist-info`
+/// directory.
+///
+/// The paths are absolute and normalized, such that they can be compared across the `RECORD`
+/// files of each wheel installed in the same environment.
+pub fn recorded_files(dist_info: &Path) -> Result<Vec<PathBuf>, Error> {
+    let Some(site_packages) = dist_info.parent() else {
+        return Err(Error::BrokenVenv(
+            "dist-info directory is not in a site-packages directory".to_string(),
+        ));
+    };
+    Ok(read_record(dist_info)?
+        .into_iter()
+        .map(|entry| normalize_path(&site_packages.join(&entry.path)))
+        .collect())
+}
+
+/// Verify the wheel represented by the given `.dist-info` directory against its `RECORD` file.
+///
+/// Each recorded file is re-hashed and compared against its recorded hash and size. Any file
+/// within the wheel's top-level directories (e.g., `foo/` for a wheel that installs
+/// `foo/__init__.py`) that's absent from `recorded` is reported as extra, where `recorded`
+/// contains the files recorded by every wheel in the environment (see [`recorded_files`]).
+/// Bytecode in `__pycache__` directories is never reported, as it's generated after installation.
+pub fn verify_wheel(
+    dist_info: &Path,
+    recorded: &FxHashSet<PathBuf>,
+) -> Result<Verification, Error> {
+    let Some(site_packages) = dist_info.parent() else {
+        return Err(Error::BrokenVenv(
+            "dist-info directory is not in a site-packages directory".to_string(),
+        ));
+    };
+
+    let mut verification = Verification::default();
+    let mut top_level = BTreeSet::new();
+
+    for entry in read_record(dist_info)? {
+        let path = site_packages.join(&entry.path);
+
+        // Track the top-level directories within `site-packages`, excluding the `.dist-info`
+        // directory itself.
+        let mut components = Path::new(&entry.path).components();
+        if let (Some(Component::Normal(first)), Some(_)) = (components.next(), components.next()) {
+            if site_packages.join(first) != dist_info {
+                top_level.insert(site_packages.join(first));
+            }
+        }
+
+        // The `RECORD` file can't contain its own hash, nor can any signatures.
+        let Some(expected) = entry.hash.as_deref() else {
+            if !path.exists() {
+                verification.missing.insert(entry.path);
+            }
+            continue;
+        };
+
+        let file = match fs::File::open(&path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                verification.missing.insert(entry.path);
+                continue;
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let Some((algorithm, _)) = expected.split_once('=') else {
+            return Err(Error::RecordFile(format!(
+                "invalid hash for `{}`: {expected}",
+                entry.path
+            )));
+        };
+        let (size, actual) = hash_file(file, algorithm)?.ok_or_else(|| {
+            Error::RecordFile(format!(
+                "unsupported hash algorithm for `{}`: {algorithm}",
+                entry.path
+            ))
+        })?;
+        if actual != expected || entry.size.is_some_and(|expected| expected != size) {
+            verification.modified.insert(entry.path);
+        }
+    }
+
+    // Identify any unrecorded files within the top-level directories.
+    for directory in top_level {
+        for entry in WalkDir::new(&directory)
+            .into_iter()
+            .filter_entry(|entry| entry.file_name() != "__pycache__")
+        {
+            let entry = entry?;
+            if entry.file_type().is_dir() {
+                continue;
+            }
+            if recorded.contains(&normalize_path(entry.path())) {
+                continue;
+            }
+            let relative = entry
+                .path()
+                .strip_prefix(site_packages)
+                .expect("walked path is within site-packages");
+            verification
+                .extra
+                .insert(relative.portable_display().to_string());
+        }
+    }
+
+    Ok(verification)
+}
+
+/// Read the `RECORD` file from the given `.dist-info` directory.
+fn read_record(dist_info: &Path) -> Result<Vec<RecordEntry>, Error> {
+    let record_path = dist_info.join("RECORD");
+    let mut record_file = match fs::File::open(&record_path) {
+        Ok(record_file) => record_file,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Err(Error::MissingRecord(record_path));
+        }
+        Err(err) => return Err(err.into()),
+    };
+    read_record_file(&mut record_file)
+}
+
+/// Hash the given file with the given algorithm, returning its size and its hash in `RECORD`
+/// format (e.g., `sha256=...`), or `None` if the algorithm isn't supported.
+fn hash_file(mut file: impl Read, algorithm: &str) -> Result<Option<(u64, String)>, Error> {
+    fn digest<D: Digest>(mut file: impl Read) -> Result<(u64, Vec<u8>), Error> {
+        let mut hasher = D::new();
+        let mut buf = vec![0; 8 * 1024];
+        let mut size = 0;
+        loop {
+            let len = match file.read(&mut buf) {
+                Ok(0) => break,
+                Ok(len) => len,
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err.into()),
+            };
+            hasher.update(&buf[..len]);
+            size += len as u64;
+        }
+        Ok((size, hasher.finalize().to_vec()))
+    }
+
+    let (size, hash) = match algorithm {
+        "sha256" => digest::<Sha256>(&mut file)?,
+        "sha384" => digest::<Sha384>(&mut file)?,
+        "sha512" => digest::<Sha512>(&mut file)?,
+        _ => return Ok(None),
+    };
+    Ok(Some((
+        size,
+        format!("{algorithm}={}", BASE64URL_NOPAD.encode(&hash)),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use assert_fs::prelude::*;
+    use indoc::indoc;
+    use rustc_hash::FxHashSet;
+
+    use super::{recorded_files, verify_wheel};
+
+    #[test]
+    fn verify() -> Result<()> {
+        let site_packages = assert_fs::TempDir::new()?;
+        site_packages
+            .child("foo/__init__.py")
+            .write_str("print('hello')\n")?;
+        site_packages.child("foo/bar.py").write_str("tampered\n")?;
+        site_packages.child("foo/extra.py").touch()?;
+        site_packages
+            .child("foo/__pycache__/__init__.cpython-312.pyc")
+            .touch()?;
+        site_packages.child("foo-1.0.dist-info/METADATA").touch()?;
+        site_packages
+            .child("foo-1.0.dist-info/RECORD")
+            .write_str(indoc! {r"
+                foo/__init__.py,sha256=A-aT2fL2h-D0Djao33_LTRwil0ASt8KlXAAOsw8wWCQ,15
+                foo/bar.py,sha256=A-aT2fL2h-D0Djao33_LTRwil0ASt8KlXAAOsw8wWCQ,15
+                foo/missing.py,sha256=A-aT2fL2h-D0Djao33_LTRwil0ASt8KlXAAOsw8wWCQ,15
+                foo-1.0.dist-info/METADATA,sha256=47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU,0
+                foo-1.0.dist-info/RECORD,,
+            "})?;
+
+        let dist_info = site_packages.child("foo-1.0.dist-info");
+        let recorded = recorded_files(dist_info.path())?
+            .into_iter()
+            .collect::<FxHashSet<_>>();
+        let verification = verify_wheel(dist_info.path(), &recorded)?;
+
+        assert_eq!(
+            verification.modified.into_iter().collect::<Vec<_>>(),
+            vec!["foo/bar.py"]
+        );
+        assert_eq!(
+            verification.missing.into_iter().collect::<Vec<_>>(),
+            vec!["foo/missing.py"]
+        );
+        assert_eq!(
+            verification.extra.into_iter().collect::<Vec<_>>(),
+            vec!["foo/extra.py"]
+        );
+
+        Ok(())
+    }
+}
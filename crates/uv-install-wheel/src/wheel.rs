@@ -730,6 +730,20 @@ pub fn read_record_file(record: &mut impl Read) -> Result<Vec<RecordEntry>, Erro
         .collect()
 }
 
+/// Read the `Tag` entries from the WHEEL file of an unzipped wheel (e.g.,
+/// `cp312-cp312-manylinux_2_17_x86_64`).
+///
+/// Returns an empty list if the WHEEL file doesn't declare any tags.
+pub fn read_wheel_tags(wheel: impl AsRef<Path>) -> Result<Vec<String>, Error> {
+    let dist_info_prefix = find_dist_info(&wheel)?;
+    let wheel_file_path = wheel
+        .as_ref()
+        .join(format!("{dist_info_prefix}.dist-info/WHEEL"));
+    let wheel_text = fs::read_to_string(wheel_file_path)?;
+    let mut data = parse_email_message_file(&mut wheel_text.as_bytes(), "WHEEL")?;
+    Ok(data.remove("Tag").unwrap_or_default())
+}
+
 /// Parse a file with email message format such as WHEEL and METADATA
 fn parse_email_message_file(
     file: impl Read,
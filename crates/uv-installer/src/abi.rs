@@ -0,0 +1,246 @@
+//! Verify that the wheels to be installed are compatible with the target interpreter's ABI,
+//! beyond the tags in their filenames.
+
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use goblin::elf::Elf;
+use memmap2::Mmap;
+use tracing::{debug, trace};
+use walkdir::WalkDir;
+
+use uv_distribution_filename::WheelFilename;
+use uv_distribution_types::CachedDist;
+use uv_fs::Simplified;
+use uv_platform_tags::{AbiTag, LanguageTag, PlatformTag, Tags};
+use uv_python::Interpreter;
+
+#[derive(thiserror::Error, Debug)]
+pub enum AbiError {
+    #[error("Failed to read the tags of: {0}")]
+    Tags(WheelFilename, #[source] uv_install_wheel::Error),
+    #[error(transparent)]
+    WalkDir(#[from] walkdir::Error),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Join(#[from] tokio::task::JoinError),
+}
+
+/// A wheel that can't be used with the target interpreter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiIncompatibility {
+    /// None of the tags declared in the wheel's `WHEEL` file are supported by the interpreter
+    /// (e.g., a free-threaded wheel on a GIL-enabled interpreter).
+    Tags {
+        filename: WheelFilename,
+        tags: Vec<String>,
+    },
+    /// A shared library bundled with the wheel requires a newer glibc than the target provides.
+    Glibc {
+        filename: WheelFilename,
+        library: PathBuf,
+        required: (u16, u16),
+        available: (u16, u16),
+    },
+}
+
+impl Display for AbiIncompatibility {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tags { filename, tags } => write!(
+                f,
+                "`{filename}` is built for `{}`, which is not supported by the interpreter",
+                tags.join("`, `")
+            ),
+            Self::Glibc {
+                filename,
+                library,
+                required: (required_major, required_minor),
+                available: (available_major, available_minor),
+            } => write!(
+                f,
+                "`{filename}` bundles `{}`, which requires glibc {required_major}.{required_minor}, but the target provides glibc {available_major}.{available_minor}",
+                library.portable_display()
+            ),
+        }
+    }
+}
+
+/// Check each of the given (unzipped) wheels against the target [`Tags`] and [`Interpreter`].
+///
+/// Unlike the filename-based compatibility check applied during resolution, this inspects the
+/// wheel's contents: the tags declared in its `WHEEL` file must be supported by the target, and,
+/// on glibc-based Linux targets, any bundled shared libraries must not require a newer glibc than
+/// the target provides.
+///
+/// The check reads every bundled shared library, so it runs on a blocking thread.
+pub async fn check_abi<'a>(
+    wheels: impl IntoIterator<Item = &'a CachedDist>,
+    tags: &Tags,
+    interpreter: &Interpreter,
+) -> Result<Vec<AbiIncompatibility>, AbiError> {
+    let wheels = wheels
+        .into_iter()
+        .map(|wheel| (wheel.filename().clone(), wheel.path().to_path_buf()))
+        .collect::<Vec<_>>();
+    let tags = tags.clone();
+    let debug = interpreter.is_debug();
+    tokio::task::spawn_blocking(move || check_abi_blocking(&wheels, &tags, debug)).await?
+}
+
+fn check_abi_blocking(
+    wheels: &[(WheelFilename, PathBuf)],
+    tags: &Tags,
+    debug: bool,
+) -> Result<Vec<AbiIncompatibility>, AbiError> {
+    // The glibc version of the target, if it's a glibc-based Linux.
+    let glibc = match tags.platform_tag() {
+        Some(PlatformTag::Manylinux { major, minor, .. }) => Some((*major, *minor)),
+        _ => None,
+    };
+
+    let mut incompatibilities = Vec::new();
+    for (filename, path) in wheels {
+        let declared = uv_install_wheel::read_wheel_tags(path)
+            .map_err(|err| AbiError::Tags(filename.clone(), err))?;
+        if !declared.is_empty() && !declared.iter().any(|tag| is_compatible(tag, tags, debug)) {
+            incompatibilities.push(AbiIncompatibility::Tags {
+                filename: filename.clone(),
+                tags: declared,
+            });
+            continue;
+        }
+
+        // Pure-Python wheels can't bundle native code.
+        if filename.platform_tags().contains(&PlatformTag::Any) {
+            continue;
+        }
+
+        let Some(available) = glibc else {
+            continue;
+        };
+        for entry in WalkDir::new(path) {
+            let entry = entry?;
+            if !entry.file_type().is_file() || !is_shared_library(entry.path()) {
+                continue;
+            }
+            let Some(required) = required_glibc(entry.path())? else {
+                continue;
+            };
+            trace!(
+                "`{}` requires glibc {}.{}",
+                entry.path().user_display(),
+                required.0,
+                required.1
+            );
+            if required > available {
+                let library = entry
+                    .path()
+                    .strip_prefix(path)
+                    .unwrap_or(entry.path())
+                    .to_path_buf();
+                incompatibilities.push(AbiIncompatibility::Glibc {
+                    filename: filename.clone(),
+                    library,
+                    required,
+                    available,
+                });
+            }
+        }
+    }
+
+    Ok(incompatibilities)
+}
+
+/// Returns `true` if the given `{python}-{abi}-{platform}` tag is supported by the target.
+///
+/// CPython ABI tags with a `d` flag (e.g., `cp312d`) are only supported by debug builds, which
+/// the [`Tags`] themselves don't distinguish.
+fn is_compatible(tag: &str, tags: &Tags, debug: bool) -> bool {
+    let mut parts = tag.splitn(3, '-');
+    let (Some(python), Some(abi), Some(platform)) = (parts.next(), parts.next(), parts.next())
+    else {
+        debug!("Ignoring malformed wheel tag: `{tag}`");
+        return false;
+    };
+    if is_debug_abi(abi) && !debug {
+        return false;
+    }
+    let (Ok(python), Ok(abi), Ok(platform)) = (
+        LanguageTag::from_str(python),
+        AbiTag::from_str(abi),
+        PlatformTag::from_str(platform),
+    ) else {
+        debug!("Ignoring unknown wheel tag: `{tag}`");
+        return false;
+    };
+    tags.is_compatible(&[python], &[abi], &[platform])
+}
+
+/// Returns `true` if the ABI tag requires a debug build of CPython (e.g., `cp312d` or `cp37dm`).
+fn is_debug_abi(abi: &str) -> bool {
+    abi.strip_prefix("cp").is_some_and(|rest| {
+        rest.trim_start_matches(|c: char| c.is_ascii_digit())
+            .contains('d')
+    })
+}
+
+/// Returns `true` if the path looks like a shared library (e.g., `foo.so` or `libfoo.so.1`).
+fn is_shared_library(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("so"))
+        || path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.contains(".so."))
+}
+
+/// Return the newest glibc version required by the given shared library, if any.
+///
+/// Returns `None` if the file isn't an ELF binary, or doesn't reference any versioned glibc
+/// symbols.
+fn required_glibc(path: &Path) -> Result<Option<(u16, u16)>, AbiError> {
+    // Bundled libraries can be gigabytes in size (e.g., in `torch`), so map the file rather than
+    // reading it; only the headers and version sections are touched while parsing.
+    let file = fs_err::File::open(path)?;
+    if file.metadata()?.len() == 0 {
+        return Ok(None);
+    }
+    // SAFETY: The wheel is unzipped into the cache, which isn't modified while it's being
+    // checked.
+    #[allow(unsafe_code)]
+    let bytes = unsafe { Mmap::map(file.file())? };
+    let Ok(elf) = Elf::parse(&bytes) else {
+        debug!("Skipping non-ELF file: `{}`", path.user_display());
+        return Ok(None);
+    };
+    let Some(verneed) = elf.verneed.as_ref() else {
+        return Ok(None);
+    };
+
+    let mut required = None;
+    for need in verneed {
+        for aux in &need {
+            let Some(version) = elf
+                .dynstrtab
+                .get_at(aux.vna_name)
+                .and_then(|name| name.strip_prefix("GLIBC_"))
+                .and_then(parse_glibc_version)
+            else {
+                continue;
+            };
+            required = required.max(Some(version));
+        }
+    }
+    Ok(required)
+}
+
+/// Parse a glibc symbol version (e.g., `2.17` or `2.2.5`) into its major and minor components.
+fn parse_glibc_version(version: &str) -> Option<(u16, u16)> {
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
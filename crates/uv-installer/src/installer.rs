@@ -19,6 +19,7 @@ pub struct Installer<'a> {
     reporter: Option<Arc<dyn Reporter>>,
     installer_name: Option<String>,
     installer_metadata: bool,
+    entry_points: bool,
 }
 
 impl<'a> Installer<'a> {
@@ -31,6 +32,7 @@ impl<'a> Installer<'a> {
             reporter: None,
             installer_name: Some("uv".to_string()),
             installer_metadata: true,
+            entry_points: true,
         }
     }
 
@@ -76,6 +78,18 @@ impl<'a> Installer<'a> {
         }
     }
 
+    /// Set whether to generate scripts for the console and GUI entry points of each wheel.
+    ///
+    /// Entry point scripts can only be generated for the host platform, so they should be
+    /// omitted when staging wheels for another platform.
+    #[must_use]
+    pub fn with_entry_points(self, entry_points: bool) -> Self {
+        Self {
+            entry_points,
+            ..self
+        }
+    }
+
     /// Install a set of wheels into a Python virtual environment.
     #[instrument(skip_all, fields(num_wheels = %wheels.len()))]
     pub async fn install(self, wheels: Vec<CachedDist>) -> Result<Vec<CachedDist>> {
@@ -86,6 +100,7 @@ impl<'a> Installer<'a> {
             reporter,
             installer_name,
             installer_metadata,
+            entry_points,
         } = self;
 
         if cache.is_some_and(Cache::is_temporary) {
@@ -111,6 +126,7 @@ impl<'a> Installer<'a> {
                 reporter,
                 relocatable,
                 installer_metadata,
+                entry_points,
             );
 
             // This may fail if the main task was cancelled.
@@ -141,6 +157,7 @@ impl<'a> Installer<'a> {
             self.reporter,
             self.venv.relocatable(),
             self.installer_metadata,
+            self.entry_points,
         )
     }
 }
@@ -155,6 +172,7 @@ fn install(
     reporter: Option<Arc<dyn Reporter>>,
     relocatable: bool,
     installer_metadata: bool,
+    entry_points: bool,
 ) -> Result<Vec<CachedDist>> {
     // Initialize the threadpool with the user settings.
     LazyLock::force(&RAYON_INITIALIZE);
@@ -176,6 +194,7 @@ fn install(
             },
             installer_name.as_deref(),
             installer_metadata,
+            entry_points,
             link_mode,
             &locks,
         )
@@ -1,3 +1,4 @@
+pub use abi::{check_abi, AbiError, AbiIncompatibility};
 pub use compile::{compile_tree, CompileError};
 pub use installer::{Installer, Reporter as InstallReporter};
 pub use plan::{Plan, Planner};
@@ -5,6 +6,7 @@ pub use preparer::{Error as PrepareError, Preparer, Reporter as PrepareReporter}
 pub use site_packages::{SatisfiesResult, SitePackages, SitePackagesDiagnostic};
 pub use uninstall::{uninstall, UninstallError};
 
+mod abi;
 mod compile;
 mod preparer;
 
@@ -4,7 +4,10 @@ use tracing::{debug, warn};
 
 use uv_cache::{Cache, CacheBucket, WheelCache};
 use uv_cache_info::Timestamp;
-use uv_configuration::{BuildOptions, ConfigSettings, Reinstall};
+use uv_configuration::{
+    BuildOptions, ConfigSettings, PackageBuildEnv, PackageConfigSettings, Reinstall,
+    WheelOptimization,
+};
 use uv_distribution::{
     BuiltWheelIndex, HttpArchivePointer, LocalArchivePointer, RegistryWheelIndex,
 };
@@ -52,14 +55,33 @@ impl<'a> Planner<'a> {
         hasher: &HashStrategy,
         index_locations: &IndexLocations,
         config_settings: &ConfigSettings,
+        config_settings_package: &PackageConfigSettings,
+        build_env: &PackageBuildEnv,
+        wheel_optimizations: &[WheelOptimization],
         cache: &Cache,
         venv: &PythonEnvironment,
         tags: &Tags,
     ) -> Result<Plan> {
         // Index all the already-downloaded wheels in the cache.
-        let mut registry_index =
-            RegistryWheelIndex::new(cache, tags, index_locations, hasher, config_settings);
-        let built_index = BuiltWheelIndex::new(cache, tags, hasher, config_settings);
+        let mut registry_index = RegistryWheelIndex::new(
+            cache,
+            tags,
+            index_locations,
+            hasher,
+            config_settings,
+            config_settings_package,
+            build_env,
+            wheel_optimizations,
+        );
+        let built_index = BuiltWheelIndex::new(
+            cache,
+            tags,
+            hasher,
+            config_settings,
+            config_settings_package,
+            build_env,
+            wheel_optimizations,
+        );
 
         let mut cached = vec![];
         let mut remote = vec![];
@@ -267,6 +267,9 @@ pub trait Reporter: Send + Sync {
     /// Callback to invoke when a source distribution build is complete.
     fn on_build_complete(&self, source: &BuildableSource, id: usize);
 
+    /// Callback to invoke when a source distribution build emits a line of output.
+    fn on_build_output(&self, id: usize, line: &str);
+
     /// Callback to invoke when a repository checkout begins.
     fn on_checkout_start(&self, url: &Url, rev: &str) -> usize;
 
@@ -299,6 +302,10 @@ impl uv_distribution::Reporter for Facade {
         self.reporter.on_build_complete(source, id);
     }
 
+    fn on_build_output(&self, id: usize, line: &str) {
+        self.reporter.on_build_output(id, line);
+    }
+
     fn on_checkout_start(&self, url: &Url, rev: &str) -> usize {
         self.reporter.on_checkout_start(url, rev)
     }
@@ -1,6 +1,7 @@
 use std::collections::BTreeSet;
+use std::env;
 use std::iter::Flatten;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use fs_err as fs;
@@ -16,6 +17,7 @@ use uv_normalize::PackageName;
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pypi_types::{Requirement, ResolverMarkerEnvironment, VerbatimParsedUrl};
 use uv_python::{Interpreter, PythonEnvironment};
+use uv_static::EnvVars;
 use uv_types::InstalledPackagesProvider;
 use uv_warnings::warn_user;
 
@@ -277,9 +279,67 @@ impl SitePackages {
             }
         }
 
+        diagnostics.extend(self.shadowing_diagnostics());
+
         Ok(diagnostics)
     }
 
+    /// Validate that the installed packages are not shadowed by distributions that precede them on
+    /// `sys.path`, e.g., via `PYTHONPATH` or a `.pth` file, in which case Python would import a
+    /// different version of the package than the one that is installed.
+    pub fn shadowing_diagnostics(&self) -> Vec<SitePackagesDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        let site_packages = self
+            .interpreter
+            .site_packages()
+            .map(std::borrow::Cow::into_owned)
+            .collect::<Vec<_>>();
+
+        // Entries in `PYTHONPATH` precede all site-packages directories.
+        let mut preceding = env::var_os(EnvVars::PYTHONPATH)
+            .map(|paths| env::split_paths(&paths).collect::<Vec<_>>())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|path| !path.as_os_str().is_empty() && !site_packages.contains(path))
+            .flat_map(|path| read_distributions(&path))
+            .collect::<Vec<_>>();
+
+        for directory in &site_packages {
+            let mut seen = FxHashSet::default();
+            for shadow in &preceding {
+                // Only the first distribution on `sys.path` is importable.
+                if !seen.insert(shadow.name()) {
+                    continue;
+                }
+                for installed in self.get_packages(shadow.name()) {
+                    if installed.path().parent() != Some(directory.as_path()) {
+                        continue;
+                    }
+                    if installed.version() == shadow.version() {
+                        continue;
+                    }
+                    diagnostics.push(SitePackagesDiagnostic::ShadowedPackage {
+                        package: installed.name().clone(),
+                        version: installed.version().clone(),
+                        shadow_version: shadow.version().clone(),
+                        shadow_path: shadow.path().to_owned(),
+                    });
+                }
+            }
+
+            // Entries in `.pth` files follow the site-packages directory that contains them.
+            preceding.extend(
+                read_pth_entries(directory)
+                    .into_iter()
+                    .filter(|path| !site_packages.contains(path))
+                    .flat_map(|path| read_distributions(&path)),
+            );
+        }
+
+        diagnostics
+    }
+
     /// Returns if the installed packages satisfy the given requirements.
     pub fn satisfies(
         &self,
@@ -439,6 +499,16 @@ pub enum SitePackagesDiagnostic {
         /// The installed versions of the package.
         paths: Vec<PathBuf>,
     },
+    ShadowedPackage {
+        /// The package that is shadowed.
+        package: PackageName,
+        /// The version of the package that is installed.
+        version: Version,
+        /// The version of the distribution that shadows the installed package.
+        shadow_version: Version,
+        /// The path to the distribution that shadows the installed package.
+        shadow_path: PathBuf,
+    },
 }
 
 impl Diagnostic for SitePackagesDiagnostic {
@@ -476,6 +546,15 @@ impl Diagnostic for SitePackagesDiagnostic {
                     paths.iter().fold(String::new(), |acc, path| acc + &format!("\n  - {}", path.display()))
                 )
             }
+            Self::ShadowedPackage {
+                package,
+                version,
+                shadow_version,
+                shadow_path,
+            } => format!(
+                "The package `{package}` is installed at `{version}`, but is shadowed by `{shadow_version}` at: {}. Python will import `{shadow_version}` instead, as it precedes the installed package on `sys.path` (e.g., via `PYTHONPATH` or a `.pth` file).",
+                shadow_path.display(),
+            ),
         }
     }
 
@@ -491,10 +570,65 @@ impl Diagnostic for SitePackagesDiagnostic {
                 ..
             } => name == package || &requirement.name == name,
             Self::DuplicatePackage { package, .. } => name == package,
+            Self::ShadowedPackage { package, .. } => name == package,
         }
     }
 }
 
+/// Read the distributions installed in a directory on `sys.path`, ignoring any that can't be read.
+fn read_distributions(directory: &Path) -> Vec<InstalledDist> {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return Vec::new();
+    };
+    let paths: BTreeSet<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .collect();
+    paths
+        .iter()
+        .filter_map(|path| InstalledDist::try_from_path(path).ok().flatten())
+        .collect()
+}
+
+/// Read the directories added to `sys.path` by the `.pth` files in a site-packages directory.
+///
+/// Mirrors the behavior of the `site` module: `.pth` files are processed in alphabetical order,
+/// comments and `import` lines are skipped, and each remaining line is interpreted as a path
+/// relative to the site-packages directory, which is only added if it exists.
+fn read_pth_entries(site_packages: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(site_packages) else {
+        return Vec::new();
+    };
+    let pth_files: BTreeSet<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension().is_some_and(|ext| ext == "pth")
+                && !path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with('.'))
+        })
+        .collect();
+    pth_files
+        .iter()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .flat_map(|contents| {
+            contents
+                .lines()
+                .filter(|line| {
+                    !line.starts_with('#')
+                        && !line.starts_with("import ")
+                        && !line.starts_with("import\t")
+                        && !line.trim().is_empty()
+                })
+                .map(|line| site_packages.join(line.trim_end()))
+                .filter(|path| path.is_dir())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
 impl InstalledPackagesProvider for SitePackages {
     fn iter(&self) -> impl Iterator<Item = &InstalledDist> {
         self.iter()
@@ -19,6 +19,49 @@ impl From<VersionSpecifiers> for Ranges<Version> {
     }
 }
 
+impl VersionSpecifiers {
+    /// Return the [`VersionSpecifiers`] in canonical form, with each version normalized (see
+    /// [`VersionSpecifier::normalize`]) and any redundant clauses removed (e.g.,
+    /// `>=1.0, >=1.2.0, <2, !=3.0` becomes `>=1.2, <2`).
+    ///
+    /// A clause is only removed if the remaining clauses match exactly the same set of versions,
+    /// per PEP 440 semantics. Specifiers that include an arbitrary equality clause (`===`) are
+    /// normalized, but never simplified.
+    #[must_use]
+    pub fn simplify(&self) -> Self {
+        let mut specifiers = self
+            .iter()
+            .map(VersionSpecifier::normalize)
+            .collect::<Vec<_>>();
+
+        if specifiers
+            .iter()
+            .any(|specifier| *specifier.operator() == Operator::ExactEqual)
+        {
+            return specifiers.into_iter().collect();
+        }
+
+        // Greedily remove each clause that doesn't change the range, retaining the tightest bounds.
+        let range = Ranges::from(self.clone());
+        let mut index = 0;
+        while index < specifiers.len() {
+            let rest = specifiers
+                .iter()
+                .enumerate()
+                .filter(|(position, _)| *position != index)
+                .map(|(_, specifier)| specifier.clone())
+                .collect::<VersionSpecifiers>();
+            if Ranges::from(rest) == range {
+                specifiers.remove(index);
+            } else {
+                index += 1;
+            }
+        }
+
+        specifiers.into_iter().collect()
+    }
+}
+
 impl From<VersionSpecifier> for Ranges<Version> {
     /// Convert the [`VersionSpecifier`] to a PubGrub-compatible version range, using PEP 440
     /// semantics.
@@ -209,3 +252,34 @@ pub fn release_specifier_to_range(specifier: VersionSpecifier) -> Ranges<Version
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::VersionSpecifiers;
+
+    #[test]
+    fn simplify() {
+        let cases = [
+            (">=1.0, >=1.2.0, <2, !=3.0", ">=1.2, <2"),
+            (">=1.0, >1.0", ">1"),
+            ("<2.0.0, <=2.0.0", "<2"),
+            ("==1.0.0.0, >=0.9", "==1"),
+            (">=1.0, !=1.5, <2", ">=1, !=1.5, <2"),
+            ("~=1.4.2, >=1.4", "~=1.4.2"),
+            ("===1.0, >=1.0.0", "===1.0, >=1"),
+            (">=2, <1", "<1, >=2"),
+        ];
+        for (specifiers, expected) in cases {
+            assert_eq!(
+                VersionSpecifiers::from_str(specifiers)
+                    .unwrap()
+                    .simplify()
+                    .to_string(),
+                expected,
+                "{specifiers}"
+            );
+        }
+    }
+}
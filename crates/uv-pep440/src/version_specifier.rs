@@ -429,6 +429,38 @@ impl VersionSpecifier {
         self.version.any_prerelease()
     }
 
+    /// Return the specifier with its version in canonical form, with any trailing `.0` release
+    /// segments removed (e.g., `==1.0.0.0` becomes `==1`), matching `packaging`'s
+    /// `canonicalize_version`.
+    ///
+    /// Trailing zeros are insignificant when comparing versions, so the normalized specifier
+    /// matches the same versions. Specifiers in which the release segments are significant (i.e.,
+    /// `~=`, `===`, `==1.0.*`, and `!=1.0.*`) are returned unchanged.
+    #[must_use]
+    pub fn normalize(&self) -> Self {
+        match self.operator {
+            Operator::TildeEqual
+            | Operator::ExactEqual
+            | Operator::EqualStar
+            | Operator::NotEqualStar => self.clone(),
+            Operator::Equal
+            | Operator::NotEqual
+            | Operator::LessThan
+            | Operator::LessThanEqual
+            | Operator::GreaterThan
+            | Operator::GreaterThanEqual => {
+                let mut release = self.version.release().to_vec();
+                while release.len() > 1 && release.last() == Some(&0) {
+                    release.pop();
+                }
+                Self {
+                    operator: self.operator,
+                    version: self.version.clone().with_release(release),
+                }
+            }
+        }
+    }
+
     /// Returns the version specifiers whose union represents the given range.
     ///
     /// This function is not applicable to ranges involving pre-release versions.
@@ -1722,6 +1754,31 @@ mod tests {
         assert_eq!(err.inner.end, 18);
     }
 
+    #[test]
+    fn normalize() {
+        let cases = [
+            ("==1.0.0.0", "==1"),
+            ("!=2.1.0", "!=2.1"),
+            (">=1.2.0rc1", ">=1.2rc1"),
+            ("==3.0.0+local", "==3+local"),
+            ("<2.0", "<2"),
+            ("==0.0", "==0"),
+            ("~=1.0.0", "~=1.0.0"),
+            ("==1.0.*", "==1.0.*"),
+            ("===1.0.0", "===1.0.0"),
+        ];
+        for (specifier, expected) in cases {
+            assert_eq!(
+                VersionSpecifier::from_str(specifier)
+                    .unwrap()
+                    .normalize()
+                    .to_string(),
+                expected,
+                "{specifier}"
+            );
+        }
+    }
+
     /// Tests the human readable error messages generated from an invalid
     /// sequence of version specifiers.
     #[test]
@@ -62,7 +62,8 @@ use crate::marker::lowering::{
 };
 use crate::marker::MarkerValueExtra;
 use crate::{
-    ExtraOperator, MarkerExpression, MarkerOperator, MarkerValueString, MarkerValueVersion,
+    ExtraOperator, FeatureOperator, MarkerExpression, MarkerOperator, MarkerValueString,
+    MarkerValueVersion,
 };
 
 /// The global node interner.
@@ -337,6 +338,16 @@ impl InternerGuard<'_> {
                 name: MarkerValueExtra::Arbitrary(_),
                 ..
             } => return NodeId::FALSE,
+            // A variable representing the presence or absence of a particular user-defined
+            // feature.
+            MarkerExpression::Feature {
+                name,
+                operator: FeatureOperator::In,
+            } => (Variable::Feature(name), Edges::from_bool(true)),
+            MarkerExpression::Feature {
+                name,
+                operator: FeatureOperator::NotIn,
+            } => (Variable::Feature(name), Edges::from_bool(false)),
         };
 
         self.create_node(var, children)
@@ -1045,6 +1056,9 @@ pub(crate) enum Variable {
         key: CanonicalMarkerValueString,
         value: ArcStr,
     },
+    /// A variable representing the presence or absence of a given user-defined feature in
+    /// `uv_features`.
+    Feature(ArcStr),
     /// A variable representing the existence or absence of a given extra.
     ///
     /// We keep extras at the leaves of the tree, so when simplifying extras we can
@@ -27,6 +27,10 @@ struct MarkerEnvironmentInner {
     python_full_version: StringVersion,
     python_version: StringVersion,
     sys_platform: String,
+    /// The user-defined features that are enabled for this environment, as provided via
+    /// `--feature`. These are not part of the interpreter's environment.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    uv_features: Vec<String>,
 }
 
 impl MarkerEnvironment {
@@ -178,6 +182,23 @@ impl MarkerEnvironment {
     pub fn sys_platform(&self) -> &str {
         &self.inner.sys_platform
     }
+
+    /// Returns the user-defined features that are enabled for this environment.
+    ///
+    /// These are matched by `'<feature>' in uv_features` markers.
+    #[inline]
+    pub fn uv_features(&self) -> &[String] {
+        &self.inner.uv_features
+    }
+
+    /// Returns `true` if the given user-defined feature is enabled for this environment.
+    #[inline]
+    pub fn has_uv_feature(&self, feature: &str) -> bool {
+        self.inner
+            .uv_features
+            .iter()
+            .any(|enabled| enabled == feature)
+    }
 }
 
 /// APIs for setting specific parts of a marker environment.
@@ -301,6 +322,19 @@ impl MarkerEnvironment {
         Arc::make_mut(&mut self.inner).sys_platform = value.into();
         self
     }
+
+    /// Set the user-defined features that are enabled for this environment.
+    ///
+    /// See also [`MarkerEnvironment::uv_features`].
+    #[inline]
+    #[must_use]
+    pub fn with_uv_features(
+        mut self,
+        features: impl IntoIterator<Item = impl Into<String>>,
+    ) -> MarkerEnvironment {
+        Arc::make_mut(&mut self.inner).uv_features = features.into_iter().map(Into::into).collect();
+        self
+    }
 }
 
 /// A builder for constructing a marker environment.
@@ -344,6 +378,7 @@ impl<'a> TryFrom<MarkerEnvironmentBuilder<'a>> for MarkerEnvironment {
                 python_full_version: builder.python_full_version.parse()?,
                 python_version: builder.python_version.parse()?,
                 sys_platform: builder.sys_platform.to_string(),
+                uv_features: Vec::new(),
             }),
         })
     }
@@ -21,10 +21,10 @@ pub use lowering::{
     CanonicalMarkerValueExtra, CanonicalMarkerValueString, CanonicalMarkerValueVersion,
 };
 pub use tree::{
-    ContainsMarkerTree, ExtraMarkerTree, ExtraOperator, InMarkerTree, MarkerExpression,
-    MarkerOperator, MarkerTree, MarkerTreeContents, MarkerTreeDebugGraph, MarkerTreeKind,
-    MarkerValue, MarkerValueExtra, MarkerValueString, MarkerValueVersion, MarkerWarningKind,
-    StringMarkerTree, StringVersion, VersionMarkerTree,
+    ContainsMarkerTree, ExtraMarkerTree, ExtraOperator, FeatureMarkerTree, FeatureOperator,
+    InMarkerTree, MarkerExpression, MarkerOperator, MarkerTree, MarkerTreeContents,
+    MarkerTreeDebugGraph, MarkerTreeKind, MarkerValue, MarkerValueExtra, MarkerValueString,
+    MarkerValueVersion, MarkerWarningKind, StringMarkerTree, StringVersion, VersionMarkerTree,
 };
 
 /// `serde` helpers for [`MarkerTree`].
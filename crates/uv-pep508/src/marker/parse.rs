@@ -6,8 +6,9 @@ use uv_pep440::{Version, VersionPattern, VersionSpecifier};
 use crate::cursor::Cursor;
 use crate::marker::MarkerValueExtra;
 use crate::{
-    ExtraOperator, MarkerExpression, MarkerOperator, MarkerTree, MarkerValue, MarkerValueString,
-    MarkerValueVersion, MarkerWarningKind, Pep508Error, Pep508ErrorSource, Pep508Url, Reporter,
+    ExtraOperator, FeatureOperator, MarkerExpression, MarkerOperator, MarkerTree, MarkerValue,
+    MarkerValueString, MarkerValueVersion, MarkerWarningKind, Pep508Error, Pep508ErrorSource,
+    Pep508Url, Reporter,
 };
 
 /// ```text
@@ -208,6 +209,7 @@ pub(crate) fn parse_marker_key_op_value<T: Pep508Url>(
         MarkerValue::MarkerEnvString(key) => {
             let value = match r_value {
                 MarkerValue::Extra
+                | MarkerValue::UvFeatures
                 | MarkerValue::MarkerEnvVersion(_)
                 | MarkerValue::MarkerEnvString(_) => {
                     reporter.report(
@@ -242,7 +244,8 @@ pub(crate) fn parse_marker_key_op_value<T: Pep508Url>(
             let value = match r_value {
                 MarkerValue::MarkerEnvVersion(_)
                 | MarkerValue::MarkerEnvString(_)
-                | MarkerValue::Extra => {
+                | MarkerValue::Extra
+                | MarkerValue::UvFeatures => {
                     reporter.report(
                         MarkerWarningKind::ExtraInvalidComparison,
                         "Comparing extra with something other than a quoted string is wrong,
@@ -257,6 +260,17 @@ pub(crate) fn parse_marker_key_op_value<T: Pep508Url>(
 
             parse_extra_expr(operator, &value, reporter)
         }
+        // `uv_features` is a list, so it may only appear on the right-hand side of `in`.
+        MarkerValue::UvFeatures => {
+            reporter.report(
+                MarkerWarningKind::FeatureInvalidComparison,
+                "Comparing uv_features with something other than a quoted string using `in` \
+                    or `not in` is wrong, will be ignored"
+                    .to_string(),
+            );
+
+            return Ok(None);
+        }
         // This is either MarkerEnvVersion, MarkerEnvString or Extra inverted
         MarkerValue::QuotedString(l_string) => {
             match r_value {
@@ -273,6 +287,8 @@ pub(crate) fn parse_marker_key_op_value<T: Pep508Url>(
                 }),
                 // `'...' == extra`
                 MarkerValue::Extra => parse_extra_expr(operator, &l_string, reporter),
+                // `'...' in uv_features`
+                MarkerValue::UvFeatures => parse_feature_expr(operator, l_string, reporter),
                 // `'...' == '...'`, doesn't make much sense
                 MarkerValue::QuotedString(_) => {
                     // Not even pypa/packaging 22.0 supports this
@@ -467,6 +483,30 @@ fn parse_inverted_version_expr(
     Some(MarkerExpression::Version { key, specifier })
 }
 
+/// Creates an instance of [`MarkerExpression::Feature`] with the given values.
+fn parse_feature_expr(
+    operator: MarkerOperator,
+    value: ArcStr,
+    reporter: &mut impl Reporter,
+) -> Option<MarkerExpression> {
+    if let Some(operator) = FeatureOperator::from_marker_operator(operator) {
+        return Some(MarkerExpression::Feature {
+            operator,
+            name: value,
+        });
+    }
+
+    reporter.report(
+        MarkerWarningKind::FeatureInvalidComparison,
+        format!(
+            "Comparing uv_features with `{operator}` is wrong, expected `in` or `not in`,
+            will be ignored"
+        ),
+    );
+
+    None
+}
+
 /// Creates an instance of [`MarkerExpression::Extra`] with the given values, falling back to
 /// [`MarkerExpression::Arbitrary`] on failure.
 fn parse_extra_expr(
@@ -9,7 +9,9 @@ use version_ranges::Ranges;
 
 use uv_pep440::{Version, VersionSpecifier};
 
-use crate::{ExtraOperator, MarkerExpression, MarkerOperator, MarkerTree, MarkerTreeKind};
+use crate::{
+    ExtraOperator, FeatureOperator, MarkerExpression, MarkerOperator, MarkerTree, MarkerTreeKind,
+};
 
 /// Returns a simplified DNF expression for a given marker tree.
 ///
@@ -161,6 +163,24 @@ fn collect_dnf(
                 path.pop();
             }
         }
+        MarkerTreeKind::Feature(marker) => {
+            for (value, tree) in marker.children() {
+                let operator = if value {
+                    FeatureOperator::In
+                } else {
+                    FeatureOperator::NotIn
+                };
+
+                let expr = MarkerExpression::Feature {
+                    name: ArcStr::from(marker.name()),
+                    operator,
+                };
+
+                path.push(expr);
+                collect_dnf(tree, dnf, path);
+                path.pop();
+            }
+        }
         MarkerTreeKind::Extra(marker) => {
             for (value, tree) in marker.children() {
                 let operator = if value {
@@ -426,6 +446,17 @@ fn is_negation(left: &MarkerExpression, right: &MarkerExpression) -> bool {
                 return false;
             };
 
+            name == name2 && operator.negate() == *operator2
+        }
+        MarkerExpression::Feature { operator, name } => {
+            let MarkerExpression::Feature {
+                name: name2,
+                operator: operator2,
+            } = right
+            else {
+                return false;
+            };
+
             name == name2 && operator.negate() == *operator2
         }
     }
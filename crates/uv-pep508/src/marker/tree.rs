@@ -32,6 +32,9 @@ pub enum MarkerWarningKind {
     /// Doing an operation other than `==` and `!=` on a quoted string with `extra`, such as
     /// `extra > "perf"` or `extra == os_name`
     ExtraInvalidComparison,
+    /// Doing an operation other than `in` and `not in` with `uv_features`, or comparing
+    /// `uv_features` with something other than a quoted string, such as `uv_features == "gpu"`
+    FeatureInvalidComparison,
     /// Comparing a string valued marker and a string lexicographically, such as `"3.9" > "3.10"`
     LexicographicComparison,
     /// Comparing two markers, such as `os_name != sys_implementation`
@@ -130,6 +133,8 @@ pub enum MarkerValue {
     MarkerEnvString(MarkerValueString),
     /// `extra`. This one is special because it's a list and not env but user given
     Extra,
+    /// `uv_features`. Like `extra`, this is a user-given list rather than part of the environment.
+    UvFeatures,
     /// Not a constant, but a user given quoted string with a value inside such as '3.8' or "windows"
     QuotedString(ArcStr),
 }
@@ -170,6 +175,7 @@ impl FromStr for MarkerValue {
             "sys_platform" => Self::MarkerEnvString(MarkerValueString::SysPlatform),
             "sys.platform" => Self::MarkerEnvString(MarkerValueString::SysPlatformDeprecated),
             "extra" => Self::Extra,
+            "uv_features" => Self::UvFeatures,
             _ => return Err(format!("Invalid key: {s}")),
         };
         Ok(value)
@@ -182,6 +188,7 @@ impl Display for MarkerValue {
             Self::MarkerEnvVersion(marker_value_version) => marker_value_version.fmt(f),
             Self::MarkerEnvString(marker_value_string) => marker_value_string.fmt(f),
             Self::Extra => f.write_str("extra"),
+            Self::UvFeatures => f.write_str("uv_features"),
             Self::QuotedString(value) => write!(f, "'{value}'"),
         }
     }
@@ -507,6 +514,11 @@ pub enum MarkerExpression {
         operator: ExtraOperator,
         name: MarkerValueExtra,
     },
+    /// `'...' <feature op> uv_features`, for a user-defined feature provided via `--feature`.
+    Feature {
+        operator: FeatureOperator,
+        name: ArcStr,
+    },
 }
 
 /// The kind of a [`MarkerExpression`].
@@ -520,6 +532,8 @@ pub(crate) enum MarkerExpressionKind {
     String(MarkerValueString),
     /// An extra expression, e.g. `extra == '...'`.
     Extra,
+    /// A feature expression, e.g. `'...' in uv_features`.
+    Feature,
 }
 
 /// The operator for an extra expression, either '==' or '!='.
@@ -561,6 +575,45 @@ impl Display for ExtraOperator {
     }
 }
 
+/// The operator for a feature expression, either 'in' or 'not in'.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum FeatureOperator {
+    /// `in`
+    In,
+    /// `not in`
+    NotIn,
+}
+
+impl FeatureOperator {
+    /// Creates a [`FeatureOperator`] from an equivalent [`MarkerOperator`].
+    ///
+    /// Returns `None` if the operator is not supported for features.
+    pub(crate) fn from_marker_operator(operator: MarkerOperator) -> Option<FeatureOperator> {
+        match operator {
+            MarkerOperator::In => Some(FeatureOperator::In),
+            MarkerOperator::NotIn => Some(FeatureOperator::NotIn),
+            _ => None,
+        }
+    }
+
+    /// Negates this operator.
+    pub(crate) fn negate(&self) -> FeatureOperator {
+        match *self {
+            FeatureOperator::In => FeatureOperator::NotIn,
+            FeatureOperator::NotIn => FeatureOperator::In,
+        }
+    }
+}
+
+impl Display for FeatureOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::In => "in",
+            Self::NotIn => "not in",
+        })
+    }
+}
+
 impl MarkerExpression {
     /// Parse a [`MarkerExpression`] from a string with the given reporter.
     pub fn parse_reporter(
@@ -600,6 +653,7 @@ impl MarkerExpression {
             MarkerExpression::VersionIn { key, .. } => MarkerExpressionKind::VersionIn(*key),
             MarkerExpression::String { key, .. } => MarkerExpressionKind::String(*key),
             MarkerExpression::Extra { .. } => MarkerExpressionKind::Extra,
+            MarkerExpression::Feature { .. } => MarkerExpressionKind::Feature,
         }
     }
 }
@@ -641,6 +695,9 @@ impl Display for MarkerExpression {
             MarkerExpression::Extra { operator, name } => {
                 write!(f, "extra {operator} '{name}'")
             }
+            MarkerExpression::Feature { operator, name } => {
+                write!(f, "'{name}' {operator} uv_features")
+            }
         }
     }
 }
@@ -862,6 +919,16 @@ impl MarkerTree {
                     low: low.negate(self.0),
                 })
             }
+            Variable::Feature(name) => {
+                let Edges::Boolean { low, high } = node.children else {
+                    unreachable!()
+                };
+                MarkerTreeKind::Feature(FeatureMarkerTree {
+                    name,
+                    high: high.negate(self.0),
+                    low: low.negate(self.0),
+                })
+            }
         }
     }
 
@@ -962,6 +1029,11 @@ impl MarkerTree {
                     .edge(extras.contains(marker.name().extra()))
                     .evaluate_reporter_impl(env, extras, reporter);
             }
+            MarkerTreeKind::Feature(marker) => {
+                return marker
+                    .edge(env.has_uv_feature(marker.name()))
+                    .evaluate_reporter_impl(env, extras, reporter);
+            }
         }
 
         false
@@ -986,6 +1058,9 @@ impl MarkerTree {
             MarkerTreeKind::Contains(marker) => marker
                 .children()
                 .any(|(_, tree)| tree.evaluate_extras(extras)),
+            MarkerTreeKind::Feature(marker) => marker
+                .children()
+                .any(|(_, tree)| tree.evaluate_extras(extras)),
             MarkerTreeKind::Extra(marker) => marker
                 .edge(extras.contains(marker.name().extra()))
                 .evaluate_extras(extras),
@@ -1216,6 +1291,11 @@ impl MarkerTree {
                         imp(tree, f);
                     }
                 }
+                MarkerTreeKind::Feature(kind) => {
+                    for (_, tree) in kind.children() {
+                        imp(tree, f);
+                    }
+                }
                 MarkerTreeKind::Extra(kind) => {
                     if kind.low.is_false() {
                         f(MarkerOperator::Equal, kind.name().extra());
@@ -1333,6 +1413,21 @@ impl MarkerTree {
                 write!(f, "{} not in {} -> ", kind.value(), kind.key())?;
                 kind.edge(false).fmt_graph(f, level + 1)?;
             }
+            MarkerTreeKind::Feature(kind) => {
+                writeln!(f)?;
+                for _ in 0..level {
+                    write!(f, "  ")?;
+                }
+                write!(f, "{} in uv_features -> ", kind.name())?;
+                kind.edge(true).fmt_graph(f, level + 1)?;
+
+                writeln!(f)?;
+                for _ in 0..level {
+                    write!(f, "  ")?;
+                }
+                write!(f, "{} not in uv_features -> ", kind.name())?;
+                kind.edge(false).fmt_graph(f, level + 1)?;
+            }
             MarkerTreeKind::Extra(kind) => {
                 writeln!(f)?;
                 for _ in 0..level {
@@ -1417,6 +1512,8 @@ pub enum MarkerTreeKind<'a> {
     In(InMarkerTree<'a>),
     /// A string expression with the `contains` operator.
     Contains(ContainsMarkerTree<'a>),
+    /// A user-defined feature expression.
+    Feature(FeatureMarkerTree<'a>),
     /// A string expression.
     Extra(ExtraMarkerTree<'a>),
 }
@@ -1593,6 +1690,50 @@ impl Ord for ContainsMarkerTree<'_> {
     }
 }
 
+/// A node representing the existence or absence of a given user-defined feature, such as
+/// `'gpu' in uv_features`.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct FeatureMarkerTree<'a> {
+    name: &'a str,
+    high: NodeId,
+    low: NodeId,
+}
+
+impl FeatureMarkerTree<'_> {
+    /// Returns the name of the feature in this expression.
+    pub fn name(&self) -> &str {
+        self.name
+    }
+
+    /// The edges of this node, corresponding to the boolean evaluation of the expression.
+    pub fn children(&self) -> impl Iterator<Item = (bool, MarkerTree)> {
+        [(true, MarkerTree(self.high)), (false, MarkerTree(self.low))].into_iter()
+    }
+
+    /// Returns the subtree associated with the given edge value.
+    pub fn edge(&self, value: bool) -> MarkerTree {
+        if value {
+            MarkerTree(self.high)
+        } else {
+            MarkerTree(self.low)
+        }
+    }
+}
+
+impl PartialOrd for FeatureMarkerTree<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FeatureMarkerTree<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.name()
+            .cmp(other.name())
+            .then_with(|| self.children().cmp(other.children()))
+    }
+}
+
 /// A node representing the existence or absence of a given extra, such as `extra == 'bar'`.
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct ExtraMarkerTree<'a> {
@@ -2071,6 +2212,41 @@ mod test {
         assert!(!result);
     }
 
+    #[test]
+    fn test_uv_features() {
+        let env37 = env37();
+        let gpu = env37.clone().with_uv_features(["gpu"]);
+
+        let marker = m("'gpu' in uv_features");
+        assert_eq!(marker.try_to_string().unwrap(), "'gpu' in uv_features");
+        assert!(!marker.evaluate(&env37, &[]));
+        assert!(marker.evaluate(&gpu, &[]));
+
+        let marker = m("'gpu' not in uv_features");
+        assert_eq!(marker.try_to_string().unwrap(), "'gpu' not in uv_features");
+        assert!(marker.evaluate(&env37, &[]));
+        assert!(!marker.evaluate(&gpu, &[]));
+
+        let marker = m("'gpu' in uv_features and python_version >= '3.7'");
+        assert!(!marker.evaluate(&env37, &[]));
+        assert!(marker.evaluate(&gpu, &[]));
+
+        assert_eq!(
+            m("'gpu' in uv_features").negate(),
+            m("'gpu' not in uv_features")
+        );
+        assert!(m("'gpu' in uv_features or 'gpu' not in uv_features").is_true());
+        assert!(is_disjoint(
+            "'gpu' in uv_features",
+            "'gpu' not in uv_features"
+        ));
+        assert!(!is_disjoint("'gpu' in uv_features", "'tpu' in uv_features"));
+
+        // Features can only be tested for membership, so other comparisons are ignored.
+        assert!(m("uv_features == 'gpu'").is_true());
+        assert!(m("os_name in uv_features").is_true());
+    }
+
     #[test]
     fn test_marker_version_star() {
         let env37 = env37();
@@ -12,6 +12,12 @@ impl ResolverMarkerEnvironment {
     pub fn markers(&self) -> &MarkerEnvironment {
         &self.0
     }
+
+    /// Enable the given user-defined features (i.e., `uv_features`) in the marker environment.
+    #[must_use]
+    pub fn with_uv_features(self, features: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self(self.0.with_uv_features(features))
+    }
 }
 
 impl From<MarkerEnvironment> for ResolverMarkerEnvironment {
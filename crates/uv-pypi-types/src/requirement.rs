@@ -719,7 +719,8 @@ impl From<RequirementSource> for RequirementSourceWire {
                     }
                     GitReference::BranchOrTag(rev)
                     | GitReference::BranchOrTagOrCommit(rev)
-                    | GitReference::NamedRef(rev) => {
+                    | GitReference::NamedRef(rev)
+                    | GitReference::VersionRange(rev) => {
                         url.query_pairs_mut().append_pair("rev", rev.as_str());
                     }
                     GitReference::DefaultBranch => {}
@@ -0,0 +1,15 @@
+"""
+Changes the owner of each path read from `stdin` (one per line) to the given user and group.
+
+Used to hand files copied into a container (which are owned by `root`) to the container's user.
+"""
+
+import os
+import sys
+
+uid = int(sys.argv[1])
+gid = int(sys.argv[2])
+for line in sys.stdin:
+    path = line.rstrip("\n")
+    if path:
+        os.lchown(path, uid, gid)
@@ -107,6 +107,88 @@ def get_major_minor_version() -> str:
     return "{}.{}".format(*sys.version_info)
 
 
+def get_config_var(name: str):
+    """Return the value of a `sysconfig` configuration variable, or `None`.
+
+    Embedded and statically-linked Python distributions may ship without the
+    `sysconfig` data module, in which case any lookup raises.
+    """
+    try:
+        return sysconfig.get_config_var(name)
+    except Exception:
+        return None
+
+
+# The `sysconfig` configuration variables that describe how native code is built for (and
+# linked against) the interpreter.
+SYSCONFIG_VARS = (
+    "ABIFLAGS",
+    "CC",
+    "CXX",
+    "EXT_SUFFIX",
+    "INCLUDEPY",
+    "LDLIBRARY",
+    "LDSHARED",
+    "LIBDIR",
+    "MACOSX_DEPLOYMENT_TARGET",
+    "MULTIARCH",
+    "Py_DEBUG",
+    "Py_ENABLE_SHARED",
+    "Py_GIL_DISABLED",
+    "SOABI",
+)
+
+
+def get_sysconfig_vars():
+    """Return the values of the `SYSCONFIG_VARS` that are defined for the interpreter."""
+    sysconfig_vars = {}
+    for name in SYSCONFIG_VARS:
+        value = get_config_var(name)
+        if value is not None:
+            sysconfig_vars[name] = value
+    return sysconfig_vars
+
+
+def get_stdlib():
+    """Return the path to the standard library.
+
+    Falls back to the location of the `os` module if `sysconfig` can't determine it.
+    """
+    try:
+        return sysconfig.get_path("stdlib")
+    except Exception:
+        return os.path.dirname(os.__file__)
+
+
+def get_default_virtualenv():
+    """Return the default Scheme for virtualenvs.
+
+    Used for interpreters that lack the `sysconfig` or `distutils` data required to
+    determine it. The paths returned are relative to a root directory, and match the
+    `venv` scheme.
+    """
+    if os.name == "nt":
+        return {
+            "purelib": os.path.join("Lib", "site-packages"),
+            "platlib": os.path.join("Lib", "site-packages"),
+            "include": "Include",
+            "scripts": "Scripts",
+            "data": "",
+        }
+    site_packages = os.path.join(
+        "lib", f"python{get_major_minor_version()}", "site-packages"
+    )
+    return {
+        "purelib": site_packages,
+        "platlib": site_packages,
+        "include": os.path.join(
+            "include", "site", f"python{get_major_minor_version()}"
+        ),
+        "scripts": "bin",
+        "data": "",
+    }
+
+
 def get_virtualenv():
     """Return the expected Scheme for virtualenvs created by this interpreter.
 
@@ -597,6 +679,20 @@ def main() -> None:
             )
             sys.exit(0)
 
+    # Determine the installation scheme. Embedded and statically-linked Python
+    # distributions may lack the `sysconfig` data required to do so, in which case we
+    # omit the scheme and rely on an explicit override file (`uv.scheme.toml`) in
+    # `sys.prefix`.
+    try:
+        scheme = get_scheme(use_sysconfig_scheme)
+    except Exception:
+        scheme = None
+
+    try:
+        virtualenv = get_virtualenv()
+    except Exception:
+        virtualenv = get_default_virtualenv()
+
     interpreter_info = {
         "result": "success",
         "markers": markers,
@@ -606,21 +702,24 @@ def main() -> None:
         "sys_base_executable": getattr(sys, "_base_executable", None),
         "sys_executable": sys.executable,
         "sys_path": sys.path,
-        "stdlib": sysconfig.get_path("stdlib"),
+        "stdlib": get_stdlib(),
         # Prior to the introduction of `sysconfig` patching, python-build-standalone installations would always use
         # "/install" as the prefix. With `sysconfig` patching, we rewrite the prefix to match the actual installation
         # location. So in newer versions, we also write a dedicated flag to indicate standalone builds.
-        "standalone": sysconfig.get_config_var("prefix") == "/install" or bool(sysconfig.get_config_var("PYTHON_BUILD_STANDALONE")),
-        "scheme": get_scheme(use_sysconfig_scheme),
-        "virtualenv": get_virtualenv(),
+        "standalone": get_config_var("prefix") == "/install" or bool(get_config_var("PYTHON_BUILD_STANDALONE")),
+        "scheme": scheme,
+        "virtualenv": virtualenv,
         "platform": os_and_arch,
         "manylinux_compatible": manylinux_compatible,
         # The `t` abiflag for freethreading Python.
         # https://peps.python.org/pep-0703/#build-configuration-changes
-        "gil_disabled": bool(sysconfig.get_config_var("Py_GIL_DISABLED")),
+        "gil_disabled": bool(get_config_var("Py_GIL_DISABLED")),
+        # The `d` abiflag for debug builds.
+        "debug": bool(get_config_var("Py_DEBUG")),
         # Determine if the interpreter is 32-bit or 64-bit.
         # https://github.com/python/cpython/blob/b228655c227b2ca298a8ffac44d14ce3d22f6faa/Lib/venv/__init__.py#L136
         "pointer_size": "64" if sys.maxsize > 2**32 else "32",
+        "sysconfig_vars": get_sysconfig_vars(),
     }
     print(json.dumps(interpreter_info))
 
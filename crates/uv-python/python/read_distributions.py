@@ -0,0 +1,30 @@
+"""
+Reads the metadata of the installed distributions in the given `site-packages` directories.
+
+Used to seed a staged installation for an environment that uv can't access directly (e.g., a
+container) with the distributions that are already installed, such that they're taken into account
+when planning the installation.
+
+Prints a JSON array of objects, each with the absolute `path` and the (lossily decoded) `contents`
+of a file within a `.dist-info` directory.
+"""
+
+import json
+import os
+import sys
+
+files = []
+for site_packages in sys.argv[1:]:
+    if not os.path.isdir(site_packages):
+        continue
+    for entry in sorted(os.listdir(site_packages)):
+        dist_info = os.path.join(site_packages, entry)
+        if not entry.endswith(".dist-info") or not os.path.isdir(dist_info):
+            continue
+        for root, _dirs, names in os.walk(dist_info):
+            for name in names:
+                path = os.path.join(root, name)
+                with open(path, encoding="utf-8", errors="replace") as f:
+                    files.append({"path": path, "contents": f.read()})
+
+json.dump(files, sys.stdout)
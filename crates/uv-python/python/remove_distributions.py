@@ -0,0 +1,46 @@
+"""
+Removes the installed distributions with the given names from the current environment.
+
+Used to replace existing distributions before copying a staged installation into an environment
+that uv can't access directly (e.g., a container).
+"""
+
+import sys
+
+import importlib.metadata
+import os
+import re
+import shutil
+
+
+def normalize(name):
+    return re.sub(r"[-_.]+", "-", name).lower()
+
+
+def remove(dist):
+    directories = set()
+    for file in dist.files or []:
+        path = os.path.normpath(str(dist.locate_file(file)))
+        if os.path.isfile(path) or os.path.islink(path):
+            os.remove(path)
+            directories.add(os.path.dirname(path))
+
+    # Remove the metadata directory itself, along with any directories left empty.
+    metadata = getattr(dist, "_path", None)
+    if metadata is not None and os.path.isdir(str(metadata)):
+        shutil.rmtree(str(metadata))
+    site_packages = {os.path.normpath(path) for path in sys.path}
+    for directory in sorted(directories, key=len, reverse=True):
+        if directory in site_packages:
+            continue
+        try:
+            os.rmdir(directory)
+        except OSError:
+            pass
+
+
+names = {normalize(name) for name in sys.argv[1:]}
+for dist in importlib.metadata.distributions():
+    name = dist.metadata["Name"]
+    if name is not None and normalize(name) in names:
+        remove(dist)
@@ -0,0 +1,634 @@
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
+use std::fmt::{Display, Formatter};
+use std::path::{Component, Path, PathBuf};
+use std::process::{ExitStatus, Output, Stdio};
+use std::time::SystemTime;
+
+use serde::Deserialize;
+use thiserror::Error;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+
+use crate::{Interpreter, PythonEnvironment};
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Invalid container Python request: `{0}` (expected, e.g., `docker://<container>#/usr/bin/python3`)")]
+    InvalidRequest(String),
+    #[error("Failed to run `{0}`; is it installed?")]
+    Spawn(String, #[source] std::io::Error),
+    #[error("Command `{command}` failed with {status}\n--- stderr:\n{stderr}\n---")]
+    Command {
+        command: String,
+        status: ExitStatus,
+        stderr: String,
+    },
+    #[error("Failed to parse the output of `{0}`")]
+    Output(String, #[source] serde_json::Error),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Join(#[from] tokio::task::JoinError),
+}
+
+/// A container runtime that can execute commands in, and copy files into, a running container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerRuntime {
+    Docker,
+    Podman,
+}
+
+impl ContainerRuntime {
+    /// Return the URL scheme used to request an interpreter in a container for this runtime.
+    fn scheme(self) -> &'static str {
+        match self {
+            Self::Docker => "docker",
+            Self::Podman => "podman",
+        }
+    }
+
+    /// Return the name of the executable for this runtime.
+    fn executable(self) -> &'static str {
+        match self {
+            Self::Docker => "docker",
+            Self::Podman => "podman",
+        }
+    }
+}
+
+/// A Python interpreter in a running container, e.g., `docker://devcontainer#/usr/bin/python3`.
+///
+/// Rather than running uv inside the container, the interpreter is queried via the container
+/// runtime's `exec` command, and packages are installed into a local staging directory that
+/// mirrors the container's filesystem before being copied into the container.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainerPython {
+    runtime: ContainerRuntime,
+    container: String,
+    executable: PathBuf,
+}
+
+impl ContainerPython {
+    /// Parse a Python request of the form `docker://<container>#<executable>` or
+    /// `podman://<container>#<executable>`.
+    ///
+    /// Returns `None` if the request does not refer to a container.
+    pub fn from_request(request: &str) -> Result<Option<Self>, Error> {
+        let Some((runtime, remainder)) = [ContainerRuntime::Docker, ContainerRuntime::Podman]
+            .into_iter()
+            .find_map(|runtime| {
+                request
+                    .strip_prefix(runtime.scheme())
+                    .and_then(|remainder| remainder.strip_prefix("://"))
+                    .map(|remainder| (runtime, remainder))
+            })
+        else {
+            return Ok(None);
+        };
+
+        let Some((container, executable)) = remainder.split_once('#') else {
+            return Err(Error::InvalidRequest(request.to_string()));
+        };
+        if container.is_empty() || !executable.starts_with('/') {
+            return Err(Error::InvalidRequest(request.to_string()));
+        }
+
+        Ok(Some(Self {
+            runtime,
+            container: container.to_string(),
+            executable: PathBuf::from(executable),
+        }))
+    }
+
+    /// Return the path to the Python executable within the container.
+    pub fn executable(&self) -> &Path {
+        &self.executable
+    }
+
+    /// Query the interpreter, and create a [`ContainerEnvironment`] to stage an installation into
+    /// the container.
+    ///
+    /// The staging directory is seeded with the metadata of the distributions that are already
+    /// installed in the container, such that they're taken into account when planning the
+    /// installation.
+    pub async fn environment(self, cache: &Cache) -> Result<ContainerEnvironment, crate::Error> {
+        let interpreter = Interpreter::query_container(&self, cache).await?;
+        let staging = tempfile::tempdir_in(cache.root())?;
+        debug!(
+            "Staging installation for `{self}` at: {}",
+            staging.path().user_display()
+        );
+
+        let seeded = self
+            .seed(staging.path(), &interpreter)
+            .await
+            .map_err(|err| crate::interpreter::Error::Container(self.to_string(), err))?;
+
+        let interpreter = interpreter.with_sysroot(staging.path().to_path_buf())?;
+        Ok(ContainerEnvironment {
+            container: self,
+            environment: PythonEnvironment::from_interpreter(interpreter),
+            staging,
+            seeded,
+        })
+    }
+
+    /// Copy the metadata of the distributions installed in the container into the staging
+    /// directory.
+    ///
+    /// Returns the seeded `.dist-info` directories, along with the modification time of their
+    /// `RECORD` files, to later distinguish them from distributions installed by uv.
+    async fn seed(
+        &self,
+        staging: &Path,
+        interpreter: &Interpreter,
+    ) -> Result<BTreeMap<PathBuf, Option<SystemTime>>, Error> {
+        /// A file within a `.dist-info` directory in the container.
+        #[derive(Deserialize)]
+        struct DistInfoFile {
+            path: PathBuf,
+            contents: String,
+        }
+
+        let mut command = self.python();
+        command
+            .arg("-I")
+            .arg("-c")
+            .arg(include_str!("../python/read_distributions.py"))
+            .arg(interpreter.purelib())
+            .arg(interpreter.platlib());
+        let output = self.run(&mut command, None).await?;
+        let files: Vec<DistInfoFile> = serde_json::from_slice(&output.stdout)
+            .map_err(|err| Error::Output(display(&command), err))?;
+
+        let mut dist_infos = Vec::new();
+        for file in files {
+            let path = reroot(staging, &file.path);
+            let Some(dist_info) = path
+                .ancestors()
+                .find(|ancestor| ancestor.extension().is_some_and(|ext| ext == "dist-info"))
+            else {
+                continue;
+            };
+            dist_infos.push(dist_info.to_path_buf());
+            if let Some(parent) = path.parent() {
+                fs_err::tokio::create_dir_all(parent).await?;
+            }
+            fs_err::tokio::write(&path, file.contents).await?;
+        }
+
+        let mut seeded = BTreeMap::new();
+        for dist_info in dist_infos {
+            let modified = fs_err::tokio::metadata(dist_info.join("RECORD"))
+                .await
+                .and_then(|metadata| metadata.modified())
+                .ok();
+            seeded.insert(dist_info, modified);
+        }
+        debug!(
+            "Seeded {} installed distributions from `{self}`",
+            seeded.len()
+        );
+        Ok(seeded)
+    }
+
+    /// Return a [`Command`] that runs the Python executable within the container.
+    pub(crate) fn python(&self) -> Command {
+        let mut command = self.exec();
+        command.arg(&self.executable);
+        command
+    }
+
+    /// Create a temporary directory within the container.
+    pub(crate) async fn tempdir(&self) -> Result<PathBuf, Error> {
+        let output = self.run(self.exec().arg("mktemp").arg("-d"), None).await?;
+        Ok(PathBuf::from(
+            String::from_utf8_lossy(&output.stdout).trim().to_string(),
+        ))
+    }
+
+    /// Recursively remove a directory within the container.
+    pub(crate) async fn remove_dir_all(&self, path: &Path) -> Result<(), Error> {
+        self.run(self.exec().arg("rm").arg("-rf").arg(path), None)
+            .await?;
+        Ok(())
+    }
+
+    /// Copy the contents of a local directory into a directory within the container.
+    ///
+    /// The container runtime creates the copied files as `root`, so the given paths (within the
+    /// container) are then handed to the container's user.
+    pub(crate) async fn copy_into(
+        &self,
+        source: &Path,
+        destination: &Path,
+        owned: &[PathBuf],
+    ) -> Result<(), Error> {
+        self.run(self.exec().arg("mkdir").arg("-p").arg(destination), None)
+            .await?;
+
+        // A trailing `/.` copies the contents of the directory, rather than the directory itself.
+        let mut command = Command::new(self.runtime.executable());
+        command.arg("cp").arg(source.join(".")).arg(format!(
+            "{}:{}",
+            self.container,
+            destination.display()
+        ));
+        self.run(&mut command, None).await?;
+
+        self.chown(owned).await
+    }
+
+    /// Change the owner of the given paths within the container to the container's user, if it
+    /// isn't `root`.
+    async fn chown(&self, paths: &[PathBuf]) -> Result<(), Error> {
+        if paths.is_empty() {
+            return Ok(());
+        }
+
+        let output = self
+            .run(
+                self.python()
+                    .arg("-I")
+                    .arg("-c")
+                    .arg("import os; print(os.getuid(), os.getgid())"),
+                None,
+            )
+            .await?;
+        let ids = String::from_utf8_lossy(&output.stdout);
+        let Some((uid, gid)) = ids.trim().split_once(' ') else {
+            return Ok(());
+        };
+        if uid == "0" {
+            return Ok(());
+        }
+
+        debug!(
+            "Changing the owner of {} copied paths in `{self}` to {uid}:{gid}",
+            paths.len()
+        );
+        let mut stdin = String::new();
+        for path in paths {
+            stdin.push_str(&path.to_string_lossy());
+            stdin.push('\n');
+        }
+        let mut command = Command::new(self.runtime.executable());
+        command
+            .arg("exec")
+            .arg("-i")
+            .arg("--user")
+            .arg("0")
+            .arg(&self.container)
+            .arg(&self.executable)
+            .arg("-I")
+            .arg("-c")
+            .arg(include_str!("../python/chown_paths.py"))
+            .arg(uid)
+            .arg(gid);
+        self.run(&mut command, Some(stdin.into_bytes())).await?;
+        Ok(())
+    }
+
+    /// Return `true` if the given path exists within the container.
+    async fn exists(&self, path: &Path) -> Result<bool, Error> {
+        let mut command = self.exec();
+        command.arg("test").arg("-e").arg(path);
+        let output = command
+            .output()
+            .await
+            .map_err(|err| Error::Spawn(self.runtime.executable().to_string(), err))?;
+        Ok(output.status.success())
+    }
+
+    /// Return a [`Command`] that executes a program within the container.
+    fn exec(&self) -> Command {
+        let mut command = Command::new(self.runtime.executable());
+        command.arg("exec").arg("-i").arg(&self.container);
+        command
+    }
+
+    /// Run a [`Command`] to completion, writing the given input to its `stdin`, and returning an
+    /// error if it fails.
+    async fn run(&self, command: &mut Command, stdin: Option<Vec<u8>>) -> Result<Output, Error> {
+        let rendered = display(command);
+        debug!("Running: `{rendered}`");
+        let spawn = |err| Error::Spawn(self.runtime.executable().to_string(), err);
+        let mut child = command
+            .stdin(if stdin.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(spawn)?;
+        if let (Some(input), Some(mut pipe)) = (stdin, child.stdin.take()) {
+            pipe.write_all(&input).await?;
+        }
+        let output = child.wait_with_output().await.map_err(spawn)?;
+        if !output.status.success() {
+            return Err(Error::Command {
+                command: rendered,
+                status: output.status,
+                stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            });
+        }
+        Ok(output)
+    }
+}
+
+impl Display for ContainerPython {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}://{}#{}",
+            self.runtime.scheme(),
+            self.container,
+            self.executable.display()
+        )
+    }
+}
+
+/// An environment for a [`ContainerPython`], in which packages are installed into a local staging
+/// directory that mirrors the container's filesystem.
+#[derive(Debug)]
+pub struct ContainerEnvironment {
+    container: ContainerPython,
+    environment: PythonEnvironment,
+    staging: tempfile::TempDir,
+    /// The `.dist-info` directories seeded from the container, along with the modification time
+    /// of their `RECORD` files.
+    seeded: BTreeMap<PathBuf, Option<SystemTime>>,
+}
+
+impl ContainerEnvironment {
+    /// Return the [`PythonEnvironment`] that installs into the staging directory.
+    pub fn environment(&self) -> &PythonEnvironment {
+        &self.environment
+    }
+
+    /// Return the [`ContainerPython`] for this environment.
+    pub fn container(&self) -> &ContainerPython {
+        &self.container
+    }
+
+    /// Returns `true` if the interpreter in the container is marked as externally managed.
+    pub async fn is_externally_managed(&self) -> Result<bool, Error> {
+        self.container
+            .exists(
+                &self
+                    .environment
+                    .interpreter()
+                    .stdlib()
+                    .join("EXTERNALLY-MANAGED"),
+            )
+            .await
+    }
+
+    /// Copy the staged installation into the container, replacing any existing installations of
+    /// the staged (or uninstalled) packages.
+    pub async fn commit(&self) -> Result<(), Error> {
+        // Scan the staging directory on a blocking thread, as it may contain many files.
+        let staging = self.staging.path().to_path_buf();
+        let site_packages = self
+            .environment
+            .site_packages()
+            .map(std::borrow::Cow::into_owned)
+            .collect::<Vec<_>>();
+        let seeded = self.seeded.clone();
+        let StagedChanges { names, owned } = tokio::task::spawn_blocking(move || {
+            StagedChanges::from_staging(&staging, &site_packages, &seeded)
+        })
+        .await??;
+        if names.is_empty() {
+            return Ok(());
+        }
+
+        // Remove any existing installations of the affected distributions, to avoid leaving
+        // behind files (and metadata) from other versions.
+        debug!(
+            "Removing existing distributions from `{}`: {}",
+            self.container,
+            names.join(", ")
+        );
+        self.container
+            .run(
+                self.container
+                    .python()
+                    .arg("-I")
+                    .arg("-c")
+                    .arg(include_str!("../python/remove_distributions.py"))
+                    .args(&names),
+                None,
+            )
+            .await?;
+        let Some(owned) = owned else {
+            return Ok(());
+        };
+
+        debug!("Copying staged installation into `{}`", self.container);
+        self.container
+            .copy_into(self.staging.path(), Path::new("/"), &owned)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// The changes to a container's installation, as staged in a local directory.
+#[derive(Debug)]
+struct StagedChanges {
+    /// The names of the distributions that were installed, upgraded, or uninstalled.
+    names: Vec<String>,
+    /// The paths (within the container) to hand to the container's user once the staging
+    /// directory is copied in, or `None` if no distributions were installed.
+    owned: Option<Vec<PathBuf>>,
+}
+
+impl StagedChanges {
+    /// Determine the changes staged in the given directory, discarding the seeded metadata of any
+    /// distributions that were left untouched, such that it isn't copied back.
+    fn from_staging(
+        staging: &Path,
+        site_packages: &[PathBuf],
+        seeded: &BTreeMap<PathBuf, Option<SystemTime>>,
+    ) -> Result<Self, Error> {
+        let mut names = Vec::new();
+        let mut staged = Vec::new();
+        for site_packages in site_packages {
+            let Ok(entries) = fs_err::read_dir(site_packages) else {
+                continue;
+            };
+            for entry in entries {
+                let path = entry?.path();
+                if path.extension().is_none_or(|ext| ext != "dist-info") {
+                    continue;
+                }
+                if seeded
+                    .get(&path)
+                    .is_some_and(|modified| *modified == record_modified(&path))
+                {
+                    fs_err::remove_dir_all(&path)?;
+                } else {
+                    names.extend(dist_info_name(&path));
+                    staged.push(path);
+                }
+            }
+        }
+
+        // Any seeded distributions that no longer exist were uninstalled (e.g., upgraded).
+        for dist_info in seeded.keys() {
+            if !dist_info.exists() {
+                names.extend(dist_info_name(dist_info));
+            }
+        }
+        names.sort_unstable();
+        names.dedup();
+        if names.is_empty() || staged.is_empty() {
+            return Ok(Self { names, owned: None });
+        }
+
+        // Every copied file (and any directory within `site-packages`) is handed to the
+        // container's user. Other directories (e.g., `/usr/local/bin`) may already exist, so their
+        // owner is left as-is.
+        let mut owned = Vec::new();
+        collect_owned(staging, site_packages, false, &mut owned)?;
+        let owned = owned
+            .into_iter()
+            .filter_map(|path| {
+                path.strip_prefix(staging)
+                    .ok()
+                    .map(|relative| Path::new("/").join(relative))
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Self {
+            names,
+            owned: Some(owned),
+        })
+    }
+}
+
+/// Collect the files beneath the given directory, along with any directories within one of the
+/// `site-packages` directories (or, if `within_site_packages` is set, any directories at all).
+pub(crate) fn collect_owned(
+    directory: &Path,
+    site_packages: &[PathBuf],
+    within_site_packages: bool,
+    owned: &mut Vec<PathBuf>,
+) -> Result<(), std::io::Error> {
+    for entry in fs_err::read_dir(directory)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            if within_site_packages {
+                owned.push(path.clone());
+            }
+            let within = within_site_packages || site_packages.contains(&path);
+            collect_owned(&path, site_packages, within, owned)?;
+        } else {
+            owned.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Return the modification time of the `RECORD` file in a `.dist-info` directory, if any.
+fn record_modified(dist_info: &Path) -> Option<SystemTime> {
+    fs_err::metadata(dist_info.join("RECORD"))
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Return the name of the distribution for a `.dist-info` directory, e.g., `foo` for
+/// `foo-1.0.0.dist-info`.
+fn dist_info_name(dist_info: &Path) -> Option<String> {
+    let file_name = dist_info.file_name()?.to_string_lossy();
+    let stem = file_name.strip_suffix(".dist-info")?;
+    let (name, _version) = stem.split_once('-')?;
+    Some(name.to_string())
+}
+
+/// Format a [`Command`] for display.
+fn display(command: &Command) -> String {
+    let command = command.as_std();
+    std::iter::once(command.get_program())
+        .chain(command.get_args())
+        .map(OsStr::to_string_lossy)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Re-root an absolute path from another filesystem under the given local directory, e.g.,
+/// `/usr/lib/python3.12` becomes `{root}/usr/lib/python3.12`.
+pub(crate) fn reroot(root: &Path, path: &Path) -> PathBuf {
+    let mut rerooted = root.to_path_buf();
+    for component in path.components() {
+        if let Component::Normal(component) = component {
+            rerooted.push(component);
+        }
+    }
+    rerooted
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{reroot, ContainerPython, ContainerRuntime, Error};
+
+    #[test]
+    fn from_request() {
+        assert_eq!(ContainerPython::from_request("3.12").unwrap(), None);
+        assert_eq!(
+            ContainerPython::from_request("/usr/bin/python3").unwrap(),
+            None
+        );
+
+        let container = ContainerPython::from_request("docker://devcontainer#/usr/bin/python3")
+            .unwrap()
+            .unwrap();
+        assert_eq!(container.runtime, ContainerRuntime::Docker);
+        assert_eq!(container.container, "devcontainer");
+        assert_eq!(container.executable(), Path::new("/usr/bin/python3"));
+        assert_eq!(
+            container.to_string(),
+            "docker://devcontainer#/usr/bin/python3"
+        );
+
+        let container = ContainerPython::from_request("podman://app#/opt/venv/bin/python")
+            .unwrap()
+            .unwrap();
+        assert_eq!(container.runtime, ContainerRuntime::Podman);
+
+        assert!(matches!(
+            ContainerPython::from_request("docker://devcontainer"),
+            Err(Error::InvalidRequest(_))
+        ));
+        assert!(matches!(
+            ContainerPython::from_request("docker://#/usr/bin/python3"),
+            Err(Error::InvalidRequest(_))
+        ));
+        assert!(matches!(
+            ContainerPython::from_request("docker://devcontainer#python3"),
+            Err(Error::InvalidRequest(_))
+        ));
+    }
+
+    #[test]
+    fn reroot_absolute() {
+        assert_eq!(
+            reroot(
+                Path::new("/tmp/staging"),
+                Path::new("/usr/local/lib/python3.12/site-packages")
+            ),
+            PathBuf::from("/tmp/staging/usr/local/lib/python3.12/site-packages")
+        );
+    }
+}
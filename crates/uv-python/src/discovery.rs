@@ -777,7 +777,10 @@ impl Error {
             Error::Query(err, _, source) => match &**err {
                 InterpreterError::Encode(_)
                 | InterpreterError::Io(_)
-                | InterpreterError::SpawnFailed { .. } => true,
+                | InterpreterError::SpawnFailed { .. }
+                | InterpreterError::SchemeOverride(..)
+                | InterpreterError::MissingScheme { .. }
+                | InterpreterError::Container(..) => true,
                 InterpreterError::UnexpectedResponse(UnexpectedResponseError { path, .. })
                 | InterpreterError::StatusCode(StatusCodeError { path, .. }) => {
                     debug!(
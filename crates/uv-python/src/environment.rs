@@ -304,6 +304,14 @@ impl PythonEnvironment {
         } else if let Some(prefix) = self.0.interpreter.prefix() {
             // Likewise, if we're installing into a `--prefix`, use a prefix-specific lockfile.
             LockedFile::acquire(prefix.root().join(".lock"), prefix.root().user_display()).await
+        } else if let Some(sysroot) = self.0.interpreter.sysroot() {
+            // If we're staging an installation for another filesystem, lock the staging directory,
+            // without writing to it.
+            LockedFile::acquire(
+                env::temp_dir().join(format!("uv-{}.lock", cache_digest(&sysroot))),
+                sysroot.user_display(),
+            )
+            .await
         } else if self.0.interpreter.is_virtualenv() {
             // If the environment a virtualenv, use a virtualenv-specific lockfile.
             LockedFile::acquire(self.0.root.join(".lock"), self.0.root.user_display()).await
@@ -1,9 +1,10 @@
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::env::consts::ARCH;
 use std::fmt::{Display, Formatter};
 use std::io;
 use std::path::{Path, PathBuf};
-use std::process::{Command, ExitStatus};
+use std::process::{Command, ExitStatus, Output};
 use std::sync::OnceLock;
 
 use configparser::ini::Ini;
@@ -14,10 +15,10 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tracing::{debug, trace, warn};
 
-use uv_cache::{Cache, CacheBucket, CachedByTimestamp, Freshness};
+use uv_cache::{Cache, CacheBucket, CacheEntry, Freshness};
 use uv_cache_info::Timestamp;
 use uv_cache_key::cache_digest;
-use uv_fs::{write_atomic_sync, PythonExt, Simplified};
+use uv_fs::{write_atomic_sync, LockedFile, PythonExt, Simplified};
 use uv_install_wheel::Layout;
 use uv_pep440::Version;
 use uv_pep508::{MarkerEnvironment, StringVersion};
@@ -25,6 +26,7 @@ use uv_platform_tags::Platform;
 use uv_platform_tags::{Tags, TagsError};
 use uv_pypi_types::{ResolverMarkerEnvironment, Scheme};
 
+use crate::container::{collect_owned, reroot, ContainerPython};
 use crate::implementation::LenientImplementationName;
 use crate::platform::{Arch, Libc, Os};
 use crate::pointer_size::PointerSize;
@@ -34,6 +36,7 @@ use crate::{
 };
 
 /// A Python executable and its associated platform markers.
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
 pub struct Interpreter {
     platform: Platform,
@@ -52,31 +55,80 @@ pub struct Interpreter {
     tags: OnceLock<Tags>,
     target: Option<Target>,
     prefix: Option<Prefix>,
+    sysroot: Option<PathBuf>,
     pointer_size: PointerSize,
     gil_disabled: bool,
+    debug: bool,
+    sysconfig_vars: BTreeMap<String, serde_json::Value>,
 }
 
 impl Interpreter {
     /// Detect the interpreter info for the given Python executable.
     pub fn query(executable: impl AsRef<Path>, cache: &Cache) -> Result<Self, Error> {
-        let info = InterpreterInfo::query_cached(executable.as_ref(), cache)?;
+        let mut info = InterpreterInfo::query_cached(executable.as_ref(), cache)?;
+
+        // Prefer an explicit scheme override, if one exists; otherwise, use the scheme reported by
+        // the interpreter.
+        let scheme = if let Some(scheme) = SchemeOverride::read(&info.sys_prefix)? {
+            debug!(
+                "Using scheme override from: {}",
+                info.sys_prefix
+                    .join(SCHEME_OVERRIDE_FILENAME)
+                    .user_display()
+            );
+            scheme.into_scheme(&info.sys_prefix)
+        } else if let Some(scheme) = info.scheme.take() {
+            scheme
+        } else {
+            return Err(Error::MissingScheme {
+                path: executable.as_ref().to_path_buf(),
+                sys_prefix: info.sys_prefix,
+            });
+        };
+
+        Ok(Self::from_info(info, scheme))
+    }
+
+    /// Detect the interpreter info for a Python executable in a running container.
+    ///
+    /// Unlike [`Interpreter::query`], the result is not cached, since the container may be
+    /// recreated at any time.
+    pub async fn query_container(
+        container: &ContainerPython,
+        cache: &Cache,
+    ) -> Result<Self, Error> {
+        let mut info = InterpreterInfo::query_container(container, cache).await?;
+
+        let Some(scheme) = info.scheme.take() else {
+            return Err(Error::MissingScheme {
+                path: container.executable().to_path_buf(),
+                sys_prefix: info.sys_prefix,
+            });
+        };
+
+        Ok(Self::from_info(info, scheme))
+    }
 
+    /// Create an [`Interpreter`] from the queried [`InterpreterInfo`] and installation scheme.
+    fn from_info(info: InterpreterInfo, scheme: Scheme) -> Self {
         debug_assert!(
             info.sys_executable.is_absolute(),
             "`sys.executable` is not an absolute Python; Python installation is broken: {}",
             info.sys_executable.display()
         );
 
-        Ok(Self {
+        Self {
             platform: info.platform,
             markers: Box::new(info.markers),
-            scheme: info.scheme,
+            scheme,
             virtualenv: info.virtualenv,
             manylinux_compatible: info.manylinux_compatible,
             sys_prefix: info.sys_prefix,
             sys_base_exec_prefix: info.sys_base_exec_prefix,
             pointer_size: info.pointer_size,
             gil_disabled: info.gil_disabled,
+            debug: info.debug,
+            sysconfig_vars: info.sysconfig_vars,
             sys_base_prefix: info.sys_base_prefix,
             sys_base_executable: info.sys_base_executable,
             sys_executable: info.sys_executable,
@@ -86,7 +138,8 @@ impl Interpreter {
             tags: OnceLock::new(),
             target: None,
             prefix: None,
-        })
+            sysroot: None,
+        }
     }
 
     /// Return a new [`Interpreter`] with the given virtual environment root.
@@ -99,6 +152,7 @@ impl Interpreter {
             sys_prefix: virtualenv.root,
             target: None,
             prefix: None,
+            sysroot: None,
             ..self
         }
     }
@@ -112,6 +166,20 @@ impl Interpreter {
         })
     }
 
+    /// Return a new [`Interpreter`] that installs into a local directory standing in for the root
+    /// of the filesystem on which the interpreter lives (e.g., to stage an installation for an
+    /// interpreter in a container).
+    pub fn with_sysroot(self, sysroot: PathBuf) -> io::Result<Self> {
+        let interpreter = Self {
+            sysroot: Some(sysroot),
+            ..self
+        };
+        for site_packages in interpreter.site_packages() {
+            fs_err::create_dir_all(site_packages)?;
+        }
+        Ok(interpreter)
+    }
+
     /// Return a new [`Interpreter`] to install into the given `--prefix` directory.
     pub fn with_prefix(self, prefix: Prefix) -> io::Result<Self> {
         prefix.init(self.virtualenv())?;
@@ -261,6 +329,12 @@ impl Interpreter {
         self.prefix.is_some()
     }
 
+    /// Returns `true` if installations are staged in a local directory standing in for the root
+    /// of the interpreter's filesystem.
+    pub fn is_sysroot(&self) -> bool {
+        self.sysroot.is_some()
+    }
+
     /// Returns `Some` if the environment is externally managed, optionally including an error
     /// message from the `EXTERNALLY-MANAGED` file.
     ///
@@ -276,6 +350,12 @@ impl Interpreter {
             return None;
         }
 
+        // If we're staging an installation for another filesystem, the marker can't be read
+        // locally; it's the caller's responsibility to check it.
+        if self.is_sysroot() {
+            return None;
+        }
+
         let Ok(contents) = fs::read_to_string(self.stdlib.join("EXTERNALLY-MANAGED")) else {
             return None;
         };
@@ -452,6 +532,20 @@ impl Interpreter {
         self.gil_disabled
     }
 
+    /// Return whether this is a debug build of Python, as specified by the sysconfig var
+    /// `Py_DEBUG`.
+    ///
+    /// Native modules built for a debug build carry a `d` abiflag (e.g., `cp312d`).
+    pub fn is_debug(&self) -> bool {
+        self.debug
+    }
+
+    /// Return the values of the `sysconfig` configuration variables that describe how native code
+    /// is built for the interpreter (e.g., `EXT_SUFFIX` or `SOABI`), omitting any that are unset.
+    pub fn sysconfig_vars(&self) -> &BTreeMap<String, serde_json::Value> {
+        &self.sysconfig_vars
+    }
+
     /// Return the `--target` directory for this interpreter, if any.
     pub fn target(&self) -> Option<&Target> {
         self.target.as_ref()
@@ -462,6 +556,12 @@ impl Interpreter {
         self.prefix.as_ref()
     }
 
+    /// Return the local directory standing in for the root of the interpreter's filesystem, if
+    /// any.
+    pub fn sysroot(&self) -> Option<&Path> {
+        self.sysroot.as_deref()
+    }
+
     /// Returns `true` if an [`Interpreter`] may be a `python-build-standalone` interpreter.
     ///
     /// This method may return false positives, but it should not return false negatives. In other
@@ -485,7 +585,7 @@ impl Interpreter {
             } else if let Some(prefix) = self.prefix.as_ref() {
                 prefix.scheme(&self.virtualenv)
             } else {
-                Scheme {
+                let scheme = Scheme {
                     purelib: self.purelib().to_path_buf(),
                     platlib: self.platlib().to_path_buf(),
                     scripts: self.scripts().to_path_buf(),
@@ -501,6 +601,20 @@ impl Interpreter {
                     } else {
                         self.include().to_path_buf()
                     },
+                };
+
+                // If we're staging an installation for another filesystem, install beneath the
+                // local root, such that relative paths (e.g., in `RECORD` files) are preserved.
+                if let Some(sysroot) = self.sysroot.as_deref() {
+                    Scheme {
+                        purelib: reroot(sysroot, &scheme.purelib),
+                        platlib: reroot(sysroot, &scheme.platlib),
+                        scripts: reroot(sysroot, &scheme.scripts),
+                        data: reroot(sysroot, &scheme.data),
+                        include: reroot(sysroot, &scheme.include),
+                    }
+                } else {
+                    scheme
                 }
             },
         }
@@ -539,7 +653,12 @@ impl Interpreter {
             .flatten()
             .map(Cow::Borrowed)
             .chain(prefix.into_iter().flatten().map(Cow::Owned))
-            .chain(interpreter.into_iter().flatten().map(Cow::Borrowed))
+            .chain(interpreter.into_iter().flatten().map(|site_packages| {
+                match self.sysroot.as_deref() {
+                    Some(sysroot) => Cow::Owned(reroot(sysroot, site_packages)),
+                    None => Cow::Borrowed(site_packages),
+                }
+            }))
     }
 
     /// Check if the interpreter matches the given Python version.
@@ -684,6 +803,12 @@ pub enum Error {
     },
     #[error("Failed to write to cache")]
     Encode(#[from] rmp_serde::encode::Error),
+    #[error("Failed to parse: `{}`", _0.user_display())]
+    SchemeOverride(PathBuf, #[source] Box<toml::de::Error>),
+    #[error("Python interpreter at `{}` does not provide an installation scheme (is `sysconfig` available?); to use it, provide the scheme in `{}`", path.user_display(), sys_prefix.join(SCHEME_OVERRIDE_FILENAME).user_display())]
+    MissingScheme { path: PathBuf, sys_prefix: PathBuf },
+    #[error("Failed to query Python interpreter in container: `{0}`")]
+    Container(String, #[source] crate::container::Error),
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -713,11 +838,14 @@ pub enum InterpreterInfoError {
     },
 }
 
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 struct InterpreterInfo {
     platform: Platform,
     markers: MarkerEnvironment,
-    scheme: Scheme,
+    /// The installation scheme, if the interpreter was able to determine one (e.g., embedded
+    /// distributions may lack the necessary `sysconfig` data).
+    scheme: Option<Scheme>,
     virtualenv: Scheme,
     manylinux_compatible: bool,
     sys_prefix: PathBuf,
@@ -730,6 +858,8 @@ struct InterpreterInfo {
     standalone: bool,
     pointer_size: PointerSize,
     gil_disabled: bool,
+    debug: bool,
+    sysconfig_vars: BTreeMap<String, serde_json::Value>,
 }
 
 impl InterpreterInfo {
@@ -738,24 +868,82 @@ impl InterpreterInfo {
         let tempdir = tempfile::tempdir_in(cache.root())?;
         Self::setup_python_query_files(tempdir.path())?;
 
-        // Sanitize the path by (1) running under isolated mode (`-I`) to ignore any site packages
-        // modifications, and then (2) adding the path containing our query script to the front of
-        // `sys.path` so that we can import it.
-        let script = format!(
-            r#"import sys; sys.path = ["{}"] + sys.path; from python.get_interpreter_info import main; main()"#,
-            tempdir.path().escape_for_python()
-        );
         let output = Command::new(interpreter)
             .arg("-I") // Isolated mode.
             .arg("-B") // Don't write bytecode.
             .arg("-c")
-            .arg(script)
+            .arg(Self::query_script(tempdir.path()))
             .output()
             .map_err(|err| Error::SpawnFailed {
                 path: interpreter.to_path_buf(),
                 err,
             })?;
 
+        Self::from_output(&output, interpreter)
+    }
+
+    /// Return the resolved [`InterpreterInfo`] for a Python executable in a running container.
+    pub(crate) async fn query_container(
+        container: &ContainerPython,
+        cache: &Cache,
+    ) -> Result<Self, Error> {
+        let tempdir = tempfile::tempdir_in(cache.root())?;
+        Self::setup_python_query_files(tempdir.path())?;
+
+        // Copy the query script into the container, handing the copied files to the container's
+        // user such that they can be removed afterwards.
+        let remote = container
+            .tempdir()
+            .await
+            .map_err(|err| Error::Container(container.to_string(), err))?;
+        let mut owned = Vec::new();
+        collect_owned(tempdir.path(), &[], true, &mut owned)?;
+        let owned = owned
+            .into_iter()
+            .filter_map(|path| {
+                path.strip_prefix(tempdir.path())
+                    .ok()
+                    .map(|relative| remote.join(relative))
+            })
+            .collect::<Vec<_>>();
+        let result = match container.copy_into(tempdir.path(), &remote, &owned).await {
+            Ok(()) => container
+                .python()
+                .arg("-I") // Isolated mode.
+                .arg("-B") // Don't write bytecode.
+                .arg("-c")
+                .arg(Self::query_script(&remote))
+                .output()
+                .await
+                .map_err(|err| Error::SpawnFailed {
+                    path: container.executable().to_path_buf(),
+                    err,
+                }),
+            Err(err) => Err(Error::Container(container.to_string(), err)),
+        };
+
+        // Remove the query script from the container, regardless of the outcome.
+        if let Err(err) = container.remove_dir_all(&remote).await {
+            warn!("Failed to remove query script from `{container}`: {err}");
+        }
+
+        Self::from_output(&result?, container.executable())
+    }
+
+    /// Return a script that runs the interpreter query from the given directory.
+    ///
+    /// Sanitizes the path by (1) running under isolated mode (`-I`) to ignore any site packages
+    /// modifications, and then (2) adding the path containing our query script to the front of
+    /// `sys.path` so that we can import it.
+    fn query_script(root: &Path) -> String {
+        format!(
+            r#"import sys; sys.path = ["{}"] + sys.path; from python.get_interpreter_info import main; main()"#,
+            root.escape_for_python()
+        )
+    }
+
+    /// Parse the [`InterpreterInfo`] from the output of the query script.
+    fn from_output(output: &Output, interpreter: &Path) -> Result<Self, Error> {
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
 
@@ -846,20 +1034,21 @@ impl InterpreterInfo {
     pub(crate) fn query_cached(executable: &Path, cache: &Cache) -> Result<Self, Error> {
         let absolute = std::path::absolute(executable)?;
 
-        let cache_entry = cache.entry(
+        // We use the absolute path for the cache entry to avoid cache collisions for relative
+        // paths. But we don't to query the executable with symbolic links resolved.
+        let digest = cache_digest(&absolute);
+        let cache_shard = cache.shard(
             CacheBucket::Interpreter,
             // Shard interpreter metadata by host architecture, to avoid cache collisions when
             // running universal binaries under Rosetta.
             ARCH,
-            // We use the absolute path for the cache entry to avoid cache collisions for relative
-            // paths. But we don't to query the executable with symbolic links resolved.
-            format!("{}.msgpack", cache_digest(&absolute)),
         );
+        let cache_entry = cache_shard.entry(format!("{digest}.msgpack"));
 
-        // We check the timestamp of the canonicalized executable to check if an underlying
-        // interpreter has been modified.
-        let modified = uv_fs::canonicalize_executable(&absolute)
-            .and_then(Timestamp::from_path)
+        // We fingerprint the canonicalized executable to check if an underlying interpreter has
+        // been modified.
+        let fingerprint = uv_fs::canonicalize_executable(&absolute)
+            .and_then(ExecutableFingerprint::from_path)
             .map_err(|err| {
                 if err.kind() == io::ErrorKind::NotFound {
                     Error::NotFound(executable.to_path_buf())
@@ -869,36 +1058,8 @@ impl InterpreterInfo {
             })?;
 
         // Read from the cache.
-        if cache
-            .freshness(&cache_entry, None)
-            .is_ok_and(Freshness::is_fresh)
-        {
-            if let Ok(data) = fs::read(cache_entry.path()) {
-                match rmp_serde::from_slice::<CachedByTimestamp<Self>>(&data) {
-                    Ok(cached) => {
-                        if cached.timestamp == modified {
-                            trace!(
-                                "Cached interpreter info for Python {}, skipping probing: {}",
-                                cached.data.markers.python_full_version(),
-                                executable.user_display()
-                            );
-                            return Ok(cached.data);
-                        }
-
-                        trace!(
-                            "Ignoring stale interpreter markers for: {}",
-                            executable.user_display()
-                        );
-                    }
-                    Err(err) => {
-                        warn!(
-                            "Broken interpreter cache entry at {}, removing: {err}",
-                            cache_entry.path().user_display()
-                        );
-                        let _ = fs_err::remove_file(cache_entry.path());
-                    }
-                }
-            }
+        if let Some(info) = Self::read_cached(executable, &cache_entry, fingerprint, cache) {
+            return Ok(info);
         }
 
         // Otherwise, run the Python script.
@@ -911,11 +1072,17 @@ impl InterpreterInfo {
         // If `executable` is a pyenv shim, a bash script that redirects to the activated
         // python executable at another path, we're not allowed to cache the interpreter info.
         if is_same_file(executable, &info.sys_executable).unwrap_or(false) {
+            // Acquire a lock on the cache entry only to write it, rather than while running the
+            // Python script, such that a slow or hanging interpreter can't block other processes.
             fs::create_dir_all(cache_entry.dir())?;
+            let _lock = LockedFile::acquire_blocking(
+                cache_shard.join(format!("{digest}.lock")),
+                absolute.user_display(),
+            )?;
             write_atomic_sync(
                 cache_entry.path(),
-                rmp_serde::to_vec(&CachedByTimestamp {
-                    timestamp: modified,
+                rmp_serde::to_vec(&CachedInterpreter {
+                    fingerprint,
                     data: info.clone(),
                 })?,
             )?;
@@ -923,6 +1090,136 @@ impl InterpreterInfo {
 
         Ok(info)
     }
+
+    /// Read the cached interpreter info for the given executable, if it's fresh and matches the
+    /// executable's current [`ExecutableFingerprint`].
+    fn read_cached(
+        executable: &Path,
+        cache_entry: &CacheEntry,
+        fingerprint: ExecutableFingerprint,
+        cache: &Cache,
+    ) -> Option<Self> {
+        if !cache
+            .freshness(cache_entry, None)
+            .is_ok_and(Freshness::is_fresh)
+        {
+            return None;
+        }
+
+        let data = fs::read(cache_entry.path()).ok()?;
+        match rmp_serde::from_slice::<CachedInterpreter>(&data) {
+            Ok(cached) => {
+                if cached.fingerprint == fingerprint {
+                    trace!(
+                        "Cached interpreter info for Python {}, skipping probing: {}",
+                        cached.data.markers.python_full_version(),
+                        executable.user_display()
+                    );
+                    return Some(cached.data);
+                }
+
+                trace!(
+                    "Ignoring stale interpreter markers for: {}",
+                    executable.user_display()
+                );
+            }
+            Err(err) => {
+                warn!(
+                    "Broken interpreter cache entry at {}, removing: {err}",
+                    cache_entry.path().user_display()
+                );
+                let _ = fs_err::remove_file(cache_entry.path());
+            }
+        }
+
+        None
+    }
+}
+
+/// The [`Timestamp`] and size of an interpreter executable, used to invalidate cached
+/// interpreter info when the interpreter is replaced or upgraded in-place.
+///
+/// The size guards against in-place replacements that preserve the timestamp (e.g., when
+/// copying an interpreter with its metadata intact).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
+struct ExecutableFingerprint {
+    timestamp: Timestamp,
+    size: u64,
+}
+
+impl ExecutableFingerprint {
+    fn from_path(path: impl AsRef<Path>) -> io::Result<Self> {
+        let metadata = fs_err::metadata(path.as_ref())?;
+        Ok(Self {
+            timestamp: Timestamp::from_metadata(&metadata),
+            size: metadata.len(),
+        })
+    }
+}
+
+/// The interpreter info stored in the cache, along with the fingerprint of the executable it was
+/// queried from.
+#[derive(Deserialize, Serialize)]
+struct CachedInterpreter {
+    fingerprint: ExecutableFingerprint,
+    data: InterpreterInfo,
+}
+
+/// The name of the file, located in an interpreter's `sys.prefix`, that provides an explicit
+/// installation scheme for the interpreter.
+///
+/// Embedded and statically-linked Python distributions (e.g., those bundled with desktop
+/// applications or game engines) often lack the `sysconfig` data required to determine where
+/// packages should be installed.
+pub const SCHEME_OVERRIDE_FILENAME: &str = "uv.scheme.toml";
+
+/// An explicit installation scheme, read from a [`SCHEME_OVERRIDE_FILENAME`] file, e.g.:
+///
+/// ```toml
+/// purelib = "Lib/site-packages"
+/// platlib = "Lib/site-packages"
+/// scripts = "Scripts"
+/// ```
+///
+/// Relative paths are resolved against the interpreter's `sys.prefix`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+struct SchemeOverride {
+    purelib: PathBuf,
+    platlib: PathBuf,
+    scripts: PathBuf,
+    data: Option<PathBuf>,
+    include: Option<PathBuf>,
+}
+
+impl SchemeOverride {
+    /// Read the scheme override from the given `sys.prefix`, if it exists.
+    fn read(sys_prefix: &Path) -> Result<Option<Self>, Error> {
+        let path = sys_prefix.join(SCHEME_OVERRIDE_FILENAME);
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let scheme =
+            toml::from_str(&content).map_err(|err| Error::SchemeOverride(path, Box::new(err)))?;
+        Ok(Some(scheme))
+    }
+
+    /// Convert the override into a [`Scheme`], resolving relative paths against `sys.prefix`.
+    ///
+    /// If omitted, `data` defaults to `sys.prefix` and `include` to `{sys.prefix}/include`.
+    fn into_scheme(self, sys_prefix: &Path) -> Scheme {
+        Scheme {
+            purelib: sys_prefix.join(self.purelib),
+            platlib: sys_prefix.join(self.platlib),
+            scripts: sys_prefix.join(self.scripts),
+            data: self
+                .data
+                .map_or_else(|| sys_prefix.to_path_buf(), |data| sys_prefix.join(data)),
+            include: sys_prefix.join(self.include.unwrap_or_else(|| PathBuf::from("include"))),
+        }
+    }
 }
 
 /// Find the Python executable that should be considered the "base" for a virtual environment.
@@ -1018,6 +1315,7 @@ fn find_base_python(
 #[cfg(unix)]
 #[cfg(test)]
 mod tests {
+    use std::path::Path;
     use std::str::FromStr;
 
     use fs_err as fs;
@@ -1027,13 +1325,11 @@ mod tests {
     use uv_cache::Cache;
     use uv_pep440::Version;
 
+    use crate::interpreter::{Error, SCHEME_OVERRIDE_FILENAME};
     use crate::Interpreter;
 
-    #[test]
-    fn test_cache_invalidation() {
-        let mock_dir = tempdir().unwrap();
-        let mocked_interpreter = mock_dir.path().join("python");
-        let json = indoc! {r##"
+    /// The interpreter info reported by the mocked interpreters.
+    const INTERPRETER_INFO: &str = indoc! {r##"
         {
             "result": "success",
             "platform": {
@@ -1083,10 +1379,18 @@ mod tests {
                 "scripts": "bin"
             },
             "pointer_size": "64",
-            "gil_disabled": true
+            "gil_disabled": true,
+            "debug": false,
+            "sysconfig_vars": {}
         }
     "##};
 
+    #[test]
+    fn test_cache_invalidation() {
+        let mock_dir = tempdir().unwrap();
+        let mocked_interpreter = mock_dir.path().join("python");
+        let json = INTERPRETER_INFO;
+
         let cache = Cache::temp().unwrap().init().unwrap();
 
         fs::write(
@@ -1122,4 +1426,166 @@ mod tests {
             Version::from_str("3.13").unwrap()
         );
     }
+
+    #[test]
+    fn test_cache_fingerprint() {
+        let mock_dir = tempdir().unwrap();
+        let mocked_interpreter = mock_dir.path().join("python");
+        // The interpreter info is only cached if the executable is `sys.executable`.
+        let json = INTERPRETER_INFO.replace(
+            "/home/ferris/projects/uv/.venv/bin/python",
+            mocked_interpreter.to_str().unwrap(),
+        );
+
+        let cache = Cache::temp().unwrap().init().unwrap();
+
+        // Write the mocked interpreter, preserving its modification time across rewrites.
+        let write = |json: &str| {
+            let mtime = fs::metadata(&mocked_interpreter)
+                .and_then(|metadata| metadata.modified())
+                .ok();
+            fs::write(
+                &mocked_interpreter,
+                formatdoc! {r"
+                #!/bin/sh
+                echo '{json}'
+                "},
+            )
+            .unwrap();
+            fs::set_permissions(
+                &mocked_interpreter,
+                std::os::unix::fs::PermissionsExt::from_mode(0o770),
+            )
+            .unwrap();
+            if let Some(mtime) = mtime {
+                fs::File::options()
+                    .write(true)
+                    .open(&mocked_interpreter)
+                    .unwrap()
+                    .file()
+                    .set_modified(mtime)
+                    .unwrap();
+            }
+        };
+
+        write(&json);
+        let interpreter = Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        assert_eq!(
+            interpreter.markers.python_version().version,
+            Version::from_str("3.12").unwrap()
+        );
+
+        // If the executable's modification time and size are unchanged, the cached info is used.
+        write(&json.replace("3.12", "3.13"));
+        let interpreter = Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        assert_eq!(
+            interpreter.markers.python_version().version,
+            Version::from_str("3.12").unwrap()
+        );
+
+        // If the size changes, the interpreter is queried again, even with the same modification
+        // time.
+        write(&format!("{}  ", json.replace("3.12", "3.13")));
+        let interpreter = Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        assert_eq!(
+            interpreter.markers.python_version().version,
+            Version::from_str("3.13").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_scheme_override() {
+        let mock_dir = tempdir().unwrap();
+        let mocked_interpreter = mock_dir.path().join("python");
+        let sys_prefix = mock_dir.path().join("prefix");
+        fs::create_dir(&sys_prefix).unwrap();
+
+        // An embedded interpreter that can't determine its own installation scheme.
+        let json = formatdoc! {r##"
+        {{
+            "result": "success",
+            "platform": {{
+                "os": {{
+                    "name": "manylinux",
+                    "major": 2,
+                    "minor": 38
+                }},
+                "arch": "x86_64"
+            }},
+            "manylinux_compatible": false,
+            "standalone": false,
+            "markers": {{
+                "implementation_name": "cpython",
+                "implementation_version": "3.12.0",
+                "os_name": "posix",
+                "platform_machine": "x86_64",
+                "platform_python_implementation": "CPython",
+                "platform_release": "6.5.0-13-generic",
+                "platform_system": "Linux",
+                "platform_version": "#13-Ubuntu SMP PREEMPT_DYNAMIC Fri Nov  3 12:16:05 UTC 2023",
+                "python_full_version": "3.12.0",
+                "python_version": "3.12",
+                "sys_platform": "linux"
+            }},
+            "sys_base_exec_prefix": "{prefix}",
+            "sys_base_prefix": "{prefix}",
+            "sys_prefix": "{prefix}",
+            "sys_executable": "{executable}",
+            "sys_path": [],
+            "stdlib": "{prefix}/lib",
+            "scheme": null,
+            "virtualenv": {{
+                "data": "",
+                "include": "include",
+                "platlib": "lib/python3.12/site-packages",
+                "purelib": "lib/python3.12/site-packages",
+                "scripts": "bin"
+            }},
+            "pointer_size": "64",
+            "gil_disabled": false,
+            "debug": false,
+            "sysconfig_vars": {{}}
+        }}
+    "##, prefix = sys_prefix.display(), executable = mocked_interpreter.display()};
+
+        let cache = Cache::temp().unwrap().init().unwrap();
+
+        fs::write(
+            &mocked_interpreter,
+            formatdoc! {r"
+        #!/bin/sh
+        echo '{json}'
+        "},
+        )
+        .unwrap();
+
+        fs::set_permissions(
+            &mocked_interpreter,
+            std::os::unix::fs::PermissionsExt::from_mode(0o770),
+        )
+        .unwrap();
+
+        // Without an override, the interpreter can't be used.
+        assert!(matches!(
+            Interpreter::query(&mocked_interpreter, &cache),
+            Err(Error::MissingScheme { .. })
+        ));
+
+        // With an override, the scheme is resolved relative to `sys.prefix`.
+        fs::write(
+            sys_prefix.join(SCHEME_OVERRIDE_FILENAME),
+            indoc! {r#"
+            purelib = "site-packages"
+            platlib = "site-packages"
+            scripts = "/usr/local/bin"
+            "#},
+        )
+        .unwrap();
+        let interpreter = Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        assert_eq!(interpreter.purelib(), sys_prefix.join("site-packages"));
+        assert_eq!(interpreter.platlib(), sys_prefix.join("site-packages"));
+        assert_eq!(interpreter.scripts(), Path::new("/usr/local/bin"));
+        assert_eq!(interpreter.data(), sys_prefix);
+        assert_eq!(interpreter.include(), sys_prefix.join("include"));
+    }
 }
@@ -4,6 +4,9 @@ use thiserror::Error;
 #[cfg(test)]
 use uv_static::EnvVars;
 
+pub use crate::container::{
+    ContainerEnvironment, ContainerPython, ContainerRuntime, Error as ContainerError,
+};
 pub use crate::discovery::{
     find_python_installations, EnvironmentPreference, Error as DiscoveryError, PythonDownloads,
     PythonNotFound, PythonPreference, PythonRequest, PythonSource, PythonVariant, VersionRequest,
@@ -11,10 +14,11 @@ pub use crate::discovery::{
 pub use crate::environment::{InvalidEnvironmentKind, PythonEnvironment};
 pub use crate::implementation::ImplementationName;
 pub use crate::installation::{PythonInstallation, PythonInstallationKey};
-pub use crate::interpreter::{Error as InterpreterError, Interpreter};
+pub use crate::interpreter::{Error as InterpreterError, Interpreter, SCHEME_OVERRIDE_FILENAME};
 pub use crate::pointer_size::PointerSize;
 pub use crate::prefix::Prefix;
 pub use crate::python_version::PythonVersion;
+pub use crate::scheme_path::{SchemeKey, SchemePath};
 pub use crate::target::Target;
 pub use crate::version_files::{
     DiscoveryOptions as VersionFileDiscoveryOptions, FilePreference as VersionFilePreference,
@@ -22,6 +26,7 @@ pub use crate::version_files::{
 };
 pub use crate::virtualenv::{Error as VirtualEnvError, PyVenvConfiguration, VirtualEnvironment};
 
+mod container;
 mod cpuinfo;
 mod discovery;
 pub mod downloads;
@@ -38,6 +43,7 @@ pub mod platform;
 mod pointer_size;
 mod prefix;
 mod python_version;
+mod scheme_path;
 mod sysconfig;
 mod target;
 mod version_files;
@@ -268,7 +274,9 @@ mod tests {
                         "scripts": "bin"
                     },
                     "pointer_size": "64",
-                    "gil_disabled": {FREE_THREADED}
+                    "gil_disabled": {FREE_THREADED},
+                    "debug": false,
+                    "sysconfig_vars": {}
                 }
             "##};
 
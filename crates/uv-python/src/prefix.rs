@@ -2,26 +2,42 @@ use std::path::{Path, PathBuf};
 
 use uv_pypi_types::Scheme;
 
+use crate::SchemePath;
+
 /// A `--prefix` directory into which packages can be installed, separate from a virtual environment
 /// or system Python interpreter.
 #[derive(Debug, Clone)]
-pub struct Prefix(PathBuf);
+pub struct Prefix {
+    root: PathBuf,
+    scheme_paths: Vec<SchemePath>,
+}
 
 impl Prefix {
+    /// Set the custom installation paths for wheel `.data` directories (e.g., `scripts`).
+    #[must_use]
+    pub fn with_scheme_paths(mut self, scheme_paths: Vec<SchemePath>) -> Self {
+        self.scheme_paths = scheme_paths;
+        self
+    }
+
     /// Return the [`Scheme`] for the `--prefix` directory.
     pub fn scheme(&self, virtualenv: &Scheme) -> Scheme {
-        Scheme {
-            purelib: self.0.join(&virtualenv.purelib),
-            platlib: self.0.join(&virtualenv.platlib),
-            scripts: self.0.join(&virtualenv.scripts),
-            data: self.0.join(&virtualenv.data),
-            include: self.0.join(&virtualenv.include),
-        }
+        SchemePath::apply(
+            &self.scheme_paths,
+            &self.root,
+            Scheme {
+                purelib: self.root.join(&virtualenv.purelib),
+                platlib: self.root.join(&virtualenv.platlib),
+                scripts: self.root.join(&virtualenv.scripts),
+                data: self.root.join(&virtualenv.data),
+                include: self.root.join(&virtualenv.include),
+            },
+        )
     }
 
     /// Return an iterator over the `site-packages` directories inside the environment.
     pub fn site_packages(&self, virtualenv: &Scheme) -> impl Iterator<Item = PathBuf> {
-        std::iter::once(self.0.join(&virtualenv.purelib))
+        std::iter::once(self.root.join(&virtualenv.purelib))
     }
 
     /// Initialize the `--prefix` directory.
@@ -34,12 +50,15 @@ impl Prefix {
 
     /// Return the path to the `--prefix` directory.
     pub fn root(&self) -> &Path {
-        &self.0
+        &self.root
     }
 }
 
 impl From<PathBuf> for Prefix {
-    fn from(path: PathBuf) -> Self {
-        Self(path)
+    fn from(root: PathBuf) -> Self {
+        Self {
+            root,
+            scheme_paths: Vec::new(),
+        }
     }
 }
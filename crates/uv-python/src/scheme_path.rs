@@ -0,0 +1,136 @@
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use uv_pypi_types::Scheme;
+
+/// A wheel `.data` directory whose installation path can be customized when installing into a
+/// `--target` or `--prefix` directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SchemeKey {
+    /// The `.data/scripts` directory.
+    Scripts,
+    /// The `.data/headers` directory.
+    Headers,
+    /// The `.data/data` directory.
+    Data,
+}
+
+impl FromStr for SchemeKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "scripts" => Ok(Self::Scripts),
+            "headers" => Ok(Self::Headers),
+            "data" => Ok(Self::Data),
+            _ => Err(format!(
+                "Invalid scheme key: `{s}` (expected one of `scripts`, `headers`, or `data`)"
+            )),
+        }
+    }
+}
+
+impl Display for SchemeKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Scripts => write!(f, "scripts"),
+            Self::Headers => write!(f, "headers"),
+            Self::Data => write!(f, "data"),
+        }
+    }
+}
+
+/// A custom installation path for a wheel `.data` directory, e.g., `scripts=usr/bin`.
+///
+/// Relative paths are resolved against the `--target` or `--prefix` directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemePath {
+    key: SchemeKey,
+    path: PathBuf,
+}
+
+impl SchemePath {
+    /// Apply the given [`SchemePath`] entries to a [`Scheme`], resolving relative paths against
+    /// the given root. Later entries take precedence over earlier ones.
+    pub fn apply(scheme_paths: &[Self], root: &Path, mut scheme: Scheme) -> Scheme {
+        for SchemePath { key, path } in scheme_paths {
+            let path = root.join(path);
+            match key {
+                SchemeKey::Scripts => scheme.scripts = path,
+                SchemeKey::Headers => scheme.include = path,
+                SchemeKey::Data => scheme.data = path,
+            }
+        }
+        scheme
+    }
+}
+
+impl FromStr for SchemePath {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((key, path)) = s.split_once('=') else {
+            return Err(format!("Invalid scheme path: {s} (expected `KEY=PATH`)"));
+        };
+        let key = SchemeKey::from_str(key.trim())?;
+        let path = path.trim();
+        if path.is_empty() {
+            return Err(format!(
+                "Invalid scheme path: {s} (expected a non-empty path)"
+            ));
+        }
+        Ok(Self {
+            key,
+            path: PathBuf::from(path),
+        })
+    }
+}
+
+impl Display for SchemePath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.key, self.path.display())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+    use std::str::FromStr;
+
+    use uv_pypi_types::Scheme;
+
+    use super::SchemePath;
+
+    #[test]
+    fn apply() {
+        let root = Path::new("/staging");
+        let scheme = Scheme {
+            purelib: root.to_path_buf(),
+            platlib: root.to_path_buf(),
+            scripts: root.join("bin"),
+            data: root.to_path_buf(),
+            include: root.join("include"),
+        };
+        let scheme_paths = [
+            SchemePath::from_str("scripts=usr/bin").unwrap(),
+            SchemePath::from_str("headers = usr/include/python3.12").unwrap(),
+            SchemePath::from_str("data=/opt/share").unwrap(),
+        ];
+        let scheme = SchemePath::apply(&scheme_paths, root, scheme);
+        assert_eq!(scheme.purelib, PathBuf::from("/staging"));
+        assert_eq!(scheme.scripts, PathBuf::from("/staging/usr/bin"));
+        assert_eq!(
+            scheme.include,
+            PathBuf::from("/staging/usr/include/python3.12")
+        );
+        assert_eq!(scheme.data, PathBuf::from("/opt/share"));
+    }
+
+    #[test]
+    fn parse() {
+        assert!(SchemePath::from_str("scripts").is_err());
+        assert!(SchemePath::from_str("purelib=lib").is_err());
+        assert!(SchemePath::from_str("data=").is_err());
+    }
+}
@@ -2,42 +2,84 @@ use std::path::{Path, PathBuf};
 
 use uv_pypi_types::Scheme;
 
+use crate::{Interpreter, SchemePath};
+
 /// A `--target` directory into which packages can be installed, separate from a virtual environment
 /// or system Python interpreter.
 #[derive(Debug, Clone)]
-pub struct Target(PathBuf);
+pub struct Target {
+    root: PathBuf,
+    scheme: Scheme,
+    scheme_paths: Vec<SchemePath>,
+}
 
 impl Target {
+    /// Return the PEP 582-style `__pypackages__` directory for the given interpreter, within the
+    /// given project directory (i.e., `__pypackages__/<major>.<minor>/lib`).
+    ///
+    /// Unlike a `--target` directory, scripts are installed alongside the `lib` directory (i.e.,
+    /// into `__pypackages__/<major>.<minor>/bin`), rather than within it.
+    pub fn pypackages(project: &Path, interpreter: &Interpreter) -> Self {
+        let base = project.join("__pypackages__").join(format!(
+            "{}.{}",
+            interpreter.python_major(),
+            interpreter.python_minor()
+        ));
+        let root = base.join("lib");
+        Self {
+            scheme: Scheme {
+                purelib: root.clone(),
+                platlib: root.clone(),
+                scripts: base.join("bin"),
+                data: base.clone(),
+                include: base.join("include"),
+            },
+            root,
+            scheme_paths: Vec::new(),
+        }
+    }
+
+    /// Set the custom installation paths for wheel `.data` directories (e.g., `scripts`).
+    #[must_use]
+    pub fn with_scheme_paths(mut self, scheme_paths: Vec<SchemePath>) -> Self {
+        self.scheme_paths = scheme_paths;
+        self
+    }
+
     /// Return the [`Scheme`] for the `--target` directory.
     pub fn scheme(&self) -> Scheme {
-        Scheme {
-            purelib: self.0.clone(),
-            platlib: self.0.clone(),
-            scripts: self.0.join("bin"),
-            data: self.0.clone(),
-            include: self.0.join("include"),
-        }
+        SchemePath::apply(&self.scheme_paths, &self.root, self.scheme.clone())
     }
 
     /// Return an iterator over the `site-packages` directories inside the environment.
     pub fn site_packages(&self) -> impl Iterator<Item = &Path> {
-        std::iter::once(self.0.as_path())
+        std::iter::once(self.root.as_path())
     }
 
     /// Initialize the `--target` directory.
     pub fn init(&self) -> std::io::Result<()> {
-        fs_err::create_dir_all(&self.0)?;
+        fs_err::create_dir_all(&self.root)?;
         Ok(())
     }
 
     /// Return the path to the `--target` directory.
     pub fn root(&self) -> &Path {
-        &self.0
+        &self.root
     }
 }
 
 impl From<PathBuf> for Target {
-    fn from(path: PathBuf) -> Self {
-        Self(path)
+    fn from(root: PathBuf) -> Self {
+        Self {
+            scheme: Scheme {
+                purelib: root.clone(),
+                platlib: root.clone(),
+                scripts: root.join("bin"),
+                data: root.clone(),
+                include: root.join("include"),
+            },
+            root,
+            scheme_paths: Vec::new(),
+        }
     }
 }
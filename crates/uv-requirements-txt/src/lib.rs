@@ -53,8 +53,7 @@ use uv_fs::Simplified;
 use uv_pep508::{expand_env_vars, Pep508Error, RequirementOrigin, VerbatimUrl};
 use uv_pypi_types::{Requirement, VerbatimParsedUrl};
 
-use crate::requirement::EditableError;
-pub use crate::requirement::RequirementsTxtRequirement;
+pub use crate::requirement::{EditableError, RequirementsTxtRequirement};
 use crate::shquote::unquote;
 
 mod requirement;
@@ -0,0 +1,121 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use serde::Deserialize;
+use tracing::debug;
+use url::Url;
+
+use uv_distribution_types::VersionId;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pypi_types::{HashDigest, HashError};
+use uv_types::KnownHashes;
+
+use crate::Error;
+
+/// The name of the file containing the known hashes.
+pub const KNOWN_HASHES_FILE: &str = "uv.hashes.toml";
+
+#[derive(Debug, thiserror::Error)]
+pub enum KnownHashesError {
+    #[error("Failed to read: `{}`", _0.user_display())]
+    Io(PathBuf, #[source] std::io::Error),
+
+    #[error("Failed to parse: `{}`", _0.user_display())]
+    Toml(PathBuf, #[source] Box<toml::de::Error>),
+
+    #[error("Expected exactly one of `version` or `url` for `{}` in: `{}`", _0, _1.user_display())]
+    Source(PackageName, PathBuf),
+
+    #[error("Invalid hash for `{}` in: `{}`", _0, _1.user_display())]
+    Hash(String, PathBuf, #[source] HashError),
+}
+
+/// The contents of a `uv.hashes.toml` file, e.g.:
+///
+/// ```toml
+/// [[package]]
+/// name = "anyio"
+/// version = "4.3.0"
+/// hashes = ["sha256:f75253795a87df48568485fd18cdd2a3fa5c4f7c5be8e5e36637733fce06fed8"]
+///
+/// [[package]]
+/// name = "iniconfig"
+/// url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl"
+/// hashes = ["sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374"]
+/// ```
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct KnownHashesWire {
+    #[serde(default)]
+    package: Vec<KnownPackageWire>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct KnownPackageWire {
+    name: PackageName,
+    version: Option<Version>,
+    url: Option<Url>,
+    hashes: Vec<String>,
+}
+
+/// Find the nearest `uv.hashes.toml` file in the given directory or any of its ancestors (as with
+/// the discovery of `uv.toml` files), and read the known hashes from it.
+pub fn find_known_hashes(directory: &Path) -> Result<KnownHashes, Error> {
+    for ancestor in directory.ancestors() {
+        if ancestor.join(KNOWN_HASHES_FILE).is_file() {
+            return read_known_hashes(ancestor);
+        }
+    }
+    Ok(KnownHashes::default())
+}
+
+/// Read the known hashes from the `uv.hashes.toml` file in the given directory, if it exists.
+pub fn read_known_hashes(directory: &Path) -> Result<KnownHashes, Error> {
+    let path = directory.join(KNOWN_HASHES_FILE);
+
+    let content = match fs_err::read_to_string(&path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Ok(KnownHashes::default());
+        }
+        Err(err) => return Err(KnownHashesError::Io(path, err).into()),
+    };
+
+    let wire: KnownHashesWire = toml::from_str(&content)
+        .map_err(|err| KnownHashesError::Toml(path.clone(), Box::new(err)))?;
+
+    debug!("Reading known hashes from: `{}`", path.user_display());
+
+    let mut known = KnownHashes::default();
+    for package in wire.package {
+        // Registry distributions are identified by version, and URL, path, and Git distributions
+        // by URL, to match the identifiers used when verifying hashes.
+        let (id, display) = match (package.version, package.url) {
+            (Some(version), None) => {
+                let display = format!("{}=={version}", package.name);
+                (VersionId::from_registry(package.name, version), display)
+            }
+            (None, Some(url)) => {
+                let display = format!("{} @ {url}", package.name);
+                (VersionId::from_url(&url), display)
+            }
+            _ => return Err(KnownHashesError::Source(package.name, path).into()),
+        };
+
+        let digests = match package
+            .hashes
+            .iter()
+            .map(|digest| HashDigest::from_str(digest))
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(digests) => digests,
+            Err(err) => return Err(KnownHashesError::Hash(display, path, err).into()),
+        };
+        known.insert(id, digests);
+    }
+
+    Ok(known)
+}
@@ -1,14 +1,23 @@
 pub use crate::extras::*;
+pub use crate::known_hashes::*;
 pub use crate::lookahead::*;
 pub use crate::source_tree::*;
 pub use crate::sources::*;
 pub use crate::specification::*;
 pub use crate::unnamed::*;
 
-use uv_distribution_types::{Dist, DistErrorKind, GitSourceDist, SourceDist};
-use uv_pypi_types::{Requirement, RequirementSource};
+use std::path::PathBuf;
+
+use uv_distribution_types::{Dist, DistErrorKind, GitSourceDist, IndexUrl, SourceDist};
+use uv_fs::Simplified;
+use uv_normalize::{InvalidNameError, PackageName};
+use uv_pep508::{Pep508Error, UnnamedRequirement};
+use uv_pypi_types::{ParsedUrlError, Requirement, RequirementSource, VerbatimParsedUrl};
+use uv_requirements_txt::{EditableError, RequirementsTxtFileError};
+use uv_resolver::PreferenceError;
 
 mod extras;
+mod known_hashes;
 mod lookahead;
 mod source_tree;
 mod sources;
@@ -29,6 +38,82 @@ pub enum Error {
 
     #[error(transparent)]
     WheelFilename(#[from] uv_distribution_filename::WheelFilenameError),
+
+    #[error(transparent)]
+    KnownHashes(#[from] KnownHashesError),
+
+    #[error("Failed to parse: `{0}`")]
+    ParseRequirement(String, #[source] Box<Pep508Error<VerbatimParsedUrl>>),
+
+    #[error(transparent)]
+    Editable(#[from] EditableError),
+
+    #[error(transparent)]
+    RequirementsTxt(#[from] RequirementsTxtFileError),
+
+    #[error(transparent)]
+    ParsedUrl(#[from] ParsedUrlError),
+
+    #[error(transparent)]
+    Preference(#[from] PreferenceError),
+
+    #[error("File not found: `{}`", _0.user_display())]
+    FileNotFound(PathBuf),
+
+    #[error("Directory not found: `{}`", _0.user_display())]
+    DirectoryNotFound(PathBuf),
+
+    #[error("Failed to read `{}`", _0.user_display())]
+    ReadFile(PathBuf, #[source] std::io::Error),
+
+    #[error("Failed to parse: `{}`", _0.user_display())]
+    PyprojectToml(PathBuf, #[source] Box<toml::de::Error>),
+
+    #[error("Multiple index URLs specified: `{0}` vs. `{1}`")]
+    MultipleIndexUrls(Box<IndexUrl>, Box<IndexUrl>),
+
+    #[error("Requirements file `{}` declares an index (`{}`), but embedded indexes are forbidden (`--embedded-index-strategy forbid`)", _0.user_display(), _1)]
+    EmbeddedIndexForbidden(PathBuf, Box<IndexUrl>),
+
+    #[error("Requirements file `{}` declares multiple indexes, but only a single index can be scoped to a file's requirements (`--embedded-index-strategy scope`)", _0.user_display())]
+    MultipleEmbeddedIndexes(PathBuf),
+
+    #[error("Unnamed requirements are not allowed as constraints (found: `{0}`)")]
+    UnnamedConstraint(Box<UnnamedRequirement<VerbatimParsedUrl>>),
+
+    #[error("Failed to canonicalize path to source tree: {}", _0.user_display())]
+    Canonicalize(PathBuf, #[source] std::io::Error),
+
+    #[error("The file `{}` appears to be a `pyproject.toml`, `setup.py`, or `setup.cfg` file, which must be in a directory", _0.user_display())]
+    SourceTreeNotInDirectory(PathBuf),
+
+    #[error("Failed to convert path to URL: `{}`", _0.user_display())]
+    PathToUrl(PathBuf),
+
+    #[error("Hash-checking is not supported for local directories: {}", _0.user_display())]
+    HashesNotSupported(PathBuf),
+
+    #[error("Invalid package name in `#package=` fragment: `{0}`")]
+    InvalidPackageFragment(String, #[source] InvalidNameError),
+
+    #[error("The directory `{}` is not a package, but contains {} ({}); select {} with `#package=<name>`", _0.user_display(), if _1.len() == 1 { "a package" } else { "multiple packages" }, format_names(_1), if _1.len() == 1 { "it" } else { "one" })]
+    MemberPackages(PathBuf, Vec<PackageName>),
+
+    #[error("The directory `{}` does not contain a package named `{}` (found: {})", _0.user_display(), _1, format_names(_2))]
+    MissingMemberPackage(PathBuf, PackageName, Vec<PackageName>),
+
+    #[error("The directory `{}` contains multiple packages named `{}` (at: {}); refer to one by its path instead", _0.user_display(), _1, _2.iter().map(|path| format!("`{}`", path.user_display())).collect::<Vec<_>>().join(", "))]
+    AmbiguousMemberPackage(PathBuf, PackageName, Vec<PathBuf>),
+}
+
+/// Format a list of package names for display, e.g., `` `bar`, `foo` ``, omitting duplicates.
+fn format_names(names: &[PackageName]) -> String {
+    let mut names = names
+        .iter()
+        .map(|name| format!("`{name}`"))
+        .collect::<Vec<_>>();
+    names.dedup();
+    names.join(", ")
 }
 
 impl Error {
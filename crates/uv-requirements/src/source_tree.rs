@@ -2,7 +2,6 @@ use std::borrow::Cow;
 use std::path::Path;
 use std::sync::Arc;
 
-use anyhow::{Context, Result};
 use futures::stream::FuturesOrdered;
 use futures::TryStreamExt;
 use url::Url;
@@ -19,6 +18,9 @@ use uv_pypi_types::Requirement;
 use uv_resolver::{InMemoryIndex, MetadataResponse};
 use uv_types::{BuildContext, HashStrategy};
 use uv_warnings::warn_user_once;
+
+use crate::Error;
+
 #[derive(Debug, Clone)]
 pub struct SourceTreeResolution {
     /// The requirements sourced from the source trees.
@@ -77,7 +79,7 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
     pub async fn resolve(
         self,
         source_trees: impl Iterator<Item = &Path>,
-    ) -> Result<Vec<SourceTreeResolution>> {
+    ) -> Result<Vec<SourceTreeResolution>, Error> {
         let resolutions: Vec<_> = source_trees
             .map(|source_tree| async { self.resolve_source_tree(source_tree).await })
             .collect::<FuturesOrdered<_>>()
@@ -87,7 +89,7 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
     }
 
     /// Infer the dependencies for a directory dependency.
-    async fn resolve_source_tree(&self, path: &Path) -> Result<SourceTreeResolution> {
+    async fn resolve_source_tree(&self, path: &Path) -> Result<SourceTreeResolution, Error> {
         let metadata = self.resolve_requires_dist(path).await?;
         let origin = RequirementOrigin::Project(path.to_path_buf(), metadata.name.clone());
 
@@ -117,7 +119,10 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
         // Apply dependency-groups
         for (group_name, group) in &metadata.dependency_groups {
             if self.groups.contains(group_name) {
-                requirements.extend(group.iter().cloned());
+                requirements.extend(group.iter().cloned().map(|requirement| Requirement {
+                    origin: Some(origin.clone()),
+                    ..requirement
+                }));
             }
         }
         // Complain if dependency groups are named that don't appear.
@@ -147,20 +152,13 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
     /// requirements without building the distribution, even if the project contains (e.g.) a
     /// dynamic version since, critically, we don't need to install the package itself; only its
     /// dependencies.
-    async fn resolve_requires_dist(&self, path: &Path) -> Result<RequiresDist> {
+    async fn resolve_requires_dist(&self, path: &Path) -> Result<RequiresDist, Error> {
         // Convert to a buildable source.
-        let source_tree = fs_err::canonicalize(path).with_context(|| {
-            format!(
-                "Failed to canonicalize path to source tree: {}",
-                path.user_display()
-            )
-        })?;
-        let source_tree = source_tree.parent().ok_or_else(|| {
-            anyhow::anyhow!(
-                "The file `{}` appears to be a `pyproject.toml`, `setup.py`, or `setup.cfg` file, which must be in a directory",
-                path.user_display()
-            )
-        })?;
+        let source_tree = fs_err::canonicalize(path)
+            .map_err(|err| Error::Canonicalize(path.to_path_buf(), err))?;
+        let source_tree = source_tree
+            .parent()
+            .ok_or_else(|| Error::SourceTreeNotInDirectory(path.to_path_buf()))?;
 
         // If the path is a `pyproject.toml`, attempt to extract the requirements statically. The
         // distribution database will do this too, but we can be even more aggressive here since we
@@ -172,7 +170,7 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
         }
 
         let Ok(url) = Url::from_directory_path(source_tree) else {
-            return Err(anyhow::anyhow!("Failed to convert path to URL"));
+            return Err(Error::PathToUrl(source_tree.to_path_buf()));
         };
         let source = SourceUrl::Directory(DirectorySourceUrl {
             url: &url,
@@ -187,10 +185,7 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
             HashStrategy::Generate(mode) => HashPolicy::Generate(*mode),
             HashStrategy::Verify(_) => HashPolicy::Generate(HashGeneration::All),
             HashStrategy::Require(_) => {
-                return Err(anyhow::anyhow!(
-                    "Hash-checking is not supported for local directories: {}",
-                    path.user_display()
-                ));
+                return Err(Error::HashesNotSupported(path.to_path_buf()));
             }
         };
 
@@ -1,6 +1,5 @@
 use std::path::{Path, PathBuf};
 
-use anyhow::Result;
 use console::Term;
 
 use uv_fs::Simplified;
@@ -86,7 +85,7 @@ impl RequirementsSource {
     ///
     /// If the user provided a value that appears to be a `requirements.txt` file or a local
     /// directory, prompt them to correct it (if the terminal is interactive).
-    pub fn from_package(name: String) -> Result<Self> {
+    pub fn from_package(name: String) -> Result<Self, std::io::Error> {
         // If the user provided a `requirements.txt` file without `-r` (as in
         // `uv pip install requirements.txt`), prompt them to correct it.
         #[allow(clippy::case_sensitive_file_extension_comparisons)]
@@ -128,7 +127,7 @@ impl RequirementsSource {
     ///
     /// If the user provided a value that appears to be a `requirements.txt` file or a local
     /// directory, prompt them to correct it (if the terminal is interactive).
-    pub fn from_with_package(name: String) -> Result<Self> {
+    pub fn from_with_package(name: String) -> Result<Self, std::io::Error> {
         // If the user provided a `requirements.txt` file without `--with-requirements` (as in
         // `uvx --with requirements.txt ruff`), prompt them to correct it.
         #[allow(clippy::case_sensitive_file_extension_comparisons)]
@@ -29,12 +29,11 @@
 
 use std::path::{Path, PathBuf};
 
-use anyhow::{Context, Result};
 use rustc_hash::FxHashSet;
 use tracing::instrument;
 use uv_cache_key::CanonicalUrl;
 use uv_client::BaseClientBuilder;
-use uv_configuration::{NoBinary, NoBuild};
+use uv_configuration::{EmbeddedIndexStrategy, NoBinary, NoBuild};
 use uv_distribution_types::{
     IndexUrl, NameRequirementSpecification, UnresolvedRequirement,
     UnresolvedRequirementSpecification,
@@ -42,13 +41,13 @@ use uv_distribution_types::{
 use uv_fs::{Simplified, CWD};
 use uv_normalize::{ExtraName, PackageName};
 use uv_pep508::{MarkerTree, UnnamedRequirement, UnnamedRequirementUrl};
-use uv_pypi_types::Requirement;
 use uv_pypi_types::VerbatimParsedUrl;
+use uv_pypi_types::{Requirement, RequirementSource};
 use uv_requirements_txt::{RequirementsTxt, RequirementsTxtRequirement};
 use uv_warnings::warn_user;
 use uv_workspace::pyproject::PyProjectToml;
 
-use crate::RequirementsSource;
+use crate::{Error, RequirementsSource};
 
 #[derive(Debug, Default, Clone)]
 pub struct RequirementsSpecification {
@@ -84,11 +83,11 @@ impl RequirementsSpecification {
     pub async fn from_source(
         source: &RequirementsSource,
         client_builder: &BaseClientBuilder<'_>,
-    ) -> Result<Self> {
+    ) -> Result<Self, Error> {
         Ok(match source {
             RequirementsSource::Package(name) => {
                 let requirement = RequirementsTxtRequirement::parse(name, &*CWD, false)
-                    .with_context(|| format!("Failed to parse: `{name}`"))?;
+                    .map_err(|err| Error::ParseRequirement(name.clone(), err))?;
                 Self {
                     requirements: vec![UnresolvedRequirementSpecification::from(requirement)],
                     ..Self::default()
@@ -96,7 +95,7 @@ impl RequirementsSpecification {
             }
             RequirementsSource::Editable(name) => {
                 let requirement = RequirementsTxtRequirement::parse(name, &*CWD, true)
-                    .with_context(|| format!("Failed to parse: `{name}`"))?;
+                    .map_err(|err| Error::ParseRequirement(name.clone(), err))?;
                 Self {
                     requirements: vec![UnresolvedRequirementSpecification::from(
                         requirement.into_editable()?,
@@ -110,7 +109,7 @@ impl RequirementsSpecification {
                     || path.starts_with("https://")
                     || path.exists())
                 {
-                    return Err(anyhow::anyhow!("File not found: `{}`", path.user_display()));
+                    return Err(Error::FileNotFound(path.clone()));
                 }
 
                 let requirements_txt = RequirementsTxt::parse(path, &*CWD, client_builder).await?;
@@ -165,18 +164,14 @@ impl RequirementsSpecification {
                 let contents = match fs_err::tokio::read_to_string(&path).await {
                     Ok(contents) => contents,
                     Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
-                        return Err(anyhow::anyhow!("File not found: `{}`", path.user_display()));
+                        return Err(Error::FileNotFound(path.clone()));
                     }
                     Err(err) => {
-                        return Err(anyhow::anyhow!(
-                            "Failed to read `{}`: {}",
-                            path.user_display(),
-                            err
-                        ));
+                        return Err(Error::ReadFile(path.clone(), err));
                     }
                 };
                 let _ = toml::from_str::<PyProjectToml>(&contents)
-                    .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
+                    .map_err(|err| Error::PyprojectToml(path.clone(), Box::new(err)))?;
 
                 Self {
                     source_trees: vec![path.clone()],
@@ -185,7 +180,7 @@ impl RequirementsSpecification {
             }
             RequirementsSource::SetupPy(path) | RequirementsSource::SetupCfg(path) => {
                 if !path.is_file() {
-                    return Err(anyhow::anyhow!("File not found: `{}`", path.user_display()));
+                    return Err(Error::FileNotFound(path.clone()));
                 }
 
                 Self {
@@ -195,10 +190,7 @@ impl RequirementsSpecification {
             }
             RequirementsSource::SourceTree(path) => {
                 if !path.is_dir() {
-                    return Err(anyhow::anyhow!(
-                        "Directory not found: `{}`",
-                        path.user_display()
-                    ));
+                    return Err(Error::DirectoryNotFound(path.clone()));
                 }
 
                 Self {
@@ -219,19 +211,25 @@ impl RequirementsSpecification {
     }
 
     /// Read the combined requirements and constraints from a set of sources.
+    ///
+    /// Any indexes declared within a `requirements.txt` file are handled according to the
+    /// [`EmbeddedIndexStrategy`].
     pub async fn from_sources(
         requirements: &[RequirementsSource],
         constraints: &[RequirementsSource],
         overrides: &[RequirementsSource],
+        embedded_index_strategy: EmbeddedIndexStrategy,
         client_builder: &BaseClientBuilder<'_>,
-    ) -> Result<Self> {
+    ) -> Result<Self, Error> {
         let mut spec = Self::default();
 
         // Read all requirements, and keep track of all requirements _and_ constraints.
         // A `requirements.txt` can contain a `-c constraints.txt` directive within it, so reading
         // a requirements file can also add constraints.
         for source in requirements {
-            let source = Self::from_source(source, client_builder).await?;
+            let source = Self::from_source(source, client_builder)
+                .await?
+                .apply_embedded_indexes(source, embedded_index_strategy)?;
             spec.requirements.extend(source.requirements);
             spec.constraints.extend(source.constraints);
             spec.overrides.extend(source.overrides);
@@ -246,8 +244,9 @@ impl RequirementsSpecification {
             if let Some(index_url) = source.index_url {
                 if let Some(existing) = spec.index_url {
                     if CanonicalUrl::new(index_url.url()) != CanonicalUrl::new(existing.url()) {
-                        return Err(anyhow::anyhow!(
-                            "Multiple index URLs specified: `{existing}` vs. `{index_url}`",
+                        return Err(Error::MultipleIndexUrls(
+                            Box::new(existing),
+                            Box::new(index_url),
                         ));
                     }
                 }
@@ -263,7 +262,9 @@ impl RequirementsSpecification {
         // Read all constraints, treating both requirements _and_ constraints as constraints.
         // Overrides are ignored.
         for source in constraints {
-            let source = Self::from_source(source, client_builder).await?;
+            let source = Self::from_source(source, client_builder)
+                .await?
+                .apply_embedded_indexes(source, embedded_index_strategy)?;
             for entry in source.requirements {
                 match entry.requirement {
                     UnresolvedRequirement::Named(requirement) => {
@@ -273,9 +274,7 @@ impl RequirementsSpecification {
                         });
                     }
                     UnresolvedRequirement::Unnamed(requirement) => {
-                        return Err(anyhow::anyhow!(
-                            "Unnamed requirements are not allowed as constraints (found: `{requirement}`)"
-                        ));
+                        return Err(Error::UnnamedConstraint(Box::new(requirement)));
                     }
                 }
             }
@@ -284,8 +283,9 @@ impl RequirementsSpecification {
             if let Some(index_url) = source.index_url {
                 if let Some(existing) = spec.index_url {
                     if CanonicalUrl::new(index_url.url()) != CanonicalUrl::new(existing.url()) {
-                        return Err(anyhow::anyhow!(
-                            "Multiple index URLs specified: `{existing}` vs. `{index_url}`",
+                        return Err(Error::MultipleIndexUrls(
+                            Box::new(existing),
+                            Box::new(index_url),
                         ));
                     }
                 }
@@ -301,15 +301,18 @@ impl RequirementsSpecification {
         // Read all overrides, treating both requirements _and_ overrides as overrides.
         // Constraints are ignored.
         for source in overrides {
-            let source = Self::from_source(source, client_builder).await?;
+            let source = Self::from_source(source, client_builder)
+                .await?
+                .apply_embedded_indexes(source, embedded_index_strategy)?;
             spec.overrides.extend(source.requirements);
             spec.overrides.extend(source.overrides);
 
             if let Some(index_url) = source.index_url {
                 if let Some(existing) = spec.index_url {
                     if CanonicalUrl::new(index_url.url()) != CanonicalUrl::new(existing.url()) {
-                        return Err(anyhow::anyhow!(
-                            "Multiple index URLs specified: `{existing}` vs. `{index_url}`",
+                        return Err(Error::MultipleIndexUrls(
+                            Box::new(existing),
+                            Box::new(index_url),
                         ));
                     }
                 }
@@ -325,10 +328,75 @@ impl RequirementsSpecification {
         Ok(spec)
     }
 
+    /// Apply the [`EmbeddedIndexStrategy`] to the indexes declared by the given source.
+    ///
+    /// Under [`EmbeddedIndexStrategy::Scope`], the source's registry requirements, constraints, and
+    /// overrides are pinned to the index declared in the file, and the index is omitted from the
+    /// set of indexes used for all other packages.
+    fn apply_embedded_indexes(
+        mut self,
+        source: &RequirementsSource,
+        strategy: EmbeddedIndexStrategy,
+    ) -> Result<Self, Error> {
+        let RequirementsSource::RequirementsTxt(path) = source else {
+            return Ok(self);
+        };
+
+        match strategy {
+            EmbeddedIndexStrategy::Merge => Ok(self),
+            EmbeddedIndexStrategy::Forbid => {
+                if let Some(index) = self
+                    .index_url
+                    .take()
+                    .or_else(|| self.extra_index_urls.pop())
+                {
+                    return Err(Error::EmbeddedIndexForbidden(path.clone(), Box::new(index)));
+                }
+                Ok(self)
+            }
+            EmbeddedIndexStrategy::Scope => {
+                let mut indexes = self
+                    .index_url
+                    .take()
+                    .into_iter()
+                    .chain(std::mem::take(&mut self.extra_index_urls));
+                let Some(index) = indexes.next() else {
+                    return Ok(self);
+                };
+                if indexes.next().is_some() {
+                    return Err(Error::MultipleEmbeddedIndexes(path.clone()));
+                }
+
+                let index = index.into_url();
+                let requirements = self
+                    .requirements
+                    .iter_mut()
+                    .chain(self.overrides.iter_mut())
+                    .filter_map(|entry| match &mut entry.requirement {
+                        UnresolvedRequirement::Named(requirement) => Some(requirement),
+                        UnresolvedRequirement::Unnamed(_) => None,
+                    })
+                    .chain(
+                        self.constraints
+                            .iter_mut()
+                            .map(|entry| &mut entry.requirement),
+                    );
+                for requirement in requirements {
+                    if let RequirementSource::Registry { index: pinned, .. } =
+                        &mut requirement.source
+                    {
+                        pinned.get_or_insert_with(|| index.clone());
+                    }
+                }
+                Ok(self)
+            }
+        }
+    }
+
     /// Parse an individual package requirement.
-    pub fn parse_package(name: &str) -> Result<UnresolvedRequirementSpecification> {
+    pub fn parse_package(name: &str) -> Result<UnresolvedRequirementSpecification, Error> {
         let requirement = RequirementsTxtRequirement::parse(name, &*CWD, false)
-            .with_context(|| format!("Failed to parse: `{name}`"))?;
+            .map_err(|err| Error::ParseRequirement(name.to_string(), err))?;
         Ok(UnresolvedRequirementSpecification::from(requirement))
     }
 
@@ -336,8 +404,15 @@ impl RequirementsSpecification {
     pub async fn from_simple_sources(
         requirements: &[RequirementsSource],
         client_builder: &BaseClientBuilder<'_>,
-    ) -> Result<Self> {
-        Self::from_sources(requirements, &[], &[], client_builder).await
+    ) -> Result<Self, Error> {
+        Self::from_sources(
+            requirements,
+            &[],
+            &[],
+            EmbeddedIndexStrategy::default(),
+            client_builder,
+        )
+        .await
     }
 
     /// Initialize a [`RequirementsSpecification`] from a list of [`Requirement`].
@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -16,12 +16,14 @@ use uv_distribution_types::{
     BuildableSource, DirectSourceUrl, DirectorySourceUrl, GitSourceUrl, PathSourceUrl,
     RemoteSource, SourceUrl, VersionId,
 };
+use uv_fs::Simplified;
 use uv_normalize::PackageName;
-use uv_pep508::{UnnamedRequirement, VersionOrUrl};
+use uv_pep508::{UnnamedRequirement, VerbatimUrl, VersionOrUrl};
 use uv_pypi_types::{Metadata10, Requirement};
-use uv_pypi_types::{ParsedUrl, VerbatimParsedUrl};
+use uv_pypi_types::{ParsedDirectoryUrl, ParsedUrl, VerbatimParsedUrl};
 use uv_resolver::{InMemoryIndex, MetadataResponse};
 use uv_types::{BuildContext, HashStrategy};
+use uv_workspace::{DiscoveryOptions, VirtualProject};
 
 /// Like [`RequirementsSpecification`], but with concrete names for all requirements.
 pub struct NamedRequirementsResolver<'a, Context: BuildContext> {
@@ -61,29 +63,54 @@ impl<'a, Context: BuildContext> NamedRequirementsResolver<'a, Context> {
         self,
         requirements: impl Iterator<Item = UnnamedRequirement<VerbatimParsedUrl>>,
     ) -> Result<Vec<Requirement>, Error> {
+        let (requirements, _) = self.resolve_with_fallbacks(requirements).await?;
+        Ok(requirements)
+    }
+
+    /// Resolve any unnamed requirements in the specification, along with a [`BuildFallback`] for
+    /// each requirement whose name could not be inferred from static metadata.
+    pub async fn resolve_with_fallbacks(
+        self,
+        requirements: impl Iterator<Item = UnnamedRequirement<VerbatimParsedUrl>>,
+    ) -> Result<(Vec<Requirement>, Vec<BuildFallback>), Error> {
         let Self {
             hasher,
             index,
             database,
         } = self;
-        requirements
+        let resolved: Vec<_> = requirements
             .map(|requirement| async {
-                Self::resolve_requirement(requirement, hasher, index, &database)
-                    .await
-                    .map(Requirement::from)
+                Self::resolve_requirement(requirement, hasher, index, &database).await
             })
             .collect::<FuturesOrdered<_>>()
             .try_collect()
-            .await
+            .await?;
+
+        let mut requirements = Vec::with_capacity(resolved.len());
+        let mut fallbacks = Vec::new();
+        for (requirement, fallback) in resolved {
+            requirements.push(Requirement::from(requirement));
+            fallbacks.extend(fallback);
+        }
+        Ok((requirements, fallbacks))
     }
 
     /// Infer the package name for a given "unnamed" requirement.
+    ///
+    /// If the name could not be inferred from static metadata, also returns a [`BuildFallback`]
+    /// describing why.
     async fn resolve_requirement(
         requirement: UnnamedRequirement<VerbatimParsedUrl>,
         hasher: &HashStrategy,
         index: &InMemoryIndex,
         database: &DistributionDatabase<'a, Context>,
-    ) -> Result<uv_pep508::Requirement<VerbatimParsedUrl>, Error> {
+    ) -> Result<
+        (
+            uv_pep508::Requirement<VerbatimParsedUrl>,
+            Option<BuildFallback>,
+        ),
+        Error,
+    > {
         // If the requirement is a wheel, extract the package name from the wheel filename.
         //
         // Ex) `anyio-4.3.0-py3-none-any.whl`
@@ -92,19 +119,23 @@ impl<'a, Context: BuildContext> NamedRequirementsResolver<'a, Context> {
             .is_some_and(|ext| ext.eq_ignore_ascii_case("whl"))
         {
             let filename = WheelFilename::from_str(&requirement.url.verbatim.filename()?)?;
-            return Ok(uv_pep508::Requirement {
-                name: filename.name,
-                extras: requirement.extras,
-                version_or_url: Some(VersionOrUrl::Url(requirement.url)),
-                marker: requirement.marker,
-                origin: requirement.origin,
-            });
+            return Ok((
+                uv_pep508::Requirement {
+                    name: filename.name,
+                    extras: requirement.extras,
+                    version_or_url: Some(VersionOrUrl::Url(requirement.url)),
+                    marker: requirement.marker,
+                    origin: requirement.origin,
+                },
+                None,
+            ));
         }
 
         // If the requirement is a source archive, try to extract the package name from the archive
         // filename. This isn't guaranteed to work.
         //
         // Ex) `anyio-4.3.0.tar.gz`
+        let mut github_archive = false;
         if let Some(filename) = requirement
             .url
             .verbatim
@@ -129,20 +160,67 @@ impl<'a, Context: BuildContext> NamedRequirementsResolver<'a, Context> {
                     "Rejecting inferred name from GitHub archive: {}",
                     requirement.url.verbatim
                 );
+                github_archive = true;
             } else {
-                return Ok(uv_pep508::Requirement {
-                    name: filename.name,
-                    extras: requirement.extras,
-                    version_or_url: Some(VersionOrUrl::Url(requirement.url)),
-                    marker: requirement.marker,
-                    origin: requirement.origin,
-                });
+                return Ok((
+                    uv_pep508::Requirement {
+                        name: filename.name,
+                        extras: requirement.extras,
+                        version_or_url: Some(VersionOrUrl::Url(requirement.url)),
+                        marker: requirement.marker,
+                        origin: requirement.origin,
+                    },
+                    None,
+                ));
             }
         }
 
-        let source = match &requirement.url.parsed_url {
+        let (source, reason) = match &requirement.url.parsed_url {
             // If the path points to a directory, attempt to read the name from static metadata.
             ParsedUrl::Directory(parsed_directory_url) => {
+                // If a specific package was requested from a directory that contains other
+                // packages, select the corresponding member.
+                //
+                // Ex) `./path/to/workspace#package=foo`
+                if let Some(package) = get_package(&requirement.url.verbatim)? {
+                    let members = find_members(&parsed_directory_url.install_path).await;
+                    let mut matches = members
+                        .iter()
+                        .filter(|(name, _)| *name == package)
+                        .map(|(_, path)| path.clone())
+                        .collect::<Vec<_>>();
+                    if matches.len() > 1 {
+                        return Err(Error::AmbiguousMemberPackage(
+                            parsed_directory_url.install_path.clone(),
+                            package,
+                            matches,
+                        ));
+                    }
+                    let Some(path) = matches.pop() else {
+                        return Err(Error::MissingMemberPackage(
+                            parsed_directory_url.install_path.clone(),
+                            package,
+                            members.into_iter().map(|(name, _)| name).collect(),
+                        ));
+                    };
+                    debug!(
+                        "Selected package `{package}` from {root} at: {path}",
+                        root = parsed_directory_url.install_path.display(),
+                        path = path.display()
+                    );
+                    let url = member_url(&requirement.url, parsed_directory_url, &path)?;
+                    return Ok((
+                        uv_pep508::Requirement {
+                            name: package,
+                            extras: requirement.extras,
+                            version_or_url: Some(VersionOrUrl::Url(url)),
+                            marker: requirement.marker,
+                            origin: requirement.origin,
+                        },
+                        None,
+                    ));
+                }
+
                 // Attempt to read a `PKG-INFO` from the directory.
                 if let Some(metadata) =
                     fs_err::read(parsed_directory_url.install_path.join("PKG-INFO"))
@@ -154,21 +232,35 @@ impl<'a, Context: BuildContext> NamedRequirementsResolver<'a, Context> {
                         path = parsed_directory_url.install_path.display(),
                         name = metadata.name
                     );
-                    return Ok(uv_pep508::Requirement {
-                        name: metadata.name,
-                        extras: requirement.extras,
-                        version_or_url: Some(VersionOrUrl::Url(requirement.url)),
-                        marker: requirement.marker,
-                        origin: requirement.origin,
-                    });
+                    return Ok((
+                        uv_pep508::Requirement {
+                            name: metadata.name,
+                            extras: requirement.extras,
+                            version_or_url: Some(VersionOrUrl::Url(requirement.url)),
+                            marker: requirement.marker,
+                            origin: requirement.origin,
+                        },
+                        None,
+                    ));
                 }
 
                 // Attempt to read a `pyproject.toml` file.
                 let project_path = parsed_directory_url.install_path.join("pyproject.toml");
-                if let Some(pyproject) = fs_err::read_to_string(project_path)
+                let pyproject = fs_err::read_to_string(project_path)
                     .ok()
-                    .and_then(|contents| toml::from_str::<PyProjectToml>(&contents).ok())
-                {
+                    .map(|contents| toml::from_str::<PyProjectToml>(&contents));
+                let reason = match &pyproject {
+                    Some(Ok(_)) => BuildFallbackReason::MissingProjectName,
+                    Some(Err(err)) => {
+                        BuildFallbackReason::InvalidPyProjectToml(err.message().to_string())
+                    }
+                    None => BuildFallbackReason::MissingMetadata,
+                };
+                let build_system = matches!(
+                    &pyproject,
+                    Some(Ok(pyproject)) if pyproject.build_system.is_some()
+                );
+                if let Some(Ok(pyproject)) = pyproject {
                     // Read PEP 621 metadata from the `pyproject.toml`.
                     if let Some(project) = pyproject.project {
                         debug!(
@@ -176,13 +268,16 @@ impl<'a, Context: BuildContext> NamedRequirementsResolver<'a, Context> {
                             path = parsed_directory_url.install_path.display(),
                             name = project.name
                         );
-                        return Ok(uv_pep508::Requirement {
-                            name: project.name,
-                            extras: requirement.extras,
-                            version_or_url: Some(VersionOrUrl::Url(requirement.url)),
-                            marker: requirement.marker,
-                            origin: requirement.origin,
-                        });
+                        return Ok((
+                            uv_pep508::Requirement {
+                                name: project.name,
+                                extras: requirement.extras,
+                                version_or_url: Some(VersionOrUrl::Url(requirement.url)),
+                                marker: requirement.marker,
+                                origin: requirement.origin,
+                            },
+                            None,
+                        ));
                     }
 
                     // Read Poetry-specific metadata from the `pyproject.toml`.
@@ -194,13 +289,16 @@ impl<'a, Context: BuildContext> NamedRequirementsResolver<'a, Context> {
                                     path = parsed_directory_url.install_path.display(),
                                     name = name
                                 );
-                                return Ok(uv_pep508::Requirement {
-                                    name,
-                                    extras: requirement.extras,
-                                    version_or_url: Some(VersionOrUrl::Url(requirement.url)),
-                                    marker: requirement.marker,
-                                    origin: requirement.origin,
-                                });
+                                return Ok((
+                                    uv_pep508::Requirement {
+                                        name,
+                                        extras: requirement.extras,
+                                        version_or_url: Some(VersionOrUrl::Url(requirement.url)),
+                                        marker: requirement.marker,
+                                        origin: requirement.origin,
+                                    },
+                                    None,
+                                ));
                             }
                         }
                     }
@@ -224,55 +322,92 @@ impl<'a, Context: BuildContext> NamedRequirementsResolver<'a, Context> {
                                     path = parsed_directory_url.install_path.display(),
                                     name = name
                                 );
-                                return Ok(uv_pep508::Requirement {
-                                    name,
-                                    extras: requirement.extras,
-                                    version_or_url: Some(VersionOrUrl::Url(requirement.url)),
-                                    marker: requirement.marker,
-                                    origin: requirement.origin,
-                                });
+                                return Ok((
+                                    uv_pep508::Requirement {
+                                        name,
+                                        extras: requirement.extras,
+                                        version_or_url: Some(VersionOrUrl::Url(requirement.url)),
+                                        marker: requirement.marker,
+                                        origin: requirement.origin,
+                                    },
+                                    None,
+                                ));
                             }
                         }
                     }
                 }
 
-                SourceUrl::Directory(DirectorySourceUrl {
-                    url: &requirement.url.verbatim,
-                    install_path: Cow::Borrowed(&parsed_directory_url.install_path),
-                    editable: parsed_directory_url.editable,
-                })
+                // If the directory isn't itself buildable, but contains other packages (e.g., a
+                // virtual workspace root), a build would fail with a misleading error. Instead,
+                // ask the user to select one of the packages.
+                if !build_system && !parsed_directory_url.install_path.join("setup.py").is_file() {
+                    let members = find_members(&parsed_directory_url.install_path).await;
+                    if !members.is_empty() {
+                        return Err(Error::MemberPackages(
+                            parsed_directory_url.install_path.clone(),
+                            members.into_iter().map(|(name, _)| name).collect(),
+                        ));
+                    }
+                }
+
+                (
+                    SourceUrl::Directory(DirectorySourceUrl {
+                        url: &requirement.url.verbatim,
+                        install_path: Cow::Borrowed(&parsed_directory_url.install_path),
+                        editable: parsed_directory_url.editable,
+                    }),
+                    reason,
+                )
             }
             ParsedUrl::Path(parsed_path_url) => {
                 let ext = match parsed_path_url.ext {
                     DistExtension::Source(ext) => ext,
                     DistExtension::Wheel => unreachable!(),
                 };
-                SourceUrl::Path(PathSourceUrl {
-                    url: &requirement.url.verbatim,
-                    path: Cow::Borrowed(&parsed_path_url.install_path),
-                    ext,
-                })
+                (
+                    SourceUrl::Path(PathSourceUrl {
+                        url: &requirement.url.verbatim,
+                        path: Cow::Borrowed(&parsed_path_url.install_path),
+                        ext,
+                    }),
+                    BuildFallbackReason::InvalidFilename,
+                )
             }
             ParsedUrl::Archive(parsed_archive_url) => {
                 let ext = match parsed_archive_url.ext {
                     DistExtension::Source(ext) => ext,
                     DistExtension::Wheel => unreachable!(),
                 };
-                SourceUrl::Direct(DirectSourceUrl {
-                    url: &parsed_archive_url.url,
-                    subdirectory: parsed_archive_url.subdirectory.as_deref(),
-                    ext,
-                })
+                (
+                    SourceUrl::Direct(DirectSourceUrl {
+                        url: &parsed_archive_url.url,
+                        subdirectory: parsed_archive_url.subdirectory.as_deref(),
+                        ext,
+                    }),
+                    if github_archive {
+                        BuildFallbackReason::GitHubArchive
+                    } else {
+                        BuildFallbackReason::InvalidFilename
+                    },
+                )
             }
-            ParsedUrl::Git(parsed_git_url) => SourceUrl::Git(GitSourceUrl {
-                url: &requirement.url.verbatim,
-                git: &parsed_git_url.url,
-                subdirectory: parsed_git_url.subdirectory.as_deref(),
-            }),
+            ParsedUrl::Git(parsed_git_url) => (
+                SourceUrl::Git(GitSourceUrl {
+                    url: &requirement.url.verbatim,
+                    git: &parsed_git_url.url,
+                    subdirectory: parsed_git_url.subdirectory.as_deref(),
+                }),
+                BuildFallbackReason::Git,
+            ),
         };
 
+        debug!(
+            "Unable to infer package name for {} from static metadata: {reason}",
+            requirement.url.verbatim
+        );
+
         // Fetch the metadata for the distribution.
-        let name = {
+        let (name, cached) = {
             let id = VersionId::from_url(source.url());
             if let Some(archive) = index
                 .distributions()
@@ -287,32 +422,198 @@ impl<'a, Context: BuildContext> NamedRequirementsResolver<'a, Context> {
                 })
             {
                 // If the metadata is already in the index, return it.
-                archive.metadata.name.clone()
+                (archive.metadata.name.clone(), true)
             } else {
-                // Run the PEP 517 build process to extract metadata from the source distribution.
+                // Run the PEP 517 build process to extract metadata from the source distribution,
+                // unless the metadata is already in the cache.
                 let hashes = hasher.get_url(source.url());
                 let source = BuildableSource::Url(source);
                 let archive = database.build_wheel_metadata(&source, hashes).await?;
 
                 let name = archive.metadata.name.clone();
+                let cached = archive.cached;
 
                 // Insert the metadata into the index.
                 index
                     .distributions()
                     .done(id, Arc::new(MetadataResponse::Found(archive)));
 
-                name
+                (name, cached)
             }
         };
 
-        Ok(uv_pep508::Requirement {
-            name,
-            extras: requirement.extras,
-            version_or_url: Some(VersionOrUrl::Url(requirement.url)),
-            marker: requirement.marker,
-            origin: requirement.origin,
-        })
+        let fallback = BuildFallback {
+            url: requirement.url.verbatim.clone(),
+            name: name.clone(),
+            reason,
+            cached,
+        };
+
+        Ok((
+            uv_pep508::Requirement {
+                name,
+                extras: requirement.extras,
+                version_or_url: Some(VersionOrUrl::Url(requirement.url)),
+                marker: requirement.marker,
+                origin: requirement.origin,
+            },
+            Some(fallback),
+        ))
+    }
+}
+
+/// An unnamed requirement whose package name could not be inferred from static metadata, such
+/// that it had to be built (or its previously-built metadata read from the cache) to determine its
+/// name.
+#[derive(Debug, Clone)]
+pub struct BuildFallback {
+    /// The URL of the requirement.
+    pub url: VerbatimUrl,
+    /// The package name, as determined by the build.
+    pub name: PackageName,
+    /// The reason the package name could not be inferred from static metadata.
+    pub reason: BuildFallbackReason,
+    /// Whether the package name was read from previously-built metadata, rather than built.
+    pub cached: bool,
+}
+
+/// The reason a package name could not be inferred from static metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuildFallbackReason {
+    /// The source archive filename does not follow the `{name}-{version}.{ext}` convention.
+    InvalidFilename,
+    /// The source archive is a GitHub archive, the filename of which is auto-generated.
+    GitHubArchive,
+    /// Git repositories don't expose static metadata prior to being fetched.
+    Git,
+    /// The directory does not contain a `PKG-INFO`, `pyproject.toml`, or `setup.cfg` file.
+    MissingMetadata,
+    /// The `pyproject.toml` file does not define a `[project]` table, nor does any `setup.cfg`
+    /// define a `metadata.name`.
+    MissingProjectName,
+    /// The `pyproject.toml` file could not be parsed.
+    InvalidPyProjectToml(String),
+}
+
+impl std::fmt::Display for BuildFallbackReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidFilename => write!(
+                f,
+                "the archive filename does not follow the `{{name}}-{{version}}` convention"
+            ),
+            Self::GitHubArchive => write!(
+                f,
+                "GitHub archive filenames do not include the package name"
+            ),
+            Self::Git => write!(f, "Git repositories do not expose static metadata"),
+            Self::MissingMetadata => write!(
+                f,
+                "no `PKG-INFO`, `pyproject.toml`, or `setup.cfg` metadata was found"
+            ),
+            Self::MissingProjectName => write!(
+                f,
+                "`pyproject.toml` does not define a `[project]` table, and `setup.cfg` does not define a `metadata.name`"
+            ),
+            Self::InvalidPyProjectToml(err) => {
+                write!(f, "`pyproject.toml` could not be parsed: {err}")
+            }
+        }
+    }
+}
+
+/// Extract the requested package from a directory URL, as in:
+///   `./path/to/workspace#package=foo`
+fn get_package(url: &VerbatimUrl) -> Result<Option<PackageName>, Error> {
+    let Some(package) = url.fragment().and_then(|fragment| {
+        fragment
+            .split('&')
+            .find_map(|fragment| fragment.strip_prefix("package="))
+    }) else {
+        return Ok(None);
+    };
+    PackageName::from_str(package)
+        .map(Some)
+        .map_err(|err| Error::InvalidPackageFragment(package.to_string(), err))
+}
+
+/// Find the packages within a directory that contains multiple packages, e.g., a workspace root,
+/// or a repository with several projects in its subdirectories.
+///
+/// The directory itself is never included. Returns an empty list if no packages are found.
+async fn find_members(root: &Path) -> Vec<(PackageName, PathBuf)> {
+    // If the directory is a workspace root, use the workspace members.
+    let options = DiscoveryOptions {
+        stop_discovery_at: Some(root),
+        ..DiscoveryOptions::default()
+    };
+    if let Ok(project) = VirtualProject::discover(root, &options).await {
+        let members = project
+            .workspace()
+            .packages()
+            .iter()
+            .filter(|(_, member)| member.root() != root)
+            .map(|(name, member)| (name.clone(), member.root().clone()))
+            .collect::<Vec<_>>();
+        if !members.is_empty() {
+            return members;
+        }
+    }
+
+    // Otherwise, look for projects in the immediate subdirectories, including those of a `src`
+    // or `packages` directory.
+    let mut members = Vec::new();
+    for parent in [root.to_path_buf(), root.join("src"), root.join("packages")] {
+        let Ok(entries) = fs_err::read_dir(&parent) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Some(name) = fs_err::read_to_string(path.join("pyproject.toml"))
+                .ok()
+                .and_then(|contents| toml::from_str::<PyProjectToml>(&contents).ok())
+                .and_then(|pyproject| pyproject.project)
+                .map(|project| project.name)
+            else {
+                continue;
+            };
+            members.push((name, path));
+        }
+    }
+    // Distinct packages may share a name, so they're retained (and reported if selected).
+    members.sort_unstable();
+    members
+}
+
+/// Construct the URL for a package within a directory that contains multiple packages, preserving
+/// the user-provided form of the URL where possible.
+fn member_url(
+    url: &VerbatimParsedUrl,
+    parsed_directory_url: &ParsedDirectoryUrl,
+    path: &Path,
+) -> Result<VerbatimParsedUrl, Error> {
+    let mut verbatim =
+        VerbatimUrl::from_absolute_path(path).map_err(|_| Error::PathToUrl(path.to_path_buf()))?;
+    if let (Some(given), Ok(relative)) = (
+        url.verbatim.given(),
+        path.strip_prefix(&parsed_directory_url.install_path),
+    ) {
+        let given = given.split_once('#').map_or(given, |(given, _)| given);
+        verbatim = verbatim.with_given(format!(
+            "{}/{}",
+            given.trim_end_matches('/'),
+            relative.portable_display()
+        ));
     }
+    Ok(VerbatimParsedUrl {
+        parsed_url: ParsedUrl::Directory(ParsedDirectoryUrl::from_source(
+            path.to_path_buf(),
+            parsed_directory_url.editable,
+            parsed_directory_url.r#virtual,
+            verbatim.to_url(),
+        )),
+        verbatim,
+    })
 }
 
 /// A pyproject.toml as specified in PEP 517.
@@ -320,6 +621,7 @@ impl<'a, Context: BuildContext> NamedRequirementsResolver<'a, Context> {
 #[serde(rename_all = "kebab-case")]
 struct PyProjectToml {
     project: Option<Project>,
+    build_system: Option<serde::de::IgnoredAny>,
     tool: Option<Tool>,
 }
 
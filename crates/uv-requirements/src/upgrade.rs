@@ -1,7 +1,5 @@
 use std::path::Path;
 
-use anyhow::Result;
-
 use uv_client::{BaseClientBuilder, Connectivity};
 use uv_configuration::Upgrade;
 use uv_fs::CWD;
@@ -9,6 +7,8 @@ use uv_git::ResolvedRepositoryReference;
 use uv_requirements_txt::RequirementsTxt;
 use uv_resolver::{Lock, LockError, Preference, PreferenceError};
 
+use crate::Error;
+
 #[derive(Debug, Default)]
 pub struct LockedRequirements {
     /// The pinned versions from the lockfile.
@@ -21,7 +21,7 @@ pub struct LockedRequirements {
 pub async fn read_requirements_txt(
     output_file: Option<&Path>,
     upgrade: &Upgrade,
-) -> Result<Vec<Preference>> {
+) -> Result<Vec<Preference>, Error> {
     // As an optimization, skip reading the lockfile is we're upgrading all packages anyway.
     if upgrade.is_all() {
         return Ok(Vec::new());
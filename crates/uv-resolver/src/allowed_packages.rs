@@ -0,0 +1,131 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use pubgrub::Range;
+
+use uv_normalize::{InvalidNameError, PackageName};
+use uv_pep440::{Version, VersionParseError};
+
+/// An explicit allow-list of package versions (e.g., an organization-approved catalog), which
+/// restricts the registry candidates that the resolver is allowed to select.
+///
+/// Packages that are absent from the allow-list are treated as unavailable, as are any versions of
+/// an allowed package that are not listed explicitly.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AllowedPackages(BTreeMap<PackageName, Range<Version>>);
+
+impl AllowedPackages {
+    /// Returns the range of allowed versions for the given package, or `None` if the package is
+    /// not allowed at all.
+    pub fn get(&self, name: &PackageName) -> Option<&Range<Version>> {
+        self.0.get(name)
+    }
+
+    /// Returns `true` if the given version of the package is allowed.
+    pub fn contains(&self, name: &PackageName, version: &Version) -> bool {
+        self.0
+            .get(name)
+            .is_some_and(|versions| versions.contains(version))
+    }
+
+    /// Returns the number of packages in the allow-list.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the allow-list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromIterator<(PackageName, Version)> for AllowedPackages {
+    fn from_iter<T: IntoIterator<Item = (PackageName, Version)>>(iter: T) -> Self {
+        let mut packages: BTreeMap<PackageName, Range<Version>> = BTreeMap::new();
+        for (name, version) in iter {
+            let versions = packages.entry(name).or_insert_with(Range::empty);
+            *versions = versions.union(&Range::singleton(version));
+        }
+        Self(packages)
+    }
+}
+
+impl FromStr for AllowedPackages {
+    type Err = AllowedPackagesError;
+
+    /// Parse an [`AllowedPackages`] from the contents of an allow-list file.
+    ///
+    /// Each line must contain a single `name==version` pair. Blank lines and comments (starting
+    /// with `#`) are ignored.
+    fn from_str(contents: &str) -> Result<Self, Self::Err> {
+        let mut entries = Vec::new();
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.split_once('#').map_or(line, |(line, _)| line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            let Some((name, version)) = line.split_once("==") else {
+                return Err(AllowedPackagesError::InvalidLine(
+                    index + 1,
+                    line.to_string(),
+                ));
+            };
+            let name = PackageName::from_str(name.trim())
+                .map_err(|err| AllowedPackagesError::InvalidName(index + 1, err))?;
+            let version = Version::from_str(version.trim())
+                .map_err(|err| AllowedPackagesError::InvalidVersion(index + 1, err))?;
+            entries.push((name, version));
+        }
+        Ok(entries.into_iter().collect())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AllowedPackagesError {
+    #[error("Expected an entry of the form `name==version` on line {0}, but found: `{1}`")]
+    InvalidLine(usize, String),
+    #[error("Invalid package name on line {0}")]
+    InvalidName(usize, #[source] InvalidNameError),
+    #[error("Invalid version on line {0}")]
+    InvalidVersion(usize, #[source] VersionParseError),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_normalize::PackageName;
+    use uv_pep440::Version;
+
+    use super::AllowedPackages;
+
+    #[test]
+    fn parse() {
+        let allowed = AllowedPackages::from_str(
+            "# Approved packages\nanyio==4.3.0\nidna==3.6\nidna==3.7  # Backport\n\nSniffio == 1.3.1\n",
+        )
+        .unwrap();
+
+        assert_eq!(allowed.len(), 3);
+
+        let idna = PackageName::from_str("idna").unwrap();
+        assert!(allowed.contains(&idna, &Version::from_str("3.6").unwrap()));
+        assert!(allowed.contains(&idna, &Version::from_str("3.7").unwrap()));
+        assert!(!allowed.contains(&idna, &Version::from_str("3.8").unwrap()));
+
+        let sniffio = PackageName::from_str("sniffio").unwrap();
+        assert!(allowed.contains(&sniffio, &Version::from_str("1.3.1").unwrap()));
+
+        let flask = PackageName::from_str("flask").unwrap();
+        assert!(allowed.get(&flask).is_none());
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let err = AllowedPackages::from_str("anyio>=4.3.0\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Expected an entry of the form `name==version` on line 1, but found: `anyio>=4.3.0`"
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use std::cmp::Ordering;
+
+use uv_distribution_types::{CompatibleDist, IndexUrl};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+/// A hook for embedders of the resolver to enforce custom policies on the candidates considered
+/// during resolution, e.g., to reject versions published within the last 14 days, or to deny
+/// specific packages.
+///
+/// A policy is installed via [`Resolver::with_policy`](crate::Resolver::with_policy), and applies
+/// to every candidate, including those pinned by preferences (e.g., from a lockfile) or already
+/// installed.
+pub trait CandidatePolicy: std::fmt::Debug + Send + Sync {
+    /// A short name for the policy (e.g., `minimum-age`), included in resolution errors for the
+    /// candidates it rejects.
+    fn name(&self) -> &str;
+
+    /// Check whether the given candidate may be selected.
+    ///
+    /// If the candidate is rejected, the resolver marks the version as unavailable (including the
+    /// returned reason in any resolution error), and continues with the next candidate.
+    fn check(&self, _candidate: &PolicyCandidate<'_>) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Returns `true` if the policy reorders the candidates for the given package via
+    /// [`CandidatePolicy::compare`].
+    ///
+    /// Reordering requires enumerating every candidate in the range up front, so it should only
+    /// be enabled for the packages that need it.
+    fn sorts(&self, _package_name: &PackageName) -> bool {
+        false
+    }
+
+    /// Compare two candidate versions of the given package, where [`Ordering::Less`] indicates
+    /// that `a` should be considered before `b`.
+    ///
+    /// Versions that compare as equal retain the order determined by the resolution strategy
+    /// (e.g., highest first).
+    fn compare(&self, _package_name: &PackageName, _a: &Version, _b: &Version) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+/// A candidate version of a package, as presented to a [`CandidatePolicy`].
+#[derive(Debug)]
+pub struct PolicyCandidate<'a> {
+    name: &'a PackageName,
+    version: &'a Version,
+    dist: &'a CompatibleDist<'a>,
+}
+
+impl<'a> PolicyCandidate<'a> {
+    pub(crate) fn new(
+        name: &'a PackageName,
+        version: &'a Version,
+        dist: &'a CompatibleDist<'a>,
+    ) -> Self {
+        Self {
+            name,
+            version,
+            dist,
+        }
+    }
+
+    /// Return the name of the package.
+    pub fn name(&self) -> &PackageName {
+        self.name
+    }
+
+    /// Return the candidate version.
+    pub fn version(&self) -> &Version {
+        self.version
+    }
+
+    /// Return the distribution that would be used for the candidate.
+    pub fn dist(&self) -> &CompatibleDist<'a> {
+        self.dist
+    }
+
+    /// Return the index from which the candidate would be installed, if it isn't already
+    /// installed.
+    pub fn index(&self) -> Option<&IndexUrl> {
+        match self.dist {
+            CompatibleDist::InstalledDist(_) => None,
+            CompatibleDist::SourceDist { sdist, .. } => Some(&sdist.index),
+            CompatibleDist::CompatibleWheel { wheel, .. } => Some(&wheel.index),
+            CompatibleDist::IncompatibleWheel { sdist, .. } => Some(&sdist.index),
+        }
+    }
+
+    /// Return the upload time of the distribution that would be used for the candidate, in
+    /// milliseconds since the Unix epoch, if reported by the index.
+    pub fn upload_time_utc_ms(&self) -> Option<i64> {
+        match self.dist {
+            CompatibleDist::InstalledDist(_) => None,
+            CompatibleDist::SourceDist { sdist, .. } => sdist.file.upload_time_utc_ms,
+            CompatibleDist::CompatibleWheel { wheel, .. } => wheel.file.upload_time_utc_ms,
+            CompatibleDist::IncompatibleWheel { sdist, .. } => sdist.file.upload_time_utc_ms,
+        }
+    }
+}
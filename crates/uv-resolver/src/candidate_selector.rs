@@ -1,8 +1,10 @@
 use std::fmt::{Display, Formatter};
+use std::sync::Arc;
 
 use either::Either;
 use itertools::Itertools;
 use pubgrub::Range;
+use rustc_hash::FxHashMap;
 use smallvec::SmallVec;
 use tracing::{debug, trace};
 
@@ -13,19 +15,26 @@ use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_types::InstalledPackagesProvider;
 
+use crate::local_version_preference::LocalVersionOrder;
 use crate::preferences::{Entry, Preferences};
 use crate::prerelease::{AllowPrerelease, PrereleaseStrategy};
 use crate::resolution_mode::ResolutionStrategy;
 use crate::universal_marker::UniversalMarker;
 use crate::version_map::{VersionMap, VersionMapDistHandle};
-use crate::{Exclusions, Manifest, Options, ResolverEnvironment};
+use crate::{
+    CandidatePolicy, Exclusions, LocalVersionPreference, Manifest, Options, ResolverEnvironment,
+};
 
 #[derive(Debug, Clone)]
 #[allow(clippy::struct_field_names)]
 pub(crate) struct CandidateSelector {
     resolution_strategy: ResolutionStrategy,
+    resolution_package: FxHashMap<PackageName, ResolutionStrategy>,
     prerelease_strategy: PrereleaseStrategy,
     index_strategy: IndexStrategy,
+    minimal_changes: bool,
+    local_version_preference: Vec<LocalVersionPreference>,
+    policy: Option<Arc<dyn CandidatePolicy>>,
 }
 
 impl CandidateSelector {
@@ -42,13 +51,41 @@ impl CandidateSelector {
                 env,
                 options.dependency_mode,
             ),
+            resolution_package: options
+                .resolution_package
+                .iter()
+                .map(|(package, mode)| {
+                    (
+                        package.clone(),
+                        ResolutionStrategy::from_mode(
+                            *mode,
+                            manifest,
+                            env,
+                            options.dependency_mode,
+                        ),
+                    )
+                })
+                .collect(),
             prerelease_strategy: PrereleaseStrategy::from_mode(
                 options.prerelease_mode,
+                &options.prerelease_package,
                 manifest,
                 env,
                 options.dependency_mode,
             ),
             index_strategy: options.index_strategy,
+            minimal_changes: options.minimal_changes,
+            local_version_preference: options.local_version_preference.clone(),
+            policy: None,
+        }
+    }
+
+    /// Set the [`CandidatePolicy`] with which to reorder candidates, if it sorts any packages.
+    #[must_use]
+    pub(crate) fn with_policy(self, policy: Arc<dyn CandidatePolicy>) -> Self {
+        Self {
+            policy: Some(policy),
+            ..self
         }
     }
 
@@ -58,6 +95,18 @@ impl CandidateSelector {
         &self.resolution_strategy
     }
 
+    /// Return the [`ResolutionStrategy`] for the given package, taking any per-package override
+    /// into account.
+    #[inline]
+    pub(crate) fn resolution_strategy_for(
+        &self,
+        package_name: &PackageName,
+    ) -> &ResolutionStrategy {
+        self.resolution_package
+            .get(package_name)
+            .unwrap_or(&self.resolution_strategy)
+    }
+
     #[inline]
     #[allow(dead_code)]
     pub(crate) fn prerelease_strategy(&self) -> &PrereleaseStrategy {
@@ -132,8 +181,25 @@ impl CandidateSelector {
             }
         }
 
-        // Otherwise, find the best candidate from the version maps.
-        let compatible = self.select_no_preference(package_name, range, version_maps, env);
+        // Otherwise, find the best candidate from the version maps. If we're minimizing changes,
+        // and the package was previously pinned to a version that no longer satisfies the range,
+        // find the candidate closest to that version instead.
+        let pinned = preferences
+            .get(package_name)
+            .iter()
+            .filter(|_| self.minimal_changes)
+            .find_or_first(|entry| env.included_by_marker(entry.marker().pep508()));
+        let compatible = if let Some(pinned) = pinned {
+            self.select_nearest(
+                package_name,
+                pinned.pin().version(),
+                range,
+                version_maps,
+                env,
+            )
+        } else {
+            self.select_no_preference(package_name, range, version_maps, env)
+        };
 
         // Cross-reference against the already-installed distribution.
         //
@@ -400,76 +466,130 @@ impl CandidateSelector {
             AllowPrerelease::IfNecessary => !version_maps.iter().any(VersionMap::stable),
         };
 
-        if self.index_strategy == IndexStrategy::UnsafeBestMatch {
+        let candidate = if self.index_strategy == IndexStrategy::UnsafeBestMatch {
             if highest {
                 Self::select_candidate(
-                    version_maps
-                        .iter()
-                        .enumerate()
-                        .map(|(map_index, version_map)| {
-                            version_map
-                                .iter(range)
-                                .rev()
-                                .map(move |item| (map_index, item))
-                        })
-                        .kmerge_by(
-                            |(index1, (version1, _)), (index2, (version2, _))| match version1
-                                .cmp(version2)
-                            {
-                                std::cmp::Ordering::Equal => index1 < index2,
-                                std::cmp::Ordering::Less => false,
-                                std::cmp::Ordering::Greater => true,
-                            },
-                        )
-                        .map(|(_, item)| item),
+                    self.order(
+                        version_maps
+                            .iter()
+                            .enumerate()
+                            .map(|(map_index, version_map)| {
+                                version_map
+                                    .iter(range)
+                                    .rev()
+                                    .map(move |item| (map_index, item))
+                            })
+                            .kmerge_by(|(index1, (version1, _)), (index2, (version2, _))| {
+                                match version1.cmp(version2) {
+                                    std::cmp::Ordering::Equal => index1 < index2,
+                                    std::cmp::Ordering::Less => false,
+                                    std::cmp::Ordering::Greater => true,
+                                }
+                            })
+                            .map(|(_, item)| item),
+                        package_name,
+                    ),
                     package_name,
                     range,
                     allow_prerelease,
                 )
             } else {
                 Self::select_candidate(
-                    version_maps
-                        .iter()
-                        .enumerate()
-                        .map(|(map_index, version_map)| {
-                            version_map.iter(range).map(move |item| (map_index, item))
-                        })
-                        .kmerge_by(
-                            |(index1, (version1, _)), (index2, (version2, _))| match version1
-                                .cmp(version2)
-                            {
-                                std::cmp::Ordering::Equal => index1 < index2,
-                                std::cmp::Ordering::Less => true,
-                                std::cmp::Ordering::Greater => false,
-                            },
-                        )
-                        .map(|(_, item)| item),
+                    self.order(
+                        version_maps
+                            .iter()
+                            .enumerate()
+                            .map(|(map_index, version_map)| {
+                                version_map.iter(range).map(move |item| (map_index, item))
+                            })
+                            .kmerge_by(|(index1, (version1, _)), (index2, (version2, _))| {
+                                match version1.cmp(version2) {
+                                    std::cmp::Ordering::Equal => index1 < index2,
+                                    std::cmp::Ordering::Less => true,
+                                    std::cmp::Ordering::Greater => false,
+                                }
+                            })
+                            .map(|(_, item)| item),
+                        package_name,
+                    ),
                     package_name,
                     range,
                     allow_prerelease,
                 )
             }
+        } else if highest {
+            version_maps.iter().find_map(|version_map| {
+                Self::select_candidate(
+                    self.order(version_map.iter(range).rev(), package_name),
+                    package_name,
+                    range,
+                    allow_prerelease,
+                )
+            })
         } else {
-            if highest {
-                version_maps.iter().find_map(|version_map| {
-                    Self::select_candidate(
-                        version_map.iter(range).rev(),
-                        package_name,
-                        range,
-                        allow_prerelease,
-                    )
-                })
-            } else {
-                version_maps.iter().find_map(|version_map| {
-                    Self::select_candidate(
-                        version_map.iter(range),
-                        package_name,
-                        range,
-                        allow_prerelease,
-                    )
-                })
+            version_maps.iter().find_map(|version_map| {
+                Self::select_candidate(
+                    self.order(version_map.iter(range), package_name),
+                    package_name,
+                    range,
+                    allow_prerelease,
+                )
+            })
+        };
+
+        // Under every index strategy, if the selected version is available on multiple indexes,
+        // the earliest index wins.
+        if let Some(candidate) = candidate.as_ref() {
+            let indexes = version_maps
+                .iter()
+                .filter(|version_map| version_map.get(candidate.version()).is_some())
+                .count();
+            if indexes > 1 {
+                debug!(
+                    "Tie-break: {package_name}=={} is available on {indexes} indexes; preferring the first",
+                    candidate.version()
+                );
             }
         }
+
+        candidate
+    }
+
+    /// Select the [`Candidate`] closest to a previously pinned version, preferring the smallest
+    /// upgrade from the pin, and then the smallest downgrade.
+    fn select_nearest<'a>(
+        &'a self,
+        package_name: &'a PackageName,
+        pinned: &Version,
+        range: &Range<Version>,
+        version_maps: &'a [VersionMap],
+        env: &ResolverEnvironment,
+    ) -> Option<Candidate<'a>> {
+        trace!("Selecting candidate for {package_name} with range {range} nearest to {pinned}");
+
+        let allow_prerelease = match self.prerelease_strategy.allows(package_name, env) {
+            AllowPrerelease::Yes => true,
+            AllowPrerelease::No => false,
+            // Allow pre-releases if there are no stable versions available.
+            AllowPrerelease::IfNecessary => !version_maps.iter().any(VersionMap::stable),
+        };
+
+        version_maps.iter().find_map(|version_map| {
+            Self::select_candidate(
+                version_map
+                    .iter(range)
+                    .filter(|(version, _)| *version >= pinned)
+                    .chain(
+                        version_map
+                            .iter(range)
+                            .rev()
+                            .filter(|(version, _)| *version < pinned),
+                    ),
+                package_name,
+                range,
+                allow_prerelease,
+            )
+        })
     }
 
     /// By default, we select the latest version, but we also allow using the lowest version instead
@@ -479,12 +599,29 @@ impl CandidateSelector {
         package_name: &PackageName,
         env: &ResolverEnvironment,
     ) -> bool {
-        match &self.resolution_strategy {
-            ResolutionStrategy::Highest => true,
-            ResolutionStrategy::Lowest => false,
-            ResolutionStrategy::LowestDirect(direct_dependencies) => {
-                !direct_dependencies.contains(package_name, env)
+        self.resolution_strategy_for(package_name)
+            .use_highest_version(package_name, env)
+    }
+
+    /// Order the candidate versions of a package according to the [`LocalVersionPreference`]s
+    /// and the [`CandidatePolicy`], if any.
+    fn order<'i, 'a: 'i>(
+        &'i self,
+        versions: impl Iterator<Item = (&'a Version, VersionMapDistHandle<'a>)> + 'i,
+        package_name: &'i PackageName,
+    ) -> impl Iterator<Item = (&'a Version, VersionMapDistHandle<'a>)> + 'i {
+        let versions = LocalVersionOrder::new(versions, &self.local_version_preference);
+        match self
+            .policy
+            .as_ref()
+            .filter(|policy| policy.sorts(package_name))
+        {
+            Some(policy) => {
+                let mut versions = versions.collect::<Vec<_>>();
+                versions.sort_by(|(a, _), (b, _)| policy.compare(package_name, a, b));
+                Either::Left(versions.into_iter())
             }
+            None => Either::Right(versions),
         }
     }
 
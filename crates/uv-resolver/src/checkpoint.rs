@@ -0,0 +1,123 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use tracing::{debug, warn};
+
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+use crate::Preference;
+
+/// The minimum interval between writes of a [`ResolverCheckpoint`] to disk.
+const PERSIST_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The partial progress of an in-flight resolution, persisted to disk periodically such that an
+/// interrupted resolution can be resumed.
+///
+/// The checkpoint records the most recent version decided for each package. When resuming, the
+/// recorded decisions are used as preferences, such that the resolver revisits the same versions
+/// (whose metadata is already present in the cache) before considering any alternatives.
+#[derive(Debug)]
+pub struct ResolverCheckpoint {
+    path: PathBuf,
+    state: Mutex<CheckpointState>,
+}
+
+#[derive(Debug)]
+struct CheckpointState {
+    decisions: BTreeMap<PackageName, Version>,
+    persisted: Instant,
+    dirty: bool,
+}
+
+impl ResolverCheckpoint {
+    /// Create a new, empty [`ResolverCheckpoint`] that will be persisted to the given path.
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            state: Mutex::new(CheckpointState {
+                decisions: BTreeMap::new(),
+                persisted: Instant::now(),
+                dirty: false,
+            }),
+        }
+    }
+
+    /// Read the decisions recorded in the checkpoint at the given path, as preferences.
+    ///
+    /// Returns `None` if no checkpoint exists.
+    pub fn read(path: &Path) -> Result<Option<Vec<Preference>>, io::Error> {
+        let contents = match fs_err::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        let preferences = contents
+            .lines()
+            .filter_map(|line| {
+                let (name, version) = line.split_once("==")?;
+                let name = PackageName::from_str(name).ok()?;
+                let version = Version::from_str(version).ok()?;
+                Some(Preference::from_checkpoint(name, version))
+            })
+            .collect();
+        Ok(Some(preferences))
+    }
+
+    /// Record a decision, persisting the checkpoint if it hasn't been persisted recently.
+    pub(crate) fn record(&self, name: &PackageName, version: &Version) {
+        let mut state = self.state.lock().unwrap();
+        if state.decisions.get(name) != Some(version) {
+            state.decisions.insert(name.clone(), version.clone());
+            state.dirty = true;
+        }
+        if state.dirty && state.persisted.elapsed() >= PERSIST_INTERVAL {
+            if let Err(err) = self.write(&state.decisions) {
+                warn!("Failed to persist resolver checkpoint: {err}");
+            }
+            state.persisted = Instant::now();
+            state.dirty = false;
+        }
+    }
+
+    /// Persist any decisions that have not yet been written to disk.
+    pub fn persist(&self) -> Result<(), io::Error> {
+        let mut state = self.state.lock().unwrap();
+        if state.dirty {
+            self.write(&state.decisions)?;
+            state.persisted = Instant::now();
+            state.dirty = false;
+        }
+        Ok(())
+    }
+
+    /// Remove the checkpoint from disk, e.g., once the resolution has completed.
+    pub fn remove(&self) -> Result<(), io::Error> {
+        match fs_err::remove_file(&self.path) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn write(&self, decisions: &BTreeMap<PackageName, Version>) -> Result<(), io::Error> {
+        debug!(
+            "Persisting {} resolver decisions to: {}",
+            decisions.len(),
+            self.path.display()
+        );
+        let mut contents = String::new();
+        for (name, version) in decisions {
+            writeln!(contents, "{name}=={version}").unwrap();
+        }
+        if let Some(parent) = self.path.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+        uv_fs::write_atomic_sync(&self.path, contents)
+    }
+}
@@ -1,8 +1,10 @@
 use std::collections::{BTreeMap, BTreeSet, Bound};
 use std::fmt::Formatter;
 use std::sync::Arc;
+use std::time::Duration;
 
 use indexmap::IndexSet;
+use owo_colors::OwoColorize;
 use pubgrub::{
     DefaultStringReporter, DerivationTree, Derived, External, Range, Ranges, Reporter, Term,
 };
@@ -22,14 +24,17 @@ use crate::dependency_provider::UvDependencyProvider;
 use crate::fork_indexes::ForkIndexes;
 use crate::fork_urls::ForkUrls;
 use crate::prerelease::AllowPrerelease;
-use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner, PubGrubReportFormatter};
+use crate::pubgrub::{
+    DerivationTreeFormatter, PubGrubPackage, PubGrubPackageInner, PubGrubReportFormatter,
+    RequirementLocation,
+};
 use crate::python_requirement::PythonRequirement;
 use crate::requires_python::LowerBound;
 use crate::resolution::ConflictingDistributionError;
 use crate::resolver::{
     MetadataUnavailable, ResolverEnvironment, UnavailablePackage, UnavailableReason,
 };
-use crate::{InMemoryIndex, Options};
+use crate::{InMemoryIndex, Options, SourcePolicy};
 
 #[derive(Debug, thiserror::Error)]
 pub enum ResolveError {
@@ -101,6 +106,9 @@ pub enum ResolveError {
     #[error(transparent)]
     NoSolution(#[from] NoSolutionError),
 
+    #[error(transparent)]
+    LimitExceeded(#[from] LimitExceededError),
+
     #[error("Attempted to construct an invalid version specifier")]
     InvalidVersion(#[from] uv_pep440::VersionSpecifierBuildError),
 
@@ -113,6 +121,14 @@ pub enum ResolveError {
     #[error("Package `{0}` is unavailable")]
     PackageUnavailable(PackageName),
 
+    #[error(
+        "Package `{0}` was requested from `{1}`, which is not allowed by its source policy (`{2}`)"
+    )]
+    SourcePolicy(PackageName, String, SourcePolicy),
+
+    #[error("Package `{0}` is provided by `{1}`, and so can't be requested from a URL: `{2}`")]
+    ProvidedUrl(PackageName, PackageName, String),
+
     #[error("Invalid extra value in conflict marker: {reason}: {raw_extra}")]
     InvalidExtraInConflictMarker {
         reason: String,
@@ -154,6 +170,7 @@ pub struct NoSolutionError {
     env: ResolverEnvironment,
     tags: Option<Tags>,
     workspace_members: BTreeSet<PackageName>,
+    origins: FxHashMap<PackageName, Vec<RequirementLocation>>,
     options: Options,
 }
 
@@ -175,6 +192,7 @@ impl NoSolutionError {
         env: ResolverEnvironment,
         tags: Option<Tags>,
         workspace_members: BTreeSet<PackageName>,
+        origins: FxHashMap<PackageName, Vec<RequirementLocation>>,
         options: Options,
     ) -> Self {
         Self {
@@ -193,6 +211,7 @@ impl NoSolutionError {
             env,
             tags,
             workspace_members,
+            origins,
             options,
         }
     }
@@ -363,6 +382,7 @@ impl std::fmt::Debug for NoSolutionError {
             env,
             tags,
             workspace_members,
+            origins,
             options,
         } = self;
         f.debug_struct("NoSolutionError")
@@ -380,6 +400,7 @@ impl std::fmt::Debug for NoSolutionError {
             .field("env", env)
             .field("tags", tags)
             .field("workspace_members", workspace_members)
+            .field("origins", origins)
             .field("options", options)
             .finish()
     }
@@ -439,6 +460,12 @@ impl std::fmt::Display for NoSolutionError {
         let report = DefaultStringReporter::report_with_formatter(&tree, &formatter);
         write!(f, "{report}")?;
 
+        // Include the derivation tree, if requested.
+        if self.options.derivation_tree {
+            let tree = DerivationTreeFormatter::new(&formatter, &self.origins).format(&tree);
+            write!(f, "\n\n{}:{tree}", "Derivation tree".bold())?;
+        }
+
         // Include any additional hints.
         let mut additional_hints = IndexSet::default();
         formatter.generate_hints(
@@ -1197,6 +1224,79 @@ impl std::fmt::Display for NoSolutionHeader {
     }
 }
 
+/// A limit on the resolution, after which it is aborted.
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum ResolutionLimit {
+    /// The resolution exceeded the `--resolution-timeout`.
+    Timeout(Duration),
+    /// The resolution exceeded `--max-backtracks` in a single fork.
+    Backtracks(usize),
+}
+
+/// An error raised when the resolution exceeds a [`ResolutionLimit`], rather than continuing to
+/// backtrack through a pathological dependency graph.
+#[derive(Debug)]
+pub struct LimitExceededError {
+    limit: ResolutionLimit,
+    /// The packages involved in the most conflicts, along with the number of conflicts.
+    contended: Vec<(PackageName, usize)>,
+}
+
+impl LimitExceededError {
+    pub(crate) fn new(limit: ResolutionLimit, contended: Vec<(PackageName, usize)>) -> Self {
+        Self { limit, contended }
+    }
+
+    /// Return the packages involved in the most conflicts, in descending order.
+    pub fn contended(&self) -> &[(PackageName, usize)] {
+        &self.contended
+    }
+}
+
+impl std::error::Error for LimitExceededError {}
+
+impl std::fmt::Display for LimitExceededError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let option = match self.limit {
+            ResolutionLimit::Timeout(timeout) => {
+                write!(f, "Resolution timed out after {}s", timeout.as_secs_f32())?;
+                "--resolution-timeout"
+            }
+            ResolutionLimit::Backtracks(max) => {
+                write!(
+                    f,
+                    "Resolution exceeded the limit of {max} backtracking steps"
+                )?;
+                "--max-backtracks"
+            }
+        };
+
+        if !self.contended.is_empty() {
+            write!(f, "; the most contended packages were:")?;
+            for (name, conflicts) in &self.contended {
+                write!(
+                    f,
+                    "\n- `{}` ({conflicts} {})",
+                    name.cyan(),
+                    if *conflicts == 1 {
+                        "conflict"
+                    } else {
+                        "conflicts"
+                    }
+                )?;
+            }
+            write!(
+                f,
+                "\n\n{}{} Consider constraining the versions of these packages to narrow the search, or raise the limit with `{}`",
+                "hint".bold().cyan(),
+                ":".bold(),
+                option.green(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
 /// Given a [`DerivationTree`], simplify version ranges using the available versions for each
 /// package.
 fn simplify_derivation_tree_ranges(
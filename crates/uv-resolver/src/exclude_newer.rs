@@ -1,7 +1,10 @@
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
 use jiff::{tz::TimeZone, Timestamp, ToSpan};
 
+use uv_normalize::PackageName;
+
 /// A timestamp that excludes files newer than it.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 pub struct ExcludeNewer(Timestamp);
@@ -89,3 +92,68 @@ impl schemars::JsonSchema for ExcludeNewer {
         .into()
     }
 }
+
+/// A per-package `name=timestamp` cutoff, as provided on the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExcludeNewerPackageEntry {
+    pub package: PackageName,
+    pub timestamp: ExcludeNewer,
+}
+
+impl FromStr for ExcludeNewerPackageEntry {
+    type Err = String;
+
+    /// Parse an [`ExcludeNewerPackageEntry`] from a string in the format `PACKAGE=DATE`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let Some((package, date)) = input.split_once('=') else {
+            return Err(format!(
+                "`{input}` is not a valid package-specific cutoff (expected `PACKAGE=DATE`)"
+            ));
+        };
+        let package = PackageName::from_str(package.trim())
+            .map_err(|err| format!("`{package}` is not a valid package name: {err}"))?;
+        let timestamp = ExcludeNewer::from_str(date.trim())?;
+        Ok(Self { package, timestamp })
+    }
+}
+
+/// A table of per-package cutoffs that take precedence over the global [`ExcludeNewer`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ExcludeNewerPackage(BTreeMap<PackageName, ExcludeNewer>);
+
+impl ExcludeNewerPackage {
+    /// Returns the cutoff for the given package, if one was provided.
+    pub fn get(&self, package: &PackageName) -> Option<&ExcludeNewer> {
+        self.0.get(package)
+    }
+
+    /// Returns `true` if no per-package cutoffs were provided.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Merge two tables of per-package cutoffs, preferring the values in `self`.
+    #[must_use]
+    pub fn merge(mut self, other: Self) -> Self {
+        for (package, timestamp) in other.0 {
+            self.0.entry(package).or_insert(timestamp);
+        }
+        self
+    }
+
+    /// Iterate over the per-package cutoffs.
+    pub fn iter(&self) -> impl Iterator<Item = (&PackageName, &ExcludeNewer)> {
+        self.0.iter()
+    }
+}
+
+impl FromIterator<ExcludeNewerPackageEntry> for ExcludeNewerPackage {
+    fn from_iter<T: IntoIterator<Item = ExcludeNewerPackageEntry>>(iter: T) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|entry| (entry.package, entry.timestamp))
+                .collect(),
+        )
+    }
+}
@@ -18,6 +18,9 @@ use uv_platform_tags::{TagCompatibility, Tags};
 use uv_pypi_types::HashDigest;
 use uv_types::HashStrategy;
 
+use crate::wheel_preference::compare_wheels;
+use crate::WheelPreference;
+
 /// A set of [`PrioritizedDist`] from a `--find-links` entry, indexed by [`PackageName`]
 /// and [`Version`].
 #[derive(Debug, Clone, Default)]
@@ -219,6 +222,18 @@ impl FlatDistributions {
     pub fn remove(&mut self, version: &Version) -> Option<PrioritizedDist> {
         self.0.remove(version)
     }
+
+    /// Re-select the highest-priority wheel for each version under the given
+    /// [`WheelPreference`]s.
+    #[must_use]
+    pub(crate) fn with_wheel_preference(mut self, wheel_preference: &[WheelPreference]) -> Self {
+        if !wheel_preference.is_empty() {
+            for prioritized_dist in self.0.values_mut() {
+                prioritized_dist.prefer_wheels(|a, b| compare_wheels(wheel_preference, a, b));
+            }
+        }
+        self
+    }
 }
 
 impl IntoIterator for FlatDistributions {
@@ -0,0 +1,25 @@
+/// The policy to apply to files on an index with unparsable filenames or metadata (e.g., an
+/// invalid version or `requires-python` specifier).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum InvalidMetadataPolicy {
+    /// Skip invalid files, emitting a warning for each.
+    Warn,
+    /// Skip invalid files silently.
+    #[default]
+    Skip,
+    /// Fail when an invalid file is encountered.
+    Error,
+}
+
+impl std::fmt::Display for InvalidMetadataPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Warn => write!(f, "warn"),
+            Self::Skip => write!(f, "skip"),
+            Self::Error => write!(f, "error"),
+        }
+    }
+}
@@ -1,9 +1,16 @@
+pub use allowed_packages::{AllowedPackages, AllowedPackagesError};
+pub use candidate_policy::{CandidatePolicy, PolicyCandidate};
+pub use checkpoint::ResolverCheckpoint;
 pub use dependency_mode::DependencyMode;
-pub use error::{NoSolutionError, NoSolutionHeader, ResolveError, SentinelRange};
-pub use exclude_newer::ExcludeNewer;
+pub use error::{
+    LimitExceededError, NoSolutionError, NoSolutionHeader, ResolveError, SentinelRange,
+};
+pub use exclude_newer::{ExcludeNewer, ExcludeNewerPackage, ExcludeNewerPackageEntry};
 pub use exclusions::Exclusions;
 pub use flat_index::{FlatDistributions, FlatIndex};
 pub use fork_strategy::ForkStrategy;
+pub use invalid_metadata::InvalidMetadataPolicy;
+pub use local_version_preference::LocalVersionPreference;
 pub use lock::{
     Installable, Lock, LockError, LockVersion, Package, PackageMap, RequirementsTxtExport,
     ResolverManifest, SatisfiesResult, TreeDisplay, VERSION,
@@ -12,20 +19,24 @@ pub use manifest::Manifest;
 pub use options::{Flexibility, Options, OptionsBuilder};
 pub use preferences::{Preference, PreferenceError, Preferences};
 pub use prerelease::PrereleaseMode;
+pub use provider::{PackageProvider, PackageProviderError, Providers};
 pub use python_requirement::PythonRequirement;
 pub use requires_python::{RequiresPython, RequiresPythonRange};
 pub use resolution::{
-    AnnotationStyle, ConflictingDistributionError, DisplayResolutionGraph, ResolverOutput,
+    AnnotationStyle, ConflictingDistributionError, DependencyGraph, DisplayResolutionExplanation,
+    DisplayResolutionGraph, GithubDetector, GithubJob, GithubSnapshot, LowerBound, ResolverOutput,
 };
-pub use resolution_mode::ResolutionMode;
+pub use resolution_mode::{ResolutionMode, ResolutionPackage, ResolutionPackageEntry};
 pub use resolver::{
     BuildId, DefaultResolverProvider, DerivationChainBuilder, InMemoryIndex, MetadataResponse,
     PackageVersionsResult, Reporter as ResolverReporter, Resolver, ResolverEnvironment,
     ResolverProvider, VersionsResponse, WheelMetadataResult,
 };
+pub use source_policy::{PackageSourcePolicy, SourcePolicies, SourcePolicy, SourcePolicyError};
 pub use universal_marker::{ConflictMarker, UniversalMarker};
 pub use version_map::VersionMap;
-pub use yanks::AllowedYanks;
+pub use wheel_preference::WheelPreference;
+pub use yanks::{AllowedYanks, YankedPolicy};
 
 /// A custom `HashSet` using `hashbrown`.
 ///
@@ -36,7 +47,10 @@ type FxHashbrownSet<T> = hashbrown::HashSet<T, rustc_hash::FxBuildHasher>;
 
 type FxHashbrownMap<K, V> = hashbrown::HashMap<K, V, rustc_hash::FxBuildHasher>;
 
+mod allowed_packages;
+mod candidate_policy;
 mod candidate_selector;
+mod checkpoint;
 mod dependency_mode;
 mod dependency_provider;
 mod error;
@@ -47,6 +61,8 @@ mod fork_indexes;
 mod fork_strategy;
 mod fork_urls;
 mod graph_ops;
+mod invalid_metadata;
+mod local_version_preference;
 mod lock;
 mod manifest;
 mod marker;
@@ -54,6 +70,7 @@ mod options;
 mod pins;
 mod preferences;
 mod prerelease;
+mod provider;
 mod pubgrub;
 mod python_requirement;
 mod redirect;
@@ -61,6 +78,8 @@ mod requires_python;
 mod resolution;
 mod resolution_mode;
 mod resolver;
+mod source_policy;
 mod universal_marker;
 mod version_map;
+mod wheel_preference;
 mod yanks;
@@ -0,0 +1,210 @@
+use std::collections::VecDeque;
+use std::iter::Peekable;
+use std::str::FromStr;
+
+use uv_pep440::Version;
+
+/// A preference for a local version label (e.g., `cu118` in `2.1.0+cu118`), used to rank
+/// candidates that share the same public version.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum LocalVersionPreference {
+    /// Prefer versions with the given local version label.
+    Label(String),
+    /// Prefer versions without a local version label, spelled `none`.
+    None,
+}
+
+impl LocalVersionPreference {
+    /// Returns `true` if the given [`Version`] satisfies this preference.
+    fn matches(&self, version: &Version) -> bool {
+        match self {
+            Self::Label(label) => version
+                .to_string()
+                .split_once('+')
+                .is_some_and(|(_, local)| local == label),
+            Self::None => !version.is_local(),
+        }
+    }
+}
+
+impl FromStr for LocalVersionPreference {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim();
+        if input.eq_ignore_ascii_case("none") {
+            return Ok(Self::None);
+        }
+        // Local version labels are normalized to lowercase, with `-` and `_` as `.` (as in
+        // PEP 440).
+        let label = input
+            .strip_prefix('+')
+            .unwrap_or(input)
+            .to_ascii_lowercase()
+            .replace(['-', '_'], ".");
+        if label.is_empty()
+            || !label.split('.').all(|segment| {
+                !segment.is_empty() && segment.chars().all(|c| c.is_ascii_alphanumeric())
+            })
+        {
+            return Err(format!(
+                "`{input}` is not a valid local version label (expected, e.g., `cu118` or `none`)"
+            ));
+        }
+        Ok(Self::Label(label))
+    }
+}
+
+impl TryFrom<String> for LocalVersionPreference {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_str(&value)
+    }
+}
+
+impl From<LocalVersionPreference> for String {
+    fn from(value: LocalVersionPreference) -> Self {
+        value.to_string()
+    }
+}
+
+impl std::fmt::Display for LocalVersionPreference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Label(label) => write!(f, "{label}"),
+            Self::None => write!(f, "none"),
+        }
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for LocalVersionPreference {
+    fn schema_name() -> String {
+        "LocalVersionPreference".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some("A local version label to prefer (e.g., `cu118`), or `none` to prefer versions without a local version label.".to_string()),
+              ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
+
+/// Return the rank of the given [`Version`] under a list of [`LocalVersionPreference`]s, where
+/// lower ranks are preferred. Versions that don't match any preference are ranked last.
+fn rank(preferences: &[LocalVersionPreference], version: &Version) -> usize {
+    preferences
+        .iter()
+        .position(|preference| preference.matches(version))
+        .unwrap_or(preferences.len())
+}
+
+/// An iterator adapter that reorders consecutive candidates sharing the same public version
+/// (e.g., `2.1.0`, `2.1.0+cpu`, and `2.1.0+cu118`) according to a list of
+/// [`LocalVersionPreference`]s, retaining the original order otherwise.
+///
+/// Local versions sort immediately after their public version, so candidates that share a public
+/// version are always adjacent, regardless of the iteration direction.
+pub(crate) struct LocalVersionOrder<'p, 'a, T, I: Iterator<Item = (&'a Version, T)>> {
+    iter: Peekable<I>,
+    buffer: VecDeque<(&'a Version, T)>,
+    preferences: &'p [LocalVersionPreference],
+}
+
+impl<'p, 'a, T, I: Iterator<Item = (&'a Version, T)>> LocalVersionOrder<'p, 'a, T, I> {
+    pub(crate) fn new(iter: I, preferences: &'p [LocalVersionPreference]) -> Self {
+        Self {
+            iter: iter.peekable(),
+            buffer: VecDeque::new(),
+            preferences,
+        }
+    }
+}
+
+impl<'a, T, I: Iterator<Item = (&'a Version, T)>> Iterator for LocalVersionOrder<'_, 'a, T, I> {
+    type Item = (&'a Version, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.preferences.is_empty() {
+            return self.iter.next();
+        }
+
+        if let Some(item) = self.buffer.pop_front() {
+            return Some(item);
+        }
+
+        let first = self.iter.next()?;
+        let public = first.0.clone().without_local();
+        let mut group = vec![first];
+        while let Some(next) = self
+            .iter
+            .next_if(|next| next.0.clone().without_local() == public)
+        {
+            group.push(next);
+        }
+
+        // Stable sort, such that candidates with equal rank retain their original order.
+        group.sort_by_key(|(version, _)| rank(self.preferences, version));
+        self.buffer.extend(group);
+        self.buffer.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_pep440::Version;
+
+    use super::{LocalVersionOrder, LocalVersionPreference};
+
+    fn order(versions: &[&str], preferences: &[&str]) -> Vec<String> {
+        let versions = versions
+            .iter()
+            .map(|version| Version::from_str(version).unwrap())
+            .collect::<Vec<_>>();
+        let preferences = preferences
+            .iter()
+            .map(|preference| LocalVersionPreference::from_str(preference).unwrap())
+            .collect::<Vec<_>>();
+        LocalVersionOrder::new(versions.iter().map(|version| (version, ())), &preferences)
+            .map(|(version, ())| version.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn prefer_label() {
+        assert_eq!(
+            order(
+                &["2.2.0", "2.1.0+cu118", "2.1.0+cpu", "2.1.0", "2.0.0+cpu"],
+                &["cpu"]
+            ),
+            vec!["2.2.0", "2.1.0+cpu", "2.1.0+cu118", "2.1.0", "2.0.0+cpu"]
+        );
+    }
+
+    #[test]
+    fn prefer_none() {
+        assert_eq!(
+            order(&["2.1.0+cu118", "2.1.0+cpu", "2.1.0"], &["none", "cu118"]),
+            vec!["2.1.0", "2.1.0+cu118", "2.1.0+cpu"]
+        );
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            LocalVersionPreference::from_str("+CU_118").unwrap(),
+            LocalVersionPreference::Label("cu.118".to_string())
+        );
+        assert!(LocalVersionPreference::from_str("cu 118").is_err());
+    }
+}
@@ -56,8 +56,8 @@ use crate::requires_python::SimplifiedMarkerTree;
 use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
 use crate::universal_marker::{ConflictMarker, UniversalMarker};
 use crate::{
-    ExcludeNewer, InMemoryIndex, MetadataResponse, PrereleaseMode, RequiresPython, ResolutionMode,
-    ResolverOutput,
+    ExcludeNewer, ExcludeNewerPackage, InMemoryIndex, MetadataResponse, PrereleaseMode,
+    RequiresPython, ResolutionMode, ResolutionPackage, ResolverOutput,
 };
 
 mod installable;
@@ -277,9 +277,17 @@ impl Lock {
         let packages = packages.into_values().collect();
         let options = ResolverOptions {
             resolution_mode: resolution.options.resolution_mode,
+            resolution_package: resolution.options.resolution_package.clone(),
             prerelease_mode: resolution.options.prerelease_mode,
+            prerelease_package: resolution
+                .options
+                .prerelease_package
+                .iter()
+                .cloned()
+                .collect(),
             fork_strategy: resolution.options.fork_strategy,
             exclude_newer: resolution.options.exclude_newer,
+            exclude_newer_package: resolution.options.exclude_newer_package.clone(),
         };
         let lock = Self::new(
             VERSION,
@@ -622,11 +630,22 @@ impl Lock {
         self.options.resolution_mode
     }
 
+    /// Returns the per-package resolution modes used to generate this lock.
+    pub fn resolution_package(&self) -> &ResolutionPackage {
+        &self.options.resolution_package
+    }
+
     /// Returns the pre-release mode used to generate this lock.
     pub fn prerelease_mode(&self) -> PrereleaseMode {
         self.options.prerelease_mode
     }
 
+    /// Returns the packages that were allowed to have pre-release versions when generating this
+    /// lock.
+    pub fn prerelease_package(&self) -> &BTreeSet<PackageName> {
+        &self.options.prerelease_package
+    }
+
     /// Returns the multi-version mode used to generate this lock.
     pub fn fork_strategy(&self) -> ForkStrategy {
         self.options.fork_strategy
@@ -637,6 +656,11 @@ impl Lock {
         self.options.exclude_newer
     }
 
+    /// Returns the per-package exclude newer settings used to generate this lock.
+    pub fn exclude_newer_package(&self) -> &ExcludeNewerPackage {
+        &self.options.exclude_newer_package
+    }
+
     /// Returns the conflicting groups that were used to generate this lock.
     pub fn conflicts(&self) -> &Conflicts {
         &self.conflicts
@@ -792,12 +816,28 @@ impl Lock {
                     value(self.options.resolution_mode.to_string()),
                 );
             }
+            if !self.options.resolution_package.is_empty() {
+                let mut table = InlineTable::new();
+                for (package, mode) in self.options.resolution_package.iter() {
+                    table.insert(package.as_ref(), Value::from(mode.to_string()));
+                }
+                options_table.insert("resolution-package", value(table));
+            }
             if self.options.prerelease_mode != PrereleaseMode::default() {
                 options_table.insert(
                     "prerelease-mode",
                     value(self.options.prerelease_mode.to_string()),
                 );
             }
+            if !self.options.prerelease_package.is_empty() {
+                let prerelease_package = self
+                    .options
+                    .prerelease_package
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Array>();
+                options_table.insert("prerelease-package", value(prerelease_package));
+            }
             if self.options.fork_strategy != ForkStrategy::default() {
                 options_table.insert(
                     "fork-strategy",
@@ -807,6 +847,13 @@ impl Lock {
             if let Some(exclude_newer) = self.options.exclude_newer {
                 options_table.insert("exclude-newer", value(exclude_newer.to_string()));
             }
+            if !self.options.exclude_newer_package.is_empty() {
+                let mut table = InlineTable::new();
+                for (package, exclude_newer) in self.options.exclude_newer_package.iter() {
+                    table.insert(package.as_ref(), Value::from(exclude_newer.to_string()));
+                }
+                options_table.insert("exclude-newer-package", value(table));
+            }
 
             if !options_table.is_empty() {
                 doc.insert("options", Item::Table(options_table));
@@ -1689,14 +1736,23 @@ struct ResolverOptions {
     /// The [`ResolutionMode`] used to generate this lock.
     #[serde(default)]
     resolution_mode: ResolutionMode,
+    /// The [`ResolutionPackage`] used to generate this lock.
+    #[serde(default)]
+    resolution_package: ResolutionPackage,
     /// The [`PrereleaseMode`] used to generate this lock.
     #[serde(default)]
     prerelease_mode: PrereleaseMode,
+    /// The packages that were allowed to have pre-release versions when generating this lock.
+    #[serde(default)]
+    prerelease_package: BTreeSet<PackageName>,
     /// The [`ForkStrategy`] used to generate this lock.
     #[serde(default)]
     fork_strategy: ForkStrategy,
     /// The [`ExcludeNewer`] used to generate this lock.
     exclude_newer: Option<ExcludeNewer>,
+    /// The [`ExcludeNewerPackage`] used to generate this lock.
+    #[serde(default)]
+    exclude_newer_package: ExcludeNewerPackage,
 }
 
 #[derive(Clone, Debug, Default, serde::Deserialize, PartialEq, Eq)]
@@ -3785,6 +3841,7 @@ impl From<GitReference> for GitSourceKind {
             GitReference::BranchOrTag(rev) => GitSourceKind::Rev(rev.to_string()),
             GitReference::BranchOrTagOrCommit(rev) => GitSourceKind::Rev(rev.to_string()),
             GitReference::NamedRef(rev) => GitSourceKind::Rev(rev.to_string()),
+            GitReference::VersionRange(rev) => GitSourceKind::Rev(rev.clone()),
             GitReference::DefaultBranch => GitSourceKind::DefaultBranch,
         }
     }
@@ -3834,7 +3891,8 @@ fn locked_git_url(git_dist: &GitSourceDist) -> Url {
         }
         GitReference::BranchOrTag(rev)
         | GitReference::BranchOrTagOrCommit(rev)
-        | GitReference::NamedRef(rev) => {
+        | GitReference::NamedRef(rev)
+        | GitReference::VersionRange(rev) => {
             url.query_pairs_mut().append_pair("rev", rev.as_str());
         }
         GitReference::DefaultBranch => {}
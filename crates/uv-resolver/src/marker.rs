@@ -49,6 +49,11 @@ pub(crate) fn requires_python(tree: MarkerTree) -> Option<RequiresPythonRange> {
                     collect_python_markers(tree, markers, range);
                 }
             }
+            MarkerTreeKind::Feature(marker) => {
+                for (_, tree) in marker.children() {
+                    collect_python_markers(tree, markers, range);
+                }
+            }
             MarkerTreeKind::Extra(marker) => {
                 for (_, tree) in marker.children() {
                     collect_python_markers(tree, markers, range);
@@ -1,34 +1,71 @@
+use std::time::Duration;
+
 use crate::fork_strategy::ForkStrategy;
-use crate::{DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionMode};
+use crate::{
+    AllowedPackages, DependencyMode, ExcludeNewer, ExcludeNewerPackage, InvalidMetadataPolicy,
+    LocalVersionPreference, PrereleaseMode, Providers, ResolutionMode, ResolutionPackage,
+    SourcePolicies, WheelPreference, YankedPolicy,
+};
 use uv_configuration::{BuildOptions, IndexStrategy};
+use uv_normalize::PackageName;
 use uv_pypi_types::SupportedEnvironments;
 
 /// Options for resolving a manifest.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Options {
     pub resolution_mode: ResolutionMode,
+    pub resolution_package: ResolutionPackage,
     pub prerelease_mode: PrereleaseMode,
+    pub prerelease_package: Vec<PackageName>,
     pub dependency_mode: DependencyMode,
     pub fork_strategy: ForkStrategy,
     pub exclude_newer: Option<ExcludeNewer>,
+    pub exclude_newer_package: ExcludeNewerPackage,
+    pub allowed_packages: Option<AllowedPackages>,
     pub index_strategy: IndexStrategy,
+    pub invalid_metadata: InvalidMetadataPolicy,
+    pub yanked: YankedPolicy,
+    pub wheel_preference: Vec<WheelPreference>,
+    pub local_version_preference: Vec<LocalVersionPreference>,
+    pub resolution_timeout: Option<Duration>,
+    pub max_backtracks: Option<usize>,
+    pub source_policies: SourcePolicies,
+    pub providers: Providers,
     pub required_environments: SupportedEnvironments,
     pub flexibility: Flexibility,
     pub build_options: BuildOptions,
+    pub minimal_changes: bool,
+    pub derivation_tree: bool,
+    pub only_hashed: bool,
 }
 
 /// Builder for [`Options`].
 #[derive(Debug, Default, Clone)]
 pub struct OptionsBuilder {
     resolution_mode: ResolutionMode,
+    resolution_package: ResolutionPackage,
     prerelease_mode: PrereleaseMode,
+    prerelease_package: Vec<PackageName>,
     dependency_mode: DependencyMode,
     fork_strategy: ForkStrategy,
     exclude_newer: Option<ExcludeNewer>,
+    exclude_newer_package: ExcludeNewerPackage,
+    allowed_packages: Option<AllowedPackages>,
     index_strategy: IndexStrategy,
+    invalid_metadata: InvalidMetadataPolicy,
+    yanked: YankedPolicy,
+    wheel_preference: Vec<WheelPreference>,
+    local_version_preference: Vec<LocalVersionPreference>,
+    resolution_timeout: Option<Duration>,
+    max_backtracks: Option<usize>,
+    source_policies: SourcePolicies,
+    providers: Providers,
     required_environments: SupportedEnvironments,
     flexibility: Flexibility,
     build_options: BuildOptions,
+    minimal_changes: bool,
+    derivation_tree: bool,
+    only_hashed: bool,
 }
 
 impl OptionsBuilder {
@@ -44,6 +81,13 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the per-package [`ResolutionMode`] overrides.
+    #[must_use]
+    pub fn resolution_package(mut self, resolution_package: ResolutionPackage) -> Self {
+        self.resolution_package = resolution_package;
+        self
+    }
+
     /// Sets the [`PrereleaseMode`].
     #[must_use]
     pub fn prerelease_mode(mut self, prerelease_mode: PrereleaseMode) -> Self {
@@ -51,6 +95,14 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the packages that are allowed to have pre-release versions, regardless of the
+    /// [`PrereleaseMode`].
+    #[must_use]
+    pub fn prerelease_package(mut self, prerelease_package: Vec<PackageName>) -> Self {
+        self.prerelease_package = prerelease_package;
+        self
+    }
+
     /// Sets the dependency mode.
     #[must_use]
     pub fn dependency_mode(mut self, dependency_mode: DependencyMode) -> Self {
@@ -72,6 +124,20 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the per-package exclusion dates.
+    #[must_use]
+    pub fn exclude_newer_package(mut self, exclude_newer_package: ExcludeNewerPackage) -> Self {
+        self.exclude_newer_package = exclude_newer_package;
+        self
+    }
+
+    /// Sets the [`AllowedPackages`].
+    #[must_use]
+    pub fn allowed_packages(mut self, allowed_packages: Option<AllowedPackages>) -> Self {
+        self.allowed_packages = allowed_packages;
+        self
+    }
+
     /// Sets the index strategy.
     #[must_use]
     pub fn index_strategy(mut self, index_strategy: IndexStrategy) -> Self {
@@ -79,6 +145,66 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the [`InvalidMetadataPolicy`].
+    #[must_use]
+    pub fn invalid_metadata(mut self, invalid_metadata: InvalidMetadataPolicy) -> Self {
+        self.invalid_metadata = invalid_metadata;
+        self
+    }
+
+    /// Sets the [`YankedPolicy`].
+    #[must_use]
+    pub fn yanked(mut self, yanked: YankedPolicy) -> Self {
+        self.yanked = yanked;
+        self
+    }
+
+    /// Sets the [`WheelPreference`]s, in order of precedence.
+    #[must_use]
+    pub fn wheel_preference(mut self, wheel_preference: Vec<WheelPreference>) -> Self {
+        self.wheel_preference = wheel_preference;
+        self
+    }
+
+    /// Sets the [`LocalVersionPreference`]s, in order of precedence.
+    #[must_use]
+    pub fn local_version_preference(
+        mut self,
+        local_version_preference: Vec<LocalVersionPreference>,
+    ) -> Self {
+        self.local_version_preference = local_version_preference;
+        self
+    }
+
+    /// Sets the maximum duration of the resolution, after which it is aborted.
+    #[must_use]
+    pub fn resolution_timeout(mut self, resolution_timeout: Option<Duration>) -> Self {
+        self.resolution_timeout = resolution_timeout;
+        self
+    }
+
+    /// Sets the maximum number of backtracking steps (i.e., conflicts) in a fork, after which the
+    /// resolution is aborted.
+    #[must_use]
+    pub fn max_backtracks(mut self, max_backtracks: Option<usize>) -> Self {
+        self.max_backtracks = max_backtracks;
+        self
+    }
+
+    /// Sets the [`SourcePolicies`].
+    #[must_use]
+    pub fn source_policies(mut self, source_policies: SourcePolicies) -> Self {
+        self.source_policies = source_policies;
+        self
+    }
+
+    /// Sets the [`Providers`].
+    #[must_use]
+    pub fn providers(mut self, providers: Providers) -> Self {
+        self.providers = providers;
+        self
+    }
+
     /// Sets the required platforms.
     #[must_use]
     pub fn required_environments(mut self, required_environments: SupportedEnvironments) -> Self {
@@ -100,18 +226,54 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets whether to minimize changes to previously pinned versions.
+    #[must_use]
+    pub fn minimal_changes(mut self, minimal_changes: bool) -> Self {
+        self.minimal_changes = minimal_changes;
+        self
+    }
+
+    /// Sets whether to include the derivation tree in resolution failure reports.
+    #[must_use]
+    pub fn derivation_tree(mut self, derivation_tree: bool) -> Self {
+        self.derivation_tree = derivation_tree;
+        self
+    }
+
+    /// Sets whether to reject distributions for which the index doesn't provide a hash.
+    #[must_use]
+    pub fn only_hashed(mut self, only_hashed: bool) -> Self {
+        self.only_hashed = only_hashed;
+        self
+    }
+
     /// Builds the options.
     pub fn build(self) -> Options {
         Options {
             resolution_mode: self.resolution_mode,
+            resolution_package: self.resolution_package,
             prerelease_mode: self.prerelease_mode,
+            prerelease_package: self.prerelease_package,
             dependency_mode: self.dependency_mode,
             fork_strategy: self.fork_strategy,
             exclude_newer: self.exclude_newer,
+            exclude_newer_package: self.exclude_newer_package,
+            allowed_packages: self.allowed_packages,
             index_strategy: self.index_strategy,
+            invalid_metadata: self.invalid_metadata,
+            yanked: self.yanked,
+            wheel_preference: self.wheel_preference,
+            local_version_preference: self.local_version_preference,
+            resolution_timeout: self.resolution_timeout,
+            max_backtracks: self.max_backtracks,
+            source_policies: self.source_policies,
+            providers: self.providers,
             required_environments: self.required_environments,
             flexibility: self.flexibility,
             build_options: self.build_options,
+            minimal_changes: self.minimal_changes,
+            derivation_tree: self.derivation_tree,
+            only_hashed: self.only_hashed,
         }
     }
 }
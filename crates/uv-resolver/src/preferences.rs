@@ -93,6 +93,18 @@ impl Preference {
         }))
     }
 
+    /// Create a [`Preference`] from a decision recorded in a resolver checkpoint.
+    pub(crate) fn from_checkpoint(name: PackageName, version: Version) -> Self {
+        Self {
+            name,
+            version,
+            marker: MarkerTree::TRUE,
+            index: PreferenceIndex::Any,
+            fork_markers: vec![],
+            hashes: HashDigests::empty(),
+        }
+    }
+
     /// Return the [`PackageName`] of the package for this [`Preference`].
     pub fn name(&self) -> &PackageName {
         &self.name
@@ -1,3 +1,5 @@
+use rustc_hash::FxHashSet;
+
 use uv_pypi_types::RequirementSource;
 
 use crate::resolver::ForkSet;
@@ -45,7 +47,16 @@ impl std::fmt::Display for PrereleaseMode {
 /// Like [`PrereleaseMode`], but with any additional information required to select a candidate,
 /// like the set of direct dependencies.
 #[derive(Debug, Clone)]
-pub(crate) enum PrereleaseStrategy {
+pub(crate) struct PrereleaseStrategy {
+    /// The strategy for packages that aren't explicitly allowed to have pre-release versions.
+    mode: PrereleaseModeStrategy,
+    /// The packages that are allowed to have pre-release versions, regardless of the mode.
+    packages: FxHashSet<PackageName>,
+}
+
+/// The strategy derived from the global [`PrereleaseMode`].
+#[derive(Debug, Clone)]
+enum PrereleaseModeStrategy {
     /// Disallow all pre-release versions.
     Disallow,
 
@@ -66,6 +77,33 @@ pub(crate) enum PrereleaseStrategy {
 
 impl PrereleaseStrategy {
     pub(crate) fn from_mode(
+        mode: PrereleaseMode,
+        packages: &[PackageName],
+        manifest: &Manifest,
+        env: &ResolverEnvironment,
+        dependencies: DependencyMode,
+    ) -> Self {
+        Self {
+            mode: PrereleaseModeStrategy::from_mode(mode, manifest, env, dependencies),
+            packages: packages.iter().cloned().collect(),
+        }
+    }
+
+    /// Returns `true` if a [`PackageName`] is allowed to have pre-release versions.
+    pub(crate) fn allows(
+        &self,
+        package_name: &PackageName,
+        env: &ResolverEnvironment,
+    ) -> AllowPrerelease {
+        if self.packages.contains(package_name) {
+            return AllowPrerelease::Yes;
+        }
+        self.mode.allows(package_name, env)
+    }
+}
+
+impl PrereleaseModeStrategy {
+    fn from_mode(
         mode: PrereleaseMode,
         manifest: &Manifest,
         env: &ResolverEnvironment,
@@ -104,23 +142,19 @@ impl PrereleaseStrategy {
     }
 
     /// Returns `true` if a [`PackageName`] is allowed to have pre-release versions.
-    pub(crate) fn allows(
-        &self,
-        package_name: &PackageName,
-        env: &ResolverEnvironment,
-    ) -> AllowPrerelease {
+    fn allows(&self, package_name: &PackageName, env: &ResolverEnvironment) -> AllowPrerelease {
         match self {
-            PrereleaseStrategy::Disallow => AllowPrerelease::No,
-            PrereleaseStrategy::Allow => AllowPrerelease::Yes,
-            PrereleaseStrategy::IfNecessary => AllowPrerelease::IfNecessary,
-            PrereleaseStrategy::Explicit(packages) => {
+            PrereleaseModeStrategy::Disallow => AllowPrerelease::No,
+            PrereleaseModeStrategy::Allow => AllowPrerelease::Yes,
+            PrereleaseModeStrategy::IfNecessary => AllowPrerelease::IfNecessary,
+            PrereleaseModeStrategy::Explicit(packages) => {
                 if packages.contains(package_name, env) {
                     AllowPrerelease::Yes
                 } else {
                     AllowPrerelease::No
                 }
             }
-            PrereleaseStrategy::IfNecessaryOrExplicit(packages) => {
+            PrereleaseModeStrategy::IfNecessaryOrExplicit(packages) => {
                 if packages.contains(package_name, env) {
                     AllowPrerelease::Yes
                 } else {
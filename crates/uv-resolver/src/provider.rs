@@ -0,0 +1,314 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use rustc_hash::FxHashSet;
+use tracing::debug;
+
+use uv_configuration::{Constraints, Overrides};
+use uv_normalize::{InvalidNameError, PackageName};
+use uv_pep440::VersionSpecifiers;
+use uv_pypi_types::{Requirement, RequirementSource};
+use uv_warnings::warn_user_once;
+
+use crate::ResolveError;
+
+/// A declaration that requirements on a (virtual or renamed) package are satisfied by another
+/// package, e.g., `opencv-python=opencv-python-headless` or `pil=pillow`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct PackageProvider {
+    pub package: PackageName,
+    pub provider: PackageName,
+}
+
+impl FromStr for PackageProvider {
+    type Err = PackageProviderError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((package, provider)) = s.split_once('=') else {
+            return Err(PackageProviderError::MissingSeparator(s.to_string()));
+        };
+        let package = PackageName::from_str(package.trim())?;
+        let provider = PackageName::from_str(provider.trim())?;
+        if package == provider {
+            return Err(PackageProviderError::SelfProvided(package));
+        }
+        Ok(Self { package, provider })
+    }
+}
+
+impl TryFrom<String> for PackageProvider {
+    type Error = PackageProviderError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_str(&value)
+    }
+}
+
+impl From<PackageProvider> for String {
+    fn from(value: PackageProvider) -> Self {
+        value.to_string()
+    }
+}
+
+impl Display for PackageProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.package, self.provider)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for PackageProvider {
+    fn schema_name() -> String {
+        "PackageProvider".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some("A package that is provided by another package, in the form `<package>=<provider>`.".to_string()),
+                ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PackageProviderError {
+    #[error("Expected a provider of the form `<package>=<provider>`, but found: `{0}`")]
+    MissingSeparator(String),
+    #[error(transparent)]
+    InvalidName(#[from] InvalidNameError),
+    #[error("Package `{0}` cannot be provided by itself")]
+    SelfProvided(PackageName),
+}
+
+/// The provider for each package that is declared as provided by another package.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Providers(BTreeMap<PackageName, PackageName>);
+
+impl Providers {
+    /// Returns the provider for the given package, if any.
+    pub fn get(&self, name: &PackageName) -> Option<&PackageName> {
+        self.0.get(name)
+    }
+
+    /// Returns `true` if no packages are provided by another package.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Retain only the providers that are already in the graph, i.e., that are named by one of
+    /// the given requirements.
+    ///
+    /// A requirement on a provided package is only satisfied by its provider if the provider is
+    /// installed regardless; otherwise, the provided package itself is resolved as usual.
+    pub(crate) fn retain_required<'a>(
+        &mut self,
+        requirements: impl IntoIterator<Item = &'a Requirement>,
+    ) {
+        let required = requirements
+            .into_iter()
+            .map(|requirement| &requirement.name)
+            .collect::<FxHashSet<_>>();
+        self.0.retain(|package, provider| {
+            if required.contains(provider) {
+                true
+            } else {
+                debug!("Ignoring provider `{provider}` for `{package}`, which is not required");
+                false
+            }
+        });
+    }
+
+    /// Return an error if any of the given requirements requests a provided package from a URL.
+    ///
+    /// Such requirements can't be replaced by a requirement on the provider without discarding
+    /// the URL.
+    pub(crate) fn validate<'a>(
+        &self,
+        requirements: impl IntoIterator<Item = &'a Requirement>,
+    ) -> Result<(), ResolveError> {
+        for requirement in requirements {
+            let Some(provider) = self.get(&requirement.name) else {
+                continue;
+            };
+            if !matches!(requirement.source, RequirementSource::Registry { .. }) {
+                return Err(ResolveError::ProvidedUrl(
+                    requirement.name.clone(),
+                    provider.clone(),
+                    requirement.to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Warn if any of the given constraints or overrides names a provided package.
+    ///
+    /// Requirements on a provided package are replaced by a requirement on its provider, with
+    /// any version specifier dropped, so a constraint or override on the provided package has no
+    /// effect.
+    pub(crate) fn warn_unapplied(&self, constraints: &Constraints, overrides: &Overrides) {
+        for (kind, requirement) in constraints
+            .requirements()
+            .map(|requirement| ("constraint", requirement))
+            .chain(
+                overrides
+                    .requirements()
+                    .map(|requirement| ("override", requirement)),
+            )
+        {
+            let Some(provider) = self.get(&requirement.name) else {
+                continue;
+            };
+            warn_user_once!(
+                "The {kind} `{requirement}` has no effect, as `{}` is provided by `{provider}`; apply the {kind} to `{provider}` instead",
+                requirement.name
+            );
+        }
+    }
+
+    /// Replace a requirement on a provided package with a requirement on its provider.
+    ///
+    /// Since the two packages are versioned independently, any version specifier on the original
+    /// requirement is dropped; the extras and markers are retained. Requirements with a URL are
+    /// rejected upfront (see [`Providers::validate`]).
+    pub(crate) fn apply<'a>(&self, requirement: Cow<'a, Requirement>) -> Cow<'a, Requirement> {
+        let Some(provider) = self.get(&requirement.name) else {
+            return requirement;
+        };
+        debug!(
+            "Replacing requirement `{requirement}` with `{provider}`, which provides `{}`",
+            requirement.name
+        );
+        let requirement = requirement.into_owned();
+        Cow::Owned(Requirement {
+            name: provider.clone(),
+            source: RequirementSource::Registry {
+                specifier: VersionSpecifiers::empty(),
+                index: None,
+                conflict: None,
+            },
+            ..requirement
+        })
+    }
+}
+
+impl FromIterator<PackageProvider> for Providers {
+    /// Collect the provider for each package, with later providers for the same package taking
+    /// precedence.
+    fn from_iter<T: IntoIterator<Item = PackageProvider>>(iter: T) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|PackageProvider { package, provider }| (package, provider))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::str::FromStr;
+
+    use uv_normalize::PackageName;
+    use uv_pypi_types::{Requirement, VerbatimParsedUrl};
+
+    use super::{PackageProvider, Providers};
+
+    fn requirement(s: &str) -> Requirement {
+        Requirement::from(uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(s).unwrap())
+    }
+
+    #[test]
+    fn parse() {
+        let provider = PackageProvider::from_str("OpenCV-Python = opencv-python-headless").unwrap();
+        assert_eq!(
+            provider.package,
+            PackageName::from_str("opencv-python").unwrap()
+        );
+        assert_eq!(
+            provider.provider,
+            PackageName::from_str("opencv-python-headless").unwrap()
+        );
+        assert_eq!(provider.to_string(), "opencv-python=opencv-python-headless");
+
+        let err = PackageProvider::from_str("pil").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Expected a provider of the form `<package>=<provider>`, but found: `pil`"
+        );
+
+        let err = PackageProvider::from_str("pil=PIL").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Package `pil` cannot be provided by itself"
+        );
+    }
+
+    #[test]
+    fn apply() {
+        let providers = [PackageProvider::from_str("pil=pillow").unwrap()]
+            .into_iter()
+            .collect::<Providers>();
+
+        let pil = requirement("PIL[extra]>=1.1.7 ; sys_platform == 'linux'");
+        let applied = providers.apply(Cow::Borrowed(&pil));
+        assert_eq!(
+            applied.to_string(),
+            "pillow[extra] ; sys_platform == 'linux'"
+        );
+
+        let numpy = requirement("numpy>=2");
+        let applied = providers.apply(Cow::Borrowed(&numpy));
+        assert!(matches!(applied, Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn retain_required() {
+        let mut providers = [
+            PackageProvider::from_str("pil=pillow").unwrap(),
+            PackageProvider::from_str("opencv-python=opencv-python-headless").unwrap(),
+        ]
+        .into_iter()
+        .collect::<Providers>();
+
+        // Only `pillow` is already in the graph.
+        let requirements = [requirement("pillow>=10"), requirement("opencv-python")];
+        providers.retain_required(&requirements);
+        assert_eq!(
+            providers.get(&PackageName::from_str("pil").unwrap()),
+            Some(&PackageName::from_str("pillow").unwrap())
+        );
+        assert_eq!(
+            providers.get(&PackageName::from_str("opencv-python").unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn validate() {
+        let providers = [PackageProvider::from_str("pil=pillow").unwrap()]
+            .into_iter()
+            .collect::<Providers>();
+
+        let requirements = [
+            requirement("pil>=1.1.7"),
+            requirement("numpy @ https://example.com/numpy-2.0.0.tar.gz"),
+        ];
+        assert!(providers.validate(&requirements).is_ok());
+
+        let requirements = [requirement("pil @ https://example.com/PIL-1.1.7.tar.gz")];
+        let err = providers.validate(&requirements).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Package `pil` is provided by `pillow`, and so can't be requested from a URL: `pil @ https://example.com/PIL-1.1.7.tar.gz`"
+        );
+    }
+}
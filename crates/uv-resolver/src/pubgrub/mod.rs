@@ -3,9 +3,11 @@ pub(crate) use crate::pubgrub::distribution::PubGrubDistribution;
 pub(crate) use crate::pubgrub::package::{PubGrubPackage, PubGrubPackageInner, PubGrubPython};
 pub(crate) use crate::pubgrub::priority::{PubGrubPriorities, PubGrubPriority, PubGrubTiebreaker};
 pub(crate) use crate::pubgrub::report::PubGrubReportFormatter;
+pub(crate) use crate::pubgrub::tree::{DerivationTreeFormatter, RequirementLocation};
 
 mod dependencies;
 mod distribution;
 mod package;
 mod priority;
 mod report;
+mod tree;
@@ -845,7 +845,11 @@ impl PubGrubReportFormatter<'_> {
                     reason: reason.clone(),
                 });
             }
-            Some(UnavailablePackage::NotFound) => {}
+            Some(
+                UnavailablePackage::NotFound
+                | UnavailablePackage::NotAllowed
+                | UnavailablePackage::SourcePolicy(..),
+            ) => {}
             None => {}
         }
 
@@ -0,0 +1,190 @@
+use std::fmt::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+use itertools::Itertools;
+use pubgrub::{DerivationTree, External, Range, ReportFormatter};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pep508::RequirementOrigin;
+
+use crate::error::ErrorTree;
+use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner, PubGrubReportFormatter};
+use crate::resolver::UnavailableReason;
+
+/// Renders a [`DerivationTree`] as an indented tree, from the final incompatibility down to the
+/// requirements and incompatibilities from which it was derived.
+///
+/// Unlike the prose report, which walks the tree to produce a linear explanation, each node is
+/// shown on its own line, and each direct requirement is annotated with the file (and line) that
+/// declared it.
+pub(crate) struct DerivationTreeFormatter<'a> {
+    /// The formatter used to describe each incompatibility.
+    formatter: &'a PubGrubReportFormatter<'a>,
+    /// The locations of the direct requirements, by package.
+    origins: &'a FxHashMap<PackageName, Vec<RequirementLocation>>,
+}
+
+impl<'a> DerivationTreeFormatter<'a> {
+    pub(crate) fn new(
+        formatter: &'a PubGrubReportFormatter<'a>,
+        origins: &'a FxHashMap<PackageName, Vec<RequirementLocation>>,
+    ) -> Self {
+        Self { formatter, origins }
+    }
+
+    /// Render the tree.
+    pub(crate) fn format(&self, tree: &ErrorTree) -> String {
+        let mut output = String::new();
+        let mut visited = FxHashSet::default();
+        self.format_node(tree, "", None, &mut visited, &mut output);
+        output
+    }
+
+    /// Render a single node, followed by its causes.
+    ///
+    /// `last` is `None` for the root of the tree, and otherwise indicates whether the node is the
+    /// last child of its parent.
+    fn format_node(
+        &self,
+        tree: &ErrorTree,
+        prefix: &str,
+        last: Option<bool>,
+        visited: &mut FxHashSet<usize>,
+        output: &mut String,
+    ) {
+        let (branch, indent) = match last {
+            None => ("", ""),
+            Some(false) => ("├── ", "│   "),
+            Some(true) => ("└── ", "    "),
+        };
+
+        match tree {
+            DerivationTree::External(external) => {
+                let description = self.formatter.format_external(external);
+                let _ = write!(output, "\n{prefix}{branch}{description}");
+                if let Some(origin) = self.format_origin(external) {
+                    let _ = write!(output, " (from {origin})");
+                }
+            }
+            DerivationTree::Derived(derived) => {
+                let description = self.formatter.format_terms(&derived.terms);
+                let _ = write!(output, "\n{prefix}{branch}{description}");
+
+                // Incompatibilities that are derived once and reused are only expanded once.
+                if let Some(id) = derived.shared_id {
+                    if !visited.insert(id) {
+                        output.push_str(" (see above)");
+                        return;
+                    }
+                }
+
+                let prefix = format!("{prefix}{indent}");
+                self.format_node(&derived.cause1, &prefix, Some(false), visited, output);
+                self.format_node(&derived.cause2, &prefix, Some(true), visited, output);
+            }
+        }
+    }
+
+    /// Format the origins of a direct requirement (e.g., `requirements.in`), if the
+    /// incompatibility describes one.
+    fn format_origin(
+        &self,
+        external: &External<PubGrubPackage, Range<Version>, UnavailableReason>,
+    ) -> Option<String> {
+        let External::FromDependencyOf(package, _, dependency, _) = external else {
+            return None;
+        };
+        if !matches!(&**package, PubGrubPackageInner::Root(_)) {
+            return None;
+        }
+        let origins = self.origins.get(dependency.name()?)?;
+        let origins = origins.iter().map(ToString::to_string).unique().join(", ");
+        if origins.is_empty() {
+            None
+        } else {
+            Some(origins)
+        }
+    }
+}
+
+/// The file, and if it could be found, the line, that declared a direct requirement.
+#[derive(Debug, Clone)]
+pub(crate) struct RequirementLocation {
+    origin: RequirementOrigin,
+    line: Option<usize>,
+}
+
+impl RequirementLocation {
+    /// Locate the declarations of the requirement on `name` within the given origin.
+    ///
+    /// Requirement origins don't record where in the file a requirement was declared, so the
+    /// file is scanned for lines that declare a requirement on the package. If none are found,
+    /// the location refers to the file as a whole.
+    pub(crate) fn locate(name: &PackageName, origin: &RequirementOrigin) -> Vec<Self> {
+        let lines = match origin {
+            RequirementOrigin::File(path) => find_lines(path, |line| {
+                leading_name(line.trim_start()).is_some_and(|leading| leading == *name)
+            }),
+            RequirementOrigin::Project(path, _) => find_lines(path, |line| {
+                line.match_indices(['"', '\'']).any(|(index, quote)| {
+                    leading_name(&line[index + quote.len()..])
+                        .is_some_and(|leading| leading == *name)
+                })
+            }),
+            RequirementOrigin::Workspace => Vec::new(),
+        };
+        if lines.is_empty() {
+            return vec![Self {
+                origin: origin.clone(),
+                line: None,
+            }];
+        }
+        lines
+            .into_iter()
+            .map(|line| Self {
+                origin: origin.clone(),
+                line: Some(line),
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Display for RequirementLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.origin {
+            RequirementOrigin::File(path) | RequirementOrigin::Project(path, _) => {
+                if let Some(line) = self.line {
+                    write!(f, "`{}:{line}`", path.user_display())
+                } else {
+                    write!(f, "`{}`", path.user_display())
+                }
+            }
+            RequirementOrigin::Workspace => write!(f, "the workspace"),
+        }
+    }
+}
+
+/// Return the (one-based) numbers of the lines in the file that match the predicate.
+fn find_lines(path: &Path, predicate: impl Fn(&str) -> bool) -> Vec<usize> {
+    let Ok(contents) = fs_err::read_to_string(path) else {
+        return Vec::new();
+    };
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| predicate(line))
+        .map(|(index, _)| index + 1)
+        .collect()
+}
+
+/// Parse the package name at the start of a requirement, if any.
+fn leading_name(requirement: &str) -> Option<PackageName> {
+    let end = requirement
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        .unwrap_or(requirement.len());
+    PackageName::from_str(&requirement[..end]).ok()
+}
@@ -0,0 +1,122 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use uv_normalize::PackageName;
+use uv_pep440::{Operator, Version};
+use uv_pypi_types::RequirementSource;
+
+use crate::ResolverOutput;
+
+/// A direct requirement whose declared lower bound doesn't match the lowest version that could be
+/// resolved.
+///
+/// When resolving with `--resolution lowest` or `--resolution lowest-direct`, each direct
+/// requirement is resolved to the lowest version that's compatible with the rest of the
+/// resolution. If that version is higher than the declared lower bound, the lower bound is
+/// untested (e.g., because the older versions don't exist, don't support the target Python
+/// version, or conflict with other requirements), and is likely wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LowerBound {
+    /// The name of the direct requirement.
+    pub name: PackageName,
+    /// The declared lower bound, or `None` if no lower bound was declared.
+    pub declared: Option<Version>,
+    /// The lowest version that could be resolved.
+    pub resolved: Version,
+}
+
+impl LowerBound {
+    /// The requirement that would accurately reflect the lowest resolvable version.
+    pub fn suggestion(&self) -> String {
+        format!("{}>={}", self.name, self.resolved)
+    }
+}
+
+impl Display for LowerBound {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.declared {
+            Some(declared) => write!(
+                f,
+                "`{}>={declared}` is untested: the lowest compatible version is {}",
+                self.name, self.resolved
+            ),
+            None => write!(
+                f,
+                "`{}` has no lower bound: the lowest compatible version is {}",
+                self.name, self.resolved
+            ),
+        }
+    }
+}
+
+impl ResolverOutput {
+    /// Return the direct requirements whose declared lower bounds are lower than the versions
+    /// they resolved to.
+    ///
+    /// Only meaningful for resolutions that selected the lowest compatible version of each direct
+    /// requirement. Requirements with exclusive lower bounds (e.g., `>1.0`), and requirements that
+    /// aren't resolved from a registry, are ignored, as are any overridden requirements.
+    pub fn lower_bounds(&self) -> Vec<LowerBound> {
+        // Combine the specifiers across all requirements for each package, e.g., such that
+        // `anyio>=3` and `anyio<5` are treated as `anyio>=3,<5`.
+        let mut specifiers = BTreeMap::new();
+        for requirement in &self.requirements {
+            let RequirementSource::Registry { specifier, .. } = &requirement.source else {
+                continue;
+            };
+            if self.overrides.get(&requirement.name).is_some() {
+                continue;
+            }
+            specifiers
+                .entry(&requirement.name)
+                .or_insert_with(Vec::new)
+                .extend(specifier.iter());
+        }
+
+        let mut bounds = Vec::new();
+        for (name, specifiers) in specifiers {
+            // If the requirement is resolved to multiple versions (e.g., across forks), compare
+            // against the lowest.
+            let Some(resolved) = self
+                .dists()
+                .filter(|dist| dist.is_base() && dist.name == *name)
+                .map(|dist| &dist.version)
+                .min()
+            else {
+                continue;
+            };
+
+            // Exclusive lower bounds can't be compared without knowing the available versions.
+            if specifiers.iter().any(|specifier| {
+                matches!(
+                    specifier.operator(),
+                    Operator::GreaterThan | Operator::EqualStar | Operator::ExactEqual
+                )
+            }) {
+                continue;
+            }
+
+            let declared = specifiers
+                .iter()
+                .filter(|specifier| {
+                    matches!(
+                        specifier.operator(),
+                        Operator::GreaterThanEqual | Operator::TildeEqual | Operator::Equal
+                    )
+                })
+                .map(|specifier| specifier.version())
+                .max();
+
+            if declared.is_some_and(|declared| declared >= resolved) {
+                continue;
+            }
+
+            bounds.push(LowerBound {
+                name: name.clone(),
+                declared: declared.cloned(),
+                resolved: resolved.clone(),
+            });
+        }
+        bounds
+    }
+}
@@ -0,0 +1,157 @@
+use std::fmt::Write;
+
+use petgraph::visit::EdgeRef;
+use petgraph::Direction;
+use serde::Serialize;
+
+use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_pep440::Version;
+
+use crate::requires_python::SimplifiedMarkerTree;
+use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
+use crate::ResolverOutput;
+
+/// The resolved dependency graph, in a form suitable for visualization and policy tooling.
+///
+/// Extras and dependency groups are represented as separate nodes, with an edge from each to their
+/// base package.
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyGraph {
+    nodes: Vec<DependencyGraphNode>,
+    edges: Vec<DependencyGraphEdge>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+struct DependencyGraphNode {
+    /// A unique identifier for the node (e.g., `flask[dotenv]==3.0.0`).
+    id: String,
+    name: PackageName,
+    version: Version,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    extra: Option<ExtraName>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<GroupName>,
+    /// Whether the package was requested directly.
+    direct: bool,
+    /// The environments in which the package is installed, if not all of them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+struct DependencyGraphEdge {
+    from: String,
+    to: String,
+    /// The environments in which the dependency applies, if not all of them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<String>,
+}
+
+impl DependencyGraph {
+    /// Create a [`DependencyGraph`] for the given resolution, omitting the given packages.
+    pub fn from_resolution(resolution: &ResolverOutput, no_emit_packages: &[PackageName]) -> Self {
+        let graph = &resolution.graph;
+        let requires_python = &resolution.requires_python;
+
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        for index in graph.node_indices() {
+            let Some(dist) = emitted(&graph[index], no_emit_packages) else {
+                continue;
+            };
+
+            let direct = graph
+                .edges_directed(index, Direction::Incoming)
+                .any(|edge| matches!(graph[edge.source()], ResolutionGraphNode::Root));
+
+            nodes.push(DependencyGraphNode {
+                id: node_id(dist),
+                name: dist.name.clone(),
+                version: dist.version.clone(),
+                extra: dist.extra.clone(),
+                group: dist.dev.clone(),
+                direct,
+                marker: SimplifiedMarkerTree::new(requires_python, dist.marker.pep508())
+                    .try_to_string(),
+            });
+
+            for edge in graph.edges_directed(index, Direction::Outgoing) {
+                let Some(target) = emitted(&graph[edge.target()], no_emit_packages) else {
+                    continue;
+                };
+                edges.push(DependencyGraphEdge {
+                    from: node_id(dist),
+                    to: node_id(target),
+                    marker: SimplifiedMarkerTree::new(requires_python, edge.weight().pep508())
+                        .try_to_string(),
+                });
+            }
+        }
+
+        nodes.sort_unstable();
+        edges.sort_unstable();
+
+        Self { nodes, edges }
+    }
+
+    /// Render the graph in the Graphviz DOT language.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+        for node in &self.nodes {
+            let mut label = node.id.clone();
+            if let Some(marker) = &node.marker {
+                write!(label, "\n{marker}").unwrap();
+            }
+            write!(dot, "    {} [label={}", quote(&node.id), quote(&label)).unwrap();
+            if node.direct {
+                dot.push_str(", penwidth=2");
+            }
+            dot.push_str("];\n");
+        }
+        for edge in &self.edges {
+            write!(dot, "    {} -> {}", quote(&edge.from), quote(&edge.to)).unwrap();
+            if let Some(marker) = &edge.marker {
+                write!(dot, " [label={}]", quote(marker)).unwrap();
+            }
+            dot.push_str(";\n");
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Return the distribution for the given node, unless it's the root or omitted from the output.
+fn emitted<'a>(
+    node: &'a ResolutionGraphNode,
+    no_emit_packages: &[PackageName],
+) -> Option<&'a AnnotatedDist> {
+    match node {
+        ResolutionGraphNode::Root => None,
+        ResolutionGraphNode::Dist(dist) => (!no_emit_packages.contains(&dist.name)).then_some(dist),
+    }
+}
+
+/// Return a unique identifier for the given node (e.g., `flask[dotenv]==3.0.0` or
+/// `project:dev==0.1.0`).
+fn node_id(dist: &AnnotatedDist) -> String {
+    let mut id = dist.name.to_string();
+    if let Some(extra) = &dist.extra {
+        write!(id, "[{extra}]").unwrap();
+    }
+    if let Some(group) = &dist.dev {
+        write!(id, ":{group}").unwrap();
+    }
+    write!(id, "=={}", dist.version).unwrap();
+    id
+}
+
+/// Quote a string as a DOT identifier.
+fn quote(value: &str) -> String {
+    format!(
+        "\"{}\"",
+        value
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+    )
+}
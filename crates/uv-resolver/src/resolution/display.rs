@@ -33,6 +33,8 @@ pub struct DisplayResolutionGraph<'a> {
     include_annotations: bool,
     /// Whether to include indexes in the output, to indicate which index was used for each package.
     include_index_annotation: bool,
+    /// Whether to write pinned versions in canonical form (e.g., `==1.0.0.0` as `==1`).
+    normalize_specifiers: bool,
     /// The style of annotation comments, used to indicate the dependencies that requested each
     /// package.
     annotation_style: AnnotationStyle,
@@ -61,6 +63,7 @@ impl<'a> DisplayResolutionGraph<'a> {
         include_markers: bool,
         include_annotations: bool,
         include_index_annotation: bool,
+        normalize_specifiers: bool,
         annotation_style: AnnotationStyle,
     ) -> DisplayResolutionGraph<'a> {
         for fork_marker in &underlying.fork_markers {
@@ -79,6 +82,7 @@ impl<'a> DisplayResolutionGraph<'a> {
             include_markers,
             include_annotations,
             include_index_annotation,
+            normalize_specifiers,
             annotation_style,
         }
     }
@@ -139,6 +143,34 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
             SourceAnnotations::default()
         };
 
+        // Determine the provided packages that each package required, such that the dependents of
+        // a provider can be annotated accordingly (e.g., `# via app (provides opencv-python)`).
+        let mut provided: FxHashMap<(&PackageName, &PackageName), BTreeSet<&PackageName>> =
+            FxHashMap::default();
+        if self.include_annotations && !self.resolution.options.providers.is_empty() {
+            for node in self.resolution.graph.node_weights() {
+                let ResolutionGraphNode::Dist(dist) = node else {
+                    continue;
+                };
+                let Some(metadata) = dist.metadata.as_ref() else {
+                    continue;
+                };
+                for requirement in metadata
+                    .requires_dist
+                    .iter()
+                    .chain(metadata.dependency_groups.values().flatten())
+                {
+                    if let Some(provider) = self.resolution.options.providers.get(&requirement.name)
+                    {
+                        provided
+                            .entry((&dist.name, provider))
+                            .or_default()
+                            .insert(&requirement.name);
+                    }
+                }
+            }
+        }
+
         // Convert a [`petgraph::graph::Graph`] based on [`ResolutionGraphNode`] to a graph based on
         // [`DisplayResolutionGraphNode`]. In other words: converts from [`AnnotatedDist`] to
         // [`RequirementsTxtDist`].
@@ -187,7 +219,11 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
         for (index, node) in nodes {
             // Display the node itself.
             let mut line = node
-                .to_requirements_txt(&self.resolution.requires_python, self.include_markers)
+                .to_requirements_txt(
+                    &self.resolution.requires_python,
+                    self.include_markers,
+                    self.normalize_specifiers,
+                )
                 .to_string();
 
             // Display the distribution hashes, if any.
@@ -217,6 +253,19 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                     dependents.sort_unstable();
                     dependents.dedup();
                     dependents
+                        .into_iter()
+                        .map(|dependent| match provided.get(&(dependent, node.name())) {
+                            Some(packages) => format!(
+                                "{dependent} (provides {})",
+                                packages
+                                    .iter()
+                                    .map(ToString::to_string)
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            ),
+                            None => dependent.to_string(),
+                        })
+                        .collect::<Vec<_>>()
                 };
 
                 // Include all external sources (e.g., requirements files).
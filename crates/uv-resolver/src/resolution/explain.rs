@@ -0,0 +1,319 @@
+use std::collections::BTreeMap;
+
+use owo_colors::OwoColorize;
+use petgraph::Direction;
+
+use uv_distribution_types::{IndexUrl, PrioritizedDist};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pypi_types::{Requirement, RequirementSource};
+
+use crate::candidate_selector::CandidateDist;
+use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
+use crate::{InMemoryIndex, ResolutionMode, ResolverOutput, VersionsResponse};
+
+/// The maximum number of rejected candidates to list for each package.
+const MAX_REJECTED: usize = 5;
+
+/// A [`std::fmt::Display`] implementation that explains why each package in a resolution was
+/// pinned to its selected version.
+///
+/// For each package, lists the requirements (and any constraints or overrides) that requested it,
+/// along with the versions that were preferred by the resolution strategy (i.e., newer versions,
+/// or older versions under `--resolution lowest`) but rejected, and why. Any dependency cycles
+/// between the packages are listed at the end.
+pub struct DisplayResolutionExplanation<'a> {
+    /// The underlying resolution.
+    resolution: &'a ResolverOutput,
+    /// The index used during resolution, which retains the available versions of each package.
+    index: &'a InMemoryIndex,
+}
+
+/// A requirement that constrained the selection of a package.
+#[derive(Debug)]
+struct Requester<'a> {
+    /// The package that declared the requirement, or `None` for the input requirements.
+    parent: Option<(&'a PackageName, &'a Version)>,
+    /// The requirement itself.
+    requirement: &'a Requirement,
+    /// The kind of requirement.
+    kind: RequesterKind,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum RequesterKind {
+    Override,
+    Requirement,
+    Constraint,
+}
+
+impl<'a> DisplayResolutionExplanation<'a> {
+    /// Create a new [`DisplayResolutionExplanation`] for the given resolution.
+    pub fn new(resolution: &'a ResolverOutput, index: &'a InMemoryIndex) -> Self {
+        Self { resolution, index }
+    }
+
+    /// Collect the requirements that requested the given package, across all of its nodes (e.g.,
+    /// including any extras).
+    fn requesters(&self, name: &'a PackageName, version: &Version) -> Vec<Requester<'a>> {
+        let graph = &self.resolution.graph;
+        let mut requesters = Vec::new();
+
+        // Overrides replace any other requirements, so they're listed first.
+        for requirement in self.resolution.overrides.get(name).into_iter().flatten() {
+            requesters.push(Requester {
+                parent: None,
+                requirement,
+                kind: RequesterKind::Override,
+            });
+        }
+
+        for index in graph.node_indices() {
+            let ResolutionGraphNode::Dist(dist) = &graph[index] else {
+                continue;
+            };
+            if dist.name != *name || dist.version != *version {
+                continue;
+            }
+            for parent in graph.neighbors_directed(index, Direction::Incoming) {
+                match &graph[parent] {
+                    ResolutionGraphNode::Root => {
+                        for requirement in &self.resolution.requirements {
+                            if requirement.name == *name
+                                && !requesters.iter().any(|requester: &Requester| {
+                                    std::ptr::eq(requester.requirement, requirement)
+                                })
+                            {
+                                requesters.push(Requester {
+                                    parent: None,
+                                    requirement,
+                                    kind: RequesterKind::Requirement,
+                                });
+                            }
+                        }
+                    }
+                    ResolutionGraphNode::Dist(parent) => {
+                        let Some(metadata) = parent.metadata.as_ref() else {
+                            continue;
+                        };
+                        for requirement in metadata
+                            .requires_dist
+                            .iter()
+                            .chain(metadata.dependency_groups.values().flatten())
+                        {
+                            if requirement.name == *name
+                                && !requesters.iter().any(|requester: &Requester| {
+                                    std::ptr::eq(requester.requirement, requirement)
+                                })
+                            {
+                                requesters.push(Requester {
+                                    parent: Some((&parent.name, &parent.version)),
+                                    requirement,
+                                    kind: RequesterKind::Requirement,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        for requirement in self.resolution.constraints.get(name).into_iter().flatten() {
+            requesters.push(Requester {
+                parent: None,
+                requirement,
+                kind: RequesterKind::Constraint,
+            });
+        }
+
+        // Sort the requesters for deterministic output: overrides, then the input requirements,
+        // then the requirements of other packages, then constraints.
+        requesters.sort_by(|a, b| {
+            a.kind
+                .cmp(&b.kind)
+                .then_with(|| a.parent.cmp(&b.parent))
+                .then_with(|| a.requirement.to_string().cmp(&b.requirement.to_string()))
+        });
+
+        requesters
+    }
+
+    /// Determine why the given candidate version wasn't selected.
+    fn rejection(
+        requesters: &[Requester<'_>],
+        selected: &Version,
+        version: &Version,
+        prioritized: &PrioritizedDist,
+    ) -> String {
+        // If an override applies, only the override is considered.
+        let overridden = requesters
+            .iter()
+            .any(|requester| requester.kind == RequesterKind::Override);
+        for requester in requesters {
+            if overridden && requester.kind != RequesterKind::Override {
+                continue;
+            }
+            let RequirementSource::Registry { specifier, .. } = &requester.requirement.source
+            else {
+                continue;
+            };
+            if !specifier.contains(version) {
+                let source = match (requester.kind, requester.parent) {
+                    (RequesterKind::Override, _) => "an override".to_string(),
+                    (RequesterKind::Constraint, _) => "a constraint".to_string(),
+                    (RequesterKind::Requirement, None) => "the input requirements".to_string(),
+                    (RequesterKind::Requirement, Some((name, version))) => {
+                        format!("{name}=={version}")
+                    }
+                };
+                return format!("excluded by `{specifier}` (from {source})");
+            }
+        }
+
+        if let CandidateDist::Incompatible(incompatibility) = CandidateDist::from(prioritized) {
+            return incompatibility.singular_message();
+        }
+
+        if version.any_prerelease() && !selected.any_prerelease() {
+            return "is a pre-release".to_string();
+        }
+
+        "is incompatible with other requirements in the resolution".to_string()
+    }
+
+    /// Returns `true` if lower versions of the given package are preferred over higher versions,
+    /// per the resolution strategy.
+    fn prefers_lowest(&self, name: &PackageName, requesters: &[Requester<'_>]) -> bool {
+        let options = &self.resolution.options;
+        match options
+            .resolution_package
+            .get(name)
+            .unwrap_or(options.resolution_mode)
+        {
+            ResolutionMode::Highest => false,
+            ResolutionMode::Lowest => true,
+            ResolutionMode::LowestDirect => requesters.iter().any(|requester| {
+                requester.kind == RequesterKind::Requirement && requester.parent.is_none()
+            }),
+        }
+    }
+
+    /// Return the versions of the given package that were available during resolution.
+    fn available(&self, dist: &AnnotatedDist) -> BTreeMap<Version, PrioritizedDist> {
+        let mut available = BTreeMap::new();
+
+        let responses =
+            self.index
+                .implicit()
+                .get(&dist.name)
+                .into_iter()
+                .chain(dist.dist.index().and_then(|index: &IndexUrl| {
+                    self.index
+                        .explicit()
+                        .get(&(dist.name.clone(), index.clone()))
+                }));
+        for response in responses {
+            let VersionsResponse::Found(version_maps) = &*response else {
+                continue;
+            };
+            for version_map in version_maps {
+                for version in version_map.versions() {
+                    if let Some(prioritized) = version_map.get(version) {
+                        available
+                            .entry(version.clone())
+                            .or_insert_with(|| prioritized.clone());
+                    }
+                }
+            }
+        }
+
+        available
+    }
+}
+
+impl std::fmt::Display for DisplayResolutionExplanation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Collect each package-version pair, ignoring extras and groups.
+        let mut dists = self
+            .resolution
+            .graph
+            .node_weights()
+            .filter_map(|node| match node {
+                ResolutionGraphNode::Dist(dist) if dist.extra.is_none() && dist.dev.is_none() => {
+                    Some(dist)
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        dists.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.version.cmp(&b.version)));
+        dists.dedup_by(|a, b| a.name == b.name && a.version == b.version);
+
+        for dist in dists {
+            writeln!(f, "{}", format!("{}=={}", dist.name, dist.version).bold())?;
+
+            // Display the requirements that requested the package.
+            let requesters = self.requesters(&dist.name, &dist.version);
+            for requester in &requesters {
+                let source = match (requester.kind, requester.parent) {
+                    (RequesterKind::Override, _) => "override".to_string(),
+                    (RequesterKind::Constraint, _) => "constraint".to_string(),
+                    (RequesterKind::Requirement, None) => "requested".to_string(),
+                    (RequesterKind::Requirement, Some((name, version))) => {
+                        format!("required by {name}=={version}")
+                    }
+                };
+                writeln!(f, "    {source}: {}", requester.requirement)?;
+            }
+
+            // Display the versions that would have been preferred, in the order in which they'd
+            // have been tried, but were rejected.
+            let available = self.available(dist);
+            let lowest = self.prefers_lowest(&dist.name, &requesters);
+            let rejected = if lowest {
+                available
+                    .iter()
+                    .take_while(|(version, _)| **version < dist.version)
+                    .collect::<Vec<_>>()
+            } else {
+                available
+                    .iter()
+                    .rev()
+                    .take_while(|(version, _)| **version > dist.version)
+                    .collect::<Vec<_>>()
+            };
+            for (version, prioritized) in rejected.iter().take(MAX_REJECTED) {
+                writeln!(
+                    f,
+                    "    {} {version}: {}",
+                    "rejected".red(),
+                    Self::rejection(&requesters, &dist.version, version, prioritized)
+                )?;
+            }
+            if rejected.len() > MAX_REJECTED {
+                writeln!(
+                    f,
+                    "    ... and {} more {} versions",
+                    rejected.len() - MAX_REJECTED,
+                    if lowest { "older" } else { "newer" }
+                )?;
+            }
+        }
+
+        // Display any dependency cycles, which are valid, but can break downstream tools.
+        let cycles = self.resolution.cycles();
+        if !cycles.is_empty() {
+            writeln!(f, "{}", "Dependency cycles:".bold())?;
+            for cycle in cycles {
+                let path = cycle
+                    .iter()
+                    .chain(cycle.first())
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" -> ");
+                writeln!(f, "    {}: {path}", "cycle".yellow())?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,196 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use petgraph::visit::EdgeRef;
+use petgraph::Direction;
+use serde::Serialize;
+
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+use crate::resolution::ResolutionGraphNode;
+use crate::ResolverOutput;
+
+/// A snapshot of a resolution, in the format accepted by the GitHub Dependency Submission API.
+///
+/// See: <https://docs.github.com/en/rest/dependency-graph/dependency-submission>
+#[derive(Debug, Clone, Serialize)]
+pub struct GithubSnapshot {
+    /// The version of the snapshot format.
+    version: u32,
+    /// The CI job that produced the snapshot, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    job: Option<GithubJob>,
+    /// The commit SHA associated with the snapshot, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sha: Option<String>,
+    /// The Git reference associated with the snapshot (e.g., `refs/heads/main`), if known.
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    reference: Option<String>,
+    /// The tool that produced the snapshot.
+    detector: GithubDetector,
+    /// The time at which the snapshot was produced.
+    scanned: jiff::Timestamp,
+    /// The resolved dependencies, keyed by manifest path.
+    manifests: BTreeMap<String, GithubManifest>,
+}
+
+/// The CI job that produced a [`GithubSnapshot`].
+#[derive(Debug, Clone, Serialize)]
+pub struct GithubJob {
+    /// An identifier that groups snapshots from the same workflow and job.
+    pub correlator: String,
+    /// The identifier of the job run.
+    pub id: String,
+}
+
+/// The tool that produced a [`GithubSnapshot`].
+#[derive(Debug, Clone, Serialize)]
+pub struct GithubDetector {
+    pub name: String,
+    pub version: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct GithubManifest {
+    name: String,
+    file: GithubManifestFile,
+    resolved: BTreeMap<String, GithubDependency>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct GithubManifestFile {
+    source_location: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct GithubDependency {
+    package_url: String,
+    relationship: GithubRelationship,
+    scope: GithubScope,
+    dependencies: BTreeSet<String>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum GithubRelationship {
+    /// The package was requested directly.
+    Direct,
+    /// The package was pulled in by another package.
+    Indirect,
+}
+
+#[derive(Debug, Copy, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum GithubScope {
+    Runtime,
+}
+
+impl GithubSnapshot {
+    /// Create a [`GithubSnapshot`] for the given resolution, attributing its packages to the
+    /// given manifest (e.g., `requirements.in`).
+    pub fn from_resolution(
+        resolution: &ResolverOutput,
+        manifest: &str,
+        no_emit_packages: &[PackageName],
+        detector: GithubDetector,
+        scanned: jiff::Timestamp,
+    ) -> Self {
+        let graph = &resolution.graph;
+
+        // Collect every resolved version of each package. In a universal resolution, a package
+        // may be pinned to multiple versions.
+        let mut versions: BTreeMap<&PackageName, BTreeSet<&Version>> = BTreeMap::new();
+        for node in graph.node_weights() {
+            if let ResolutionGraphNode::Dist(dist) = node {
+                versions
+                    .entry(&dist.name)
+                    .or_default()
+                    .insert(&dist.version);
+            }
+        }
+
+        // Collapse extras and dependency groups onto their base package, merging their edges.
+        let mut resolved: BTreeMap<String, GithubDependency> = BTreeMap::new();
+        for index in graph.node_indices() {
+            let ResolutionGraphNode::Dist(dist) = &graph[index] else {
+                continue;
+            };
+            if no_emit_packages.contains(&dist.name) {
+                continue;
+            }
+
+            let direct = graph
+                .edges_directed(index, Direction::Incoming)
+                .any(|edge| matches!(graph[edge.source()], ResolutionGraphNode::Root));
+
+            let dependencies =
+                graph
+                    .edges_directed(index, Direction::Outgoing)
+                    .filter_map(|edge| match &graph[edge.target()] {
+                        ResolutionGraphNode::Dist(target) if target.name != dist.name => {
+                            Some(package_url(&target.name, &target.version))
+                        }
+                        _ => None,
+                    });
+
+            let key = if versions
+                .get(&dist.name)
+                .is_some_and(|versions| versions.len() > 1)
+            {
+                format!("{}@{}", dist.name, dist.version)
+            } else {
+                dist.name.to_string()
+            };
+
+            let entry = resolved.entry(key).or_insert_with(|| GithubDependency {
+                package_url: package_url(&dist.name, &dist.version),
+                relationship: GithubRelationship::Indirect,
+                scope: GithubScope::Runtime,
+                dependencies: BTreeSet::new(),
+            });
+            if direct {
+                entry.relationship = GithubRelationship::Direct;
+            }
+            entry.dependencies.extend(dependencies);
+        }
+
+        let manifest = GithubManifest {
+            name: manifest.to_string(),
+            file: GithubManifestFile {
+                source_location: manifest.to_string(),
+            },
+            resolved,
+        };
+
+        Self {
+            version: 0,
+            job: None,
+            sha: None,
+            reference: None,
+            detector,
+            scanned,
+            manifests: BTreeMap::from([(manifest.name.clone(), manifest)]),
+        }
+    }
+
+    /// Set the CI job that produced the snapshot.
+    #[must_use]
+    pub fn with_job(mut self, job: GithubJob) -> Self {
+        self.job = Some(job);
+        self
+    }
+
+    /// Set the commit SHA and Git reference associated with the snapshot.
+    #[must_use]
+    pub fn with_commit(mut self, sha: String, reference: String) -> Self {
+        self.sha = Some(sha);
+        self.reference = Some(reference);
+        self
+    }
+}
+
+/// Format a [package URL](https://github.com/package-url/purl-spec) for a PyPI package.
+fn package_url(name: &PackageName, version: &Version) -> String {
+    format!("pkg:pypi/{name}@{version}")
+}
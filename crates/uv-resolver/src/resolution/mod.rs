@@ -9,13 +9,21 @@ use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::Version;
 use uv_pypi_types::HashDigests;
 
+pub use crate::resolution::bounds::LowerBound;
+pub use crate::resolution::dependency_graph::DependencyGraph;
 pub use crate::resolution::display::{AnnotationStyle, DisplayResolutionGraph};
+pub use crate::resolution::explain::DisplayResolutionExplanation;
+pub use crate::resolution::github_snapshot::{GithubDetector, GithubJob, GithubSnapshot};
 pub(crate) use crate::resolution::output::ResolutionGraphNode;
 pub use crate::resolution::output::{ConflictingDistributionError, ResolverOutput};
 pub(crate) use crate::resolution::requirements_txt::RequirementsTxtDist;
 use crate::universal_marker::UniversalMarker;
 
+mod bounds;
+mod dependency_graph;
 mod display;
+mod explain;
+mod github_snapshot;
 mod output;
 mod requirements_txt;
 
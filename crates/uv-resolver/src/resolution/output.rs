@@ -1,10 +1,12 @@
-use std::collections::BTreeMap;
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, VecDeque};
 use std::fmt::{Display, Formatter};
 use std::sync::Arc;
 
 use indexmap::IndexSet;
 use petgraph::{
     graph::{Graph, NodeIndex},
+    visit::EdgeRef,
     Directed, Direction,
 };
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
@@ -33,7 +35,7 @@ use crate::resolver::{Resolution, ResolutionDependencyEdge, ResolutionPackage};
 use crate::universal_marker::{ConflictMarker, UniversalMarker};
 use crate::{
     InMemoryIndex, MetadataResponse, Options, PythonRequirement, RequiresPython, ResolveError,
-    VersionsResponse,
+    VersionsResponse, YankedPolicy,
 };
 
 /// The output of a successful resolution.
@@ -230,6 +232,13 @@ impl ResolverOutput {
             report_missing_lower_bounds(&graph, &mut diagnostics, constraints, overrides);
         }
 
+        // If yanked versions are allowed outright, don't warn when one is selected.
+        if options.yanked == YankedPolicy::Allow {
+            diagnostics.retain(|diagnostic| {
+                !matches!(diagnostic, ResolutionDiagnostic::YankedVersion { .. })
+            });
+        }
+
         let output = Self {
             graph,
             requires_python,
@@ -591,7 +600,7 @@ impl ResolverOutput {
     }
 
     /// Returns an iterator over the distinct packages in the graph.
-    fn dists(&self) -> impl Iterator<Item = &AnnotatedDist> {
+    pub(super) fn dists(&self) -> impl Iterator<Item = &AnnotatedDist> {
         self.graph
             .node_indices()
             .filter_map(move |index| match &self.graph[index] {
@@ -615,11 +624,191 @@ impl ResolverOutput {
         self.dists().any(|dist| dist.name() == name)
     }
 
+    /// Return the requirements that were used to build the graph.
+    pub fn requirements(&self) -> &[Requirement] {
+        &self.requirements
+    }
+
+    /// Return the subgraph reachable from the given subset of the direct requirements, e.g., to
+    /// derive the resolution for a single extra from a resolution across all extras.
+    ///
+    /// The root's edges are retained only for packages (and extras) requested by the given
+    /// requirements; every other package is retained if it's reachable from those packages.
+    #[must_use]
+    pub fn filter(&self, requirements: &[Requirement]) -> Self {
+        let is_requested = |node: &ResolutionGraphNode| match node {
+            ResolutionGraphNode::Root => false,
+            ResolutionGraphNode::Dist(dist) => requirements.iter().any(|requirement| {
+                requirement.name == dist.name
+                    && dist
+                        .extra
+                        .as_ref()
+                        .is_none_or(|extra| requirement.extras.contains(extra))
+                    && dist.dev.is_none()
+            }),
+        };
+
+        // Find every node that's reachable from the requested packages.
+        let mut reachable = FxHashSet::default();
+        let mut queue = self
+            .graph
+            .node_indices()
+            .filter(|index| matches!(self.graph[*index], ResolutionGraphNode::Root))
+            .collect::<VecDeque<_>>();
+        reachable.extend(queue.iter().copied());
+        while let Some(index) = queue.pop_front() {
+            let is_root = matches!(self.graph[index], ResolutionGraphNode::Root);
+            for neighbor in self.graph.neighbors_directed(index, Direction::Outgoing) {
+                if is_root && !is_requested(&self.graph[neighbor]) {
+                    continue;
+                }
+                if reachable.insert(neighbor) {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        let graph = self.graph.filter_map(
+            |index, node| reachable.contains(&index).then(|| node.clone()),
+            |index, marker| {
+                let (source, target) = self.graph.edge_endpoints(index)?;
+                if !reachable.contains(&source) || !reachable.contains(&target) {
+                    return None;
+                }
+                if matches!(self.graph[source], ResolutionGraphNode::Root)
+                    && !is_requested(&self.graph[target])
+                {
+                    return None;
+                }
+                Some(*marker)
+            },
+        );
+
+        Self {
+            graph,
+            requires_python: self.requires_python.clone(),
+            fork_markers: self.fork_markers.clone(),
+            diagnostics: self.diagnostics.clone(),
+            requirements: requirements.to_vec(),
+            constraints: self.constraints.clone(),
+            overrides: self.overrides.clone(),
+            options: self.options.clone(),
+        }
+    }
+
+    /// Return the distinct package versions in the graph that were resolved from a registry,
+    /// along with the index from which each was resolved.
+    pub fn registry_pins(&self) -> Vec<(&PackageName, &Version, &IndexUrl)> {
+        let mut pins = self
+            .dists()
+            .filter(|dist| dist.is_base())
+            .filter_map(|dist| Some((&dist.name, &dist.version, dist.index()?)))
+            .collect::<Vec<_>>();
+        pins.sort_by(|a, b| a.0.cmp(b.0).then_with(|| a.1.cmp(b.1)));
+        pins.dedup();
+        pins
+    }
+
+    /// Replace the hashes for the given package version (e.g., with hashes fetched from a
+    /// trusted source, rather than those reported by the index).
+    pub fn set_hashes(&mut self, name: &PackageName, version: &Version, hashes: &HashDigests) {
+        for node in self.graph.node_weights_mut() {
+            if let ResolutionGraphNode::Dist(dist) = node {
+                if dist.name == *name && dist.version == *version {
+                    dist.hashes = hashes.clone();
+                }
+            }
+        }
+    }
+
     /// Return the [`ResolutionDiagnostic`]s that were encountered while building the graph.
     pub fn diagnostics(&self) -> &[ResolutionDiagnostic] {
         &self.diagnostics
     }
 
+    /// Return the range of Python versions supported by every pinned distribution, i.e., the
+    /// intersection of their `Requires-Python` metadata.
+    ///
+    /// Returns `None` if none of the pinned distributions declare a `Requires-Python`, or if the
+    /// declared ranges are disjoint (e.g., in a universal resolution, where packages may be
+    /// pinned for distinct Python versions).
+    pub fn requires_python_intersection(&self) -> Option<RequiresPython> {
+        RequiresPython::intersection(
+            self.dists()
+                .filter(|dist| dist.is_base())
+                .filter_map(|dist| dist.metadata.as_ref()?.requires_python.as_ref()),
+        )
+    }
+
+    /// Return the dependency cycles in the graph, e.g., `a` depends on `b`, which depends on `a`.
+    ///
+    /// Extras and dependency groups are treated as the package itself, such that cycles induced by
+    /// extras (e.g., `a[test]` depends on `b`, which depends on `a`) are included, while a package
+    /// that depends on its own extras is not considered cyclic.
+    ///
+    /// Each cycle is returned as the path from the alphabetically-first package in the cycle back
+    /// to itself, excluding the final repetition of the first package.
+    pub fn cycles(&self) -> Vec<Vec<PackageName>> {
+        // Collapse the graph into a graph over package names.
+        let mut graph: Graph<&PackageName, (), Directed> = Graph::new();
+        let mut indices: FxHashMap<&PackageName, NodeIndex> = FxHashMap::default();
+        for index in self.graph.node_indices() {
+            if let Some(name) = self.graph[index].package_name() {
+                indices.entry(name).or_insert_with(|| graph.add_node(name));
+            }
+        }
+        for edge in self.graph.edge_references() {
+            let (Some(source), Some(target)) = (
+                self.graph[edge.source()].package_name(),
+                self.graph[edge.target()].package_name(),
+            ) else {
+                continue;
+            };
+            if source != target {
+                graph.update_edge(indices[source], indices[target], ());
+            }
+        }
+
+        let mut cycles = petgraph::algo::tarjan_scc(&graph)
+            .into_iter()
+            .filter(|component| component.len() > 1)
+            .filter_map(|component| {
+                // Find the shortest cycle through the first package in the component, visiting
+                // neighbors in alphabetical order for deterministic output.
+                let start = *component.iter().min_by_key(|index| graph[**index])?;
+                let members = component.iter().copied().collect::<FxHashSet<_>>();
+                let mut parents: FxHashMap<NodeIndex, NodeIndex> = FxHashMap::default();
+                let mut queue = VecDeque::from([start]);
+                while let Some(node) = queue.pop_front() {
+                    let mut neighbors = graph
+                        .neighbors(node)
+                        .filter(|neighbor| members.contains(neighbor))
+                        .collect::<Vec<_>>();
+                    neighbors.sort_unstable_by_key(|neighbor| graph[*neighbor]);
+                    for neighbor in neighbors {
+                        if neighbor == start {
+                            let mut cycle = vec![graph[node].clone()];
+                            let mut current = node;
+                            while let Some(parent) = parents.get(&current) {
+                                cycle.push(graph[*parent].clone());
+                                current = *parent;
+                            }
+                            cycle.reverse();
+                            return Some(cycle);
+                        }
+                        if let Entry::Vacant(entry) = parents.entry(neighbor) {
+                            entry.insert(node);
+                            queue.push_back(neighbor);
+                        }
+                    }
+                }
+                None
+            })
+            .collect::<Vec<_>>();
+        cycles.sort();
+        cycles
+    }
+
     /// Return the marker tree specific to this resolution.
     ///
     /// This accepts an in-memory-index and marker environment, all
@@ -700,6 +889,11 @@ impl ResolverOutput {
                         add_marker_params_from_tree(tree, set);
                     }
                 }
+                MarkerTreeKind::Feature(marker) => {
+                    for (_, tree) in marker.children() {
+                        add_marker_params_from_tree(tree, set);
+                    }
+                }
             }
         }
 
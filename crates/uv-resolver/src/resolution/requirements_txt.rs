@@ -6,7 +6,7 @@ use itertools::Itertools;
 
 use uv_distribution_types::{DistributionMetadata, Name, ResolvedDist, Verbatim, VersionOrUrlRef};
 use uv_normalize::{ExtraName, PackageName};
-use uv_pep440::Version;
+use uv_pep440::{Version, VersionSpecifier};
 use uv_pep508::{split_scheme, MarkerTree, Scheme};
 use uv_pypi_types::HashDigest;
 
@@ -32,10 +32,14 @@ impl<'dist> RequirementsTxtDist<'dist> {
     /// This typically results in a PEP 508 representation of the requirement, but will write an
     /// unnamed requirement for relative paths, which can't be represented with PEP 508 (but are
     /// supported in `requirements.txt`).
+    ///
+    /// If `normalize_specifiers` is set, pinned versions are written in canonical form (e.g.,
+    /// `==1.0.0.0` as `==1`).
     pub(crate) fn to_requirements_txt(
         &self,
         requires_python: &RequiresPython,
         include_markers: bool,
+        normalize_specifiers: bool,
     ) -> Cow<str> {
         // If the URL is editable, write it as an editable requirement.
         if self.dist.is_editable() {
@@ -106,14 +110,24 @@ impl<'dist> RequirementsTxtDist<'dist> {
             }
         }
 
+        let version_or_url = self.version_or_url();
+        let version_or_url = match version_or_url {
+            VersionOrUrlRef::Version(version) if normalize_specifiers => Cow::Owned(
+                VersionSpecifier::equals_version(version.clone())
+                    .normalize()
+                    .to_string(),
+            ),
+            _ => version_or_url.verbatim(),
+        };
+
         if self.extras.is_empty() {
             if let Some(markers) = SimplifiedMarkerTree::new(requires_python, self.markers)
                 .try_to_string()
                 .filter(|_| include_markers)
             {
-                Cow::Owned(format!("{} ; {}", self.dist.verbatim(), markers))
+                Cow::Owned(format!("{}{} ; {}", self.name(), version_or_url, markers))
             } else {
-                self.dist.verbatim()
+                Cow::Owned(format!("{}{}", self.name(), version_or_url))
             }
         } else {
             let mut extras = self.extras.clone();
@@ -127,7 +141,7 @@ impl<'dist> RequirementsTxtDist<'dist> {
                     "{}[{}]{} ; {}",
                     self.name(),
                     extras.into_iter().join(", "),
-                    self.version_or_url().verbatim(),
+                    version_or_url,
                     markers,
                 ))
             } else {
@@ -135,7 +149,7 @@ impl<'dist> RequirementsTxtDist<'dist> {
                     "{}[{}]{}",
                     self.name(),
                     extras.into_iter().join(", "),
-                    self.version_or_url().verbatim()
+                    version_or_url
                 ))
             }
         }
@@ -1,3 +1,8 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use uv_normalize::PackageName;
+
 use crate::resolver::{ForkMap, ForkSet};
 use crate::{DependencyMode, Manifest, ResolverEnvironment};
 
@@ -26,6 +31,87 @@ impl std::fmt::Display for ResolutionMode {
     }
 }
 
+impl FromStr for ResolutionMode {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "highest" => Ok(Self::Highest),
+            "lowest" => Ok(Self::Lowest),
+            "lowest-direct" => Ok(Self::LowestDirect),
+            _ => Err(format!(
+                "`{input}` is not a valid resolution strategy (expected one of `highest`, `lowest`, or `lowest-direct`)"
+            )),
+        }
+    }
+}
+
+/// A per-package `name=mode` override, as provided on the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolutionPackageEntry {
+    pub package: PackageName,
+    pub mode: ResolutionMode,
+}
+
+impl FromStr for ResolutionPackageEntry {
+    type Err = String;
+
+    /// Parse a [`ResolutionPackageEntry`] from a string in the format `PACKAGE=MODE`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let Some((package, mode)) = input.split_once('=') else {
+            return Err(format!(
+                "`{input}` is not a valid package-specific resolution strategy (expected `PACKAGE=MODE`)"
+            ));
+        };
+        let package = PackageName::from_str(package.trim())
+            .map_err(|err| format!("`{package}` is not a valid package name: {err}"))?;
+        let mode = ResolutionMode::from_str(mode.trim())?;
+        Ok(Self { package, mode })
+    }
+}
+
+/// A table of per-package resolution strategies that take precedence over the global
+/// [`ResolutionMode`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ResolutionPackage(BTreeMap<PackageName, ResolutionMode>);
+
+impl ResolutionPackage {
+    /// Returns the resolution strategy for the given package, if one was provided.
+    pub fn get(&self, package: &PackageName) -> Option<ResolutionMode> {
+        self.0.get(package).copied()
+    }
+
+    /// Returns `true` if no per-package resolution strategies were provided.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Merge two tables of per-package resolution strategies, preferring the values in `self`.
+    #[must_use]
+    pub fn merge(mut self, other: Self) -> Self {
+        for (package, mode) in other.0 {
+            self.0.entry(package).or_insert(mode);
+        }
+        self
+    }
+
+    /// Iterate over the per-package resolution strategies.
+    pub fn iter(&self) -> impl Iterator<Item = (&PackageName, &ResolutionMode)> {
+        self.0.iter()
+    }
+}
+
+impl FromIterator<ResolutionPackageEntry> for ResolutionPackage {
+    fn from_iter<T: IntoIterator<Item = ResolutionPackageEntry>>(iter: T) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|entry| (entry.package, entry.mode))
+                .collect(),
+        )
+    }
+}
+
 /// Like [`ResolutionMode`], but with any additional information required to select a candidate,
 /// like the set of direct dependencies.
 #[derive(Debug, Clone)]
@@ -58,4 +144,19 @@ impl ResolutionStrategy {
             }
         }
     }
+
+    /// Returns `true` if the highest compatible version of the given package should be selected.
+    pub(crate) fn use_highest_version(
+        &self,
+        package_name: &PackageName,
+        env: &ResolverEnvironment,
+    ) -> bool {
+        match self {
+            Self::Highest => true,
+            Self::Lowest => false,
+            Self::LowestDirect(direct_dependencies) => {
+                !direct_dependencies.contains(package_name, env)
+            }
+        }
+    }
 }
@@ -1,6 +1,7 @@
 use std::fmt::{Display, Formatter};
 
 use crate::resolver::{MetadataUnavailable, VersionFork};
+use crate::SourcePolicy;
 use uv_distribution_types::IncompatibleDist;
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_platform_tags::{AbiTag, Tags};
@@ -43,6 +44,13 @@ pub(crate) enum UnavailableVersion {
     /// The source distribution has a `requires-python` requirement that is not met by the installed
     /// Python version (and static metadata is not available).
     RequiresPython(VersionSpecifiers),
+    /// The version is not included in the allow-list.
+    NotAllowed,
+    /// The version lacks a usable wheel, but the package's source policy only allows wheels.
+    SourcePolicy(SourcePolicy),
+    /// The version was rejected by the named [`CandidatePolicy`](crate::CandidatePolicy), for the
+    /// given reason.
+    Policy { name: String, reason: String },
 }
 
 impl UnavailableVersion {
@@ -56,6 +64,13 @@ impl UnavailableVersion {
             UnavailableVersion::RequiresPython(requires_python) => {
                 format!("Python {requires_python}")
             }
+            UnavailableVersion::NotAllowed => "not in the allowed packages".into(),
+            UnavailableVersion::SourcePolicy(policy) => {
+                format!("no usable wheels, as required by its source policy (`{policy}`)")
+            }
+            UnavailableVersion::Policy { name, reason } => {
+                format!("rejected by the `{name}` policy ({reason})")
+            }
         }
     }
 
@@ -67,6 +82,9 @@ impl UnavailableVersion {
             UnavailableVersion::InvalidStructure => format!("has {self}"),
             UnavailableVersion::Offline => format!("needs {self}"),
             UnavailableVersion::RequiresPython(..) => format!("requires {self}"),
+            UnavailableVersion::NotAllowed => format!("is {self}"),
+            UnavailableVersion::SourcePolicy(..) => format!("has {self}"),
+            UnavailableVersion::Policy { .. } => format!("was {self}"),
         }
     }
 
@@ -78,6 +96,9 @@ impl UnavailableVersion {
             UnavailableVersion::InvalidStructure => format!("have {self}"),
             UnavailableVersion::Offline => format!("need {self}"),
             UnavailableVersion::RequiresPython(..) => format!("require {self}"),
+            UnavailableVersion::NotAllowed => format!("are {self}"),
+            UnavailableVersion::SourcePolicy(..) => format!("have {self}"),
+            UnavailableVersion::Policy { .. } => format!("were {self}"),
         }
     }
 
@@ -95,6 +116,9 @@ impl UnavailableVersion {
             UnavailableVersion::InvalidStructure => None,
             UnavailableVersion::Offline => None,
             UnavailableVersion::RequiresPython(..) => None,
+            UnavailableVersion::NotAllowed => None,
+            UnavailableVersion::SourcePolicy(..) => None,
+            UnavailableVersion::Policy { .. } => None,
         }
     }
 }
@@ -134,16 +158,24 @@ pub(crate) enum UnavailablePackage {
     InvalidMetadata(String),
     /// The package has an invalid structure.
     InvalidStructure(String),
+    /// The package is not included in the allow-list.
+    NotAllowed,
+    /// The package was requested from a registry, but its source policy doesn't allow it.
+    SourcePolicy(SourcePolicy),
 }
 
 impl UnavailablePackage {
-    pub(crate) fn message(&self) -> &'static str {
+    pub(crate) fn message(&self) -> String {
         match self {
-            UnavailablePackage::NoIndex => "not found in the provided package locations",
-            UnavailablePackage::Offline => "not found in the cache",
-            UnavailablePackage::NotFound => "not found in the package registry",
-            UnavailablePackage::InvalidMetadata(_) => "invalid metadata",
-            UnavailablePackage::InvalidStructure(_) => "an invalid package format",
+            UnavailablePackage::NoIndex => "not found in the provided package locations".into(),
+            UnavailablePackage::Offline => "not found in the cache".into(),
+            UnavailablePackage::NotFound => "not found in the package registry".into(),
+            UnavailablePackage::InvalidMetadata(_) => "invalid metadata".into(),
+            UnavailablePackage::InvalidStructure(_) => "an invalid package format".into(),
+            UnavailablePackage::NotAllowed => "not in the allowed packages".into(),
+            UnavailablePackage::SourcePolicy(policy) => {
+                format!("not allowed from a package index by its source policy (`{policy}`)")
+            }
         }
     }
 
@@ -154,13 +186,15 @@ impl UnavailablePackage {
             UnavailablePackage::NotFound => format!("was {self}"),
             UnavailablePackage::InvalidMetadata(_) => format!("has {self}"),
             UnavailablePackage::InvalidStructure(_) => format!("has {self}"),
+            UnavailablePackage::NotAllowed => format!("is {self}"),
+            UnavailablePackage::SourcePolicy(..) => format!("is {self}"),
         }
     }
 }
 
 impl Display for UnavailablePackage {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str(self.message())
+        f.write_str(&self.message())
     }
 }
 
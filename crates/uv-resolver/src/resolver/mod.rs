@@ -38,8 +38,9 @@ use uv_types::{BuildContext, HashStrategy, InstalledPackagesProvider};
 use uv_warnings::warn_user_once;
 
 use crate::candidate_selector::{Candidate, CandidateDist, CandidateSelector};
+use crate::checkpoint::ResolverCheckpoint;
 use crate::dependency_provider::UvDependencyProvider;
-use crate::error::{NoSolutionError, ResolveError};
+use crate::error::{LimitExceededError, NoSolutionError, ResolutionLimit, ResolveError};
 use crate::fork_indexes::ForkIndexes;
 use crate::fork_strategy::ForkStrategy;
 use crate::fork_urls::ForkUrls;
@@ -48,7 +49,7 @@ use crate::pins::FilePins;
 use crate::preferences::Preferences;
 use crate::pubgrub::{
     PubGrubDependency, PubGrubDistribution, PubGrubPackage, PubGrubPackageInner, PubGrubPriorities,
-    PubGrubPython,
+    PubGrubPython, RequirementLocation,
 };
 use crate::python_requirement::PythonRequirement;
 use crate::resolution::ResolverOutput;
@@ -75,7 +76,10 @@ pub use crate::resolver::provider::{
 };
 pub use crate::resolver::reporter::{BuildId, Reporter};
 use crate::yanks::AllowedYanks;
-use crate::{marker, DependencyMode, Exclusions, FlatIndex, Options, ResolutionMode, VersionMap};
+use crate::{
+    marker, CandidatePolicy, DependencyMode, Exclusions, FlatIndex, Options, PolicyCandidate,
+    ResolutionMode, SourcePolicy, VersionMap,
+};
 
 mod availability;
 mod batch_prefetch;
@@ -128,6 +132,10 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     options: Options,
     /// The reporter to use for this resolver.
     reporter: Option<Arc<dyn Reporter>>,
+    /// The checkpoint in which to record the resolver's progress, if any.
+    checkpoint: Option<Arc<ResolverCheckpoint>>,
+    /// The policy to apply to each candidate, if any.
+    policy: Option<Arc<dyn CandidatePolicy>>,
 }
 
 impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
@@ -170,9 +178,14 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
             flat_index,
             tags,
             python_requirement.target(),
-            AllowedYanks::from_manifest(&manifest, &env, options.dependency_mode),
+            AllowedYanks::from_manifest(&manifest, &env, options.dependency_mode, options.yanked),
             hasher,
             options.exclude_newer,
+            &options.exclude_newer_package,
+            options.invalid_metadata,
+            &options.wheel_preference,
+            &options.source_policies,
+            options.only_hashed,
             build_context.build_options(),
             build_context.capabilities(),
         );
@@ -201,7 +214,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
     /// Initialize a new resolver using a user provided backend.
     pub fn new_custom_io(
         manifest: Manifest,
-        options: Options,
+        mut options: Options,
         hasher: &HashStrategy,
         env: ResolverEnvironment,
         tags: Option<Tags>,
@@ -214,6 +227,27 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         provider: Provider,
         installed_packages: InstalledPackages,
     ) -> Result<Self, ResolveError> {
+        // A provider only satisfies requirements on the packages it provides if it's already in
+        // the graph, i.e., if it's required upfront.
+        if !options.providers.is_empty() {
+            let requirements = manifest
+                .user_requirements(&env, options.dependency_mode)
+                .collect::<Vec<_>>();
+            options
+                .providers
+                .retain_required(requirements.iter().map(AsRef::as_ref));
+            options
+                .providers
+                .warn_unapplied(&manifest.constraints, &manifest.overrides);
+            options.providers.validate(
+                manifest
+                    .requirements(&env, options.dependency_mode)
+                    .collect::<Vec<_>>()
+                    .iter()
+                    .map(AsRef::as_ref),
+            )?;
+        }
+
         let state = ResolverState {
             index: index.clone(),
             git: git.clone(),
@@ -240,6 +274,8 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             incomplete_packages: DashMap::default(),
             options,
             reporter: None,
+            checkpoint: None,
+            policy: None,
         };
         Ok(Self { state, provider })
     }
@@ -258,6 +294,31 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         }
     }
 
+    /// Set the [`ResolverCheckpoint`] in which to record the resolver's progress.
+    #[must_use]
+    pub fn with_checkpoint(self, checkpoint: Arc<ResolverCheckpoint>) -> Self {
+        Self {
+            state: ResolverState {
+                checkpoint: Some(checkpoint),
+                ..self.state
+            },
+            provider: self.provider,
+        }
+    }
+
+    /// Set the [`CandidatePolicy`] to apply to each candidate considered during resolution.
+    #[must_use]
+    pub fn with_policy(self, policy: Arc<dyn CandidatePolicy>) -> Self {
+        Self {
+            state: ResolverState {
+                selector: self.state.selector.with_policy(policy.clone()),
+                policy: Some(policy),
+                ..self.state
+            },
+            provider: self.provider,
+        }
+    }
+
     /// Resolve a set of requirements into a set of pinned versions.
     pub async fn resolve(self) -> Result<ResolverOutput, ResolveError> {
         let state = Arc::new(self.state);
@@ -287,7 +348,22 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         let resolve_fut = async move { rx.await.map_err(|_| ResolveError::ChannelClosed) };
 
         // Wait for both to complete.
-        let ((), resolution) = tokio::try_join!(requests_fut, resolve_fut)?;
+        let joined = async { tokio::try_join!(requests_fut, resolve_fut) };
+
+        // The solver checks the `--resolution-timeout` between iterations, but it can also be
+        // blocked on a metadata fetch or source distribution build, so bound the whole resolution.
+        let ((), resolution) = if let Some(timeout) = state.options.resolution_timeout {
+            if let Ok(result) = tokio::time::timeout(timeout, joined).await {
+                result?
+            } else {
+                debug!("Aborting resolution after {:.3}s", timeout.as_secs_f32());
+                return Err(
+                    LimitExceededError::new(ResolutionLimit::Timeout(timeout), Vec::new()).into(),
+                );
+            }
+        } else {
+            joined.await?
+        };
 
         state.on_complete();
         resolution
@@ -327,6 +403,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         let mut preferences = self.preferences.clone();
         let mut forked_states = self.env.initial_forked_states(state)?;
         let mut resolutions = vec![];
+        let started = Instant::now();
 
         'FORK: while let Some(mut state) = forked_states.pop() {
             if let Some(split) = state.env.end_user_fork_display() {
@@ -381,6 +458,9 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
                         Self::reprioritize_conflicts(&mut state);
 
+                        // Abort if the resolution has exceeded any configured limits.
+                        self.check_limits(&state, started)?;
+
                         trace!(
                             "Assigned packages: {}",
                             state
@@ -637,7 +717,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                             dependencies,
                             &self.git,
                             &self.workspace_members,
-                            self.selector.resolution_strategy(),
+                            &self.selector,
                         )?;
                     }
                     ForkedDependencies::Forked {
@@ -718,6 +798,32 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         )
     }
 
+    /// Return an error if the resolution has exceeded the `--resolution-timeout`, or if the fork
+    /// has exceeded `--max-backtracks`.
+    fn check_limits(&self, state: &ForkState, started: Instant) -> Result<(), ResolveError> {
+        let limit = if let Some(timeout) = self
+            .options
+            .resolution_timeout
+            .filter(|timeout| started.elapsed() > *timeout)
+        {
+            ResolutionLimit::Timeout(timeout)
+        } else if let Some(max) = self
+            .options
+            .max_backtracks
+            .filter(|max| state.conflict_tracker.total > *max)
+        {
+            ResolutionLimit::Backtracks(max)
+        } else {
+            return Ok(());
+        };
+        debug!(
+            "Aborting resolution after {:.3}s and {} conflicts",
+            started.elapsed().as_secs_f32(),
+            state.conflict_tracker.total
+        );
+        Err(LimitExceededError::new(limit, state.most_contended(5)).into())
+    }
+
     /// Change the priority of often conflicting packages and backtrack.
     ///
     /// To be called after unit propagation.
@@ -865,7 +971,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     fork.dependencies.clone(),
                     &self.git,
                     &self.workspace_members,
-                    self.selector.resolution_strategy(),
+                    &self.selector,
                 )?;
                 // Emit a request to fetch the metadata for each registry package.
                 for dependency in &fork.dependencies {
@@ -1053,6 +1159,16 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             | PubGrubPackageInner::Dev { name, .. }
             | PubGrubPackageInner::Package { name, .. } => {
                 if let Some(url) = package.name().and_then(|name| fork_urls.get(name)) {
+                    // If the package has a source policy, ensure that it allows the URL.
+                    if let Some(policy) = self.options.source_policies.get(name) {
+                        if !policy.allows_url(&url.parsed_url) {
+                            return Err(ResolveError::SourcePolicy(
+                                name.clone(),
+                                url.verbatim.to_string(),
+                                policy.clone(),
+                            ));
+                        }
+                    }
                     self.choose_version_url(name, range, url, python_requirement)
                 } else {
                     self.choose_version_registry(
@@ -1204,12 +1320,47 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             }
         };
 
+        // If the package has a source policy, ensure that it allows the package to be installed
+        // from this registry.
+        let policy = self.options.source_policies.get(name);
+        if let Some(policy) = policy {
+            if !policy.allows_registry() {
+                self.unavailable_packages.insert(
+                    name.clone(),
+                    UnavailablePackage::SourcePolicy(policy.clone()),
+                );
+                return Ok(None);
+            }
+            if let (Some(required), Some(index)) = (policy.index(), index) {
+                if required.url() != index.url() {
+                    return Err(ResolveError::SourcePolicy(
+                        name.clone(),
+                        index.to_string(),
+                        policy.clone(),
+                    ));
+                }
+            }
+        }
+
+        // If an allow-list was provided, treat any package that isn't listed as unavailable.
+        let allowed = if let Some(allowed_packages) = self.options.allowed_packages.as_ref() {
+            let Some(allowed) = allowed_packages.get(name) else {
+                self.unavailable_packages
+                    .insert(name.clone(), UnavailablePackage::NotAllowed);
+                return Ok(None);
+            };
+            Some(allowed)
+        } else {
+            None
+        };
+
         debug!("Searching for a compatible version of {package} ({range})");
 
-        // Find a version.
+        // Find a version, preferring those in the allow-list.
+        let restricted = allowed.map(|allowed| range.intersection(allowed));
         let Some(candidate) = self.selector.select(
             name,
-            range,
+            restricted.as_ref().unwrap_or(range),
             version_maps,
             preferences,
             &self.installed_packages,
@@ -1217,6 +1368,26 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             index,
             env,
         ) else {
+            // If no allowed versions exist in the range, mark the best disallowed version as
+            // unavailable, such that the error reflects the allow-list.
+            if restricted.is_some() {
+                if let Some(candidate) = self.selector.select(
+                    name,
+                    range,
+                    version_maps,
+                    preferences,
+                    &self.installed_packages,
+                    &self.exclusions,
+                    index,
+                    env,
+                ) {
+                    return Ok(Some(ResolverVersion::Unavailable(
+                        candidate.version().clone(),
+                        UnavailableVersion::NotAllowed,
+                    )));
+                }
+            }
+
             // Short circuit: we couldn't find _any_ versions for a package.
             return Ok(None);
         };
@@ -1234,6 +1405,40 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             }
         };
 
+        // If the package's source policy only allows wheels, reject versions that would need to
+        // be built from source.
+        if matches!(policy, Some(SourcePolicy::Binary))
+            && matches!(
+                dist,
+                CompatibleDist::SourceDist { .. } | CompatibleDist::IncompatibleWheel { .. }
+            )
+        {
+            return Ok(Some(ResolverVersion::Unavailable(
+                candidate.version().clone(),
+                UnavailableVersion::SourcePolicy(SourcePolicy::Binary),
+            )));
+        }
+
+        // Check whether the version is rejected by the policy, if any.
+        if let Some(policy) = self.policy.as_ref() {
+            if let Err(reason) =
+                policy.check(&PolicyCandidate::new(name, candidate.version(), dist))
+            {
+                debug!(
+                    "Rejecting {name}=={} due to `{}` policy: {reason}",
+                    candidate.version(),
+                    policy.name()
+                );
+                return Ok(Some(ResolverVersion::Unavailable(
+                    candidate.version().clone(),
+                    UnavailableVersion::Policy {
+                        name: policy.name().to_string(),
+                        reason,
+                    },
+                )));
+            }
+        }
+
         // Check whether the version is incompatible due to its Python requirement.
         if let Some((requires_python, incompatibility)) =
             Self::check_requires_python(dist, python_requirement)
@@ -1742,6 +1947,13 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     }
                 };
 
+                self.options.providers.validate(
+                    metadata
+                        .requires_dist
+                        .iter()
+                        .chain(metadata.dependency_groups.values().flatten()),
+                )?;
+
                 let requirements = self.flatten_requirements(
                     &metadata.requires_dist,
                     &metadata.dependency_groups,
@@ -1977,6 +2189,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
     {
         self.overrides
             .apply(dependencies)
+            .map(move |requirement| self.options.providers.apply(requirement))
             .filter(move |requirement| {
                 Self::is_requirement_applicable(
                     requirement,
@@ -2430,6 +2643,25 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             }
         }
 
+        // Locate the declarations of the direct requirements (e.g., in `requirements.txt` files),
+        // to reference them in the derivation tree.
+        let mut origins = FxHashMap::default();
+        if self.options.derivation_tree {
+            let mut seen = FxHashSet::default();
+            for requirement in &self.requirements {
+                let Some(origin) = &requirement.origin else {
+                    continue;
+                };
+                if !seen.insert((&requirement.name, origin)) {
+                    continue;
+                }
+                origins
+                    .entry(requirement.name.clone())
+                    .or_insert_with(Vec::new)
+                    .extend(RequirementLocation::locate(&requirement.name, origin));
+            }
+        }
+
         let mut available_indexes = FxHashMap::default();
         let mut available_versions = FxHashMap::default();
         for package in err.packages() {
@@ -2485,21 +2717,25 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             env,
             self.tags.clone(),
             self.workspace_members.clone(),
+            origins,
             self.options.clone(),
         ))
     }
 
     fn on_progress(&self, package: &PubGrubPackage, version: &Version) {
-        if let Some(reporter) = self.reporter.as_ref() {
-            match &**package {
-                PubGrubPackageInner::Root(_) => {}
-                PubGrubPackageInner::Python(_) => {}
-                PubGrubPackageInner::Marker { .. } => {}
-                PubGrubPackageInner::Extra { .. } => {}
-                PubGrubPackageInner::Dev { .. } => {}
-                PubGrubPackageInner::Package { name, .. } => {
+        match &**package {
+            PubGrubPackageInner::Root(_) => {}
+            PubGrubPackageInner::Python(_) => {}
+            PubGrubPackageInner::Marker { .. } => {}
+            PubGrubPackageInner::Extra { .. } => {}
+            PubGrubPackageInner::Dev { .. } => {}
+            PubGrubPackageInner::Package { name, .. } => {
+                if let Some(reporter) = self.reporter.as_ref() {
                     reporter.on_progress(name, &VersionOrUrlRef::Version(version));
                 }
+                if let Some(checkpoint) = self.checkpoint.as_ref() {
+                    checkpoint.record(name, version);
+                }
             }
         }
     }
@@ -2630,7 +2866,7 @@ impl ForkState {
         dependencies: Vec<PubGrubDependency>,
         git: &GitResolver,
         workspace_members: &BTreeSet<PackageName>,
-        resolution_strategy: &ResolutionStrategy,
+        selector: &CandidateSelector,
     ) -> Result<(), ResolveError> {
         for dependency in &dependencies {
             let PubGrubDependency {
@@ -2672,10 +2908,12 @@ impl ForkState {
                     .bounding_range()
                     .map(|(lowest, _highest)| lowest == Bound::Unbounded)
                     .unwrap_or(true);
-                let strategy_lowest = matches!(
-                    resolution_strategy,
-                    ResolutionStrategy::Lowest | ResolutionStrategy::LowestDirect(..)
-                );
+                let strategy_lowest = package.name_no_root().is_some_and(|name| {
+                    matches!(
+                        selector.resolution_strategy_for(name),
+                        ResolutionStrategy::Lowest | ResolutionStrategy::LowestDirect(..)
+                    )
+                });
                 if !has_url && missing_lower_bound && strategy_lowest {
                     warn_user_once!(
                         "The direct dependency `{name}` is unpinned. \
@@ -2711,6 +2949,30 @@ impl ForkState {
         Ok(())
     }
 
+    /// Return the (up to) `limit` packages involved in the most conflicts, as either the affected
+    /// package or the culprit, in descending order.
+    fn most_contended(&self, limit: usize) -> Vec<(PackageName, usize)> {
+        let mut counts: FxHashMap<&PackageName, usize> = FxHashMap::default();
+        for (id, count) in self
+            .conflict_tracker
+            .affected
+            .iter()
+            .chain(&self.conflict_tracker.culprit)
+        {
+            if let Some(name) = self.pubgrub.package_store[*id].name() {
+                *counts.entry(name).or_default() += count;
+            }
+        }
+        counts
+            .into_iter()
+            .sorted_by(|(a_name, a_count), (b_name, b_count)| {
+                b_count.cmp(a_count).then_with(|| a_name.cmp(b_name))
+            })
+            .take(limit)
+            .map(|(name, count)| (name.clone(), count))
+            .collect()
+    }
+
     fn record_conflict(
         &mut self,
         affected: Id<PubGrubPackage>,
@@ -2761,6 +3023,9 @@ impl ForkState {
                 }
             }
 
+            self.conflict_tracker.total += 1;
+            uv_client::record_backtrack();
+
             let affected_count = self.conflict_tracker.affected.entry(self.next).or_default();
             *affected_count += 1;
             if *affected_count == CONFLICT_THRESHOLD {
@@ -3731,4 +3996,6 @@ struct ConflictTracker {
     ///
     /// Distilled from `culprit` for fast checking in the hot loop.
     deprioritize: Vec<Id<PubGrubPackage>>,
+    /// The total number of conflicts, each of which causes the resolver to backtrack.
+    total: usize,
 }
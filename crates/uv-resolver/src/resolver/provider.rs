@@ -1,6 +1,9 @@
 use std::future::Future;
 use std::sync::Arc;
 
+use tracing::debug;
+
+use uv_client::{OwnedArchive, SimpleMetadata};
 use uv_configuration::BuildOptions;
 use uv_distribution::{ArchiveMetadata, DistributionDatabase, Reporter};
 use uv_distribution_types::{Dist, IndexCapabilities, IndexUrl, InstalledDist, RequestedDist};
@@ -8,11 +11,15 @@ use uv_normalize::PackageName;
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_platform_tags::Tags;
 use uv_types::{BuildContext, HashStrategy};
+use uv_warnings::warn_user_once;
 
-use crate::flat_index::FlatIndex;
+use crate::flat_index::{FlatDistributions, FlatIndex};
 use crate::version_map::VersionMap;
 use crate::yanks::AllowedYanks;
-use crate::{ExcludeNewer, RequiresPython};
+use crate::{
+    ExcludeNewer, ExcludeNewerPackage, InvalidMetadataPolicy, RequiresPython, SourcePolicies,
+    SourcePolicy, WheelPreference,
+};
 
 pub type PackageVersionsResult = Result<VersionsResponse, uv_client::Error>;
 pub type WheelMetadataResult = Result<MetadataResponse, uv_distribution::Error>;
@@ -114,6 +121,11 @@ pub struct DefaultResolverProvider<'a, Context: BuildContext> {
     allowed_yanks: AllowedYanks,
     hasher: HashStrategy,
     exclude_newer: Option<ExcludeNewer>,
+    exclude_newer_package: ExcludeNewerPackage,
+    invalid_metadata: InvalidMetadataPolicy,
+    wheel_preference: Vec<WheelPreference>,
+    source_policies: SourcePolicies,
+    only_hashed: bool,
     build_options: &'a BuildOptions,
     capabilities: &'a IndexCapabilities,
 }
@@ -128,6 +140,11 @@ impl<'a, Context: BuildContext> DefaultResolverProvider<'a, Context> {
         allowed_yanks: AllowedYanks,
         hasher: &'a HashStrategy,
         exclude_newer: Option<ExcludeNewer>,
+        exclude_newer_package: &ExcludeNewerPackage,
+        invalid_metadata: InvalidMetadataPolicy,
+        wheel_preference: &[WheelPreference],
+        source_policies: &SourcePolicies,
+        only_hashed: bool,
         build_options: &'a BuildOptions,
         capabilities: &'a IndexCapabilities,
     ) -> Self {
@@ -139,12 +156,73 @@ impl<'a, Context: BuildContext> DefaultResolverProvider<'a, Context> {
             allowed_yanks,
             hasher: hasher.clone(),
             exclude_newer,
+            exclude_newer_package: exclude_newer_package.clone(),
+            invalid_metadata,
+            wheel_preference: wheel_preference.to_vec(),
+            source_policies: source_policies.clone(),
+            only_hashed,
             build_options,
             capabilities,
         }
     }
 }
 
+impl<Context: BuildContext> DefaultResolverProvider<'_, Context> {
+    /// Return the distributions for the given package from the `--find-links` entries, if any.
+    ///
+    /// Packages that are restricted to a specific index by their source policy never use the
+    /// `--find-links` entries.
+    fn flat_distributions(&self, package_name: &PackageName) -> Option<FlatDistributions> {
+        if self
+            .source_policies
+            .get(package_name)
+            .is_some_and(|policy| policy.index().is_some())
+        {
+            return None;
+        }
+        self.flat_index
+            .get(package_name)
+            .cloned()
+            .map(|flat_index| flat_index.with_wheel_preference(&self.wheel_preference))
+    }
+
+    /// Apply the [`InvalidMetadataPolicy`] to any files on the index that could not be parsed.
+    fn check_invalid_files(
+        &self,
+        package_name: &PackageName,
+        index: &IndexUrl,
+        metadata: &OwnedArchive<SimpleMetadata>,
+    ) -> Result<(), uv_client::Error> {
+        for file in metadata.invalid() {
+            match self.invalid_metadata {
+                InvalidMetadataPolicy::Skip => {
+                    debug!(
+                        "Skipping invalid file for {package_name} on {index}: {} ({})",
+                        file.filename, file.reason
+                    );
+                }
+                InvalidMetadataPolicy::Warn => {
+                    warn_user_once!(
+                        "Skipping invalid file `{}` for `{package_name}` on {index}: {}",
+                        file.filename,
+                        file.reason
+                    );
+                }
+                InvalidMetadataPolicy::Error => {
+                    return Err(uv_client::ErrorKind::InvalidFile {
+                        package: package_name.to_string(),
+                        filename: file.filename.to_string(),
+                        index: index.to_string(),
+                        reason: file.reason.to_string(),
+                    }
+                    .into());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Context> {
     /// Make a "Simple API" request for the package and convert the result to a [`VersionMap`].
     async fn get_package_versions<'io>(
@@ -152,6 +230,13 @@ impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Con
         package_name: &'io PackageName,
         index: Option<&'io IndexUrl>,
     ) -> PackageVersionsResult {
+        // If the package is restricted to a specific index, query that index alone.
+        let index = index.or_else(|| {
+            self.source_policies
+                .get(package_name)
+                .and_then(SourcePolicy::index)
+        });
+
         let result = self
             .fetcher
             .client()
@@ -161,35 +246,44 @@ impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Con
             .await;
 
         match result {
-            Ok(results) => Ok(VersionsResponse::Found(
-                results
-                    .into_iter()
-                    .map(|(index, metadata)| {
-                        VersionMap::from_metadata(
-                            metadata,
-                            package_name,
-                            index,
-                            self.tags.as_ref(),
-                            &self.requires_python,
-                            &self.allowed_yanks,
-                            &self.hasher,
-                            self.exclude_newer.as_ref(),
-                            self.flat_index.get(package_name).cloned(),
-                            self.build_options,
-                        )
-                    })
-                    .collect(),
-            )),
+            Ok(results) => {
+                for (index, metadata) in &results {
+                    self.check_invalid_files(package_name, index, metadata)?;
+                }
+                Ok(VersionsResponse::Found(
+                    results
+                        .into_iter()
+                        .map(|(index, metadata)| {
+                            VersionMap::from_metadata(
+                                metadata,
+                                package_name,
+                                index,
+                                self.tags.as_ref(),
+                                &self.requires_python,
+                                &self.allowed_yanks,
+                                &self.hasher,
+                                self.exclude_newer_package
+                                    .get(package_name)
+                                    .or(self.exclude_newer.as_ref()),
+                                self.flat_distributions(package_name),
+                                self.build_options,
+                                &self.wheel_preference,
+                                self.only_hashed,
+                            )
+                        })
+                        .collect(),
+                ))
+            }
             Err(err) => match err.into_kind() {
                 uv_client::ErrorKind::PackageNotFound(_) => {
-                    if let Some(flat_index) = self.flat_index.get(package_name).cloned() {
+                    if let Some(flat_index) = self.flat_distributions(package_name) {
                         Ok(VersionsResponse::Found(vec![VersionMap::from(flat_index)]))
                     } else {
                         Ok(VersionsResponse::NotFound)
                     }
                 }
                 uv_client::ErrorKind::NoIndex(_) => {
-                    if let Some(flat_index) = self.flat_index.get(package_name).cloned() {
+                    if let Some(flat_index) = self.flat_distributions(package_name) {
                         Ok(VersionsResponse::Found(vec![VersionMap::from(flat_index)]))
                     } else if self.flat_index.offline() {
                         Ok(VersionsResponse::Offline)
@@ -198,7 +292,7 @@ impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Con
                     }
                 }
                 uv_client::ErrorKind::Offline(_) => {
-                    if let Some(flat_index) = self.flat_index.get(package_name).cloned() {
+                    if let Some(flat_index) = self.flat_distributions(package_name) {
                         Ok(VersionsResponse::Found(vec![VersionMap::from(flat_index)]))
                     } else {
                         Ok(VersionsResponse::Offline)
@@ -0,0 +1,242 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use uv_distribution_filename::DistExtension;
+use uv_distribution_types::{IndexUrl, IndexUrlError};
+use uv_normalize::{InvalidNameError, PackageName};
+use uv_pypi_types::ParsedUrl;
+
+/// A restriction on the sources from which a package may be installed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SourcePolicy {
+    /// Only allow pre-built wheels, whether from a registry or a direct URL.
+    Binary,
+    /// Only allow the package to be installed from a Git repository.
+    Git,
+    /// Only allow the package to be installed from the given index.
+    Index(IndexUrl),
+}
+
+impl SourcePolicy {
+    /// Returns `true` if the policy allows the package to be installed from the given URL.
+    pub(crate) fn allows_url(&self, url: &ParsedUrl) -> bool {
+        match self {
+            Self::Binary => match url {
+                ParsedUrl::Path(path) => matches!(path.ext, DistExtension::Wheel),
+                ParsedUrl::Archive(archive) => matches!(archive.ext, DistExtension::Wheel),
+                ParsedUrl::Directory(_) | ParsedUrl::Git(_) => false,
+            },
+            Self::Git => matches!(url, ParsedUrl::Git(_)),
+            Self::Index(_) => false,
+        }
+    }
+
+    /// Returns `true` if the policy allows the package to be installed from a registry.
+    pub(crate) fn allows_registry(&self) -> bool {
+        match self {
+            Self::Binary | Self::Index(_) => true,
+            Self::Git => false,
+        }
+    }
+
+    /// Returns the index from which the package must be installed, if any.
+    pub(crate) fn index(&self) -> Option<&IndexUrl> {
+        match self {
+            Self::Index(index) => Some(index),
+            Self::Binary | Self::Git => None,
+        }
+    }
+}
+
+impl Display for SourcePolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Binary => write!(f, "binary"),
+            Self::Git => write!(f, "git"),
+            Self::Index(index) => write!(f, "index:{index}"),
+        }
+    }
+}
+
+impl FromStr for SourcePolicy {
+    type Err = SourcePolicyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "binary" => Ok(Self::Binary),
+            "git" => Ok(Self::Git),
+            _ => {
+                if let Some(index) = s.strip_prefix("index:") {
+                    let index = IndexUrl::from_str(index)
+                        .map_err(|err| SourcePolicyError::InvalidIndex(index.to_string(), err))?;
+                    Ok(Self::Index(index))
+                } else {
+                    Err(SourcePolicyError::InvalidPolicy(s.to_string()))
+                }
+            }
+        }
+    }
+}
+
+/// A [`SourcePolicy`] for a single package, e.g., `numpy=binary` or
+/// `torch=index:https://download.pytorch.org/whl/cpu`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct PackageSourcePolicy {
+    pub package: PackageName,
+    pub policy: SourcePolicy,
+}
+
+impl FromStr for PackageSourcePolicy {
+    type Err = SourcePolicyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((package, policy)) = s.split_once('=') else {
+            return Err(SourcePolicyError::MissingSeparator(s.to_string()));
+        };
+        let package = PackageName::from_str(package.trim())?;
+        let policy = SourcePolicy::from_str(policy.trim())?;
+        Ok(Self { package, policy })
+    }
+}
+
+impl TryFrom<String> for PackageSourcePolicy {
+    type Error = SourcePolicyError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_str(&value)
+    }
+}
+
+impl From<PackageSourcePolicy> for String {
+    fn from(value: PackageSourcePolicy) -> Self {
+        value.to_string()
+    }
+}
+
+impl Display for PackageSourcePolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.package, self.policy)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for PackageSourcePolicy {
+    fn schema_name() -> String {
+        "PackageSourcePolicy".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some("A source policy for a package, in the form `<package>=<policy>`, where the policy is one of `binary`, `git`, or `index:<url>`.".to_string()),
+                ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SourcePolicyError {
+    #[error("Expected a source policy of the form `<package>=<policy>`, but found: `{0}`")]
+    MissingSeparator(String),
+    #[error(transparent)]
+    InvalidName(#[from] InvalidNameError),
+    #[error("Unknown source policy `{0}` (expected `binary`, `git`, or `index:<url>`)")]
+    InvalidPolicy(String),
+    #[error("Invalid index URL in source policy: `{0}`")]
+    InvalidIndex(String, #[source] IndexUrlError),
+}
+
+/// The [`SourcePolicy`] for each package that has one.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SourcePolicies(BTreeMap<PackageName, SourcePolicy>);
+
+impl SourcePolicies {
+    /// Returns the [`SourcePolicy`] for the given package, if any.
+    pub fn get(&self, name: &PackageName) -> Option<&SourcePolicy> {
+        self.0.get(name)
+    }
+
+    /// Returns `true` if no packages have a source policy.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromIterator<PackageSourcePolicy> for SourcePolicies {
+    /// Collect the policies for each package, with later policies for the same package taking
+    /// precedence.
+    fn from_iter<T: IntoIterator<Item = PackageSourcePolicy>>(iter: T) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|PackageSourcePolicy { package, policy }| (package, policy))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_normalize::PackageName;
+    use uv_pypi_types::ParsedUrl;
+
+    use super::{PackageSourcePolicy, SourcePolicy};
+
+    #[test]
+    fn parse() {
+        let policy = PackageSourcePolicy::from_str("NumPy=binary").unwrap();
+        assert_eq!(policy.package, PackageName::from_str("numpy").unwrap());
+        assert_eq!(policy.policy, SourcePolicy::Binary);
+        assert_eq!(policy.to_string(), "numpy=binary");
+
+        let policy =
+            PackageSourcePolicy::from_str("torch=index:https://download.pytorch.org/whl/cpu")
+                .unwrap();
+        assert!(matches!(policy.policy, SourcePolicy::Index(_)));
+        assert_eq!(
+            policy.to_string(),
+            "torch=index:https://download.pytorch.org/whl/cpu"
+        );
+
+        let err = PackageSourcePolicy::from_str("numpy").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Expected a source policy of the form `<package>=<policy>`, but found: `numpy`"
+        );
+
+        let err = PackageSourcePolicy::from_str("numpy=sdist").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown source policy `sdist` (expected `binary`, `git`, or `index:<url>`)"
+        );
+    }
+
+    #[test]
+    fn allows_url() {
+        let wheel = ParsedUrl::try_from(
+            url::Url::parse("https://example.com/foo-1.0.0-py3-none-any.whl").unwrap(),
+        )
+        .unwrap();
+        let sdist =
+            ParsedUrl::try_from(url::Url::parse("https://example.com/foo-1.0.0.tar.gz").unwrap())
+                .unwrap();
+        let git = ParsedUrl::try_from(
+            url::Url::parse("git+https://github.com/astral-test/uv-public-pypackage").unwrap(),
+        )
+        .unwrap();
+
+        assert!(SourcePolicy::Binary.allows_url(&wheel));
+        assert!(!SourcePolicy::Binary.allows_url(&sdist));
+        assert!(!SourcePolicy::Binary.allows_url(&git));
+
+        assert!(!SourcePolicy::Git.allows_url(&wheel));
+        assert!(SourcePolicy::Git.allows_url(&git));
+    }
+}
@@ -21,7 +21,8 @@ use uv_types::HashStrategy;
 use uv_warnings::warn_user_once;
 
 use crate::flat_index::FlatDistributions;
-use crate::{yanks::AllowedYanks, ExcludeNewer, RequiresPython};
+use crate::wheel_preference::compare_wheels;
+use crate::{yanks::AllowedYanks, ExcludeNewer, RequiresPython, WheelPreference};
 
 /// A map from versions to distributions.
 #[derive(Debug)]
@@ -52,6 +53,8 @@ impl VersionMap {
         exclude_newer: Option<&ExcludeNewer>,
         flat_index: Option<FlatDistributions>,
         build_options: &BuildOptions,
+        wheel_preference: &[WheelPreference],
+        only_hashed: bool,
     ) -> Self {
         let mut stable = false;
         let mut local = false;
@@ -112,6 +115,8 @@ impl VersionMap {
                 hasher: hasher.clone(),
                 requires_python: requires_python.clone(),
                 exclude_newer: exclude_newer.copied(),
+                wheel_preference: wheel_preference.to_vec(),
+                only_hashed,
             }),
         }
     }
@@ -347,6 +352,10 @@ struct VersionMapLazy {
     hasher: HashStrategy,
     /// The `requires-python` constraint for the resolution.
     requires_python: RequiresPython,
+    /// The preferences to apply when selecting amongst compatible wheels.
+    wheel_preference: Vec<WheelPreference>,
+    /// When true, distributions for which the index doesn't provide a hash aren't allowed.
+    only_hashed: bool,
 }
 
 impl VersionMapLazy {
@@ -458,6 +467,10 @@ impl VersionMapLazy {
             if priority_dist.is_empty() {
                 None
             } else {
+                if !self.wheel_preference.is_empty() {
+                    priority_dist
+                        .prefer_wheels(|a, b| compare_wheels(&self.wheel_preference, a, b));
+                }
                 Some(priority_dist)
             }
         };
@@ -494,6 +507,11 @@ impl VersionMapLazy {
             }
         }
 
+        // Check if the index provided a hash, if hashes are required.
+        if self.only_hashed && hashes.is_empty() {
+            return SourceDistCompatibility::Incompatible(IncompatibleSource::MissingHash);
+        }
+
         // Check if hashes line up. If hashes aren't required, they're considered matching.
         let hash_policy = self.hasher.get_package(name, version);
         let required_hashes = hash_policy.digests();
@@ -539,6 +557,11 @@ impl VersionMapLazy {
             }
         }
 
+        // Check if the index provided a hash, if hashes are required.
+        if self.only_hashed && hashes.is_empty() {
+            return WheelCompatibility::Incompatible(IncompatibleWheel::MissingHash);
+        }
+
         // Determine a compatibility for the wheel based on tags.
         let priority = if let Some(tags) = &self.tags {
             match filename.compatibility(tags) {
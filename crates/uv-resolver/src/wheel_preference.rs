@@ -0,0 +1,232 @@
+use std::cmp::Ordering;
+
+use uv_distribution_types::RegistryBuiltWheel;
+use uv_platform_tags::{AbiTag, PlatformTag};
+
+/// A preference between wheels for the same version of a package, when more than one is
+/// compatible with the target environment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum WheelPreference {
+    /// Prefer wheels for glibc-based Linux (`manylinux`) over those for musl-based Linux.
+    Manylinux,
+    /// Prefer wheels for musl-based Linux (`musllinux`) over those for glibc-based Linux.
+    Musllinux,
+    /// Prefer wheels built against the stable ABI (`abi3`) over version-specific wheels.
+    Abi3,
+    /// Prefer version-specific wheels over those built against the stable ABI (`abi3`).
+    NoAbi3,
+    /// Prefer the smallest wheel, based on the file size reported by the index.
+    Smallest,
+}
+
+impl WheelPreference {
+    /// Compare two wheels under this preference, returning [`Ordering::Greater`] if `a` is
+    /// preferred over `b`.
+    fn compare(self, a: &RegistryBuiltWheel, b: &RegistryBuiltWheel) -> Ordering {
+        match self {
+            Self::Manylinux => is_manylinux(a).cmp(&is_manylinux(b)),
+            Self::Musllinux => is_musllinux(a).cmp(&is_musllinux(b)),
+            Self::Abi3 => is_abi3(a).cmp(&is_abi3(b)),
+            Self::NoAbi3 => is_abi3(b).cmp(&is_abi3(a)),
+            // Wheels without a known size are ranked last.
+            Self::Smallest => match (a.file.size, b.file.size) {
+                (Some(a), Some(b)) => b.cmp(&a),
+                (Some(_), None) => Ordering::Greater,
+                (None, Some(_)) => Ordering::Less,
+                (None, None) => Ordering::Equal,
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for WheelPreference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Manylinux => write!(f, "manylinux"),
+            Self::Musllinux => write!(f, "musllinux"),
+            Self::Abi3 => write!(f, "abi3"),
+            Self::NoAbi3 => write!(f, "no-abi3"),
+            Self::Smallest => write!(f, "smallest"),
+        }
+    }
+}
+
+/// Compare two wheels under a list of [`WheelPreference`]s, in order of precedence, returning
+/// [`Ordering::Greater`] if `a` is preferred over `b`.
+pub(crate) fn compare_wheels(
+    preferences: &[WheelPreference],
+    a: &RegistryBuiltWheel,
+    b: &RegistryBuiltWheel,
+) -> Ordering {
+    preferences
+        .iter()
+        .map(|preference| preference.compare(a, b))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+fn is_manylinux(wheel: &RegistryBuiltWheel) -> bool {
+    wheel
+        .filename
+        .platform_tags()
+        .iter()
+        .any(PlatformTag::is_manylinux)
+}
+
+fn is_musllinux(wheel: &RegistryBuiltWheel) -> bool {
+    wheel
+        .filename
+        .platform_tags()
+        .iter()
+        .any(|tag| matches!(tag, PlatformTag::Musllinux { .. }))
+}
+
+fn is_abi3(wheel: &RegistryBuiltWheel) -> bool {
+    wheel.filename.abi_tags().contains(&AbiTag::Abi3)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_distribution_filename::WheelFilename;
+    use uv_distribution_types::{
+        File, FileLocation, HashComparison, IndexUrl, PrioritizedDist, RegistryBuiltWheel,
+        UrlString, WheelCompatibility,
+    };
+    use uv_platform_tags::TagPriority;
+    use uv_pypi_types::HashDigests;
+
+    use super::{compare_wheels, WheelPreference};
+
+    fn wheel(filename: &str, size: Option<u64>) -> RegistryBuiltWheel {
+        RegistryBuiltWheel {
+            filename: WheelFilename::from_str(filename).unwrap(),
+            file: Box::new(File {
+                dist_info_metadata: false,
+                filename: filename.into(),
+                hashes: HashDigests::empty(),
+                requires_python: None,
+                size,
+                upload_time_utc_ms: None,
+                url: FileLocation::AbsoluteUrl(UrlString::new(
+                    format!("https://example.com/{filename}").into(),
+                )),
+                yanked: None,
+            }),
+            index: IndexUrl::from_str("https://example.com/simple").unwrap(),
+        }
+    }
+
+    /// Return the filename of the wheel selected under the given preferences, where wheels are
+    /// given in increasing order of tag priority.
+    fn select(wheels: Vec<RegistryBuiltWheel>, preferences: &[WheelPreference]) -> String {
+        let mut dist = PrioritizedDist::default();
+        for (priority, wheel) in wheels.into_iter().enumerate() {
+            let compatibility = WheelCompatibility::Compatible(
+                HashComparison::Matched,
+                Some(TagPriority::try_from(priority).unwrap()),
+                None,
+            );
+            dist.insert_built(wheel, vec![], compatibility);
+        }
+        dist.prefer_wheels(|a, b| compare_wheels(preferences, a, b));
+        dist.best_wheel().unwrap().0.filename.to_string()
+    }
+
+    #[test]
+    fn no_preference() {
+        let wheels = vec![
+            wheel("foo-1.0.0-cp38-abi3-manylinux_2_17_x86_64.whl", Some(10)),
+            wheel("foo-1.0.0-cp312-cp312-manylinux_2_17_x86_64.whl", Some(20)),
+        ];
+        assert_eq!(
+            select(wheels, &[]),
+            "foo-1.0.0-cp312-cp312-manylinux_2_17_x86_64.whl"
+        );
+    }
+
+    #[test]
+    fn abi3() {
+        let wheels = || {
+            vec![
+                wheel("foo-1.0.0-cp38-abi3-manylinux_2_17_x86_64.whl", Some(10)),
+                wheel("foo-1.0.0-cp312-cp312-manylinux_2_17_x86_64.whl", Some(20)),
+            ]
+        };
+        assert_eq!(
+            select(wheels(), &[WheelPreference::Abi3]),
+            "foo-1.0.0-cp38-abi3-manylinux_2_17_x86_64.whl"
+        );
+        assert_eq!(
+            select(wheels(), &[WheelPreference::NoAbi3]),
+            "foo-1.0.0-cp312-cp312-manylinux_2_17_x86_64.whl"
+        );
+    }
+
+    #[test]
+    fn linux() {
+        let wheels = || {
+            vec![
+                wheel("foo-1.0.0-py3-none-musllinux_1_2_x86_64.whl", Some(20)),
+                wheel("foo-1.0.0-py3-none-manylinux_2_17_x86_64.whl", Some(10)),
+            ]
+        };
+        assert_eq!(
+            select(wheels(), &[WheelPreference::Musllinux]),
+            "foo-1.0.0-py3-none-musllinux_1_2_x86_64.whl"
+        );
+        assert_eq!(
+            select(wheels(), &[WheelPreference::Manylinux]),
+            "foo-1.0.0-py3-none-manylinux_2_17_x86_64.whl"
+        );
+    }
+
+    #[test]
+    fn smallest() {
+        let wheels = vec![
+            wheel("foo-1.0.0-py3-none-musllinux_1_2_x86_64.whl", Some(10)),
+            wheel("foo-1.0.0-py3-none-manylinux_2_17_x86_64.whl", None),
+            wheel("foo-1.0.0-py3-none-any.whl", Some(20)),
+        ];
+        assert_eq!(
+            select(wheels, &[WheelPreference::Smallest]),
+            "foo-1.0.0-py3-none-musllinux_1_2_x86_64.whl"
+        );
+    }
+
+    #[test]
+    fn precedence() {
+        let wheels = || {
+            vec![
+                wheel("foo-1.0.0-cp38-abi3-musllinux_1_2_x86_64.whl", Some(10)),
+                wheel("foo-1.0.0-cp312-cp312-musllinux_1_2_x86_64.whl", Some(30)),
+                wheel("foo-1.0.0-cp312-cp312-manylinux_2_17_x86_64.whl", Some(20)),
+            ]
+        };
+        assert_eq!(
+            select(
+                wheels(),
+                &[WheelPreference::Musllinux, WheelPreference::Smallest]
+            ),
+            "foo-1.0.0-cp38-abi3-musllinux_1_2_x86_64.whl"
+        );
+        assert_eq!(
+            select(
+                wheels(),
+                &[WheelPreference::Musllinux, WheelPreference::NoAbi3]
+            ),
+            "foo-1.0.0-cp312-cp312-musllinux_1_2_x86_64.whl"
+        );
+        assert_eq!(
+            select(
+                wheels(),
+                &[WheelPreference::Smallest, WheelPreference::Musllinux]
+            ),
+            "foo-1.0.0-cp38-abi3-musllinux_1_2_x86_64.whl"
+        );
+    }
+}
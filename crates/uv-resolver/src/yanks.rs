@@ -8,17 +8,58 @@ use uv_pypi_types::RequirementSource;
 
 use crate::{DependencyMode, Manifest, ResolverEnvironment};
 
+/// The policy to apply to files that are marked as yanked by the index.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum YankedPolicy {
+    /// Allow yanked versions, as if they weren't yanked.
+    Allow,
+    /// Allow yanked versions, emitting a warning if a yanked version is selected.
+    Warn,
+    /// Disallow yanked versions, even if they're pinned.
+    Deny,
+    /// Disallow yanked versions, unless they're pinned with an exact (`==` or `===`) requirement
+    /// or by an existing lockfile, emitting a warning if a yanked version is selected.
+    #[default]
+    AllowIfPinned,
+}
+
+impl std::fmt::Display for YankedPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Allow => write!(f, "allow"),
+            Self::Warn => write!(f, "warn"),
+            Self::Deny => write!(f, "deny"),
+            Self::AllowIfPinned => write!(f, "allow-if-pinned"),
+        }
+    }
+}
+
 /// A set of package versions that are permitted, even if they're marked as yanked by the
 /// relevant index.
 #[derive(Debug, Default, Clone)]
-pub struct AllowedYanks(Arc<FxHashMap<PackageName, FxHashSet<Version>>>);
+pub struct AllowedYanks {
+    policy: YankedPolicy,
+    pinned: Arc<FxHashMap<PackageName, FxHashSet<Version>>>,
+}
 
 impl AllowedYanks {
     pub fn from_manifest(
         manifest: &Manifest,
         env: &ResolverEnvironment,
         dependencies: DependencyMode,
+        policy: YankedPolicy,
     ) -> Self {
+        // Only the default policy depends on the set of pinned versions.
+        if policy != YankedPolicy::AllowIfPinned {
+            return Self {
+                policy,
+                pinned: Arc::default(),
+            };
+        }
+
         let mut allowed_yanks = FxHashMap::<PackageName, FxHashSet<Version>>::default();
 
         // Allow yanks for any pinned input requirements.
@@ -48,13 +89,21 @@ impl AllowedYanks {
                 .extend(preferences.map(|(.., version)| version.clone()));
         }
 
-        Self(Arc::new(allowed_yanks))
+        Self {
+            policy,
+            pinned: Arc::new(allowed_yanks),
+        }
     }
 
     /// Returns `true` if the package-version is allowed, even if it's marked as yanked.
     pub fn contains(&self, package_name: &PackageName, version: &Version) -> bool {
-        self.0
-            .get(package_name)
-            .is_some_and(|versions| versions.contains(version))
+        match self.policy {
+            YankedPolicy::Allow | YankedPolicy::Warn => true,
+            YankedPolicy::Deny => false,
+            YankedPolicy::AllowIfPinned => self
+                .pinned
+                .get(package_name)
+                .is_some_and(|versions| versions.contains(version)),
+        }
     }
 }
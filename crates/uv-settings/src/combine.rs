@@ -4,14 +4,18 @@ use std::path::PathBuf;
 use url::Url;
 
 use uv_configuration::{
-    ConfigSettings, IndexStrategy, KeyringProviderType, RequiredVersion, TargetTriple,
+    ByteSize, ConfigSettings, EmbeddedIndexStrategy, IndexStrategy, IndexUnavailablePolicy,
+    KeyringProviderType, PackageBuildEnv, PackageConfigSettings, RequiredVersion, TargetTriple,
     TrustedPublishing,
 };
 use uv_distribution_types::{Index, IndexUrl, PipExtraIndex, PipFindLinks, PipIndex};
 use uv_install_wheel::LinkMode;
 use uv_pypi_types::{SchemaConflicts, SupportedEnvironments};
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
-use uv_resolver::{AnnotationStyle, ExcludeNewer, ForkStrategy, PrereleaseMode, ResolutionMode};
+use uv_resolver::{
+    AnnotationStyle, ExcludeNewer, ExcludeNewerPackage, ForkStrategy, InvalidMetadataPolicy,
+    PrereleaseMode, ResolutionMode, ResolutionPackage, YankedPolicy,
+};
 
 use crate::{FilesystemOptions, Options, PipOptions};
 
@@ -73,11 +77,16 @@ macro_rules! impl_combine_or {
 }
 
 impl_combine_or!(AnnotationStyle);
+impl_combine_or!(ByteSize);
+impl_combine_or!(EmbeddedIndexStrategy);
 impl_combine_or!(ExcludeNewer);
 impl_combine_or!(ForkStrategy);
 impl_combine_or!(Index);
 impl_combine_or!(IndexStrategy);
 impl_combine_or!(IndexUrl);
+impl_combine_or!(IndexUnavailablePolicy);
+impl_combine_or!(InvalidMetadataPolicy);
+impl_combine_or!(YankedPolicy);
 impl_combine_or!(KeyringProviderType);
 impl_combine_or!(LinkMode);
 impl_combine_or!(NonZeroUsize);
@@ -98,6 +107,8 @@ impl_combine_or!(TargetTriple);
 impl_combine_or!(TrustedPublishing);
 impl_combine_or!(Url);
 impl_combine_or!(bool);
+impl_combine_or!(u64);
+impl_combine_or!(usize);
 
 impl<T> Combine for Option<Vec<T>> {
     /// Combine two vectors by extending the vector in `self` with the vector in `other`, if they're
@@ -124,6 +135,50 @@ impl Combine for Option<ConfigSettings> {
     }
 }
 
+impl Combine for Option<PackageBuildEnv> {
+    /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
+    /// `Some`.
+    fn combine(self, other: Option<PackageBuildEnv>) -> Option<PackageBuildEnv> {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.merge(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+impl Combine for Option<PackageConfigSettings> {
+    /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
+    /// `Some`.
+    fn combine(self, other: Option<PackageConfigSettings>) -> Option<PackageConfigSettings> {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.merge(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+impl Combine for Option<ExcludeNewerPackage> {
+    /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
+    /// `Some`.
+    fn combine(self, other: Option<ExcludeNewerPackage>) -> Option<ExcludeNewerPackage> {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.merge(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+impl Combine for Option<ResolutionPackage> {
+    /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
+    /// `Some`.
+    fn combine(self, other: Option<ResolutionPackage>) -> Option<ResolutionPackage> {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.merge(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 impl Combine for serde::de::IgnoredAny {
     fn combine(self, _other: Self) -> Self {
         self
@@ -5,19 +5,24 @@ use url::Url;
 
 use uv_cache_info::CacheKey;
 use uv_configuration::{
-    ConfigSettings, IndexStrategy, KeyringProviderType, PackageNameSpecifier, RequiredVersion,
-    TargetTriple, TrustedHost, TrustedPublishing,
+    ByteSize, ConfigSettings, EmbeddedIndexStrategy, IndexStrategy, IndexUnavailablePolicy,
+    KeyringProviderType, PackageBuildEnv, PackageConfigSettings, PackageNameSpecifier,
+    RequiredVersion, TargetTriple, TrustedHost, TrustedPublishing,
 };
 use uv_distribution_types::{
     Index, IndexUrl, IndexUrlError, PipExtraIndex, PipFindLinks, PipIndex, StaticMetadata,
 };
 use uv_install_wheel::LinkMode;
 use uv_macros::{CombineOptions, OptionsMetadata};
-use uv_normalize::{ExtraName, PackageName};
+use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep508::Requirement;
 use uv_pypi_types::{SupportedEnvironments, VerbatimParsedUrl};
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
-use uv_resolver::{AnnotationStyle, ExcludeNewer, ForkStrategy, PrereleaseMode, ResolutionMode};
+use uv_resolver::{
+    AnnotationStyle, ExcludeNewer, ExcludeNewerPackage, ForkStrategy, InvalidMetadataPolicy,
+    LocalVersionPreference, PackageProvider, PackageSourcePolicy, PrereleaseMode, ResolutionMode,
+    ResolutionPackage, WheelPreference, YankedPolicy,
+};
 use uv_static::EnvVars;
 
 /// A `pyproject.toml` with an (optional) `[tool.uv]` section.
@@ -271,6 +276,9 @@ pub struct GlobalOptions {
     /// The maximum number of source distributions that uv will build concurrently at any given
     /// time.
     ///
+    /// When more builds are pending than can run at once, the cheapest builds (by the size of
+    /// their source tree) are run first.
+    ///
     /// Defaults to the number of available CPU cores.
     #[option(
         default = "None",
@@ -320,6 +328,8 @@ pub struct InstallerOptions {
     pub index_strategy: Option<IndexStrategy>,
     pub keyring_provider: Option<KeyringProviderType>,
     pub config_settings: Option<ConfigSettings>,
+    pub config_settings_package: Option<PackageConfigSettings>,
+    pub build_env: Option<PackageBuildEnv>,
     pub exclude_newer: Option<ExcludeNewer>,
     pub link_mode: Option<LinkMode>,
     pub compile_bytecode: Option<bool>,
@@ -344,11 +354,16 @@ pub struct ResolverOptions {
     pub index_strategy: Option<IndexStrategy>,
     pub keyring_provider: Option<KeyringProviderType>,
     pub resolution: Option<ResolutionMode>,
+    pub resolution_package: Option<ResolutionPackage>,
     pub prerelease: Option<PrereleaseMode>,
+    pub prerelease_package: Option<Vec<PackageName>>,
     pub fork_strategy: Option<ForkStrategy>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
     pub config_settings: Option<ConfigSettings>,
+    pub config_settings_package: Option<PackageConfigSettings>,
+    pub build_env: Option<PackageBuildEnv>,
     pub exclude_newer: Option<ExcludeNewer>,
+    pub exclude_newer_package: Option<ExcludeNewerPackage>,
     pub link_mode: Option<LinkMode>,
     pub upgrade: Option<bool>,
     pub upgrade_package: Option<Vec<Requirement<VerbatimParsedUrl>>>,
@@ -508,6 +523,18 @@ pub struct ResolverInstallerOptions {
         possible_values = true
     )]
     pub resolution: Option<ResolutionMode>,
+    /// The strategy to use when selecting between the different compatible versions of specific
+    /// packages, in the format `PACKAGE = "MODE"`.
+    ///
+    /// Takes precedence over `resolution` for the given packages.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            resolution-package = { numpy = "lowest" }
+        "#
+    )]
+    pub resolution_package: Option<ResolutionPackage>,
     /// The strategy to use when considering pre-release versions.
     ///
     /// By default, uv will accept pre-releases for packages that _only_ publish pre-releases,
@@ -522,6 +549,15 @@ pub struct ResolverInstallerOptions {
         possible_values = true
     )]
     pub prerelease: Option<PrereleaseMode>,
+    /// Allow pre-release versions for specific packages, regardless of the `prerelease` strategy.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            prerelease-package = ["black"]
+        "#
+    )]
+    pub prerelease_package: Option<Vec<PackageName>>,
     /// The strategy to use when selecting multiple versions of a given package across Python
     /// versions and platforms.
     ///
@@ -574,6 +610,33 @@ pub struct ResolverInstallerOptions {
         "#
     )]
     pub config_settings: Option<ConfigSettings>,
+    /// Settings to pass to the [PEP 517](https://peps.python.org/pep-0517/) build backend for
+    /// specific packages, specified as `KEY=VALUE` pairs for each package.
+    ///
+    /// The settings are only passed to the build backend when building the named package, and
+    /// take precedence over any settings in `config-settings` with the same key.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            config-settings-package = { numpy = { setup-args = "-Dallow-noblas=true" } }
+        "#
+    )]
+    pub config_settings_package: Option<PackageConfigSettings>,
+    /// Environment variables to set when building source distributions for specific packages,
+    /// specified as a table of variable names to values for each package.
+    ///
+    /// The variables are only set when building the named package (e.g., to pass `MAX_JOBS` or
+    /// `CMAKE_ARGS` to a package with native extensions), and are not applied to its build
+    /// dependencies.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            build-env = { flash-attn = { MAX_JOBS = "4" } }
+        "#
+    )]
+    pub build_env: Option<PackageBuildEnv>,
     /// Disable isolation when building source distributions.
     ///
     /// Assumes that build dependencies specified by [PEP 518](https://peps.python.org/pep-0518/)
@@ -611,6 +674,21 @@ pub struct ResolverInstallerOptions {
         "#
     )]
     pub exclude_newer: Option<ExcludeNewer>,
+    /// Limit candidate packages for specific packages to those that were uploaded prior to the
+    /// given date.
+    ///
+    /// Accepts a table of package names to dates, where each date is an
+    /// [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339.html) timestamp (e.g.,
+    /// `2006-12-02T02:07:43Z`) or local date (e.g., `2006-12-02`) in your system's configured time
+    /// zone. Takes precedence over `exclude-newer` for the given packages.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            exclude-newer-package = { tqdm = "2022-04-04" }
+        "#
+    )]
+    pub exclude_newer_package: Option<ExcludeNewerPackage>,
     /// The method to use when installing packages from the global cache.
     ///
     /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
@@ -1085,6 +1163,20 @@ pub struct PipOptions {
         "#
     )]
     pub strict: Option<bool>,
+    /// Before installing, verify that each wheel is compatible with the target interpreter.
+    ///
+    /// In addition to the tags in the wheel's filename, checks that the tags declared in its
+    /// `WHEEL` file are supported by the interpreter (including free-threaded and debug builds),
+    /// and, on glibc-based Linux, that any bundled shared libraries don't require a newer glibc
+    /// than the target provides. Fails with the incompatible wheels, rather than installing them.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            check-abi = true
+        "#
+    )]
+    pub check_abi: Option<bool>,
     /// Include optional dependencies from the specified extra; may be provided more than once.
     ///
     /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
@@ -1117,6 +1209,19 @@ pub struct PipOptions {
         "#
     )]
     pub no_extra: Option<Vec<ExtraName>>,
+    /// Include dependencies from the specified dependency group; may be provided more than once.
+    ///
+    /// Dependency groups are defined via `dependency-groups` in a `pyproject.toml`.
+    ///
+    /// Only applies to `pyproject.toml` sources.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            group = ["dev", "lint"]
+        "#
+    )]
+    pub group: Option<Vec<GroupName>>,
     /// Ignore package dependencies, instead only add those packages explicitly listed
     /// on the command line to the resulting requirements file.
     #[option(
@@ -1150,6 +1255,18 @@ pub struct PipOptions {
         possible_values = true
     )]
     pub resolution: Option<ResolutionMode>,
+    /// The strategy to use when selecting between the different compatible versions of specific
+    /// packages, in the format `PACKAGE = "MODE"`.
+    ///
+    /// Takes precedence over `resolution` for the given packages.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            resolution-package = { numpy = "lowest" }
+        "#
+    )]
+    pub resolution_package: Option<ResolutionPackage>,
     /// The strategy to use when considering pre-release versions.
     ///
     /// By default, uv will accept pre-releases for packages that _only_ publish pre-releases,
@@ -1164,6 +1281,15 @@ pub struct PipOptions {
         possible_values = true
     )]
     pub prerelease: Option<PrereleaseMode>,
+    /// Allow pre-release versions for specific packages, regardless of the `prerelease` strategy.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            prerelease-package = ["black"]
+        "#
+    )]
+    pub prerelease_package: Option<Vec<PackageName>>,
     /// The strategy to use when selecting multiple versions of a given package across Python
     /// versions and platforms.
     ///
@@ -1183,6 +1309,190 @@ pub struct PipOptions {
         possible_values = true
     )]
     pub fork_strategy: Option<ForkStrategy>,
+    /// How to handle indexes declared within requirements files (e.g., via `--index-url` or
+    /// `--extra-index-url`).
+    ///
+    /// By default, uv merges such indexes with those provided on the command line or in
+    /// configuration, and uses them for all packages (`merge`). Under `scope`, the requirements
+    /// listed in each file are pinned to the index declared in that file; under `forbid`, uv
+    /// rejects any requirements file that declares an index.
+    #[option(
+        default = "\"merge\"",
+        value_type = "str",
+        example = r#"
+            embedded-index-strategy = "scope"
+        "#,
+        possible_values = true
+    )]
+    pub embedded_index_strategy: Option<EmbeddedIndexStrategy>,
+    /// How to handle files on an index with an unparsable filename or version.
+    ///
+    /// By default, uv skips such files silently (`skip`). Under `warn`, uv skips them with a
+    /// warning that names each file; under `error`, uv fails instead.
+    #[option(
+        default = "\"skip\"",
+        value_type = "str",
+        example = r#"
+            invalid-metadata = "error"
+        "#,
+        possible_values = true
+    )]
+    pub invalid_metadata: Option<InvalidMetadataPolicy>,
+    /// How to handle versions that are marked as yanked by the index.
+    ///
+    /// By default, uv only selects a yanked version if it's pinned with an exact (`==` or `===`)
+    /// requirement, or by an existing output file (`allow-if-pinned`), and warns when it does so.
+    /// Under `allow`, yanked versions are treated as if they weren't yanked; under `warn`, they're
+    /// allowed with a warning; and under `deny`, they're rejected even if they're pinned.
+    #[option(
+        default = "\"allow-if-pinned\"",
+        value_type = "str",
+        example = r#"
+            yanked = "deny"
+        "#,
+        possible_values = true
+    )]
+    pub yanked: Option<YankedPolicy>,
+    /// How to handle an extra index that can't be reached.
+    ///
+    /// By default, uv fails if any index can't be reached (`error`). Under `warn`, uv continues
+    /// with the remaining indexes with a warning. The default index, and any index that's the
+    /// sole source of a package, is always required. Unavailable indexes are only skipped under
+    /// the `unsafe-best-match` and `unsafe-first-match` index strategies.
+    #[option(
+        default = "\"error\"",
+        value_type = "str",
+        example = r#"
+            index-unavailable = "warn"
+        "#,
+        possible_values = true
+    )]
+    pub index_unavailable: Option<IndexUnavailablePolicy>,
+    /// Prefer specific wheel variants when multiple wheels for a version are compatible.
+    ///
+    /// By default, uv selects the most specific compatible wheel for the target platform. Each
+    /// preference reorders the compatible wheels, with earlier preferences taking precedence:
+    ///
+    /// - `manylinux`: Prefer wheels for glibc-based Linux over those for musl-based Linux.
+    /// - `musllinux`: Prefer wheels for musl-based Linux over those for glibc-based Linux.
+    /// - `abi3`: Prefer wheels built against the stable ABI over version-specific wheels.
+    /// - `no-abi3`: Prefer version-specific wheels over those built against the stable ABI.
+    /// - `smallest`: Prefer the smallest wheel, based on the file size reported by the index.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            wheel-preference = ["musllinux", "smallest"]
+        "#
+    )]
+    pub wheel_preference: Option<Vec<WheelPreference>>,
+    /// Prefer specific local version labels when multiple variants of a version are available.
+    ///
+    /// Local versions (like `2.1.0+cu118`) share a public version (`2.1.0`) with other variants,
+    /// which may be served by different indexes. By default, such variants are ranked by version
+    /// order. Each preference is either a local version label (e.g., `cu118` or `cpu`), or `none`
+    /// to prefer the variant without a local version label, with earlier preferences taking
+    /// precedence.
+    ///
+    /// Only variants of the selected public version are reordered; a newer public version is
+    /// always preferred over an older one.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            local-version-preference = ["cu118", "cpu"]
+        "#
+    )]
+    pub local_version_preference: Option<Vec<LocalVersionPreference>>,
+    /// Abort the resolution if it takes longer than the given number of seconds.
+    ///
+    /// On timeout, uv reports the packages involved in the most conflicts, which are typically
+    /// the best candidates for additional constraints.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            resolution-timeout = 60
+        "#
+    )]
+    pub resolution_timeout: Option<u64>,
+    /// Abort the resolution after the given number of backtracking steps.
+    ///
+    /// Each conflict between the versions selected for two packages causes the resolver to
+    /// backtrack. When the limit is exceeded, uv reports the packages involved in the most
+    /// conflicts, which are typically the best candidates for additional constraints.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            max-backtracks = 10000
+        "#
+    )]
+    pub max_backtracks: Option<usize>,
+    /// Restrict the sources from which individual packages may be installed.
+    ///
+    /// Each entry is a `<package>=<policy>` pair, where the policy is one of:
+    ///
+    /// - `binary`: Only install pre-built wheels, whether from an index or a direct URL.
+    /// - `git`: Only install from a Git repository.
+    /// - `index:<url>`: Only install from the given index, ignoring all other indexes and
+    ///   `--find-links` entries.
+    ///
+    /// If a requirement cannot be satisfied under its policy, resolution fails with an error
+    /// naming the violated policy.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            source-policy = ["numpy=binary", "my-lib=git"]
+        "#
+    )]
+    pub source_policy: Option<Vec<PackageSourcePolicy>>,
+    /// Declare that requirements on a package are satisfied by another package.
+    ///
+    /// Each entry is a `<package>=<provider>` pair, e.g., `opencv-python=opencv-python-headless`
+    /// or `pil=pillow`. If the provider is required directly, any requirement on the package,
+    /// whether direct or transitive, is replaced by a requirement on its provider, such that the
+    /// two are never installed alongside each other.
+    ///
+    /// Since the packages are versioned independently, any version specifier on the replaced
+    /// requirement is ignored. Replaced requirements can't be requested from a URL.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            provided-by = ["opencv-python=opencv-python-headless"]
+        "#
+    )]
+    pub provided_by: Option<Vec<PackageProvider>>,
+    /// Fail if the total size of the distributions selected for installation exceeds the given
+    /// limit.
+    ///
+    /// Accepts a size with an optional unit (e.g., `500MB` or `1GiB`). The check is performed
+    /// after resolution, before any distributions are downloaded, using the file sizes reported by
+    /// the index (per PEP 700) or, if unavailable, `HEAD` requests. Packages that are already
+    /// installed or cached are not counted.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            max-total-download-size = "500MB"
+        "#
+    )]
+    pub max_total_download_size: Option<ByteSize>,
+    /// Fail if any distribution selected for installation exceeds the given size.
+    ///
+    /// Accepts a size with an optional unit (e.g., `100MB` or `1GiB`). The check is performed
+    /// after resolution, before any distributions are downloaded, using the file sizes reported by
+    /// the index (per PEP 700) or, if unavailable, `HEAD` requests.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            max-package-size = "100MB"
+        "#
+    )]
+    pub max_package_size: Option<ByteSize>,
     /// Pre-defined static metadata for dependencies of the project (direct or transitive). When
     /// provided, enables the resolver to use the specified metadata instead of querying the
     /// registry or building the relevant package from source.
@@ -1282,6 +1592,19 @@ pub struct PipOptions {
         "#
     )]
     pub generate_hashes: Option<bool>,
+    /// Only select distributions for which the index provides a hash.
+    ///
+    /// Wheels and source distributions that are listed on the index without a hash are treated
+    /// as unavailable, such that every selected distribution can be verified by hash. Combine
+    /// with `generate-hashes` to produce an output file that is fully hash-pinned.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            only-hashed = true
+        "#
+    )]
+    pub only_hashed: Option<bool>,
     /// Settings to pass to the [PEP 517](https://peps.python.org/pep-0517/) build backend,
     /// specified as `KEY=VALUE` pairs.
     #[option(
@@ -1292,6 +1615,33 @@ pub struct PipOptions {
         "#
     )]
     pub config_settings: Option<ConfigSettings>,
+    /// Settings to pass to the [PEP 517](https://peps.python.org/pep-0517/) build backend for
+    /// specific packages, specified as `KEY=VALUE` pairs for each package.
+    ///
+    /// The settings are only passed to the build backend when building the named package, and
+    /// take precedence over any settings in `config-settings` with the same key.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            config-settings-package = { numpy = { setup-args = "-Dallow-noblas=true" } }
+        "#
+    )]
+    pub config_settings_package: Option<PackageConfigSettings>,
+    /// Environment variables to set when building source distributions for specific packages,
+    /// specified as a table of variable names to values for each package.
+    ///
+    /// The variables are only set when building the named package (e.g., to pass `MAX_JOBS` or
+    /// `CMAKE_ARGS` to a package with native extensions), and are not applied to its build
+    /// dependencies.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            build-env = { flash-attn = { MAX_JOBS = "4" } }
+        "#
+    )]
+    pub build_env: Option<PackageBuildEnv>,
     /// The minimum Python version that should be supported by the resolved requirements (e.g.,
     /// `3.8` or `3.8.17`).
     ///
@@ -1318,6 +1668,19 @@ pub struct PipOptions {
         "#
     )]
     pub python_platform: Option<TargetTriple>,
+    /// Enable a user-defined feature for marker evaluation; may be provided more than once.
+    ///
+    /// Features are matched by `uv_features` markers, e.g., `torch ; 'gpu' in uv_features`.
+    /// Requirements guarded by a disabled feature are omitted, unless performing a universal
+    /// resolution, in which case feature markers are preserved in the output.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            feature = ["gpu"]
+        "#
+    )]
+    pub feature: Option<Vec<String>>,
     /// Perform a universal resolution, attempting to generate a single `requirements.txt` output
     /// file that is compatible with all operating systems, architectures, and Python
     /// implementations.
@@ -1346,6 +1709,21 @@ pub struct PipOptions {
         "#
     )]
     pub exclude_newer: Option<ExcludeNewer>,
+    /// Limit candidate packages for specific packages to those that were uploaded prior to the
+    /// given date.
+    ///
+    /// Accepts a table of package names to dates, where each date is an
+    /// [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339.html) timestamp (e.g.,
+    /// `2006-12-02T02:07:43Z`) or local date (e.g., `2006-12-02`) in your system's configured time
+    /// zone. Takes precedence over `exclude-newer` for the given packages.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            exclude-newer-package = { tqdm = "2022-04-04" }
+        "#
+    )]
+    pub exclude_newer_package: Option<ExcludeNewerPackage>,
     /// Specify a package to omit from the output resolution. Its dependencies will still be
     /// included in the resolution. Equivalent to pip-compile's `--unsafe-package` option.
     #[option(
@@ -1407,6 +1785,17 @@ pub struct PipOptions {
         "#
     )]
     pub emit_index_annotation: Option<bool>,
+    /// Include a comment in the header of the output file indicating the range of Python versions
+    /// supported by all of the pinned dependencies (i.e., the intersection of their
+    /// `Requires-Python` metadata).
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            emit-requires-python = true
+        "#
+    )]
+    pub emit_requires_python: Option<bool>,
     /// The style of the annotation comments included in the output file, used to indicate the
     /// source of each package.
     #[option(
@@ -1586,11 +1975,16 @@ impl From<ResolverInstallerOptions> for ResolverOptions {
             index_strategy: value.index_strategy,
             keyring_provider: value.keyring_provider,
             resolution: value.resolution,
+            resolution_package: value.resolution_package,
             prerelease: value.prerelease,
+            prerelease_package: value.prerelease_package,
             fork_strategy: value.fork_strategy,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
+            config_settings_package: value.config_settings_package,
+            build_env: value.build_env,
             exclude_newer: value.exclude_newer,
+            exclude_newer_package: value.exclude_newer_package,
             link_mode: value.link_mode,
             upgrade: value.upgrade,
             upgrade_package: value.upgrade_package,
@@ -1616,6 +2010,8 @@ impl From<ResolverInstallerOptions> for InstallerOptions {
             index_strategy: value.index_strategy,
             keyring_provider: value.keyring_provider,
             config_settings: value.config_settings,
+            config_settings_package: value.config_settings_package,
+            build_env: value.build_env,
             exclude_newer: value.exclude_newer,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
@@ -1649,13 +2045,18 @@ pub struct ToolOptions {
     pub index_strategy: Option<IndexStrategy>,
     pub keyring_provider: Option<KeyringProviderType>,
     pub resolution: Option<ResolutionMode>,
+    pub resolution_package: Option<ResolutionPackage>,
     pub prerelease: Option<PrereleaseMode>,
+    pub prerelease_package: Option<Vec<PackageName>>,
     pub fork_strategy: Option<ForkStrategy>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
     pub config_settings: Option<ConfigSettings>,
+    pub config_settings_package: Option<PackageConfigSettings>,
+    pub build_env: Option<PackageBuildEnv>,
     pub no_build_isolation: Option<bool>,
     pub no_build_isolation_package: Option<Vec<PackageName>>,
     pub exclude_newer: Option<ExcludeNewer>,
+    pub exclude_newer_package: Option<ExcludeNewerPackage>,
     pub link_mode: Option<LinkMode>,
     pub compile_bytecode: Option<bool>,
     pub no_sources: Option<bool>,
@@ -1676,13 +2077,18 @@ impl From<ResolverInstallerOptions> for ToolOptions {
             index_strategy: value.index_strategy,
             keyring_provider: value.keyring_provider,
             resolution: value.resolution,
+            resolution_package: value.resolution_package,
             prerelease: value.prerelease,
+            prerelease_package: value.prerelease_package,
             fork_strategy: value.fork_strategy,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
+            config_settings_package: value.config_settings_package,
+            build_env: value.build_env,
             no_build_isolation: value.no_build_isolation,
             no_build_isolation_package: value.no_build_isolation_package,
             exclude_newer: value.exclude_newer,
+            exclude_newer_package: value.exclude_newer_package,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
             no_sources: value.no_sources,
@@ -1705,13 +2111,18 @@ impl From<ToolOptions> for ResolverInstallerOptions {
             index_strategy: value.index_strategy,
             keyring_provider: value.keyring_provider,
             resolution: value.resolution,
+            resolution_package: value.resolution_package,
             prerelease: value.prerelease,
+            prerelease_package: value.prerelease_package,
             fork_strategy: value.fork_strategy,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
+            config_settings_package: value.config_settings_package,
+            build_env: value.build_env,
             no_build_isolation: value.no_build_isolation,
             no_build_isolation_package: value.no_build_isolation_package,
             exclude_newer: value.exclude_newer,
+            exclude_newer_package: value.exclude_newer_package,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
             no_sources: value.no_sources,
@@ -1757,13 +2168,18 @@ pub struct OptionsWire {
     keyring_provider: Option<KeyringProviderType>,
     allow_insecure_host: Option<Vec<TrustedHost>>,
     resolution: Option<ResolutionMode>,
+    resolution_package: Option<ResolutionPackage>,
     prerelease: Option<PrereleaseMode>,
+    prerelease_package: Option<Vec<PackageName>>,
     fork_strategy: Option<ForkStrategy>,
     dependency_metadata: Option<Vec<StaticMetadata>>,
     config_settings: Option<ConfigSettings>,
+    config_settings_package: Option<PackageConfigSettings>,
+    build_env: Option<PackageBuildEnv>,
     no_build_isolation: Option<bool>,
     no_build_isolation_package: Option<Vec<PackageName>>,
     exclude_newer: Option<ExcludeNewer>,
+    exclude_newer_package: Option<ExcludeNewerPackage>,
     link_mode: Option<LinkMode>,
     compile_bytecode: Option<bool>,
     no_sources: Option<bool>,
@@ -1840,13 +2256,18 @@ impl From<OptionsWire> for Options {
             keyring_provider,
             allow_insecure_host,
             resolution,
+            resolution_package,
             prerelease,
+            prerelease_package,
             fork_strategy,
             dependency_metadata,
             config_settings,
+            config_settings_package,
+            build_env,
             no_build_isolation,
             no_build_isolation_package,
             exclude_newer,
+            exclude_newer_package,
             link_mode,
             compile_bytecode,
             no_sources,
@@ -1904,13 +2325,18 @@ impl From<OptionsWire> for Options {
                 index_strategy,
                 keyring_provider,
                 resolution,
+                resolution_package,
                 prerelease,
+                prerelease_package,
                 fork_strategy,
                 dependency_metadata,
                 config_settings,
+                config_settings_package,
+                build_env,
                 no_build_isolation,
                 no_build_isolation_package,
                 exclude_newer,
+                exclude_newer_package,
                 link_mode,
                 compile_bytecode,
                 no_sources,
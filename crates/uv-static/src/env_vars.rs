@@ -69,6 +69,11 @@ impl EnvVars {
     /// Python can lead to unexpected behavior.
     pub const UV_SYSTEM_PYTHON: &'static str = "UV_SYSTEM_PYTHON";
 
+    /// Equivalent to the `--create-venv` command-line argument. If set to `true`, `uv pip install`
+    /// will create a virtual environment for the project in the current directory if no
+    /// environment is found.
+    pub const UV_PIP_CREATE_VENV: &'static str = "UV_PIP_CREATE_VENV";
+
     /// Equivalent to the `--python` command-line argument. If set to a path, uv will use
     /// this Python interpreter for all operations.
     pub const UV_PYTHON: &'static str = "UV_PYTHON";
@@ -92,6 +97,62 @@ impl EnvVars {
     /// that contains the package.
     pub const UV_INDEX_STRATEGY: &'static str = "UV_INDEX_STRATEGY";
 
+    /// Equivalent to the `--embedded-index-strategy` command-line argument.
+    ///
+    /// For example, if set to `scope`, uv will pin the requirements listed in each requirements
+    /// file to the index declared in that file.
+    pub const UV_EMBEDDED_INDEX_STRATEGY: &'static str = "UV_EMBEDDED_INDEX_STRATEGY";
+
+    /// Equivalent to the `--invalid-metadata` command-line argument.
+    ///
+    /// For example, if set to `error`, uv will fail when an index lists a file with an unparsable
+    /// filename or version, rather than skipping it.
+    pub const UV_INVALID_METADATA: &'static str = "UV_INVALID_METADATA";
+
+    /// Equivalent to the `--yanked` command-line argument.
+    ///
+    /// For example, if set to `deny`, uv will refuse to select a yanked version, even if it's
+    /// pinned.
+    pub const UV_YANKED: &'static str = "UV_YANKED";
+
+    /// Equivalent to the `--index-unavailable` command-line argument.
+    ///
+    /// For example, if set to `warn`, uv will continue with the remaining indexes when an extra
+    /// index can't be reached, rather than failing.
+    pub const UV_INDEX_UNAVAILABLE: &'static str = "UV_INDEX_UNAVAILABLE";
+
+    /// Equivalent to the `--max-total-download-size` command-line argument.
+    ///
+    /// For example, if set to `500MB`, uv will fail if the distributions selected for installation
+    /// exceed 500 MB in total.
+    pub const UV_MAX_TOTAL_DOWNLOAD_SIZE: &'static str = "UV_MAX_TOTAL_DOWNLOAD_SIZE";
+
+    /// Equivalent to the `--max-package-size` command-line argument.
+    ///
+    /// For example, if set to `100MB`, uv will fail if any distribution selected for installation
+    /// exceeds 100 MB.
+    pub const UV_MAX_PACKAGE_SIZE: &'static str = "UV_MAX_PACKAGE_SIZE";
+
+    /// Equivalent to the `--wheel-preference` command-line argument.
+    ///
+    /// Accepts a comma-separated list of preferences, in order of precedence (e.g.,
+    /// `musllinux,smallest`).
+    pub const UV_WHEEL_PREFERENCE: &'static str = "UV_WHEEL_PREFERENCE";
+
+    /// Equivalent to the `--local-version-preference` command-line argument.
+    ///
+    /// Accepts a comma-separated list of local version labels, in order of precedence (e.g.,
+    /// `cu118,cpu`).
+    pub const UV_LOCAL_VERSION_PREFERENCE: &'static str = "UV_LOCAL_VERSION_PREFERENCE";
+
+    /// Equivalent to the `--resolution-timeout` command-line argument. If set, uv will abort the
+    /// resolution after the given number of seconds.
+    pub const UV_RESOLUTION_TIMEOUT: &'static str = "UV_RESOLUTION_TIMEOUT";
+
+    /// Equivalent to the `--max-backtracks` command-line argument. If set, uv will abort the
+    /// resolution after the given number of backtracking steps.
+    pub const UV_MAX_BACKTRACKS: &'static str = "UV_MAX_BACKTRACKS";
+
     /// Equivalent to the `--require-hashes` command-line argument. If set to `true`,
     /// uv will require that all dependencies have a hash specified in the requirements file.
     pub const UV_REQUIRE_HASHES: &'static str = "UV_REQUIRE_HASHES";
@@ -104,6 +165,16 @@ impl EnvVars {
     /// as constraints for any source distribution builds. Uses space-separated list of files.
     pub const UV_BUILD_CONSTRAINT: &'static str = "UV_BUILD_CONSTRAINT";
 
+    /// Equivalent to the `--build-log-dir` command-line argument. If set, uv will capture the
+    /// output of each source distribution build in a per-package log file within the given
+    /// directory.
+    pub const UV_BUILD_LOG_DIR: &'static str = "UV_BUILD_LOG_DIR";
+
+    /// Equivalent to the `--optimize-wheels` command-line argument. If set (e.g., to
+    /// `recompress,strip`), uv will optimize wheels built from source distributions before
+    /// caching them.
+    pub const UV_OPTIMIZE_WHEELS: &'static str = "UV_OPTIMIZE_WHEELS";
+
     /// Equivalent to the `--override` command-line argument. If set, uv will use this file
     /// as the overrides file. Uses space-separated list of files.
     pub const UV_OVERRIDE: &'static str = "UV_OVERRIDE";
@@ -162,6 +233,11 @@ impl EnvVars {
     /// not use pre-built wheels for the given space-delimited list of packages.
     pub const UV_NO_BINARY_PACKAGE: &'static str = "UV_NO_BINARY_PACKAGE";
 
+    /// Equivalent to the `--pip-env` command-line argument. If set, uv will respect pip's
+    /// `PIP_INDEX_URL`, `PIP_EXTRA_INDEX_URL`, `PIP_FIND_LINKS`, and `PIP_NO_BINARY`
+    /// environment variables in the `uv pip` interface.
+    pub const UV_PIP_ENV: &'static str = "UV_PIP_ENV";
+
     /// Equivalent to the `--publish-url` command-line argument. The URL of the upload
     /// endpoint of the index to use with `uv publish`.
     pub const UV_PUBLISH_URL: &'static str = "UV_PUBLISH_URL";
@@ -222,6 +298,10 @@ impl EnvVars {
     /// packages.
     pub const UV_CONCURRENT_INSTALLS: &'static str = "UV_CONCURRENT_INSTALLS";
 
+    /// Equivalent to the `--max-requests-per-second` command-line argument. Sets the maximum
+    /// number of requests per second that uv will send to any one host.
+    pub const UV_MAX_REQUESTS_PER_SECOND: &'static str = "UV_MAX_REQUESTS_PER_SECOND";
+
     /// Equivalent to the `--no-progress` command-line argument. Disables all progress output. For
     /// example, spinners and progress bars.
     pub const UV_NO_PROGRESS: &'static str = "UV_NO_PROGRESS";
@@ -283,6 +363,11 @@ impl EnvVars {
     /// Use to disable line wrapping for diagnostics.
     pub const UV_NO_WRAP: &'static str = "UV_NO_WRAP";
 
+    /// Use to exit with a dedicated code for each class of failure, e.g., `3` for resolution
+    /// failures and `4` for network errors. See the [exit codes](../reference/exit-codes.md)
+    /// reference for details.
+    pub const UV_DETAILED_EXIT_CODES: &'static str = "UV_DETAILED_EXIT_CODES";
+
     /// Provides the HTTP Basic authentication username for a named index.
     ///
     /// The `name` parameter is the name of the index. For example, given an index named `foo`,
@@ -333,6 +418,10 @@ impl EnvVars {
     #[attr_hidden]
     pub const UV_INTERNAL__TEST_DIR: &'static str = "UV_INTERNAL__TEST_DIR";
 
+    /// Used to install a candidate policy in the resolver, to exercise the policy hook in tests.
+    #[attr_hidden]
+    pub const UV_INTERNAL__CANDIDATE_POLICY: &'static str = "UV_INTERNAL__CANDIDATE_POLICY";
+
     /// Path to system-level configuration directory on Unix systems.
     pub const XDG_CONFIG_DIRS: &'static str = "XDG_CONFIG_DIRS";
 
@@ -437,6 +526,41 @@ impl EnvVars {
     /// The standard `PATH` env var.
     pub const PATH: &'static str = "PATH";
 
+    /// The C compiler used by native builds.
+    ///
+    /// Source distributions are cached separately for each value.
+    pub const CC: &'static str = "CC";
+
+    /// The C++ compiler used by native builds.
+    ///
+    /// Source distributions are cached separately for each value.
+    pub const CXX: &'static str = "CXX";
+
+    /// Flags passed to the C compiler by native builds.
+    ///
+    /// Source distributions are cached separately for each value.
+    pub const CFLAGS: &'static str = "CFLAGS";
+
+    /// Flags passed to the C++ compiler by native builds.
+    ///
+    /// Source distributions are cached separately for each value.
+    pub const CXXFLAGS: &'static str = "CXXFLAGS";
+
+    /// Flags passed to the linker by native builds.
+    ///
+    /// Source distributions are cached separately for each value.
+    pub const LDFLAGS: &'static str = "LDFLAGS";
+
+    /// The number of parallel jobs used by some native builds (e.g., `torch` extensions).
+    ///
+    /// Source distributions are cached separately for each value.
+    pub const MAX_JOBS: &'static str = "MAX_JOBS";
+
+    /// The CUDA toolkit used by native builds of CUDA extensions.
+    ///
+    /// Source distributions are cached separately for each value.
+    pub const CUDA_HOME: &'static str = "CUDA_HOME";
+
     /// The standard `HOME` env var.
     pub const HOME: &'static str = "HOME";
 
@@ -492,6 +616,26 @@ impl EnvVars {
     /// Used for trusted publishing via `uv publish`.
     pub const GITHUB_ACTIONS: &'static str = "GITHUB_ACTIONS";
 
+    /// Used to populate the `sha` of a snapshot emitted by
+    /// `uv pip compile --format github-snapshot`.
+    pub const GITHUB_SHA: &'static str = "GITHUB_SHA";
+
+    /// Used to populate the `ref` of a snapshot emitted by
+    /// `uv pip compile --format github-snapshot`.
+    pub const GITHUB_REF: &'static str = "GITHUB_REF";
+
+    /// Used to populate the job `correlator` of a snapshot emitted by
+    /// `uv pip compile --format github-snapshot`.
+    pub const GITHUB_WORKFLOW: &'static str = "GITHUB_WORKFLOW";
+
+    /// Used to populate the job `correlator` of a snapshot emitted by
+    /// `uv pip compile --format github-snapshot`.
+    pub const GITHUB_JOB: &'static str = "GITHUB_JOB";
+
+    /// Used to populate the job `id` of a snapshot emitted by
+    /// `uv pip compile --format github-snapshot`.
+    pub const GITHUB_RUN_ID: &'static str = "GITHUB_RUN_ID";
+
     /// Used for trusted publishing via `uv publish`. Contains the oidc token url.
     pub const ACTIONS_ID_TOKEN_REQUEST_URL: &'static str = "ACTIONS_ID_TOKEN_REQUEST_URL";
 
@@ -521,6 +665,22 @@ impl EnvVars {
     #[attr_hidden]
     pub const CI: &'static str = "CI";
 
+    /// pip's equivalent of `UV_INDEX_URL`. Respected by the `uv pip` interface when `--pip-env`
+    /// is enabled.
+    pub const PIP_INDEX_URL: &'static str = "PIP_INDEX_URL";
+
+    /// pip's equivalent of `UV_EXTRA_INDEX_URL`. Respected by the `uv pip` interface when
+    /// `--pip-env` is enabled.
+    pub const PIP_EXTRA_INDEX_URL: &'static str = "PIP_EXTRA_INDEX_URL";
+
+    /// pip's equivalent of `UV_FIND_LINKS`, as a space-separated list of locations. Respected
+    /// by the `uv pip` interface when `--pip-env` is enabled.
+    pub const PIP_FIND_LINKS: &'static str = "PIP_FIND_LINKS";
+
+    /// pip's equivalent of `--no-binary`, as a comma-separated list of packages (or `:all:`).
+    /// Respected by the `uv pip` interface when `--pip-env` is enabled.
+    pub const PIP_NO_BINARY: &'static str = "PIP_NO_BINARY";
+
     /// Use to set the .netrc file location.
     pub const NETRC: &'static str = "NETRC";
 
@@ -637,9 +797,19 @@ impl EnvVars {
     /// Skip writing `uv` installer metadata files (e.g., `INSTALLER`, `REQUESTED`, and `direct_url.json`) to site-packages `.dist-info` directories.
     pub const UV_NO_INSTALLER_METADATA: &'static str = "UV_NO_INSTALLER_METADATA";
 
+    /// Record statistics about each invocation (e.g., cache hit rates and bytes downloaded) in the
+    /// local state directory, for display via `uv stats`.
+    pub const UV_STATS: &'static str = "UV_STATS";
+
     /// Enables fetching files stored in Git LFS when installing a package from a Git repository.
     pub const UV_GIT_LFS: &'static str = "UV_GIT_LFS";
 
+    /// Disables the detection of nested project roots within source distribution archives. If
+    /// set, uv will build a source archive from its top-level directory (or the `subdirectory`
+    /// provided in its URL), even if that directory doesn't contain a `pyproject.toml`,
+    /// `setup.py`, or `setup.cfg`.
+    pub const UV_NO_DETECT_SOURCE_ROOT: &'static str = "UV_NO_DETECT_SOURCE_ROOT";
+
     /// Number of times that `uv run` has been recursively invoked. Used to guard against infinite
     /// recursion, e.g., when `uv run`` is used in a script shebang.
     #[attr_hidden]
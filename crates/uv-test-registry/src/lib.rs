@@ -0,0 +1,382 @@
+//! An in-memory package registry for exercising resolution and installation scenarios without
+//! network access.
+//!
+//! A [`TestRegistry`] is populated with [`Release`]s, each of which is rendered as a pure-Python
+//! wheel with the given core metadata. With the `server` feature, the registry can be served over
+//! HTTP as a [Simple API](https://packaging.python.org/en/latest/specifications/simple-repository-api/)
+//! index via [`TestRegistry::start`].
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io::{Cursor, Write as _};
+use std::str::FromStr;
+
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use base64::Engine;
+use sha2::{Digest, Sha256};
+use zip::write::FileOptions;
+use zip::ZipWriter;
+
+use uv_normalize::{InvalidNameError, PackageName};
+use uv_pep440::{Version, VersionParseError};
+
+#[cfg(feature = "server")]
+pub use server::RegistryServer;
+
+#[cfg(feature = "server")]
+mod server;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    InvalidName(#[from] InvalidNameError),
+    #[error(transparent)]
+    InvalidVersion(#[from] VersionParseError),
+}
+
+/// A single version of a package, published as a pure-Python wheel.
+#[derive(Debug, Clone)]
+pub struct Release {
+    /// The name of the package, as it appears in the core metadata.
+    name: String,
+    version: Version,
+    requires_dist: Vec<String>,
+    requires_python: Option<String>,
+    provides_extra: Vec<String>,
+    yanked: bool,
+    unhashed: bool,
+    wheel_tag: Option<String>,
+    attested_hash: Option<String>,
+}
+
+impl Release {
+    /// Create a [`Release`] for the given package name and version.
+    pub fn new(name: &str, version: &str) -> Result<Self, Error> {
+        PackageName::from_str(name)?;
+        Ok(Self {
+            name: name.to_string(),
+            version: Version::from_str(version)?,
+            requires_dist: Vec::new(),
+            requires_python: None,
+            provides_extra: Vec::new(),
+            yanked: false,
+            unhashed: false,
+            wheel_tag: None,
+            attested_hash: None,
+        })
+    }
+
+    /// Add a dependency, as a PEP 508 requirement (e.g., `anyio>=4; extra == "async"`).
+    #[must_use]
+    pub fn requires(mut self, requirement: impl Into<String>) -> Self {
+        self.requires_dist.push(requirement.into());
+        self
+    }
+
+    /// Set the `Requires-Python` specifier (e.g., `>=3.8`).
+    #[must_use]
+    pub fn requires_python(mut self, requires_python: impl Into<String>) -> Self {
+        self.requires_python = Some(requires_python.into());
+        self
+    }
+
+    /// Declare an extra provided by the release.
+    #[must_use]
+    pub fn extra(mut self, extra: impl Into<String>) -> Self {
+        self.provides_extra.push(extra.into());
+        self
+    }
+
+    /// Mark the release as yanked.
+    #[must_use]
+    pub fn yanked(mut self) -> Self {
+        self.yanked = true;
+        self
+    }
+
+    /// Link the wheel without its hash, as some indexes do.
+    #[must_use]
+    pub fn unhashed(mut self) -> Self {
+        self.unhashed = true;
+        self
+    }
+
+    /// Declare the given tag (e.g., `cp313-cp313t-any`) in the wheel's `WHEEL` file, in lieu of
+    /// the `py3-none-any` tag in its filename.
+    #[must_use]
+    pub fn wheel_tag(mut self, tag: impl Into<String>) -> Self {
+        self.wheel_tag = Some(tag.into());
+        self
+    }
+
+    /// Report the given hash (e.g., `sha256:...`) from the registry's hash provider endpoint,
+    /// rather than the hash of the wheel itself.
+    #[must_use]
+    pub fn attest(mut self, hash: impl Into<String>) -> Self {
+        self.attested_hash = Some(hash.into());
+        self
+    }
+
+    /// The normalized name of the package.
+    pub fn package_name(&self) -> PackageName {
+        PackageName::from_str(&self.name).expect("name was validated on construction")
+    }
+
+    /// The version of the release.
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    /// The filename of the wheel for this release.
+    pub fn wheel_filename(&self) -> String {
+        format!(
+            "{}-{}-py3-none-any.whl",
+            self.package_name().as_dist_info_name(),
+            self.version
+        )
+    }
+
+    /// Render the core metadata (i.e., the `METADATA` file) for this release.
+    pub fn metadata(&self) -> String {
+        let mut metadata = String::new();
+        writeln!(metadata, "Metadata-Version: 2.3").unwrap();
+        writeln!(metadata, "Name: {}", self.name).unwrap();
+        writeln!(metadata, "Version: {}", self.version).unwrap();
+        if let Some(requires_python) = &self.requires_python {
+            writeln!(metadata, "Requires-Python: {requires_python}").unwrap();
+        }
+        for extra in &self.provides_extra {
+            writeln!(metadata, "Provides-Extra: {extra}").unwrap();
+        }
+        for requirement in &self.requires_dist {
+            writeln!(metadata, "Requires-Dist: {requirement}").unwrap();
+        }
+        metadata
+    }
+
+    /// Build the wheel for this release, containing an empty top-level module named after the
+    /// package.
+    pub fn wheel(&self) -> Vec<u8> {
+        let name = self.package_name();
+        let module = name.as_dist_info_name();
+        let dist_info = format!("{module}-{}.dist-info", self.version);
+
+        let mut files = vec![
+            (
+                format!("{module}/__init__.py"),
+                format!("__version__ = \"{}\"\n", self.version),
+            ),
+            (format!("{dist_info}/METADATA"), self.metadata()),
+            (
+                format!("{dist_info}/WHEEL"),
+                format!(
+                    "Wheel-Version: 1.0\nGenerator: uv-test-registry\nRoot-Is-Purelib: true\nTag: {}\n",
+                    self.wheel_tag.as_deref().unwrap_or("py3-none-any")
+                ),
+            ),
+        ];
+
+        let mut record = String::new();
+        for (path, contents) in &files {
+            let digest = BASE64_URL_SAFE_NO_PAD.encode(Sha256::digest(contents.as_bytes()));
+            writeln!(record, "{path},sha256={digest},{}", contents.len()).unwrap();
+        }
+        writeln!(record, "{dist_info}/RECORD,,").unwrap();
+        files.push((format!("{dist_info}/RECORD"), record));
+
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        for (path, contents) in files {
+            writer
+                .start_file(path, FileOptions::default())
+                .expect("writing to memory is infallible");
+            writer
+                .write_all(contents.as_bytes())
+                .expect("writing to memory is infallible");
+        }
+        writer
+            .finish()
+            .expect("writing to memory is infallible")
+            .into_inner()
+    }
+}
+
+/// A set of packages, each with one or more [`Release`]s.
+#[derive(Debug, Clone, Default)]
+pub struct TestRegistry {
+    packages: BTreeMap<PackageName, Vec<Release>>,
+}
+
+impl TestRegistry {
+    /// Create an empty [`TestRegistry`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a [`Release`] to the registry.
+    #[must_use]
+    pub fn release(mut self, release: Release) -> Self {
+        self.packages
+            .entry(release.package_name())
+            .or_default()
+            .push(release);
+        self
+    }
+
+    /// Return the names of the packages in the registry.
+    pub fn packages(&self) -> impl Iterator<Item = &PackageName> {
+        self.packages.keys()
+    }
+
+    /// Return the [`Release`]s for the given package.
+    pub fn releases(&self, name: &PackageName) -> &[Release] {
+        self.packages
+            .get(name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Find the [`Release`] for the wheel with the given filename.
+    pub fn find_wheel(&self, filename: &str) -> Option<&Release> {
+        self.packages
+            .values()
+            .flatten()
+            .find(|release| release.wheel_filename() == filename)
+    }
+
+    /// Render the Simple API root page, listing every package.
+    pub fn render_index(&self) -> String {
+        let mut html = String::from("<!DOCTYPE html>\n<html>\n<body>\n");
+        for name in self.packages.keys() {
+            writeln!(html, "<a href=\"/simple/{name}/\">{name}</a>").unwrap();
+        }
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+
+    /// Render the Simple API page for the given package, if it exists.
+    ///
+    /// Each wheel is linked with its SHA-256 hash (unless the release is unhashed), and with the
+    /// hash of its core metadata such that clients can fetch the metadata without downloading the
+    /// wheel (per PEP 658).
+    pub fn render_project(&self, name: &PackageName) -> Option<String> {
+        let releases = self.packages.get(name)?;
+        let mut html = String::from("<!DOCTYPE html>\n<html>\n<body>\n");
+        for release in releases {
+            let filename = release.wheel_filename();
+            let fragment = if release.unhashed {
+                String::new()
+            } else {
+                format!("#sha256={:x}", Sha256::digest(release.wheel()))
+            };
+            let metadata_hash = format!("{:x}", Sha256::digest(release.metadata()));
+            write!(
+                html,
+                "<a href=\"/files/{filename}{fragment}\" data-dist-info-metadata=\"sha256={metadata_hash}\" data-core-metadata=\"sha256={metadata_hash}\""
+            )
+            .unwrap();
+            if let Some(requires_python) = &release.requires_python {
+                write!(
+                    html,
+                    " data-requires-python=\"{}\"",
+                    escape_html(requires_python)
+                )
+                .unwrap();
+            }
+            if release.yanked {
+                write!(html, " data-yanked=\"\"").unwrap();
+            }
+            writeln!(html, ">{filename}</a>").unwrap();
+        }
+        html.push_str("</body>\n</html>\n");
+        Some(html)
+    }
+
+    /// Render the hash provider response for the given package version, if it exists, listing
+    /// the attested hash of the release (or, absent an attestation, the hash of its wheel).
+    pub fn render_hashes(&self, name: &PackageName, version: &Version) -> Option<String> {
+        let release = self
+            .packages
+            .get(name)?
+            .iter()
+            .find(|release| release.version == *version)?;
+        let hash = release
+            .attested_hash
+            .clone()
+            .unwrap_or_else(|| format!("sha256:{:x}", Sha256::digest(release.wheel())));
+        Some(format!("{{\"hashes\": [\"{hash}\"]}}"))
+    }
+}
+
+/// Escape a string for use in an HTML attribute.
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Read};
+    use std::str::FromStr;
+
+    use uv_normalize::PackageName;
+
+    use super::{Release, TestRegistry};
+
+    #[test]
+    fn render() {
+        let registry = TestRegistry::new()
+            .release(
+                Release::new("Foo-Bar", "1.0.0")
+                    .unwrap()
+                    .requires("anyio>=4")
+                    .requires_python(">=3.8,<4")
+                    .yanked(),
+            )
+            .release(Release::new("anyio", "4.0.0").unwrap());
+
+        let release = &registry.releases(&PackageName::from_str("foo-bar").unwrap())[0];
+        assert_eq!(release.wheel_filename(), "foo_bar-1.0.0-py3-none-any.whl");
+        assert_eq!(
+            release.metadata(),
+            "Metadata-Version: 2.3\nName: Foo-Bar\nVersion: 1.0.0\nRequires-Python: >=3.8,<4\nRequires-Dist: anyio>=4\n"
+        );
+
+        let index = registry.render_index();
+        assert!(index.contains("<a href=\"/simple/anyio/\">anyio</a>"));
+        assert!(index.contains("<a href=\"/simple/foo-bar/\">foo-bar</a>"));
+
+        let project = registry
+            .render_project(&PackageName::from_str("foo-bar").unwrap())
+            .unwrap();
+        assert!(project.contains("data-requires-python=\"&gt;=3.8,&lt;4\""));
+        assert!(project.contains("data-yanked=\"\""));
+        assert!(project.contains(">foo_bar-1.0.0-py3-none-any.whl</a>"));
+        assert!(project.contains("foo_bar-1.0.0-py3-none-any.whl#sha256="));
+
+        assert!(registry
+            .render_project(&PackageName::from_str("missing").unwrap())
+            .is_none());
+    }
+
+    #[test]
+    fn wheel() {
+        let release = Release::new("foo", "1.0.0")
+            .unwrap()
+            .requires("bar; extra == 'baz'")
+            .extra("baz");
+        let mut archive = zip::ZipArchive::new(Cursor::new(release.wheel())).unwrap();
+
+        let mut metadata = String::new();
+        archive
+            .by_name("foo-1.0.0.dist-info/METADATA")
+            .unwrap()
+            .read_to_string(&mut metadata)
+            .unwrap();
+        assert_eq!(metadata, release.metadata());
+        assert!(archive.by_name("foo/__init__.py").is_ok());
+        assert!(archive.by_name("foo-1.0.0.dist-info/RECORD").is_ok());
+    }
+}
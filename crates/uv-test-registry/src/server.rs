@@ -0,0 +1,174 @@
+use std::convert::Infallible;
+use std::io;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+use http_body_util::Full;
+use hyper::body::{Bytes, Incoming};
+use hyper::header::{HeaderValue, CONTENT_TYPE};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use tokio::sync::oneshot;
+use url::Url;
+
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+use crate::TestRegistry;
+
+/// A [`TestRegistry`] served over HTTP on a local port.
+///
+/// The server runs on a background thread, and is shut down when dropped.
+#[derive(Debug)]
+pub struct RegistryServer {
+    addr: SocketAddr,
+    requests: Arc<Mutex<Vec<String>>>,
+    shutdown: Option<oneshot::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl TestRegistry {
+    /// Serve the registry on a random local port.
+    pub fn start(self) -> io::Result<RegistryServer> {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        listener.set_nonblocking(true)?;
+        let addr = listener.local_addr()?;
+
+        let registry = Arc::new(self);
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let (shutdown, mut shutdown_rx) = oneshot::channel();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()?;
+        let thread = std::thread::Builder::new()
+            .name("uv-test-registry".to_string())
+            .spawn({
+                let requests = requests.clone();
+                move || {
+                    runtime.block_on(async move {
+                        let listener = tokio::net::TcpListener::from_std(listener)
+                            .expect("listener is non-blocking");
+                        loop {
+                            let stream = tokio::select! {
+                                _ = &mut shutdown_rx => break,
+                                accepted = listener.accept() => match accepted {
+                                    Ok((stream, _)) => stream,
+                                    Err(_) => continue,
+                                },
+                            };
+
+                            let registry = registry.clone();
+                            let requests = requests.clone();
+                            let service = service_fn(move |request: Request<Incoming>| {
+                                let path = request.uri().path().to_string();
+                                requests.lock().unwrap().push(path.clone());
+                                let response = respond(&registry, &path);
+                                async move { Ok::<_, Infallible>(response) }
+                            });
+                            tokio::spawn(async move {
+                                // Clients may drop connections at any point; there's no one to
+                                // report the error to.
+                                let _ = http1::Builder::new()
+                                    .serve_connection(TokioIo::new(stream), service)
+                                    .await;
+                            });
+                        }
+                    });
+                }
+            })?;
+
+        Ok(RegistryServer {
+            addr,
+            requests,
+            shutdown: Some(shutdown),
+            thread: Some(thread),
+        })
+    }
+}
+
+impl RegistryServer {
+    /// The URL of the Simple API index (e.g., to pass as `--index-url`).
+    pub fn index_url(&self) -> Url {
+        Url::parse(&format!("http://{}/simple", self.addr)).expect("address is a valid URL")
+    }
+
+    /// The address on which the registry is served.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// The paths requested from the registry so far, in order.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for RegistryServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Respond to a request for the given path.
+fn respond(registry: &TestRegistry, path: &str) -> Response<Full<Bytes>> {
+    let segments = path
+        .trim_matches('/')
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>();
+
+    match segments.as_slice() {
+        ["simple"] => html(registry.render_index()),
+        ["simple", name] => PackageName::from_str(name)
+            .ok()
+            .and_then(|name| registry.render_project(&name))
+            .map_or_else(not_found, html),
+        ["files", filename] => {
+            if let Some(filename) = filename.strip_suffix(".metadata") {
+                registry
+                    .find_wheel(filename)
+                    .map_or_else(not_found, |release| {
+                        Response::new(Full::new(Bytes::from(release.metadata())))
+                    })
+            } else {
+                registry
+                    .find_wheel(filename)
+                    .map_or_else(not_found, |release| {
+                        Response::new(Full::new(Bytes::from(release.wheel())))
+                    })
+            }
+        }
+        ["hashes", name, version] => PackageName::from_str(name)
+            .ok()
+            .zip(Version::from_str(version).ok())
+            .and_then(|(name, version)| registry.render_hashes(&name, &version))
+            .map_or_else(not_found, |body| {
+                Response::new(Full::new(Bytes::from(body)))
+            }),
+        _ => not_found(),
+    }
+}
+
+fn html(body: String) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::new(Bytes::from(body)));
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("text/html"));
+    response
+}
+
+fn not_found() -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::new(Bytes::new()));
+    *response.status_mut() = StatusCode::NOT_FOUND;
+    response
+}
@@ -129,6 +129,7 @@ impl HashStrategy {
     pub fn from_requirements<'a>(
         requirements: impl Iterator<Item = (&'a UnresolvedRequirement, &'a [String])>,
         constraints: impl Iterator<Item = (&'a Requirement, &'a [String])>,
+        known: &KnownHashes,
         marker_env: Option<&ResolverMarkerEnvironment>,
         mode: HashCheckingMode,
     ) -> Result<Self, HashStrategyError> {
@@ -176,6 +177,9 @@ impl HashStrategy {
             constraint_hashes.insert(id, digests);
         }
 
+        // Treat the known hashes as additional constraints.
+        known.constrain(&mut constraint_hashes)?;
+
         // For each requirement, map from name to allowed hashes. We use the last entry for each
         // package.
         let mut requirement_hashes = FxHashMap::<VersionId, Vec<HashDigest>>::default();
@@ -223,6 +227,15 @@ impl HashStrategy {
                     .collect::<Result<Vec<_>, _>>()?
             };
 
+            // If the requirement provides its own hashes, they must overlap with the known hashes.
+            if !digests.is_empty() {
+                if let Some(known) = known.get(&id) {
+                    if !digests.iter().any(|digest| known.contains(digest)) {
+                        return Err(HashStrategyError::NoKnownIntersection(id.to_string()));
+                    }
+                }
+            }
+
             let digests = if let Some(constraint) = constraint_hashes.remove(&id) {
                 if digests.is_empty() {
                     // If there are _only_ hashes on the constraints, use them.
@@ -274,6 +287,7 @@ impl HashStrategy {
     /// Generate the required hashes from a [`Resolution`].
     pub fn from_resolution(
         resolution: &Resolution,
+        known: &KnownHashes,
         mode: HashCheckingMode,
     ) -> Result<Self, HashStrategyError> {
         let mut hashes = FxHashMap::<VersionId, Vec<HashDigest>>::default();
@@ -292,6 +306,9 @@ impl HashStrategy {
             hashes.insert(dist.version_id(), digests.to_vec());
         }
 
+        // Enforce the known hashes on top of those in the resolution.
+        known.constrain(&mut hashes)?;
+
         match mode {
             HashCheckingMode::Verify => Ok(Self::Verify(Arc::new(hashes))),
             HashCheckingMode::Require => Ok(Self::Require(Arc::new(hashes))),
@@ -325,6 +342,56 @@ impl HashStrategy {
     }
 }
 
+/// A set of known hashes, keyed by distribution.
+///
+/// Unlike the hashes attached to individual requirements, known hashes (e.g., as read from a
+/// `uv.hashes.toml` file) are enforced for any matching distribution, regardless of how that
+/// distribution was requested.
+#[derive(Debug, Default, Clone)]
+pub struct KnownHashes(FxHashMap<VersionId, Vec<HashDigest>>);
+
+impl KnownHashes {
+    /// Add the known hashes for the given distribution.
+    ///
+    /// Registry-based distributions are identified by name and version (as in
+    /// [`VersionId::from_registry`]); URL, path, and Git distributions are identified by their URL
+    /// (as in [`VersionId::from_url`]).
+    pub fn insert(&mut self, id: VersionId, digests: Vec<HashDigest>) {
+        self.0.entry(id).or_default().extend(digests);
+    }
+
+    /// Returns the known hashes for the given distribution, if any.
+    fn get(&self, id: &VersionId) -> Option<&[HashDigest]> {
+        self.0.get(id).map(Vec::as_slice)
+    }
+
+    /// Returns `true` if there are no known hashes.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Constrain the given hashes by the known hashes.
+    ///
+    /// If a distribution has both known and given hashes, the result is their intersection;
+    /// if it only has known hashes, the known hashes are used as-is.
+    fn constrain(
+        &self,
+        hashes: &mut FxHashMap<VersionId, Vec<HashDigest>>,
+    ) -> Result<(), HashStrategyError> {
+        for (id, known) in &self.0 {
+            if let Some(digests) = hashes.get_mut(id) {
+                digests.retain(|digest| known.contains(digest));
+                if digests.is_empty() {
+                    return Err(HashStrategyError::NoKnownIntersection(id.to_string()));
+                }
+            } else {
+                hashes.insert(id.clone(), known.clone());
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum HashStrategyError {
     #[error(transparent)]
@@ -337,4 +404,6 @@ pub enum HashStrategyError {
     MissingHashes(String, HashCheckingMode),
     #[error("In `{1}` mode, all requirements must have a hash, but there were no overlapping hashes between the requirements and constraints for: {0}")]
     NoIntersection(String, HashCheckingMode),
+    #[error("None of the provided hashes for `{0}` match the known hashes")]
+    NoKnownIntersection(String),
 }
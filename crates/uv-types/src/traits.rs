@@ -1,13 +1,16 @@
+use std::convert::Infallible;
 use std::fmt::{Debug, Display, Formatter};
 use std::future::Future;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use anyhow::Result;
-use rustc_hash::FxHashSet;
-
 use uv_cache::Cache;
-use uv_configuration::{BuildKind, BuildOptions, BuildOutput, ConfigSettings, SourceStrategy};
+use uv_configuration::{
+    BuildKind, BuildOptions, BuildOutput, ConfigSettings, PackageBuildEnv, PackageConfigSettings,
+    SourceStrategy, WheelOptimization,
+};
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{
     CachedDist, DependencyMetadata, DistributionId, IndexCapabilities, IndexLocations,
@@ -18,13 +21,14 @@ use uv_pep508::PackageName;
 use uv_pypi_types::Requirement;
 use uv_python::{Interpreter, PythonEnvironment};
 
-///  Avoids cyclic crate dependencies between resolver, installer and builder.
+/// The context in which source distributions are built, used to avoid cyclic crate dependencies
+/// between the resolver, installer and builder.
 ///
-/// To resolve the dependencies of a packages, we may need to build one or more source
-/// distributions. To building a source distribution, we need to create a virtual environment from
-/// the same base python as we use for the root resolution, resolve the build requirements
-/// (potentially which nested source distributions, recursing a level deeper), installing
-/// them and then build. The installer, the resolver and the source distribution builder are each in
+/// To resolve the dependencies of a package, we may need to build one or more source
+/// distributions. To build a source distribution, we need to create a virtual environment from
+/// the same base Python as we use for the root resolution, resolve the build requirements
+/// (potentially with nested source distributions, recursing a level deeper), install them, and
+/// then build. The installer, the resolver and the source distribution builder are each in
 /// their own crate. To avoid circular crate dependencies, this type dispatches between the three
 /// crates with its three main methods ([`BuildContext::resolve`], [`BuildContext::install`] and
 /// [`BuildContext::setup_build`]).
@@ -55,7 +59,34 @@ use uv_python::{Interpreter, PythonEnvironment};
 /// Put in a different way, the types here allow `uv-resolver` to depend on `uv-build` and
 /// `uv-build-frontend` to depend on `uv-resolver` without having actual crate dependencies between
 /// them.
+///
+/// ## Custom implementations
+///
+/// `uv-dispatch` provides the implementation used by uv itself, but the trait is public so that
+/// external build orchestrators can supply their own, e.g., to create build environments from
+/// toolchains managed by another build system. The source distribution builder in
+/// `uv-distribution` (reached via `DistributionDatabase`) only interacts with the build context
+/// through this trait, so it can be reused unchanged with any implementation.
+///
+/// An implementation must provide:
+///
+/// - The shared state: the [`Interpreter`] that built wheels must be compatible with, the
+///   [`Cache`] in which built wheels and metadata are stored, the [`GitResolver`] and the
+///   [`IndexCapabilities`] (which may be shared across contexts to avoid redundant fetches).
+/// - The build settings: [`BuildOptions`], config settings, per-package environment variables and
+///   the [`SourceStrategy`]. These participate in cache keys, so they must be stable for the
+///   lifetime of the context.
+/// - The build environment: [`BuildContext::setup_build`] is responsible for creating an isolated
+///   environment, installing the build requirements into it, and returning a
+///   [`SourceBuildTrait`] that runs the PEP 517 hooks. Implementations that manage their own
+///   environments can do so here, without calling [`BuildContext::resolve`] or
+///   [`BuildContext::install`].
+///
+/// [`BuildContext::build_log_dir`], [`BuildContext::wheel_optimizations`] and
+/// [`BuildContext::direct_build`] have default implementations that disable the respective
+/// feature.
 pub trait BuildContext {
+    /// The builder returned by [`BuildContext::setup_build`].
     type SourceDistBuilder: SourceBuildTrait;
 
     /// Return a reference to the interpreter.
@@ -75,13 +106,34 @@ pub trait BuildContext {
 
     /// Whether source distribution building or pre-built wheels is disabled.
     ///
-    /// This [`BuildContext::setup_build`] calls will fail if builds are disabled.
+    /// [`BuildContext::setup_build`] calls will fail if builds are disabled.
     /// This method exists to avoid fetching source distributions if we know we can't build them.
     fn build_options(&self) -> &BuildOptions;
 
     /// The [`ConfigSettings`] used to build distributions.
     fn config_settings(&self) -> &ConfigSettings;
 
+    /// The per-package [`ConfigSettings`] used to build distributions, which take precedence over
+    /// the global [`ConfigSettings`].
+    fn config_settings_package(&self) -> &PackageConfigSettings;
+
+    /// The per-package environment variables to set when building distributions.
+    fn build_env(&self) -> &PackageBuildEnv;
+
+    /// The directory in which to capture the output of each source distribution build, if any.
+    ///
+    /// Defaults to `None`, i.e., build output is not persisted.
+    fn build_log_dir(&self) -> Option<&Path> {
+        None
+    }
+
+    /// The optimizations to apply to wheels after they're built from a source distribution.
+    ///
+    /// Defaults to no optimizations.
+    fn wheel_optimizations(&self) -> &[WheelOptimization] {
+        &[]
+    }
+
     /// Whether to incorporate `tool.uv.sources` when resolving requirements.
     fn sources(&self) -> SourceStrategy;
 
@@ -89,6 +141,8 @@ pub trait BuildContext {
     fn locations(&self) -> &IndexLocations;
 
     /// Resolve the given requirements into a ready-to-install set of package versions.
+    ///
+    /// Used by `uv-build-frontend` to resolve the build requirements of a source distribution.
     fn resolve<'a>(
         &'a self,
         requirements: &'a [Requirement],
@@ -96,7 +150,9 @@ pub trait BuildContext {
     ) -> impl Future<Output = Result<Resolution, impl IsBuildBackendError>> + 'a;
 
     /// Install the given set of package versions into the virtual environment. The environment must
-    /// use the same base Python as [`BuildContext::interpreter`]
+    /// use the same base Python as [`BuildContext::interpreter`].
+    ///
+    /// Used by `uv-build-frontend` to populate the build environment of a source distribution.
     fn install<'a>(
         &'a self,
         resolution: &'a Resolution,
@@ -130,20 +186,27 @@ pub trait BuildContext {
     /// it builds and returns the name of the built file.
     ///
     /// `version_id` is for error reporting only.
+    ///
+    /// Defaults to `Ok(None)`, such that all builds go through [`BuildContext::setup_build`].
     fn direct_build<'a>(
         &'a self,
-        source: &'a Path,
-        subdirectory: Option<&'a Path>,
-        output_dir: &'a Path,
-        build_kind: BuildKind,
-        version_id: Option<&'a str>,
-    ) -> impl Future<Output = Result<Option<DistFilename>, impl IsBuildBackendError>> + 'a;
+        _source: &'a Path,
+        _subdirectory: Option<&'a Path>,
+        _output_dir: &'a Path,
+        _build_kind: BuildKind,
+        _version_id: Option<&'a str>,
+    ) -> impl Future<Output = Result<Option<DistFilename>, impl IsBuildBackendError>> + 'a {
+        std::future::ready(Ok::<_, Infallible>(None))
+    }
 }
 
 /// A wrapper for `uv_build::SourceBuild` to avoid cyclical crate dependencies.
 ///
 /// You can either call only `wheel()` to build the wheel directly, call only `metadata()` to get
 /// the metadata without performing the actual or first call `metadata()` and then `wheel()`.
+/// A callback invoked with each line of output produced by a build backend.
+pub type BuildOutputCallback = Arc<dyn Fn(&str) + Send + Sync>;
+
 pub trait SourceBuildTrait {
     /// A wrapper for `uv_build::SourceBuild::get_metadata_without_build`.
     ///
@@ -164,6 +227,10 @@ pub trait SourceBuildTrait {
         &'a self,
         wheel_dir: &'a Path,
     ) -> impl Future<Output = Result<String, AnyErrorBuild>> + 'a;
+
+    /// Invoke the given callback with each line of output produced by subsequent build steps, as
+    /// it's produced.
+    fn on_output(&mut self, callback: BuildOutputCallback);
 }
 
 /// A wrapper for [`uv_installer::SitePackages`]
@@ -244,22 +311,43 @@ impl Deref for AnyErrorBuild {
     }
 }
 
-/// The stack of packages being built.
+/// The stack of packages being built, in the order in which the builds were started.
 #[derive(Debug, Clone, Default)]
-pub struct BuildStack(FxHashSet<DistributionId>);
+pub struct BuildStack(Vec<(DistributionId, PackageName)>);
 
 impl BuildStack {
     /// Return an empty stack.
     pub fn empty() -> Self {
-        Self(FxHashSet::default())
+        Self(Vec::new())
     }
 
     pub fn contains(&self, id: &DistributionId) -> bool {
-        self.0.contains(id)
+        self.0.iter().any(|(existing, _)| existing == id)
     }
 
     /// Push a package onto the stack.
-    pub fn insert(&mut self, id: DistributionId) -> bool {
-        self.0.insert(id)
+    ///
+    /// Returns `false` if the package is already on the stack.
+    pub fn insert(&mut self, id: DistributionId, name: PackageName) -> bool {
+        if self.contains(&id) {
+            return false;
+        }
+        self.0.push((id, name));
+        true
+    }
+
+    /// Return the cycle that would be formed by building the given package, starting from the
+    /// build that first required it, e.g., `[a, b, a]`.
+    ///
+    /// Returns `None` if the package isn't on the stack.
+    pub fn cycle(&self, id: &DistributionId, name: &PackageName) -> Option<Vec<PackageName>> {
+        let start = self.0.iter().position(|(existing, _)| existing == id)?;
+        Some(
+            self.0[start..]
+                .iter()
+                .map(|(_, name)| name.clone())
+                .chain(std::iter::once(name.clone()))
+                .collect(),
+        )
     }
 }
@@ -1541,6 +1541,7 @@ impl Source {
                         GitReference::BranchOrTag(rev) => Some(rev),
                         GitReference::BranchOrTagOrCommit(rev) => Some(rev),
                         GitReference::NamedRef(rev) => Some(rev),
+                        GitReference::VersionRange(rev) => Some(rev),
                         GitReference::DefaultBranch => None,
                     };
                     Source::Git {
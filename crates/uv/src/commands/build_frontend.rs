@@ -22,7 +22,8 @@ use uv_cache::{Cache, CacheBucket};
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildKind, BuildOptions, BuildOutput, Concurrency, ConfigSettings, Constraints,
-    HashCheckingMode, IndexStrategy, KeyringProviderType, PreviewMode, SourceStrategy, TrustedHost,
+    HashCheckingMode, IndexStrategy, KeyringProviderType, PackageBuildEnv, PackageConfigSettings,
+    PreviewMode, SourceStrategy, TrustedHost,
 };
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution_filename::{
@@ -39,9 +40,11 @@ use uv_python::{
     VersionRequest,
 };
 use uv_requirements::RequirementsSource;
-use uv_resolver::{ExcludeNewer, FlatIndex, RequiresPython};
+use uv_resolver::{ExcludeNewer, ExcludeNewerPackage, FlatIndex, RequiresPython};
 use uv_settings::PythonInstallMirrors;
-use uv_types::{AnyErrorBuild, BuildContext, BuildIsolation, BuildStack, HashStrategy};
+use uv_types::{
+    AnyErrorBuild, BuildContext, BuildIsolation, BuildStack, HashStrategy, KnownHashes,
+};
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceError};
 
 #[derive(Debug, Error)]
@@ -205,13 +208,18 @@ async fn build_impl(
         index_strategy,
         keyring_provider,
         resolution: _,
+        resolution_package: _,
         prerelease: _,
+        prerelease_package: _,
         fork_strategy: _,
         dependency_metadata,
         config_setting,
+        config_setting_package,
+        build_env,
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
+        exclude_newer_package,
         link_mode,
         upgrade: _,
         build_options,
@@ -345,6 +353,7 @@ async fn build_impl(
             keyring_provider,
             allow_insecure_host,
             exclude_newer,
+            exclude_newer_package,
             sources,
             concurrency,
             build_options,
@@ -354,6 +363,8 @@ async fn build_impl(
             dependency_metadata,
             link_mode,
             config_setting,
+            config_setting_package,
+            build_env,
             preview,
         );
         async {
@@ -425,6 +436,7 @@ async fn build_package(
     keyring_provider: KeyringProviderType,
     allow_insecure_host: &[TrustedHost],
     exclude_newer: Option<ExcludeNewer>,
+    exclude_newer_package: &ExcludeNewerPackage,
     sources: SourceStrategy,
     concurrency: Concurrency,
     build_options: &BuildOptions,
@@ -434,6 +446,8 @@ async fn build_package(
     dependency_metadata: &DependencyMetadata,
     link_mode: LinkMode,
     config_setting: &ConfigSettings,
+    config_setting_package: &PackageConfigSettings,
+    build_env: &PackageBuildEnv,
     preview: PreviewMode,
 ) -> Result<Vec<BuildMessage>, Error> {
     let output_dir = if let Some(output_dir) = output_dir {
@@ -513,6 +527,7 @@ async fn build_package(
             build_constraints
                 .iter()
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            &KnownHashes::default(),
             Some(&interpreter.resolver_marker_environment()),
             hash_checking,
         )?
@@ -574,6 +589,8 @@ async fn build_package(
         state.clone(),
         index_strategy,
         config_setting,
+        config_setting_package,
+        build_env,
         build_isolation,
         link_mode,
         build_options,
@@ -582,7 +599,8 @@ async fn build_package(
         sources,
         concurrency,
         preview,
-    );
+    )
+    .with_exclude_newer_package(exclude_newer_package.clone());
 
     prepare_output_directory(&output_dir).await?;
 
@@ -0,0 +1,86 @@
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_distribution_filename::{SourceDistFilename, WheelFilename};
+use uv_fs::Simplified;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Add the wheels and source distributions in one or more local directories to the cache.
+pub(crate) async fn cache_add(
+    paths: &[PathBuf],
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let mut num_added = 0usize;
+    let mut num_cached = 0usize;
+
+    for directory in paths {
+        if !directory.is_dir() {
+            anyhow::bail!(
+                "Expected a directory of distributions, but found: `{}`",
+                directory.user_display()
+            );
+        }
+
+        let mut entries = fs_err::read_dir(directory)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        entries.sort();
+
+        for path in entries {
+            if !path.is_file() {
+                continue;
+            }
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+
+            let added = if let Ok(filename) = WheelFilename::from_str(name) {
+                uv_distribution::ingest_wheel(cache, &path, &filename).await
+            } else if let Ok(filename) = SourceDistFilename::parsed_normalized_filename(name) {
+                uv_distribution::ingest_source_dist(cache, &path, &filename).await
+            } else {
+                debug!("Skipping non-distribution file: {}", path.user_display());
+                continue;
+            };
+            let added = added
+                .with_context(|| format!("Failed to add `{}` to the cache", path.user_display()))?;
+
+            if added {
+                debug!("Added to cache: {}", path.user_display());
+                num_added += 1;
+            } else {
+                debug!("Already cached: {}", path.user_display());
+                num_cached += 1;
+            }
+        }
+    }
+
+    let s = if num_added == 1 { "" } else { "s" };
+    if num_cached == 0 {
+        writeln!(
+            printer.stderr(),
+            "Added {} to: {}",
+            format!("{num_added} distribution{s}").bold(),
+            cache.root().user_display().cyan()
+        )?;
+    } else {
+        writeln!(
+            printer.stderr(),
+            "Added {} to: {} ({} already cached)",
+            format!("{num_added} distribution{s}").bold(),
+            cache.root().user_display().cyan(),
+            num_cached
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
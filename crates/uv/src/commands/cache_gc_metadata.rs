@@ -0,0 +1,66 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+/// Remove corrupt (or, with `all`, all) distribution metadata from the cache.
+pub(crate) fn cache_gc_metadata(all: bool, cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Collecting metadata in cache at: {}",
+        cache.root().user_display().cyan()
+    )?;
+
+    let summary = uv_distribution::gc_metadata(cache, all).with_context(|| {
+        format!(
+            "Failed to collect metadata in cache at: {}",
+            cache.root().user_display()
+        )
+    })?;
+
+    // Write a summary of the number of entries removed.
+    match summary.num_files {
+        0 if all => {
+            write!(printer.stderr(), "No metadata entries found")?;
+        }
+        0 => {
+            write!(printer.stderr(), "No corrupt metadata entries found")?;
+        }
+        1 => {
+            write!(printer.stderr(), "Removed 1 metadata entry")?;
+        }
+        num_files => {
+            write!(printer.stderr(), "Removed {num_files} metadata entries")?;
+        }
+    }
+
+    // If any, write a summary of the total byte count removed.
+    if summary.total_bytes > 0 {
+        let bytes = if summary.total_bytes < 1024 {
+            format!("{}B", summary.total_bytes)
+        } else {
+            let (bytes, unit) = human_readable_bytes(summary.total_bytes);
+            format!("{bytes:.1}{unit}")
+        };
+        write!(printer.stderr(), " ({})", bytes.green())?;
+    }
+
+    writeln!(printer.stderr())?;
+
+    Ok(ExitStatus::Success)
+}
@@ -12,7 +12,7 @@ use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_resolver::SentinelRange;
 
-use crate::commands::pip;
+use crate::commands::{pip, ExitStatus};
 
 static SUGGESTIONS: LazyLock<FxHashMap<PackageName, PackageName>> = LazyLock::new(|| {
     let suggestions: Vec<(String, String)> =
@@ -70,8 +70,13 @@ impl OperationDiagnostic {
 
     /// Attempt to report an error with rich diagnostic context.
     ///
-    /// Returns `Some` if the error was not handled.
-    pub(crate) fn report(self, err: pip::operations::Error) -> Option<pip::operations::Error> {
+    /// Returns the [`ExitStatus`] for the failure if the error was handled, or the error itself
+    /// if it was not.
+    pub(crate) fn report(
+        self,
+        err: pip::operations::Error,
+    ) -> Result<ExitStatus, pip::operations::Error> {
+        let status = ExitStatus::from_failure(&err);
         match err {
             pip::operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err)) => {
                 if let Some(context) = self.context {
@@ -83,7 +88,7 @@ impl OperationDiagnostic {
                 } else {
                     no_solution(&err);
                 }
-                None
+                Ok(status)
             }
             pip::operations::Error::Resolve(uv_resolver::ResolveError::Dist(
                 kind,
@@ -92,11 +97,11 @@ impl OperationDiagnostic {
                 err,
             )) => {
                 requested_dist_error(kind, dist, &chain, err);
-                None
+                Ok(status)
             }
             pip::operations::Error::Requirements(uv_requirements::Error::Dist(kind, dist, err)) => {
                 dist_error(kind, dist, &DerivationChain::default(), Arc::new(err));
-                None
+                Ok(status)
             }
             pip::operations::Error::Prepare(uv_installer::PrepareError::Dist(
                 kind,
@@ -105,25 +110,29 @@ impl OperationDiagnostic {
                 err,
             )) => {
                 dist_error(kind, dist, &chain, Arc::new(err));
-                None
+                Ok(status)
             }
             pip::operations::Error::Requirements(err) => {
                 if let Some(context) = self.context {
                     let err = miette::Report::msg(format!("{err}"))
                         .context(format!("Failed to resolve {context} requirement"));
                     anstream::eprint!("{err:?}");
-                    None
+                    Ok(status)
                 } else {
-                    Some(pip::operations::Error::Requirements(err))
+                    Err(pip::operations::Error::Requirements(err))
                 }
             }
+            pip::operations::Error::Unavailable(err) => {
+                unavailable_requirements(&err);
+                Ok(ExitStatus::Failure)
+            }
             pip::operations::Error::Resolve(uv_resolver::ResolveError::Client(err))
                 if !self.native_tls && err.is_ssl() =>
             {
                 native_tls_hint(err);
-                None
+                Ok(status)
             }
-            err => Some(err),
+            err => Err(err),
         }
     }
 }
@@ -253,6 +262,20 @@ pub(crate) fn no_solution_hint(err: uv_resolver::NoSolutionError, help: String)
     anstream::eprint!("{report:?}");
 }
 
+/// Render a [`pip::operations::UnavailableRequirementsError`], with a hint if no `--find-links`
+/// locations were provided.
+pub(crate) fn unavailable_requirements(err: &pip::operations::UnavailableRequirementsError) {
+    anstream::eprintln!("{}{} {err}", "error".red().bold(), ":".bold());
+    if !err.find_links() {
+        anstream::eprintln!(
+            "\n{}{} Packages were unavailable because index lookups were disabled and no additional package locations were provided (try: `{}`)",
+            "hint".bold().cyan(),
+            ":".bold(),
+            "--find-links <uri>".green(),
+        );
+    }
+}
+
 /// Render a [`uv_resolver::NoSolutionError`] with a help message.
 pub(crate) fn native_tls_hint(err: uv_client::Error) {
     #[derive(Debug, miette::Diagnostic)]
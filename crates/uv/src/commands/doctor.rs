@@ -0,0 +1,388 @@
+use std::error::Error;
+use std::fmt::Write;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use reqwest::StatusCode;
+use url::Url;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, Connectivity};
+use uv_configuration::{KeyringProviderType, TrustedHost};
+use uv_distribution_types::{Index, IndexLocations, IndexUrl};
+use uv_fs::Simplified;
+use uv_install_wheel::LinkMode;
+use uv_python::{PythonInstallation, PythonPreference};
+use uv_static::EnvVars;
+
+use crate::commands::python::find::find_installation;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The package whose page is requested to check that an index is reachable.
+const PROBE_PACKAGE: &str = "pip";
+
+/// Check that uv can operate in the current environment, reporting a remediation for each failed
+/// check.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn doctor(
+    project_dir: &Path,
+    python: Option<String>,
+    system: bool,
+    index_locations: &IndexLocations,
+    keyring_provider: KeyringProviderType,
+    link_mode: LinkMode,
+    allow_insecure_host: &[TrustedHost],
+    connectivity: Connectivity,
+    native_tls: bool,
+    python_preference: PythonPreference,
+    no_config: bool,
+    cache: Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let mut report = Report::new(printer);
+
+    // Check that the cache can be initialized, and is writable.
+    let cache = match cache.init() {
+        Ok(cache) => {
+            match tempfile::NamedTempFile::new_in(cache.root()) {
+                Ok(_) => report.ok(format!(
+                    "Cache is writable at `{}`",
+                    cache.root().user_display()
+                ))?,
+                Err(err) => report.error(
+                    format!(
+                        "Cache is not writable at `{}`: {err}",
+                        cache.root().user_display()
+                    ),
+                    "Ensure that the cache directory is writable, or select a different directory with `--cache-dir` (or `UV_CACHE_DIR`)",
+                )?,
+            }
+            cache
+        }
+        Err(err) => {
+            report.error(
+                format!("Failed to initialize the cache: {err}"),
+                "Ensure that the cache directory is writable, or select a different directory with `--cache-dir` (or `UV_CACHE_DIR`)",
+            )?;
+            // Continue with a temporary cache, to run the remaining checks.
+            Cache::temp()?.init()?
+        }
+    };
+
+    // Check that a Python interpreter can be discovered.
+    let installation = match find_installation(
+        project_dir,
+        python,
+        false,
+        no_config,
+        system,
+        python_preference,
+        &cache,
+    )
+    .await
+    {
+        Ok(installation) => {
+            let interpreter = installation.interpreter();
+            report.ok(format!(
+                "Found Python {} at `{}`",
+                interpreter.python_version(),
+                interpreter.sys_executable().user_display()
+            ))?;
+            Some(installation)
+        }
+        Err(err) => {
+            report.error(
+                format!("Failed to find a Python interpreter: {err:#}"),
+                "Install a Python interpreter with `uv python install`, or select one with `--python`",
+            )?;
+            None
+        }
+    };
+
+    // Check that the link mode is supported from the cache into the environment (or, absent a
+    // virtual environment, into the current directory).
+    let target = installation
+        .as_ref()
+        .map(PythonInstallation::interpreter)
+        .filter(|interpreter| interpreter.is_virtualenv())
+        .map_or(project_dir, |interpreter| interpreter.purelib());
+    match link_mode.check(cache.root(), target) {
+        Ok(()) => report.ok(format!(
+            "Cache supports the `{}` link mode into `{}`",
+            link_mode_name(link_mode),
+            target.user_display()
+        ))?,
+        Err(err) => report.warning(
+            format!(
+                "Cache does not support the `{}` link mode into `{}`: {err}",
+                link_mode_name(link_mode),
+                target.user_display()
+            ),
+            "uv will fall back to copying files; to suppress this, use `--link-mode=copy` (or `UV_LINK_MODE=copy`), or place the cache on the same filesystem as the environment with `--cache-dir`",
+        )?,
+    }
+
+    // Check that any proxy and TLS configuration is valid.
+    let mut configured = false;
+    for var in [
+        EnvVars::HTTP_PROXY,
+        EnvVars::HTTPS_PROXY,
+        EnvVars::ALL_PROXY,
+    ] {
+        let Some((var, value)) = [var.to_string(), var.to_ascii_lowercase()]
+            .into_iter()
+            .find_map(|var| std::env::var(&var).ok().map(|value| (var, value)))
+        else {
+            continue;
+        };
+        configured = true;
+        match Url::parse(&value) {
+            Ok(url) => report.ok(format!("Using proxy `{}` from `{var}`", redact(url)))?,
+            Err(err) => report.error(
+                format!("`{var}` is not a valid URL: {err}"),
+                format!("Set `{var}` to a URL, like `http://proxy.example.com:8080`"),
+            )?,
+        }
+    }
+    if let Some(path) = std::env::var_os(EnvVars::SSL_CERT_FILE) {
+        configured = true;
+        if Path::new(&path).is_file() {
+            report.ok(format!(
+                "Using certificates from `{}` (`SSL_CERT_FILE`)",
+                path.simplified_display()
+            ))?;
+        } else {
+            report.error(
+                format!(
+                    "`SSL_CERT_FILE` points to a missing file: `{}`",
+                    path.simplified_display()
+                ),
+                "Set `SSL_CERT_FILE` to an existing PEM file, or unset it",
+            )?;
+        }
+    }
+    if native_tls {
+        configured = true;
+        report.ok("Using the system's native certificate store (`--native-tls`)")?;
+    }
+    if !configured {
+        report.ok("No proxy or custom certificates are configured")?;
+    }
+
+    // Check that each index is reachable, and accepts any provided credentials.
+    if matches!(connectivity, Connectivity::Offline) {
+        report.warning(
+            "Skipped index checks in offline mode",
+            "Omit `--offline` to check that each index is reachable",
+        )?;
+    } else {
+        // Populate credentials from the index URLs, as in resolution.
+        for index in index_locations.allowed_indexes() {
+            if let Some(credentials) = index.credentials() {
+                let credentials = Arc::new(credentials);
+                uv_auth::store_credentials(index.raw_url(), credentials.clone());
+                if let Some(root_url) = index.root_url() {
+                    uv_auth::store_credentials(&root_url, credentials.clone());
+                }
+            }
+        }
+
+        let client = BaseClientBuilder::new()
+            .connectivity(connectivity)
+            .native_tls(native_tls)
+            .keyring(keyring_provider)
+            .allow_insecure_host(allow_insecure_host.to_vec())
+            .build();
+
+        for index in index_locations.allowed_indexes() {
+            let display = index.url().redacted().to_string();
+
+            if let IndexUrl::Path(url) = index.url() {
+                if url.raw().to_file_path().is_ok_and(|path| path.is_dir()) {
+                    report.ok(format!("Index `{display}` exists"))?;
+                } else {
+                    report.error(
+                        format!("Index `{display}` does not exist"),
+                        "Check the path to the index, which must be a directory",
+                    )?;
+                }
+                continue;
+            }
+
+            // Probe a package page, rather than the index root, since some registries don't
+            // serve the root of the Simple API (e.g., they return a 404 for `/simple`).
+            let mut url = index.url().url().clone();
+            if let Ok(mut segments) = url.path_segments_mut() {
+                segments.pop_if_empty().push(PROBE_PACKAGE).push("");
+            }
+            match client.for_host(&url).get(url.clone()).send().await {
+                Ok(response) => {
+                    let status = response.status();
+                    if status.is_success() || status.is_redirection() {
+                        report.ok(format!("Index `{display}` is reachable"))?;
+                    } else if status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN
+                    {
+                        report.error(
+                            format!("Index `{display}` rejected the request ({status})"),
+                            credentials_hint(index),
+                        )?;
+                    } else if status == StatusCode::NOT_FOUND {
+                        // The index may be reachable, but not provide the probed package (e.g., a
+                        // private registry that doesn't mirror PyPI).
+                        report.warning(
+                            format!(
+                                "Index `{display}` does not provide `{PROBE_PACKAGE}` ({status})"
+                            ),
+                            "If the index is expected to mirror PyPI, check the index URL, which should point to the root of a Simple API (e.g., `https://pypi.org/simple`)",
+                        )?;
+                    } else {
+                        report.error(
+                            format!("Index `{display}` responded with an error ({status})"),
+                            "Check that the index is available, or retry later",
+                        )?;
+                    }
+                }
+                Err(err) => {
+                    let message = chain(&err);
+                    let hint = if message.contains("certificate") {
+                        "If the index uses a custom certificate (e.g., behind a corporate proxy), use `--native-tls`, or provide the certificate with `SSL_CERT_FILE`"
+                    } else {
+                        "Check your network connection, and any proxy configuration (`HTTP_PROXY`, `HTTPS_PROXY`, and `NO_PROXY`)"
+                    };
+                    report.error(
+                        format!("Failed to reach index `{display}`: {message}"),
+                        hint,
+                    )?;
+                }
+            }
+        }
+    }
+
+    // Check that Git is available.
+    match &*uv_git::GIT {
+        Ok(path) => report.ok(format!("Git is available at `{}`", path.user_display()))?,
+        Err(err) => report.warning(
+            format!("Git is not available: {err}"),
+            "Install Git to use Git dependencies (e.g., `git+https://github.com/...`)",
+        )?,
+    }
+
+    report.finish()
+}
+
+/// A report of the results of each check.
+struct Report {
+    printer: Printer,
+    errors: usize,
+}
+
+impl Report {
+    fn new(printer: Printer) -> Self {
+        Self { printer, errors: 0 }
+    }
+
+    /// Report a check that passed.
+    fn ok(&mut self, message: impl std::fmt::Display) -> Result<()> {
+        writeln!(self.printer.stderr(), "{}: {message}", "ok".green().bold())?;
+        Ok(())
+    }
+
+    /// Report a check that passed, but for which uv may not behave as expected.
+    fn warning(
+        &mut self,
+        message: impl std::fmt::Display,
+        hint: impl std::fmt::Display,
+    ) -> Result<()> {
+        writeln!(
+            self.printer.stderr(),
+            "{}: {message}\n  {}{} {hint}",
+            "warning".yellow().bold(),
+            "hint".bold().cyan(),
+            ":".bold()
+        )?;
+        Ok(())
+    }
+
+    /// Report a check that failed.
+    fn error(
+        &mut self,
+        message: impl std::fmt::Display,
+        hint: impl std::fmt::Display,
+    ) -> Result<()> {
+        self.errors += 1;
+        writeln!(
+            self.printer.stderr(),
+            "{}: {message}\n  {}{} {hint}",
+            "error".red().bold(),
+            "hint".bold().cyan(),
+            ":".bold()
+        )?;
+        Ok(())
+    }
+
+    /// Summarize the report, returning a failure if any check failed.
+    fn finish(self) -> Result<ExitStatus> {
+        match self.errors {
+            0 => {
+                writeln!(self.printer.stderr(), "All checks passed")?;
+                Ok(ExitStatus::Success)
+            }
+            1 => {
+                writeln!(self.printer.stderr(), "{} check failed", "1".bold())?;
+                Ok(ExitStatus::Failure)
+            }
+            errors => {
+                writeln!(
+                    self.printer.stderr(),
+                    "{} checks failed",
+                    errors.to_string().bold()
+                )?;
+                Ok(ExitStatus::Failure)
+            }
+        }
+    }
+}
+
+/// Return the user-facing name of a [`LinkMode`].
+fn link_mode_name(link_mode: LinkMode) -> &'static str {
+    match link_mode {
+        LinkMode::Clone => "clone",
+        LinkMode::Copy => "copy",
+        LinkMode::Hardlink => "hardlink",
+        LinkMode::Symlink => "symlink",
+    }
+}
+
+/// Return a hint for providing credentials to the given [`Index`].
+fn credentials_hint(index: &Index) -> String {
+    if let Some(name) = index.name.as_ref() {
+        format!(
+            "Provide credentials with `{}` and `{}`, or with `--keyring-provider subprocess`",
+            EnvVars::index_username(&name.to_env_var()),
+            EnvVars::index_password(&name.to_env_var())
+        )
+    } else {
+        "Provide credentials in the index URL, in a `.netrc` file, or with `--keyring-provider subprocess`".to_string()
+    }
+}
+
+/// Format an error, including its chain of causes.
+fn chain(err: &(dyn Error + 'static)) -> String {
+    let mut message = err.to_string();
+    let mut source = err.source();
+    while let Some(err) = source {
+        write!(message, ": {err}").expect("writing to a string is infallible");
+        source = err.source();
+    }
+    message
+}
+
+/// Remove any credentials from a URL, for display.
+fn redact(mut url: Url) -> Url {
+    let _ = url.set_username("");
+    let _ = url.set_password(None);
+    url
+}
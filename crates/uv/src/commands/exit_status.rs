@@ -0,0 +1,120 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use uv_static::EnvVars;
+
+use crate::commands::pip::operations;
+use crate::commands::project::ProjectError;
+use crate::commands::ExitStatus;
+
+impl ExitStatus {
+    /// Determine the [`ExitStatus`] for a failure that was already reported to the user.
+    ///
+    /// Returns [`ExitStatus::Failure`] unless detailed exit codes are enabled.
+    pub(crate) fn from_failure(err: &(dyn Error + 'static)) -> Self {
+        Self::detailed(err).unwrap_or(Self::Failure)
+    }
+
+    /// Determine the [`ExitStatus`] for an error that was propagated to the top level.
+    ///
+    /// Returns [`ExitStatus::Error`] unless detailed exit codes are enabled.
+    pub(crate) fn from_error(err: &(dyn Error + 'static)) -> Self {
+        Self::detailed(err).unwrap_or(Self::Error)
+    }
+
+    /// Classify the error by the first cause in its chain that maps to a failure class, if
+    /// detailed exit codes are enabled.
+    fn detailed(err: &(dyn Error + 'static)) -> Option<Self> {
+        std::env::var_os(EnvVars::UV_DETAILED_EXIT_CODES)?;
+        std::iter::successors(Some(err), |&err| err.source()).find_map(Self::classify)
+    }
+
+    /// Classify a single error in a causal chain.
+    ///
+    /// Many of our error types use `#[error(transparent)]`, which skips the wrapped error when
+    /// walking the chain, so wrappers are unpacked explicitly here.
+    fn classify(err: &(dyn Error + 'static)) -> Option<Self> {
+        if let Some(err) = err.downcast_ref::<ProjectError>() {
+            return match err {
+                ProjectError::Operation(err) => Self::classify(err),
+                ProjectError::Python(err) => Self::classify(err),
+                ProjectError::Requirements(err) => Self::classify(err),
+                ProjectError::Interpreter(_) | ProjectError::Virtualenv(_) => {
+                    Some(Self::EnvironmentError)
+                }
+                _ => None,
+            };
+        }
+        if let Some(err) = err.downcast_ref::<operations::Error>() {
+            return match err {
+                operations::Error::Resolve(err) => Self::classify(err),
+                operations::Error::Requirements(err) => Self::classify(err),
+                operations::Error::UnavailableDistributions(_) => Some(Self::ResolutionFailure),
+                _ => None,
+            };
+        }
+        if let Some(err) = err.downcast_ref::<uv_requirements::Error>() {
+            return match err {
+                uv_requirements::Error::Distribution(err) => Self::classify(err),
+                _ => None,
+            };
+        }
+        if let Some(err) = err.downcast_ref::<uv_resolver::ResolveError>() {
+            return match err {
+                uv_resolver::ResolveError::NoSolution(_) => Some(Self::ResolutionFailure),
+                uv_resolver::ResolveError::Client(err) => Self::classify(err),
+                uv_resolver::ResolveError::Distribution(err) => Self::classify(err),
+                _ => None,
+            };
+        }
+        if err.is::<uv_resolver::NoSolutionError>() {
+            return Some(Self::ResolutionFailure);
+        }
+        if let Some(err) = err.downcast_ref::<uv_distribution::Error>().or_else(|| {
+            err.downcast_ref::<Arc<uv_distribution::Error>>()
+                .map(|err| &**err)
+        }) {
+            return match err {
+                uv_distribution::Error::Reqwest(_)
+                | uv_distribution::Error::ReqwestMiddlewareError(_) => Some(Self::NetworkError),
+                uv_distribution::Error::Client(err) => Self::classify(err),
+                uv_distribution::Error::Build(_) => Some(Self::BuildFailure),
+                uv_distribution::Error::MismatchedHashes { .. }
+                | uv_distribution::Error::MissingHashes { .. }
+                | uv_distribution::Error::MissingActualHashes { .. }
+                | uv_distribution::Error::MissingExpectedHashes { .. }
+                | uv_distribution::Error::SizeMismatch(_) => Some(Self::HashMismatch),
+                _ => None,
+            };
+        }
+        if let Some(err) = err.downcast_ref::<uv_client::Error>() {
+            return match err.kind() {
+                uv_client::ErrorKind::WrappedReqwestError(..)
+                | uv_client::ErrorKind::Offline(_) => Some(Self::NetworkError),
+                _ => None,
+            };
+        }
+        if err.is::<uv_client::WrappedReqwestError>() || err.is::<reqwest::Error>() {
+            return Some(Self::NetworkError);
+        }
+        if err.is::<uv_build_frontend::Error>() {
+            return Some(Self::BuildFailure);
+        }
+        if let Some(err) = err.downcast_ref::<uv_python::Error>() {
+            return match err {
+                uv_python::Error::VirtualEnv(_)
+                | uv_python::Error::Query(_)
+                | uv_python::Error::Discovery(_)
+                | uv_python::Error::ManagedPython(_)
+                | uv_python::Error::MissingPython(_)
+                | uv_python::Error::MissingEnvironment(_)
+                | uv_python::Error::InvalidEnvironment(_) => Some(Self::EnvironmentError),
+                _ => None,
+            };
+        }
+        if err.is::<uv_python::PythonNotFound>() || err.is::<uv_python::InterpreterError>() {
+            return Some(Self::EnvironmentError);
+        }
+        None
+    }
+}
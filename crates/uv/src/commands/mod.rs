@@ -8,19 +8,26 @@ use std::time::Duration;
 use std::{fmt::Display, fmt::Write, process::ExitCode};
 
 pub(crate) use build_frontend::build_frontend;
+pub(crate) use cache_add::cache_add;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
+pub(crate) use cache_gc_metadata::cache_gc_metadata;
 pub(crate) use cache_prune::cache_prune;
+pub(crate) use doctor::doctor;
 pub(crate) use help::help;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
 pub(crate) use pip::freeze::pip_freeze;
 pub(crate) use pip::install::pip_install;
+pub(crate) use pip::lint::pip_lint;
 pub(crate) use pip::list::pip_list;
+pub(crate) use pip::merge::pip_merge;
+pub(crate) use pip::refresh::pip_refresh_editables;
 pub(crate) use pip::show::pip_show;
 pub(crate) use pip::sync::pip_sync;
 pub(crate) use pip::tree::pip_tree;
 pub(crate) use pip::uninstall::pip_uninstall;
+pub(crate) use pip::verify::pip_verify;
 pub(crate) use project::add::add;
 pub(crate) use project::export::export;
 pub(crate) use project::init::{init, InitKind, InitProjectKind};
@@ -32,12 +39,15 @@ pub(crate) use project::tree::tree;
 pub(crate) use publish::publish;
 pub(crate) use python::dir::dir as python_dir;
 pub(crate) use python::find::find as python_find;
+pub(crate) use python::inspect::inspect as python_inspect;
 pub(crate) use python::install::install as python_install;
 pub(crate) use python::list::list as python_list;
 pub(crate) use python::pin::pin as python_pin;
 pub(crate) use python::uninstall::uninstall as python_uninstall;
 #[cfg(feature = "self-update")]
 pub(crate) use self_update::self_update;
+pub(crate) use stats::{record_stats, stats};
+pub(crate) use tags::tags;
 pub(crate) use tool::dir::dir as tool_dir;
 pub(crate) use tool::install::install as tool_install;
 pub(crate) use tool::list::list as tool_list;
@@ -49,22 +59,26 @@ pub(crate) use tool::upgrade::upgrade as tool_upgrade;
 use uv_cache::Cache;
 use uv_configuration::Concurrency;
 use uv_distribution_types::InstalledMetadata;
-use uv_fs::{Simplified, CWD};
+use uv_fs::{Simplified, TextFormat, CWD};
 use uv_installer::compile_tree;
 use uv_normalize::PackageName;
 use uv_python::PythonEnvironment;
 use uv_scripts::Pep723Script;
 pub(crate) use venv::venv;
-pub(crate) use version::version;
+pub(crate) use version::{version, version_compare, version_sort};
 
 use crate::printer::Printer;
 
 pub(crate) mod build_backend;
 mod build_frontend;
+mod cache_add;
 mod cache_clean;
 mod cache_dir;
+mod cache_gc_metadata;
 mod cache_prune;
 mod diagnostics;
+mod doctor;
+mod exit_status;
 mod help;
 pub(crate) mod pip;
 mod project;
@@ -74,6 +88,8 @@ pub(crate) mod reporters;
 mod run;
 #[cfg(feature = "self-update")]
 mod self_update;
+mod stats;
+mod tags;
 mod tool;
 mod venv;
 mod version;
@@ -89,6 +105,21 @@ pub(crate) enum ExitStatus {
     /// The command failed with an unexpected error.
     Error,
 
+    /// The command failed because the requirements could not be resolved.
+    ResolutionFailure,
+
+    /// The command failed due to a network error.
+    NetworkError,
+
+    /// The command failed because a distribution could not be built.
+    BuildFailure,
+
+    /// The command failed because a distribution did not match its expected hashes.
+    HashMismatch,
+
+    /// The command failed because a suitable Python interpreter or environment was not found.
+    EnvironmentError,
+
     /// The command's exit status is propagated from an external command.
     External(u8),
 }
@@ -99,6 +130,11 @@ impl From<ExitStatus> for ExitCode {
             ExitStatus::Success => Self::from(0),
             ExitStatus::Failure => Self::from(1),
             ExitStatus::Error => Self::from(2),
+            ExitStatus::ResolutionFailure => Self::from(3),
+            ExitStatus::NetworkError => Self::from(4),
+            ExitStatus::BuildFailure => Self::from(5),
+            ExitStatus::HashMismatch => Self::from(6),
+            ExitStatus::EnvironmentError => Self::from(7),
             ExitStatus::External(code) => Self::from(code),
         }
     }
@@ -239,6 +275,9 @@ impl<'a> OutputWriter<'a> {
     }
 
     /// Commit the buffer to the output file.
+    ///
+    /// If the output file already exists, its encoding, line endings, and final newline are
+    /// preserved.
     async fn commit(self) -> std::io::Result<()> {
         if let Some(output_file) = self.output_file {
             if let Some(parent_dir) = output_file.parent() {
@@ -250,10 +289,40 @@ impl<'a> OutputWriter<'a> {
                 .map(Cow::Owned)
                 .unwrap_or(Cow::Borrowed(output_file));
             let stream = anstream::adapter::strip_bytes(&self.buffer).into_vec();
+            let stream = match fs_err::read(&*output_file) {
+                Ok(existing) => {
+                    TextFormat::detect(&existing).encode(&String::from_utf8_lossy(&stream))
+                }
+                Err(_) => stream,
+            };
             uv_fs::write_atomic(output_file, &stream).await?;
         }
         Ok(())
     }
+
+    /// Commit the buffer to the output file, embedding a digest header and writing a detached
+    /// signature alongside it.
+    async fn commit_signed(self, key: &pip::signature::SigningKey) -> std::io::Result<()> {
+        if let Some(output_file) = self.output_file {
+            if let Some(parent_dir) = output_file.parent() {
+                fs_err::create_dir_all(parent_dir)?;
+            }
+
+            // If the output file is an existing symlink, write to the destination instead.
+            let output_file = fs_err::read_link(output_file)
+                .map(Cow::Owned)
+                .unwrap_or(Cow::Borrowed(output_file));
+            let stream = anstream::adapter::strip_bytes(&self.buffer).into_vec();
+            let file_name = output_file
+                .file_name()
+                .map(|file_name| file_name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let (signed, signature) = pip::signature::sign(&stream, &file_name, key);
+            uv_fs::write_atomic(&output_file, &signed).await?;
+            uv_fs::write_atomic(pip::signature::signature_path(&output_file), signature).await?;
+        }
+        Ok(())
+    }
 }
 
 /// Given a list of names, return a conjunction of the names (e.g., "Alice, Bob, and Charlie").
@@ -0,0 +1,369 @@
+use std::fmt;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+use jiff::Timestamp;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+use tracing::debug;
+
+use uv_distribution_types::{
+    CachedDist, DistributionMetadata, Hashed, InstalledDist, InstalledMetadata, Name, Resolution,
+    VersionOrUrlRef,
+};
+use uv_install_wheel::recorded_files;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_python::PythonEnvironment;
+
+use crate::commands::pip::loggers::{DefaultInstallLogger, InstallLogger};
+use crate::commands::pip::operations::Changelog;
+use crate::commands::reporters::InstallReporter;
+use crate::printer::Printer;
+
+/// A single event in the audit log, written as one line of JSON.
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "kebab-case")]
+enum Event {
+    /// The sync has started.
+    Started {
+        timestamp: Timestamp,
+        environment: PathBuf,
+        python: String,
+    },
+    /// A distribution was removed from the environment.
+    Uninstalled {
+        timestamp: Timestamp,
+        name: PackageName,
+        version: String,
+    },
+    /// A distribution was installed into the environment.
+    Installed {
+        timestamp: Timestamp,
+        name: PackageName,
+        version: String,
+        source: Option<String>,
+        hashes: Vec<String>,
+    },
+    /// A file was removed from the environment.
+    FileRemoved {
+        timestamp: Timestamp,
+        package: PackageName,
+        path: PathBuf,
+    },
+    /// A file was written to the environment.
+    FileWritten {
+        timestamp: Timestamp,
+        package: PackageName,
+        path: PathBuf,
+    },
+    /// The sync has completed.
+    Completed {
+        timestamp: Timestamp,
+        installed: usize,
+        uninstalled: usize,
+        elapsed_ms: u128,
+    },
+    /// The sync has failed, after applying the changes recorded before it.
+    Failed {
+        timestamp: Timestamp,
+        installed: usize,
+        uninstalled: usize,
+        elapsed_ms: u128,
+        error: String,
+    },
+}
+
+/// The source and hashes of a resolved distribution, as recorded in the audit log.
+#[derive(Debug)]
+struct Resolved {
+    source: Option<String>,
+    hashes: Vec<String>,
+}
+
+/// The events recorded over the course of a sync, shared with the installer.
+#[derive(Debug)]
+struct Recorder {
+    /// The files recorded for each distribution that was installed prior to the sync.
+    previous: FxHashMap<PackageName, Vec<PathBuf>>,
+    /// The source and hashes of each distribution in the resolution.
+    resolved: FxHashMap<PackageName, Resolved>,
+    events: Mutex<Vec<Event>>,
+}
+
+impl Recorder {
+    fn push(&self, event: Event) {
+        self.events.lock().unwrap().push(event);
+    }
+
+    /// Record the removal of a distribution, along with the files it had written.
+    fn uninstalled(&self, dist: &InstalledDist) {
+        let timestamp = Timestamp::now();
+        let mut events = self.events.lock().unwrap();
+        events.push(Event::Uninstalled {
+            timestamp,
+            name: dist.name().clone(),
+            version: version(dist),
+        });
+        for path in self.previous.get(dist.name()).into_iter().flatten() {
+            events.push(Event::FileRemoved {
+                timestamp,
+                package: dist.name().clone(),
+                path: path.clone(),
+            });
+        }
+    }
+
+    /// Record the installation of a distribution.
+    ///
+    /// The hashes are those of the wheel that was installed, falling back to the hashes in the
+    /// resolution if the wheel was not hashed.
+    fn installed(&self, wheel: &CachedDist) {
+        let resolved = self.resolved.get(wheel.name());
+        let hashes = if wheel.hashes().is_empty() {
+            resolved
+                .map(|resolved| resolved.hashes.clone())
+                .unwrap_or_default()
+        } else {
+            wheel.hashes().iter().map(ToString::to_string).collect()
+        };
+        self.push(Event::Installed {
+            timestamp: Timestamp::now(),
+            name: wheel.name().clone(),
+            version: version(wheel),
+            source: resolved.and_then(|resolved| resolved.source.clone()),
+            hashes,
+        });
+    }
+}
+
+/// A JSON Lines audit log of the changes made to an environment by `uv pip sync`.
+///
+/// Each event is timestamped as it happens; the log is written once the sync completes or
+/// fails.
+pub(crate) struct AuditLog {
+    path: PathBuf,
+    start: Instant,
+    recorder: Arc<Recorder>,
+}
+
+impl AuditLog {
+    /// Create an [`AuditLog`], capturing the files of all distributions that are currently
+    /// installed, such that removals can be reported as they happen.
+    pub(crate) fn new(
+        path: PathBuf,
+        site_packages: &SitePackages,
+        resolution: &Resolution,
+        environment: &PythonEnvironment,
+    ) -> Self {
+        let resolved = resolution
+            .hashes()
+            .map(|(dist, hashes)| {
+                let source = if let Some(index) = dist.index() {
+                    Some(index.redacted().to_string())
+                } else if let VersionOrUrlRef::Url(url) = dist.version_or_url() {
+                    Some(url.to_string())
+                } else {
+                    None
+                };
+                let hashes = hashes.iter().map(ToString::to_string).collect();
+                (dist.name().clone(), Resolved { source, hashes })
+            })
+            .collect();
+
+        let started = Event::Started {
+            timestamp: Timestamp::now(),
+            environment: environment.root().to_path_buf(),
+            python: environment.interpreter().python_full_version().to_string(),
+        };
+
+        Self {
+            path,
+            start: Instant::now(),
+            recorder: Arc::new(Recorder {
+                previous: record(site_packages),
+                resolved,
+                events: Mutex::new(vec![started]),
+            }),
+        }
+    }
+
+    /// Return an [`InstallLogger`] that records each change to the environment as it happens.
+    pub(crate) fn logger(&self) -> AuditLogger {
+        AuditLogger {
+            recorder: self.recorder.clone(),
+        }
+    }
+
+    /// Append the recorded events to the audit log, once the sync has completed.
+    pub(crate) fn write(self, environment: &PythonEnvironment) -> Result<()> {
+        self.flush(environment, None)
+    }
+
+    /// Append the recorded events to the audit log, once the sync has failed.
+    pub(crate) fn write_failure(
+        self,
+        environment: &PythonEnvironment,
+        err: &impl fmt::Display,
+    ) -> Result<()> {
+        self.flush(environment, Some(err.to_string()))
+    }
+
+    fn flush(self, environment: &PythonEnvironment, err: Option<String>) -> Result<()> {
+        // Read the files written by the installed distributions.
+        let current = SitePackages::from_environment(environment)
+            .map(|site_packages| record(&site_packages))
+            .unwrap_or_else(|err| {
+                debug!("Failed to read the environment for the audit log: {err}");
+                FxHashMap::default()
+            });
+
+        let recorded = std::mem::take(&mut *self.recorder.events.lock().unwrap());
+
+        // Attribute the files of each installed distribution to its installation.
+        let mut events = Vec::with_capacity(recorded.len());
+        let mut installed = 0;
+        let mut uninstalled = 0;
+        for event in recorded {
+            match &event {
+                Event::Installed {
+                    timestamp, name, ..
+                } => {
+                    installed += 1;
+                    let files = current
+                        .get(name)
+                        .into_iter()
+                        .flatten()
+                        .map(|path| Event::FileWritten {
+                            timestamp: *timestamp,
+                            package: name.clone(),
+                            path: path.clone(),
+                        })
+                        .collect::<Vec<_>>();
+                    events.push(event);
+                    events.extend(files);
+                }
+                Event::Uninstalled { .. } => {
+                    uninstalled += 1;
+                    events.push(event);
+                }
+                _ => events.push(event),
+            }
+        }
+
+        let elapsed_ms = self.start.elapsed().as_millis();
+        events.push(match err {
+            None => Event::Completed {
+                timestamp: Timestamp::now(),
+                installed,
+                uninstalled,
+                elapsed_ms,
+            },
+            Some(err) => Event::Failed {
+                timestamp: Timestamp::now(),
+                installed,
+                uninstalled,
+                elapsed_ms,
+                error: err,
+            },
+        });
+
+        let mut buffer = Vec::new();
+        for event in &events {
+            serde_json::to_writer(&mut buffer, event)?;
+            buffer.push(b'\n');
+        }
+
+        let mut file = fs_err::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Failed to open audit log: `{}`", self.path.display()))?;
+        file.write_all(&buffer)?;
+
+        Ok(())
+    }
+}
+
+/// An [`InstallLogger`] that records each change to the environment in an [`AuditLog`].
+pub(crate) struct AuditLogger {
+    recorder: Arc<Recorder>,
+}
+
+impl InstallLogger for AuditLogger {
+    fn on_audit(&self, count: usize, start: Instant, printer: Printer) -> fmt::Result {
+        DefaultInstallLogger.on_audit(count, start, printer)
+    }
+
+    fn on_prepare(&self, count: usize, start: Instant, printer: Printer) -> fmt::Result {
+        DefaultInstallLogger.on_prepare(count, start, printer)
+    }
+
+    fn on_uninstall(&self, count: usize, start: Instant, printer: Printer) -> fmt::Result {
+        DefaultInstallLogger.on_uninstall(count, start, printer)
+    }
+
+    fn on_install(&self, count: usize, start: Instant, printer: Printer) -> fmt::Result {
+        DefaultInstallLogger.on_install(count, start, printer)
+    }
+
+    fn on_complete(&self, changelog: &Changelog, printer: Printer) -> fmt::Result {
+        DefaultInstallLogger.on_complete(changelog, printer)
+    }
+
+    fn on_uninstalled(&self, dist: &InstalledDist) {
+        self.recorder.uninstalled(dist);
+    }
+
+    fn install_reporter(
+        &self,
+        reporter: InstallReporter,
+    ) -> Arc<dyn uv_installer::InstallReporter> {
+        Arc::new(AuditReporter {
+            reporter,
+            recorder: self.recorder.clone(),
+        })
+    }
+}
+
+/// An install reporter that records each installed distribution as it's installed.
+struct AuditReporter {
+    reporter: InstallReporter,
+    recorder: Arc<Recorder>,
+}
+
+impl uv_installer::InstallReporter for AuditReporter {
+    fn on_install_progress(&self, wheel: &CachedDist) {
+        self.recorder.installed(wheel);
+        self.reporter.on_install_progress(wheel);
+    }
+
+    fn on_install_complete(&self) {
+        self.reporter.on_install_complete();
+    }
+}
+
+/// Return the version of an installed distribution, as recorded in the audit log.
+fn version(dist: &impl InstalledMetadata) -> String {
+    dist.installed_version().version().to_string()
+}
+
+/// Read the files recorded in the `RECORD` of each distribution in the environment.
+fn record(site_packages: &SitePackages) -> FxHashMap<PackageName, Vec<PathBuf>> {
+    let mut recorded = FxHashMap::default();
+    for dist in site_packages.iter() {
+        if !matches!(dist, InstalledDist::Registry(_) | InstalledDist::Url(_)) {
+            continue;
+        }
+        match recorded_files(dist.path()) {
+            Ok(files) => {
+                recorded.insert(dist.name().clone(), files);
+            }
+            Err(err) => debug!("Failed to read `RECORD` for `{}`: {err}", dist.name()),
+        }
+    }
+    recorded
+}
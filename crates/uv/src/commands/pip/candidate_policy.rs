@@ -0,0 +1,67 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_resolver::{CandidatePolicy, PolicyCandidate};
+use uv_static::EnvVars;
+
+/// A [`CandidatePolicy`] read from the `UV_INTERNAL__CANDIDATE_POLICY` environment variable, used
+/// to exercise the policy hook in tests.
+///
+/// The variable contains whitespace-separated directives: `deny:<name>==<version>` rejects the
+/// given version of a package, and `lowest:<name>` considers the versions of a package in
+/// ascending order.
+#[derive(Debug, Default)]
+pub(crate) struct TestCandidatePolicy {
+    denied: Vec<(PackageName, Version)>,
+    lowest: Vec<PackageName>,
+}
+
+impl TestCandidatePolicy {
+    /// Read the policy from the environment, if set and valid.
+    pub(crate) fn from_env() -> Option<Self> {
+        let value = std::env::var(EnvVars::UV_INTERNAL__CANDIDATE_POLICY).ok()?;
+        let mut policy = Self::default();
+        for directive in value.split_whitespace() {
+            if let Some(requirement) = directive.strip_prefix("deny:") {
+                let (name, version) = requirement.split_once("==")?;
+                policy.denied.push((
+                    PackageName::from_str(name).ok()?,
+                    Version::from_str(version).ok()?,
+                ));
+            } else if let Some(name) = directive.strip_prefix("lowest:") {
+                policy.lowest.push(PackageName::from_str(name).ok()?);
+            } else {
+                return None;
+            }
+        }
+        Some(policy)
+    }
+}
+
+impl CandidatePolicy for TestCandidatePolicy {
+    fn name(&self) -> &str {
+        "test"
+    }
+
+    fn check(&self, candidate: &PolicyCandidate<'_>) -> Result<(), String> {
+        if self
+            .denied
+            .iter()
+            .any(|(name, version)| name == candidate.name() && version == candidate.version())
+        {
+            Err("version is denied".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn sorts(&self, package_name: &PackageName) -> bool {
+        self.lowest.contains(package_name)
+    }
+
+    fn compare(&self, _package_name: &PackageName, a: &Version, b: &Version) -> Ordering {
+        a.cmp(b)
+    }
+}
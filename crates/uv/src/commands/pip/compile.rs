@@ -1,48 +1,68 @@
 use std::collections::BTreeSet;
 use std::env;
-use std::path::Path;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, Context, Result};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashSet;
 use tracing::debug;
 
-use uv_cache::Cache;
-use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
+use uv_cache::{Cache, CacheBucket};
+use uv_cache_key::hash_digest;
+use uv_cli::PipCompileFormat;
+use uv_client::{
+    BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClient, RegistryClientBuilder,
+};
 use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, Constraints, DevGroupsSpecification,
-    ExtrasSpecification, IndexStrategy, NoBinary, NoBuild, PreviewMode, Reinstall, SourceStrategy,
+    EmbeddedIndexStrategy, ExtrasSpecification, IndexStrategy, IndexUnavailablePolicy, NoBinary,
+    NoBuild, PackageBuildEnv, PackageConfigSettings, PreviewMode, Reinstall, SourceStrategy,
     TrustedHost, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
+use uv_distribution::DistributionDatabase;
 use uv_distribution_types::{
-    DependencyMetadata, HashGeneration, Index, IndexLocations, NameRequirementSpecification,
-    Origin, UnresolvedRequirementSpecification, Verbatim,
+    DependencyMetadata, HashGeneration, Index, IndexLocations, InstalledDist, Name,
+    NameRequirementSpecification, Origin, UnresolvedRequirement,
+    UnresolvedRequirementSpecification, Verbatim,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
+use uv_installer::SitePackages;
 use uv_normalize::PackageName;
-use uv_pypi_types::{Conflicts, Requirement, SupportedEnvironments};
+use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
+use uv_pep508::{MarkerExpression, MarkerTree, MarkerValueVersion, RequirementOrigin};
+use uv_pypi_types::{Conflicts, Requirement, RequirementSource, SupportedEnvironments};
 use uv_python::{
     EnvironmentPreference, PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest,
     PythonVersion, VersionRequest,
 };
 use uv_requirements::{
     upgrade::read_requirements_txt, RequirementsSource, RequirementsSpecification,
+    SourceTreeResolver,
 };
 use uv_resolver::{
-    AnnotationStyle, DependencyMode, DisplayResolutionGraph, ExcludeNewer, FlatIndex, ForkStrategy,
-    InMemoryIndex, OptionsBuilder, PrereleaseMode, PythonRequirement, RequiresPython,
-    ResolutionMode, ResolverEnvironment,
+    AllowedPackages, AnnotationStyle, DependencyGraph, DependencyMode,
+    DisplayResolutionExplanation, DisplayResolutionGraph, ExcludeNewer, ExcludeNewerPackage,
+    FlatIndex, ForkStrategy, GithubDetector, GithubJob, GithubSnapshot, InMemoryIndex,
+    InvalidMetadataPolicy, LocalVersionPreference, LowerBound, OptionsBuilder, PackageProvider,
+    PackageSourcePolicy, PrereleaseMode, PythonRequirement, RequiresPython, ResolutionMode,
+    ResolutionPackage, ResolverCheckpoint, ResolverEnvironment, ResolverOutput, WheelPreference,
+    YankedPolicy,
 };
+use uv_static::EnvVars;
 use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy};
 use uv_warnings::warn_user;
+use uv_workspace::pyproject::PyProjectToml;
 
-use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::loggers::{DefaultResolveLogger, SummaryResolveLogger};
+use crate::commands::pip::signature::SigningKey;
 use crate::commands::pip::{operations, resolution_environment};
 use crate::commands::{diagnostics, ExitStatus, OutputWriter};
 use crate::printer::Printer;
@@ -61,12 +81,29 @@ pub(crate) async fn pip_compile(
     extras: ExtrasSpecification,
     groups: DevGroupsSpecification,
     output_file: Option<&Path>,
+    format: PipCompileFormat,
+    output_per_extra: bool,
+    verify_determinism: bool,
+    seed: Option<u64>,
+    resume: bool,
+    allowed_packages: Option<&Path>,
+    minimal_changes: bool,
+    report_bounds: bool,
+    normalize_specifiers: bool,
+    show_derivation_tree: bool,
+    explain: bool,
+    constraint_from_env: bool,
+    sign: Option<&Path>,
+    emit_graph: Option<&Path>,
     resolution_mode: ResolutionMode,
+    resolution_package: ResolutionPackage,
     prerelease_mode: PrereleaseMode,
+    prerelease_package: Vec<PackageName>,
     fork_strategy: ForkStrategy,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     generate_hashes: bool,
+    only_hashed: bool,
     no_emit_packages: Vec<PackageName>,
     include_extras: bool,
     include_markers: bool,
@@ -78,20 +115,36 @@ pub(crate) async fn pip_compile(
     include_build_options: bool,
     include_marker_expression: bool,
     include_index_annotation: bool,
+    include_requires_python: bool,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
+    embedded_index_strategy: EmbeddedIndexStrategy,
+    invalid_metadata: InvalidMetadataPolicy,
+    yanked: YankedPolicy,
+    index_unavailable: IndexUnavailablePolicy,
+    wheel_preference: Vec<WheelPreference>,
+    local_version_preference: Vec<LocalVersionPreference>,
+    resolution_timeout: Option<u64>,
+    max_backtracks: Option<usize>,
+    source_policy: Vec<PackageSourcePolicy>,
+    provided_by: Vec<PackageProvider>,
     dependency_metadata: DependencyMetadata,
     keyring_provider: KeyringProviderType,
     allow_insecure_host: &[TrustedHost],
     config_settings: ConfigSettings,
+    config_settings_package: PackageConfigSettings,
+    build_env: PackageBuildEnv,
     connectivity: Connectivity,
     no_build_isolation: bool,
     no_build_isolation_package: Vec<PackageName>,
     build_options: BuildOptions,
     mut python_version: Option<PythonVersion>,
+    python_versions: Vec<PythonVersion>,
     python_platform: Option<TargetTriple>,
-    universal: bool,
+    features: Vec<String>,
+    mut universal: bool,
     exclude_newer: Option<ExcludeNewer>,
+    exclude_newer_package: ExcludeNewerPackage,
     sources: SourceStrategy,
     annotation_style: AnnotationStyle,
     link_mode: LinkMode,
@@ -136,12 +189,99 @@ pub(crate) async fn pip_compile(
         ));
     }
 
+    // The bounds report compares the declared lower bounds against the resolved versions, which is
+    // only meaningful if the lowest versions were selected.
+    if report_bounds
+        && !matches!(
+            resolution_mode,
+            ResolutionMode::Lowest | ResolutionMode::LowestDirect
+        )
+    {
+        return Err(anyhow!(
+            "`--report-bounds` requires `--resolution lowest` or `--resolution lowest-direct`"
+        ));
+    }
+
+    // If multiple Python versions were requested, perform a universal resolution with a fork for
+    // each version (within each of the supported environments, if any).
+    let environments = if python_versions.len() > 1 {
+        if python_platform.is_some() {
+            return Err(anyhow!(
+                "`--python-platform` cannot be used with multiple `--python-version` targets"
+            ));
+        }
+        universal = true;
+
+        let targets = python_versions
+            .iter()
+            .map(|python_version| {
+                MarkerTree::expression(MarkerExpression::Version {
+                    key: MarkerValueVersion::PythonVersion,
+                    specifier: VersionSpecifier::equals_version(Version::new([
+                        u64::from(python_version.major()),
+                        u64::from(python_version.minor()),
+                    ])),
+                })
+            })
+            .collect::<Vec<_>>();
+        if environments.as_markers().is_empty() {
+            SupportedEnvironments::from_markers(targets)
+        } else {
+            SupportedEnvironments::from_markers(
+                environments
+                    .iter()
+                    .flat_map(|environment| {
+                        targets.iter().map(move |target| {
+                            let mut marker = *environment;
+                            marker.and(*target);
+                            marker
+                        })
+                    })
+                    .filter(|marker| !marker.is_false())
+                    .collect(),
+            )
+        }
+    } else {
+        environments
+    };
+
+    // Read the signing key up-front, to avoid resolving only to fail when writing the output.
+    let signing_key = if let Some(sign) = sign {
+        if format == PipCompileFormat::GithubSnapshot {
+            return Err(anyhow!(
+                "`--sign` is not supported with `--format github-snapshot`"
+            ));
+        }
+        Some(SigningKey::from_path(sign)?)
+    } else {
+        None
+    };
+
+    // Determine the manifest to which the resolved packages are attributed in a GitHub snapshot,
+    // i.e., the first requirements file, falling back to the output file.
+    let manifest = requirements
+        .iter()
+        .find_map(|source| match source {
+            RequirementsSource::RequirementsTxt(path)
+            | RequirementsSource::PyprojectToml(path)
+            | RequirementsSource::SetupPy(path)
+            | RequirementsSource::SetupCfg(path)
+            | RequirementsSource::SourceTree(path) => Some(path.portable_display().to_string()),
+            RequirementsSource::Package(_) | RequirementsSource::Editable(_) => None,
+        })
+        .or_else(|| output_file.map(|path| path.portable_display().to_string()))
+        .unwrap_or_else(|| "requirements.txt".to_string());
+
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
         .keyring(keyring_provider)
         .allow_insecure_host(allow_insecure_host.to_vec());
 
+    // Read the `requires-python` declared by the project, to compare against the range of Python
+    // versions supported by the pinned dependencies.
+    let declared_requires_python = declared_requires_python(requirements).await;
+
     // Read all requirements from the provided sources.
     let RequirementsSpecification {
         project,
@@ -160,11 +300,21 @@ pub(crate) async fn pip_compile(
         requirements,
         constraints,
         overrides,
+        embedded_index_strategy,
         &client_builder,
     )
     .await?;
 
-    let constraints = constraints
+    // Read the allow-list, if provided.
+    let allowed_packages = allowed_packages
+        .map(|path| {
+            let contents = fs_err::read_to_string(path)?;
+            AllowedPackages::from_str(&contents)
+                .with_context(|| format!("Failed to parse allow-list: `{}`", path.user_display()))
+        })
+        .transpose()?;
+
+    let mut constraints: Vec<NameRequirementSpecification> = constraints
         .iter()
         .cloned()
         .chain(
@@ -217,6 +367,67 @@ pub(crate) async fn pip_compile(
         }
     }
 
+    // Determine the files to write. The joint resolution, across all requested extras and groups,
+    // is written to the output file; with `--output-per-extra`, the base requirements and each
+    // requested extra and group are also written to files of their own.
+    let mut outputs = vec![CompileOutput {
+        output_file: output_file.map(Path::to_path_buf),
+        extras: extras.clone(),
+        groups: groups.clone(),
+    }];
+    if output_per_extra {
+        let Some(output_file) = output_file else {
+            return Err(anyhow!(
+                "`--output-per-extra` requires an output file (e.g., `--output-file requirements.txt`)"
+            ));
+        };
+        outputs.push(CompileOutput {
+            output_file: Some(output_file_for(output_file, "base")),
+            extras: ExtrasSpecification::None,
+            groups: DevGroupsSpecification::default(),
+        });
+        for extra in extras
+            .extra_names(used_extras.iter())
+            .cloned()
+            .collect::<BTreeSet<_>>()
+        {
+            outputs.push(CompileOutput {
+                output_file: Some(output_file_for(output_file, extra.as_ref())),
+                extras: ExtrasSpecification::Some(vec![extra]),
+                groups: DevGroupsSpecification::default(),
+            });
+        }
+        for group in groups
+            .desugarred_names()
+            .filter(|group| groups.contains(group))
+            .cloned()
+            .collect::<BTreeSet<_>>()
+        {
+            outputs.push(CompileOutput {
+                output_file: Some(output_file_for(output_file, group.as_ref())),
+                extras: ExtrasSpecification::None,
+                groups: DevGroupsSpecification::from_group(group),
+            });
+        }
+        if outputs.len() == 2 {
+            return Err(anyhow!(
+                "`--output-per-extra` requires at least one extra or dependency group (e.g., `--extra dev`)"
+            ));
+        }
+        let mut seen = FxHashSet::default();
+        for path in outputs
+            .iter()
+            .filter_map(|output| output.output_file.as_deref())
+        {
+            if !seen.insert(path) {
+                return Err(anyhow!(
+                    "Multiple extras or dependency groups would be written to `{}`",
+                    path.user_display()
+                ));
+            }
+        }
+    }
+
     // Find an interpreter to use for building distributions
     let environment_preference = EnvironmentPreference::from_system_flag(system, false);
     let interpreter = if let Some(python) = python.as_ref() {
@@ -241,6 +452,43 @@ pub(crate) async fn pip_compile(
         interpreter.sys_executable().user_display().cyan()
     );
 
+    // If requested, constrain the resolution to the versions installed in the environment.
+    if constraint_from_env {
+        let environment = PythonEnvironment::from_interpreter(interpreter.clone());
+        let site_packages = SitePackages::from_environment(&environment)?;
+        let installed = site_packages
+            .iter()
+            // Editable and direct URL installations can't be pinned by version.
+            .filter(|dist| {
+                !matches!(
+                    dist,
+                    InstalledDist::Url(_) | InstalledDist::LegacyEditable(_)
+                )
+            })
+            .map(|dist| Requirement {
+                name: dist.name().clone(),
+                extras: vec![],
+                groups: vec![],
+                marker: MarkerTree::default(),
+                source: RequirementSource::Registry {
+                    specifier: VersionSpecifiers::from(VersionSpecifier::equals_version(
+                        dist.version().clone(),
+                    )),
+                    index: None,
+                    conflict: None,
+                },
+                origin: None,
+            })
+            .map(NameRequirementSpecification::from)
+            .collect::<Vec<_>>();
+        debug!(
+            "Constraining resolution to {} package(s) installed at: {}",
+            installed.len(),
+            environment.root().user_display()
+        );
+        constraints.extend(installed);
+    }
+
     if let Some(python_version) = python_version.as_ref() {
         // If the requested version does not match the version we're using warn the user
         // _unless_ they have not specified a patch version and that is the only difference
@@ -299,7 +547,10 @@ pub(crate) async fn pip_compile(
     } else {
         let (tags, marker_env) =
             resolution_environment(python_version, python_platform, &interpreter)?;
-        (Some(tags), ResolverEnvironment::specific(marker_env))
+        (
+            Some(tags),
+            ResolverEnvironment::specific(marker_env.with_uv_features(features)),
+        )
     };
 
     // Generate, but don't enforce hashes for the requirements.
@@ -341,12 +592,83 @@ pub(crate) async fn pip_compile(
         .cache(cache.clone())
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
+        .index_unavailable(index_unavailable)
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
 
-    // Read the lockfile, if present.
-    let preferences = read_requirements_txt(output_file, &upgrade).await?;
+    // Read the lockfile, if present. A GitHub snapshot can't be used as a source of preferences.
+    let mut preferences = if format != PipCompileFormat::RequirementsTxt {
+        Vec::new()
+    } else if minimal_changes && upgrade.is_all() {
+        // When minimizing changes, `--upgrade` only applies to the direct requirements.
+        let direct = requirements
+            .iter()
+            .filter_map(|spec| match &spec.requirement {
+                UnresolvedRequirement::Named(requirement) => Some(&requirement.name),
+                UnresolvedRequirement::Unnamed(_) => None,
+            })
+            .collect::<FxHashSet<_>>();
+        read_requirements_txt(output_file, &Upgrade::None)
+            .await?
+            .into_iter()
+            .filter(|preference| !direct.contains(preference.name()))
+            .collect()
+    } else {
+        read_requirements_txt(output_file, &upgrade).await?
+    };
+
+    // If requested, record the resolver's progress in the cache, such that an interrupted
+    // resolution can be resumed. The checkpoint is keyed by all inputs to the resolution.
+    let checkpoint = if resume {
+        let inputs = requirements
+            .iter()
+            .map(|spec| spec.requirement.to_string())
+            .chain(constraints.iter().map(|spec| spec.requirement.to_string()))
+            .chain(overrides.iter().map(|spec| spec.requirement.to_string()))
+            .chain(
+                source_trees
+                    .iter()
+                    .map(|path| path.user_display().to_string()),
+            )
+            .chain(
+                index_locations
+                    .allowed_indexes()
+                    .into_iter()
+                    .chain(index_locations.flat_indexes())
+                    .map(|index| index.url.to_string()),
+            )
+            .chain([
+                python_requirement.target().to_string(),
+                resolver_env.to_string(),
+                resolution_mode.to_string(),
+                prerelease_mode.to_string(),
+                exclude_newer
+                    .as_ref()
+                    .map(ToString::to_string)
+                    .unwrap_or_default(),
+                format!("{exclude_newer_package:?}"),
+            ])
+            .collect::<Vec<_>>();
+        let path = cache
+            .bucket(CacheBucket::Resolutions)
+            .join(format!("{}.txt", hash_digest(&inputs)));
+
+        // Prefer the versions decided by an interrupted resolution.
+        if let Some(decisions) = ResolverCheckpoint::read(&path)? {
+            debug!(
+                "Resuming resolution from {} checkpointed decisions",
+                decisions.len()
+            );
+            preferences = decisions.into_iter().chain(preferences).collect();
+        } else {
+            debug!("No resolver checkpoint found; resolving from scratch");
+        }
+
+        Some(Arc::new(ResolverCheckpoint::new(path)))
+    } else {
+        None
+    };
 
     // Combine the `--no-binary` and `--no-build` flags from the requirements files.
     let build_options = build_options.combine(no_binary, no_build);
@@ -391,6 +713,8 @@ pub(crate) async fn pip_compile(
         state,
         index_strategy,
         &config_settings,
+        &config_settings_package,
+        &build_env,
         build_isolation,
         link_mode,
         &build_options,
@@ -399,194 +723,555 @@ pub(crate) async fn pip_compile(
         sources,
         concurrency,
         preview,
-    );
+    )
+    .with_exclude_newer_package(exclude_newer_package.clone());
 
     let options = OptionsBuilder::new()
         .resolution_mode(resolution_mode)
+        .resolution_package(resolution_package)
         .prerelease_mode(prerelease_mode)
+        .prerelease_package(prerelease_package)
         .fork_strategy(fork_strategy)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
+        .exclude_newer_package(exclude_newer_package)
+        .allowed_packages(allowed_packages)
+        .minimal_changes(minimal_changes)
+        .derivation_tree(show_derivation_tree)
+        .only_hashed(only_hashed)
         .index_strategy(index_strategy)
+        .invalid_metadata(invalid_metadata)
+        .yanked(yanked)
+        .wheel_preference(wheel_preference)
+        .local_version_preference(local_version_preference)
+        .resolution_timeout(resolution_timeout.map(Duration::from_secs))
+        .max_backtracks(max_backtracks)
+        .source_policies(source_policy.into_iter().collect())
+        .providers(provided_by.into_iter().collect())
         .build_options(build_options.clone())
         .build();
 
-    // Resolve the requirements.
-    let resolution = match operations::resolve(
-        requirements,
-        constraints,
-        overrides,
-        source_trees,
-        project,
+    // Resolve the requirements, across all requested extras and groups.
+    let resolve = operations::resolve(
+        requirements.clone(),
+        constraints.clone(),
+        overrides.clone(),
+        source_trees.clone(),
+        project.clone(),
         BTreeSet::default(),
         &extras,
         &groups,
-        preferences,
+        preferences.clone(),
         EmptyInstalledPackages,
         &hasher,
         &Reinstall::None,
         &upgrade,
         tags.as_deref(),
         resolver_env.clone(),
-        python_requirement,
+        python_requirement.clone(),
         Conflicts::empty(),
         &client,
         &flat_index,
         &top_level_index,
         &build_dispatch,
         concurrency,
-        options,
+        options.clone(),
+        checkpoint.clone(),
         Box::new(DefaultResolveLogger),
         printer,
-    )
-    .await
-    {
+    );
+    let result = if let Some(checkpoint) = &checkpoint {
+        // If the resolution is interrupted, flush the outstanding progress before exiting.
+        tokio::select! {
+            result = resolve => result,
+            _ = tokio::signal::ctrl_c() => {
+                checkpoint.persist()?;
+                writeln!(
+                    printer.stderr(),
+                    "Resolution interrupted; rerun with `{}` to continue from the recorded progress",
+                    "--resume".green()
+                )?;
+                return Ok(ExitStatus::Failure);
+            }
+        }
+    } else {
+        resolve.await
+    };
+    let mut resolution = match result {
         Ok(resolution) => resolution,
         Err(err) => {
+            // Persist any outstanding progress, such that the resolution can be resumed.
+            if let Some(checkpoint) = &checkpoint {
+                checkpoint.persist()?;
+            }
             return diagnostics::OperationDiagnostic::native_tls(native_tls)
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                .map_err(Into::into);
         }
     };
 
-    // Write the resolved dependencies to the output channel.
-    let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file);
+    // The resolution completed, so there's nothing left to resume.
+    if let Some(checkpoint) = &checkpoint {
+        checkpoint.remove()?;
+    }
 
-    if include_header {
-        writeln!(
-            writer,
+    // If requested, explain why each package was pinned to its selected version.
+    if explain {
+        write!(
+            printer.stderr(),
             "{}",
-            "# This file was autogenerated by uv via the following command:".green()
-        )?;
-        writeln!(
-            writer,
-            "{}",
-            format!(
-                "#    {}",
-                cmd(
-                    include_index_url,
-                    include_find_links,
-                    custom_compile_command
-                )
-            )
-            .green()
+            DisplayResolutionExplanation::new(&resolution, &top_level_index)
         )?;
     }
 
-    if include_marker_expression {
-        if let Some(marker_env) = resolver_env.marker_environment() {
-            let relevant_markers = resolution.marker_tree(&top_level_index, marker_env)?;
-            if let Some(relevant_markers) = relevant_markers.contents() {
-                writeln!(
-                    writer,
-                    "{}",
-                    "# Pinned dependencies known to be valid for:".green()
-                )?;
-                writeln!(writer, "{}", format!("#    {relevant_markers}").green())?;
+    // If requested, resolve the requirements a second time, with the inputs presented in a
+    // random order and a fresh index, and verify that both resolutions render identically.
+    if verify_determinism {
+        let seed = seed.unwrap_or_else(operations::random_seed);
+        debug!("Shuffling inputs with seed: {seed}");
+        let shuffled = match operations::resolve(
+            operations::shuffle(requirements.iter().cloned(), seed),
+            operations::shuffle(constraints.iter().cloned(), seed),
+            operations::shuffle(overrides.iter().cloned(), seed),
+            operations::shuffle(source_trees.iter().cloned(), seed),
+            project.clone(),
+            BTreeSet::default(),
+            &extras,
+            &groups,
+            operations::shuffle(preferences.iter().cloned(), seed),
+            EmptyInstalledPackages,
+            &hasher,
+            &Reinstall::None,
+            &upgrade,
+            tags.as_deref(),
+            resolver_env.clone(),
+            python_requirement.clone(),
+            Conflicts::empty(),
+            &client,
+            &flat_index,
+            &InMemoryIndex::default(),
+            &build_dispatch,
+            concurrency,
+            options.clone(),
+            None,
+            Box::new(SummaryResolveLogger),
+            printer,
+        )
+        .await
+        {
+            Ok(resolution) => resolution,
+            Err(err) => {
+                return diagnostics::OperationDiagnostic::native_tls(native_tls)
+                    .report(err)
+                    .map_err(Into::into)
             }
+        };
+
+        let render = |resolution: &ResolverOutput| {
+            DisplayResolutionGraph::new(
+                resolution,
+                &resolver_env,
+                &no_emit_packages,
+                generate_hashes,
+                include_extras,
+                include_markers || universal,
+                include_annotations,
+                include_index_annotation,
+                normalize_specifiers,
+                annotation_style,
+            )
+            .to_string()
+        };
+        if !operations::verify_determinism(&render(&resolution), &render(&shuffled), seed, printer)?
+        {
+            return Ok(ExitStatus::Failure);
         }
     }
 
-    let mut wrote_preamble = false;
+    // Replace the hashes reported by any index with a configured hash provider.
+    if generate_hashes {
+        operations::attest_hashes(
+            &mut resolution,
+            &index_locations.index_urls(),
+            &client,
+            concurrency,
+        )
+        .await?;
+    }
 
-    // If necessary, include the `--index-url` and `--extra-index-url` locations.
-    if include_index_url {
-        if let Some(index) = index_locations.default_index() {
-            writeln!(writer, "--index-url {}", index.url().verbatim())?;
-            wrote_preamble = true;
+    // If requested, write the resolution's dependency graph.
+    if let Some(emit_graph) = emit_graph {
+        let graph = DependencyGraph::from_resolution(&resolution, &no_emit_packages);
+        let contents = if emit_graph
+            .extension()
+            .is_some_and(|extension| extension == "dot" || extension == "gv")
+        {
+            graph.to_dot()
+        } else {
+            format!("{}\n", serde_json::to_string_pretty(&graph)?)
+        };
+        if let Some(parent_dir) = emit_graph.parent() {
+            fs_err::create_dir_all(parent_dir)?;
         }
-        let mut seen = FxHashSet::default();
-        for extra_index in index_locations.implicit_indexes() {
-            if seen.insert(extra_index.url()) {
-                writeln!(writer, "--extra-index-url {}", extra_index.url().verbatim())?;
-                wrote_preamble = true;
+        uv_fs::write_atomic(emit_graph, contents).await?;
+    }
+
+    for (index, output) in outputs.iter().enumerate() {
+        let joint = index == 0;
+
+        // Derive the resolution for each individual extra and group from the joint resolution,
+        // such that packages are pinned consistently across all files.
+        let filtered;
+        let resolution = if joint {
+            &resolution
+        } else {
+            let requirements = output_requirements(
+                &resolution,
+                &source_trees,
+                output,
+                &hasher,
+                &top_level_index,
+                &client,
+                &build_dispatch,
+                concurrency,
+            )
+            .await?;
+            filtered = resolution.filter(&requirements);
+            &filtered
+        };
+
+        // Write the resolved dependencies to the output channel. Only the joint resolution is
+        // written to stdout.
+        let output_file = output.output_file.as_deref();
+        let mut writer = OutputWriter::new(joint && (!quiet || output_file.is_none()), output_file);
+
+        // If requested, write a GitHub Dependency Submission API snapshot in lieu of a
+        // `requirements.txt` file.
+        if format == PipCompileFormat::GithubSnapshot {
+            let mut snapshot = GithubSnapshot::from_resolution(
+                resolution,
+                &manifest,
+                &no_emit_packages,
+                GithubDetector {
+                    name: "uv".to_string(),
+                    version: uv_version::version().to_string(),
+                    url: "https://github.com/astral-sh/uv".to_string(),
+                },
+                jiff::Timestamp::now(),
+            );
+            if let (Ok(sha), Ok(reference)) =
+                (env::var(EnvVars::GITHUB_SHA), env::var(EnvVars::GITHUB_REF))
+            {
+                snapshot = snapshot.with_commit(sha, reference);
+            }
+            if let (Ok(workflow), Ok(job), Ok(run_id)) = (
+                env::var(EnvVars::GITHUB_WORKFLOW),
+                env::var(EnvVars::GITHUB_JOB),
+                env::var(EnvVars::GITHUB_RUN_ID),
+            ) {
+                snapshot = snapshot.with_job(GithubJob {
+                    correlator: format!("{workflow}_{job}"),
+                    id: run_id,
+                });
             }
+            writeln!(writer, "{}", serde_json::to_string_pretty(&snapshot)?)?;
+            writer.commit().await?;
+            if joint {
+                operations::diagnose_resolution(resolution.diagnostics(), printer)?;
+                operations::diagnose_requires_python(
+                    declared_requires_python.as_ref(),
+                    resolution.requires_python_intersection().as_ref(),
+                    printer,
+                )?;
+                if report_bounds {
+                    report_lower_bounds(&resolution.lower_bounds(), printer)?;
+                }
+            }
+            continue;
         }
-    }
 
-    // If necessary, include the `--find-links` locations.
-    if include_find_links {
-        for flat_index in index_locations.flat_indexes() {
-            writeln!(writer, "--find-links {}", flat_index.url().verbatim())?;
-            wrote_preamble = true;
+        if include_header {
+            writeln!(
+                writer,
+                "{}",
+                "# This file was autogenerated by uv via the following command:".green()
+            )?;
+            writeln!(
+                writer,
+                "{}",
+                format!(
+                    "#    {}",
+                    cmd(
+                        include_index_url,
+                        include_find_links,
+                        custom_compile_command.clone()
+                    )
+                )
+                .green()
+            )?;
         }
-    }
 
-    // If necessary, include the `--no-binary` and `--only-binary` options.
-    if include_build_options {
-        match build_options.no_binary() {
-            NoBinary::None => {}
-            NoBinary::All => {
-                writeln!(writer, "--no-binary :all:")?;
+        if include_marker_expression {
+            if let Some(marker_env) = resolver_env.marker_environment() {
+                let relevant_markers = resolution.marker_tree(&top_level_index, marker_env)?;
+                if let Some(relevant_markers) = relevant_markers.contents() {
+                    writeln!(
+                        writer,
+                        "{}",
+                        "# Pinned dependencies known to be valid for:".green()
+                    )?;
+                    writeln!(writer, "{}", format!("#    {relevant_markers}").green())?;
+                }
+            }
+        }
+
+        if include_requires_python {
+            if let Some(requires_python) = resolution.requires_python_intersection() {
+                writeln!(
+                    writer,
+                    "{}",
+                    "# Pinned dependencies are known to support Python:".green()
+                )?;
+                writeln!(writer, "{}", format!("#    {requires_python}").green())?;
+            }
+        }
+
+        let mut wrote_preamble = false;
+
+        // If necessary, include the `--index-url` and `--extra-index-url` locations.
+        if include_index_url {
+            if let Some(index) = index_locations.default_index() {
+                writeln!(writer, "--index-url {}", index.url().verbatim())?;
                 wrote_preamble = true;
             }
-            NoBinary::Packages(packages) => {
-                for package in packages {
-                    writeln!(writer, "--no-binary {package}")?;
+            let mut seen = FxHashSet::default();
+            for extra_index in index_locations.implicit_indexes() {
+                if seen.insert(extra_index.url()) {
+                    writeln!(writer, "--extra-index-url {}", extra_index.url().verbatim())?;
                     wrote_preamble = true;
                 }
             }
         }
-        match build_options.no_build() {
-            NoBuild::None => {}
-            NoBuild::All => {
-                writeln!(writer, "--only-binary :all:")?;
+
+        // If necessary, include the `--find-links` locations.
+        if include_find_links {
+            for flat_index in index_locations.flat_indexes() {
+                writeln!(writer, "--find-links {}", flat_index.url().verbatim())?;
                 wrote_preamble = true;
             }
-            NoBuild::Packages(packages) => {
-                for package in packages {
-                    writeln!(writer, "--only-binary {package}")?;
+        }
+
+        // If necessary, include the `--no-binary` and `--only-binary` options.
+        if include_build_options {
+            match build_options.no_binary() {
+                NoBinary::None => {}
+                NoBinary::All => {
+                    writeln!(writer, "--no-binary :all:")?;
                     wrote_preamble = true;
                 }
+                NoBinary::Packages(packages) => {
+                    for package in packages {
+                        writeln!(writer, "--no-binary {package}")?;
+                        wrote_preamble = true;
+                    }
+                }
+            }
+            match build_options.no_build() {
+                NoBuild::None => {}
+                NoBuild::All => {
+                    writeln!(writer, "--only-binary :all:")?;
+                    wrote_preamble = true;
+                }
+                NoBuild::Packages(packages) => {
+                    for package in packages {
+                        writeln!(writer, "--only-binary {package}")?;
+                        wrote_preamble = true;
+                    }
+                }
+            }
+        }
+
+        // If we wrote an index, add a newline to separate it from the requirements
+        if wrote_preamble {
+            writeln!(writer)?;
+        }
+
+        write!(
+            writer,
+            "{}",
+            DisplayResolutionGraph::new(
+                resolution,
+                &resolver_env,
+                &no_emit_packages,
+                generate_hashes,
+                include_extras,
+                include_markers || universal,
+                include_annotations,
+                include_index_annotation,
+                normalize_specifiers,
+                annotation_style,
+            )
+        )?;
+
+        // If any "unsafe" packages were excluded, notify the user.
+        let excluded = no_emit_packages
+            .iter()
+            .filter(|name| resolution.contains(name))
+            .collect::<Vec<_>>();
+        if !excluded.is_empty() {
+            writeln!(writer)?;
+            writeln!(
+                writer,
+                "{}",
+                "# The following packages were excluded from the output:".green()
+            )?;
+            for package in excluded {
+                writeln!(writer, "# {package}")?;
             }
         }
+
+        // Commit the output to disk, signing it if requested.
+        if let Some(signing_key) = signing_key.as_ref() {
+            writer.commit_signed(signing_key).await?;
+        } else {
+            writer.commit().await?;
+        }
+
+        // Notify the user of any resolution diagnostics.
+        if joint {
+            operations::diagnose_resolution(resolution.diagnostics(), printer)?;
+            operations::diagnose_requires_python(
+                declared_requires_python.as_ref(),
+                resolution.requires_python_intersection().as_ref(),
+                printer,
+            )?;
+            if report_bounds {
+                report_lower_bounds(&resolution.lower_bounds(), printer)?;
+            }
+        } else if let Some(output_file) = output_file {
+            debug!("Wrote {}", output_file.user_display());
+        }
     }
 
-    // If we wrote an index, add a newline to separate it from the requirements
-    if wrote_preamble {
-        writeln!(writer)?;
+    Ok(ExitStatus::Success)
+}
+
+/// Return the intersection of the `requires-python` declared by each `pyproject.toml` among the
+/// given sources, if any.
+async fn declared_requires_python(sources: &[RequirementsSource]) -> Option<RequiresPython> {
+    let mut specifiers = Vec::new();
+    for source in sources {
+        let RequirementsSource::PyprojectToml(path) = source else {
+            continue;
+        };
+        // Any errors in the `pyproject.toml` are reported when reading the requirements.
+        let Ok(contents) = fs_err::tokio::read_to_string(path).await else {
+            continue;
+        };
+        let Ok(pyproject) = toml::from_str::<PyProjectToml>(&contents) else {
+            continue;
+        };
+        if let Some(requires_python) = pyproject
+            .project
+            .and_then(|project| project.requires_python)
+        {
+            specifiers.push(requires_python);
+        }
     }
+    RequiresPython::intersection(specifiers.iter())
+}
 
-    write!(
-        writer,
-        "{}",
-        DisplayResolutionGraph::new(
-            &resolution,
-            &resolver_env,
-            &no_emit_packages,
-            generate_hashes,
-            include_extras,
-            include_markers || universal,
-            include_annotations,
-            include_index_annotation,
-            annotation_style,
-        )
-    )?;
+/// Report any direct requirements whose declared lower bounds couldn't be satisfied.
+fn report_lower_bounds(bounds: &[LowerBound], printer: Printer) -> Result<()> {
+    if bounds.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "All direct requirements can be satisfied by their declared lower bounds"
+        )?;
+        return Ok(());
+    }
 
-    // If any "unsafe" packages were excluded, notify the user.
-    let excluded = no_emit_packages
-        .into_iter()
-        .filter(|name| resolution.contains(name))
-        .collect::<Vec<_>>();
-    if !excluded.is_empty() {
-        writeln!(writer)?;
+    let s = if bounds.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "Found {} direct requirement{s} with inaccurate lower bounds:",
+        bounds.len()
+    )?;
+    for bound in bounds {
         writeln!(
-            writer,
-            "{}",
-            "# The following packages were excluded from the output:".green()
+            printer.stderr(),
+            " {} {bound} (consider `{}`)",
+            "-".dimmed(),
+            bound.suggestion()
         )?;
-        for package in excluded {
-            writeln!(writer, "# {package}")?;
-        }
     }
 
-    // Commit the output to disk.
-    writer.commit().await?;
+    Ok(())
+}
 
-    // Notify the user of any resolution diagnostics.
-    operations::diagnose_resolution(resolution.diagnostics(), printer)?;
+/// A file to write as part of a `pip compile` invocation, along with the extras and groups to
+/// resolve for it.
+#[derive(Debug)]
+struct CompileOutput {
+    output_file: Option<PathBuf>,
+    extras: ExtrasSpecification,
+    groups: DevGroupsSpecification,
+}
 
-    Ok(ExitStatus::Success)
+/// Return the direct requirements for an individual output file: those provided directly (e.g.,
+/// via a `requirements.txt` file), along with those of each source tree for the output's extras
+/// and groups.
+///
+/// The source tree metadata is read from the index populated by the joint resolution, such that
+/// no additional builds are required.
+async fn output_requirements(
+    resolution: &ResolverOutput,
+    source_trees: &[PathBuf],
+    output: &CompileOutput,
+    hasher: &HashStrategy,
+    index: &InMemoryIndex,
+    client: &RegistryClient,
+    build_dispatch: &BuildDispatch<'_>,
+    concurrency: Concurrency,
+) -> Result<Vec<Requirement>> {
+    let mut requirements = resolution
+        .requirements()
+        .iter()
+        .filter(|requirement| !matches!(requirement.origin, Some(RequirementOrigin::Project(..))))
+        .cloned()
+        .collect::<Vec<_>>();
+    if !source_trees.is_empty() {
+        let resolutions = SourceTreeResolver::new(
+            &output.extras,
+            &output.groups,
+            hasher,
+            index,
+            DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
+        )
+        .resolve(source_trees.iter().map(PathBuf::as_path))
+        .await?;
+        requirements.extend(
+            resolutions
+                .into_iter()
+                .flat_map(|resolution| resolution.requirements),
+        );
+    }
+    Ok(requirements)
+}
+
+/// Return the output file for an individual extra or dependency group, by appending its name to
+/// the file stem (e.g., `requirements-dev.txt` for `requirements.txt` and `dev`).
+fn output_file_for(output_file: &Path, name: &str) -> PathBuf {
+    let stem = output_file
+        .file_stem()
+        .map(|stem| stem.to_string_lossy())
+        .unwrap_or_default();
+    let file_name = if let Some(extension) = output_file.extension() {
+        format!("{stem}-{name}.{}", extension.to_string_lossy())
+    } else {
+        format!("{stem}-{name}")
+    };
+    output_file.with_file_name(file_name)
 }
 
 /// Format the uv command used to generate the output file.
@@ -669,6 +1354,83 @@ fn cmd(
                 return Some(None);
             }
 
+            // Always skip the `--minimal-changes` flag, which only applies when upgrading.
+            if arg == "--minimal-changes" {
+                *skip_next = None;
+                return Some(None);
+            }
+
+            // Always skip the `--report-bounds` flag, which doesn't affect the output.
+            if arg == "--report-bounds" {
+                *skip_next = None;
+                return Some(None);
+            }
+
+            // Always skip the `--show-derivation-tree` flag, which doesn't affect the output.
+            if arg == "--show-derivation-tree" {
+                *skip_next = None;
+                return Some(None);
+            }
+
+            // Always skip the `--verify-determinism` flag, which doesn't affect the output.
+            if arg == "--verify-determinism" {
+                *skip_next = None;
+                return Some(None);
+            }
+
+            // Always skip the `--seed` option and mark the next item to be skipped, as it doesn't
+            // affect the output.
+            if arg == "--seed" {
+                *skip_next = Some(true);
+                return Some(None);
+            }
+
+            // Skip only this argument if option and value are together
+            if arg.starts_with("--seed=") {
+                // Reset state; skip this iteration.
+                *skip_next = None;
+                return Some(None);
+            }
+
+            // Always skip the `--resume` flag, which doesn't affect the output.
+            if arg == "--resume" {
+                *skip_next = None;
+                return Some(None);
+            }
+
+            // Always skip the `--sign` option and mark the next item to be skipped, to avoid
+            // recording the path to the signing key.
+            if arg == "--sign" {
+                *skip_next = Some(true);
+                return Some(None);
+            }
+
+            // Skip only this argument if option and value are together
+            if arg.starts_with("--sign=") {
+                // Reset state; skip this iteration.
+                *skip_next = None;
+                return Some(None);
+            }
+
+            // Always skip the `--explain` flag, which doesn't affect the output.
+            if arg == "--explain" {
+                *skip_next = None;
+                return Some(None);
+            }
+
+            // Always skip the `--emit-graph` option and mark the next item to be skipped
+            if arg == "--emit-graph" {
+                *skip_next = Some(true);
+                return Some(None);
+            }
+
+            // Skip only this argument if option and value are together
+            if arg.starts_with("--emit-graph=") {
+                // Reset state; skip this iteration.
+                *skip_next = None;
+                return Some(None);
+            }
+
             // Always skip the `--quiet` flag.
             if arg == "--quiet" || arg == "-q" {
                 *skip_next = None;
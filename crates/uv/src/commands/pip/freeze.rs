@@ -1,5 +1,5 @@
 use std::fmt::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 use itertools::Itertools;
@@ -19,17 +19,22 @@ pub(crate) fn pip_freeze(
     exclude_editable: bool,
     strict: bool,
     python: Option<&str>,
+    environment: Option<&Path>,
     system: bool,
     paths: Option<Vec<PathBuf>>,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
     // Detect the current Python interpreter.
-    let environment = PythonEnvironment::find(
-        &python.map(PythonRequest::parse).unwrap_or_default(),
-        EnvironmentPreference::from_system_flag(system, false),
-        cache,
-    )?;
+    let environment = if let Some(environment) = environment {
+        PythonEnvironment::from_root(environment, cache)?
+    } else {
+        PythonEnvironment::find(
+            &python.map(PythonRequest::parse).unwrap_or_default(),
+            EnvironmentPreference::from_system_flag(system, false),
+            cache,
+        )?
+    };
 
     report_target_environment(&environment, cache, printer)?;
 
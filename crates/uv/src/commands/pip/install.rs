@@ -1,17 +1,20 @@
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use tracing::{debug, enabled, Level};
 
-use uv_cache::Cache;
+use uv_cache::{Cache, Refresh};
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, Constraints, DevGroupsSpecification, DryRun,
-    ExtrasSpecification, HashCheckingMode, IndexStrategy, PreviewMode, Reinstall, SourceStrategy,
-    TrustedHost, Upgrade,
+    BuildOptions, ByteSize, Concurrency, ConfigSettings, Constraints, DevGroupsSpecification,
+    DryRun, EmbeddedIndexStrategy, ExtrasSpecification, HashCheckingMode, IndexStrategy,
+    IndexUnavailablePolicy, NoBinary, NoBuild, PackageBuildEnv, PackageConfigSettings, PreviewMode,
+    Reinstall, SourceStrategy, TrustedHost, Upgrade, WheelOptimization,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
@@ -19,26 +22,34 @@ use uv_distribution_types::{
     DependencyMetadata, Index, IndexLocations, NameRequirementSpecification, Origin, Resolution,
     UnresolvedRequirementSpecification,
 };
-use uv_fs::Simplified;
+use uv_fs::{Simplified, CWD};
 use uv_install_wheel::LinkMode;
 use uv_installer::{SatisfiesResult, SitePackages};
 use uv_pep508::PackageName;
 use uv_pypi_types::{Conflicts, Requirement};
 use uv_python::{
-    EnvironmentPreference, Prefix, PythonEnvironment, PythonInstallation, PythonPreference,
-    PythonRequest, PythonVersion, Target,
+    ContainerPython, EnvironmentPreference, Prefix, PythonDownloads, PythonEnvironment,
+    PythonInstallation, PythonPreference, PythonRequest, PythonVersion, Target,
 };
-use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_requirements::{find_known_hashes, RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
-    DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PythonRequirement,
-    ResolutionMode, ResolverEnvironment,
+    DependencyMode, DisplayResolutionExplanation, ExcludeNewer, ExcludeNewerPackage, FlatIndex,
+    InvalidMetadataPolicy, LocalVersionPreference, OptionsBuilder, PackageProvider,
+    PackageSourcePolicy, PrereleaseMode, PythonRequirement, ResolutionMode, ResolutionPackage,
+    ResolverEnvironment, WheelPreference, YankedPolicy,
 };
-use uv_types::{BuildIsolation, HashStrategy};
+use uv_settings::PythonInstallMirrors;
+use uv_types::{BuildIsolation, HashStrategy, KnownHashes};
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceError};
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
-use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::pip::size_budget::SizeBudget;
+use crate::commands::pip::{is_foreign_platform, operations, resolution_markers, resolution_tags};
+use crate::commands::project::ProjectEnvironment;
+use crate::commands::venv::activation_command;
 use crate::commands::{diagnostics, ExitStatus};
 use crate::printer::Printer;
 
@@ -55,40 +66,73 @@ pub(crate) async fn pip_install(
     extras: &ExtrasSpecification,
     groups: &DevGroupsSpecification,
     resolution_mode: ResolutionMode,
+    resolution_package: ResolutionPackage,
     prerelease_mode: PrereleaseMode,
+    prerelease_package: Vec<PackageName>,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
+    embedded_index_strategy: EmbeddedIndexStrategy,
+    invalid_metadata: InvalidMetadataPolicy,
+    yanked: YankedPolicy,
+    index_unavailable: IndexUnavailablePolicy,
+    wheel_preference: Vec<WheelPreference>,
+    local_version_preference: Vec<LocalVersionPreference>,
+    resolution_timeout: Option<u64>,
+    max_backtracks: Option<usize>,
+    source_policy: Vec<PackageSourcePolicy>,
+    provided_by: Vec<PackageProvider>,
+    only_hashed: bool,
+    max_total_download_size: Option<ByteSize>,
+    max_package_size: Option<ByteSize>,
     dependency_metadata: DependencyMetadata,
     keyring_provider: KeyringProviderType,
     reinstall: Reinstall,
+    reinstall_vcs: bool,
+    reinstall_local: bool,
     link_mode: LinkMode,
     compile: bool,
     hash_checking: Option<HashCheckingMode>,
     installer_metadata: bool,
     connectivity: Connectivity,
     config_settings: &ConfigSettings,
+    config_settings_package: &PackageConfigSettings,
+    build_env: &PackageBuildEnv,
     no_build_isolation: bool,
     no_build_isolation_package: Vec<PackageName>,
     build_options: BuildOptions,
     modifications: Modifications,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
+    features: Vec<String>,
     strict: bool,
+    check_abi: bool,
     exclude_newer: Option<ExcludeNewer>,
+    exclude_newer_package: ExcludeNewerPackage,
     sources: SourceStrategy,
     python: Option<String>,
+    environment: Option<&Path>,
     system: bool,
     break_system_packages: bool,
     target: Option<Target>,
     prefix: Option<Prefix>,
+    pypackages: bool,
+    create_venv: bool,
+    install_mirrors: &PythonInstallMirrors,
     python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    no_config: bool,
     concurrency: Concurrency,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
+    project_dir: &Path,
     cache: Cache,
     dry_run: DryRun,
+    build_log_dir: Option<&Path>,
+    optimize_wheels: Vec<WheelOptimization>,
+    show_derivation_tree: bool,
+    explain: bool,
     printer: Printer,
     preview: PreviewMode,
 ) -> anyhow::Result<ExitStatus> {
@@ -120,6 +164,7 @@ pub(crate) async fn pip_install(
         overrides,
         extras,
         groups,
+        embedded_index_strategy,
         &client_builder,
     )
     .await?;
@@ -158,8 +203,43 @@ pub(crate) async fn pip_install(
             )
             .collect();
 
+    // If the interpreter lives in a container (e.g., `docker://<container>#/usr/bin/python3`),
+    // stage the installation locally and copy it into the container once it completes.
+    let container = match python
+        .as_deref()
+        .map(ContainerPython::from_request)
+        .transpose()?
+    {
+        Some(Some(container)) => {
+            if target.is_some() || prefix.is_some() || pypackages || environment.is_some() {
+                return Err(anyhow::anyhow!(
+                    "`--target`, `--prefix`, `--pypackages`, and `--environment` are not supported with a container Python interpreter (`{container}`)"
+                ));
+            }
+            if compile {
+                return Err(anyhow::anyhow!(
+                    "`--compile-bytecode` is not supported with a container Python interpreter (`{container}`)"
+                ));
+            }
+            Some(container.environment(&cache).await?)
+        }
+        _ => None,
+    };
+
     // Detect the current Python interpreter.
-    let environment = if target.is_some() || prefix.is_some() {
+    let environment = if let Some(container) = container.as_ref() {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Using Python {} environment in container: {}",
+                container.environment().interpreter().python_version(),
+                container.container()
+            )
+            .dimmed()
+        )?;
+        container.environment().clone()
+    } else if target.is_some() || prefix.is_some() || pypackages {
         let installation = PythonInstallation::find(
             &python
                 .as_deref()
@@ -171,17 +251,54 @@ pub(crate) async fn pip_install(
         )?;
         report_interpreter(&installation, true, printer)?;
         PythonEnvironment::from_installation(installation)
+    } else if let Some(environment) = environment {
+        let environment = PythonEnvironment::from_root(environment, &cache)?;
+        report_target_environment(&environment, &cache, printer)?;
+        environment
     } else {
-        let environment = PythonEnvironment::find(
+        match PythonEnvironment::find(
             &python
                 .as_deref()
                 .map(PythonRequest::parse)
                 .unwrap_or_default(),
             EnvironmentPreference::from_system_flag(system, true),
             &cache,
-        )?;
-        report_target_environment(&environment, &cache, printer)?;
-        environment
+        ) {
+            Ok(environment) => {
+                report_target_environment(&environment, &cache, printer)?;
+                environment
+            }
+            // If requested, create a virtual environment for the project, as in `uv sync`.
+            Err(uv_python::Error::MissingEnvironment(err))
+                if create_venv && !system && !dry_run.enabled() =>
+            {
+                let Some(environment) = create_project_environment(
+                    python.as_deref(),
+                    install_mirrors,
+                    python_preference,
+                    python_downloads,
+                    connectivity,
+                    native_tls,
+                    allow_insecure_host,
+                    no_config,
+                    &cache,
+                    printer,
+                )
+                .await?
+                else {
+                    return Err(uv_python::Error::MissingEnvironment(err).into());
+                };
+                environment
+            }
+            Err(err) => return Err(err.into()),
+        }
+    };
+
+    // Install into the `__pypackages__` directory for the interpreter, if requested.
+    let target = if pypackages {
+        Some(Target::pypackages(project_dir, environment.interpreter()))
+    } else {
+        target
     };
 
     // Apply any `--target` or `--prefix` directories.
@@ -202,6 +319,14 @@ pub(crate) async fn pip_install(
     };
 
     // If the environment is externally managed, abort.
+    if let Some(container) = container.as_ref() {
+        if !break_system_packages && container.is_externally_managed().await? {
+            return Err(anyhow::anyhow!(
+                "The interpreter at {} is externally managed. Instead, create a virtual environment in the container with `python -m venv`.",
+                container.container().cyan()
+            ));
+        }
+    }
     if let Some(externally_managed) = environment.interpreter().is_externally_managed() {
         if break_system_packages {
             debug!("Ignoring externally managed environment due to `--break-system-packages`");
@@ -229,11 +354,49 @@ pub(crate) async fn pip_install(
         python_version.as_ref(),
         python_platform.as_ref(),
         interpreter,
-    );
+    )
+    .with_uv_features(features);
+
+    // If the packages are staged for another platform, skip any steps that require executing them
+    // on the host (i.e., generating entry point scripts and compiling bytecode).
+    let foreign = is_foreign_platform(python_platform.as_ref(), interpreter);
+    if foreign {
+        debug!(
+            "Staging packages for another platform (`sys_platform` is `{}`, not `{}`)",
+            marker_env.sys_platform(),
+            interpreter.markers().sys_platform()
+        );
+        if !interpreter.is_target() && !interpreter.is_prefix() {
+            warn_user!(
+                "Installing packages for another platform into the environment at {}, which will leave it unusable; use `--target` to stage the packages in a separate directory instead",
+                environment.root().user_display().cyan()
+            );
+        }
+        if compile {
+            warn_user!(
+                "Skipping bytecode compilation, which isn't supported when installing packages for another platform"
+            );
+        }
+    }
+    let compile = compile && !foreign;
 
     // Determine the set of installed packages.
     let site_packages = SitePackages::from_environment(&environment)?;
 
+    // Reinstall any packages that were installed from a VCS or local source, if requested.
+    let provenance =
+        operations::reinstall_by_provenance(&site_packages, reinstall_vcs, reinstall_local);
+    let cache = if provenance.is_none() {
+        cache
+    } else {
+        let refresh = cache
+            .refresh()
+            .clone()
+            .combine(Refresh::from(provenance.clone()));
+        cache.with_refresh(refresh)
+    };
+    let reinstall = reinstall.combine(provenance);
+
     // Check if the current environment satisfies the requirements.
     // Ideally, the resolver would be fast enough to let us remove this check. But right now, for large environments,
     // it's an order of magnitude faster to validate the environment than to resolve the requirements.
@@ -257,6 +420,13 @@ pub(crate) async fn pip_install(
                         debug!("Requirement satisfied: {requirement}");
                     }
                 }
+
+                // In strict mode, reject any requested extras that don't exist, even though
+                // there's nothing to install.
+                if strict {
+                    operations::validate_installed_extras(&recursive_requirements, &site_packages)?;
+                }
+
                 DefaultInstallLogger.on_audit(requirements.len(), start, printer)?;
                 if dry_run.enabled() {
                     writeln!(printer.stderr(), "Would make no changes")?;
@@ -284,6 +454,14 @@ pub(crate) async fn pip_install(
         interpreter,
     )?;
 
+    // Read any known hashes from the project directory, or the nearest ancestor that contains a
+    // `uv.hashes.toml` file.
+    let known_hashes = if hash_checking.is_some() {
+        find_known_hashes(project_dir)?
+    } else {
+        KnownHashes::default()
+    };
+
     // Collect the set of required hashes.
     let hasher = if let Some(hash_checking) = hash_checking {
         HashStrategy::from_requirements(
@@ -294,6 +472,7 @@ pub(crate) async fn pip_install(
             constraints
                 .iter()
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            &known_hashes,
             Some(&marker_env),
             hash_checking,
         )?
@@ -336,6 +515,7 @@ pub(crate) async fn pip_install(
         .cache(cache.clone())
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
+        .index_unavailable(index_unavailable)
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -343,6 +523,14 @@ pub(crate) async fn pip_install(
     // Combine the `--no-binary` and `--no-build` flags from the requirements files.
     let build_options = build_options.combine(no_binary, no_build);
 
+    // Source distributions can't be built for an interpreter in a container, since the build
+    // backend would need to run within the container.
+    let build_options = if container.is_some() {
+        build_options.combine(NoBinary::None, NoBuild::All)
+    } else {
+        build_options
+    };
+
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
         let client = FlatIndexClient::new(&client, &cache);
@@ -369,6 +557,7 @@ pub(crate) async fn pip_install(
             build_constraints
                 .iter()
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            &known_hashes,
             Some(&marker_env),
             HashCheckingMode::Verify,
         )?
@@ -396,6 +585,8 @@ pub(crate) async fn pip_install(
         state.clone(),
         index_strategy,
         config_settings,
+        config_settings_package,
+        build_env,
         build_isolation,
         link_mode,
         &build_options,
@@ -404,15 +595,31 @@ pub(crate) async fn pip_install(
         sources,
         concurrency,
         preview,
-    );
+    )
+    .with_exclude_newer_package(exclude_newer_package.clone())
+    .with_build_log_dir(build_log_dir.map(Path::to_path_buf))
+    .with_wheel_optimizations(optimize_wheels);
 
     let options = OptionsBuilder::new()
         .resolution_mode(resolution_mode)
+        .resolution_package(resolution_package)
         .prerelease_mode(prerelease_mode)
+        .prerelease_package(prerelease_package)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
+        .exclude_newer_package(exclude_newer_package)
         .index_strategy(index_strategy)
+        .invalid_metadata(invalid_metadata)
+        .yanked(yanked)
+        .wheel_preference(wheel_preference)
+        .local_version_preference(local_version_preference)
+        .resolution_timeout(resolution_timeout.map(Duration::from_secs))
+        .max_backtracks(max_backtracks)
+        .source_policies(source_policy.into_iter().collect())
+        .providers(provided_by.into_iter().collect())
+        .only_hashed(only_hashed)
         .build_options(build_options.clone())
+        .derivation_tree(show_derivation_tree)
         .build();
 
     // Resolve the requirements.
@@ -440,19 +647,35 @@ pub(crate) async fn pip_install(
         &build_dispatch,
         concurrency,
         options,
+        None,
         Box::new(DefaultResolveLogger),
         printer,
     )
     .await
     {
-        Ok(graph) => Resolution::from(graph),
+        Ok(graph) => {
+            // If requested, explain why each package was pinned to its selected version.
+            if explain {
+                write!(
+                    printer.stderr(),
+                    "{}",
+                    DisplayResolutionExplanation::new(&graph, state.index())
+                )?;
+            }
+            Resolution::from(graph)
+        }
         Err(err) => {
             return diagnostics::OperationDiagnostic::native_tls(native_tls)
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                .map_err(Into::into)
         }
     };
 
+    // In strict mode, reject any requested extras that don't exist.
+    if strict {
+        operations::validate_extras(resolution.diagnostics())?;
+    }
+
     // Sync the environment.
     match operations::install(
         &resolution,
@@ -462,6 +685,7 @@ pub(crate) async fn pip_install(
         &build_options,
         link_mode,
         compile,
+        !foreign,
         &index_locations,
         config_settings,
         &hasher,
@@ -469,11 +693,16 @@ pub(crate) async fn pip_install(
         &client,
         state.in_flight(),
         concurrency,
+        SizeBudget {
+            max_total_download_size,
+            max_package_size,
+        },
         &build_dispatch,
         &cache,
         &environment,
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        check_abi,
         dry_run,
         printer,
     )
@@ -483,7 +712,14 @@ pub(crate) async fn pip_install(
         Err(err) => {
             return diagnostics::OperationDiagnostic::native_tls(native_tls)
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                .map_err(Into::into)
+        }
+    }
+
+    // Copy the staged installation into the container.
+    if let Some(container) = container.as_ref() {
+        if !dry_run.enabled() {
+            container.commit().await?;
         }
     }
 
@@ -491,9 +727,64 @@ pub(crate) async fn pip_install(
     operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
     // Notify the user of any environment diagnostics.
-    if strict && !dry_run.enabled() {
-        operations::diagnose_environment(&resolution, &environment, &marker_env, printer)?;
+    if !dry_run.enabled() {
+        if strict {
+            operations::diagnose_environment(&resolution, &environment, &marker_env, printer)?;
+        } else {
+            operations::diagnose_shadowing(&resolution, &environment, printer)?;
+        }
     }
 
     Ok(ExitStatus::Success)
 }
+
+/// Create a virtual environment for the project in the current directory, for `--create-venv`.
+///
+/// Returns `None` if the current directory isn't within a project.
+#[allow(clippy::fn_params_excessive_bools)]
+async fn create_project_environment(
+    python: Option<&str>,
+    install_mirrors: &PythonInstallMirrors,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    connectivity: Connectivity,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> anyhow::Result<Option<PythonEnvironment>> {
+    let project = match VirtualProject::discover(&CWD, &DiscoveryOptions::default()).await {
+        Ok(project) => project,
+        Err(
+            WorkspaceError::MissingProject(_)
+            | WorkspaceError::MissingPyprojectToml
+            | WorkspaceError::NonWorkspace(_),
+        ) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let environment = ProjectEnvironment::get_or_init(
+        project.workspace(),
+        python.map(PythonRequest::parse),
+        install_mirrors,
+        python_preference,
+        python_downloads,
+        connectivity,
+        native_tls,
+        allow_insecure_host,
+        no_config,
+        None,
+        cache,
+        DryRun::Disabled,
+        printer,
+    )
+    .await?
+    .into_environment()?;
+
+    if let Some(activation) = activation_command(&environment) {
+        writeln!(printer.stderr(), "Activate with: {}", activation.green())?;
+    }
+
+    Ok(Some(environment))
+}
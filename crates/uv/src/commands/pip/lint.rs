@@ -0,0 +1,737 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
+use tracing::debug;
+use version_ranges::Ranges;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{
+    BuildOptions, Concurrency, ConfigSettings, Constraints, DevGroupsSpecification,
+    ExtrasSpecification, IndexStrategy, KeyringProviderType, PackageBuildEnv,
+    PackageConfigSettings, PreviewMode, Reinstall, SourceStrategy, TrustedHost, Upgrade,
+};
+use uv_dispatch::{BuildDispatch, SharedState};
+use uv_distribution_types::{
+    DependencyMetadata, Index, IndexLocations, NameRequirementSpecification, UnresolvedRequirement,
+    UnresolvedRequirementSpecification,
+};
+use uv_fs::Simplified;
+use uv_install_wheel::LinkMode;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pep508::{RequirementOrigin, VerbatimUrl};
+use uv_pypi_types::{Conflicts, Requirement, RequirementSource, VerbatimParsedUrl};
+use uv_python::{EnvironmentPreference, PythonEnvironment, PythonRequest};
+use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_resolver::{
+    ExcludeNewer, FlatIndex, OptionsBuilder, PythonRequirement, RequiresPython, ResolverEnvironment,
+};
+use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy};
+use uv_warnings::warn_user;
+use uv_workspace::pyproject::PyProjectToml;
+
+use crate::commands::pip::loggers::SummaryResolveLogger;
+use crate::commands::pip::operations;
+use crate::commands::{elapsed, ExitStatus};
+use crate::printer::Printer;
+
+/// Lint requirements files for common mistakes.
+pub(crate) async fn pip_lint(
+    requirements: &[RequirementsSource],
+    constraints: &[RequirementsSource],
+    fix: bool,
+    index_locations: IndexLocations,
+    index_strategy: IndexStrategy,
+    keyring_provider: KeyringProviderType,
+    dependency_metadata: DependencyMetadata,
+    build_options: BuildOptions,
+    exclude_newer: Option<ExcludeNewer>,
+    python: Option<&str>,
+    system: bool,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    let start = Instant::now();
+
+    let client_builder = BaseClientBuilder::new()
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .keyring(keyring_provider)
+        .allow_insecure_host(allow_insecure_host.to_vec());
+
+    // Apply any mechanical fixes before linting, such that the reported diagnostics reflect the
+    // state of the files on disk.
+    if fix {
+        let mut fixed = 0;
+        for source in requirements.iter().chain(constraints) {
+            let RequirementsSource::RequirementsTxt(path) = source else {
+                continue;
+            };
+            if !is_local_file(path) {
+                continue;
+            }
+            fixed += fix_requirements_txt(path)?;
+        }
+        if fixed > 0 {
+            let s = if fixed == 1 { "" } else { "s" };
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!("Fixed {}", format!("{fixed} issue{s}").bold()).dimmed()
+            )?;
+        }
+    }
+
+    // Read the requirements and constraints.
+    let mut entries = Vec::new();
+    for source in requirements {
+        read_source(source, &client_builder, &mut entries).await?;
+    }
+    let mut constraint_entries = Vec::new();
+    for source in constraints {
+        let spec = RequirementsSpecification::from_source(source, &client_builder).await?;
+        for entry in spec.requirements {
+            if let UnresolvedRequirement::Named(requirement) = entry.requirement {
+                constraint_entries.push(requirement);
+            }
+        }
+        constraint_entries.extend(spec.constraints.into_iter().map(|spec| spec.requirement));
+    }
+
+    // To detect unused constraints, resolve the requirements (along with the constraints) and
+    // determine which of the constrained packages appear in the resolved graph.
+    let constrained = if constraint_entries.is_empty() {
+        None
+    } else {
+        resolve_constrained(
+            &entries,
+            &constraint_entries,
+            index_locations,
+            index_strategy,
+            dependency_metadata,
+            build_options,
+            exclude_newer,
+            python,
+            system,
+            client_builder,
+            concurrency,
+            cache,
+            printer,
+            preview,
+        )
+        .await?
+    };
+
+    let diagnostics = lint(&entries, &constraint_entries, constrained.as_ref());
+
+    let s = if entries.len() + constraint_entries.len() == 1 {
+        ""
+    } else {
+        "s"
+    };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Linted {} {}",
+            format!(
+                "{} requirement{}",
+                entries.len() + constraint_entries.len(),
+                s
+            )
+            .bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+
+    if diagnostics.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "No issues found".to_string().dimmed()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let issues = if diagnostics.len() == 1 {
+        "issue"
+    } else {
+        "issues"
+    };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Found {}",
+            format!("{} {}", diagnostics.len(), issues).bold()
+        )
+        .dimmed()
+    )?;
+    for diagnostic in &diagnostics {
+        writeln!(printer.stderr(), "{}", diagnostic.message().bold())?;
+    }
+
+    Ok(ExitStatus::Failure)
+}
+
+/// A diagnostic emitted by `uv pip lint`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LintDiagnostic {
+    /// The same requirement (with the same markers) is declared more than once.
+    DuplicateRequirement {
+        name: PackageName,
+        origins: Vec<String>,
+    },
+    /// Two requirements for the same package have specifiers that can never both be satisfied.
+    ContradictorySpecifiers {
+        name: PackageName,
+        first: String,
+        second: String,
+    },
+    /// A requirement has specifiers that can never be satisfied on their own.
+    UnsatisfiableSpecifiers { requirement: String, origin: String },
+    /// A constraint doesn't apply to any package in the resolved requirements.
+    UnusedConstraint { name: PackageName, origin: String },
+    /// A requirement has a marker that can never evaluate to `true`.
+    UnsatisfiableMarker { requirement: String, origin: String },
+    /// A requirement doesn't declare a lower bound on its version.
+    MissingLowerBound { requirement: String, origin: String },
+}
+
+impl LintDiagnostic {
+    /// Convert the diagnostic into a human-readable message.
+    fn message(&self) -> String {
+        match self {
+            Self::DuplicateRequirement { name, origins } => format!(
+                "The requirement `{name}` is declared more than once (in: {})",
+                origins.join(", ")
+            ),
+            Self::ContradictorySpecifiers {
+                name,
+                first,
+                second,
+            } => format!(
+                "The requirements for `{name}` are contradictory: `{first}` and `{second}` can never both be satisfied"
+            ),
+            Self::UnsatisfiableSpecifiers {
+                requirement,
+                origin,
+            } => format!("{origin}: The version specifiers on `{requirement}` can never be satisfied"),
+            Self::UnusedConstraint { name, origin } => format!(
+                "{origin}: The constraint on `{name}` does not apply to any package in the resolved requirements"
+            ),
+            Self::UnsatisfiableMarker {
+                requirement,
+                origin,
+            } => format!("{origin}: The markers on `{requirement}` can never be satisfied"),
+            Self::MissingLowerBound {
+                requirement,
+                origin,
+            } => format!("{origin}: The requirement `{requirement}` is missing a lower bound"),
+        }
+    }
+}
+
+/// Read the named requirements from a [`RequirementsSource`].
+pub(super) async fn read_source(
+    source: &RequirementsSource,
+    client_builder: &BaseClientBuilder<'_>,
+    entries: &mut Vec<Requirement>,
+) -> Result<()> {
+    match source {
+        RequirementsSource::PyprojectToml(path) => {
+            let contents = fs_err::tokio::read_to_string(path).await?;
+            let pyproject = toml::from_str::<PyProjectToml>(&contents)
+                .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
+            let Some(project) = pyproject.project else {
+                debug!(
+                    "No `[project]` table found in `{}`; skipping",
+                    path.user_display()
+                );
+                return Ok(());
+            };
+            let origin = RequirementOrigin::Project(path.clone(), project.name.clone());
+            for dependency in project.dependencies.iter().flatten().chain(
+                project
+                    .optional_dependencies
+                    .iter()
+                    .flat_map(|extras| extras.values().flatten()),
+            ) {
+                let requirement = uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(dependency)
+                    .with_context(|| {
+                        format!(
+                            "Failed to parse `{dependency}` in `{}`",
+                            path.user_display()
+                        )
+                    })?;
+                entries.push(Requirement::from(requirement.with_origin(origin.clone())));
+            }
+        }
+        RequirementsSource::SetupPy(path) | RequirementsSource::SetupCfg(path) => {
+            return Err(anyhow::anyhow!(
+                "Linting is only supported for `requirements.txt` and `pyproject.toml` files, but found: `{}`",
+                path.user_display()
+            ));
+        }
+        _ => {
+            let spec = RequirementsSpecification::from_source(source, client_builder).await?;
+            for entry in spec.requirements {
+                if let UnresolvedRequirement::Named(requirement) = entry.requirement {
+                    entries.push(requirement);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Resolve the requirements along with the constraints, returning the names of the constrained
+/// packages that appear in the resolved graph.
+///
+/// Returns `None` if the requirements can't be resolved, in which case unused constraints can't be
+/// detected.
+async fn resolve_constrained(
+    requirements: &[Requirement],
+    constraints: &[Requirement],
+    index_locations: IndexLocations,
+    index_strategy: IndexStrategy,
+    dependency_metadata: DependencyMetadata,
+    build_options: BuildOptions,
+    exclude_newer: Option<ExcludeNewer>,
+    python: Option<&str>,
+    system: bool,
+    client_builder: BaseClientBuilder<'_>,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<Option<FxHashSet<PackageName>>> {
+    let environment = match PythonEnvironment::find(
+        &python.map(PythonRequest::parse).unwrap_or_default(),
+        EnvironmentPreference::from_system_flag(system, false),
+        cache,
+    ) {
+        Ok(environment) => environment,
+        Err(err) => {
+            debug!("No Python environment found to resolve the requirements: {err}");
+            warn_user!(
+                "Skipping the unused constraint check, as no Python interpreter was found to resolve the requirements"
+            );
+            return Ok(None);
+        }
+    };
+    let interpreter = environment.interpreter();
+
+    // Resolve for all environments supported by the interpreter's Python version, such that a
+    // constraint is considered used if it applies on any platform.
+    let python_requirement = PythonRequirement::from_requires_python(
+        interpreter,
+        RequiresPython::greater_than_equal_version(interpreter.python_version()),
+    );
+
+    // Add all authenticated sources to the cache.
+    for index in index_locations.allowed_indexes() {
+        if let Some(credentials) = index.credentials() {
+            let credentials = Arc::new(credentials);
+            uv_auth::store_credentials(index.raw_url(), credentials.clone());
+            if let Some(root_url) = index.root_url() {
+                uv_auth::store_credentials(&root_url, credentials.clone());
+            }
+        }
+    }
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::try_from(client_builder)?
+        .cache(cache.clone())
+        .index_urls(index_locations.index_urls())
+        .index_strategy(index_strategy)
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // Resolve the flat indexes from `--find-links`.
+    let hasher = HashStrategy::None;
+    let flat_index = {
+        let client = FlatIndexClient::new(&client, cache);
+        let entries = client
+            .fetch(index_locations.flat_indexes().map(Index::url))
+            .await?;
+        FlatIndex::from_entries(entries, None, &hasher, &build_options)
+    };
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    let config_settings = ConfigSettings::default();
+    let config_settings_package = PackageConfigSettings::default();
+    let build_env = PackageBuildEnv::default();
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        cache,
+        Constraints::default(),
+        interpreter,
+        &index_locations,
+        &flat_index,
+        &dependency_metadata,
+        state.clone(),
+        index_strategy,
+        &config_settings,
+        &config_settings_package,
+        &build_env,
+        BuildIsolation::Isolated,
+        LinkMode::default(),
+        &build_options,
+        &hasher,
+        exclude_newer,
+        SourceStrategy::Enabled,
+        concurrency,
+        preview,
+    );
+
+    let options = OptionsBuilder::new()
+        .exclude_newer(exclude_newer)
+        .index_strategy(index_strategy)
+        .build_options(build_options.clone())
+        .build();
+
+    let resolution = match operations::resolve(
+        requirements
+            .iter()
+            .cloned()
+            .map(UnresolvedRequirementSpecification::from)
+            .collect(),
+        constraints
+            .iter()
+            .cloned()
+            .map(NameRequirementSpecification::from)
+            .collect(),
+        Vec::new(),
+        Vec::new(),
+        None,
+        BTreeSet::default(),
+        &ExtrasSpecification::default(),
+        &DevGroupsSpecification::default(),
+        Vec::new(),
+        EmptyInstalledPackages,
+        &hasher,
+        &Reinstall::None,
+        &Upgrade::None,
+        None,
+        ResolverEnvironment::universal(vec![]),
+        python_requirement,
+        Conflicts::empty(),
+        &client,
+        &flat_index,
+        state.index(),
+        &build_dispatch,
+        concurrency,
+        options,
+        None,
+        Box::new(SummaryResolveLogger),
+        printer,
+    )
+    .await
+    {
+        Ok(resolution) => resolution,
+        Err(err) => {
+            debug!("Failed to resolve the requirements: {err}");
+            warn_user!(
+                "Skipping the unused constraint check, as the requirements could not be resolved"
+            );
+            return Ok(None);
+        }
+    };
+
+    Ok(Some(
+        constraints
+            .iter()
+            .map(|constraint| &constraint.name)
+            .filter(|name| resolution.contains(name))
+            .cloned()
+            .collect(),
+    ))
+}
+
+/// Run all lints over the given requirements and constraints.
+///
+/// If `constrained` is provided, it contains the names of the constrained packages that appear in
+/// the resolved graph; any other constraint is reported as unused.
+fn lint(
+    requirements: &[Requirement],
+    constraints: &[Requirement],
+    constrained: Option<&FxHashSet<PackageName>>,
+) -> Vec<LintDiagnostic> {
+    let mut diagnostics = Vec::new();
+
+    // Flag duplicate requirements, i.e., requirements for the same package with the same markers.
+    let mut seen: FxHashMap<(&PackageName, String), Vec<String>> = FxHashMap::default();
+    for requirement in requirements {
+        let marker = requirement.marker.try_to_string().unwrap_or_default();
+        seen.entry((&requirement.name, marker))
+            .or_default()
+            .push(display_origin(requirement.origin.as_ref()));
+    }
+    for ((name, _), origins) in seen
+        .into_iter()
+        .sorted_by(|((a, x), _), ((b, y), _)| a.cmp(b).then_with(|| x.cmp(y)))
+    {
+        if origins.len() > 1 {
+            diagnostics.push(LintDiagnostic::DuplicateRequirement {
+                name: name.clone(),
+                origins,
+            });
+        }
+    }
+
+    // Flag contradictory specifiers across requirements and constraints whose markers overlap.
+    let ranges = requirements
+        .iter()
+        .chain(constraints)
+        .filter_map(|requirement| {
+            let RequirementSource::Registry { specifier, .. } = &requirement.source else {
+                return None;
+            };
+            Some((requirement, Ranges::<Version>::from(specifier.clone())))
+        })
+        .collect::<Vec<_>>();
+    for (i, (first, first_range)) in ranges.iter().enumerate() {
+        for (second, second_range) in &ranges[i + 1..] {
+            if first.name != second.name {
+                continue;
+            }
+            if first_range.is_empty() || second_range.is_empty() {
+                continue;
+            }
+            if first.marker.is_disjoint(second.marker) {
+                continue;
+            }
+            if first_range.intersection(second_range).is_empty() {
+                diagnostics.push(LintDiagnostic::ContradictorySpecifiers {
+                    name: first.name.clone(),
+                    first: first.to_string(),
+                    second: second.to_string(),
+                });
+            }
+        }
+    }
+
+    // Flag requirements whose specifiers contradict each other, like `foo>2,<1`.
+    for (requirement, range) in &ranges {
+        if range.is_empty() {
+            diagnostics.push(LintDiagnostic::UnsatisfiableSpecifiers {
+                requirement: requirement.to_string(),
+                origin: display_origin(requirement.origin.as_ref()),
+            });
+        }
+    }
+
+    // Flag constraints that don't apply to any package in the resolved graph, including
+    // transitive dependencies.
+    if let Some(constrained) = constrained {
+        for constraint in constraints {
+            if constrained.contains(&constraint.name) {
+                continue;
+            }
+            diagnostics.push(LintDiagnostic::UnusedConstraint {
+                name: constraint.name.clone(),
+                origin: display_origin(constraint.origin.as_ref()),
+            });
+        }
+    }
+
+    // Flag markers that can never be satisfied.
+    for requirement in requirements.iter().chain(constraints) {
+        if requirement.marker.is_false() {
+            diagnostics.push(LintDiagnostic::UnsatisfiableMarker {
+                requirement: requirement.name.to_string(),
+                origin: display_origin(requirement.origin.as_ref()),
+            });
+        }
+    }
+
+    // Flag registry requirements without a lower bound.
+    for requirement in requirements {
+        let RequirementSource::Registry { specifier, .. } = &requirement.source else {
+            continue;
+        };
+        if requirement.marker.is_false() {
+            continue;
+        }
+        // Unsatisfiable specifiers are already flagged above.
+        let range = Ranges::<Version>::from(specifier.clone());
+        let Some((lower, _)) = range.bounding_range() else {
+            continue;
+        };
+        if matches!(lower, std::ops::Bound::Unbounded) {
+            diagnostics.push(LintDiagnostic::MissingLowerBound {
+                requirement: requirement.to_string(),
+                origin: display_origin(requirement.origin.as_ref()),
+            });
+        }
+    }
+
+    diagnostics
+}
+
+/// Format the origin of a requirement for display.
+pub(super) fn display_origin(origin: Option<&RequirementOrigin>) -> String {
+    match origin {
+        Some(RequirementOrigin::File(path) | RequirementOrigin::Project(path, _)) => {
+            path.user_display().to_string()
+        }
+        Some(RequirementOrigin::Workspace) => "(workspace)".to_string(),
+        None => "(unknown)".to_string(),
+    }
+}
+
+/// Returns `true` if the path refers to a local file that can be rewritten.
+fn is_local_file(path: &Path) -> bool {
+    !(path == Path::new("-") || path.starts_with("http://") || path.starts_with("https://"))
+}
+
+/// Remove exact duplicate requirements and requirements with unsatisfiable markers from a
+/// `requirements.txt` file, returning the number of lines removed.
+///
+/// Only single-line requirements are considered; lines with continuations, options, or
+/// includes are left untouched.
+fn fix_requirements_txt(path: &Path) -> Result<usize> {
+    let contents = fs_err::read_to_string(path)?;
+
+    let mut seen = FxHashSet::default();
+    let mut removed = 0;
+    let mut output = String::with_capacity(contents.len());
+    let mut continuation = false;
+    for line in contents.split_inclusive('\n') {
+        let previous = continuation;
+        let statement = line.split(" #").next().unwrap_or_default().trim();
+        continuation = statement.ends_with('\\');
+        if previous || continuation || statement.is_empty() || statement.starts_with(['-', '#']) {
+            output.push_str(line);
+            continue;
+        }
+        let Ok(requirement) = uv_pep508::Requirement::<VerbatimUrl>::from_str(statement) else {
+            output.push_str(line);
+            continue;
+        };
+        if requirement.marker.is_false() || !seen.insert(requirement.to_string()) {
+            debug!("Removing `{statement}` from `{}`", path.user_display());
+            removed += 1;
+            continue;
+        }
+        output.push_str(line);
+    }
+
+    if removed > 0 {
+        fs_err::write(path, output)?;
+    }
+
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rustc_hash::FxHashSet;
+
+    use uv_normalize::PackageName;
+    use uv_pypi_types::{Requirement, VerbatimParsedUrl};
+
+    use super::{lint, LintDiagnostic};
+
+    fn requirement(s: &str) -> Requirement {
+        Requirement::from(uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(s).unwrap())
+    }
+
+    #[test]
+    fn duplicate() {
+        let requirements = [requirement("foo>=1.0"), requirement("foo>=1.0")];
+        let diagnostics = lint(&requirements, &[], None);
+        assert!(matches!(
+            diagnostics.as_slice(),
+            [LintDiagnostic::DuplicateRequirement { .. }]
+        ));
+    }
+
+    #[test]
+    fn contradictory() {
+        let requirements = [requirement("foo>=2.0"), requirement("foo<1.0")];
+        let diagnostics = lint(&requirements, &[], None);
+        assert!(diagnostics.iter().any(|diagnostic| matches!(
+            diagnostic,
+            LintDiagnostic::ContradictorySpecifiers { .. }
+        )));
+    }
+
+    #[test]
+    fn contradictory_disjoint_markers() {
+        let requirements = [
+            requirement("foo>=2.0 ; python_version >= '3.8'"),
+            requirement("foo>=0.1,<1.0 ; python_version < '3.8'"),
+        ];
+        let diagnostics = lint(&requirements, &[], None);
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn unsatisfiable_specifiers() {
+        let requirements = [requirement("foo>2,<1"), requirement("bar>=1.0")];
+        let diagnostics = lint(&requirements, &[], None);
+        assert!(matches!(
+            diagnostics.as_slice(),
+            [LintDiagnostic::UnsatisfiableSpecifiers { .. }]
+        ));
+    }
+
+    #[test]
+    fn unused_constraint() {
+        let requirements = [requirement("foo>=1.0")];
+        let constraints = [requirement("bar<2.0"), requirement("baz<2.0")];
+
+        // `baz` is a transitive dependency of `foo`, so only `bar` is unused.
+        let constrained = FxHashSet::from_iter([PackageName::from_str("baz").unwrap()]);
+        let diagnostics = lint(&requirements, &constraints, Some(&constrained));
+        assert!(matches!(
+            diagnostics.as_slice(),
+            [LintDiagnostic::UnusedConstraint { name, .. }] if name.as_ref() == "bar"
+        ));
+
+        // Without a resolution, unused constraints can't be detected.
+        let diagnostics = lint(&requirements, &constraints, None);
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn unsatisfiable_marker() {
+        let requirements = [requirement(
+            "foo>=1.0 ; python_version < '3.0' and python_version >= '3.0'",
+        )];
+        let diagnostics = lint(&requirements, &[], None);
+        assert!(matches!(
+            diagnostics.as_slice(),
+            [LintDiagnostic::UnsatisfiableMarker { .. }]
+        ));
+    }
+
+    #[test]
+    fn missing_lower_bound() {
+        let requirements = [requirement("foo<2.0"), requirement("bar")];
+        let diagnostics = lint(&requirements, &[], None);
+        assert_eq!(diagnostics.len(), 2);
+    }
+}
@@ -1,5 +1,6 @@
 use std::cmp::max;
 use std::fmt::Write;
+use std::path::Path;
 
 use anstream::println;
 use anyhow::Result;
@@ -49,6 +50,7 @@ pub(crate) async fn pip_list(
     strict: bool,
     exclude_newer: Option<ExcludeNewer>,
     python: Option<&str>,
+    environment: Option<&Path>,
     system: bool,
     native_tls: bool,
     cache: &Cache,
@@ -60,11 +62,15 @@ pub(crate) async fn pip_list(
     }
 
     // Detect the current Python interpreter.
-    let environment = PythonEnvironment::find(
-        &python.map(PythonRequest::parse).unwrap_or_default(),
-        EnvironmentPreference::from_system_flag(system, false),
-        cache,
-    )?;
+    let environment = if let Some(environment) = environment {
+        PythonEnvironment::from_root(environment, cache)?
+    } else {
+        PythonEnvironment::find(
+            &python.map(PythonRequest::parse).unwrap_or_default(),
+            EnvironmentPreference::from_system_flag(system, false),
+            cache,
+        )?
+    };
 
     report_target_environment(&environment, cache, printer)?;
 
@@ -1,16 +1,18 @@
 use std::collections::BTreeSet;
 use std::fmt;
 use std::fmt::Write;
+use std::sync::Arc;
 
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::{FxBuildHasher, FxHashMap};
 
-use uv_distribution_types::{InstalledMetadata, Name};
+use uv_distribution_types::{InstalledDist, InstalledMetadata, Name};
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 
 use crate::commands::pip::operations::Changelog;
+use crate::commands::reporters::InstallReporter;
 use crate::commands::{elapsed, ChangeEvent, ChangeEventKind};
 use crate::printer::Printer;
 
@@ -35,6 +37,18 @@ pub(crate) trait InstallLogger {
 
     /// Log the completion of the operation.
     fn on_complete(&self, changelog: &Changelog, printer: Printer) -> fmt::Result;
+
+    /// Log the removal of a single distribution, as soon as it's removed.
+    fn on_uninstalled(&self, _dist: &InstalledDist) {}
+
+    /// Return the reporter to notify as each distribution is installed, wrapping the given
+    /// progress reporter.
+    fn install_reporter(
+        &self,
+        reporter: InstallReporter,
+    ) -> Arc<dyn uv_installer::InstallReporter> {
+        Arc::new(reporter)
+    }
 }
 
 /// The default logger for install operations.
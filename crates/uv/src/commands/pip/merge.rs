@@ -0,0 +1,315 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Instant;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use version_ranges::Ranges;
+
+use uv_client::{BaseClientBuilder, Connectivity};
+use uv_configuration::TrustedHost;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifiers};
+use uv_pep508::{RequirementOrigin, VerbatimUrl};
+use uv_pypi_types::{Requirement, RequirementSource};
+use uv_requirements::RequirementsSource;
+
+use crate::commands::pip::lint::{display_origin, read_source};
+use crate::commands::{elapsed, ExitStatus};
+use crate::printer::Printer;
+
+/// Merge multiple requirements files into a single set of requirements.
+pub(crate) async fn pip_merge(
+    requirements: &[RequirementsSource],
+    output_file: Option<&Path>,
+    connectivity: Connectivity,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = Instant::now();
+
+    let client_builder = BaseClientBuilder::new()
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .allow_insecure_host(allow_insecure_host.to_vec());
+
+    // Read the requirements.
+    let mut entries = Vec::new();
+    for source in requirements {
+        read_source(source, &client_builder, &mut entries).await?;
+    }
+    let total = entries.len();
+
+    let merged = merge(entries)?;
+
+    let mut output = String::new();
+    for entry in &merged {
+        writeln!(
+            output,
+            "{}",
+            uv_pep508::Requirement::<VerbatimUrl>::from(entry.requirement.clone())
+        )?;
+        match entry.origins.as_slice() {
+            [] => {}
+            [origin] => writeln!(output, "    # via {}", display_via(origin))?,
+            origins => {
+                writeln!(output, "    # via")?;
+                for origin in origins {
+                    writeln!(output, "    #   {}", display_via(origin))?;
+                }
+            }
+        }
+    }
+
+    if let Some(output_file) = output_file {
+        fs_err::tokio::write(output_file, &output).await?;
+    } else {
+        write!(printer.stdout(), "{output}")?;
+    }
+
+    let s = if total == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Merged {} into {} {}",
+            format!("{total} requirement{s}").bold(),
+            format!(
+                "{} requirement{}",
+                merged.len(),
+                if merged.len() == 1 { "" } else { "s" }
+            )
+            .bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+#[derive(Debug, thiserror::Error)]
+enum MergeError {
+    #[error("The requirements `{first}` ({first_origin}) and `{second}` ({second_origin}) have no versions in common")]
+    EmptyIntersection {
+        first: String,
+        first_origin: String,
+        second: String,
+        second_origin: String,
+    },
+    #[error("The requirements `{first}` ({first_origin}) and `{second}` ({second_origin}) refer to different sources")]
+    ConflictingSources {
+        first: String,
+        first_origin: String,
+        second: String,
+        second_origin: String,
+    },
+}
+
+/// A requirement produced by merging one or more requirements for the same package.
+#[derive(Debug)]
+struct MergedRequirement {
+    /// The merged requirement.
+    requirement: Requirement,
+    /// The origins of the requirements that were merged.
+    origins: Vec<RequirementOrigin>,
+}
+
+/// Merge requirements for the same package and markers into a single requirement, sorted by
+/// package name.
+///
+/// Version specifiers are intersected and extras are unioned. Requirements with different markers
+/// are kept as separate entries, since each constraint only applies to its own environments. A URL
+/// requirement can absorb requirements without version specifiers, but two requirements with
+/// different sources can't be merged.
+fn merge(requirements: Vec<Requirement>) -> Result<Vec<MergedRequirement>, MergeError> {
+    let mut merged: BTreeMap<PackageName, Vec<MergedRequirement>> = BTreeMap::new();
+
+    for requirement in requirements {
+        let entries = merged.entry(requirement.name.clone()).or_default();
+        let Some(existing) = entries
+            .iter_mut()
+            .find(|existing| existing.requirement.marker == requirement.marker)
+        else {
+            entries.push(MergedRequirement {
+                origins: requirement.origin.clone().into_iter().collect(),
+                requirement,
+            });
+            continue;
+        };
+
+        let source = match (&existing.requirement.source, &requirement.source) {
+            (
+                RequirementSource::Registry {
+                    specifier: first, ..
+                },
+                RequirementSource::Registry {
+                    specifier: second, ..
+                },
+            ) => {
+                let specifier = intersect(first, second);
+                if Ranges::<Version>::from(specifier.clone()).is_empty() {
+                    return Err(MergeError::EmptyIntersection {
+                        first: display_requirement(&existing.requirement),
+                        first_origin: display_origins(&existing.origins),
+                        second: display_requirement(&requirement),
+                        second_origin: display_origin(requirement.origin.as_ref()),
+                    });
+                }
+                let mut source = existing.requirement.source.clone();
+                if let RequirementSource::Registry {
+                    specifier: merged, ..
+                } = &mut source
+                {
+                    *merged = specifier;
+                }
+                source
+            }
+            (_, RequirementSource::Registry { specifier, .. }) if specifier.is_empty() => {
+                existing.requirement.source.clone()
+            }
+            (RequirementSource::Registry { specifier, .. }, _) if specifier.is_empty() => {
+                requirement.source.clone()
+            }
+            (first, second) if first == second => existing.requirement.source.clone(),
+            _ => {
+                return Err(MergeError::ConflictingSources {
+                    first: display_requirement(&existing.requirement),
+                    first_origin: display_origins(&existing.origins),
+                    second: display_requirement(&requirement),
+                    second_origin: display_origin(requirement.origin.as_ref()),
+                });
+            }
+        };
+
+        existing.requirement.source = source;
+        for extra in requirement.extras {
+            if !existing.requirement.extras.contains(&extra) {
+                existing.requirement.extras.push(extra);
+            }
+        }
+        existing.requirement.extras.sort();
+        if let Some(origin) = requirement.origin {
+            if !existing.origins.contains(&origin) {
+                existing.origins.push(origin);
+            }
+        }
+    }
+
+    Ok(merged.into_values().flatten().collect())
+}
+
+/// Intersect two sets of version specifiers, omitting any duplicates.
+fn intersect(first: &VersionSpecifiers, second: &VersionSpecifiers) -> VersionSpecifiers {
+    let mut specifiers = first.iter().cloned().collect::<Vec<_>>();
+    for specifier in second.iter() {
+        if !specifiers.contains(specifier) {
+            specifiers.push(specifier.clone());
+        }
+    }
+    specifiers.into_iter().collect()
+}
+
+/// Format a requirement as it would appear in a `requirements.txt` file.
+fn display_requirement(requirement: &Requirement) -> String {
+    uv_pep508::Requirement::<VerbatimUrl>::from(requirement.clone()).to_string()
+}
+
+/// Format the origins of a merged requirement for display.
+fn display_origins(origins: &[RequirementOrigin]) -> String {
+    origins
+        .iter()
+        .map(|origin| display_origin(Some(origin)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Format the origin of a requirement for a `# via` annotation.
+fn display_via(origin: &RequirementOrigin) -> String {
+    match origin {
+        RequirementOrigin::File(path) => format!("-r {}", path.user_display()),
+        RequirementOrigin::Project(path, name) => format!("{name} ({})", path.user_display()),
+        RequirementOrigin::Workspace => display_origin(Some(origin)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_pypi_types::{Requirement, VerbatimParsedUrl};
+
+    use super::{display_requirement, merge, MergeError};
+
+    fn requirement(s: &str) -> Requirement {
+        Requirement::from(uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(s).unwrap())
+    }
+
+    fn merged(requirements: &[&str]) -> Vec<String> {
+        merge(requirements.iter().map(|s| requirement(s)).collect())
+            .unwrap()
+            .iter()
+            .map(|merged| display_requirement(&merged.requirement))
+            .collect()
+    }
+
+    #[test]
+    fn intersect_specifiers() {
+        assert_eq!(
+            merged(&["foo>=1.0", "bar", "foo<2.0", "foo>=1.0"]),
+            ["bar", "foo>=1.0,<2.0"]
+        );
+    }
+
+    #[test]
+    fn union_extras() {
+        assert_eq!(
+            merged(&[
+                "foo[b]>=1.0 ; sys_platform == 'linux'",
+                "foo[a]<2.0 ; sys_platform == 'linux'",
+            ]),
+            ["foo[a,b]>=1.0,<2.0 ; sys_platform == 'linux'"]
+        );
+    }
+
+    #[test]
+    fn separate_markers() {
+        // Requirements with different markers are kept apart, rather than intersecting their
+        // specifiers across environments.
+        assert_eq!(
+            merged(&[
+                "foo>=2.0 ; sys_platform == 'linux'",
+                "foo<1.0 ; sys_platform == 'darwin'",
+                "foo[a] ; sys_platform == 'linux'",
+            ]),
+            [
+                "foo[a]>=2.0 ; sys_platform == 'linux'",
+                "foo<1.0 ; sys_platform == 'darwin'"
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_intersection() {
+        let err = merge(vec![requirement("foo>=2.0"), requirement("foo<1.0")]).unwrap_err();
+        assert!(matches!(err, MergeError::EmptyIntersection { .. }));
+    }
+
+    #[test]
+    fn url_absorbs_unpinned() {
+        assert_eq!(
+            merged(&["foo", "foo @ https://example.com/foo-1.0.tar.gz"]),
+            ["foo @ https://example.com/foo-1.0.tar.gz"]
+        );
+
+        let err = merge(vec![
+            requirement("foo>=1.0"),
+            requirement("foo @ https://example.com/foo-1.0.tar.gz"),
+        ])
+        .unwrap_err();
+        assert!(matches!(err, MergeError::ConflictingSources { .. }));
+    }
+}
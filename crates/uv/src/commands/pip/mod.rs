@@ -5,18 +5,26 @@ use uv_platform_tags::{Tags, TagsError};
 use uv_pypi_types::ResolverMarkerEnvironment;
 use uv_python::{Interpreter, PythonVersion};
 
+pub(crate) mod audit_log;
+pub(crate) mod candidate_policy;
 pub(crate) mod check;
 pub(crate) mod compile;
 pub(crate) mod freeze;
 pub(crate) mod install;
 pub(crate) mod latest;
+pub(crate) mod lint;
 pub(crate) mod list;
 pub(crate) mod loggers;
+pub(crate) mod merge;
 pub(crate) mod operations;
+pub(crate) mod refresh;
 pub(crate) mod show;
+pub(crate) mod signature;
+pub(crate) mod size_budget;
 pub(crate) mod sync;
 pub(crate) mod tree;
 pub(crate) mod uninstall;
+pub(crate) mod verify;
 
 pub(crate) fn resolution_markers(
     python_version: Option<&PythonVersion>,
@@ -71,6 +79,18 @@ pub(crate) fn resolution_tags<'env>(
     })
 }
 
+/// Returns `true` if the given [`TargetTriple`] targets a different operating system than the
+/// interpreter (e.g., when staging Windows wheels from Linux), in which case the installed
+/// packages can't be executed on the host.
+pub(crate) fn is_foreign_platform(
+    python_platform: Option<&TargetTriple>,
+    interpreter: &Interpreter,
+) -> bool {
+    python_platform.is_some_and(|python_platform| {
+        python_platform.sys_platform() != interpreter.markers().sys_platform()
+    })
+}
+
 /// Determine the tags, markers, and interpreter to use for resolution.
 pub(crate) fn resolution_environment(
     python_version: Option<PythonVersion>,
@@ -1,8 +1,10 @@
 //! Common operations shared across the `pip` API and subcommands.
 
 use anyhow::{anyhow, Context};
+use futures::{StreamExt, TryStreamExt};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use std::borrow::Cow;
 use std::collections::{BTreeSet, HashSet};
 use std::fmt::Write;
 use std::path::PathBuf;
@@ -11,39 +13,48 @@ use tracing::debug;
 use uv_tool::InstalledTools;
 
 use uv_cache::Cache;
-use uv_client::{BaseClientBuilder, RegistryClient};
+use uv_client::{
+    BaseClientBuilder, HashProvider, HashProviderError, HttpHashProvider, RegistryClient,
+};
 use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, Constraints, DevGroupsSpecification, DryRun,
-    ExtrasSpecification, Overrides, Reinstall, Upgrade,
+    EmbeddedIndexStrategy, ExtrasSpecification, Overrides, Reinstall, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
 use uv_distribution_types::{
-    CachedDist, Diagnostic, InstalledDist, LocalDist, NameRequirementSpecification,
-    ResolutionDiagnostic, UnresolvedRequirement, UnresolvedRequirementSpecification,
+    BuiltDist, Dist, DistributionMetadata, IndexLocations, IndexUrls, InstalledMetadata, Name,
+    Resolution, SourceDist, Verbatim,
 };
 use uv_distribution_types::{
-    DistributionMetadata, IndexLocations, InstalledMetadata, Name, Resolution,
+    CachedDist, Diagnostic, InstalledDist, LocalDist, NameRequirementSpecification,
+    ResolutionDiagnostic, UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_installer::{Plan, Planner, Preparer, SitePackages};
 use uv_normalize::PackageName;
 use uv_platform_tags::Tags;
-use uv_pypi_types::{Conflicts, ResolverMarkerEnvironment};
+use uv_pypi_types::{
+    Conflicts, DirectUrl, Requirement, RequirementSource, ResolverMarkerEnvironment,
+};
 use uv_python::{PythonEnvironment, PythonInstallation};
 use uv_requirements::{
-    LookaheadResolver, NamedRequirementsResolver, RequirementsSource, RequirementsSpecification,
-    SourceTreeResolver,
+    BuildFallback, LookaheadResolver, NamedRequirementsResolver, RequirementsSource,
+    RequirementsSpecification, SourceTreeResolver,
 };
 use uv_resolver::{
     DependencyMode, Exclusions, FlatIndex, InMemoryIndex, Manifest, Options, Preference,
-    Preferences, PythonRequirement, Resolver, ResolverEnvironment, ResolverOutput,
+    Preferences, PythonRequirement, RequiresPython, Resolver, ResolverCheckpoint,
+    ResolverEnvironment, ResolverOutput,
 };
-use uv_types::{HashStrategy, InFlight, InstalledPackagesProvider};
+use uv_types::{BuildContext, HashStrategy, InFlight, InstalledPackagesProvider};
 use uv_warnings::warn_user;
+use version_ranges::Ranges;
 
+use crate::commands::pip::candidate_policy::TestCandidatePolicy;
 use crate::commands::pip::loggers::{DefaultInstallLogger, InstallLogger, ResolveLogger};
+use crate::commands::pip::size_budget::{self, SizeBudget};
 use crate::commands::reporters::{InstallReporter, PrepareReporter, ResolverReporter};
 use crate::commands::{compile_bytecode, ChangeEventKind, DryRunEvent};
 use crate::printer::Printer;
@@ -55,6 +66,7 @@ pub(crate) async fn read_requirements(
     overrides: &[RequirementsSource],
     extras: &ExtrasSpecification,
     groups: &DevGroupsSpecification,
+    embedded_index_strategy: EmbeddedIndexStrategy,
     client_builder: &BaseClientBuilder<'_>,
 ) -> Result<RequirementsSpecification, Error> {
     // If the user requests `extras` but does not provide a valid source (e.g., a `pyproject.toml`),
@@ -88,6 +100,7 @@ pub(crate) async fn read_requirements(
         requirements,
         constraints,
         overrides,
+        embedded_index_strategy,
         client_builder,
     )
     .await?)
@@ -98,11 +111,15 @@ pub(crate) async fn read_constraints(
     constraints: &[RequirementsSource],
     client_builder: &BaseClientBuilder<'_>,
 ) -> Result<Vec<NameRequirementSpecification>, Error> {
-    Ok(
-        RequirementsSpecification::from_sources(&[], constraints, &[], client_builder)
-            .await?
-            .constraints,
+    Ok(RequirementsSpecification::from_sources(
+        &[],
+        constraints,
+        &[],
+        EmbeddedIndexStrategy::default(),
+        client_builder,
     )
+    .await?
+    .constraints)
 }
 
 /// Resolve a set of requirements, similar to running `pip compile`.
@@ -130,6 +147,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     build_dispatch: &BuildDispatch<'_>,
     concurrency: Concurrency,
     options: Options,
+    checkpoint: Option<Arc<ResolverCheckpoint>>,
     logger: Box<dyn ResolveLogger>,
     printer: Printer,
 ) -> Result<ResolverOutput, Error> {
@@ -152,16 +170,16 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
 
         // Resolve any unnamed requirements.
         if !unnamed.is_empty() {
-            requirements.extend(
-                NamedRequirementsResolver::new(
-                    hasher,
-                    index,
-                    DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
-                )
-                .with_reporter(Arc::new(ResolverReporter::from(printer)))
-                .resolve(unnamed.into_iter())
-                .await?,
-            );
+            let (named, fallbacks) = NamedRequirementsResolver::new(
+                hasher,
+                index,
+                DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
+            )
+            .with_reporter(Arc::new(ResolverReporter::from(printer)))
+            .resolve_with_fallbacks(unnamed.into_iter())
+            .await?;
+            requirements.extend(named);
+            report_build_fallbacks(&fallbacks, printer)?;
         }
 
         // Resolve any source trees into requirements.
@@ -236,16 +254,16 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
 
         // Resolve any unnamed overrides.
         if !unnamed.is_empty() {
-            overrides.extend(
-                NamedRequirementsResolver::new(
-                    hasher,
-                    index,
-                    DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
-                )
-                .with_reporter(Arc::new(ResolverReporter::from(printer)))
-                .resolve(unnamed.into_iter())
-                .await?,
-            );
+            let (named, fallbacks) = NamedRequirementsResolver::new(
+                hasher,
+                index,
+                DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
+            )
+            .with_reporter(Arc::new(ResolverReporter::from(printer)))
+            .resolve_with_fallbacks(unnamed.into_iter())
+            .await?;
+            overrides.extend(named);
+            report_build_fallbacks(&fallbacks, printer)?;
         }
 
         overrides
@@ -261,6 +279,29 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     let overrides = Overrides::from_requirements(overrides);
     let preferences = Preferences::from_iter(preferences, &resolver_env);
 
+    // If index lookups are disabled, verify that every direct requirement is available in the
+    // provided package locations upfront, so that we can report all of the missing requirements
+    // at once, rather than failing on the first one during resolution. If only a single
+    // requirement is missing, the resolver's own derivation is at least as informative.
+    if build_dispatch.locations().no_index() {
+        let unavailable = unavailable_requirements(
+            &requirements,
+            &constraints,
+            &overrides,
+            &installed_packages,
+            flat_index,
+            reinstall,
+            &resolver_env,
+        );
+        if unavailable.len() > 1 {
+            return Err(UnavailableRequirementsError {
+                requirements: unavailable,
+                find_links: build_dispatch.locations().flat_indexes().next().is_some(),
+            }
+            .into());
+        }
+    }
+
     // Determine any lookahead requirements.
     let lookaheads = match options.dependency_mode {
         DependencyMode::Transitive => {
@@ -320,6 +361,18 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
         )?
         .with_reporter(Arc::new(reporter));
 
+        let resolver = if let Some(checkpoint) = checkpoint {
+            resolver.with_checkpoint(checkpoint)
+        } else {
+            resolver
+        };
+
+        let resolver = if let Some(policy) = TestCandidatePolicy::from_env() {
+            resolver.with_policy(Arc::new(policy))
+        } else {
+            resolver
+        };
+
         resolver.resolve().await?
     };
 
@@ -395,8 +448,118 @@ impl Changelog {
     }
 }
 
+/// Determine the direct requirements that can't be satisfied by the `--find-links` sources or
+/// the already-installed packages.
+///
+/// Requirements that don't apply to the current environment, and requirements that aren't
+/// fetched from a registry (e.g., URL and path requirements), are ignored.
+fn unavailable_requirements<InstalledPackages: InstalledPackagesProvider>(
+    requirements: &[Requirement],
+    constraints: &Constraints,
+    overrides: &Overrides,
+    installed_packages: &InstalledPackages,
+    flat_index: &FlatIndex,
+    reinstall: &Reinstall,
+    resolver_env: &ResolverEnvironment,
+) -> Vec<Requirement> {
+    overrides
+        .apply(requirements)
+        .filter(|requirement| requirement.evaluate_markers(resolver_env.marker_environment(), &[]))
+        .filter(|requirement| {
+            let RequirementSource::Registry {
+                specifier,
+                index: None,
+                ..
+            } = &requirement.source
+            else {
+                return false;
+            };
+
+            // Intersect the requirement with any constraints on the same package.
+            let mut range = Ranges::from(specifier.clone());
+            for constraint in constraints.get(&requirement.name).into_iter().flatten() {
+                if let RequirementSource::Registry { specifier, .. } = &constraint.source {
+                    range = range.intersection(&Ranges::from(specifier.clone()));
+                }
+            }
+
+            let installed = !reinstall.contains(&requirement.name)
+                && installed_packages
+                    .get_packages(&requirement.name)
+                    .iter()
+                    .any(|dist| range.contains(dist.version()));
+            let found = flat_index
+                .get(&requirement.name)
+                .is_some_and(|dists| dists.iter().any(|(version, _)| range.contains(version)));
+
+            !installed && !found
+        })
+        .map(Cow::into_owned)
+        .collect()
+}
+
+/// Determine the installed packages to reinstall based on where they were installed from, as
+/// recorded in their `direct_url.json`.
+///
+/// With `vcs`, selects packages installed from a VCS repository. With `local`, selects packages
+/// installed from a local directory or archive, including editables.
+pub(crate) fn reinstall_by_provenance(
+    site_packages: &SitePackages,
+    vcs: bool,
+    local: bool,
+) -> Reinstall {
+    if !vcs && !local {
+        return Reinstall::None;
+    }
+
+    let packages = site_packages
+        .iter()
+        .filter(|dist| match dist {
+            InstalledDist::Url(dist) => match dist.direct_url.as_ref() {
+                DirectUrl::VcsUrl { .. } => vcs,
+                DirectUrl::LocalDirectory { .. } => local,
+                DirectUrl::ArchiveUrl { .. } => local && dist.url.scheme() == "file",
+            },
+            InstalledDist::LegacyEditable(_) => local,
+            InstalledDist::Registry(_)
+            | InstalledDist::EggInfoFile(_)
+            | InstalledDist::EggInfoDirectory(_) => false,
+        })
+        .map(|dist| dist.name().clone())
+        .unique()
+        .collect::<Vec<_>>();
+
+    if packages.is_empty() {
+        Reinstall::None
+    } else {
+        debug!(
+            "Reinstalling by provenance: {}",
+            packages.iter().map(ToString::to_string).join(", ")
+        );
+        Reinstall::Packages(packages)
+    }
+}
+
+/// Returns `true` if the distribution can be read from the local filesystem, e.g., a path
+/// requirement or a wheel provided by a local `--find-links` directory.
+fn is_local(dist: &Dist) -> bool {
+    match dist {
+        Dist::Built(BuiltDist::Path(_))
+        | Dist::Source(SourceDist::Path(_) | SourceDist::Directory(_)) => true,
+        Dist::Built(BuiltDist::DirectUrl(_))
+        | Dist::Source(SourceDist::DirectUrl(_) | SourceDist::Git(_)) => false,
+        Dist::Built(BuiltDist::Registry(_)) | Dist::Source(SourceDist::Registry(_)) => dist
+            .file()
+            .and_then(|file| file.url.to_url().ok())
+            .is_some_and(|url| url.scheme() == "file"),
+    }
+}
+
 /// Install a set of requirements into the current environment.
 ///
+/// If `entry_points` is `false`, no scripts are generated for the entry points of the installed
+/// packages (e.g., when staging packages for another platform).
+///
 /// Returns a [`Changelog`] summarizing the changes made to the environment.
 pub(crate) async fn install(
     resolution: &Resolution,
@@ -406,6 +569,7 @@ pub(crate) async fn install(
     build_options: &BuildOptions,
     link_mode: LinkMode,
     compile: bool,
+    entry_points: bool,
     index_urls: &IndexLocations,
     config_settings: &ConfigSettings,
     hasher: &HashStrategy,
@@ -413,11 +577,13 @@ pub(crate) async fn install(
     client: &RegistryClient,
     in_flight: &InFlight,
     concurrency: Concurrency,
+    size_budget: SizeBudget,
     build_dispatch: &BuildDispatch<'_>,
     cache: &Cache,
     venv: &PythonEnvironment,
     logger: Box<dyn InstallLogger>,
     installer_metadata: bool,
+    check_abi: bool,
     dry_run: DryRun,
     printer: Printer,
 ) -> Result<Changelog, Error> {
@@ -433,12 +599,33 @@ pub(crate) async fn install(
             hasher,
             index_urls,
             config_settings,
+            build_dispatch.config_settings_package(),
+            build_dispatch.build_env(),
+            build_dispatch.wheel_optimizations(),
             cache,
             venv,
             tags,
         )
         .context("Failed to determine installation plan")?;
 
+    // If index lookups and network access are both disabled, verify that every distribution in the
+    // resolution is either cached or readable from a local package location before fetching any,
+    // so that we can report all of the missing distributions at once.
+    if index_urls.no_index() && client.connectivity().is_offline() {
+        let unavailable = plan
+            .remote
+            .iter()
+            .filter(|dist| !is_local(dist))
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        if !unavailable.is_empty() {
+            return Err(UnavailableDistributionsError { dists: unavailable }.into());
+        }
+    }
+
+    // Enforce the download size budget before downloading anything.
+    size_budget::enforce(&plan.remote, size_budget, client, concurrency).await?;
+
     if dry_run.enabled() {
         report_dry_run(resolution, plan, modifications, start, printer)?;
         return Ok(Changelog::default());
@@ -494,6 +681,19 @@ pub(crate) async fn install(
         wheels
     };
 
+    // If requested, verify that the wheels are compatible with the target before modifying the
+    // environment.
+    if check_abi {
+        let incompatibilities =
+            uv_installer::check_abi(wheels.iter().chain(&cached), tags, venv.interpreter()).await?;
+        if !incompatibilities.is_empty() {
+            return Err(AbiIncompatibleError {
+                messages: incompatibilities.iter().map(ToString::to_string).collect(),
+            }
+            .into());
+        }
+    }
+
     // Remove any upgraded or extraneous installations.
     let uninstalls = extraneous.into_iter().chain(reinstalls).collect::<Vec<_>>();
     if !uninstalls.is_empty() {
@@ -510,6 +710,7 @@ pub(crate) async fn install(
                         summary.dir_count,
                         if summary.dir_count == 1 { "y" } else { "ies" },
                     );
+                    logger.on_uninstalled(dist_info);
                 }
                 Err(uv_installer::UninstallError::Uninstall(
                     uv_install_wheel::Error::MissingRecord(_),
@@ -542,7 +743,8 @@ pub(crate) async fn install(
             .with_link_mode(link_mode)
             .with_cache(cache)
             .with_installer_metadata(installer_metadata)
-            .with_reporter(Arc::new(
+            .with_entry_points(entry_points)
+            .with_reporter(logger.install_reporter(
                 InstallReporter::from(printer).with_length(installs.len() as u64),
             ))
             // This technically can block the runtime, but we are on the main thread and
@@ -665,6 +867,49 @@ pub(crate) fn report_target_environment(
     Ok(writeln!(printer.stderr(), "{}", message.dimmed())?)
 }
 
+/// Report any unnamed requirements that had to be built (or whose previously-built metadata had to
+/// be read from the cache) to determine their package names, in verbose mode.
+fn report_build_fallbacks(fallbacks: &[BuildFallback], printer: Printer) -> Result<(), Error> {
+    if fallbacks.is_empty() || printer != Printer::Verbose {
+        return Ok(());
+    }
+
+    let (cached, built): (Vec<_>, Vec<_>) = fallbacks.iter().partition(|fallback| fallback.cached);
+
+    for (fallbacks, verb) in [(built, "Built"), (cached, "Read cached build metadata for")] {
+        if fallbacks.is_empty() {
+            continue;
+        }
+        let s = if fallbacks.len() == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{verb} {} requirement{s} to determine {} package name{s}, as static metadata was unavailable:",
+            fallbacks.len(),
+            if fallbacks.len() == 1 { "its" } else { "their" },
+        )?;
+        for fallback in fallbacks {
+            writeln!(
+                printer.stderr(),
+                " {} {} ({}): {}",
+                "-".dimmed(),
+                fallback.name.bold(),
+                fallback.url.verbatim(),
+                fallback.reason
+            )?;
+        }
+    }
+    writeln!(
+        printer.stderr(),
+        "{}{} Add static metadata to these projects, or provide their names explicitly (e.g., `{} @ {}`), to avoid building them",
+        "hint".bold().cyan(),
+        ":".bold(),
+        fallbacks[0].name,
+        fallbacks[0].url.verbatim(),
+    )?;
+
+    Ok(())
+}
+
 /// Report on the results of a dry-run installation.
 fn report_dry_run(
     resolution: &Resolution,
@@ -810,6 +1055,204 @@ pub(crate) fn diagnose_resolution(
     Ok(())
 }
 
+/// Replace the hashes of any distributions resolved from an index with a configured hash provider,
+/// rather than trusting the digests reported by the index.
+pub(crate) async fn attest_hashes(
+    resolution: &mut ResolverOutput,
+    index_urls: &IndexUrls,
+    client: &RegistryClient,
+    concurrency: Concurrency,
+) -> Result<(), Error> {
+    let hashes = futures::stream::iter(resolution.registry_pins().into_iter().filter_map(
+        |(name, version, index)| {
+            let provider = HttpHashProvider::new(client, index_urls.hash_provider(index)?);
+            Some(async move {
+                let hashes = provider.hashes(name, version).await?;
+                Ok::<_, HashProviderError>((name.clone(), version.clone(), hashes))
+            })
+        },
+    ))
+    .buffer_unordered(concurrency.downloads)
+    .try_collect::<Vec<_>>()
+    .await?;
+
+    for (name, version, hashes) in hashes {
+        debug!("Using hashes from the hash provider for `{name}=={version}`");
+        resolution.set_hashes(&name, &version, &hashes);
+    }
+    Ok(())
+}
+
+/// Warn if the range of Python versions supported by the pinned dependencies is narrower than the
+/// `requires-python` declared by the project.
+///
+/// As in [`RequiresPython::is_contained_by`], only the lower bounds are compared, since packages
+/// can't know whether they're compatible with future Python versions.
+pub(crate) fn diagnose_requires_python(
+    declared: Option<&RequiresPython>,
+    supported: Option<&RequiresPython>,
+    printer: Printer,
+) -> Result<(), Error> {
+    let (Some(declared), Some(supported)) = (declared, supported) else {
+        return Ok(());
+    };
+    if declared.is_contained_by(supported.specifiers()) {
+        return Ok(());
+    }
+    writeln!(
+        printer.stderr(),
+        "{}{} {}",
+        "warning".yellow().bold(),
+        ":".bold(),
+        format!(
+            "The pinned dependencies require Python `{supported}`, which is narrower than the project's `requires-python` (`{declared}`)"
+        )
+        .bold()
+    )?;
+    Ok(())
+}
+
+/// Fail if any requirement requested an extra that isn't provided by the resolved distribution.
+///
+/// By default, missing extras are reported as warnings by [`diagnose_resolution`]; in strict mode,
+/// they're treated as errors, and reported before any changes are made to the environment.
+pub(crate) fn validate_extras(diagnostics: &[ResolutionDiagnostic]) -> Result<(), Error> {
+    let missing = diagnostics
+        .iter()
+        .filter(|diagnostic| matches!(diagnostic, ResolutionDiagnostic::MissingExtra { .. }))
+        .map(Diagnostic::message)
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(MissingExtrasError { messages: missing }.into())
+    }
+}
+
+/// Validate that the extras requested by the given requirements are provided by the installed
+/// distributions, for when the environment already satisfies the requirements and no resolution
+/// is performed.
+pub(crate) fn validate_installed_extras<'a>(
+    requirements: impl IntoIterator<Item = &'a UnresolvedRequirementSpecification>,
+    site_packages: &SitePackages,
+) -> Result<(), Error> {
+    let mut missing = Vec::new();
+    for entry in requirements {
+        if entry.requirement.extras().is_empty() {
+            continue;
+        }
+        let installed = match &entry.requirement {
+            UnresolvedRequirement::Named(requirement) => {
+                site_packages.get_packages(&requirement.name)
+            }
+            UnresolvedRequirement::Unnamed(requirement) => {
+                site_packages.get_urls(requirement.url.verbatim.raw())
+            }
+        };
+        for dist in installed {
+            let Ok(metadata) = dist.metadata() else {
+                continue;
+            };
+            for extra in entry.requirement.extras() {
+                if !metadata.provides_extras.contains(extra) {
+                    missing.push(format!(
+                        "The package `{dist}` does not have an extra named `{extra}`"
+                    ));
+                }
+            }
+        }
+    }
+    missing.sort();
+    missing.dedup();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(MissingExtrasError { messages: missing }.into())
+    }
+}
+
+/// Shuffle the given items into a pseudo-random order determined by the `seed`, e.g., to verify
+/// that a resolution is independent of the order of its inputs.
+pub(crate) fn shuffle<T>(items: impl IntoIterator<Item = T>, seed: u64) -> Vec<T> {
+    use std::hash::{BuildHasher, BuildHasherDefault, DefaultHasher};
+
+    // `DefaultHasher::new` uses fixed keys, so the order is stable for a given seed.
+    let state = BuildHasherDefault::<DefaultHasher>::default();
+    let mut items = items
+        .into_iter()
+        .enumerate()
+        .map(|(index, item)| (state.hash_one((seed, index)), item))
+        .collect::<Vec<_>>();
+    items.sort_unstable_by_key(|(key, _)| *key);
+    items.into_iter().map(|(_, item)| item).collect()
+}
+
+/// Generate a random seed with which to [`shuffle`] the inputs to a resolution.
+pub(crate) fn random_seed() -> u64 {
+    use std::hash::BuildHasher;
+
+    std::hash::RandomState::new().hash_one(0u64)
+}
+
+/// Compare the output of two resolutions of the same inputs, reporting any lines that differ,
+/// along with the `seed` with which the inputs to the second resolution were shuffled.
+///
+/// Returns `true` if the outputs are identical.
+pub(crate) fn verify_determinism(
+    first: &str,
+    second: &str,
+    seed: u64,
+    printer: Printer,
+) -> Result<bool, Error> {
+    if first == second {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "Verified that the resolution is deterministic".dimmed()
+        )?;
+        return Ok(true);
+    }
+
+    let first_lines = first.lines().collect::<HashSet<_>>();
+    let second_lines = second.lines().collect::<HashSet<_>>();
+
+    writeln!(
+        printer.stderr(),
+        "{}{} {}",
+        "error".red().bold(),
+        ":".bold(),
+        "Resolution is not deterministic; the following lines differ between runs:".bold()
+    )?;
+    for line in first.lines().filter(|line| !second_lines.contains(line)) {
+        writeln!(printer.stderr(), "{}", format!("- {line}").red())?;
+    }
+    for line in second.lines().filter(|line| !first_lines.contains(line)) {
+        writeln!(printer.stderr(), "{}", format!("+ {line}").green())?;
+    }
+    if first_lines == second_lines {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "  (the same lines were emitted in a different order)".dimmed()
+        )?;
+    }
+    writeln!(
+        printer.stderr(),
+        "\n{}{} Divergence is usually caused by iteration over an unordered collection, or by the order in which packages are merged across indexes",
+        "hint".bold().cyan(),
+        ":".bold()
+    )?;
+    writeln!(
+        printer.stderr(),
+        "\n{}{} The inputs were shuffled with seed {}; pass `{}` to reproduce the same order",
+        "hint".bold().cyan(),
+        ":".bold(),
+        seed.cyan(),
+        format!("--seed {seed}").green()
+    )?;
+    Ok(false)
+}
+
 /// Report any diagnostics on installed distributions in the Python environment.
 pub(crate) fn diagnose_environment(
     resolution: &Resolution,
@@ -836,6 +1279,32 @@ pub(crate) fn diagnose_environment(
     Ok(())
 }
 
+/// Report any installed distributions in the Python environment that are shadowed by other
+/// distributions on `sys.path`.
+pub(crate) fn diagnose_shadowing(
+    resolution: &Resolution,
+    venv: &PythonEnvironment,
+    printer: Printer,
+) -> Result<(), Error> {
+    let site_packages = SitePackages::from_environment(venv)?;
+    for diagnostic in site_packages.shadowing_diagnostics() {
+        // Only surface diagnostics that are "relevant" to the current resolution.
+        if resolution
+            .distributions()
+            .any(|dist| diagnostic.includes(dist.name()))
+        {
+            writeln!(
+                printer.stderr(),
+                "{}{} {}",
+                "warning".yellow().bold(),
+                ":".bold(),
+                diagnostic.message().bold()
+            )?;
+        }
+    }
+    Ok(())
+}
+
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum Error {
     #[error("Failed to prepare distributions")]
@@ -859,6 +1328,130 @@ pub(crate) enum Error {
     #[error(transparent)]
     Requirements(#[from] uv_requirements::Error),
 
+    #[error(transparent)]
+    Unavailable(#[from] UnavailableRequirementsError),
+
+    #[error(transparent)]
+    UnavailableDistributions(#[from] UnavailableDistributionsError),
+
+    #[error(transparent)]
+    MissingExtras(#[from] MissingExtrasError),
+
+    #[error(transparent)]
+    Abi(#[from] uv_installer::AbiError),
+
+    #[error(transparent)]
+    AbiIncompatible(#[from] AbiIncompatibleError),
+
+    #[error(transparent)]
+    HashProvider(#[from] HashProviderError),
+
     #[error(transparent)]
     Anyhow(#[from] anyhow::Error),
 }
+
+/// An error for wheels that are incompatible with the target interpreter, as determined by
+/// inspecting their contents.
+#[derive(Debug, thiserror::Error)]
+pub(crate) struct AbiIncompatibleError {
+    messages: Vec<String>,
+}
+
+impl std::fmt::Display for AbiIncompatibleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Some wheels are incompatible with the target interpreter:"
+        )?;
+        for message in &self.messages {
+            write!(f, "\n  - {message}")?;
+        }
+        write!(
+            f,
+            "\n\n{}{} Wheels are inspected because `{}` was provided",
+            "hint".bold().cyan(),
+            ":".bold(),
+            "--check-abi".green()
+        )
+    }
+}
+
+/// An error for requirements that requested extras which aren't provided by the resolved
+/// distributions, in strict mode.
+#[derive(Debug, thiserror::Error)]
+pub(crate) struct MissingExtrasError {
+    messages: Vec<String>,
+}
+
+impl std::fmt::Display for MissingExtrasError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Requested extras are not provided by the resolved distributions:"
+        )?;
+        for message in &self.messages {
+            write!(f, "\n  - {message}")?;
+        }
+        write!(
+            f,
+            "\n\n{}{} Extras are validated because `{}` was provided",
+            "hint".bold().cyan(),
+            ":".bold(),
+            "--strict".green()
+        )
+    }
+}
+
+/// An error for direct requirements that aren't available in the provided package locations when
+/// index lookups are disabled.
+#[derive(Debug, thiserror::Error)]
+pub(crate) struct UnavailableRequirementsError {
+    requirements: Vec<Requirement>,
+    find_links: bool,
+}
+
+impl UnavailableRequirementsError {
+    /// Returns `true` if any `--find-links` locations were provided.
+    pub(crate) fn find_links(&self) -> bool {
+        self.find_links
+    }
+}
+
+impl std::fmt::Display for UnavailableRequirementsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The following requirements are not available in the provided package locations:"
+        )?;
+        for requirement in &self.requirements {
+            write!(f, "\n  - {}", requirement.cyan())?;
+        }
+        Ok(())
+    }
+}
+
+/// An error for resolved distributions that are neither cached nor available from a local package
+/// location when index lookups and network access are disabled.
+#[derive(Debug, thiserror::Error)]
+pub(crate) struct UnavailableDistributionsError {
+    dists: Vec<String>,
+}
+
+impl std::fmt::Display for UnavailableDistributionsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The following distributions are not cached and can't be fetched while offline:"
+        )?;
+        for dist in &self.dists {
+            write!(f, "\n  - {}", dist.cyan())?;
+        }
+        write!(
+            f,
+            "\n\n{}{} Provide the distributions via a local `{}` directory, or populate the cache while online",
+            "hint".bold().cyan(),
+            ":".bold(),
+            "--find-links".green(),
+        )
+    }
+}
@@ -0,0 +1,289 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use tracing::debug;
+
+use uv_cache::{Cache, Refresh};
+use uv_client::Connectivity;
+use uv_configuration::{
+    BuildOptions, Concurrency, ConfigSettings, DevGroupsSpecification, DryRun,
+    EmbeddedIndexStrategy, ExtrasSpecification, IndexStrategy, IndexUnavailablePolicy,
+    KeyringProviderType, PackageBuildEnv, PackageConfigSettings, PreviewMode, Reinstall,
+    SourceStrategy, TrustedHost, Upgrade,
+};
+use uv_distribution_types::{DependencyMetadata, IndexLocations, Name};
+use uv_fs::{Simplified, CWD};
+use uv_install_wheel::LinkMode;
+use uv_installer::SitePackages;
+use uv_pep508::PackageName;
+use uv_python::{
+    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest,
+};
+use uv_requirements::RequirementsSource;
+use uv_resolver::{
+    DependencyMode, ExcludeNewer, ExcludeNewerPackage, InvalidMetadataPolicy, PrereleaseMode,
+    ResolutionMode, ResolutionPackage, YankedPolicy,
+};
+use uv_settings::PythonInstallMirrors;
+use uv_warnings::warn_user;
+
+use crate::commands::pip::install::pip_install;
+use crate::commands::pip::operations::{report_target_environment, Modifications};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Rebuild the editable packages in the current Python environment, refreshing their metadata
+/// and entry points in place.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn pip_refresh_editables(
+    packages: &[PackageName],
+    link_mode: LinkMode,
+    compile: bool,
+    index_locations: IndexLocations,
+    index_strategy: IndexStrategy,
+    dependency_metadata: DependencyMetadata,
+    keyring_provider: KeyringProviderType,
+    installer_metadata: bool,
+    connectivity: Connectivity,
+    config_settings: &ConfigSettings,
+    config_settings_package: &PackageConfigSettings,
+    build_env: &PackageBuildEnv,
+    no_build_isolation: bool,
+    no_build_isolation_package: Vec<PackageName>,
+    build_options: BuildOptions,
+    exclude_newer: Option<ExcludeNewer>,
+    sources: SourceStrategy,
+    python: Option<String>,
+    environment: Option<&Path>,
+    system: bool,
+    break_system_packages: bool,
+    python_preference: PythonPreference,
+    concurrency: Concurrency,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    cache: Cache,
+    dry_run: DryRun,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    // Detect the current Python interpreter. The environment is reported (and validated) once
+    // the packages are reinstalled.
+    let environment = if let Some(environment) = environment {
+        PythonEnvironment::from_root(environment, &cache)?
+    } else {
+        PythonEnvironment::find(
+            &python
+                .as_deref()
+                .map(PythonRequest::parse)
+                .unwrap_or_default(),
+            EnvironmentPreference::from_system_flag(system, true),
+            &cache,
+        )?
+    };
+
+    // Determine the set of installed packages.
+    let site_packages = SitePackages::from_environment(&environment)?;
+
+    // Collect the editable packages to refresh, along with their source trees.
+    let mut names = Vec::new();
+    let mut editables = Vec::new();
+    for dist in site_packages.iter() {
+        if !packages.is_empty() && !packages.contains(dist.name()) {
+            continue;
+        }
+        let Some(url) = dist.as_editable() else {
+            continue;
+        };
+        let Ok(path) = url.to_file_path() else {
+            warn_user!(
+                "Skipping editable package `{}` with a non-local source: {url}",
+                dist.name()
+            );
+            continue;
+        };
+        debug!(
+            "Refreshing editable package `{}` from: {}",
+            dist.name(),
+            path.user_display()
+        );
+        names.push(dist.name().clone());
+        editables.push(RequirementsSource::Editable(
+            path.simplified_display().to_string(),
+        ));
+    }
+
+    for package in packages {
+        if !names.contains(package) {
+            warn_user!("`{package}` is not installed as an editable package");
+        }
+    }
+
+    if editables.is_empty() {
+        report_target_environment(&environment, &cache, printer)?;
+        writeln!(printer.stderr(), "No editable packages to refresh")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // Rebuild and reinstall each of the editable packages, regardless of whether its build is
+    // cached.
+    let refresh = cache
+        .refresh()
+        .clone()
+        .combine(Refresh::from(Reinstall::Packages(names.clone())));
+    let cache = cache.with_refresh(refresh);
+
+    // Any dependencies that the packages have gained since they were installed are installed too.
+    reinstall(
+        &editables,
+        names,
+        DependencyMode::Transitive,
+        &environment,
+        link_mode,
+        compile,
+        index_locations,
+        index_strategy,
+        dependency_metadata,
+        keyring_provider,
+        installer_metadata,
+        connectivity,
+        config_settings,
+        config_settings_package,
+        build_env,
+        no_build_isolation,
+        no_build_isolation_package,
+        build_options,
+        exclude_newer,
+        sources,
+        break_system_packages,
+        python_preference,
+        concurrency,
+        native_tls,
+        allow_insecure_host,
+        cache,
+        dry_run,
+        printer,
+        preview,
+    )
+    .await
+}
+
+/// Reinstall the given packages into the environment from the given requirements, regardless of
+/// whether they're already installed, as in `uv pip install --reinstall-package`.
+///
+/// With [`DependencyMode::Direct`], the dependencies of the packages are left as-is.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(super) async fn reinstall(
+    requirements: &[RequirementsSource],
+    packages: Vec<PackageName>,
+    dependency_mode: DependencyMode,
+    environment: &PythonEnvironment,
+    link_mode: LinkMode,
+    compile: bool,
+    index_locations: IndexLocations,
+    index_strategy: IndexStrategy,
+    dependency_metadata: DependencyMetadata,
+    keyring_provider: KeyringProviderType,
+    installer_metadata: bool,
+    connectivity: Connectivity,
+    config_settings: &ConfigSettings,
+    config_settings_package: &PackageConfigSettings,
+    build_env: &PackageBuildEnv,
+    no_build_isolation: bool,
+    no_build_isolation_package: Vec<PackageName>,
+    build_options: BuildOptions,
+    exclude_newer: Option<ExcludeNewer>,
+    sources: SourceStrategy,
+    break_system_packages: bool,
+    python_preference: PythonPreference,
+    concurrency: Concurrency,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    cache: Cache,
+    dry_run: DryRun,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    pip_install(
+        requirements,
+        &[],
+        &[],
+        &[],
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        &ExtrasSpecification::default(),
+        &DevGroupsSpecification::default(),
+        ResolutionMode::default(),
+        ResolutionPackage::default(),
+        PrereleaseMode::default(),
+        Vec::new(),
+        dependency_mode,
+        Upgrade::default(),
+        index_locations,
+        index_strategy,
+        EmbeddedIndexStrategy::default(),
+        InvalidMetadataPolicy::default(),
+        YankedPolicy::default(),
+        IndexUnavailablePolicy::default(),
+        Vec::new(),
+        Vec::new(),
+        None,
+        None,
+        Vec::new(),
+        Vec::new(),
+        false,
+        None,
+        None,
+        dependency_metadata,
+        keyring_provider,
+        Reinstall::Packages(packages),
+        false,
+        false,
+        link_mode,
+        compile,
+        None,
+        installer_metadata,
+        connectivity,
+        config_settings,
+        config_settings_package,
+        build_env,
+        no_build_isolation,
+        no_build_isolation_package,
+        build_options,
+        Modifications::Sufficient,
+        None,
+        None,
+        Vec::new(),
+        false,
+        false,
+        exclude_newer,
+        ExcludeNewerPackage::default(),
+        sources,
+        None,
+        Some(environment.root()),
+        false,
+        break_system_packages,
+        None,
+        None,
+        false,
+        false,
+        &PythonInstallMirrors::default(),
+        python_preference,
+        PythonDownloads::Never,
+        false,
+        concurrency,
+        native_tls,
+        allow_insecure_host,
+        &CWD,
+        cache,
+        dry_run,
+        None,
+        Vec::new(),
+        false,
+        false,
+        printer,
+        preview,
+    )
+    .await
+}
@@ -0,0 +1,199 @@
+//! Enforcement of download size budgets (`--max-total-download-size` and `--max-package-size`).
+
+use std::fmt::Write;
+use std::sync::Arc;
+
+use anyhow::{bail, Result};
+use futures::{stream, StreamExt};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_client::RegistryClient;
+use uv_configuration::{ByteSize, Concurrency};
+use uv_distribution_types::{
+    BuiltDist, Dist, DistributionMetadata, Name, RemoteSource, SourceDist,
+};
+use uv_warnings::warn_user;
+
+use crate::commands::human_readable_bytes;
+
+/// The number of distributions to list when the total download size is exceeded.
+const LARGEST_OFFENDERS: usize = 5;
+
+/// A download size budget, as configured by `--max-total-download-size` and `--max-package-size`.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct SizeBudget {
+    /// The maximum combined size of the distributions to download.
+    pub(crate) max_total_download_size: Option<ByteSize>,
+    /// The maximum size of any individual distribution to download.
+    pub(crate) max_package_size: Option<ByteSize>,
+}
+
+impl SizeBudget {
+    /// Returns `true` if neither limit is set.
+    fn is_unbounded(self) -> bool {
+        self.max_total_download_size.is_none() && self.max_package_size.is_none()
+    }
+}
+
+/// Enforce the download size budget for the distributions that need to be downloaded, before any
+/// of them are.
+///
+/// Sizes are taken from the index (per PEP 700) where available, falling back to a `HEAD` request
+/// for remote files and to the file metadata for local files. Callers should only pass the
+/// distributions that are neither installed nor cached (i.e., [`Plan::remote`]), as nothing else
+/// will be downloaded.
+///
+/// [`Plan::remote`]: uv_installer::Plan::remote
+pub(crate) async fn enforce(
+    dists: &[Arc<Dist>],
+    budget: SizeBudget,
+    client: &RegistryClient,
+    concurrency: Concurrency,
+) -> Result<()> {
+    if budget.is_unbounded() || dists.is_empty() {
+        return Ok(());
+    }
+    let SizeBudget {
+        max_total_download_size,
+        max_package_size,
+    } = budget;
+
+    let sizes = stream::iter(dists.iter().map(AsRef::as_ref))
+        .map(|dist| async move { (dist, dist_size(dist, client).await) })
+        .buffer_unordered(concurrency.downloads)
+        .collect::<Vec<_>>()
+        .await;
+
+    // Warn about any distributions for which the size couldn't be determined, since they'll be
+    // excluded from the budget.
+    let unknown = sizes
+        .iter()
+        .filter(|(_, size)| size.is_none())
+        .map(|(dist, _)| dist.to_string())
+        .collect::<Vec<_>>();
+    if !unknown.is_empty() {
+        warn_user!(
+            "Unable to determine the download size of {} distribution{}, which will not be counted against the size budget: {}",
+            unknown.len(),
+            if unknown.len() == 1 { "" } else { "s" },
+            unknown.join(", ")
+        );
+    }
+
+    let mut sizes = sizes
+        .into_iter()
+        .filter_map(|(dist, size)| Some((dist, size?)))
+        .collect::<Vec<_>>();
+    sizes.sort_by(|(a, a_size), (b, b_size)| {
+        b_size
+            .cmp(a_size)
+            .then_with(|| a.name().cmp(b.name()))
+            .then_with(|| {
+                a.version_or_url()
+                    .to_string()
+                    .cmp(&b.version_or_url().to_string())
+            })
+    });
+
+    if let Some(max_package_size) = max_package_size {
+        let offenders = sizes
+            .iter()
+            .filter(|(_, size)| *size > max_package_size.as_bytes())
+            .collect::<Vec<_>>();
+        if !offenders.is_empty() {
+            let mut message = format!(
+                "{} distribution{} exceed{} the maximum package size ({}):",
+                offenders.len(),
+                if offenders.len() == 1 { "" } else { "s" },
+                if offenders.len() == 1 { "s" } else { "" },
+                max_package_size.cyan()
+            );
+            for (dist, size) in offenders {
+                write_offender(&mut message, dist, *size)?;
+            }
+            bail!(message);
+        }
+    }
+
+    if let Some(max_total_download_size) = max_total_download_size {
+        let total = sizes.iter().map(|(_, size)| *size).sum::<u64>();
+        if total > max_total_download_size.as_bytes() {
+            let (quantity, unit) = human_readable_bytes(total);
+            let mut message = format!(
+                "The total download size ({}) exceeds the maximum total download size ({}). The largest distributions are:",
+                format!("{quantity:.1}{unit}").cyan(),
+                max_total_download_size.cyan()
+            );
+            for (dist, size) in sizes.iter().take(LARGEST_OFFENDERS) {
+                write_offender(&mut message, dist, *size)?;
+            }
+            bail!(message);
+        }
+        debug!("Total download size is within budget: {total} bytes");
+    }
+
+    Ok(())
+}
+
+/// Write a distribution and its size to an error message.
+fn write_offender(message: &mut String, dist: &Dist, size: u64) -> std::fmt::Result {
+    let (quantity, unit) = human_readable_bytes(size);
+    write!(
+        message,
+        "\n  {} ({})",
+        dist.bold(),
+        format!("{quantity:.1}{unit}").cyan()
+    )
+}
+
+/// Determine the download size of a distribution, if possible.
+async fn dist_size(dist: &Dist, client: &RegistryClient) -> Option<u64> {
+    if let Some(size) = dist.size() {
+        return Some(size);
+    }
+
+    let url = match dist {
+        Dist::Built(BuiltDist::Registry(wheels)) => wheels.best_wheel().file.url.to_url().ok()?,
+        Dist::Built(BuiltDist::DirectUrl(wheel)) => (*wheel.location).clone(),
+        Dist::Built(BuiltDist::Path(wheel)) => {
+            return fs_err::metadata(&wheel.install_path)
+                .ok()
+                .map(|metadata| metadata.len());
+        }
+        Dist::Source(SourceDist::Registry(sdist)) => sdist.file.url.to_url().ok()?,
+        Dist::Source(SourceDist::DirectUrl(sdist)) => (*sdist.location).clone(),
+        Dist::Source(SourceDist::Path(sdist)) => {
+            return fs_err::metadata(&sdist.install_path)
+                .ok()
+                .map(|metadata| metadata.len());
+        }
+        Dist::Source(SourceDist::Git(_) | SourceDist::Directory(_)) => return None,
+    };
+
+    // Files in a local registry or `--find-links` directory can be measured directly.
+    if url.scheme() == "file" {
+        let path = url.to_file_path().ok()?;
+        return fs_err::metadata(path).ok().map(|metadata| metadata.len());
+    }
+
+    let response = match client
+        .uncached_client(&url)
+        .head(url.clone())
+        .header("accept-encoding", "identity")
+        .send()
+        .await
+        .and_then(|response| response.error_for_status().map_err(Into::into))
+    {
+        Ok(response) => response,
+        Err(err) => {
+            debug!("Failed to determine the size of `{dist}` via `HEAD` request: {err}");
+            return None;
+        }
+    };
+    response
+        .headers()
+        .get(reqwest::header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok())
+}
@@ -1,41 +1,52 @@
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use owo_colors::OwoColorize;
 use tracing::debug;
 
-use uv_cache::Cache;
+use uv_cache::{Cache, Refresh};
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, Constraints, DevGroupsSpecification, DryRun,
-    ExtrasSpecification, HashCheckingMode, IndexStrategy, PreviewMode, Reinstall, SourceStrategy,
-    TrustedHost, Upgrade,
+    BuildOptions, ByteSize, Concurrency, ConfigSettings, Constraints, DevGroupsSpecification,
+    DryRun, EmbeddedIndexStrategy, ExtrasSpecification, HashCheckingMode, IndexStrategy,
+    IndexUnavailablePolicy, PackageBuildEnv, PackageConfigSettings, PreviewMode, Reinstall,
+    SourceStrategy, TrustedHost, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
-use uv_distribution_types::{DependencyMetadata, Index, IndexLocations, Origin, Resolution};
+use uv_distribution_types::{
+    DependencyMetadata, HashGeneration, Index, IndexLocations, Name, NameRequirementSpecification,
+    Origin, Resolution, UnresolvedRequirement, UnresolvedRequirementSpecification,
+};
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_installer::SitePackages;
 use uv_pep508::PackageName;
 use uv_pypi_types::Conflicts;
 use uv_python::{
-    EnvironmentPreference, Prefix, PythonEnvironment, PythonInstallation, PythonPreference,
-    PythonRequest, PythonVersion, Target,
+    ContainerPython, EnvironmentPreference, Prefix, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest, PythonVersion, Target,
 };
-use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_requirements::{find_known_hashes, RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
-    DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PythonRequirement,
-    ResolutionMode, ResolverEnvironment,
+    DependencyMode, ExcludeNewer, ExcludeNewerPackage, FlatIndex, InvalidMetadataPolicy,
+    LocalVersionPreference, OptionsBuilder, PackageProvider, PackageSourcePolicy, PrereleaseMode,
+    PythonRequirement, ResolutionMode, ResolverEnvironment, WheelPreference, YankedPolicy,
 };
-use uv_types::{BuildIsolation, HashStrategy};
+use uv_types::{BuildIsolation, HashStrategy, KnownHashes};
+use uv_warnings::warn_user;
 
+use crate::commands::pip::audit_log::AuditLog;
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
-use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::pip::signature::{self, SignatureHistory, VerifyingKey};
+use crate::commands::pip::size_budget::SizeBudget;
+use crate::commands::pip::{is_foreign_platform, operations, resolution_markers, resolution_tags};
 use crate::commands::{diagnostics, ExitStatus};
 use crate::printer::Printer;
 
@@ -45,40 +56,78 @@ pub(crate) async fn pip_sync(
     requirements: &[RequirementsSource],
     constraints: &[RequirementsSource],
     build_constraints: &[RequirementsSource],
+    only: Vec<PackageName>,
     reinstall: Reinstall,
+    reinstall_vcs: bool,
+    reinstall_local: bool,
     link_mode: LinkMode,
     compile: bool,
     hash_checking: Option<HashCheckingMode>,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
+    embedded_index_strategy: EmbeddedIndexStrategy,
+    invalid_metadata: InvalidMetadataPolicy,
+    yanked: YankedPolicy,
+    index_unavailable: IndexUnavailablePolicy,
+    wheel_preference: Vec<WheelPreference>,
+    local_version_preference: Vec<LocalVersionPreference>,
+    resolution_timeout: Option<u64>,
+    max_backtracks: Option<usize>,
+    source_policy: Vec<PackageSourcePolicy>,
+    provided_by: Vec<PackageProvider>,
+    only_hashed: bool,
+    max_total_download_size: Option<ByteSize>,
+    max_package_size: Option<ByteSize>,
     dependency_metadata: DependencyMetadata,
     keyring_provider: KeyringProviderType,
     allow_empty_requirements: bool,
     installer_metadata: bool,
     connectivity: Connectivity,
     config_settings: &ConfigSettings,
+    config_settings_package: &PackageConfigSettings,
+    build_env: &PackageBuildEnv,
     no_build_isolation: bool,
     no_build_isolation_package: Vec<PackageName>,
     build_options: BuildOptions,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
+    features: Vec<String>,
     strict: bool,
+    check_abi: bool,
     exclude_newer: Option<ExcludeNewer>,
+    exclude_newer_package: ExcludeNewerPackage,
     python: Option<String>,
+    environment: Option<&Path>,
+    verify_signature: Option<&Path>,
+    audit_log: Option<PathBuf>,
     system: bool,
     break_system_packages: bool,
     target: Option<Target>,
     prefix: Option<Prefix>,
+    pypackages: bool,
     sources: SourceStrategy,
     python_preference: PythonPreference,
     concurrency: Concurrency,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
+    project_dir: &Path,
     cache: Cache,
     dry_run: DryRun,
     printer: Printer,
     preview: PreviewMode,
 ) -> Result<ExitStatus> {
+    // Installing into a container is only supported by `uv pip install`.
+    if let Some(container) = python
+        .as_deref()
+        .map(ContainerPython::from_request)
+        .transpose()?
+        .flatten()
+    {
+        return Err(anyhow!(
+            "`uv pip sync` is not supported with a container Python interpreter (`{container}`); use `uv pip install` instead"
+        ));
+    }
+
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
@@ -92,7 +141,25 @@ pub(crate) async fn pip_sync(
     let upgrade = Upgrade::default();
     let resolution_mode = ResolutionMode::default();
     let prerelease_mode = PrereleaseMode::default();
-    let dependency_mode = DependencyMode::Direct;
+
+    // Verify the signatures of the provided requirements and constraints files, along with any
+    // files they include.
+    let verified = if let Some(verify_signature) = verify_signature {
+        let key = VerifyingKey::from_path(verify_signature)?;
+        let mut verified = Vec::new();
+        for source in requirements.iter().chain(constraints) {
+            let RequirementsSource::RequirementsTxt(path) = source else {
+                return Err(anyhow!(
+                    "`--verify-signature` requires all requirements to be provided via `requirements.txt` files, but found: `{source}`"
+                ));
+            };
+            verified.extend(signature::verify_all(path, &key)?);
+            debug!("Verified signature for: `{}`", path.user_display());
+        }
+        Some(verified)
+    } else {
+        None
+    };
 
     // Read all requirements from the provided sources.
     let RequirementsSpecification {
@@ -114,10 +181,55 @@ pub(crate) async fn pip_sync(
         overrides,
         &extras,
         &groups,
+        embedded_index_strategy,
         &client_builder,
     )
     .await?;
 
+    // If requested, sync only the subgraph rooted at the given packages. Every requirement is
+    // retained as a constraint, such that the subgraph is resolved to the pinned versions.
+    let (requirements, constraints, dependency_mode, pinned) = if only.is_empty() {
+        (
+            requirements,
+            constraints,
+            DependencyMode::Direct,
+            BTreeSet::default(),
+        )
+    } else {
+        let pinned = requirements
+            .iter()
+            .filter_map(requirement_name)
+            .cloned()
+            .collect::<BTreeSet<_>>();
+        if let Some(name) = only.iter().find(|name| !pinned.contains(*name)) {
+            return Err(anyhow!(
+                "`{name}` was provided via `--only`, but is not included in the requirements"
+            ));
+        }
+        let constraints = constraints
+            .into_iter()
+            .chain(requirements.iter().filter_map(|entry| {
+                let UnresolvedRequirement::Named(requirement) = &entry.requirement else {
+                    return None;
+                };
+                Some(NameRequirementSpecification {
+                    requirement: requirement.clone(),
+                    hashes: entry.hashes.clone(),
+                })
+            }))
+            .collect::<Vec<_>>();
+        let requirements = requirements
+            .into_iter()
+            .filter(|entry| requirement_name(entry).is_some_and(|name| only.contains(name)))
+            .collect::<Vec<_>>();
+        (
+            requirements,
+            constraints,
+            DependencyMode::Transitive,
+            pinned,
+        )
+    };
+
     // Read build constraints.
     let build_constraints =
         operations::read_constraints(build_constraints, &client_builder).await?;
@@ -132,7 +244,7 @@ pub(crate) async fn pip_sync(
     }
 
     // Detect the current Python interpreter.
-    let environment = if target.is_some() || prefix.is_some() {
+    let environment = if target.is_some() || prefix.is_some() || pypackages {
         let installation = PythonInstallation::find(
             &python
                 .as_deref()
@@ -144,6 +256,10 @@ pub(crate) async fn pip_sync(
         )?;
         report_interpreter(&installation, true, printer)?;
         PythonEnvironment::from_installation(installation)
+    } else if let Some(environment) = environment {
+        let environment = PythonEnvironment::from_root(environment, &cache)?;
+        report_target_environment(&environment, &cache, printer)?;
+        environment
     } else {
         let environment = PythonEnvironment::find(
             &python
@@ -157,6 +273,13 @@ pub(crate) async fn pip_sync(
         environment
     };
 
+    // Install into the `__pypackages__` directory for the interpreter, if requested.
+    let target = if pypackages {
+        Some(Target::pypackages(project_dir, environment.interpreter()))
+    } else {
+        target
+    };
+
     // Apply any `--target` or `--prefix` directories.
     let environment = if let Some(target) = target {
         debug!(
@@ -196,6 +319,22 @@ pub(crate) async fn pip_sync(
 
     let _lock = environment.lock().await?;
 
+    // Reject any signed file that's older than the last one synced into the environment.
+    let signature_root = environment
+        .interpreter()
+        .target()
+        .map(Target::root)
+        .or_else(|| environment.interpreter().prefix().map(Prefix::root))
+        .unwrap_or_else(|| environment.root())
+        .to_path_buf();
+    let signature_history = if let Some(verified) = verified.as_deref() {
+        let history = SignatureHistory::read(&signature_root)?;
+        history.check(verified)?;
+        Some(history)
+    } else {
+        None
+    };
+
     let interpreter = environment.interpreter();
 
     // Determine the Python requirement, if the user requested a specific version.
@@ -210,13 +349,46 @@ pub(crate) async fn pip_sync(
         python_version.as_ref(),
         python_platform.as_ref(),
         interpreter,
-    );
+    )
+    .with_uv_features(features);
+
+    // If the packages are staged for another platform, skip any steps that require executing them
+    // on the host (i.e., generating entry point scripts and compiling bytecode).
+    let foreign = is_foreign_platform(python_platform.as_ref(), interpreter);
+    if foreign {
+        debug!(
+            "Staging packages for another platform (`sys_platform` is `{}`, not `{}`)",
+            marker_env.sys_platform(),
+            interpreter.markers().sys_platform()
+        );
+        if !interpreter.is_target() && !interpreter.is_prefix() {
+            warn_user!(
+                "Installing packages for another platform into the environment at {}, which will leave it unusable; use `--target` to stage the packages in a separate directory instead",
+                environment.root().user_display().cyan()
+            );
+        }
+        if compile {
+            warn_user!(
+                "Skipping bytecode compilation, which isn't supported when installing packages for another platform"
+            );
+        }
+    }
+    let compile = compile && !foreign;
+
     let tags = resolution_tags(
         python_version.as_ref(),
         python_platform.as_ref(),
         interpreter,
     )?;
 
+    // Read any known hashes from the project directory, or the nearest ancestor that contains a
+    // `uv.hashes.toml` file.
+    let known_hashes = if hash_checking.is_some() {
+        find_known_hashes(project_dir)?
+    } else {
+        KnownHashes::default()
+    };
+
     // Collect the set of required hashes.
     let hasher = if let Some(hash_checking) = hash_checking {
         HashStrategy::from_requirements(
@@ -226,13 +398,34 @@ pub(crate) async fn pip_sync(
             constraints
                 .iter()
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            &known_hashes,
             Some(&marker_env),
             hash_checking,
         )?
+    } else if audit_log.is_some() {
+        // Hash every distribution, such that the audit log records what was installed.
+        HashStrategy::Generate(HashGeneration::All)
     } else {
         HashStrategy::None
     };
 
+    // Determine the set of installed packages.
+    let site_packages = SitePackages::from_environment(&environment)?;
+
+    // Reinstall any packages that were installed from a VCS or local source, if requested.
+    let provenance =
+        operations::reinstall_by_provenance(&site_packages, reinstall_vcs, reinstall_local);
+    let cache = if provenance.is_none() {
+        cache
+    } else {
+        let refresh = cache
+            .refresh()
+            .clone()
+            .combine(Refresh::from(provenance.clone()));
+        cache.with_refresh(refresh)
+    };
+    let reinstall = reinstall.combine(provenance);
+
     // Incorporate any index locations from the provided sources.
     let index_locations = index_locations.combine(
         extra_index_urls
@@ -265,6 +458,7 @@ pub(crate) async fn pip_sync(
         .cache(cache.clone())
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
+        .index_unavailable(index_unavailable)
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -298,6 +492,7 @@ pub(crate) async fn pip_sync(
             build_constraints
                 .iter()
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            &known_hashes,
             Some(&marker_env),
             HashCheckingMode::Verify,
         )?
@@ -328,6 +523,8 @@ pub(crate) async fn pip_sync(
         state.clone(),
         index_strategy,
         config_settings,
+        config_settings_package,
+        build_env,
         build_isolation,
         link_mode,
         &build_options,
@@ -336,17 +533,25 @@ pub(crate) async fn pip_sync(
         sources,
         concurrency,
         preview,
-    );
-
-    // Determine the set of installed packages.
-    let site_packages = SitePackages::from_environment(&environment)?;
+    )
+    .with_exclude_newer_package(exclude_newer_package.clone());
 
     let options = OptionsBuilder::new()
         .resolution_mode(resolution_mode)
         .prerelease_mode(prerelease_mode)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
+        .exclude_newer_package(exclude_newer_package)
         .index_strategy(index_strategy)
+        .invalid_metadata(invalid_metadata)
+        .yanked(yanked)
+        .wheel_preference(wheel_preference)
+        .local_version_preference(local_version_preference)
+        .resolution_timeout(resolution_timeout.map(Duration::from_secs))
+        .max_backtracks(max_backtracks)
+        .source_policies(source_policy.into_iter().collect())
+        .providers(provided_by.into_iter().collect())
+        .only_hashed(only_hashed)
         .build_options(build_options.clone())
         .build();
 
@@ -374,6 +579,7 @@ pub(crate) async fn pip_sync(
         &build_dispatch,
         concurrency,
         options,
+        None,
         Box::new(DefaultResolveLogger),
         printer,
     )
@@ -383,19 +589,48 @@ pub(crate) async fn pip_sync(
         Err(err) => {
             return diagnostics::OperationDiagnostic::native_tls(native_tls)
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                .map_err(Into::into)
         }
     };
 
+    // When syncing a subgraph, every package in the subgraph must be pinned in the requirements,
+    // as in a full sync.
+    if !only.is_empty() {
+        if let Some(dist) = resolution
+            .distributions()
+            .find(|dist| !pinned.contains(dist.name()))
+        {
+            return Err(anyhow!(
+                "`{}` is required by the packages provided via `--only`, but is not pinned in the requirements",
+                dist.name()
+            ));
+        }
+    }
+
+    // In strict mode, reject any requested extras that don't exist.
+    if strict {
+        operations::validate_extras(resolution.diagnostics())?;
+    }
+
+    // Capture the current state of the environment, if an audit log was requested.
+    let audit_log = audit_log
+        .map(|path| AuditLog::new(path, &site_packages, &resolution, &environment))
+        .filter(|_| !dry_run.enabled());
+
     // Sync the environment.
     match operations::install(
         &resolution,
         site_packages,
-        Modifications::Exact,
+        if only.is_empty() {
+            Modifications::Exact
+        } else {
+            Modifications::Sufficient
+        },
         &reinstall,
         &build_options,
         link_mode,
         compile,
+        !foreign,
         &index_locations,
         config_settings,
         &hasher,
@@ -403,11 +638,19 @@ pub(crate) async fn pip_sync(
         &client,
         state.in_flight(),
         concurrency,
+        SizeBudget {
+            max_total_download_size,
+            max_package_size,
+        },
         &build_dispatch,
         &cache,
         &environment,
-        Box::new(DefaultInstallLogger),
+        match &audit_log {
+            Some(audit_log) => Box::new(audit_log.logger()),
+            None => Box::new(DefaultInstallLogger),
+        },
         installer_metadata,
+        check_abi,
         dry_run,
         printer,
     )
@@ -415,9 +658,25 @@ pub(crate) async fn pip_sync(
     {
         Ok(_) => {}
         Err(err) => {
+            // Record any changes that were made before the failure.
+            if let Some(audit_log) = audit_log {
+                audit_log.write_failure(&environment, &err)?;
+            }
             return diagnostics::OperationDiagnostic::native_tls(native_tls)
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                .map_err(Into::into);
+        }
+    }
+
+    // Record the changes to the environment in the audit log.
+    if let Some(audit_log) = audit_log {
+        audit_log.write(&environment)?;
+    }
+
+    // Record the signing timestamps of the synced files.
+    if let (Some(history), Some(verified)) = (signature_history, verified.as_deref()) {
+        if !dry_run.enabled() {
+            history.write(&signature_root, verified)?;
         }
     }
 
@@ -425,9 +684,21 @@ pub(crate) async fn pip_sync(
     operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
     // Notify the user of any environment diagnostics.
-    if strict && !dry_run.enabled() {
-        operations::diagnose_environment(&resolution, &environment, &marker_env, printer)?;
+    if !dry_run.enabled() {
+        if strict {
+            operations::diagnose_environment(&resolution, &environment, &marker_env, printer)?;
+        } else {
+            operations::diagnose_shadowing(&resolution, &environment, printer)?;
+        }
     }
 
     Ok(ExitStatus::Success)
 }
+
+/// Return the name of the given requirement, if it's known prior to resolution.
+fn requirement_name(entry: &UnresolvedRequirementSpecification) -> Option<&PackageName> {
+    match &entry.requirement {
+        UnresolvedRequirement::Named(requirement) => Some(&requirement.name),
+        UnresolvedRequirement::Unnamed(_) => None,
+    }
+}
@@ -1,4 +1,5 @@
 use std::fmt::Write;
+use std::path::Path;
 
 use anyhow::Result;
 use itertools::{Either, Itertools};
@@ -15,7 +16,7 @@ use uv_pypi_types::Requirement;
 use uv_pypi_types::VerbatimParsedUrl;
 use uv_python::EnvironmentPreference;
 use uv_python::PythonRequest;
-use uv_python::{Prefix, PythonEnvironment, Target};
+use uv_python::{ContainerPython, Prefix, PythonEnvironment, Target};
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 
 use crate::commands::pip::operations::report_target_environment;
@@ -27,6 +28,7 @@ use crate::printer::Printer;
 pub(crate) async fn pip_uninstall(
     sources: &[RequirementsSource],
     python: Option<String>,
+    environment: Option<&Path>,
     system: bool,
     break_system_packages: bool,
     target: Option<Target>,
@@ -41,6 +43,18 @@ pub(crate) async fn pip_uninstall(
 ) -> Result<ExitStatus> {
     let start = std::time::Instant::now();
 
+    // Uninstalling from a container is not supported.
+    if let Some(container) = python
+        .as_deref()
+        .map(ContainerPython::from_request)
+        .transpose()?
+        .flatten()
+    {
+        return Err(anyhow::anyhow!(
+            "`uv pip uninstall` is not supported with a container Python interpreter (`{container}`)"
+        ));
+    }
+
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
@@ -51,14 +65,18 @@ pub(crate) async fn pip_uninstall(
     let spec = RequirementsSpecification::from_simple_sources(sources, &client_builder).await?;
 
     // Detect the current Python interpreter.
-    let environment = PythonEnvironment::find(
-        &python
-            .as_deref()
-            .map(PythonRequest::parse)
-            .unwrap_or_default(),
-        EnvironmentPreference::from_system_flag(system, true),
-        &cache,
-    )?;
+    let environment = if let Some(environment) = environment {
+        PythonEnvironment::from_root(environment, &cache)?
+    } else {
+        PythonEnvironment::find(
+            &python
+                .as_deref()
+                .map(PythonRequest::parse)
+                .unwrap_or_default(),
+            EnvironmentPreference::from_system_flag(system, true),
+            &cache,
+        )?
+    };
 
     report_target_environment(&environment, &cache, printer)?;
 
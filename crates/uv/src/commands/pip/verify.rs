@@ -0,0 +1,311 @@
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Instant;
+
+use anyhow::Result;
+use fs_err as fs;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::Connectivity;
+use uv_configuration::{
+    BuildOptions, Concurrency, ConfigSettings, DryRun, IndexStrategy, KeyringProviderType,
+    PackageBuildEnv, PackageConfigSettings, PreviewMode, SourceStrategy, TrustedHost,
+};
+use uv_distribution_types::{DependencyMetadata, IndexLocations, InstalledDist, Name};
+use uv_fs::Simplified;
+use uv_install_wheel::{recorded_files, verify_wheel, LinkMode, Verification};
+use uv_installer::SitePackages;
+use uv_pep508::PackageName;
+use uv_python::{EnvironmentPreference, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_requirements::RequirementsSource;
+use uv_resolver::{DependencyMode, ExcludeNewer};
+use uv_warnings::warn_user;
+
+use crate::commands::pip::operations::report_target_environment;
+use crate::commands::pip::refresh::reinstall;
+use crate::commands::{elapsed, ExitStatus};
+use crate::printer::Printer;
+
+/// Verify the integrity of the installed packages against their `RECORD` files, optionally
+/// reinstalling any package with missing or modified files.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn pip_verify(
+    packages: &[PackageName],
+    repair: bool,
+    remove_extra: bool,
+    link_mode: LinkMode,
+    compile: bool,
+    index_locations: IndexLocations,
+    index_strategy: IndexStrategy,
+    dependency_metadata: DependencyMetadata,
+    keyring_provider: KeyringProviderType,
+    installer_metadata: bool,
+    connectivity: Connectivity,
+    config_settings: &ConfigSettings,
+    config_settings_package: &PackageConfigSettings,
+    build_env: &PackageBuildEnv,
+    no_build_isolation: bool,
+    no_build_isolation_package: Vec<PackageName>,
+    build_options: BuildOptions,
+    exclude_newer: Option<ExcludeNewer>,
+    sources: SourceStrategy,
+    python: Option<String>,
+    environment: Option<&Path>,
+    system: bool,
+    break_system_packages: bool,
+    python_preference: PythonPreference,
+    concurrency: Concurrency,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    cache: Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    let start = Instant::now();
+
+    // Detect the current Python interpreter.
+    let environment = if let Some(environment) = environment {
+        let environment = PythonEnvironment::from_root(environment, &cache)?;
+        report_target_environment(&environment, &cache, printer)?;
+        environment
+    } else {
+        let environment = PythonEnvironment::find(
+            &python
+                .as_deref()
+                .map(PythonRequest::parse)
+                .unwrap_or_default(),
+            EnvironmentPreference::from_system_flag(system, repair),
+            &cache,
+        )?;
+        report_target_environment(&environment, &cache, printer)?;
+        environment
+    };
+
+    // If we're repairing the environment and it's externally managed, abort.
+    if repair {
+        if let Some(externally_managed) = environment.interpreter().is_externally_managed() {
+            if break_system_packages {
+                debug!("Ignoring externally managed environment due to `--break-system-packages`");
+            } else {
+                return if let Some(error) = externally_managed.into_error() {
+                    Err(anyhow::anyhow!(
+                        "The interpreter at {} is externally managed, and indicates the following:\n\n{}\n\nConsider creating a virtual environment with `uv venv`.",
+                        environment.root().user_display().cyan(),
+                        textwrap::indent(&error, "  ").green(),
+                    ))
+                } else {
+                    Err(anyhow::anyhow!(
+                        "The interpreter at {} is externally managed. Instead, create a virtual environment with `uv venv`.",
+                        environment.root().user_display().cyan()
+                    ))
+                };
+            }
+        }
+    }
+
+    let lock = environment.lock().await?;
+
+    // Build the installed index.
+    let site_packages = SitePackages::from_environment(&environment)?;
+
+    // Collect the files recorded by every package in the environment, such that files shared
+    // across packages (e.g., in namespace packages) aren't reported as extra.
+    let mut recorded = FxHashSet::default();
+    for dist in site_packages.iter() {
+        if !matches!(dist, InstalledDist::Registry(_) | InstalledDist::Url(_)) {
+            continue;
+        }
+        match recorded_files(dist.path()) {
+            Ok(files) => recorded.extend(files),
+            Err(err) => debug!("Failed to read `RECORD` for `{}`: {err}", dist.name()),
+        }
+    }
+
+    // Verify each package.
+    let mut checked = 0usize;
+    let mut failures: Vec<(InstalledDist, Verification)> = Vec::new();
+    for dist in site_packages.iter() {
+        if !packages.is_empty() && !packages.contains(dist.name()) {
+            continue;
+        }
+        if !matches!(dist, InstalledDist::Registry(_) | InstalledDist::Url(_)) {
+            debug!(
+                "Skipping `{}`, which was not installed from a wheel",
+                dist.name()
+            );
+            continue;
+        }
+        checked += 1;
+        match verify_wheel(dist.path(), &recorded) {
+            Ok(verification) if verification.is_empty() => {}
+            Ok(verification) => failures.push((dist.clone(), verification)),
+            Err(uv_install_wheel::Error::MissingRecord(_)) => {
+                warn_user!(
+                    "Unable to verify `{}`: no `RECORD` file was found",
+                    dist.name()
+                );
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    for package in packages {
+        if site_packages.get_packages(package).is_empty() {
+            warn_user!("`{package}` is not installed");
+        }
+    }
+
+    let s = if checked == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Verified {} {}",
+            format!("{checked} package{s}").bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+
+    if failures.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "All installed files match their records"
+                .to_string()
+                .dimmed()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let s = if failures.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Found {} with integrity issues",
+            format!("{} package{s}", failures.len()).bold()
+        )
+        .dimmed()
+    )?;
+    for (dist, verification) in &failures {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!("{}=={}", dist.name(), dist.version()).bold()
+        )?;
+        for path in &verification.modified {
+            writeln!(printer.stderr(), "  {} {path}", "modified:".yellow())?;
+        }
+        for path in &verification.missing {
+            writeln!(printer.stderr(), "  {} {path}", "missing:".red())?;
+        }
+        for path in &verification.extra {
+            writeln!(printer.stderr(), "  {} {path}", "extra:".cyan())?;
+        }
+    }
+
+    if !repair {
+        return Ok(ExitStatus::Failure);
+    }
+
+    // Unrecorded files are only removed on request, since they may have been added deliberately
+    // (e.g., by a plugin or a post-install step).
+    if remove_extra {
+        for (dist, verification) in &failures {
+            let root = dist
+                .path()
+                .parent()
+                .expect("`.dist-info` directory is within site-packages");
+            for path in &verification.extra {
+                debug!("Removing unrecorded file: {path}");
+                fs::remove_file(root.join(path))?;
+            }
+        }
+    } else if failures
+        .iter()
+        .any(|(_, verification)| !verification.extra.is_empty())
+    {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "Unrecorded files were left in place; pass `--remove-extra` to remove them".dimmed()
+        )?;
+    }
+
+    // Reinstall each package with missing or modified files at its installed version.
+    let mut names = Vec::new();
+    let mut requirements = Vec::new();
+    for (dist, verification) in &failures {
+        if verification.missing.is_empty() && verification.modified.is_empty() {
+            continue;
+        }
+        let requirement = match dist {
+            InstalledDist::Registry(dist) => format!("{}=={}", dist.name, dist.version),
+            InstalledDist::Url(dist) if !dist.editable => format!("{} @ {}", dist.name, dist.url),
+            _ => {
+                warn_user!(
+                    "Unable to repair editable package `{}`; use `uv pip refresh-editables` instead",
+                    dist.name()
+                );
+                continue;
+            }
+        };
+
+        names.push(dist.name().clone());
+        requirements.push(RequirementsSource::Package(requirement));
+    }
+
+    if requirements.is_empty() {
+        // If the only issues were unrecorded files, and they've been removed, the environment is
+        // intact.
+        let repaired = remove_extra
+            && failures.iter().all(|(_, verification)| {
+                verification.missing.is_empty() && verification.modified.is_empty()
+            });
+        return Ok(if repaired {
+            ExitStatus::Success
+        } else {
+            ExitStatus::Failure
+        });
+    }
+
+    // Release the environment lock, which is reacquired for the installation.
+    drop(lock);
+
+    reinstall(
+        &requirements,
+        names,
+        DependencyMode::Direct,
+        &environment,
+        link_mode,
+        compile,
+        index_locations,
+        index_strategy,
+        dependency_metadata,
+        keyring_provider,
+        installer_metadata,
+        connectivity,
+        config_settings,
+        config_settings_package,
+        build_env,
+        no_build_isolation,
+        no_build_isolation_package,
+        build_options,
+        exclude_newer,
+        sources,
+        break_system_packages,
+        python_preference,
+        concurrency,
+        native_tls,
+        allow_insecure_host,
+        cache,
+        DryRun::Disabled,
+        printer,
+        preview,
+    )
+    .await
+}
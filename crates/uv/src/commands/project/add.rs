@@ -26,6 +26,7 @@ use uv_fs::Simplified;
 use uv_git::GIT_STORE;
 use uv_git_types::GitReference;
 use uv_normalize::{PackageName, DEV_DEPENDENCIES};
+use uv_pep440::VersionSpecifier;
 use uv_pep508::{ExtraName, Requirement, UnnamedRequirement, VersionOrUrl};
 use uv_pypi_types::{redact_credentials, ParsedUrl, RequirementSource, VerbatimParsedUrl};
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
@@ -67,6 +68,7 @@ pub(crate) async fn add(
     editable: Option<bool>,
     dependency_type: DependencyType,
     raw_sources: bool,
+    normalize_specifiers: bool,
     indexes: Vec<Index>,
     rev: Option<String>,
     tag: Option<String>,
@@ -342,6 +344,8 @@ pub(crate) async fn add(
                 state.clone().into_inner(),
                 settings.index_strategy,
                 &settings.config_setting,
+                &settings.config_setting_package,
+                &settings.build_env,
                 build_isolation,
                 settings.link_mode,
                 &settings.build_options,
@@ -350,7 +354,8 @@ pub(crate) async fn add(
                 sources,
                 concurrency,
                 preview,
-            );
+            )
+            .with_exclude_newer_package(settings.exclude_newer_package.clone());
 
             requirements.extend(
                 NamedRequirementsResolver::new(
@@ -412,7 +417,7 @@ pub(crate) async fn add(
         requirement.extras.sort_unstable();
         requirement.extras.dedup();
 
-        let (requirement, source) = match target {
+        let (mut requirement, source) = match target {
             AddTarget::Script(_, _) | AddTarget::Project(_, _) if raw_sources => {
                 (uv_pep508::Requirement::from(requirement), None)
             }
@@ -448,6 +453,15 @@ pub(crate) async fn add(
             }
         };
 
+        // Normalize and simplify the version specifiers, if requested.
+        if normalize_specifiers {
+            if let Some(VersionOrUrl::VersionSpecifier(specifiers)) =
+                &mut requirement.version_or_url
+            {
+                *specifiers = specifiers.simplify();
+            }
+        }
+
         // Redact any credentials. By default, we avoid writing sensitive credentials to files that
         // will be checked into version control (e.g., `pyproject.toml` and `uv.lock`). Instead,
         // we store the credentials in a global store, and reuse them during resolution. The
@@ -631,6 +645,7 @@ pub(crate) async fn add(
         locked,
         &dependency_type,
         raw_sources,
+        normalize_specifiers,
         settings.as_ref(),
         installer_metadata,
         connectivity,
@@ -651,7 +666,7 @@ pub(crate) async fn add(
             match err {
                 ProjectError::Operation(err) => diagnostics::OperationDiagnostic::native_tls(native_tls).with_hint(format!("If you want to add the package regardless of the failed resolution, provide the `{}` flag to skip locking and syncing.", "--frozen".green()))
                     .report(err)
-                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into())),
+                    .map_err(Into::into),
                 err => Err(err.into()),
             }
         }
@@ -669,6 +684,7 @@ async fn lock_and_sync(
     locked: bool,
     dependency_type: &DependencyType,
     raw_sources: bool,
+    normalize_specifiers: bool,
     settings: ResolverInstallerSettingsRef<'_>,
     installer_metadata: bool,
     connectivity: Connectivity,
@@ -758,6 +774,16 @@ async fn lock_and_sync(
             // For example, convert `1.2.3+local` to `1.2.3`.
             let minimum = (*minimum).clone().without_local();
 
+            // Remove any trailing zeros, if requested. For example, convert `2.0.0` to `2`.
+            let minimum = if normalize_specifiers {
+                VersionSpecifier::greater_than_equal_version(minimum)
+                    .normalize()
+                    .into_parts()
+                    .1
+            } else {
+                minimum
+            };
+
             match edit.dependency_type {
                 DependencyType::Production => {
                     toml.set_dependency_minimum_version(*index, minimum)?;
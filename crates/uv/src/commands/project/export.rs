@@ -193,7 +193,7 @@ pub(crate) async fn export(
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(native_tls)
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                .map_err(Into::into)
         }
         Err(err) => return Err(err.into()),
     };
@@ -81,6 +81,8 @@ pub(crate) async fn lock(
     locked: bool,
     frozen: bool,
     dry_run: DryRun,
+    verify_determinism: bool,
+    seed: Option<u64>,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverSettings,
@@ -186,23 +188,51 @@ pub(crate) async fn lock(
     let state = UniversalState::default();
 
     // Perform the lock operation.
-    match do_safe_lock(
-        mode,
-        target,
-        settings.as_ref(),
-        &state,
-        Box::new(DefaultResolveLogger),
-        connectivity,
-        concurrency,
-        native_tls,
-        allow_insecure_host,
-        cache,
-        printer,
-        preview,
-    )
-    .await
-    {
-        Ok(lock) => {
+    let result = if verify_determinism {
+        if matches!(mode, LockMode::Frozen) {
+            warn_user!(
+                "`--verify-determinism` has no effect when used alongside `--frozen`, as the project is not resolved"
+            );
+        }
+        do_verified_lock(
+            mode,
+            target,
+            seed.unwrap_or_else(pip::operations::random_seed),
+            settings.as_ref(),
+            &state,
+            Box::new(DefaultResolveLogger),
+            connectivity,
+            concurrency,
+            native_tls,
+            allow_insecure_host,
+            cache,
+            printer,
+            preview,
+        )
+        .await
+    } else {
+        do_safe_lock(
+            mode,
+            target,
+            settings.as_ref(),
+            &state,
+            Box::new(DefaultResolveLogger),
+            connectivity,
+            concurrency,
+            native_tls,
+            allow_insecure_host,
+            cache,
+            printer,
+            preview,
+        )
+        .await
+        .map(Some)
+    };
+
+    match result {
+        // The resolution was not deterministic; the differences have already been reported.
+        Ok(None) => Ok(ExitStatus::Failure),
+        Ok(Some(lock)) => {
             if dry_run.enabled() {
                 // In `--dry-run` mode, show all changes.
                 let mut changed = false;
@@ -232,7 +262,7 @@ pub(crate) async fn lock(
         Err(ProjectError::Operation(err)) => {
             diagnostics::OperationDiagnostic::native_tls(native_tls)
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                .map_err(Into::into)
         }
         Err(err) => Err(err.into()),
     }
@@ -250,6 +280,17 @@ pub(super) enum LockMode<'env> {
     Frozen,
 }
 
+/// The order in which to present the project requirements to the resolver.
+///
+/// The resolution should be independent of the order; presenting the inputs in a random order is
+/// used to verify that it is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InputOrder {
+    Forward,
+    /// Shuffle the inputs with the given seed.
+    Shuffled(u64),
+}
+
 /// Perform a lock operation, respecting the `--locked` and `--frozen` parameters.
 #[allow(clippy::fn_params_excessive_bools)]
 pub(super) async fn do_safe_lock(
@@ -269,24 +310,21 @@ pub(super) async fn do_safe_lock(
     match mode {
         LockMode::Frozen => {
             // Read the existing lockfile, but don't attempt to lock the project.
-            let existing = target
-                .read()
+            let existing = read_existing_lock(mode, target)
                 .await?
                 .ok_or_else(|| ProjectError::MissingLockfile)?;
             Ok(LockResult::Unchanged(existing))
         }
         LockMode::Locked(interpreter) => {
             // Read the existing lockfile.
-            let existing = target
-                .read()
-                .await?
-                .ok_or_else(|| ProjectError::MissingLockfile)?;
+            let existing = read_existing_lock(mode, target).await?;
 
             // Perform the lock operation, but don't write the lockfile to disk.
             let result = do_lock(
                 target,
                 interpreter,
-                Some(existing),
+                existing,
+                InputOrder::Forward,
                 settings,
                 state,
                 logger,
@@ -309,23 +347,14 @@ pub(super) async fn do_safe_lock(
         }
         LockMode::Write(interpreter) | LockMode::DryRun(interpreter) => {
             // Read the existing lockfile.
-            let existing = match target.read().await {
-                Ok(Some(existing)) => Some(existing),
-                Ok(None) => None,
-                Err(ProjectError::Lock(err)) => {
-                    warn_user!(
-                        "Failed to read existing lockfile; ignoring locked requirements: {err}"
-                    );
-                    None
-                }
-                Err(err) => return Err(err),
-            };
+            let existing = read_existing_lock(mode, target).await?;
 
             // Perform the lock operation.
             let result = do_lock(
                 target,
                 interpreter,
                 existing,
+                InputOrder::Forward,
                 settings,
                 state,
                 logger,
@@ -351,11 +380,156 @@ pub(super) async fn do_safe_lock(
     }
 }
 
+/// Perform a lock operation, like [`do_safe_lock`], but resolve the project a second time with
+/// the inputs presented in a different order, and verify that both resolutions produce an
+/// identical lockfile before writing it to disk.
+///
+/// Both resolutions start from the existing lockfile, if any. The second resolution is always
+/// performed, even if the existing lockfile satisfies the requirements, with its versions as
+/// preferences.
+///
+/// Returns `None` if the resolutions differ, after reporting the differences.
+#[allow(clippy::fn_params_excessive_bools)]
+async fn do_verified_lock(
+    mode: LockMode<'_>,
+    target: LockTarget<'_>,
+    seed: u64,
+    settings: ResolverSettingsRef<'_>,
+    state: &UniversalState,
+    logger: Box<dyn ResolveLogger>,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<Option<LockResult>, ProjectError> {
+    let interpreter = match mode {
+        LockMode::Write(interpreter)
+        | LockMode::DryRun(interpreter)
+        | LockMode::Locked(interpreter) => interpreter,
+        LockMode::Frozen => {
+            return do_safe_lock(
+                mode,
+                target,
+                settings,
+                state,
+                logger,
+                connectivity,
+                concurrency,
+                native_tls,
+                allow_insecure_host,
+                cache,
+                printer,
+                preview,
+            )
+            .await
+            .map(Some);
+        }
+    };
+
+    // Read the existing lockfile.
+    let existing = read_existing_lock(mode, target).await?;
+
+    let result = do_lock(
+        target,
+        interpreter,
+        existing.clone(),
+        InputOrder::Forward,
+        settings,
+        state,
+        logger,
+        connectivity,
+        concurrency,
+        native_tls,
+        allow_insecure_host,
+        cache,
+        printer,
+        preview,
+    )
+    .await?;
+
+    // Use a separate state for the second resolution, so that it can't reuse the results of the
+    // first.
+    let shuffled = do_lock(
+        target,
+        interpreter,
+        existing,
+        InputOrder::Shuffled(seed),
+        settings,
+        &UniversalState::default(),
+        Box::new(SummaryResolveLogger),
+        connectivity,
+        concurrency,
+        native_tls,
+        allow_insecure_host,
+        cache,
+        printer,
+        preview,
+    )
+    .await?;
+
+    if !pip::operations::verify_determinism(
+        &result.lock().to_toml()?,
+        &shuffled.lock().to_toml()?,
+        seed,
+        printer,
+    )? {
+        return Ok(None);
+    }
+
+    // Write (or check) the lockfile that was verified.
+    match mode {
+        LockMode::Locked(_) => {
+            if matches!(result, LockResult::Changed(_, _)) {
+                return Err(ProjectError::LockMismatch);
+            }
+        }
+        LockMode::Write(_) => {
+            if let LockResult::Changed(_, lock) = &result {
+                target.commit(lock).await?;
+            }
+        }
+        LockMode::DryRun(_) | LockMode::Frozen => {}
+    }
+
+    Ok(Some(result))
+}
+
+/// Read the existing lockfile, if any.
+///
+/// In `--locked` and `--frozen` mode, the lockfile must exist; otherwise, a lockfile that fails to
+/// parse is ignored, with a warning.
+async fn read_existing_lock(
+    mode: LockMode<'_>,
+    target: LockTarget<'_>,
+) -> Result<Option<Lock>, ProjectError> {
+    match mode {
+        LockMode::Frozen | LockMode::Locked(_) => Ok(Some(
+            target
+                .read()
+                .await?
+                .ok_or_else(|| ProjectError::MissingLockfile)?,
+        )),
+        LockMode::Write(_) | LockMode::DryRun(_) => match target.read().await {
+            Ok(Some(existing)) => Ok(Some(existing)),
+            Ok(None) => Ok(None),
+            Err(ProjectError::Lock(err)) => {
+                warn_user!("Failed to read existing lockfile; ignoring locked requirements: {err}");
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        },
+    }
+}
+
 /// Lock the project requirements into a lockfile.
 async fn do_lock(
     target: LockTarget<'_>,
     interpreter: &Interpreter,
     existing_lock: Option<Lock>,
+    order: InputOrder,
     settings: ResolverSettingsRef<'_>,
     state: &UniversalState,
     logger: Box<dyn ResolveLogger>,
@@ -375,13 +549,18 @@ async fn do_lock(
         index_strategy,
         keyring_provider,
         resolution,
+        resolution_package,
         prerelease,
+        prerelease_package,
         fork_strategy,
         dependency_metadata,
         config_setting,
+        config_setting_package,
+        build_env,
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
+        exclude_newer_package,
         link_mode,
         upgrade,
         build_options,
@@ -577,9 +756,12 @@ async fn do_lock(
 
     let options = OptionsBuilder::new()
         .resolution_mode(resolution)
+        .resolution_package(resolution_package.clone())
         .prerelease_mode(prerelease)
+        .prerelease_package(prerelease_package.to_vec())
         .fork_strategy(fork_strategy)
         .exclude_newer(exclude_newer)
+        .exclude_newer_package(exclude_newer_package.clone())
         .index_strategy(index_strategy)
         .build_options(build_options.clone())
         .required_environments(required_environments.cloned().unwrap_or_default())
@@ -615,6 +797,8 @@ async fn do_lock(
         state.fork().into_inner(),
         index_strategy,
         config_setting,
+        config_setting_package,
+        build_env,
         build_isolation,
         link_mode,
         build_options,
@@ -623,7 +807,8 @@ async fn do_lock(
         sources,
         concurrency,
         preview,
-    );
+    )
+    .with_exclude_newer_package(exclude_newer_package.clone());
 
     let database = DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads);
 
@@ -670,8 +855,9 @@ async fn do_lock(
     };
 
     match existing_lock {
-        // Resolution from the lockfile succeeded.
-        Some(ValidatedLock::Satisfies(lock)) => {
+        // Resolution from the lockfile succeeded. When verifying determinism, the shuffled
+        // resolution is performed regardless, with the existing lockfile as preferences.
+        Some(ValidatedLock::Satisfies(lock)) if order == InputOrder::Forward => {
             // Print the success message after completing resolution.
             logger.on_complete(lock.len(), start, printer)?;
 
@@ -731,33 +917,46 @@ async fn do_lock(
                     }),
             );
 
+            // Collect the inputs to the resolver.
+            let mut resolver_requirements = ExtrasResolver::new(&hasher, state.index(), database)
+                .with_reporter(Arc::new(ResolverReporter::from(printer)))
+                .resolve(target.members_requirements())
+                .await
+                .map_err(|err| ProjectError::Operation(err.into()))?
+                .into_iter()
+                .chain(target.group_requirements())
+                .chain(requirements.iter().cloned())
+                .chain(
+                    dependency_groups
+                        .values()
+                        .flat_map(|requirements| requirements.iter().cloned()),
+                )
+                .map(UnresolvedRequirementSpecification::from)
+                .collect::<Vec<_>>();
+            let mut resolver_constraints = constraints
+                .iter()
+                .cloned()
+                .map(NameRequirementSpecification::from)
+                .collect::<Vec<_>>();
+            let mut resolver_overrides = overrides
+                .iter()
+                .cloned()
+                .map(UnresolvedRequirementSpecification::from)
+                .collect::<Vec<_>>();
+            let mut preferences = preferences;
+            if let InputOrder::Shuffled(seed) = order {
+                debug!("Shuffling inputs with seed: {seed}");
+                resolver_requirements = pip::operations::shuffle(resolver_requirements, seed);
+                resolver_constraints = pip::operations::shuffle(resolver_constraints, seed);
+                resolver_overrides = pip::operations::shuffle(resolver_overrides, seed);
+                preferences = pip::operations::shuffle(preferences, seed);
+            }
+
             // Resolve the requirements.
             let resolution = pip::operations::resolve(
-                ExtrasResolver::new(&hasher, state.index(), database)
-                    .with_reporter(Arc::new(ResolverReporter::from(printer)))
-                    .resolve(target.members_requirements())
-                    .await
-                    .map_err(|err| ProjectError::Operation(err.into()))?
-                    .into_iter()
-                    .chain(target.group_requirements())
-                    .chain(requirements.iter().cloned())
-                    .chain(
-                        dependency_groups
-                            .values()
-                            .flat_map(|requirements| requirements.iter().cloned()),
-                    )
-                    .map(UnresolvedRequirementSpecification::from)
-                    .collect(),
-                constraints
-                    .iter()
-                    .cloned()
-                    .map(NameRequirementSpecification::from)
-                    .collect(),
-                overrides
-                    .iter()
-                    .cloned()
-                    .map(UnresolvedRequirementSpecification::from)
-                    .collect(),
+                resolver_requirements,
+                resolver_constraints,
+                resolver_overrides,
                 source_trees,
                 // The root is always null in workspaces, it "depends on" the projects
                 None,
@@ -779,6 +978,7 @@ async fn do_lock(
                 &build_dispatch,
                 concurrency,
                 options,
+                None,
                 Box::new(SummaryResolveLogger),
                 printer,
             )
@@ -871,6 +1071,13 @@ impl ValidatedLock {
             );
             return Ok(Self::Unusable(lock));
         }
+        if *lock.resolution_package() != options.resolution_package {
+            let _ = writeln!(
+                printer.stderr(),
+                "Ignoring existing lockfile due to change in package-specific resolution modes"
+            );
+            return Ok(Self::Unusable(lock));
+        }
         if lock.prerelease_mode() != options.prerelease_mode {
             let _ = writeln!(
                 printer.stderr(),
@@ -880,6 +1087,19 @@ impl ValidatedLock {
             );
             return Ok(Self::Unusable(lock));
         }
+        if *lock.prerelease_package()
+            != options
+                .prerelease_package
+                .iter()
+                .cloned()
+                .collect::<BTreeSet<_>>()
+        {
+            let _ = writeln!(
+                printer.stderr(),
+                "Ignoring existing lockfile due to change in package-specific pre-release allowances"
+            );
+            return Ok(Self::Unusable(lock));
+        }
         if lock.fork_strategy() != options.fork_strategy {
             let _ = writeln!(
                 printer.stderr(),
@@ -918,6 +1138,13 @@ impl ValidatedLock {
                 return Ok(Self::Unusable(lock));
             }
         }
+        if *lock.exclude_newer_package() != options.exclude_newer_package {
+            let _ = writeln!(
+                printer.stderr(),
+                "Ignoring existing lockfile due to change in package-specific timestamp cutoffs"
+            );
+            return Ok(Self::Unusable(lock));
+        }
 
         match upgrade {
             Upgrade::None => {}
@@ -49,6 +49,7 @@ use uv_workspace::Workspace;
 
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
 use crate::commands::pip::operations::{Changelog, Modifications};
+use crate::commands::pip::size_budget::SizeBudget;
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{capitalize, conjunction, pip};
 use crate::printer::Printer;
@@ -1106,7 +1107,7 @@ impl ScriptPython {
 
 /// The Python environment for a project.
 #[derive(Debug)]
-enum ProjectEnvironment {
+pub(crate) enum ProjectEnvironment {
     /// An existing [`PythonEnvironment`] was discovered, which satisfies the project's requirements.
     Existing(PythonEnvironment),
     /// An existing [`PythonEnvironment`] was discovered, but did not satisfy the project's
@@ -1513,13 +1514,18 @@ pub(crate) async fn resolve_names(
         index_strategy,
         keyring_provider,
         resolution: _,
+        resolution_package: _,
         prerelease: _,
+        prerelease_package: _,
         fork_strategy: _,
         dependency_metadata,
         config_setting,
+        config_setting_package,
+        build_env,
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
+        exclude_newer_package,
         link_mode,
         compile_bytecode: _,
         sources,
@@ -1582,6 +1588,8 @@ pub(crate) async fn resolve_names(
         state.clone(),
         *index_strategy,
         config_setting,
+        config_setting_package,
+        build_env,
         build_isolation,
         *link_mode,
         build_options,
@@ -1590,7 +1598,8 @@ pub(crate) async fn resolve_names(
         *sources,
         concurrency,
         preview,
-    );
+    )
+    .with_exclude_newer_package(exclude_newer_package.clone());
 
     // Resolve the unnamed requirements.
     requirements.extend(
@@ -1653,13 +1662,18 @@ pub(crate) async fn resolve_environment(
         index_strategy,
         keyring_provider,
         resolution,
+        resolution_package,
         prerelease,
+        prerelease_package,
         fork_strategy,
         dependency_metadata,
         config_setting,
+        config_setting_package,
+        build_env,
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
+        exclude_newer_package,
         link_mode,
         upgrade: _,
         build_options,
@@ -1718,9 +1732,12 @@ pub(crate) async fn resolve_environment(
 
     let options = OptionsBuilder::new()
         .resolution_mode(resolution)
+        .resolution_package(resolution_package.clone())
         .prerelease_mode(prerelease)
+        .prerelease_package(prerelease_package.to_vec())
         .fork_strategy(fork_strategy)
         .exclude_newer(exclude_newer)
+        .exclude_newer_package(exclude_newer_package.clone())
         .index_strategy(index_strategy)
         .build_options(build_options.clone())
         .build();
@@ -1772,6 +1789,8 @@ pub(crate) async fn resolve_environment(
         state.clone().into_inner(),
         index_strategy,
         config_setting,
+        config_setting_package,
+        build_env,
         build_isolation,
         link_mode,
         build_options,
@@ -1780,7 +1799,8 @@ pub(crate) async fn resolve_environment(
         sources,
         concurrency,
         preview,
-    );
+    )
+    .with_exclude_newer_package(exclude_newer_package.clone());
 
     // Resolve the requirements.
     Ok(pip::operations::resolve(
@@ -1807,6 +1827,7 @@ pub(crate) async fn resolve_environment(
         &resolve_dispatch,
         concurrency,
         options,
+        None,
         logger,
         printer,
     )
@@ -1836,9 +1857,12 @@ pub(crate) async fn sync_environment(
         keyring_provider,
         dependency_metadata,
         config_setting,
+        config_setting_package,
+        build_env,
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
+        exclude_newer_package,
         link_mode,
         compile_bytecode,
         reinstall,
@@ -1912,6 +1936,8 @@ pub(crate) async fn sync_environment(
         state.clone().into_inner(),
         index_strategy,
         config_setting,
+        config_setting_package,
+        build_env,
         build_isolation,
         link_mode,
         build_options,
@@ -1920,7 +1946,8 @@ pub(crate) async fn sync_environment(
         sources,
         concurrency,
         preview,
-    );
+    )
+    .with_exclude_newer_package(exclude_newer_package.clone());
 
     // Sync the environment.
     pip::operations::install(
@@ -1931,6 +1958,7 @@ pub(crate) async fn sync_environment(
         build_options,
         link_mode,
         compile_bytecode,
+        true,
         index_locations,
         config_setting,
         &hasher,
@@ -1938,11 +1966,13 @@ pub(crate) async fn sync_environment(
         &client,
         state.in_flight(),
         concurrency,
+        SizeBudget::default(),
         &build_dispatch,
         cache,
         &venv,
         logger,
         installer_metadata,
+        false,
         dry_run,
         printer,
     )
@@ -1996,13 +2026,18 @@ pub(crate) async fn update_environment(
         index_strategy,
         keyring_provider,
         resolution,
+        resolution_package,
         prerelease,
+        prerelease_package,
         fork_strategy,
         dependency_metadata,
         config_setting,
+        config_setting_package,
+        build_env,
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
+        exclude_newer_package,
         link_mode,
         compile_bytecode,
         sources,
@@ -2090,9 +2125,12 @@ pub(crate) async fn update_environment(
 
     let options = OptionsBuilder::new()
         .resolution_mode(*resolution)
+        .resolution_package(resolution_package.clone())
         .prerelease_mode(*prerelease)
+        .prerelease_package(prerelease_package.clone())
         .fork_strategy(*fork_strategy)
         .exclude_newer(*exclude_newer)
+        .exclude_newer_package(exclude_newer_package.clone())
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
         .build();
@@ -2131,6 +2169,8 @@ pub(crate) async fn update_environment(
         state.clone(),
         *index_strategy,
         config_setting,
+        config_setting_package,
+        build_env,
         build_isolation,
         *link_mode,
         build_options,
@@ -2139,7 +2179,8 @@ pub(crate) async fn update_environment(
         *sources,
         concurrency,
         preview,
-    );
+    )
+    .with_exclude_newer_package(exclude_newer_package.clone());
 
     // Resolve the requirements.
     let resolution = match pip::operations::resolve(
@@ -2166,6 +2207,7 @@ pub(crate) async fn update_environment(
         &build_dispatch,
         concurrency,
         options,
+        None,
         resolve,
         printer,
     )
@@ -2184,6 +2226,7 @@ pub(crate) async fn update_environment(
         build_options,
         *link_mode,
         *compile_bytecode,
+        true,
         index_locations,
         config_setting,
         &hasher,
@@ -2191,11 +2234,13 @@ pub(crate) async fn update_environment(
         &client,
         state.in_flight(),
         concurrency,
+        SizeBudget::default(),
         &build_dispatch,
         cache,
         &venv,
         install,
         installer_metadata,
+        false,
         dry_run,
         printer,
     )
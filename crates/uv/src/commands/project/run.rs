@@ -26,7 +26,7 @@ use uv_installer::{SatisfiesResult, SitePackages};
 use uv_normalize::PackageName;
 use uv_python::{
     EnvironmentPreference, Interpreter, PythonDownloads, PythonEnvironment, PythonInstallation,
-    PythonPreference, PythonRequest, PythonVersionFile, VersionFileDiscoveryOptions,
+    PythonPreference, PythonRequest, PythonVersionFile, Target, VersionFileDiscoveryOptions,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::Lock;
@@ -90,6 +90,7 @@ pub(crate) async fn run(
     printer: Printer,
     env_file: Vec<PathBuf>,
     no_env_file: bool,
+    pypackages: bool,
     preview: PreviewMode,
     max_recursion_depth: u32,
 ) -> anyhow::Result<ExitStatus> {
@@ -268,7 +269,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     return diagnostics::OperationDiagnostic::native_tls(native_tls)
                         .with_context("script")
                         .report(err)
-                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                        .map_err(Into::into)
                 }
                 Err(err) => return Err(err.into()),
             };
@@ -313,7 +314,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     return diagnostics::OperationDiagnostic::native_tls(native_tls)
                         .with_context("script")
                         .report(err)
-                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                        .map_err(Into::into)
                 }
                 Err(err) => return Err(err.into()),
             }
@@ -387,7 +388,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                         return diagnostics::OperationDiagnostic::native_tls(native_tls)
                             .with_context("script")
                             .report(err)
-                            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                            .map_err(Into::into)
                     }
                     Err(err) => return Err(err.into()),
                 }
@@ -684,7 +685,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     Err(ProjectError::Operation(err)) => {
                         return diagnostics::OperationDiagnostic::native_tls(native_tls)
                             .report(err)
-                            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                            .map_err(Into::into)
                     }
                     Err(err) => return Err(err.into()),
                 };
@@ -772,7 +773,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     Err(ProjectError::Operation(err)) => {
                         return diagnostics::OperationDiagnostic::native_tls(native_tls)
                             .report(err)
-                            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                            .map_err(Into::into)
                     }
                     Err(err) => return Err(err.into()),
                 }
@@ -909,7 +910,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     return diagnostics::OperationDiagnostic::native_tls(native_tls)
                         .with_context("`--with`")
                         .report(err)
-                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                        .map_err(Into::into)
                 }
                 Err(err) => return Err(err.into()),
             };
@@ -1017,23 +1018,31 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
     debug!("Running `{command}`");
     let mut process = command.as_command(interpreter);
 
+    // If requested, use the `__pypackages__` directory for the interpreter.
+    let pypackages = pypackages.then(|| Target::pypackages(project_dir, interpreter));
+
     // Construct the `PATH` environment variable.
     let new_path = std::env::join_paths(
-        ephemeral_env
+        pypackages
             .as_ref()
-            .map(PythonEnvironment::scripts)
+            .map(|target| target.scheme().scripts)
             .into_iter()
-            .chain(std::iter::once(base_interpreter.scripts()))
+            .chain(
+                ephemeral_env
+                    .as_ref()
+                    .map(|env| env.scripts().to_path_buf()),
+            )
+            .chain(std::iter::once(base_interpreter.scripts().to_path_buf()))
             .chain(
                 // On Windows, non-virtual Python distributions put `python.exe` in the top-level
                 // directory, rather than in the `Scripts` subdirectory.
                 cfg!(windows)
                     .then(|| base_interpreter.sys_executable().parent())
                     .flatten()
+                    .map(Path::to_path_buf)
                     .into_iter(),
             )
             .dedup()
-            .map(PathBuf::from)
             .chain(
                 std::env::var_os(EnvVars::PATH)
                     .as_ref()
@@ -1043,6 +1052,23 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
     )?;
     process.env(EnvVars::PATH, new_path);
 
+    // Add the `__pypackages__` directory to the `PYTHONPATH`.
+    if let Some(pypackages) = pypackages.as_ref() {
+        debug!(
+            "Using `__pypackages__` directory at {}",
+            pypackages.root().user_display()
+        );
+        let new_python_path = std::env::join_paths(
+            std::iter::once(pypackages.root().to_path_buf()).chain(
+                std::env::var_os(EnvVars::PYTHONPATH)
+                    .as_ref()
+                    .iter()
+                    .flat_map(std::env::split_paths),
+            ),
+        )?;
+        process.env(EnvVars::PYTHONPATH, new_python_path);
+    }
+
     // Increment recursion depth counter.
     process.env(
         EnvVars::UV_RUN_RECURSION_DEPTH,
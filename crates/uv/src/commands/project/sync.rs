@@ -23,6 +23,7 @@ use uv_normalize::PackageName;
 use uv_pep508::{MarkerTree, VersionOrUrl};
 use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl, ParsedUrl};
 use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_requirements::find_known_hashes;
 use uv_resolver::{FlatIndex, Installable};
 use uv_scripts::{Pep723ItemRef, Pep723Script};
 use uv_settings::PythonInstallMirrors;
@@ -34,6 +35,7 @@ use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace}
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
 use crate::commands::pip::operations;
 use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::size_budget::SizeBudget;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::{do_safe_lock, LockMode, LockResult};
 use crate::commands::project::lock_target::LockTarget;
@@ -303,7 +305,7 @@ pub(crate) async fn sync(
                 Err(ProjectError::Operation(err)) => {
                     return diagnostics::OperationDiagnostic::native_tls(native_tls)
                         .report(err)
-                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                        .map_err(Into::into)
                 }
                 Err(err) => return Err(err.into()),
             }
@@ -388,7 +390,7 @@ pub(crate) async fn sync(
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(native_tls)
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                .map_err(Into::into)
         }
         Err(err) => return Err(err.into()),
     };
@@ -476,7 +478,7 @@ pub(crate) async fn sync(
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(native_tls)
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                .map_err(Into::into)
         }
         Err(err) => return Err(err.into()),
     }
@@ -541,9 +543,12 @@ pub(super) async fn do_sync(
         keyring_provider,
         dependency_metadata,
         config_setting,
+        config_setting_package,
+        build_env,
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
+        exclude_newer_package,
         link_mode,
         compile_bytecode,
         reinstall,
@@ -656,8 +661,12 @@ pub(super) async fn do_sync(
     let build_constraints = Constraints::default();
     let build_hasher = HashStrategy::default();
 
+    // Read any known hashes from the project root, or the nearest ancestor that defines them.
+    let known_hashes = find_known_hashes(target.install_path())?;
+
     // Extract the hashes from the lockfile.
-    let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
+    let hasher =
+        HashStrategy::from_resolution(&resolution, &known_hashes, HashCheckingMode::Verify)?;
 
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
@@ -680,6 +689,8 @@ pub(super) async fn do_sync(
         state.clone().into_inner(),
         index_strategy,
         config_setting,
+        config_setting_package,
+        build_env,
         build_isolation,
         link_mode,
         build_options,
@@ -688,7 +699,8 @@ pub(super) async fn do_sync(
         sources,
         concurrency,
         preview,
-    );
+    )
+    .with_exclude_newer_package(exclude_newer_package.clone());
 
     let site_packages = SitePackages::from_environment(venv)?;
 
@@ -701,6 +713,7 @@ pub(super) async fn do_sync(
         build_options,
         link_mode,
         compile_bytecode,
+        true,
         index_locations,
         config_setting,
         &hasher,
@@ -708,11 +721,13 @@ pub(super) async fn do_sync(
         &client,
         state.in_flight(),
         concurrency,
+        SizeBudget::default(),
         &build_dispatch,
         cache,
         venv,
         logger,
         installer_metadata,
+        false,
         dry_run,
         printer,
     )
@@ -154,7 +154,7 @@ pub(crate) async fn tree(
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(native_tls)
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                .map_err(Into::into)
         }
         Err(err) => return Err(err.into()),
     };
@@ -192,13 +192,18 @@ pub(crate) async fn tree(
                 index_strategy: _,
                 keyring_provider,
                 resolution: _,
+                resolution_package: _,
                 prerelease: _,
+                prerelease_package: _,
                 fork_strategy: _,
                 dependency_metadata: _,
                 config_setting: _,
+                config_setting_package: _,
+                build_env: _,
                 no_build_isolation: _,
                 no_build_isolation_package: _,
                 exclude_newer: _,
+                exclude_newer_package: _,
                 link_mode: _,
                 upgrade: _,
                 build_options: _,
@@ -23,6 +23,36 @@ pub(crate) async fn find(
     python_preference: PythonPreference,
     cache: &Cache,
 ) -> Result<ExitStatus> {
+    let python = find_installation(
+        project_dir,
+        request,
+        no_project,
+        no_config,
+        system,
+        python_preference,
+        cache,
+    )
+    .await?;
+
+    println!(
+        "{}",
+        std::path::absolute(python.interpreter().sys_executable())?.simplified_display()
+    );
+
+    Ok(ExitStatus::Success)
+}
+
+/// Find the Python installation for a `python find`, `python inspect`, or `tags` invocation,
+/// warning if it is incompatible with the current workspace.
+pub(crate) async fn find_installation(
+    project_dir: &Path,
+    request: Option<String>,
+    no_project: bool,
+    no_config: bool,
+    system: bool,
+    python_preference: PythonPreference,
+    cache: &Cache,
+) -> Result<PythonInstallation> {
     let environment_preference = if system {
         EnvironmentPreference::OnlySystem
     } else {
@@ -78,10 +108,5 @@ pub(crate) async fn find(
         }
     };
 
-    println!(
-        "{}",
-        std::path::absolute(python.interpreter().sys_executable())?.simplified_display()
-    );
-
-    Ok(ExitStatus::Success)
+    Ok(python)
 }
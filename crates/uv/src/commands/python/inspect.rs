@@ -0,0 +1,105 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde::Serialize;
+
+use uv_cache::Cache;
+use uv_pep508::MarkerEnvironment;
+use uv_platform_tags::Platform;
+use uv_pypi_types::Scheme;
+use uv_python::{PointerSize, PythonPreference};
+
+use crate::commands::python::find::find_installation;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The probed information for a Python interpreter, as used by uv when resolving and installing
+/// packages.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Serialize)]
+struct InspectData<'a> {
+    executable: PathBuf,
+    implementation: &'a str,
+    version: String,
+    platform: &'a Platform,
+    markers: &'a MarkerEnvironment,
+    /// The compatible wheel tags, from highest to lowest priority.
+    tags: Vec<String>,
+    /// The installation scheme used for packages installed into the interpreter.
+    scheme: Scheme,
+    /// The installation scheme used for virtual environments created from the interpreter.
+    virtualenv: &'a Scheme,
+    sys_prefix: &'a Path,
+    sys_base_prefix: &'a Path,
+    sys_base_exec_prefix: &'a Path,
+    sys_base_executable: Option<&'a Path>,
+    sys_path: &'a [PathBuf],
+    stdlib: &'a Path,
+    pointer_size: PointerSize,
+    gil_disabled: bool,
+    manylinux_compatible: bool,
+    standalone: bool,
+    is_virtualenv: bool,
+    /// The `sysconfig` configuration variables that describe how native code is built for the
+    /// interpreter.
+    sysconfig_vars: &'a BTreeMap<String, serde_json::Value>,
+}
+
+/// Print the probed information for a Python interpreter as JSON.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn inspect(
+    project_dir: &Path,
+    request: Option<String>,
+    no_project: bool,
+    no_config: bool,
+    system: bool,
+    python_preference: PythonPreference,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let python = find_installation(
+        project_dir,
+        request,
+        no_project,
+        no_config,
+        system,
+        python_preference,
+        cache,
+    )
+    .await?;
+    let interpreter = python.interpreter();
+
+    let data = InspectData {
+        executable: std::path::absolute(interpreter.sys_executable())?,
+        implementation: interpreter.implementation_name(),
+        version: interpreter.python_full_version().to_string(),
+        platform: interpreter.platform(),
+        markers: interpreter.markers(),
+        tags: interpreter
+            .tags()?
+            .to_string()
+            .lines()
+            .map(ToString::to_string)
+            .collect(),
+        scheme: interpreter.layout().scheme,
+        virtualenv: interpreter.virtualenv(),
+        sys_prefix: interpreter.sys_prefix(),
+        sys_base_prefix: interpreter.sys_base_prefix(),
+        sys_base_exec_prefix: interpreter.sys_base_exec_prefix(),
+        sys_base_executable: interpreter.sys_base_executable(),
+        sys_path: interpreter.sys_path(),
+        stdlib: interpreter.stdlib(),
+        pointer_size: interpreter.pointer_size(),
+        gil_disabled: interpreter.gil_disabled(),
+        manylinux_compatible: interpreter.manylinux_compatible(),
+        standalone: interpreter.is_standalone(),
+        is_virtualenv: interpreter.is_virtualenv(),
+        sysconfig_vars: interpreter.sysconfig_vars(),
+    };
+
+    writeln!(printer.stdout(), "{}", serde_json::to_string_pretty(&data)?)?;
+
+    Ok(ExitStatus::Success)
+}
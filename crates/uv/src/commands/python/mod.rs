@@ -1,5 +1,6 @@
 pub(crate) mod dir;
 pub(crate) mod find;
+pub(crate) mod inspect;
 pub(crate) mod install;
 pub(crate) mod list;
 pub(crate) mod pin;
@@ -103,7 +103,8 @@ impl ProgressReporter {
             ProgressBar::with_draw_target(None, self.printer.target()),
         );
 
-        progress.set_style(ProgressStyle::with_template("{wide_msg}").unwrap());
+        // Display the latest line of build output alongside the package being built.
+        progress.set_style(ProgressStyle::with_template("{prefix} {wide_msg:.dim}").unwrap());
         let message = format!(
             "   {} {}",
             "Building".bold().cyan(),
@@ -112,7 +113,7 @@ impl ProgressReporter {
         if multi_progress.is_hidden() && !*HAS_UV_TEST_NO_CLI_PROGRESS {
             let _ = writeln!(self.printer.stderr(), "{message}");
         }
-        progress.set_message(message);
+        progress.set_prefix(message);
 
         state.headers += 1;
         state.bars.insert(id, progress);
@@ -142,9 +143,26 @@ impl ProgressReporter {
         if multi_progress.is_hidden() && !*HAS_UV_TEST_NO_CLI_PROGRESS {
             let _ = writeln!(self.printer.stderr(), "{message}");
         }
+        progress.set_style(ProgressStyle::with_template("{wide_msg}").unwrap());
         progress.finish_with_message(message);
     }
 
+    fn on_build_output(&self, id: usize, line: &str) {
+        let ProgressMode::Multi { state, .. } = &self.mode else {
+            return;
+        };
+
+        let state = state.lock().unwrap();
+        if let Some(progress) = state.bars.get(&id) {
+            // Build backends often emit colored output; strip it, as the line is rendered dimmed.
+            let line = anstream::adapter::strip_str(line).to_string();
+            let line = line.trim();
+            if !line.is_empty() {
+                progress.set_message(line.to_string());
+            }
+        }
+    }
+
     fn on_download_start(&self, name: String, size: Option<u64>) -> usize {
         let ProgressMode::Multi {
             multi_progress,
@@ -351,6 +369,10 @@ impl uv_installer::PrepareReporter for PrepareReporter {
         self.reporter.on_build_complete(source, id);
     }
 
+    fn on_build_output(&self, id: usize, line: &str) {
+        self.reporter.on_build_output(id, line);
+    }
+
     fn on_download_start(&self, name: &PackageName, size: Option<u64>) -> usize {
         self.reporter.on_download_start(name.to_string(), size)
     }
@@ -427,6 +449,10 @@ impl uv_resolver::ResolverReporter for ResolverReporter {
         self.reporter.on_build_complete(source, id);
     }
 
+    fn on_build_output(&self, id: usize, line: &str) {
+        self.reporter.on_build_output(id, line);
+    }
+
     fn on_checkout_start(&self, url: &Url, rev: &str) -> usize {
         self.reporter.on_checkout_start(url, rev)
     }
@@ -457,6 +483,10 @@ impl uv_distribution::Reporter for ResolverReporter {
         self.reporter.on_build_complete(source, id);
     }
 
+    fn on_build_output(&self, id: usize, line: &str) {
+        self.reporter.on_build_output(id, line);
+    }
+
     fn on_download_start(&self, name: &PackageName, size: Option<u64>) -> usize {
         self.reporter.on_download_start(name.to_string(), size)
     }
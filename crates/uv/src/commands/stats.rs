@@ -0,0 +1,180 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io::{BufRead, BufReader, ErrorKind};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_client::Stats;
+use uv_fs::Simplified;
+use uv_state::{StateBucket, StateStore};
+
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+/// The statistics recorded for a single invocation.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct StatsEntry {
+    /// The command that was invoked, e.g., `pip compile`.
+    command: String,
+    /// The time at which the invocation completed.
+    timestamp: jiff::Timestamp,
+    /// The duration of the invocation, in milliseconds.
+    elapsed_ms: u64,
+    #[serde(flatten)]
+    stats: Stats,
+}
+
+/// Return the path to the file in which statistics are recorded, one JSON entry per line.
+fn stats_file() -> Result<PathBuf> {
+    Ok(StateStore::from_settings(None)?
+        .bucket(StateBucket::Stats)
+        .join("stats.jsonl"))
+}
+
+/// Append the statistics for the current invocation to the local stats store.
+pub(crate) fn record_stats(command: String, stats: Stats, elapsed: Duration) -> Result<()> {
+    let store = StateStore::from_settings(None)?.init()?;
+    let bucket = store.bucket(StateBucket::Stats);
+    fs_err::create_dir_all(&bucket)?;
+
+    let entry = StatsEntry {
+        command,
+        timestamp: jiff::Timestamp::now(),
+        elapsed_ms: u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX),
+        stats,
+    };
+    let mut line = serde_json::to_string(&entry)?;
+    line.push('\n');
+
+    let mut file = fs_err::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(bucket.join("stats.jsonl"))?;
+    std::io::Write::write_all(&mut file, line.as_bytes())?;
+    Ok(())
+}
+
+/// Display the statistics recorded for previous invocations, aggregated by command.
+pub(crate) fn stats(clear: bool, printer: Printer) -> Result<ExitStatus> {
+    let path = stats_file()?;
+
+    if clear {
+        match fs_err::remove_file(&path) {
+            Ok(()) => {
+                writeln!(
+                    printer.stderr(),
+                    "Removed statistics at: {}",
+                    path.user_display().cyan()
+                )?;
+            }
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                writeln!(printer.stderr(), "No statistics to remove")?;
+            }
+            Err(err) => return Err(err.into()),
+        }
+        return Ok(ExitStatus::Success);
+    }
+
+    let file = match fs_err::File::open(&path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            writeln!(
+                printer.stderr(),
+                "No statistics have been recorded; set `{}` to record statistics for each invocation",
+                "UV_STATS=1".green()
+            )?;
+            return Ok(ExitStatus::Success);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Aggregate the entries by command.
+    let mut commands: BTreeMap<String, (u64, Stats)> = BTreeMap::new();
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.with_context(|| format!("Failed to read: `{}`", path.user_display()))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        // Skip any malformed entries (e.g., from an interrupted write), rather than failing.
+        let entry = match serde_json::from_str::<StatsEntry>(&line) {
+            Ok(entry) => entry,
+            Err(err) => {
+                debug!(
+                    "Skipping malformed entry on line {} of `{}`: {err}",
+                    index + 1,
+                    path.user_display()
+                );
+                continue;
+            }
+        };
+        let (runs, stats) = commands.entry(entry.command).or_default();
+        *runs += 1;
+        *stats += entry.stats;
+    }
+
+    let rows = commands
+        .iter()
+        .map(|(command, (runs, stats))| {
+            let hits = match stats.cache_hit_rate() {
+                Some(rate) => format!("{:.1}%", rate * 100.0),
+                None => "-".to_string(),
+            };
+            let (bytes, unit) = human_readable_bytes(stats.bytes_downloaded);
+            [
+                command.clone(),
+                runs.to_string(),
+                hits,
+                format!("{bytes:.1}{unit}"),
+                stats.builds.to_string(),
+                stats.backtracks.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let header = [
+        "command",
+        "runs",
+        "cache hits",
+        "downloaded",
+        "builds",
+        "backtracks",
+    ];
+    let widths = header.map(str::len);
+    let widths = rows.iter().fold(widths, |mut widths, row| {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+        widths
+    });
+
+    // Left-align the command, and right-align the numeric columns.
+    let format_row = |row: [&str; 6]| {
+        row.iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(column, (cell, width))| {
+                if column == 0 {
+                    format!("{cell:<width$}")
+                } else {
+                    format!("{cell:>width$}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+    };
+
+    writeln!(printer.stdout(), "{}", format_row(header).bold())?;
+    for row in &rows {
+        writeln!(
+            printer.stdout(),
+            "{}",
+            format_row([&row[0], &row[1], &row[2], &row[3], &row[4], &row[5]])
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,57 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+
+use uv_cache::Cache;
+use uv_cli::TagsFormat;
+use uv_configuration::TargetTriple;
+use uv_python::{PythonPreference, PythonVersion};
+
+use crate::commands::pip::resolution_tags;
+use crate::commands::python::find::find_installation;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Display the wheel tags supported by a Python interpreter, from highest to lowest priority.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn tags(
+    project_dir: &Path,
+    python: Option<String>,
+    python_version: Option<&PythonVersion>,
+    python_platform: Option<&TargetTriple>,
+    no_config: bool,
+    system: bool,
+    python_preference: PythonPreference,
+    output_format: TagsFormat,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let installation = find_installation(
+        project_dir,
+        python,
+        false,
+        no_config,
+        system,
+        python_preference,
+        cache,
+    )
+    .await?;
+    let interpreter = installation.interpreter();
+
+    // Determine the tags, overriding the Python version and platform if requested.
+    let tags = resolution_tags(python_version, python_platform, interpreter)?;
+    let tags = tags.to_string();
+
+    match output_format {
+        TagsFormat::Text => {
+            write!(printer.stdout(), "{tags}")?;
+        }
+        TagsFormat::Json => {
+            let tags = tags.lines().collect::<Vec<_>>();
+            writeln!(printer.stdout(), "{}", serde_json::to_string_pretty(&tags)?)?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -8,7 +8,9 @@ use tracing::{debug, trace};
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
 use uv_client::{BaseClientBuilder, Connectivity};
-use uv_configuration::{Concurrency, DryRun, PreviewMode, Reinstall, TrustedHost, Upgrade};
+use uv_configuration::{
+    Concurrency, DryRun, EmbeddedIndexStrategy, PreviewMode, Reinstall, TrustedHost, Upgrade,
+};
 use uv_distribution_types::{NameRequirementSpecification, UnresolvedRequirementSpecification};
 use uv_normalize::PackageName;
 use uv_pep440::{VersionSpecifier, VersionSpecifiers};
@@ -232,9 +234,14 @@ pub(crate) async fn install(
     };
 
     // Read the `--with` requirements.
-    let spec =
-        RequirementsSpecification::from_sources(with, constraints, overrides, &client_builder)
-            .await?;
+    let spec = RequirementsSpecification::from_sources(
+        with,
+        constraints,
+        overrides,
+        EmbeddedIndexStrategy::default(),
+        &client_builder,
+    )
+    .await?;
 
     // Resolve the `--from` and `--with` requirements.
     let requirements = {
@@ -420,7 +427,7 @@ pub(crate) async fn install(
             Err(ProjectError::Operation(err)) => {
                 return diagnostics::OperationDiagnostic::native_tls(native_tls)
                     .report(err)
-                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                    .map_err(Into::into)
             }
             Err(err) => return Err(err.into()),
         };
@@ -480,7 +487,7 @@ pub(crate) async fn install(
                     .flatten() else {
                         return diagnostics::OperationDiagnostic::native_tls(native_tls)
                             .report(err)
-                            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                            .map_err(Into::into);
                     };
 
                     debug!(
@@ -509,7 +516,7 @@ pub(crate) async fn install(
                         Err(ProjectError::Operation(err)) => {
                             return diagnostics::OperationDiagnostic::native_tls(native_tls)
                                 .report(err)
-                                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                                .map_err(Into::into);
                         }
                         Err(err) => return Err(err.into()),
                     }
@@ -553,7 +560,7 @@ pub(crate) async fn install(
             Err(ProjectError::Operation(err)) => {
                 return diagnostics::OperationDiagnostic::native_tls(native_tls)
                     .report(err)
-                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                    .map_err(Into::into)
             }
             Err(err) => return Err(err.into()),
         }
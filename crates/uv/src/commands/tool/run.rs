@@ -141,7 +141,7 @@ pub(crate) async fn run(
             return diagnostics::OperationDiagnostic::native_tls(native_tls)
                 .with_context("tool")
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                .map_err(Into::into)
         }
         Err(ProjectError::Requirements(err)) => {
             let err = miette::Report::msg(format!("{err}"))
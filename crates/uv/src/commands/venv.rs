@@ -14,7 +14,8 @@ use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, Constraints, IndexStrategy, KeyringProviderType,
-    NoBinary, NoBuild, PreviewMode, SourceStrategy, TrustedHost,
+    NoBinary, NoBuild, PackageBuildEnv, PackageConfigSettings, PreviewMode, SourceStrategy,
+    TrustedHost,
 };
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution_types::{DependencyMetadata, Index, IndexLocations};
@@ -22,7 +23,8 @@ use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_pypi_types::Requirement;
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest,
 };
 use uv_resolver::{ExcludeNewer, FlatIndex};
 use uv_settings::PythonInstallMirrors;
@@ -328,6 +330,8 @@ async fn venv_impl(
         let build_constraints = Constraints::default();
         let build_hasher = HashStrategy::default();
         let config_settings = ConfigSettings::default();
+        let config_settings_package = PackageConfigSettings::default();
+        let build_env = PackageBuildEnv::default();
         let sources = SourceStrategy::Disabled;
 
         // Do not allow builds
@@ -345,6 +349,8 @@ async fn venv_impl(
             state.clone(),
             index_strategy,
             &config_settings,
+            &config_settings_package,
+            &build_env,
             BuildIsolation::Isolated,
             link_mode,
             &build_options,
@@ -391,7 +397,18 @@ async fn venv_impl(
     }
 
     // Determine the appropriate activation command.
-    let activation = match Shell::from_env() {
+    let activation = activation_command(&venv);
+    if let Some(act) = activation {
+        writeln!(printer.stderr(), "Activate with: {}", act.green()).into_diagnostic()?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Determine the command to activate the given virtual environment in the current shell, if the
+/// shell can be detected.
+pub(crate) fn activation_command(venv: &PythonEnvironment) -> Option<String> {
+    match Shell::from_env() {
         None => None,
         Some(Shell::Bash | Shell::Zsh | Shell::Ksh) => Some(format!(
             "source {}",
@@ -414,10 +431,5 @@ async fn venv_impl(
             Shell::Powershell,
         )),
         Some(Shell::Cmd) => Some(shlex_windows(venv.scripts().join("activate"), Shell::Cmd)),
-    };
-    if let Some(act) = activation {
-        writeln!(printer.stderr(), "Activate with: {}", act.green()).into_diagnostic()?;
     }
-
-    Ok(ExitStatus::Success)
 }
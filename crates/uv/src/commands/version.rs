@@ -1,6 +1,13 @@
-use anyhow::Result;
+use std::cmp::Ordering;
+use std::io::BufRead;
+use std::str::FromStr;
 
-use uv_cli::VersionFormat;
+use anyhow::{Context, Result};
+
+use uv_cli::{VersionCompareArgs, VersionFormat, VersionSortArgs};
+use uv_pep440::{Version, VersionSpecifiers};
+
+use crate::commands::ExitStatus;
 
 /// Display version information
 pub(crate) fn version(output_format: VersionFormat, buffer: &mut dyn std::io::Write) -> Result<()> {
@@ -18,3 +25,121 @@ pub(crate) fn version(output_format: VersionFormat, buffer: &mut dyn std::io::Wr
     };
     Ok(())
 }
+
+/// Compare a version against a version specifier or another version.
+///
+/// In JSON mode, displays `{"ordering": "<"}` for a version, and `{"satisfied": true}` for a
+/// specifier.
+pub(crate) fn version_compare(
+    args: &VersionCompareArgs,
+    output_format: VersionFormat,
+    buffer: &mut dyn std::io::Write,
+) -> Result<ExitStatus> {
+    let version = Version::from_str(&args.version)
+        .with_context(|| format!("Failed to parse version: `{}`", args.version))?;
+
+    // If the right-hand side is a plain version, display the ordering.
+    if let Ok(other) = Version::from_str(&args.other) {
+        let ordering = match version.cmp(&other) {
+            Ordering::Less => "<",
+            Ordering::Equal => "==",
+            Ordering::Greater => ">",
+        };
+        match output_format {
+            VersionFormat::Text => writeln!(buffer, "{ordering}")?,
+            VersionFormat::Json => {
+                write_json(buffer, &serde_json::json!({ "ordering": ordering }))?;
+            }
+        }
+        return Ok(ExitStatus::Success);
+    }
+
+    // Otherwise, check whether the version satisfies the specifier.
+    let specifiers = VersionSpecifiers::from_str(&args.other)
+        .with_context(|| format!("Failed to parse version specifier: `{}`", args.other))?;
+    let satisfied = specifiers.contains(&version);
+    match output_format {
+        VersionFormat::Text => writeln!(buffer, "{satisfied}")?,
+        VersionFormat::Json => {
+            write_json(buffer, &serde_json::json!({ "satisfied": satisfied }))?;
+        }
+    }
+    if satisfied {
+        Ok(ExitStatus::Success)
+    } else {
+        Ok(ExitStatus::Failure)
+    }
+}
+
+/// Sort versions in PEP 440 order.
+///
+/// In JSON mode, displays the sorted versions as an array of strings.
+pub(crate) fn version_sort(
+    args: VersionSortArgs,
+    output_format: VersionFormat,
+    buffer: &mut dyn std::io::Write,
+) -> Result<ExitStatus> {
+    let VersionSortArgs {
+        versions,
+        reverse,
+        filter,
+    } = args;
+
+    // If no versions were provided, read them from stdin.
+    let versions = if versions.is_empty() {
+        std::io::stdin()
+            .lock()
+            .lines()
+            .map(|line| line.map(|line| line.trim().to_string()))
+            .filter(|line| !line.as_ref().is_ok_and(String::is_empty))
+            .collect::<Result<Vec<_>, _>>()?
+    } else {
+        versions
+    };
+
+    let filter = filter
+        .as_deref()
+        .map(VersionSpecifiers::from_str)
+        .transpose()
+        .context("Failed to parse version specifier")?;
+
+    // Retain the original strings, such that the output matches the input verbatim.
+    let mut versions = versions
+        .into_iter()
+        .map(|raw| {
+            let version = Version::from_str(&raw)
+                .with_context(|| format!("Failed to parse version: `{raw}`"))?;
+            Ok((version, raw))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if let Some(filter) = filter {
+        versions.retain(|(version, _)| filter.contains(version));
+    }
+
+    versions.sort_by(|(a, _), (b, _)| a.cmp(b));
+    if reverse {
+        versions.reverse();
+    }
+
+    match output_format {
+        VersionFormat::Text => {
+            for (_, raw) in versions {
+                writeln!(buffer, "{raw}")?;
+            }
+        }
+        VersionFormat::Json => {
+            let versions = versions.into_iter().map(|(_, raw)| raw).collect::<Vec<_>>();
+            write_json(buffer, &versions)?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Write a value as pretty-printed JSON, followed by a trailing newline.
+fn write_json(buffer: &mut dyn std::io::Write, value: &impl serde::Serialize) -> Result<()> {
+    serde_json::to_writer_pretty(&mut *buffer, value)?;
+    writeln!(buffer)?;
+    Ok(())
+}
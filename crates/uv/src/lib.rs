@@ -10,7 +10,7 @@ use std::sync::atomic::Ordering;
 use anstream::eprintln;
 use anyhow::{bail, Context, Result};
 use clap::error::{ContextKind, ContextValue};
-use clap::{CommandFactory, Parser};
+use clap::{CommandFactory, FromArgMatches};
 use futures::FutureExt;
 use owo_colors::OwoColorize;
 use settings::PipTreeSettings;
@@ -22,7 +22,9 @@ use uv_cli::{
     compat::CompatArgs, BuildBackendCommand, CacheCommand, CacheNamespace, Cli, Commands,
     PipCommand, PipNamespace, ProjectCommand,
 };
-use uv_cli::{PythonCommand, PythonNamespace, ToolCommand, ToolNamespace, TopLevelArgs};
+use uv_cli::{
+    PythonCommand, PythonNamespace, ToolCommand, ToolNamespace, TopLevelArgs, VersionCommand,
+};
 #[cfg(feature = "self-update")]
 use uv_cli::{SelfCommand, SelfNamespace, SelfUpdateArgs};
 use uv_fs::{Simplified, CWD};
@@ -37,8 +39,9 @@ use crate::commands::{ExitStatus, RunCommand, ScriptPath, ToolRunCommand};
 use crate::printer::Printer;
 use crate::settings::{
     CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipFreezeSettings,
-    PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings, PipUninstallSettings,
-    PublishSettings,
+    PipInstallSettings, PipLintSettings, PipListSettings, PipMergeSettings,
+    PipRefreshEditablesSettings, PipShowSettings, PipSyncSettings, PipUninstallSettings,
+    PipVerifySettings, PrintDownloads, PublishSettings,
 };
 
 pub(crate) mod commands;
@@ -47,7 +50,9 @@ pub(crate) mod printer;
 pub(crate) mod settings;
 
 #[instrument(skip_all)]
-async fn run(mut cli: Cli) -> Result<ExitStatus> {
+async fn run(mut cli: Cli, command_name: String) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
     // Enable flag to pick up warnings generated by workspace loading.
     if !cli.top_level.global_args.quiet {
         uv_warnings::enable();
@@ -308,6 +313,23 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
 
     anstream::ColorChoice::write_global(globals.color.into());
 
+    // Configure the download log, which records every URL fetched during the command.
+    let print_downloads = globals.print_downloads.clone();
+    if print_downloads.is_some() {
+        uv_client::enable_download_log();
+    }
+
+    // Configure the statistics, which are recorded for every command other than `uv stats`.
+    let record_stats = globals.stats && !matches!(&*cli.command, Commands::Stats(_));
+    if record_stats {
+        uv_client::enable_stats();
+    }
+
+    // Configure the request budget for each host.
+    if let Some(max_requests_per_second) = globals.max_requests_per_second {
+        uv_client::set_max_requests_per_second(max_requests_per_second);
+    }
+
     miette::set_hook(Box::new(|_| {
         Box::new(
             miette::MietteHandlerOpts::new()
@@ -364,11 +386,14 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             show_settings!(args);
 
             // Initialize the cache.
-            let cache = cache.init()?.with_refresh(
-                args.refresh
-                    .combine(Refresh::from(args.settings.reinstall.clone()))
-                    .combine(Refresh::from(args.settings.upgrade.clone())),
-            );
+            let cache = cache
+                .init()?
+                .with_refresh(
+                    args.refresh
+                        .combine(Refresh::from(args.settings.reinstall.clone()))
+                        .combine(Refresh::from(args.settings.upgrade.clone())),
+                )
+                .with_rebuild(args.rebuild);
 
             let requirements = args
                 .src_file
@@ -403,12 +428,29 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.extras,
                 args.settings.groups,
                 args.settings.output_file.as_deref(),
+                args.format,
+                args.output_per_extra,
+                args.verify_determinism,
+                args.seed,
+                args.resume,
+                args.allowed_packages.as_deref(),
+                args.minimal_changes,
+                args.report_bounds,
+                args.normalize_specifiers,
+                args.show_derivation_tree,
+                args.explain,
+                args.constraint_from_env,
+                args.sign.as_deref(),
+                args.emit_graph.as_deref(),
                 args.settings.resolution,
+                args.settings.resolution_package,
                 args.settings.prerelease,
+                args.settings.prerelease_package,
                 args.settings.fork_strategy,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
                 args.settings.generate_hashes,
+                args.settings.only_hashed,
                 args.settings.no_emit_package,
                 args.settings.no_strip_extras,
                 args.settings.no_strip_markers,
@@ -420,20 +462,36 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.emit_build_options,
                 args.settings.emit_marker_expression,
                 args.settings.emit_index_annotation,
+                args.settings.emit_requires_python,
                 args.settings.index_locations,
                 args.settings.index_strategy,
+                args.settings.embedded_index_strategy,
+                args.settings.invalid_metadata,
+                args.settings.yanked,
+                args.settings.index_unavailable,
+                args.settings.wheel_preference,
+                args.settings.local_version_preference,
+                args.settings.resolution_timeout,
+                args.settings.max_backtracks,
+                args.settings.source_policy,
+                args.settings.provided_by,
                 args.settings.dependency_metadata,
                 args.settings.keyring_provider,
                 &globals.allow_insecure_host,
                 args.settings.config_setting,
+                args.settings.config_setting_package,
+                args.settings.build_env,
                 globals.connectivity,
                 args.settings.no_build_isolation,
                 args.settings.no_build_isolation_package,
                 args.settings.build_options,
                 args.settings.python_version,
+                args.python_versions,
                 args.settings.python_platform,
+                args.settings.features,
                 args.settings.universal,
                 args.settings.exclude_newer,
+                args.settings.exclude_newer_package,
                 args.settings.sources,
                 args.settings.annotation_style,
                 args.settings.link_mode,
@@ -459,11 +517,14 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             show_settings!(args);
 
             // Initialize the cache.
-            let cache = cache.init()?.with_refresh(
-                args.refresh
-                    .combine(Refresh::from(args.settings.reinstall.clone()))
-                    .combine(Refresh::from(args.settings.upgrade.clone())),
-            );
+            let cache = cache
+                .init()?
+                .with_refresh(
+                    args.refresh
+                        .combine(Refresh::from(args.settings.reinstall.clone()))
+                        .combine(Refresh::from(args.settings.upgrade.clone())),
+                )
+                .with_rebuild(args.rebuild);
 
             let requirements = args
                 .src_file
@@ -485,35 +546,61 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &requirements,
                 &constraints,
                 &build_constraints,
+                args.only,
                 args.settings.reinstall,
+                args.reinstall_vcs,
+                args.reinstall_local,
                 args.settings.link_mode,
                 args.settings.compile_bytecode,
                 args.settings.hash_checking,
                 args.settings.index_locations,
                 args.settings.index_strategy,
+                args.settings.embedded_index_strategy,
+                args.settings.invalid_metadata,
+                args.settings.yanked,
+                args.settings.index_unavailable,
+                args.settings.wheel_preference,
+                args.settings.local_version_preference,
+                args.settings.resolution_timeout,
+                args.settings.max_backtracks,
+                args.settings.source_policy,
+                args.settings.provided_by,
+                args.settings.only_hashed,
+                args.settings.max_total_download_size,
+                args.settings.max_package_size,
                 args.settings.dependency_metadata,
                 args.settings.keyring_provider,
                 args.settings.allow_empty_requirements,
                 globals.installer_metadata,
                 globals.connectivity,
                 &args.settings.config_setting,
+                &args.settings.config_setting_package,
+                &args.settings.build_env,
                 args.settings.no_build_isolation,
                 args.settings.no_build_isolation_package,
                 args.settings.build_options,
                 args.settings.python_version,
                 args.settings.python_platform,
+                args.settings.features,
                 args.settings.strict,
+                args.settings.check_abi,
                 args.settings.exclude_newer,
+                args.settings.exclude_newer_package,
                 args.settings.python,
+                args.environment.as_deref(),
+                args.verify_signature.as_deref(),
+                args.audit_log,
                 args.settings.system,
                 args.settings.break_system_packages,
                 args.settings.target,
                 args.settings.prefix,
+                args.pypackages,
                 args.settings.sources,
                 globals.python_preference,
                 globals.concurrency,
                 globals.native_tls,
                 &globals.allow_insecure_host,
+                &project_dir,
                 cache,
                 args.dry_run,
                 printer,
@@ -531,14 +618,20 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             show_settings!(args);
 
             // Initialize the cache.
-            let cache = cache.init()?.with_refresh(
-                args.refresh
-                    .combine(Refresh::from(args.settings.reinstall.clone()))
-                    .combine(Refresh::from(args.settings.upgrade.clone())),
-            );
+            let cache = cache
+                .init()?
+                .with_refresh(
+                    args.refresh
+                        .combine(Refresh::from(args.settings.reinstall.clone()))
+                        .combine(Refresh::from(args.settings.upgrade.clone())),
+                )
+                .with_rebuild(args.rebuild);
 
             let mut requirements = Vec::with_capacity(
-                args.package.len() + args.editables.len() + args.requirements.len(),
+                args.package.len()
+                    + args.editables.len()
+                    + args.requirements.len()
+                    + args.with.len(),
             );
             for package in args.package {
                 requirements.push(RequirementsSource::from_package(package)?);
@@ -549,6 +642,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                     .into_iter()
                     .map(RequirementsSource::from_requirements_file),
             );
+            for package in args.with {
+                requirements.push(RequirementsSource::from_package(package)?);
+            }
             let constraints = args
                 .constraints
                 .into_iter()
@@ -576,40 +672,73 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &args.settings.extras,
                 &args.settings.groups,
                 args.settings.resolution,
+                args.settings.resolution_package,
                 args.settings.prerelease,
+                args.settings.prerelease_package,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
                 args.settings.index_locations,
                 args.settings.index_strategy,
+                args.settings.embedded_index_strategy,
+                args.settings.invalid_metadata,
+                args.settings.yanked,
+                args.settings.index_unavailable,
+                args.settings.wheel_preference,
+                args.settings.local_version_preference,
+                args.settings.resolution_timeout,
+                args.settings.max_backtracks,
+                args.settings.source_policy,
+                args.settings.provided_by,
+                args.settings.only_hashed,
+                args.settings.max_total_download_size,
+                args.settings.max_package_size,
                 args.settings.dependency_metadata,
                 args.settings.keyring_provider,
                 args.settings.reinstall,
+                args.reinstall_vcs,
+                args.reinstall_local,
                 args.settings.link_mode,
                 args.settings.compile_bytecode,
                 args.settings.hash_checking,
                 globals.installer_metadata,
                 globals.connectivity,
                 &args.settings.config_setting,
+                &args.settings.config_setting_package,
+                &args.settings.build_env,
                 args.settings.no_build_isolation,
                 args.settings.no_build_isolation_package,
                 args.settings.build_options,
                 args.modifications,
                 args.settings.python_version,
                 args.settings.python_platform,
+                args.settings.features,
                 args.settings.strict,
+                args.settings.check_abi,
                 args.settings.exclude_newer,
+                args.settings.exclude_newer_package,
                 args.settings.sources,
                 args.settings.python,
+                args.environment.as_deref(),
                 args.settings.system,
                 args.settings.break_system_packages,
                 args.settings.target,
                 args.settings.prefix,
+                args.pypackages,
+                args.create_venv,
+                &args.install_mirrors,
                 globals.python_preference,
+                globals.python_downloads,
+                cli.top_level.no_config,
                 globals.concurrency,
                 globals.native_tls,
                 &globals.allow_insecure_host,
+                &project_dir,
                 cache,
                 args.dry_run,
+                args.build_log_dir.as_deref(),
+                args.optimize_wheels,
+                args.show_derivation_tree,
+                args.explain,
                 printer,
                 globals.preview,
             )
@@ -637,6 +766,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::pip_uninstall(
                 &sources,
                 args.settings.python,
+                args.environment.as_deref(),
                 args.settings.system,
                 args.settings.break_system_packages,
                 args.settings.target,
@@ -665,6 +795,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.exclude_editable,
                 args.settings.strict,
                 args.settings.python.as_deref(),
+                args.environment.as_deref(),
                 args.settings.system,
                 args.paths,
                 &cache,
@@ -698,6 +829,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.strict,
                 args.settings.exclude_newer,
                 args.settings.python.as_deref(),
+                args.environment.as_deref(),
                 args.settings.system,
                 globals.native_tls,
                 &cache,
@@ -776,6 +908,165 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 printer,
             )
         }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Lint(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipLintSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            let requirements = args
+                .src_file
+                .into_iter()
+                .map(RequirementsSource::from_requirements_file)
+                .collect::<Vec<_>>();
+            let constraints = args
+                .constraints
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Vec<_>>();
+
+            commands::pip_lint(
+                &requirements,
+                &constraints,
+                args.fix,
+                args.settings.index_locations,
+                args.settings.index_strategy,
+                args.settings.keyring_provider,
+                args.settings.dependency_metadata,
+                args.settings.build_options,
+                args.settings.exclude_newer,
+                args.settings.python.as_deref(),
+                args.settings.system,
+                globals.connectivity,
+                globals.concurrency,
+                globals.native_tls,
+                &globals.allow_insecure_host,
+                &cache,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Merge(args),
+        }) => {
+            // Resolve the settings from the command-line arguments.
+            let args = PipMergeSettings::resolve(args);
+            show_settings!(args);
+
+            let requirements = args
+                .src_file
+                .into_iter()
+                .map(RequirementsSource::from_requirements_file)
+                .collect::<Vec<_>>();
+
+            commands::pip_merge(
+                &requirements,
+                args.output_file.as_deref(),
+                globals.connectivity,
+                globals.native_tls,
+                &globals.allow_insecure_host,
+                printer,
+            )
+            .await
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::RefreshEditables(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipRefreshEditablesSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache
+                .init()?
+                .with_refresh(args.refresh)
+                .with_rebuild(args.rebuild);
+
+            commands::pip_refresh_editables(
+                &args.package,
+                args.settings.link_mode,
+                args.settings.compile_bytecode,
+                args.settings.index_locations,
+                args.settings.index_strategy,
+                args.settings.dependency_metadata,
+                args.settings.keyring_provider,
+                globals.installer_metadata,
+                globals.connectivity,
+                &args.settings.config_setting,
+                &args.settings.config_setting_package,
+                &args.settings.build_env,
+                args.settings.no_build_isolation,
+                args.settings.no_build_isolation_package,
+                args.settings.build_options,
+                args.settings.exclude_newer,
+                args.settings.sources,
+                args.settings.python,
+                args.environment.as_deref(),
+                args.settings.system,
+                args.settings.break_system_packages,
+                globals.python_preference,
+                globals.concurrency,
+                globals.native_tls,
+                &globals.allow_insecure_host,
+                cache,
+                args.dry_run,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Verify(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipVerifySettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache
+                .init()?
+                .with_refresh(args.refresh)
+                .with_rebuild(args.rebuild);
+
+            commands::pip_verify(
+                &args.package,
+                args.repair,
+                args.remove_extra,
+                args.settings.link_mode,
+                args.settings.compile_bytecode,
+                args.settings.index_locations,
+                args.settings.index_strategy,
+                args.settings.dependency_metadata,
+                args.settings.keyring_provider,
+                globals.installer_metadata,
+                globals.connectivity,
+                &args.settings.config_setting,
+                &args.settings.config_setting_package,
+                &args.settings.build_env,
+                args.settings.no_build_isolation,
+                args.settings.no_build_isolation_package,
+                args.settings.build_options,
+                args.settings.exclude_newer,
+                args.settings.sources,
+                args.settings.python,
+                args.environment.as_deref(),
+                args.settings.system,
+                args.settings.break_system_packages,
+                globals.python_preference,
+                globals.concurrency,
+                globals.native_tls,
+                &globals.allow_insecure_host,
+                cache,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Clean(args),
         })
@@ -789,6 +1080,22 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             show_settings!(args);
             commands::cache_prune(args.ci, &cache, printer)
         }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::GcMetadata(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_gc_metadata(args.all, &cache, printer)
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Add(args),
+        }) => {
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::cache_add(&args.paths, &cache, printer).await
+        }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
         }) => {
@@ -801,10 +1108,13 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             show_settings!(args);
 
             // Initialize the cache.
-            let cache = cache.init()?.with_refresh(
-                args.refresh
-                    .combine(Refresh::from(args.settings.upgrade.clone())),
-            );
+            let cache = cache
+                .init()?
+                .with_refresh(
+                    args.refresh
+                        .combine(Refresh::from(args.settings.upgrade.clone())),
+                )
+                .with_rebuild(args.rebuild);
 
             // Resolve the build constraints.
             let build_constraints = args
@@ -858,11 +1168,14 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             show_settings!(args);
 
             // Initialize the cache.
-            let cache = cache.init()?.with_refresh(
-                args.refresh
-                    .combine(Refresh::from(args.settings.reinstall.clone()))
-                    .combine(Refresh::from(args.settings.upgrade.clone())),
-            );
+            let cache = cache
+                .init()?
+                .with_refresh(
+                    args.refresh
+                        .combine(Refresh::from(args.settings.reinstall.clone()))
+                        .combine(Refresh::from(args.settings.upgrade.clone())),
+                )
+                .with_rebuild(args.rebuild);
 
             // Since we use ".venv" as the default name, we use "." as the default prompt.
             let prompt = args.prompt.or_else(|| {
@@ -932,7 +1245,64 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 is not available. Please use your package manager to update uv."
             );
         }
-        Commands::Version { output_format } => {
+        Commands::Doctor(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::DoctorSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // The cache is initialized by the command itself, to report any failures.
+            commands::doctor(
+                &project_dir,
+                args.settings.python,
+                args.settings.system,
+                &args.settings.index_locations,
+                args.settings.keyring_provider,
+                args.settings.link_mode,
+                &globals.allow_insecure_host,
+                globals.connectivity,
+                globals.native_tls,
+                globals.python_preference,
+                cli.top_level.no_config,
+                cache,
+                printer,
+            )
+            .await
+        }
+        Commands::Stats(args) => commands::stats(args.clear, printer),
+        Commands::Tags(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::TagsSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::tags(
+                &project_dir,
+                args.python,
+                args.python_version.as_ref(),
+                args.python_platform.as_ref(),
+                cli.top_level.no_config,
+                args.system,
+                globals.python_preference,
+                args.output_format,
+                &cache,
+                printer,
+            )
+            .await
+        }
+        Commands::Version {
+            command: Some(VersionCommand::Compare(args)),
+            output_format,
+        } => commands::version_compare(&args, output_format, &mut stdout()),
+        Commands::Version {
+            command: Some(VersionCommand::Sort(args)),
+            output_format,
+        } => commands::version_sort(args, output_format, &mut stdout()),
+        Commands::Version {
+            command: None,
+            output_format,
+        } => {
             commands::version(output_format, &mut stdout())?;
             Ok(ExitStatus::Success)
         }
@@ -979,11 +1349,14 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             show_settings!(args);
 
             // Initialize the cache.
-            let cache = cache.init()?.with_refresh(
-                args.refresh
-                    .combine(Refresh::from(args.settings.reinstall.clone()))
-                    .combine(Refresh::from(args.settings.upgrade.clone())),
-            );
+            let cache = cache
+                .init()?
+                .with_refresh(
+                    args.refresh
+                        .combine(Refresh::from(args.settings.reinstall.clone()))
+                        .combine(Refresh::from(args.settings.upgrade.clone())),
+                )
+                .with_rebuild(args.rebuild);
 
             let mut requirements = Vec::with_capacity(
                 args.with.len() + args.with_editable.len() + args.with_requirements.len(),
@@ -1033,11 +1406,14 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             show_settings!(args);
 
             // Initialize the cache.
-            let cache = cache.init()?.with_refresh(
-                args.refresh
-                    .combine(Refresh::from(args.settings.reinstall.clone()))
-                    .combine(Refresh::from(args.settings.upgrade.clone())),
-            );
+            let cache = cache
+                .init()?
+                .with_refresh(
+                    args.refresh
+                        .combine(Refresh::from(args.settings.reinstall.clone()))
+                        .combine(Refresh::from(args.settings.upgrade.clone())),
+                )
+                .with_rebuild(args.rebuild);
 
             let mut requirements = Vec::with_capacity(
                 args.with.len() + args.with_editable.len() + args.with_requirements.len(),
@@ -1250,6 +1626,27 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             )
             .await
         }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::Inspect(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonFindSettings::resolve(args, filesystem);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::python_inspect(
+                &project_dir,
+                args.request,
+                args.no_project,
+                cli.top_level.no_config,
+                args.system,
+                globals.python_preference,
+                &cache,
+                printer,
+            )
+            .await
+        }
         Commands::Python(PythonNamespace {
             command: PythonCommand::Pin(args),
         }) => {
@@ -1395,9 +1792,42 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         .await
         .expect("tokio threadpool exited unexpectedly"),
     };
+
+    // Write out the log of fetched URLs, even if the command failed.
+    if let Some(print_downloads) = print_downloads {
+        let mut downloads = String::new();
+        for download in uv_client::downloads() {
+            writeln!(downloads, "{download}")?;
+        }
+        match print_downloads {
+            PrintDownloads::Stderr => anstream::eprint!("{downloads}"),
+            PrintDownloads::File(path) => fs_err::write(&path, downloads)?,
+        }
+    }
+
+    // Record the statistics for the command, even if the command failed.
+    if record_stats {
+        if let Some(stats) = uv_client::stats() {
+            if let Err(err) = commands::record_stats(command_name, stats, start.elapsed()) {
+                debug!("Failed to record statistics: {err}");
+            }
+        }
+    }
+
     result
 }
 
+/// Return the name of the invoked command, e.g., `pip compile`, for use in the recorded statistics.
+fn command_name(matches: &clap::ArgMatches) -> String {
+    let mut names = Vec::new();
+    let mut matches = matches;
+    while let Some((name, subcommand)) = matches.subcommand() {
+        names.push(name);
+        matches = subcommand;
+    }
+    names.join(" ")
+}
+
 /// Run a [`ProjectCommand`].
 async fn run_project(
     project_command: Box<ProjectCommand>,
@@ -1465,11 +1895,14 @@ async fn run_project(
             show_settings!(args);
 
             // Initialize the cache.
-            let cache = cache.init()?.with_refresh(
-                args.refresh
-                    .combine(Refresh::from(args.settings.reinstall.clone()))
-                    .combine(Refresh::from(args.settings.upgrade.clone())),
-            );
+            let cache = cache
+                .init()?
+                .with_refresh(
+                    args.refresh
+                        .combine(Refresh::from(args.settings.reinstall.clone()))
+                        .combine(Refresh::from(args.settings.upgrade.clone())),
+                )
+                .with_rebuild(args.rebuild);
 
             let mut requirements = Vec::with_capacity(
                 args.with.len() + args.with_editable.len() + args.with_requirements.len(),
@@ -1521,6 +1954,7 @@ async fn run_project(
                 printer,
                 args.env_file,
                 args.no_env_file,
+                args.pypackages,
                 globals.preview,
                 args.max_recursion_depth,
             ))
@@ -1532,11 +1966,14 @@ async fn run_project(
             show_settings!(args);
 
             // Initialize the cache.
-            let cache = cache.init()?.with_refresh(
-                args.refresh
-                    .combine(Refresh::from(args.settings.reinstall.clone()))
-                    .combine(Refresh::from(args.settings.upgrade.clone())),
-            );
+            let cache = cache
+                .init()?
+                .with_refresh(
+                    args.refresh
+                        .combine(Refresh::from(args.settings.reinstall.clone()))
+                        .combine(Refresh::from(args.settings.upgrade.clone())),
+                )
+                .with_rebuild(args.rebuild);
 
             // Unwrap the script.
             let script = script.map(|script| match script {
@@ -1582,10 +2019,13 @@ async fn run_project(
             show_settings!(args);
 
             // Initialize the cache.
-            let cache = cache.init()?.with_refresh(
-                args.refresh
-                    .combine(Refresh::from(args.settings.upgrade.clone())),
-            );
+            let cache = cache
+                .init()?
+                .with_refresh(
+                    args.refresh
+                        .combine(Refresh::from(args.settings.upgrade.clone())),
+                )
+                .with_rebuild(args.rebuild);
 
             // If the script already exists, use it; otherwise, propagate the file path and we'll
             // initialize it later on.
@@ -1605,6 +2045,8 @@ async fn run_project(
                 args.locked,
                 args.frozen,
                 args.dry_run,
+                args.verify_determinism,
+                args.seed,
                 args.python,
                 args.install_mirrors,
                 args.settings,
@@ -1628,11 +2070,14 @@ async fn run_project(
             show_settings!(args);
 
             // Initialize the cache.
-            let cache = cache.init()?.with_refresh(
-                args.refresh
-                    .combine(Refresh::from(args.settings.reinstall.clone()))
-                    .combine(Refresh::from(args.settings.upgrade.clone())),
-            );
+            let cache = cache
+                .init()?
+                .with_refresh(
+                    args.refresh
+                        .combine(Refresh::from(args.settings.reinstall.clone()))
+                        .combine(Refresh::from(args.settings.upgrade.clone())),
+                )
+                .with_rebuild(args.rebuild);
 
             // If the script already exists, use it; otherwise, propagate the file path and we'll
             // initialize it later on.
@@ -1668,6 +2113,7 @@ async fn run_project(
                 args.editable,
                 args.dependency_type,
                 args.raw_sources,
+                args.normalize_specifiers,
                 args.indexes,
                 args.rev,
                 args.tag,
@@ -1698,11 +2144,14 @@ async fn run_project(
             show_settings!(args);
 
             // Initialize the cache.
-            let cache = cache.init()?.with_refresh(
-                args.refresh
-                    .combine(Refresh::from(args.settings.reinstall.clone()))
-                    .combine(Refresh::from(args.settings.upgrade.clone())),
-            );
+            let cache = cache
+                .init()?
+                .with_refresh(
+                    args.refresh
+                        .combine(Refresh::from(args.settings.reinstall.clone()))
+                        .combine(Refresh::from(args.settings.upgrade.clone())),
+                )
+                .with_rebuild(args.rebuild);
 
             // Unwrap the script.
             let script = script.map(|script| match script {
@@ -1790,7 +2239,7 @@ async fn run_project(
             show_settings!(args);
 
             // Initialize the cache.
-            let cache = cache.init()?;
+            let cache = cache.init()?.with_rebuild(args.rebuild);
 
             // Unwrap the script.
             let script = script.map(|script| match script {
@@ -1869,8 +2318,14 @@ where
 
     // `std::env::args` is not `Send` so we parse before passing to our runtime
     // https://github.com/rust-lang/rust/pull/48005
-    let cli = match Cli::try_parse_from(args) {
-        Ok(cli) => cli,
+    let parsed = Cli::command()
+        .try_get_matches_from(args)
+        .and_then(|mut matches| {
+            let command_name = command_name(&matches);
+            Cli::from_arg_matches_mut(&mut matches).map(|cli| (cli, command_name))
+        });
+    let (cli, command_name) = match parsed {
+        Ok(parsed) => parsed,
         Err(mut err) => {
             if let Some(ContextValue::String(subcommand)) = err.get(ContextKind::InvalidSubcommand)
             {
@@ -1947,7 +2402,7 @@ where
             .build()
             .expect("Failed building the Runtime");
         // Box the large main future to avoid stack overflows.
-        let result = runtime.block_on(Box::pin(run(cli)));
+        let result = runtime.block_on(Box::pin(run(cli, command_name)));
         // Avoid waiting for pending tasks to complete.
         //
         // The resolver may have kicked off HTTP requests during resolution that
@@ -1976,7 +2431,7 @@ where
             for err in causes {
                 eprintln!("  {}: {}", "Caused by".red().bold(), err.to_string().trim());
             }
-            ExitStatus::Error.into()
+            ExitStatus::from_error(err.as_ref()).into()
         }
     }
 }
@@ -1,5 +1,5 @@
 use std::env::VarError;
-use std::num::NonZeroUsize;
+use std::num::{NonZeroU32, NonZeroUsize};
 use std::path::PathBuf;
 use std::process;
 use std::str::FromStr;
@@ -14,28 +14,35 @@ use uv_cli::{
     ToolUpgradeArgs,
 };
 use uv_cli::{
-    AddArgs, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe,
-    PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonListFormat, PythonPinArgs, PythonUninstallArgs, RemoveArgs, RunArgs, SyncArgs,
-    ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs,
+    AddArgs, ColorChoice, DoctorArgs, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs,
+    Maybe, PipCheckArgs, PipCompileArgs, PipCompileFormat, PipFreezeArgs, PipInstallArgs,
+    PipLintArgs, PipListArgs, PipMergeArgs, PipRefreshEditablesArgs, PipShowArgs, PipSyncArgs,
+    PipTreeArgs, PipUninstallArgs, PipVerifyArgs, PythonFindArgs, PythonInstallArgs,
+    PythonListArgs, PythonListFormat, PythonPinArgs, PythonUninstallArgs, RemoveArgs, RunArgs,
+    SyncArgs, TagsArgs, TagsFormat, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs,
+    ToolUninstallArgs, TreeArgs, VenvArgs,
 };
 use uv_client::Connectivity;
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, DevGroupsSpecification, DryRun, EditableMode,
-    ExportFormat, ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallOptions,
-    KeyringProviderType, NoBinary, NoBuild, PreviewMode, ProjectBuildBackend, Reinstall,
+    BuildOptions, ByteSize, Concurrency, ConfigSettings, DevGroupsSpecification, DryRun,
+    EditableMode, EmbeddedIndexStrategy, ExportFormat, ExtrasSpecification, HashCheckingMode,
+    IndexStrategy, IndexUnavailablePolicy, InstallOptions, KeyringProviderType, NoBinary, NoBuild,
+    PackageBuildEnv, PackageConfigSettings, PreviewMode, ProjectBuildBackend, Reinstall,
     RequiredVersion, SourceStrategy, TargetTriple, TrustedHost, TrustedPublishing, Upgrade,
-    VersionControlSystem,
+    VersionControlSystem, WheelOptimization,
+};
+use uv_distribution_types::{
+    DependencyMetadata, Index, IndexLocations, IndexUrl, PipExtraIndex, PipFindLinks, PipIndex,
 };
-use uv_distribution_types::{DependencyMetadata, Index, IndexLocations, IndexUrl};
 use uv_install_wheel::LinkMode;
 use uv_normalize::PackageName;
 use uv_pep508::{ExtraName, RequirementOrigin};
 use uv_pypi_types::{Requirement, SupportedEnvironments};
-use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, Target};
+use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, SchemePath, Target};
 use uv_resolver::{
-    AnnotationStyle, DependencyMode, ExcludeNewer, ForkStrategy, PrereleaseMode, ResolutionMode,
+    AnnotationStyle, DependencyMode, ExcludeNewer, ExcludeNewerPackage, ForkStrategy,
+    InvalidMetadataPolicy, LocalVersionPreference, PackageProvider, PackageSourcePolicy,
+    PrereleaseMode, ResolutionMode, ResolutionPackage, WheelPreference, YankedPolicy,
 };
 use uv_settings::{
     Combine, FilesystemOptions, Options, PipOptions, PublishOptions, PythonInstallMirrors,
@@ -69,6 +76,18 @@ pub(crate) struct GlobalSettings {
     pub(crate) python_downloads: PythonDownloads,
     pub(crate) no_progress: bool,
     pub(crate) installer_metadata: bool,
+    pub(crate) print_downloads: Option<PrintDownloads>,
+    pub(crate) max_requests_per_second: Option<NonZeroU32>,
+    pub(crate) stats: bool,
+}
+
+/// Where to write the log of fetched URLs requested via `--print-downloads`.
+#[derive(Debug, Clone)]
+pub(crate) enum PrintDownloads {
+    /// Write the log to stderr.
+    Stderr,
+    /// Write the log to the given file.
+    File(PathBuf),
 }
 
 impl GlobalSettings {
@@ -108,7 +127,9 @@ impl GlobalSettings {
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_downloads))
                     .map(NonZeroUsize::get)
                     .unwrap_or(Concurrency::DEFAULT_DOWNLOADS),
-                builds: env(env::CONCURRENT_BUILDS)
+                builds: args
+                    .build_jobs
+                    .combine(env(env::CONCURRENT_BUILDS))
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_builds))
                     .map(NonZeroUsize::get)
                     .unwrap_or_else(Concurrency::threads),
@@ -161,6 +182,12 @@ impl GlobalSettings {
             // with log messages.
             no_progress: args.no_progress || std::env::var_os(EnvVars::RUST_LOG).is_some(),
             installer_metadata: !args.no_installer_metadata,
+            print_downloads: args.print_downloads.clone().map(|path| match path {
+                Some(path) => PrintDownloads::File(path),
+                None => PrintDownloads::Stderr,
+            }),
+            max_requests_per_second: args.max_requests_per_second,
+            stats: args.stats,
         }
     }
 }
@@ -296,9 +323,11 @@ pub(crate) struct RunSettings {
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
+    pub(crate) rebuild: Vec<PackageName>,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) env_file: Vec<PathBuf>,
     pub(crate) no_env_file: bool,
+    pub(crate) pypackages: bool,
     pub(crate) max_recursion_depth: u32,
 }
 
@@ -351,6 +380,7 @@ impl RunSettings {
             show_resolution,
             env_file,
             no_env_file,
+            pypackages,
             max_recursion_depth,
         } = args;
 
@@ -403,6 +433,7 @@ impl RunSettings {
             no_sync,
             active: flag(active, no_active),
             python: python.and_then(Maybe::into_option),
+            rebuild: refresh.rebuild_package.clone(),
             refresh: Refresh::from(refresh),
             settings: ResolverInstallerSettings::combine(
                 resolver_installer_options(installer, build),
@@ -410,6 +441,7 @@ impl RunSettings {
             ),
             env_file,
             no_env_file,
+            pypackages,
             install_mirrors,
             max_recursion_depth: max_recursion_depth.unwrap_or(Self::DEFAULT_MAX_RECURSION_DEPTH),
         }
@@ -430,6 +462,7 @@ pub(crate) struct ToolRunSettings {
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
+    pub(crate) rebuild: Vec<PackageName>,
     pub(crate) settings: ResolverInstallerSettings,
 }
 
@@ -497,6 +530,7 @@ impl ToolRunSettings {
             isolated,
             show_resolution,
             python: python.and_then(Maybe::into_option),
+            rebuild: refresh.rebuild_package.clone(),
             refresh: Refresh::from(refresh),
             settings: ResolverInstallerSettings::combine(
                 resolver_installer_options(installer, build),
@@ -520,6 +554,7 @@ pub(crate) struct ToolInstallSettings {
     pub(crate) overrides: Vec<PathBuf>,
     pub(crate) python: Option<String>,
     pub(crate) refresh: Refresh,
+    pub(crate) rebuild: Vec<PackageName>,
     pub(crate) options: ResolverInstallerOptions,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) force: bool,
@@ -588,6 +623,7 @@ impl ToolInstallSettings {
             python: python.and_then(Maybe::into_option),
             force,
             editable,
+            rebuild: refresh.rebuild_package.clone(),
             refresh: Refresh::from(refresh),
             options,
             settings,
@@ -627,6 +663,7 @@ impl ToolUpgradeSettings {
             pre,
             fork_strategy,
             config_setting,
+            config_setting_package,
             no_build_isolation,
             no_build_isolation_package,
             build_isolation,
@@ -657,14 +694,18 @@ impl ToolUpgradeSettings {
             index_strategy,
             keyring_provider,
             resolution,
+            resolution_package: None,
             prerelease,
             pre,
+            prerelease_package: Vec::new(),
             fork_strategy,
             config_setting,
+            config_setting_package,
             no_build_isolation,
             no_build_isolation_package,
             build_isolation,
             exclude_newer,
+            exclude_newer_package: None,
             link_mode,
             compile_bytecode,
             no_compile_bytecode,
@@ -933,6 +974,72 @@ impl PythonFindSettings {
     }
 }
 
+/// The resolved settings to use for a `tags` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct TagsSettings {
+    pub(crate) python: Option<String>,
+    pub(crate) python_version: Option<PythonVersion>,
+    pub(crate) python_platform: Option<TargetTriple>,
+    pub(crate) system: bool,
+    pub(crate) output_format: TagsFormat,
+}
+
+impl TagsSettings {
+    /// Resolve the [`TagsSettings`] from the CLI and workspace configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: TagsArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let TagsArgs {
+            python,
+            python_version,
+            python_platform,
+            system,
+            no_system,
+            output_format,
+        } = args;
+
+        Self {
+            python: python.and_then(Maybe::into_option),
+            python_version,
+            python_platform,
+            system: flag(system, no_system).unwrap_or_default(),
+            output_format,
+        }
+    }
+}
+
+/// The resolved settings to use for a `doctor` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct DoctorSettings {
+    pub(crate) settings: PipSettings,
+}
+
+impl DoctorSettings {
+    /// Resolve the [`DoctorSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: DoctorArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let DoctorArgs {
+            python,
+            system,
+            no_system,
+            index_args,
+            keyring_provider,
+            link_mode,
+        } = args;
+
+        Self {
+            settings: PipSettings::combine(
+                PipOptions {
+                    python: python.and_then(Maybe::into_option),
+                    system: flag(system, no_system),
+                    keyring_provider,
+                    link_mode,
+                    ..PipOptions::from(index_args)
+                },
+                filesystem,
+            ),
+        }
+    }
+}
+
 /// The resolved settings to use for a `python pin` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -980,6 +1087,7 @@ pub(crate) struct SyncSettings {
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
+    pub(crate) rebuild: Vec<PackageName>,
     pub(crate) settings: ResolverInstallerSettings,
 }
 
@@ -1064,6 +1172,7 @@ impl SyncSettings {
             all_packages,
             package,
             python: python.and_then(Maybe::into_option),
+            rebuild: refresh.rebuild_package.clone(),
             refresh: Refresh::from(refresh),
             settings,
             install_mirrors,
@@ -1078,10 +1187,13 @@ pub(crate) struct LockSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
     pub(crate) dry_run: DryRun,
+    pub(crate) verify_determinism: bool,
+    pub(crate) seed: Option<u64>,
     pub(crate) script: Option<PathBuf>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
+    pub(crate) rebuild: Vec<PackageName>,
     pub(crate) settings: ResolverSettings,
 }
 
@@ -1093,6 +1205,8 @@ impl LockSettings {
             check,
             check_exists,
             dry_run,
+            verify_determinism,
+            seed,
             script,
             resolver,
             build,
@@ -1109,8 +1223,11 @@ impl LockSettings {
             locked: check,
             frozen: check_exists,
             dry_run: DryRun::from_args(dry_run),
+            verify_determinism,
+            seed,
             script,
             python: python.and_then(Maybe::into_option),
+            rebuild: refresh.rebuild_package.clone(),
             refresh: Refresh::from(refresh),
             settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
             install_mirrors,
@@ -1132,6 +1249,7 @@ pub(crate) struct AddSettings {
     pub(crate) editable: Option<bool>,
     pub(crate) extras: Vec<ExtraName>,
     pub(crate) raw_sources: bool,
+    pub(crate) normalize_specifiers: bool,
     pub(crate) rev: Option<String>,
     pub(crate) tag: Option<String>,
     pub(crate) branch: Option<String>,
@@ -1140,6 +1258,7 @@ pub(crate) struct AddSettings {
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
+    pub(crate) rebuild: Vec<PackageName>,
     pub(crate) indexes: Vec<Index>,
     pub(crate) settings: ResolverInstallerSettings,
 }
@@ -1158,6 +1277,7 @@ impl AddSettings {
             no_editable,
             extra,
             raw_sources,
+            normalize_specifiers,
             rev,
             tag,
             branch,
@@ -1243,6 +1363,7 @@ impl AddSettings {
             requirements,
             dependency_type,
             raw_sources,
+            normalize_specifiers,
             rev,
             tag,
             branch,
@@ -1251,6 +1372,7 @@ impl AddSettings {
             python: python.and_then(Maybe::into_option),
             editable: flag(editable, no_editable),
             extras: extra.unwrap_or_default(),
+            rebuild: refresh.rebuild_package.clone(),
             refresh: Refresh::from(refresh),
             indexes,
             settings: ResolverInstallerSettings::combine(
@@ -1277,6 +1399,7 @@ pub(crate) struct RemoveSettings {
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
+    pub(crate) rebuild: Vec<PackageName>,
     pub(crate) settings: ResolverInstallerSettings,
 }
 
@@ -1332,6 +1455,7 @@ impl RemoveSettings {
             package,
             script,
             python: python.and_then(Maybe::into_option),
+            rebuild: refresh.rebuild_package.clone(),
             refresh: Refresh::from(refresh),
             settings: ResolverInstallerSettings::combine(
                 resolver_installer_options(installer, build),
@@ -1444,6 +1568,7 @@ pub(crate) struct ExportSettings {
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
+    pub(crate) rebuild: Vec<PackageName>,
     pub(crate) settings: ResolverSettings,
 }
 
@@ -1523,6 +1648,7 @@ impl ExportSettings {
             include_header: flag(header, no_header).unwrap_or(true),
             script,
             python: python.and_then(Maybe::into_option),
+            rebuild: refresh.rebuild_package.clone(),
             refresh: Refresh::from(refresh),
             settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
             install_mirrors,
@@ -1542,7 +1668,23 @@ pub(crate) struct PipCompileSettings {
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
     pub(crate) environments: SupportedEnvironments,
+    pub(crate) format: PipCompileFormat,
+    pub(crate) output_per_extra: bool,
+    pub(crate) verify_determinism: bool,
+    pub(crate) seed: Option<u64>,
+    pub(crate) resume: bool,
+    pub(crate) allowed_packages: Option<PathBuf>,
+    pub(crate) minimal_changes: bool,
+    pub(crate) report_bounds: bool,
+    pub(crate) normalize_specifiers: bool,
+    pub(crate) show_derivation_tree: bool,
+    pub(crate) explain: bool,
+    pub(crate) constraint_from_env: bool,
+    pub(crate) sign: Option<PathBuf>,
+    pub(crate) emit_graph: Option<PathBuf>,
+    pub(crate) python_versions: Vec<PythonVersion>,
     pub(crate) refresh: Refresh,
+    pub(crate) rebuild: Vec<PackageName>,
     pub(crate) settings: PipSettings,
 }
 
@@ -1556,11 +1698,36 @@ impl PipCompileSettings {
             extra,
             all_extras,
             no_all_extras,
+            group,
             build_constraints,
             refresh,
             no_deps,
             deps,
             output_file,
+            sign,
+            emit_graph,
+            format,
+            output_per_extra,
+            verify_determinism,
+            seed,
+            resume,
+            allowed_packages,
+            minimal_changes,
+            report_bounds,
+            normalize_specifiers,
+            show_derivation_tree,
+            explain,
+            constraint_from_env,
+            embedded_index_strategy,
+            invalid_metadata,
+            yanked,
+            index_unavailable,
+            wheel_preference,
+            local_version_preference,
+            resolution_timeout,
+            max_backtracks,
+            source_policy,
+            provided_by,
             no_strip_extras,
             strip_extras,
             no_strip_markers,
@@ -1577,12 +1744,15 @@ impl PipCompileSettings {
             no_system,
             generate_hashes,
             no_generate_hashes,
+            only_hashed,
+            no_only_hashed,
             no_build,
             build,
             no_binary,
             only_binary,
             python_version,
             python_platform,
+            feature,
             universal,
             no_universal,
             no_emit_package,
@@ -1596,6 +1766,9 @@ impl PipCompileSettings {
             no_emit_marker_expression,
             emit_index_annotation,
             no_emit_index_annotation,
+            emit_requires_python,
+            no_emit_requires_python,
+            pip_env,
             compat_args: _,
         } = args;
 
@@ -1647,6 +1820,13 @@ impl PipCompileSettings {
             SupportedEnvironments::default()
         };
 
+        // If multiple Python versions are requested, the lowest is used as the base version for the
+        // resolution.
+        let mut python_versions = python_version;
+        python_versions.sort_by(|a, b| a.version().cmp(b.version()));
+        python_versions.dedup();
+        let python_version = python_versions.first().cloned();
+
         Self {
             src_file,
             constraints: constraints
@@ -1665,6 +1845,22 @@ impl PipCompileSettings {
             overrides_from_workspace,
             build_constraints_from_workspace,
             environments,
+            format,
+            output_per_extra,
+            verify_determinism,
+            seed,
+            resume,
+            allowed_packages,
+            minimal_changes,
+            report_bounds,
+            normalize_specifiers,
+            show_derivation_tree,
+            explain,
+            constraint_from_env,
+            sign,
+            emit_graph,
+            python_versions,
+            rebuild: refresh.rebuild_package.clone(),
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -1675,6 +1871,7 @@ impl PipCompileSettings {
                     only_binary,
                     extra,
                     all_extras: flag(all_extras, no_all_extras),
+                    group: Some(group),
                     no_deps: flag(no_deps, deps),
                     output_file,
                     no_strip_extras: flag(no_strip_extras, strip_extras),
@@ -1683,8 +1880,10 @@ impl PipCompileSettings {
                     no_header: flag(no_header, header),
                     custom_compile_command,
                     generate_hashes: flag(generate_hashes, no_generate_hashes),
+                    only_hashed: flag(only_hashed, no_only_hashed),
                     python_version,
                     python_platform,
+                    feature,
                     universal: flag(universal, no_universal),
                     no_emit_package,
                     emit_index_url: flag(emit_index_url, no_emit_index_url),
@@ -1692,9 +1891,21 @@ impl PipCompileSettings {
                     emit_build_options: flag(emit_build_options, no_emit_build_options),
                     emit_marker_expression: flag(emit_marker_expression, no_emit_marker_expression),
                     emit_index_annotation: flag(emit_index_annotation, no_emit_index_annotation),
+                    emit_requires_python: flag(emit_requires_python, no_emit_requires_python),
                     annotation_style,
+                    embedded_index_strategy,
+                    invalid_metadata,
+                    yanked,
+                    index_unavailable,
+                    wheel_preference,
+                    local_version_preference,
+                    resolution_timeout,
+                    max_backtracks,
+                    source_policy,
+                    provided_by,
                     ..PipOptions::from(resolver)
-                },
+                }
+                .combine(pip_env_options(pip_env)),
                 filesystem,
             ),
         }
@@ -1708,8 +1919,16 @@ pub(crate) struct PipSyncSettings {
     pub(crate) src_file: Vec<PathBuf>,
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
+    pub(crate) only: Vec<PackageName>,
     pub(crate) dry_run: DryRun,
     pub(crate) refresh: Refresh,
+    pub(crate) rebuild: Vec<PackageName>,
+    pub(crate) environment: Option<PathBuf>,
+    pub(crate) verify_signature: Option<PathBuf>,
+    pub(crate) audit_log: Option<PathBuf>,
+    pub(crate) reinstall_vcs: bool,
+    pub(crate) reinstall_local: bool,
+    pub(crate) pypackages: bool,
     pub(crate) settings: PipSettings,
 }
 
@@ -1720,19 +1939,25 @@ impl PipSyncSettings {
             src_file,
             constraints,
             build_constraints,
+            only,
             installer,
             refresh,
             require_hashes,
             no_require_hashes,
             verify_hashes,
             no_verify_hashes,
+            verify_signature,
+            audit_log,
             python,
+            environment,
             system,
             no_system,
             break_system_packages,
             no_break_system_packages,
             target,
             prefix,
+            pypackages,
+            scheme_path,
             allow_empty_requirements,
             no_allow_empty_requirements,
             no_build,
@@ -1741,9 +1966,29 @@ impl PipSyncSettings {
             only_binary,
             python_version,
             python_platform,
+            feature,
             strict,
             no_strict,
+            check_abi,
+            no_check_abi,
             dry_run,
+            embedded_index_strategy,
+            invalid_metadata,
+            yanked,
+            index_unavailable,
+            wheel_preference,
+            local_version_preference,
+            resolution_timeout,
+            max_backtracks,
+            source_policy,
+            provided_by,
+            only_hashed,
+            no_only_hashed,
+            max_total_download_size,
+            max_package_size,
+            reinstall_vcs,
+            reinstall_local,
+            pip_env,
             compat_args: _,
         } = *args;
 
@@ -1757,8 +2002,16 @@ impl PipSyncSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            only,
             dry_run: DryRun::from_args(dry_run),
+            rebuild: refresh.rebuild_package.clone(),
             refresh: Refresh::from(refresh),
+            environment,
+            verify_signature,
+            audit_log,
+            reinstall_vcs,
+            reinstall_local,
+            pypackages,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -1777,11 +2030,28 @@ impl PipSyncSettings {
                     ),
                     python_version,
                     python_platform,
+                    feature,
                     strict: flag(strict, no_strict),
+                    check_abi: flag(check_abi, no_check_abi),
+                    embedded_index_strategy,
+                    invalid_metadata,
+                    yanked,
+                    index_unavailable,
+                    wheel_preference,
+                    local_version_preference,
+                    resolution_timeout,
+                    max_backtracks,
+                    source_policy,
+                    provided_by,
+                    only_hashed: flag(only_hashed, no_only_hashed),
+                    max_total_download_size,
+                    max_package_size,
                     ..PipOptions::from(installer)
-                },
+                }
+                .combine(pip_env_options(pip_env)),
                 filesystem,
-            ),
+            )
+            .with_scheme_paths(scheme_path),
         }
     }
 }
@@ -1793,15 +2063,27 @@ pub(crate) struct PipInstallSettings {
     pub(crate) package: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
     pub(crate) editables: Vec<String>,
+    pub(crate) with: Vec<String>,
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) overrides: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) build_log_dir: Option<PathBuf>,
+    pub(crate) optimize_wheels: Vec<WheelOptimization>,
+    pub(crate) show_derivation_tree: bool,
+    pub(crate) explain: bool,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
     pub(crate) modifications: Modifications,
     pub(crate) refresh: Refresh,
+    pub(crate) rebuild: Vec<PackageName>,
+    pub(crate) environment: Option<PathBuf>,
+    pub(crate) create_venv: bool,
+    pub(crate) reinstall_vcs: bool,
+    pub(crate) reinstall_local: bool,
+    pub(crate) pypackages: bool,
+    pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) settings: PipSettings,
 }
 
@@ -1812,12 +2094,14 @@ impl PipInstallSettings {
             package,
             requirements,
             editable,
+            with,
             constraints,
             overrides,
             build_constraints,
             extra,
             all_extras,
             no_all_extras,
+            group,
             installer,
             refresh,
             no_deps,
@@ -1827,23 +2111,52 @@ impl PipInstallSettings {
             verify_hashes,
             no_verify_hashes,
             python,
+            environment,
             system,
             no_system,
+            create_venv,
+            no_create_venv,
             break_system_packages,
             no_break_system_packages,
             target,
             prefix,
+            pypackages,
+            scheme_path,
             no_build,
             build,
             no_binary,
             only_binary,
             python_version,
             python_platform,
+            feature,
             inexact,
             exact,
             strict,
             no_strict,
+            check_abi,
+            no_check_abi,
             dry_run,
+            embedded_index_strategy,
+            invalid_metadata,
+            yanked,
+            index_unavailable,
+            wheel_preference,
+            local_version_preference,
+            resolution_timeout,
+            max_backtracks,
+            source_policy,
+            provided_by,
+            only_hashed,
+            no_only_hashed,
+            max_total_download_size,
+            max_package_size,
+            reinstall_vcs,
+            reinstall_local,
+            build_log_dir,
+            optimize_wheels,
+            show_derivation_tree,
+            explain,
+            pip_env,
             compat_args: _,
         } = args;
 
@@ -1889,10 +2202,19 @@ impl PipInstallSettings {
             Vec::new()
         };
 
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
         Self {
             package,
             requirements,
             editables: editable,
+            with: with
+                .into_iter()
+                .flat_map(CommaSeparatedRequirements::into_iter)
+                .collect(),
             constraints: constraints
                 .into_iter()
                 .filter_map(Maybe::into_option)
@@ -1906,6 +2228,10 @@ impl PipInstallSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             dry_run: DryRun::from_args(dry_run),
+            build_log_dir,
+            optimize_wheels,
+            show_derivation_tree,
+            explain,
             constraints_from_workspace,
             overrides_from_workspace,
             build_constraints_from_workspace,
@@ -1914,7 +2240,14 @@ impl PipInstallSettings {
             } else {
                 Modifications::Sufficient
             },
+            rebuild: refresh.rebuild_package.clone(),
             refresh: Refresh::from(refresh),
+            environment,
+            create_venv: flag(create_venv, no_create_venv).unwrap_or_default(),
+            reinstall_vcs,
+            reinstall_local,
+            pypackages,
+            install_mirrors,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -1926,17 +2259,35 @@ impl PipInstallSettings {
                     no_binary,
                     only_binary,
                     strict: flag(strict, no_strict),
+                    check_abi: flag(check_abi, no_check_abi),
                     extra,
                     all_extras: flag(all_extras, no_all_extras),
+                    group: Some(group),
                     no_deps: flag(no_deps, deps),
                     python_version,
                     python_platform,
+                    feature,
                     require_hashes: flag(require_hashes, no_require_hashes),
                     verify_hashes: flag(verify_hashes, no_verify_hashes),
+                    embedded_index_strategy,
+                    invalid_metadata,
+                    yanked,
+                    index_unavailable,
+                    wheel_preference,
+                    local_version_preference,
+                    resolution_timeout,
+                    max_backtracks,
+                    source_policy,
+                    provided_by,
+                    only_hashed: flag(only_hashed, no_only_hashed),
+                    max_total_download_size,
+                    max_package_size,
                     ..PipOptions::from(installer)
-                },
+                }
+                .combine(pip_env_options(pip_env)),
                 filesystem,
-            ),
+            )
+            .with_scheme_paths(scheme_path),
         }
     }
 }
@@ -1948,6 +2299,7 @@ pub(crate) struct PipUninstallSettings {
     pub(crate) package: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) environment: Option<PathBuf>,
     pub(crate) settings: PipSettings,
 }
 
@@ -1958,6 +2310,7 @@ impl PipUninstallSettings {
             package,
             requirements,
             python,
+            environment,
             keyring_provider,
             system,
             no_system,
@@ -1973,6 +2326,7 @@ impl PipUninstallSettings {
             package,
             requirements,
             dry_run: DryRun::from_args(dry_run),
+            environment,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -1995,6 +2349,7 @@ impl PipUninstallSettings {
 pub(crate) struct PipFreezeSettings {
     pub(crate) exclude_editable: bool,
     pub(crate) paths: Option<Vec<PathBuf>>,
+    pub(crate) environment: Option<PathBuf>,
     pub(crate) settings: PipSettings,
 }
 
@@ -2006,6 +2361,7 @@ impl PipFreezeSettings {
             strict,
             no_strict,
             python,
+            environment,
             paths,
             system,
             no_system,
@@ -2015,6 +2371,7 @@ impl PipFreezeSettings {
         Self {
             exclude_editable,
             paths,
+            environment,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -2036,6 +2393,7 @@ pub(crate) struct PipListSettings {
     pub(crate) exclude: Vec<PackageName>,
     pub(crate) format: ListFormat,
     pub(crate) outdated: bool,
+    pub(crate) environment: Option<PathBuf>,
     pub(crate) settings: PipSettings,
 }
 
@@ -2053,6 +2411,7 @@ impl PipListSettings {
             no_strict,
             fetch,
             python,
+            environment,
             system,
             no_system,
             compat_args: _,
@@ -2063,6 +2422,7 @@ impl PipListSettings {
             exclude,
             format,
             outdated: flag(outdated, no_outdated).unwrap_or(false),
+            environment,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -2194,6 +2554,168 @@ impl PipCheckSettings {
     }
 }
 
+/// The resolved settings to use for a `pip lint` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct PipLintSettings {
+    pub(crate) src_file: Vec<PathBuf>,
+    pub(crate) constraints: Vec<PathBuf>,
+    pub(crate) fix: bool,
+    pub(crate) settings: PipSettings,
+}
+
+impl PipLintSettings {
+    /// Resolve the [`PipLintSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: PipLintArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let PipLintArgs {
+            src_file,
+            constraints,
+            fix,
+            fetch,
+            python,
+            system,
+            no_system,
+        } = args;
+
+        Self {
+            src_file,
+            constraints: constraints
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            fix,
+            settings: PipSettings::combine(
+                PipOptions {
+                    python: python.and_then(Maybe::into_option),
+                    system: flag(system, no_system),
+                    ..PipOptions::from(fetch)
+                },
+                filesystem,
+            ),
+        }
+    }
+}
+
+/// The resolved settings to use for a `pip merge` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PipMergeSettings {
+    pub(crate) src_file: Vec<PathBuf>,
+    pub(crate) output_file: Option<PathBuf>,
+}
+
+impl PipMergeSettings {
+    /// Resolve the [`PipMergeSettings`] from the CLI arguments.
+    pub(crate) fn resolve(args: PipMergeArgs) -> Self {
+        let PipMergeArgs {
+            src_file,
+            output_file,
+        } = args;
+
+        Self {
+            src_file,
+            output_file,
+        }
+    }
+}
+
+/// The resolved settings to use for a `pip refresh-editables` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PipRefreshEditablesSettings {
+    pub(crate) package: Vec<PackageName>,
+    pub(crate) dry_run: DryRun,
+    pub(crate) refresh: Refresh,
+    pub(crate) rebuild: Vec<PackageName>,
+    pub(crate) environment: Option<PathBuf>,
+    pub(crate) settings: PipSettings,
+}
+
+impl PipRefreshEditablesSettings {
+    /// Resolve the [`PipRefreshEditablesSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: PipRefreshEditablesArgs,
+        filesystem: Option<FilesystemOptions>,
+    ) -> Self {
+        let PipRefreshEditablesArgs {
+            package,
+            installer,
+            refresh,
+            python,
+            environment,
+            system,
+            no_system,
+            break_system_packages,
+            no_break_system_packages,
+            dry_run,
+        } = args;
+
+        Self {
+            package,
+            dry_run: DryRun::from_args(dry_run),
+            rebuild: refresh.rebuild_package.clone(),
+            refresh: Refresh::from(refresh),
+            environment,
+            settings: PipSettings::combine(
+                PipOptions {
+                    python: python.and_then(Maybe::into_option),
+                    system: flag(system, no_system),
+                    break_system_packages: flag(break_system_packages, no_break_system_packages),
+                    ..PipOptions::from(installer)
+                },
+                filesystem,
+            ),
+        }
+    }
+}
+
+/// The resolved settings to use for a `pip verify` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PipVerifySettings {
+    pub(crate) package: Vec<PackageName>,
+    pub(crate) repair: bool,
+    pub(crate) remove_extra: bool,
+    pub(crate) refresh: Refresh,
+    pub(crate) rebuild: Vec<PackageName>,
+    pub(crate) environment: Option<PathBuf>,
+    pub(crate) settings: PipSettings,
+}
+
+impl PipVerifySettings {
+    /// Resolve the [`PipVerifySettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: PipVerifyArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let PipVerifyArgs {
+            package,
+            repair,
+            remove_extra,
+            installer,
+            refresh,
+            python,
+            environment,
+            system,
+            no_system,
+            break_system_packages,
+            no_break_system_packages,
+        } = args;
+
+        Self {
+            package,
+            repair,
+            remove_extra,
+            rebuild: refresh.rebuild_package.clone(),
+            refresh: Refresh::from(refresh),
+            environment,
+            settings: PipSettings::combine(
+                PipOptions {
+                    python: python.and_then(Maybe::into_option),
+                    system: flag(system, no_system),
+                    break_system_packages: flag(break_system_packages, no_break_system_packages),
+                    ..PipOptions::from(installer)
+                },
+                filesystem,
+            ),
+        }
+    }
+}
+
 /// The resolved settings to use for a `build` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -2212,6 +2734,7 @@ pub(crate) struct BuildSettings {
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
+    pub(crate) rebuild: Vec<PackageName>,
     pub(crate) settings: ResolverSettings,
 }
 
@@ -2264,6 +2787,7 @@ impl BuildSettings {
                 flag(verify_hashes, no_verify_hashes),
             ),
             python: python.and_then(Maybe::into_option),
+            rebuild: refresh.rebuild_package.clone(),
             refresh: Refresh::from(refresh),
             settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
             install_mirrors,
@@ -2283,6 +2807,7 @@ pub(crate) struct VenvSettings {
     pub(crate) relocatable: bool,
     pub(crate) no_project: bool,
     pub(crate) refresh: Refresh,
+    pub(crate) rebuild: Vec<PackageName>,
     pub(crate) settings: PipSettings,
 }
 
@@ -2317,6 +2842,7 @@ impl VenvSettings {
             system_site_packages,
             no_project,
             relocatable,
+            rebuild: refresh.rebuild_package.clone(),
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2345,9 +2871,12 @@ pub(crate) struct InstallerSettingsRef<'a> {
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) dependency_metadata: &'a DependencyMetadata,
     pub(crate) config_setting: &'a ConfigSettings,
+    pub(crate) config_setting_package: &'a PackageConfigSettings,
+    pub(crate) build_env: &'a PackageBuildEnv,
     pub(crate) no_build_isolation: bool,
     pub(crate) no_build_isolation_package: &'a [PackageName],
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) exclude_newer_package: &'a ExcludeNewerPackage,
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
     pub(crate) reinstall: &'a Reinstall,
@@ -2366,13 +2895,18 @@ pub(crate) struct ResolverSettings {
     pub(crate) index_strategy: IndexStrategy,
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) resolution: ResolutionMode,
+    pub(crate) resolution_package: ResolutionPackage,
     pub(crate) prerelease: PrereleaseMode,
+    pub(crate) prerelease_package: Vec<PackageName>,
     pub(crate) fork_strategy: ForkStrategy,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) config_setting: ConfigSettings,
+    pub(crate) config_setting_package: PackageConfigSettings,
+    pub(crate) build_env: PackageBuildEnv,
     pub(crate) no_build_isolation: bool,
     pub(crate) no_build_isolation_package: Vec<PackageName>,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) exclude_newer_package: ExcludeNewerPackage,
     pub(crate) link_mode: LinkMode,
     pub(crate) upgrade: Upgrade,
     pub(crate) build_options: BuildOptions,
@@ -2385,13 +2919,18 @@ pub(crate) struct ResolverSettingsRef<'a> {
     pub(crate) index_strategy: IndexStrategy,
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) resolution: ResolutionMode,
+    pub(crate) resolution_package: &'a ResolutionPackage,
     pub(crate) prerelease: PrereleaseMode,
+    pub(crate) prerelease_package: &'a [PackageName],
     pub(crate) fork_strategy: ForkStrategy,
     pub(crate) dependency_metadata: &'a DependencyMetadata,
     pub(crate) config_setting: &'a ConfigSettings,
+    pub(crate) config_setting_package: &'a PackageConfigSettings,
+    pub(crate) build_env: &'a PackageBuildEnv,
     pub(crate) no_build_isolation: bool,
     pub(crate) no_build_isolation_package: &'a [PackageName],
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) exclude_newer_package: &'a ExcludeNewerPackage,
     pub(crate) link_mode: LinkMode,
     pub(crate) upgrade: &'a Upgrade,
     pub(crate) build_options: &'a BuildOptions,
@@ -2417,13 +2956,18 @@ impl ResolverSettings {
             index_strategy: self.index_strategy,
             keyring_provider: self.keyring_provider,
             resolution: self.resolution,
+            resolution_package: &self.resolution_package,
             prerelease: self.prerelease,
+            prerelease_package: &self.prerelease_package,
             fork_strategy: self.fork_strategy,
             dependency_metadata: &self.dependency_metadata,
             config_setting: &self.config_setting,
+            config_setting_package: &self.config_setting_package,
+            build_env: &self.build_env,
             no_build_isolation: self.no_build_isolation,
             no_build_isolation_package: &self.no_build_isolation_package,
             exclude_newer: self.exclude_newer,
+            exclude_newer_package: &self.exclude_newer_package,
             link_mode: self.link_mode,
             upgrade: &self.upgrade,
             build_options: &self.build_options,
@@ -2452,7 +2996,9 @@ impl From<ResolverOptions> for ResolverSettings {
                 value.no_index.unwrap_or_default(),
             ),
             resolution: value.resolution.unwrap_or_default(),
+            resolution_package: value.resolution_package.unwrap_or_default(),
             prerelease: value.prerelease.unwrap_or_default(),
+            prerelease_package: value.prerelease_package.unwrap_or_default(),
             fork_strategy: value.fork_strategy.unwrap_or_default(),
             dependency_metadata: DependencyMetadata::from_entries(
                 value.dependency_metadata.into_iter().flatten(),
@@ -2460,9 +3006,12 @@ impl From<ResolverOptions> for ResolverSettings {
             index_strategy: value.index_strategy.unwrap_or_default(),
             keyring_provider: value.keyring_provider.unwrap_or_default(),
             config_setting: value.config_settings.unwrap_or_default(),
+            config_setting_package: value.config_settings_package.unwrap_or_default(),
+            build_env: value.build_env.unwrap_or_default(),
             no_build_isolation: value.no_build_isolation.unwrap_or_default(),
             no_build_isolation_package: value.no_build_isolation_package.unwrap_or_default(),
             exclude_newer: value.exclude_newer,
+            exclude_newer_package: value.exclude_newer_package.unwrap_or_default(),
             link_mode: value.link_mode.unwrap_or_default(),
             sources: SourceStrategy::from_args(value.no_sources.unwrap_or_default()),
             upgrade: Upgrade::from_args(
@@ -2488,13 +3037,18 @@ pub(crate) struct ResolverInstallerSettingsRef<'a> {
     pub(crate) index_strategy: IndexStrategy,
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) resolution: ResolutionMode,
+    pub(crate) resolution_package: &'a ResolutionPackage,
     pub(crate) prerelease: PrereleaseMode,
+    pub(crate) prerelease_package: &'a [PackageName],
     pub(crate) fork_strategy: ForkStrategy,
     pub(crate) dependency_metadata: &'a DependencyMetadata,
     pub(crate) config_setting: &'a ConfigSettings,
+    pub(crate) config_setting_package: &'a PackageConfigSettings,
+    pub(crate) build_env: &'a PackageBuildEnv,
     pub(crate) no_build_isolation: bool,
     pub(crate) no_build_isolation_package: &'a [PackageName],
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) exclude_newer_package: &'a ExcludeNewerPackage,
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
     pub(crate) sources: SourceStrategy,
@@ -2515,13 +3069,18 @@ pub(crate) struct ResolverInstallerSettings {
     pub(crate) index_strategy: IndexStrategy,
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) resolution: ResolutionMode,
+    pub(crate) resolution_package: ResolutionPackage,
     pub(crate) prerelease: PrereleaseMode,
+    pub(crate) prerelease_package: Vec<PackageName>,
     pub(crate) fork_strategy: ForkStrategy,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) config_setting: ConfigSettings,
+    pub(crate) config_setting_package: PackageConfigSettings,
+    pub(crate) build_env: PackageBuildEnv,
     pub(crate) no_build_isolation: bool,
     pub(crate) no_build_isolation_package: Vec<PackageName>,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) exclude_newer_package: ExcludeNewerPackage,
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
     pub(crate) sources: SourceStrategy,
@@ -2552,13 +3111,18 @@ impl ResolverInstallerSettings {
             index_strategy: self.index_strategy,
             keyring_provider: self.keyring_provider,
             resolution: self.resolution,
+            resolution_package: &self.resolution_package,
             prerelease: self.prerelease,
+            prerelease_package: &self.prerelease_package,
             fork_strategy: self.fork_strategy,
             dependency_metadata: &self.dependency_metadata,
             config_setting: &self.config_setting,
+            config_setting_package: &self.config_setting_package,
+            build_env: &self.build_env,
             no_build_isolation: self.no_build_isolation,
             no_build_isolation_package: &self.no_build_isolation_package,
             exclude_newer: self.exclude_newer,
+            exclude_newer_package: &self.exclude_newer_package,
             link_mode: self.link_mode,
             compile_bytecode: self.compile_bytecode,
             sources: self.sources,
@@ -2589,7 +3153,9 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                 value.no_index.unwrap_or_default(),
             ),
             resolution: value.resolution.unwrap_or_default(),
+            resolution_package: value.resolution_package.unwrap_or_default(),
             prerelease: value.prerelease.unwrap_or_default(),
+            prerelease_package: value.prerelease_package.unwrap_or_default(),
             fork_strategy: value.fork_strategy.unwrap_or_default(),
             dependency_metadata: DependencyMetadata::from_entries(
                 value.dependency_metadata.into_iter().flatten(),
@@ -2597,9 +3163,12 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
             index_strategy: value.index_strategy.unwrap_or_default(),
             keyring_provider: value.keyring_provider.unwrap_or_default(),
             config_setting: value.config_settings.unwrap_or_default(),
+            config_setting_package: value.config_settings_package.unwrap_or_default(),
+            build_env: value.build_env.unwrap_or_default(),
             no_build_isolation: value.no_build_isolation.unwrap_or_default(),
             no_build_isolation_package: value.no_build_isolation_package.unwrap_or_default(),
             exclude_newer: value.exclude_newer,
+            exclude_newer_package: value.exclude_newer_package.unwrap_or_default(),
             link_mode: value.link_mode.unwrap_or_default(),
             sources: SourceStrategy::from_args(value.no_sources.unwrap_or_default()),
             compile_bytecode: value.compile_bytecode.unwrap_or_default(),
@@ -2647,10 +3216,25 @@ pub(crate) struct PipSettings {
     pub(crate) build_options: BuildOptions,
     pub(crate) allow_empty_requirements: bool,
     pub(crate) strict: bool,
+    pub(crate) check_abi: bool,
     pub(crate) dependency_mode: DependencyMode,
     pub(crate) resolution: ResolutionMode,
+    pub(crate) resolution_package: ResolutionPackage,
     pub(crate) prerelease: PrereleaseMode,
+    pub(crate) prerelease_package: Vec<PackageName>,
     pub(crate) fork_strategy: ForkStrategy,
+    pub(crate) embedded_index_strategy: EmbeddedIndexStrategy,
+    pub(crate) invalid_metadata: InvalidMetadataPolicy,
+    pub(crate) yanked: YankedPolicy,
+    pub(crate) index_unavailable: IndexUnavailablePolicy,
+    pub(crate) wheel_preference: Vec<WheelPreference>,
+    pub(crate) local_version_preference: Vec<LocalVersionPreference>,
+    pub(crate) resolution_timeout: Option<u64>,
+    pub(crate) max_backtracks: Option<usize>,
+    pub(crate) source_policy: Vec<PackageSourcePolicy>,
+    pub(crate) provided_by: Vec<PackageProvider>,
+    pub(crate) max_total_download_size: Option<ByteSize>,
+    pub(crate) max_package_size: Option<ByteSize>,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) output_file: Option<PathBuf>,
     pub(crate) no_strip_extras: bool,
@@ -2659,17 +3243,23 @@ pub(crate) struct PipSettings {
     pub(crate) no_header: bool,
     pub(crate) custom_compile_command: Option<String>,
     pub(crate) generate_hashes: bool,
+    pub(crate) only_hashed: bool,
     pub(crate) config_setting: ConfigSettings,
+    pub(crate) config_setting_package: PackageConfigSettings,
+    pub(crate) build_env: PackageBuildEnv,
     pub(crate) python_version: Option<PythonVersion>,
     pub(crate) python_platform: Option<TargetTriple>,
+    pub(crate) features: Vec<String>,
     pub(crate) universal: bool,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) exclude_newer_package: ExcludeNewerPackage,
     pub(crate) no_emit_package: Vec<PackageName>,
     pub(crate) emit_index_url: bool,
     pub(crate) emit_find_links: bool,
     pub(crate) emit_build_options: bool,
     pub(crate) emit_marker_expression: bool,
     pub(crate) emit_index_annotation: bool,
+    pub(crate) emit_requires_python: bool,
     pub(crate) annotation_style: AnnotationStyle,
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
@@ -2710,14 +3300,30 @@ impl PipSettings {
             no_build_isolation,
             no_build_isolation_package,
             strict,
+            check_abi,
             extra,
             all_extras,
             no_extra,
+            group,
             no_deps,
             allow_empty_requirements,
             resolution,
+            resolution_package,
             prerelease,
+            prerelease_package,
             fork_strategy,
+            embedded_index_strategy,
+            invalid_metadata,
+            yanked,
+            index_unavailable,
+            wheel_preference,
+            local_version_preference,
+            resolution_timeout,
+            max_backtracks,
+            source_policy,
+            provided_by,
+            max_total_download_size,
+            max_package_size,
             dependency_metadata,
             output_file,
             no_strip_extras,
@@ -2726,17 +3332,23 @@ impl PipSettings {
             no_header,
             custom_compile_command,
             generate_hashes,
+            only_hashed,
             config_settings,
+            config_settings_package,
+            build_env,
             python_version,
             python_platform,
+            feature,
             universal,
             exclude_newer,
+            exclude_newer_package,
             no_emit_package,
             emit_index_url,
             emit_find_links,
             emit_build_options,
             emit_marker_expression,
             emit_index_annotation,
+            emit_requires_python,
             annotation_style,
             link_mode,
             compile_bytecode,
@@ -2758,13 +3370,18 @@ impl PipSettings {
             index_strategy: top_level_index_strategy,
             keyring_provider: top_level_keyring_provider,
             resolution: top_level_resolution,
+            resolution_package: top_level_resolution_package,
             prerelease: top_level_prerelease,
+            prerelease_package: top_level_prerelease_package,
             fork_strategy: top_level_fork_strategy,
             dependency_metadata: top_level_dependency_metadata,
             config_settings: top_level_config_settings,
+            config_settings_package: top_level_config_settings_package,
+            build_env: top_level_build_env,
             no_build_isolation: top_level_no_build_isolation,
             no_build_isolation_package: top_level_no_build_isolation_package,
             exclude_newer: top_level_exclude_newer,
+            exclude_newer_package: top_level_exclude_newer_package,
             link_mode: top_level_link_mode,
             compile_bytecode: top_level_compile_bytecode,
             no_sources: top_level_no_sources,
@@ -2790,14 +3407,20 @@ impl PipSettings {
         let index_strategy = index_strategy.combine(top_level_index_strategy);
         let keyring_provider = keyring_provider.combine(top_level_keyring_provider);
         let resolution = resolution.combine(top_level_resolution);
+        let resolution_package = resolution_package.combine(top_level_resolution_package);
         let prerelease = prerelease.combine(top_level_prerelease);
+        let prerelease_package = prerelease_package.combine(top_level_prerelease_package);
         let fork_strategy = fork_strategy.combine(top_level_fork_strategy);
         let dependency_metadata = dependency_metadata.combine(top_level_dependency_metadata);
         let config_settings = config_settings.combine(top_level_config_settings);
+        let config_settings_package =
+            config_settings_package.combine(top_level_config_settings_package);
+        let build_env = build_env.combine(top_level_build_env);
         let no_build_isolation = no_build_isolation.combine(top_level_no_build_isolation);
         let no_build_isolation_package =
             no_build_isolation_package.combine(top_level_no_build_isolation_package);
         let exclude_newer = exclude_newer.combine(top_level_exclude_newer);
+        let exclude_newer_package = exclude_newer_package.combine(top_level_exclude_newer_package);
         let link_mode = link_mode.combine(top_level_link_mode);
         let compile_bytecode = compile_bytecode.combine(top_level_compile_bytecode);
         let no_sources = no_sources.combine(top_level_no_sources);
@@ -2834,7 +3457,7 @@ impl PipSettings {
                 false,
                 false,
                 false,
-                Vec::new(),
+                args.group.combine(group).unwrap_or_default(),
                 Vec::new(),
                 false,
                 Vec::new(),
@@ -2846,11 +3469,51 @@ impl PipSettings {
                 DependencyMode::Transitive
             },
             resolution: args.resolution.combine(resolution).unwrap_or_default(),
+            resolution_package: args
+                .resolution_package
+                .combine(resolution_package)
+                .unwrap_or_default(),
             prerelease: args.prerelease.combine(prerelease).unwrap_or_default(),
+            prerelease_package: args
+                .prerelease_package
+                .combine(prerelease_package)
+                .unwrap_or_default(),
             fork_strategy: args
                 .fork_strategy
                 .combine(fork_strategy)
                 .unwrap_or_default(),
+            embedded_index_strategy: args
+                .embedded_index_strategy
+                .combine(embedded_index_strategy)
+                .unwrap_or_default(),
+            invalid_metadata: args
+                .invalid_metadata
+                .combine(invalid_metadata)
+                .unwrap_or_default(),
+            yanked: args.yanked.combine(yanked).unwrap_or_default(),
+            index_unavailable: args
+                .index_unavailable
+                .combine(index_unavailable)
+                .unwrap_or_default(),
+            wheel_preference: args
+                .wheel_preference
+                .combine(wheel_preference)
+                .unwrap_or_default(),
+            local_version_preference: args
+                .local_version_preference
+                .combine(local_version_preference)
+                .unwrap_or_default(),
+            resolution_timeout: args.resolution_timeout.combine(resolution_timeout),
+            max_backtracks: args.max_backtracks.combine(max_backtracks),
+            source_policy: args
+                .source_policy
+                .combine(source_policy)
+                .unwrap_or_default(),
+            provided_by: args.provided_by.combine(provided_by).unwrap_or_default(),
+            max_total_download_size: args
+                .max_total_download_size
+                .combine(max_total_download_size),
+            max_package_size: args.max_package_size.combine(max_package_size),
             dependency_metadata: DependencyMetadata::from_entries(
                 args.dependency_metadata
                     .combine(dependency_metadata)
@@ -2884,6 +3547,7 @@ impl PipSettings {
                 .generate_hashes
                 .combine(generate_hashes)
                 .unwrap_or_default(),
+            only_hashed: args.only_hashed.combine(only_hashed).unwrap_or_default(),
             allow_empty_requirements: args
                 .allow_empty_requirements
                 .combine(allow_empty_requirements)
@@ -2900,10 +3564,20 @@ impl PipSettings {
                 .config_settings
                 .combine(config_settings)
                 .unwrap_or_default(),
+            config_setting_package: args
+                .config_settings_package
+                .combine(config_settings_package)
+                .unwrap_or_default(),
+            build_env: args.build_env.combine(build_env).unwrap_or_default(),
             python_version: args.python_version.combine(python_version),
             python_platform: args.python_platform.combine(python_platform),
+            features: args.feature.combine(feature).unwrap_or_default(),
             universal: args.universal.combine(universal).unwrap_or_default(),
             exclude_newer: args.exclude_newer.combine(exclude_newer),
+            exclude_newer_package: args
+                .exclude_newer_package
+                .combine(exclude_newer_package)
+                .unwrap_or_default(),
             no_emit_package: args
                 .no_emit_package
                 .combine(no_emit_package)
@@ -2928,6 +3602,10 @@ impl PipSettings {
                 .emit_index_annotation
                 .combine(emit_index_annotation)
                 .unwrap_or_default(),
+            emit_requires_python: args
+                .emit_requires_python
+                .combine(emit_requires_python)
+                .unwrap_or_default(),
             link_mode: args.link_mode.combine(link_mode).unwrap_or_default(),
             hash_checking: HashCheckingMode::from_args(
                 args.require_hashes.combine(require_hashes),
@@ -2949,6 +3627,7 @@ impl PipSettings {
                 args.no_sources.combine(no_sources).unwrap_or_default(),
             ),
             strict: args.strict.combine(strict).unwrap_or_default(),
+            check_abi: args.check_abi.combine(check_abi).unwrap_or_default(),
             upgrade: Upgrade::from_args(
                 args.upgrade.combine(upgrade),
                 args.upgrade_package
@@ -2982,6 +3661,22 @@ impl PipSettings {
             install_mirrors,
         }
     }
+
+    /// Apply custom installation paths for wheel `.data` directories to the `--target` or
+    /// `--prefix` directory.
+    fn with_scheme_paths(mut self, scheme_paths: Vec<SchemePath>) -> Self {
+        if scheme_paths.is_empty() {
+            return self;
+        }
+        if let Some(target) = self.target.take() {
+            self.target = Some(target.with_scheme_paths(scheme_paths));
+        } else if let Some(prefix) = self.prefix.take() {
+            self.prefix = Some(prefix.with_scheme_paths(scheme_paths));
+        } else {
+            warn_user_once!("`--scheme-path` has no effect without `--target` or `--prefix`");
+        }
+        self
+    }
 }
 
 impl<'a> From<ResolverInstallerSettingsRef<'a>> for ResolverSettingsRef<'a> {
@@ -2991,13 +3686,18 @@ impl<'a> From<ResolverInstallerSettingsRef<'a>> for ResolverSettingsRef<'a> {
             index_strategy: settings.index_strategy,
             keyring_provider: settings.keyring_provider,
             resolution: settings.resolution,
+            resolution_package: settings.resolution_package,
             prerelease: settings.prerelease,
+            prerelease_package: settings.prerelease_package,
             fork_strategy: settings.fork_strategy,
             dependency_metadata: settings.dependency_metadata,
             config_setting: settings.config_setting,
+            config_setting_package: settings.config_setting_package,
+            build_env: settings.build_env,
             no_build_isolation: settings.no_build_isolation,
             no_build_isolation_package: settings.no_build_isolation_package,
             exclude_newer: settings.exclude_newer,
+            exclude_newer_package: settings.exclude_newer_package,
             link_mode: settings.link_mode,
             upgrade: settings.upgrade,
             build_options: settings.build_options,
@@ -3014,9 +3714,12 @@ impl<'a> From<ResolverInstallerSettingsRef<'a>> for InstallerSettingsRef<'a> {
             keyring_provider: settings.keyring_provider,
             dependency_metadata: settings.dependency_metadata,
             config_setting: settings.config_setting,
+            config_setting_package: settings.config_setting_package,
+            build_env: settings.build_env,
             no_build_isolation: settings.no_build_isolation,
             no_build_isolation_package: settings.no_build_isolation_package,
             exclude_newer: settings.exclude_newer,
+            exclude_newer_package: settings.exclude_newer_package,
             link_mode: settings.link_mode,
             compile_bytecode: settings.compile_bytecode,
             reinstall: settings.reinstall,
@@ -3123,6 +3826,23 @@ mod env {
         EnvVars::UV_PYTHON_DOWNLOADS,
         "one of 'auto', 'true', 'manual', 'never', or 'false'",
     );
+
+    pub(super) const PIP_INDEX_URL: (&str, &str) = (EnvVars::PIP_INDEX_URL, "a URL or path");
+
+    pub(super) const PIP_EXTRA_INDEX_URL: (&str, &str) = (
+        EnvVars::PIP_EXTRA_INDEX_URL,
+        "a space-separated list of URLs or paths",
+    );
+
+    pub(super) const PIP_FIND_LINKS: (&str, &str) = (
+        EnvVars::PIP_FIND_LINKS,
+        "a space-separated list of URLs or paths",
+    );
+
+    pub(super) const PIP_NO_BINARY: (&str, &str) = (
+        EnvVars::PIP_NO_BINARY,
+        "a comma-separated list of package names, `:all:`, or `:none:`",
+    );
 }
 
 /// Attempt to load and parse an environment variable with the given name.
@@ -3144,6 +3864,61 @@ where
     )
 }
 
+/// Attempt to load and parse a list from an environment variable with the given name, split on
+/// whitespace and any of the given additional delimiters.
+///
+/// Exits the program and prints an error message containing the expected type if
+/// parsing values.
+fn env_list<T>((name, expected): (&str, &str), delimiters: &[char]) -> Option<Vec<T>>
+where
+    T: FromStr,
+{
+    let val = match std::env::var(name) {
+        Ok(val) => val,
+        Err(VarError::NotPresent) => return None,
+        Err(VarError::NotUnicode(_)) => parse_failure(name, expected),
+    };
+    Some(
+        val.split(|c: char| c.is_whitespace() || delimiters.contains(&c))
+            .filter(|item| !item.is_empty())
+            .map(|item| {
+                item.parse()
+                    .unwrap_or_else(|_| parse_failure(name, expected))
+            })
+            .collect(),
+    )
+}
+
+/// Load pip's environment variables (e.g., `PIP_INDEX_URL`) as [`PipOptions`], if `--pip-env` is
+/// enabled.
+///
+/// The returned options are layered beneath the command-line arguments, such that uv's own
+/// arguments and environment variables take precedence.
+fn pip_env_options(enabled: bool) -> PipOptions {
+    if !enabled {
+        return PipOptions::default();
+    }
+    PipOptions {
+        index_url: env(env::PIP_INDEX_URL)
+            .map(Index::from_index_url)
+            .map(PipIndex::from),
+        extra_index_url: env_list(env::PIP_EXTRA_INDEX_URL, &[]).map(|urls| {
+            urls.into_iter()
+                .map(Index::from_extra_index_url)
+                .map(PipExtraIndex::from)
+                .collect()
+        }),
+        find_links: env_list(env::PIP_FIND_LINKS, &[]).map(|urls| {
+            urls.into_iter()
+                .map(Index::from_find_links)
+                .map(PipFindLinks::from)
+                .collect()
+        }),
+        no_binary: env_list(env::PIP_NO_BINARY, &[',']),
+        ..PipOptions::default()
+    }
+}
+
 /// Prints a parse error and exits the process.
 #[allow(clippy::exit, clippy::print_stderr)]
 fn parse_failure(name: &str, expected: &str) -> ! {
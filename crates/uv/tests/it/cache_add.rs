@@ -0,0 +1,113 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use predicates::prelude::predicate;
+
+use crate::common::uv_snapshot;
+use crate::common::TestContext;
+
+/// `cache add` should store local wheels and source distributions in the cache, keyed by their
+/// digests, for reuse regardless of where they're later fetched from.
+#[test]
+fn cache_add() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let wheelhouse = context.temp_dir.child("wheelhouse");
+    wheelhouse.create_dir_all()?;
+    wheelhouse.child("ok-1.0.0-py3-none-any.whl").write_file(
+        &context
+            .workspace_root
+            .join("scripts/links/ok-1.0.0-py3-none-any.whl"),
+    )?;
+    wheelhouse.child("extras-0.0.1.tar.gz").write_file(
+        &context
+            .workspace_root
+            .join("scripts/links/extras-0.0.1.tar.gz"),
+    )?;
+
+    uv_snapshot!(context.filters(), context.cache_add().arg("wheelhouse"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Added 2 distributions to: [CACHE_DIR]/
+    "###);
+
+    // Both distributions should be keyed by their SHA-256 digests, and the wheel's metadata should
+    // be registered alongside it.
+    let wheel = context.cache_dir.child(
+        "wheels-v5/sha256/79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f/ok",
+    );
+    wheel
+        .child("1.0.0-py3-none-any.rev")
+        .assert(predicate::path::is_file());
+    wheel
+        .child("1.0.0-py3-none-any.msgpack")
+        .assert(predicate::path::is_file());
+    context
+        .cache_dir
+        .child(
+            "sdists-v8/sha256/8647b718f60d556e1cc02e895a15ab922a310fd45284b2cd683a25bafd570459/extras/source.tar.gz",
+        )
+        .assert(predicate::path::is_file());
+
+    // Adding the same distributions again should be a no-op.
+    uv_snapshot!(context.filters(), context.cache_add().arg("wheelhouse"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Added 0 distributions to: [CACHE_DIR]/ (2 already cached)
+    "###);
+
+    // Installing the same wheel from a different location, with its hash, should reuse the
+    // cached archive rather than unpacking the wheel again.
+    let elsewhere = context.temp_dir.child("elsewhere");
+    elsewhere.create_dir_all()?;
+    elsewhere.child("ok-1.0.0-py3-none-any.whl").write_file(
+        &context
+            .workspace_root
+            .join("scripts/links/ok-1.0.0-py3-none-any.whl"),
+    )?;
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "./elsewhere/ok-1.0.0-py3-none-any.whl --hash=sha256:79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f",
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0 (from file://[TEMP_DIR]/elsewhere/ok-1.0.0-py3-none-any.whl)
+    "###);
+
+    assert_eq!(
+        fs_err::read_dir(context.cache_dir.child("archive-v0").path())?.count(),
+        1
+    );
+
+    Ok(())
+}
+
+/// `cache add` should reject paths that aren't directories.
+#[test]
+fn cache_add_missing_directory() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.cache_add().arg("wheelhouse"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Expected a directory of distributions, but found: `wheelhouse`
+    "###);
+}
@@ -0,0 +1,119 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+
+use crate::common::uv_snapshot;
+use crate::common::TestContext;
+
+/// Return the paths to the metadata entries in the cache bucket with the given prefix.
+fn metadata_entries(context: &TestContext, bucket: &str) -> Vec<PathBuf> {
+    walkdir::WalkDir::new(context.cache_dir.path())
+        .into_iter()
+        .filter_map(std::result::Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "msgpack"))
+        .filter(|entry| {
+            entry
+                .path()
+                .components()
+                .any(|component| component.as_os_str().to_string_lossy().starts_with(bucket))
+        })
+        .map(walkdir::DirEntry::into_path)
+        .collect()
+}
+
+/// Return the paths to the cached wheel metadata entries.
+fn wheel_metadata_entries(context: &TestContext) -> Vec<PathBuf> {
+    metadata_entries(context, "wheels-")
+}
+
+/// Return the paths to the metadata store entries.
+fn stored_metadata_entries(context: &TestContext) -> Vec<PathBuf> {
+    metadata_entries(context, "metadata-")
+}
+
+/// `cache gc-metadata` should remove corrupt metadata entries, and all entries with `--all`.
+#[test]
+fn gc_metadata() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([(r"\(\d+(\.\d+)?[KMG]?i?B\)", "([SIZE])")])
+        .collect();
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+    assert_eq!(wheel_metadata_entries(&context).len(), 1);
+    assert_eq!(stored_metadata_entries(&context).len(), 1);
+
+    // The cached metadata is intact, so nothing should be removed.
+    uv_snapshot!(&filters, context.cache_gc_metadata(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Collecting metadata in cache at: [CACHE_DIR]/
+    No corrupt metadata entries found
+    "###);
+
+    // Corrupt the cached metadata.
+    let entries = wheel_metadata_entries(&context);
+    fs_err::write(&entries[0], "corrupt")?;
+
+    uv_snapshot!(&filters, context.cache_gc_metadata(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Collecting metadata in cache at: [CACHE_DIR]/
+    Removed 1 metadata entry ([SIZE])
+    "###);
+    assert!(wheel_metadata_entries(&context).is_empty());
+
+    // Resolving again should reuse the metadata store, rather than re-fetching the metadata.
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--reinstall")
+        .assert()
+        .success();
+    assert!(wheel_metadata_entries(&context).is_empty());
+
+    // A corrupt entry in the metadata store should be replaced on next use.
+    let stored = stored_metadata_entries(&context);
+    assert_eq!(stored.len(), 1);
+    fs_err::write(&stored[0], "corrupt")?;
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--reinstall")
+        .assert()
+        .success();
+    assert_eq!(wheel_metadata_entries(&context).len(), 1);
+    assert_ne!(fs_err::read(&stored[0])?, b"corrupt");
+
+    // With `--all`, intact metadata should be removed too.
+    uv_snapshot!(&filters, context.cache_gc_metadata().arg("--all"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Collecting metadata in cache at: [CACHE_DIR]/
+    Removed 2 metadata entries ([SIZE])
+    "###);
+    assert!(wheel_metadata_entries(&context).is_empty());
+    assert!(stored_metadata_entries(&context).is_empty());
+
+    Ok(())
+}
@@ -666,6 +666,38 @@ impl TestContext {
         command
     }
 
+    /// Create a `pip verify` command with options shared across scenarios.
+    pub fn pip_verify(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pip").arg("verify");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
+    /// Create a `pip lint` command with options shared across scenarios.
+    pub fn pip_lint(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pip").arg("lint");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
+    /// Create a `pip merge` command with options shared across scenarios.
+    pub fn pip_merge(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pip").arg("merge");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
+    /// Create a `pip refresh-editables` command with options shared across scenarios.
+    pub fn pip_refresh_editables(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pip").arg("refresh-editables");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
     pub fn pip_list(&self) -> Command {
         let mut command = self.new_command();
         command.arg("pip").arg("list");
@@ -775,6 +807,49 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv tags` command with options shared across scenarios.
+    pub fn tags(&self) -> Command {
+        let mut command = self.new_command();
+        command
+            .arg("tags")
+            .env(EnvVars::UV_PYTHON_INSTALL_DIR, "")
+            .current_dir(&self.temp_dir);
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv doctor` command with options shared across scenarios.
+    pub fn doctor(&self) -> Command {
+        let mut command = self.new_command();
+        command
+            .arg("doctor")
+            .env(EnvVars::UV_PYTHON_INSTALL_DIR, "")
+            .current_dir(&self.temp_dir);
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv stats` command with options shared across scenarios.
+    pub fn stats(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("stats").current_dir(&self.temp_dir);
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv python inspect` command with options shared across scenarios.
+    pub fn python_inspect(&self) -> Command {
+        let mut command = self.new_command();
+        command
+            .arg("python")
+            .arg("inspect")
+            .env(EnvVars::UV_PREVIEW, "1")
+            .env(EnvVars::UV_PYTHON_INSTALL_DIR, "")
+            .current_dir(&self.temp_dir);
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv python install` command with options shared across scenarios.
     pub fn python_install(&self) -> Command {
         let mut command = self.new_command();
@@ -896,6 +971,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache add` command.
+    pub fn cache_add(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("add");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv cache clean` command.
     pub fn clean(&self) -> Command {
         let mut command = self.new_command();
@@ -912,6 +995,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache gc-metadata` command.
+    pub fn cache_gc_metadata(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("gc-metadata");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv version` command.
+    pub fn version(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("version");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv build_backend` command.
     ///
     /// Note that this command is hidden and only invoking it through a build frontend is supported.
@@ -0,0 +1,44 @@
+use uv_static::EnvVars;
+
+use crate::common::TestContext;
+
+/// Run the checks in offline mode, such that the index checks are skipped.
+#[test]
+fn doctor_offline() {
+    let context = TestContext::new("3.12");
+
+    let output = context
+        .doctor()
+        .arg("--offline")
+        .env_remove(EnvVars::SSL_CERT_FILE)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("ok: Cache is writable at"));
+    assert!(stderr.contains("ok: Found Python 3.12"));
+    assert!(stderr.contains("warning: Skipped index checks in offline mode"));
+    assert!(stderr.ends_with("All checks passed\n"));
+}
+
+/// Report a failure, with a remediation, if no Python interpreter can be found.
+#[test]
+fn doctor_missing_python() {
+    let context = TestContext::new("3.12");
+
+    let output = context
+        .doctor()
+        .arg("--offline")
+        .arg("--python")
+        .arg("3.8.0")
+        .env_remove(EnvVars::SSL_CERT_FILE)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("error: Failed to find a Python interpreter"));
+    assert!(stderr.contains("hint: Install a Python interpreter with `uv python install`"));
+    assert!(stderr.ends_with("1 check failed\n"));
+}
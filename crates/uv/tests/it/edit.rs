@@ -3801,6 +3801,54 @@ fn add_frozen() -> Result<()> {
     Ok(())
 }
 
+/// Normalize and simplify the version specifiers with `--normalize-specifiers`.
+#[test]
+fn add_normalize_specifiers() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add()
+        .arg("anyio>=3.0.0,>=3.7.0,<5.0.0,!=6.0")
+        .arg("iniconfig==2.0.0.0")
+        .arg("--normalize-specifiers")
+        .arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio>=3.7,<5",
+            "iniconfig==2",
+        ]
+        "###
+        );
+    });
+
+    Ok(())
+}
+
 /// Add a requirement without updating the environment.
 #[test]
 fn add_no_sync() -> Result<()> {
@@ -30,6 +30,9 @@ fn help() {
       publish                    Upload distributions to an index
       cache                      Manage uv's cache
       self                       Manage the uv executable
+      tags                       Display the wheel tags supported by a Python interpreter
+      doctor                     Check that uv can operate in the current environment
+      stats                      Display the statistics recorded for previous invocations
       version                    Display uv's version
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
@@ -62,6 +65,13 @@ fn help() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --print-downloads[=<FILE>]
+              Print every URL fetched during the command, along with its size and SHA-256 hash
+          --max-requests-per-second <N>
+              The maximum number of requests per second to send to any one host [env:
+              UV_MAX_REQUESTS_PER_SECOND=]
+          --build-jobs <N>
+              The maximum number of source distributions to build concurrently
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -111,6 +121,9 @@ fn help_flag() {
       publish  Upload distributions to an index
       cache    Manage uv's cache
       self     Manage the uv executable
+      tags     Display the wheel tags supported by a Python interpreter
+      doctor   Check that uv can operate in the current environment
+      stats    Display the statistics recorded for previous invocations
       version  Display uv's version
       help     Display documentation for a command
 
@@ -142,6 +155,13 @@ fn help_flag() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --print-downloads[=<FILE>]
+              Print every URL fetched during the command, along with its size and SHA-256 hash
+          --max-requests-per-second <N>
+              The maximum number of requests per second to send to any one host [env:
+              UV_MAX_REQUESTS_PER_SECOND=]
+          --build-jobs <N>
+              The maximum number of source distributions to build concurrently
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -190,6 +210,9 @@ fn help_short_flag() {
       publish  Upload distributions to an index
       cache    Manage uv's cache
       self     Manage the uv executable
+      tags     Display the wheel tags supported by a Python interpreter
+      doctor   Check that uv can operate in the current environment
+      stats    Display the statistics recorded for previous invocations
       version  Display uv's version
       help     Display documentation for a command
 
@@ -221,6 +244,13 @@ fn help_short_flag() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --print-downloads[=<FILE>]
+              Print every URL fetched during the command, along with its size and SHA-256 hash
+          --max-requests-per-second <N>
+              The maximum number of requests per second to send to any one host [env:
+              UV_MAX_REQUESTS_PER_SECOND=]
+          --build-jobs <N>
+              The maximum number of source distributions to build concurrently
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -294,6 +324,7 @@ fn help_subcommand() {
       list       List the available Python installations
       install    Download and install Python versions
       find       Search for a Python installation
+      inspect    Show information about a Python installation
       pin        Pin to a specific Python version
       dir        Show the uv Python installation directory
       uninstall  Uninstall Python versions
@@ -398,6 +429,34 @@ fn help_subcommand() {
               
               [env: UV_NO_PROGRESS=]
 
+          --print-downloads[=<FILE>]
+              Print every URL fetched during the command, along with its size and SHA-256 hash.
+              
+              Each download is printed on its own line, as `<url> <size> sha256:<hash>`, once the
+              command completes. Responses served from the cache are not included. Responses whose body
+              was not read to completion are marked as `(incomplete)`.
+              
+              If a file path is provided (e.g., `--print-downloads=downloads.txt`), the log is written
+              to that file instead of stderr.
+
+          --max-requests-per-second <N>
+              The maximum number of requests per second to send to any one host.
+              
+              Useful for indexes that enforce rate limits, such as Azure Artifacts. Regardless of this
+              setting, uv slows down when an index throttles requests (via `Retry-After`), or advertises
+              that its rate limit is nearly exhausted (via `X-RateLimit-*` headers).
+              
+              [env: UV_MAX_REQUESTS_PER_SECOND=]
+
+          --build-jobs <N>
+              The maximum number of source distributions to build concurrently.
+              
+              When more builds are pending than can run at once, uv schedules the cheapest builds (by
+              the size of their source tree) first, such that a single large build doesn't hold up the
+              rest of the resolution.
+              
+              Defaults to the number of available CPU cores.
+
           --directory <DIRECTORY>
               Change to the given directory prior to running the command.
               
@@ -647,6 +706,34 @@ fn help_subsubcommand() {
               
               [env: UV_NO_PROGRESS=]
 
+          --print-downloads[=<FILE>]
+              Print every URL fetched during the command, along with its size and SHA-256 hash.
+              
+              Each download is printed on its own line, as `<url> <size> sha256:<hash>`, once the
+              command completes. Responses served from the cache are not included. Responses whose body
+              was not read to completion are marked as `(incomplete)`.
+              
+              If a file path is provided (e.g., `--print-downloads=downloads.txt`), the log is written
+              to that file instead of stderr.
+
+          --max-requests-per-second <N>
+              The maximum number of requests per second to send to any one host.
+              
+              Useful for indexes that enforce rate limits, such as Azure Artifacts. Regardless of this
+              setting, uv slows down when an index throttles requests (via `Retry-After`), or advertises
+              that its rate limit is nearly exhausted (via `X-RateLimit-*` headers).
+              
+              [env: UV_MAX_REQUESTS_PER_SECOND=]
+
+          --build-jobs <N>
+              The maximum number of source distributions to build concurrently.
+              
+              When more builds are pending than can run at once, uv schedules the cheapest builds (by
+              the size of their source tree) first, such that a single large build doesn't hold up the
+              rest of the resolution.
+              
+              Defaults to the number of available CPU cores.
+
           --directory <DIRECTORY>
               Change to the given directory prior to running the command.
               
@@ -711,6 +798,7 @@ fn help_flag_subcommand() {
       list       List the available Python installations
       install    Download and install Python versions
       find       Search for a Python installation
+      inspect    Show information about a Python installation
       pin        Pin to a specific Python version
       dir        Show the uv Python installation directory
       uninstall  Uninstall Python versions
@@ -743,6 +831,13 @@ fn help_flag_subcommand() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --print-downloads[=<FILE>]
+              Print every URL fetched during the command, along with its size and SHA-256 hash
+          --max-requests-per-second <N>
+              The maximum number of requests per second to send to any one host [env:
+              UV_MAX_REQUESTS_PER_SECOND=]
+          --build-jobs <N>
+              The maximum number of source distributions to build concurrently
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -816,6 +911,13 @@ fn help_flag_subsubcommand() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --print-downloads[=<FILE>]
+              Print every URL fetched during the command, along with its size and SHA-256 hash
+          --max-requests-per-second <N>
+              The maximum number of requests per second to send to any one host [env:
+              UV_MAX_REQUESTS_PER_SECOND=]
+          --build-jobs <N>
+              The maximum number of source distributions to build concurrently
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -860,6 +962,9 @@ fn help_unknown_subcommand() {
         publish
         cache
         self
+        tags
+        doctor
+        stats
         version
         generate-shell-completion
     "###);
@@ -887,6 +992,9 @@ fn help_unknown_subcommand() {
         publish
         cache
         self
+        tags
+        doctor
+        stats
         version
         generate-shell-completion
     "###);
@@ -906,6 +1014,7 @@ fn help_unknown_subsubcommand() {
         list
         install
         find
+        inspect
         pin
         dir
         uninstall
@@ -941,6 +1050,9 @@ fn help_with_global_option() {
       publish                    Upload distributions to an index
       cache                      Manage uv's cache
       self                       Manage the uv executable
+      tags                       Display the wheel tags supported by a Python interpreter
+      doctor                     Check that uv can operate in the current environment
+      stats                      Display the statistics recorded for previous invocations
       version                    Display uv's version
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
@@ -973,6 +1085,13 @@ fn help_with_global_option() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --print-downloads[=<FILE>]
+              Print every URL fetched during the command, along with its size and SHA-256 hash
+          --max-requests-per-second <N>
+              The maximum number of requests per second to send to any one host [env:
+              UV_MAX_REQUESTS_PER_SECOND=]
+          --build-jobs <N>
+              The maximum number of source distributions to build concurrently
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -1057,6 +1176,9 @@ fn help_with_no_pager() {
       publish                    Upload distributions to an index
       cache                      Manage uv's cache
       self                       Manage the uv executable
+      tags                       Display the wheel tags supported by a Python interpreter
+      doctor                     Check that uv can operate in the current environment
+      stats                      Display the statistics recorded for previous invocations
       version                    Display uv's version
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
@@ -1089,6 +1211,13 @@ fn help_with_no_pager() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --print-downloads[=<FILE>]
+              Print every URL fetched during the command, along with its size and SHA-256 hash
+          --max-requests-per-second <N>
+              The maximum number of requests per second to send to any one host [env:
+              UV_MAX_REQUESTS_PER_SECOND=]
+          --build-jobs <N>
+              The maximum number of source distributions to build concurrently
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -15106,6 +15106,72 @@ fn lock_named_index() -> Result<()> {
     Ok(())
 }
 
+/// Configure a freshness lifetime for the metadata served by an index.
+#[test]
+fn lock_index_metadata_ttl() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["typing-extensions"]
+
+        [[tool.uv.index]]
+        name = "heron"
+        url = "https://pypi-proxy.fly.dev/simple"
+        cache = { metadata-ttl = 3600 }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // Re-locking from scratch reuses the cached metadata without making any requests, so it
+    // succeeds even if the index is unreachable.
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .env(EnvVars::HTTPS_PROXY, "http://127.0.0.1:1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // `--refresh` still revalidates the cached metadata, which requires reaching the index.
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+
+    context
+        .lock()
+        .arg("--refresh")
+        .env(EnvVars::HTTPS_PROXY, "http://127.0.0.1:1")
+        .assert()
+        .failure();
+
+    uv_snapshot!(context.filters(), context.lock().arg("--refresh"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn lock_default_index() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -19175,6 +19241,94 @@ fn lock_dry_run() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn lock_verify_determinism() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "iniconfig==1.1.1"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--verify-determinism"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Verified that the resolution is deterministic
+    "###);
+
+    // The lockfile is written as usual.
+    assert!(context.temp_dir.child("uv.lock").exists());
+
+    // The shuffled resolution is verified against an existing lockfile, too.
+    uv_snapshot!(context.filters(), context.lock().arg("--verify-determinism").arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Verified that the resolution is deterministic
+    "###);
+
+    Ok(())
+}
+
+/// `--verify-determinism` respects the versions in the existing lockfile, rather than resolving
+/// from scratch.
+#[test]
+fn lock_verify_determinism_existing() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig<2"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Relax the requirement; the locked version remains preferred.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--verify-determinism"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Verified that the resolution is deterministic
+    "###);
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(r#"version = "1.1.1""#));
+
+    Ok(())
+}
+
 #[test]
 fn lock_dry_run_noop() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -11,12 +11,21 @@ mod build;
 #[cfg(feature = "python")]
 mod build_backend;
 
+#[cfg(feature = "python")]
+mod cache_add;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_clean;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_gc_metadata;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_prune;
 
+#[cfg(feature = "python")]
+mod doctor;
+
 #[cfg(all(feature = "python", feature = "pypi", feature = "test-ecosystem"))]
 mod ecosystem;
 
@@ -54,8 +63,14 @@ mod pip_install;
 
 mod pip_install_scenarios;
 
+mod pip_lint;
+
+mod pip_merge;
+
 mod pip_list;
 
+mod pip_refresh_editables;
+
 mod pip_show;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
@@ -64,6 +79,9 @@ mod pip_sync;
 mod pip_tree;
 mod pip_uninstall;
 
+#[cfg(feature = "python")]
+mod pip_verify;
+
 #[cfg(feature = "pypi")]
 mod publish;
 
@@ -90,6 +108,15 @@ mod show_settings;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod sync;
 
+#[cfg(feature = "python")]
+mod stats;
+
+#[cfg(feature = "python")]
+mod tags;
+
+#[cfg(feature = "python")]
+mod test_registry;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_dir;
 
@@ -111,6 +138,8 @@ mod tool_upgrade;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tree;
 
+mod version;
+
 #[cfg(feature = "python")]
 mod venv;
 
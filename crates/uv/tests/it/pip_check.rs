@@ -1,6 +1,9 @@
 use anyhow::Result;
 use assert_fs::fixture::FileWriteStr;
 use assert_fs::fixture::PathChild;
+use assert_fs::fixture::PathCreateDir;
+
+use uv_static::EnvVars;
 
 use crate::common::uv_snapshot;
 use crate::common::TestContext;
@@ -187,3 +190,57 @@ fn check_multiple_incompatible_packages() -> Result<()> {
 
     Ok(())
 }
+
+// iniconfig 1.0.0 is present in a `PYTHONPATH` entry, and so shadows the installed iniconfig 2.0.0.
+#[test]
+fn check_shadowed_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let shadow = context.temp_dir.child("shadow");
+    shadow.child("iniconfig-1.0.0.dist-info").create_dir_all()?;
+
+    uv_snapshot!(context.filters(), context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .env(EnvVars::PYTHONPATH, shadow.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    warning: The package `iniconfig` is installed at `2.0.0`, but is shadowed by `1.0.0` at: [TEMP_DIR]/shadow/iniconfig-1.0.0.dist-info. Python will import `1.0.0` instead, as it precedes the installed package on `sys.path` (e.g., via `PYTHONPATH` or a `.pth` file).
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context
+        .pip_check()
+        .env(EnvVars::PYTHONPATH, shadow.path()), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 1 package in [TIME]
+    Found 1 incompatibility
+    The package `iniconfig` is installed at `2.0.0`, but is shadowed by `1.0.0` at: [TEMP_DIR]/shadow/iniconfig-1.0.0.dist-info. Python will import `1.0.0` instead, as it precedes the installed package on `sys.path` (e.g., via `PYTHONPATH` or a `.pth` file).
+    "###
+    );
+
+    // Without `PYTHONPATH`, the package is no longer shadowed.
+    uv_snapshot!(context.pip_check(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 1 package in [TIME]
+    All installed packages are compatible
+    "###
+    );
+
+    Ok(())
+}
@@ -17,6 +17,7 @@ use crate::common::{
 };
 use uv_fs::Simplified;
 use uv_static::EnvVars;
+use uv_test_registry::{Release, TestRegistry};
 
 #[test]
 fn missing_requirements_txt() {
@@ -435,6 +436,152 @@ fn install_package() {
     context.assert_command("import flask").success();
 }
 
+/// Write the URL, size, and hash of every fetched file with `--print-downloads`.
+#[test]
+fn install_print_downloads() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--print-downloads=downloads.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    let downloads = fs_err::read_to_string(context.temp_dir.child("downloads.txt"))?;
+    let lines = downloads.lines().collect::<Vec<_>>();
+    assert!(lines
+        .iter()
+        .any(|line| line.starts_with("https://pypi.org/simple/iniconfig/ ")));
+    assert!(lines.iter().any(|line| {
+        line.contains("/iniconfig-2.0.0-py3-none-any.whl ") && !line.ends_with(" (incomplete)")
+    }));
+    assert!(lines.iter().all(|line| line.contains(" sha256:")));
+
+    Ok(())
+}
+
+/// Capture the output of a failing build in a per-package log file with `--build-log-dir`.
+#[test]
+fn install_build_log_dir() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+    project.child("setup.py").write_str(indoc! {r#"
+        import sys
+
+        print("Compiling native extension...", file=sys.stderr)
+        sys.exit(1)
+    "#})?;
+
+    let filters = [
+        (r"exit code: 1", "exit status: 1"),
+        (
+            r"project-0\.1\.0-[0-9a-f]{16}\.log",
+            "project-0.1.0-[HASH].log",
+        ),
+    ]
+    .into_iter()
+    .chain(context.filters())
+    .collect::<Vec<_>>();
+    uv_snapshot!(filters, context.pip_install()
+        .arg("./project")
+        .arg("--build-log-dir")
+        .arg("logs"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `project @ file://[TEMP_DIR]/project`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `setuptools.build_meta.build_wheel` failed (exit status: 1)
+
+          [stderr]
+          Compiling native extension...
+
+          The full build output was written to: logs/project-0.1.0-[HASH].log
+
+          hint: This usually indicates a problem with the package or the build environment.
+    "###
+    );
+
+    // The log contains the full output of the build.
+    let log = fs_err::read_dir(context.temp_dir.child("logs").path())?
+        .next()
+        .expect("a build log to be written")?;
+    assert!(log
+        .file_name()
+        .to_string_lossy()
+        .starts_with("project-0.1.0-"));
+    let log = fs_err::read_to_string(log.path())?;
+    assert!(log.contains("Compiling native extension..."));
+
+    Ok(())
+}
+
+/// Recompress a wheel built from a source distribution before installing it.
+#[test]
+fn install_optimize_wheels() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+    project
+        .child("project")
+        .child("__init__.py")
+        .write_str("MESSAGE = 'Hello, world!'\n")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./project")
+        .arg("--optimize-wheels")
+        .arg("recompress,strip"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/project)
+    "###
+    );
+
+    // The recompressed wheel is installed intact.
+    context
+        .assert_command("from project import MESSAGE; assert MESSAGE == 'Hello, world!'")
+        .success();
+
+    Ok(())
+}
+
 /// Install a package from a `requirements.txt` into a virtual environment.
 #[test]
 fn install_requirements_txt() -> Result<()> {
@@ -1630,6 +1777,83 @@ fn reinstall_build_system() -> Result<()> {
     Ok(())
 }
 
+/// Container interpreters are requested as `docker://<container>#<path>`; invalid requests and
+/// unsupported options are rejected before contacting the container runtime.
+#[test]
+fn install_container_python_invalid() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--python")
+        .arg("docker://devcontainer")
+        .arg("iniconfig"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Invalid container Python request: `docker://devcontainer` (expected, e.g., `docker://<container>#/usr/bin/python3`)
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--python")
+        .arg("podman://devcontainer#/usr/bin/python3")
+        .arg("--target")
+        .arg("target")
+        .arg("iniconfig"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--target`, `--prefix`, `--pypackages`, and `--environment` are not supported with a container Python interpreter (`podman://devcontainer#/usr/bin/python3`)
+    "###
+    );
+}
+
+/// Stage packages for another platform in a `--target` directory, without generating entry point
+/// scripts or compiling bytecode.
+#[test]
+#[cfg(not(windows))]
+fn install_target_foreign_platform() {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("tqdm==4.66.2")
+        .arg("--target")
+        .arg("target")
+        .arg("--python-platform")
+        .arg("x86_64-pc-windows-msvc")
+        .arg("--only-binary")
+        .arg(":all:")
+        .arg("--compile-bytecode"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/python
+    warning: Skipping bytecode compilation, which isn't supported when installing packages for another platform
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + colorama==0.4.6
+     + tqdm==4.66.2
+    "###
+    );
+
+    // The Windows-only dependency is included, but the `tqdm` entry point is omitted.
+    let target = context.temp_dir.child("target");
+    assert!(target.child("tqdm").is_dir());
+    assert!(target.child("colorama").is_dir());
+    assert!(!target.child("bin").child("tqdm").exists());
+    assert!(!target.child("tqdm").child("__pycache__").exists());
+}
+
 /// Install a package without using the remote index
 #[test]
 fn install_no_index() {
@@ -1653,6 +1877,45 @@ fn install_no_index() {
     context.assert_command("import flask").failure();
 }
 
+/// Install from a remote `--find-links` source with `--no-index` and `--offline`, reporting every
+/// distribution that isn't cached upfront.
+#[test]
+fn install_no_index_offline() {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
+
+    // Resolve once while online, to cache the `--find-links` page, but without fetching any wheels.
+    context
+        .pip_install()
+        .arg("tqdm")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg("https://astral-sh.github.io/pytorch-mirror/whl/torch_stable.html")
+        .arg("--dry-run")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("tqdm")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg("https://astral-sh.github.io/pytorch-mirror/whl/torch_stable.html")
+        .arg("--offline"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: The following distributions are not cached and can't be fetched while offline:
+      - tqdm==4.64.1
+
+    hint: Provide the distributions via a local `--find-links` directory, or populate the cache while online
+    "###
+    );
+
+    context.assert_command("import tqdm").failure();
+}
+
 /// Install a package without using the remote index
 /// Covers a case where the user requests a version which should be included in the error
 #[test]
@@ -2522,6 +2785,71 @@ fn install_no_binary_cache() {
     );
 }
 
+/// Force a package to be rebuilt with `--rebuild-package`, ignoring the cached build.
+#[test]
+fn install_rebuild_package() {
+    let context = TestContext::new("3.12");
+
+    // Build and install a source distribution.
+    uv_snapshot!(
+        context.pip_install().arg("idna").arg("--no-binary").arg(":all:"),
+        @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + idna==3.6
+    "###
+    );
+
+    // Re-create the virtual environment.
+    context.venv().assert().success();
+
+    // Re-install. The built wheel should be installed from the cache.
+    uv_snapshot!(
+        context.pip_install().arg("idna").arg("--no-binary").arg(":all:"),
+        @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + idna==3.6
+    "###
+    );
+
+    // Re-create the virtual environment.
+    context.venv().assert().success();
+
+    // Re-install with `--rebuild-package`. The distribution should be built again.
+    uv_snapshot!(
+        context
+            .pip_install()
+            .arg("idna")
+            .arg("--no-binary")
+            .arg(":all:")
+            .arg("--rebuild-package")
+            .arg("idna"),
+        @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + idna==3.6
+    "###
+    );
+}
+
 /// Respect `--only-binary` flags in `requirements.txt`
 #[test]
 fn only_binary_requirements_txt() {
@@ -3661,31 +3989,177 @@ fn config_settings_path() {
     assert!(!finder.exists());
 }
 
-/// Reinstall a duplicate package in a virtual environment.
 #[test]
-fn reinstall_duplicate() -> Result<()> {
-    use uv_fs::copy_dir_all;
+fn config_settings_package() {
+    let context = TestContext::new("3.12");
 
-    // Sync a version of `pip` into a virtual environment.
-    let context1 = TestContext::new("3.12");
-    let requirements_txt = context1.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("pip==21.3.1")?;
+    // Install the editable package with `--editable_mode=compat`, but scoped to another package.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-e")
+        .arg(context.workspace_root.join("scripts/packages/setuptools_editable"))
+        .arg("--config-settings-package")
+        .arg("iniconfig:editable_mode=compat")
+        , @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
 
-    // Run `pip sync`.
-    context1
-        .pip_install()
-        .arg("-r")
-        .arg(requirements_txt.path())
-        .assert()
-        .success();
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + setuptools-editable==0.1.0 (from file://[WORKSPACE]/scripts/packages/setuptools_editable)
+    "###
+    );
 
-    // Sync a different version of `pip` into a virtual environment.
-    let context2 = TestContext::new("3.12");
-    let requirements_txt = context2.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("pip==22.1.1")?;
+    // The setting should be ignored, so the `finder.py` file should be present.
+    let finder = context
+        .site_packages()
+        .join("__editable___setuptools_editable_0_1_0_finder.py");
+    assert!(finder.exists());
 
-    // Run `pip sync`.
-    context2
+    // Uninstall the package.
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("setuptools-editable"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - setuptools-editable==0.1.0 (from file://[WORKSPACE]/scripts/packages/setuptools_editable)
+    "###);
+
+    // Install the editable package with `--editable_mode=compat`, scoped to the package itself.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-e")
+        .arg(context.workspace_root.join("scripts/packages/setuptools_editable"))
+        .arg("--config-settings-package")
+        .arg("setuptools-editable:editable_mode=compat")
+        , @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + setuptools-editable==0.1.0 (from file://[WORKSPACE]/scripts/packages/setuptools_editable)
+    "###
+    );
+
+    // The setting should be respected, so the `finder.py` file should _not_ be present.
+    let finder = context
+        .site_packages()
+        .join("__editable___setuptools_editable_0_1_0_finder.py");
+    assert!(!finder.exists());
+
+    // Providing an invalid setting should fail.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-e")
+        .arg(context.workspace_root.join("scripts/packages/setuptools_editable"))
+        .arg("--config-settings-package")
+        .arg("editable_mode=compat")
+        , @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'editable_mode=compat' for '--config-setting-package <CONFIG_SETTING_PACKAGE>': Invalid config setting: editable_mode=compat (expected `PACKAGE:KEY=VALUE`)
+
+    For more information, try '--help'.
+    "###
+    );
+}
+
+/// Pass per-package `build-env` variables through to the build backend of a source distribution.
+#[test]
+fn build_env_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // The backend records the value of `PROBE_VALUE` in the built module.
+    let setup_py = indoc! {r#"
+        import os
+        from setuptools import setup
+
+        with open("probe.py", "w") as f:
+            f.write("VALUE = {!r}\n".format(os.environ.get("PROBE_VALUE")))
+
+        setup(name="foo", version="1.0.0", py_modules=["probe"])
+    "#};
+
+    let source_dist = context.temp_dir.child("foo-1.0.0.tar.gz");
+    // Flush the file after we're done.
+    {
+        let file = File::create(source_dist.path())?;
+        let enc = GzEncoder::new(file, flate2::Compression::default());
+        let mut tar = tar::Builder::new(enc);
+
+        let mut header = tar::Header::new_gnu();
+        header.set_size(setup_py.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        tar.append_data(&mut header, "foo-1.0.0/setup.py", Cursor::new(setup_py))?;
+        tar.finish()?;
+    }
+
+    // Set the variable for `foo`, and a different value for an unrelated package.
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! {r#"
+        [pip]
+        build-env = { foo = { PROBE_VALUE = "foo" }, bar = { PROBE_VALUE = "bar" } }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context
+        .pip_install()
+        .arg(source_dist.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo==1.0.0 (from file://[TEMP_DIR]/foo-1.0.0.tar.gz)
+    "###
+    );
+
+    // The variable should have reached the PEP 517 build.
+    let probe = fs::read_to_string(context.site_packages().join("probe.py"))?;
+    assert_eq!(probe, "VALUE = 'foo'\n");
+
+    Ok(())
+}
+
+/// Reinstall a duplicate package in a virtual environment.
+#[test]
+fn reinstall_duplicate() -> Result<()> {
+    use uv_fs::copy_dir_all;
+
+    // Sync a version of `pip` into a virtual environment.
+    let context1 = TestContext::new("3.12");
+    let requirements_txt = context1.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("pip==21.3.1")?;
+
+    // Run `pip sync`.
+    context1
+        .pip_install()
+        .arg("-r")
+        .arg(requirements_txt.path())
+        .assert()
+        .success();
+
+    // Sync a different version of `pip` into a virtual environment.
+    let context2 = TestContext::new("3.12");
+    let requirements_txt = context2.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("pip==22.1.1")?;
+
+    // Run `pip sync`.
+    context2
         .pip_install()
         .arg("-r")
         .arg(requirements_txt.path())
@@ -4343,6 +4817,76 @@ fn invalidate_path_on_env_var() -> Result<()> {
     Ok(())
 }
 
+/// Install a wheel containing files that differ only by case, which must be restored to their
+/// original names in the environment.
+#[test]
+#[cfg(target_os = "linux")]
+fn install_case_colliding_wheel() -> Result<()> {
+    use std::io::Write;
+
+    let context = TestContext::new("3.12");
+
+    let wheel = context
+        .temp_dir
+        .child("case_collision-1.0.0-py3-none-any.whl");
+    let mut writer = zip::ZipWriter::new(File::create(wheel.path())?);
+    let options = zip::write::FileOptions::default();
+    for (name, contents) in [
+        ("case_collision/__init__.py", ""),
+        ("case_collision/README.txt", "upper"),
+        ("case_collision/readme.txt", "lower"),
+        (
+            "case_collision-1.0.0.dist-info/METADATA",
+            "Metadata-Version: 2.1\nName: case-collision\nVersion: 1.0.0\n",
+        ),
+        (
+            "case_collision-1.0.0.dist-info/WHEEL",
+            "Wheel-Version: 1.0\nRoot-Is-Purelib: true\nTag: py3-none-any\n",
+        ),
+        (
+            "case_collision-1.0.0.dist-info/RECORD",
+            indoc! {"
+                case_collision/__init__.py,,
+                case_collision/README.txt,,
+                case_collision/readme.txt,,
+                case_collision-1.0.0.dist-info/METADATA,,
+                case_collision-1.0.0.dist-info/WHEEL,,
+                case_collision-1.0.0.dist-info/RECORD,,
+            "},
+        ),
+    ] {
+        writer.start_file(name, options)?;
+        writer.write_all(contents.as_bytes())?;
+    }
+    writer.finish()?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(wheel.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + case-collision==1.0.0 (from file://[TEMP_DIR]/case_collision-1.0.0-py3-none-any.whl)
+    "###
+    );
+
+    // Both files should be installed under their original names, with no case-mangled leftovers.
+    let package = context.site_packages().join("case_collision");
+    assert_eq!(fs::read_to_string(package.join("README.txt"))?, "upper");
+    assert_eq!(fs::read_to_string(package.join("readme.txt"))?, "lower");
+    let mut names = fs::read_dir(&package)?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().to_string()))
+        .collect::<Result<Vec<_>>>()?;
+    names.sort();
+    assert_eq!(names, ["README.txt", "__init__.py", "readme.txt"]);
+
+    Ok(())
+}
+
 /// Install from a direct path (wheel) with changed versions in the file name.
 #[test]
 fn path_name_version_change() {
@@ -6976,98 +7520,75 @@ fn verify_hashes_match() -> Result<()> {
     Ok(())
 }
 
-/// Omit a transitive dependency in `--verify-hashes`. This is allowed.
+/// Enforce the hashes in a `uv.hashes.toml` file, even if the requirements file omits them.
 #[test]
-fn verify_hashes_omit_dependency() -> Result<()> {
+fn verify_known_hashes_mismatch() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    // Write to a requirements file.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(
-        "anyio==4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
-    )?;
+    requirements_txt.write_str("idna==3.6")?;
 
-    // Install without error when `--require-hashes` is omitted.
+    let hashes_toml = context.temp_dir.child("uv.hashes.toml");
+    hashes_toml.write_str(indoc::indoc! {r#"
+        [[package]]
+        name = "idna"
+        version = "3.6"
+        hashes = ["sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2"]
+    "#})?;
+
+    // Raise an error.
     uv_snapshot!(context.pip_install()
+        .arg("--no-deps")
         .arg("-r")
-        .arg("requirements.txt")
-        .arg("--verify-hashes"), @r###"
-    success: true
-    exit_code: 0
+        .arg("requirements.txt"), @r###"
+    success: false
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 3 packages in [TIME]
-    Prepared 3 packages in [TIME]
-    Installed 3 packages in [TIME]
-     + anyio==4.0.0
-     + idna==3.6
-     + sniffio==1.3.1
-    "###
-    );
+    Resolved 1 package in [TIME]
+      × Failed to download `idna==3.6`
+      ╰─▶ Hash mismatch for `idna==3.6`
 
-    Ok(())
-}
+          Expected:
+            sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2
 
-/// We allow `--verify-hashes` for editable dependencies.
-#[test]
-fn verify_hashes_editable() -> Result<()> {
-    let context = TestContext::new("3.12");
+          Computed:
+            sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
+    "###
+    );
 
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(&indoc::formatdoc! {r"
-        -e file://{workspace_root}/scripts/packages/black_editable[d]
-        ",
-        workspace_root = context.workspace_root.simplified_display(),
-    })?;
+    // If the requirements file provides hashes too, they must overlap with the known hashes.
+    requirements_txt.write_str(indoc::indoc! {r"
+        idna==3.6 \
+            --hash=sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
+    "})?;
 
-    // Install the editable packages.
-    uv_snapshot!(context.filters(), context.pip_install()
+    uv_snapshot!(context.pip_install()
+        .arg("--no-deps")
         .arg("-r")
-        .arg(requirements_txt.path())
-        .arg("--verify-hashes"), @r###"
-    success: true
-    exit_code: 0
+        .arg("requirements.txt"), @r###"
+    success: false
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 8 packages in [TIME]
-    Prepared 8 packages in [TIME]
-    Installed 8 packages in [TIME]
-     + aiohttp==3.9.3
-     + aiosignal==1.3.1
-     + attrs==23.2.0
-     + black==0.1.0 (from file://[WORKSPACE]/scripts/packages/black_editable)
-     + frozenlist==1.4.1
-     + idna==3.6
-     + multidict==6.0.5
-     + yarl==1.9.4
+    error: None of the provided hashes for `idna-3.6` match the known hashes
     "###
     );
 
-    Ok(())
-}
-
-/// Allow arguments within a `requirements.txt` file to be quoted or unquoted, as in the CLI.
-#[test]
-fn double_quoted_arguments() -> Result<()> {
-    let context = TestContext::new("3.12");
-
-    let constraints_in = context.temp_dir.child("constraints.in");
-    constraints_in.write_str(indoc::indoc! {r"
-        iniconfig==1.0.0
-    "})?;
-
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r#"
-       --constraint "./constraints.in"
-
-        iniconfig
+    // Update the known hashes.
+    hashes_toml.write_str(indoc::indoc! {r#"
+        [[package]]
+        name = "idna"
+        version = "3.6"
+        hashes = ["sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f"]
     "#})?;
 
     uv_snapshot!(context.pip_install()
+        .arg("--no-deps")
         .arg("-r")
-        .arg("requirements.in"), @r###"
+        .arg("requirements.txt"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -7076,33 +7597,235 @@ fn double_quoted_arguments() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + iniconfig==1.0.0
+     + idna==3.6
     "###
     );
 
     Ok(())
 }
 
-/// Allow arguments within a `requirements.txt` file to be quoted or unquoted, as in the CLI.
+/// Discover a `uv.hashes.toml` file in an ancestor of the working directory.
 #[test]
-fn single_quoted_arguments() -> Result<()> {
+fn verify_known_hashes_ancestor() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    let constraints_in = context.temp_dir.child("constraints.in");
-    constraints_in.write_str(indoc::indoc! {r"
-        iniconfig==1.0.0
-    "})?;
-
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
-       --constraint './constraints.in'
+    let hashes_toml = context.temp_dir.child("uv.hashes.toml");
+    hashes_toml.write_str(indoc::indoc! {r#"
+        [[package]]
+        name = "idna"
+        version = "3.6"
+        hashes = ["sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2"]
+    "#})?;
 
-        iniconfig
-    "})?;
+    let child = context.temp_dir.child("child");
+    child.create_dir_all()?;
 
     uv_snapshot!(context.pip_install()
-        .arg("-r")
-        .arg("requirements.in"), @r###"
+        .arg("--no-deps")
+        .arg("idna==3.6")
+        .current_dir(&child), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `idna==3.6`
+      ╰─▶ Hash mismatch for `idna==3.6`
+
+          Expected:
+            sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2
+
+          Computed:
+            sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
+    "###
+    );
+
+    Ok(())
+}
+
+/// Enforce the hashes in a `uv.hashes.toml` file for a direct URL distribution.
+#[test]
+fn verify_known_hashes_url() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let hashes_toml = context.temp_dir.child("uv.hashes.toml");
+    hashes_toml.write_str(indoc::indoc! {r#"
+        [[package]]
+        name = "anyio"
+        url = "https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl"
+        hashes = ["sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f"]
+    "#})?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("--no-deps")
+        .arg("anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl`
+      ╰─▶ Hash mismatch for `anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl`
+
+          Expected:
+            sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+
+          Computed:
+            sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    "###
+    );
+
+    // An entry must be identified by either a version or a URL.
+    hashes_toml.write_str(indoc::indoc! {r#"
+        [[package]]
+        name = "anyio"
+        hashes = ["sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--no-deps")
+        .arg("anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Expected exactly one of `version` or `url` for `anyio` in: `uv.hashes.toml`
+    "###
+    );
+
+    Ok(())
+}
+
+/// Omit a transitive dependency in `--verify-hashes`. This is allowed.
+#[test]
+fn verify_hashes_omit_dependency() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Write to a requirements file.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "anyio==4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+    )?;
+
+    // Install without error when `--require-hashes` is omitted.
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--verify-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.0.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    Ok(())
+}
+
+/// We allow `--verify-hashes` for editable dependencies.
+#[test]
+fn verify_hashes_editable() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&indoc::formatdoc! {r"
+        -e file://{workspace_root}/scripts/packages/black_editable[d]
+        ",
+        workspace_root = context.workspace_root.simplified_display(),
+    })?;
+
+    // Install the editable packages.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg(requirements_txt.path())
+        .arg("--verify-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 8 packages in [TIME]
+    Prepared 8 packages in [TIME]
+    Installed 8 packages in [TIME]
+     + aiohttp==3.9.3
+     + aiosignal==1.3.1
+     + attrs==23.2.0
+     + black==0.1.0 (from file://[WORKSPACE]/scripts/packages/black_editable)
+     + frozenlist==1.4.1
+     + idna==3.6
+     + multidict==6.0.5
+     + yarl==1.9.4
+    "###
+    );
+
+    Ok(())
+}
+
+/// Allow arguments within a `requirements.txt` file to be quoted or unquoted, as in the CLI.
+#[test]
+fn double_quoted_arguments() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let constraints_in = context.temp_dir.child("constraints.in");
+    constraints_in.write_str(indoc::indoc! {r"
+        iniconfig==1.0.0
+    "})?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc::indoc! {r#"
+       --constraint "./constraints.in"
+
+        iniconfig
+    "#})?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==1.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Allow arguments within a `requirements.txt` file to be quoted or unquoted, as in the CLI.
+#[test]
+fn single_quoted_arguments() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let constraints_in = context.temp_dir.child("constraints.in");
+    constraints_in.write_str(indoc::indoc! {r"
+        iniconfig==1.0.0
+    "})?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc::indoc! {r"
+       --constraint './constraints.in'
+
+        iniconfig
+    "})?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.in"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -7459,10 +8182,10 @@ fn local_index_absolute() -> Result<()> {
     Ok(())
 }
 
-/// Resolve against a local directory laid out as a PEP 503-compatible index, provided via a
-/// relative path on the CLI.
+/// Apply the `--invalid-metadata` policy to a file with an unparsable `requires-python` specifier
+/// on a local index.
 #[test]
-fn local_index_relative() -> Result<()> {
+fn local_index_invalid_metadata() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let root = context.temp_dir.child("simple-html");
@@ -7481,39 +8204,89 @@ fn local_index_relative() -> Result<()> {
           <body>
             <h1>Links for tqdm</h1>
             <a
-              href="{}/tqdm-1000.0.0-py3-none-any.whl"
+              href="{0}/tqdm-1000.0.0-py3-none-any.whl"
               data-requires-python=">=3.8"
             >
               tqdm-1000.0.0-py3-none-any.whl
             </a>
+            <a
+              href="{0}/tqdm-999.0.0-py3-none-any.whl"
+              data-requires-python="banana"
+            >
+              tqdm-999.0.0-py3-none-any.whl
+            </a>
           </body>
         </html>
     "#, Url::from_directory_path(context.workspace_root.join("scripts/links/")).unwrap().as_str()})?;
 
+    let index_url = Url::from_directory_path(root).unwrap();
+
+    // By default, the invalid file is skipped silently.
     uv_snapshot!(context.filters(), context.pip_install()
         .env_remove(EnvVars::UV_EXCLUDE_NEWER)
         .arg("tqdm")
+        .arg("--dry-run")
         .arg("--index-url")
-        .arg("./simple-html"), @r###"
+        .arg(index_url.as_str()), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
+    Would download 1 package
+    Would install 1 package
+     + tqdm==1000.0.0
+    "###
+    );
+
+    // With `warn`, the invalid file is skipped with a warning.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("tqdm")
+        .arg("--dry-run")
+        .arg("--invalid-metadata")
+        .arg("warn")
+        .arg("--index-url")
+        .arg(index_url.as_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Skipping invalid file `tqdm-999.0.0-py3-none-any.whl` for `tqdm` on file://[TEMP_DIR]/simple-html/: Failed to parse `requires-python`: `banana`
+    Resolved 1 package in [TIME]
+    Would download 1 package
+    Would install 1 package
      + tqdm==1000.0.0
     "###
     );
 
+    // With `error`, the invalid file fails the resolution.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("tqdm")
+        .arg("--dry-run")
+        .arg("--invalid-metadata")
+        .arg("error")
+        .arg("--index-url")
+        .arg(index_url.as_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Invalid file `tqdm-999.0.0-py3-none-any.whl` for package `tqdm` on file://[TEMP_DIR]/simple-html/: Failed to parse `requires-python`: `banana`
+    "###
+    );
+
     Ok(())
 }
 
 /// Resolve against a local directory laid out as a PEP 503-compatible index, provided via a
-/// `requirements.txt` file.
+/// relative path on the CLI.
 #[test]
-fn local_index_requirements_txt_absolute() -> Result<()> {
+fn local_index_relative() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let root = context.temp_dir.child("simple-html");
@@ -7541,16 +8314,11 @@ fn local_index_requirements_txt_absolute() -> Result<()> {
         </html>
     "#, Url::from_directory_path(context.workspace_root.join("scripts/links/")).unwrap().as_str()})?;
 
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(&indoc::formatdoc! {r"
-        --index-url {}
-        tqdm
-    ", Url::from_directory_path(root).unwrap().as_str()})?;
-
     uv_snapshot!(context.filters(), context.pip_install()
         .env_remove(EnvVars::UV_EXCLUDE_NEWER)
-        .arg("-r")
-        .arg("requirements.txt"), @r###"
+        .arg("tqdm")
+        .arg("--index-url")
+        .arg("./simple-html"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -7567,9 +8335,9 @@ fn local_index_requirements_txt_absolute() -> Result<()> {
 }
 
 /// Resolve against a local directory laid out as a PEP 503-compatible index, provided via a
-/// relative path in a `requirements.txt` file.
+/// `requirements.txt` file.
 #[test]
-fn local_index_requirements_txt_relative() -> Result<()> {
+fn local_index_requirements_txt_absolute() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let root = context.temp_dir.child("simple-html");
@@ -7598,11 +8366,67 @@ fn local_index_requirements_txt_relative() -> Result<()> {
     "#, Url::from_directory_path(context.workspace_root.join("scripts/links/")).unwrap().as_str()})?;
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(
-        r"
-        --index-url ./simple-html
+    requirements_txt.write_str(&indoc::formatdoc! {r"
+        --index-url {}
         tqdm
-    ",
+    ", Url::from_directory_path(root).unwrap().as_str()})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("-r")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve against a local directory laid out as a PEP 503-compatible index, provided via a
+/// relative path in a `requirements.txt` file.
+#[test]
+fn local_index_requirements_txt_relative() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let root = context.temp_dir.child("simple-html");
+    fs_err::create_dir_all(&root)?;
+
+    let tqdm = root.child("tqdm");
+    fs_err::create_dir_all(&tqdm)?;
+
+    let index = tqdm.child("index.html");
+    index.write_str(&indoc::formatdoc! {r#"
+        <!DOCTYPE html>
+        <html>
+          <head>
+            <meta name="pypi:repository-version" content="1.1" />
+          </head>
+          <body>
+            <h1>Links for tqdm</h1>
+            <a
+              href="{}/tqdm-1000.0.0-py3-none-any.whl"
+              data-requires-python=">=3.8"
+            >
+              tqdm-1000.0.0-py3-none-any.whl
+            </a>
+          </body>
+        </html>
+    "#, Url::from_directory_path(context.workspace_root.join("scripts/links/")).unwrap().as_str()})?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        r"
+        --index-url ./simple-html
+        tqdm
+    ",
     )?;
 
     uv_snapshot!(context.filters(), context.pip_install()
@@ -7816,6 +8640,97 @@ fn install_relocatable() -> Result<()> {
     Ok(())
 }
 
+/// Install into a non-activated virtual environment with `--environment`.
+#[test]
+fn install_environment() {
+    let context = TestContext::new("3.12");
+
+    // Create a second virtual environment, distinct from the default one.
+    let other = context.temp_dir.child("other");
+    context
+        .venv()
+        .arg(other.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--environment")
+        .arg(other.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using Python 3.12.[X] environment at: other
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // The package should be installed into the named environment...
+    uv_snapshot!(context.filters(), context.pip_freeze()
+        .arg("--environment")
+        .arg(other.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig==2.0.0
+
+    ----- stderr -----
+    Using Python 3.12.[X] environment at: other
+    "###
+    );
+
+    // ...but not the default environment.
+    uv_snapshot!(context.filters(), context.pip_freeze(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###
+    );
+
+    // The environment must exist.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--environment")
+        .arg(context.temp_dir.child("missing").as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No virtual environment or system Python installation found for directory `missing`; run `uv venv` to create an environment
+    "###
+    );
+
+    // The environment can't be combined with a Python request.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--environment")
+        .arg(other.as_os_str())
+        .arg("--python")
+        .arg("3.12"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--environment <ENVIRONMENT>' cannot be used with '--python <PYTHON>'
+
+    Usage: uv pip install --cache-dir [CACHE_DIR] --environment <ENVIRONMENT> --exclude-newer <EXCLUDE_NEWER> <PACKAGE|--requirements <REQUIREMENTS>|--editable <EDITABLE>>
+
+    For more information, try '--help'.
+    "###
+    );
+}
+
 /// Install requesting Python 3.12 when the virtual environment uses 3.11
 #[test]
 fn install_incompatible_python_version() {
@@ -8610,6 +9525,74 @@ fn test_dynamic_version_sdist_wrong_version() -> Result<()> {
     Ok(())
 }
 
+/// Install a source archive in which the project is nested within a subdirectory, as in the
+/// auto-generated tarballs for some GitHub repositories.
+#[test]
+fn install_nested_source_root() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = indoc! {r#"
+    [project]
+    name = "foo"
+    version = "1.0.0"
+    requires-python = ">=3.9"
+    dependencies = []
+
+    [build-system]
+    requires = ["setuptools>=42"]
+    build-backend = "setuptools.build_meta"
+    "#};
+
+    let source_dist = context.temp_dir.child("foo-1.0.0.tar.gz");
+    // Flush the file after we're done.
+    {
+        let file = File::create(source_dist.path())?;
+        let enc = GzEncoder::new(file, flate2::Compression::default());
+        let mut tar = tar::Builder::new(enc);
+
+        for (path, contents) in [
+            ("foo-1.0.0/README.md", "# foo\n"),
+            ("foo-1.0.0/bindings/python/pyproject.toml", pyproject_toml),
+            ("foo-1.0.0/bindings/python/foo.py", ""),
+        ] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            tar.append_data(&mut header, path, Cursor::new(contents))?;
+        }
+        tar.finish()?;
+    }
+
+    // With detection disabled, the archive root isn't a project.
+    context
+        .pip_install()
+        .arg(source_dist.path())
+        .env(EnvVars::UV_NO_DETECT_SOURCE_ROOT, "1")
+        .assert()
+        .failure();
+
+    // Otherwise, the nested project root should be used.
+    uv_snapshot!(context.filters(), context
+        .pip_install()
+        .arg(source_dist.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo==1.0.0 (from file://[TEMP_DIR]/foo-1.0.0.tar.gz)
+    "###
+    );
+
+    context.assert_command("import foo").success();
+
+    Ok(())
+}
+
 /// Install a package with multiple wheels at the same version, differing only in the build tag. We
 /// should choose the wheel with the highest build tag.
 #[test]
@@ -8871,7 +9854,9 @@ fn cyclic_build_dependency() {
     Resolved 1 package in [TIME]
       × Failed to download and build `circular-one==0.2.0`
       ├─▶ Failed to install requirements from `build-system.requires`
-      ╰─▶ Cyclic build dependency detected for `circular-one`
+      ╰─▶ Cyclic build dependency detected: `circular-one` -> `circular-one`
+
+          hint: Building `circular-one` from source requires a pre-built wheel for at least one of the packages in the cycle. Consider allowing pre-built wheels for these packages (e.g., by removing them from `--no-binary`), or installing the build requirements of `circular-one` into the environment and re-running with `--no-build-isolation`.
     "###
     );
 
@@ -9131,3 +10116,675 @@ fn unsupported_git_scheme() {
     "###
     );
 }
+
+/// Install a package from a directory that contains multiple packages, but is not itself a
+/// package.
+#[test]
+fn install_directory_multiple_packages() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let workspace = context.temp_dir.child("workspace");
+    workspace.child("pyproject.toml").write_str(indoc! {
+        r#"
+        [tool.uv.workspace]
+        members = ["packages/*"]
+        "#
+    })?;
+    for name in ["foo", "bar"] {
+        let package = workspace.child("packages").child(name);
+        package
+            .child("pyproject.toml")
+            .write_str(&indoc::formatdoc! {
+                r#"
+            [project]
+            name = "{name}"
+            version = "1.0.0"
+
+            [build-system]
+            requires = ["hatchling"]
+            build-backend = "hatchling.build"
+            "#
+            })?;
+        package
+            .child("src")
+            .child(name)
+            .child("__init__.py")
+            .touch()?;
+    }
+
+    // Without selecting a package, we should error with the list of packages.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./workspace"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The directory `workspace` is not a package, but contains multiple packages (`bar`, `foo`); select one with `#package=<name>`
+    "###
+    );
+
+    // Selecting a package that doesn't exist should also error.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./workspace#package=baz"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The directory `workspace` does not contain a package named `baz` (found: `bar`, `foo`)
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./workspace#package=bar"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + bar==1.0.0 (from file://[TEMP_DIR]/workspace/packages/bar)
+    "###
+    );
+
+    Ok(())
+}
+
+/// Install from a directory that contains a single package, or several packages with the same
+/// name, but is not itself a package.
+#[test]
+fn install_directory_member_packages() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = indoc! {r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+    "#};
+
+    let single = context.temp_dir.child("single");
+    single
+        .child("packages")
+        .child("foo")
+        .child("pyproject.toml")
+        .write_str(pyproject_toml)?;
+
+    // A single package should be described as such.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./single"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The directory `single` is not a package, but contains a package (`foo`); select it with `#package=<name>`
+    "###
+    );
+
+    let repo = context.temp_dir.child("repo");
+    for parent in ["src", "packages"] {
+        repo.child(parent)
+            .child("foo")
+            .child("pyproject.toml")
+            .write_str(pyproject_toml)?;
+    }
+
+    // Selecting a name that's shared by several packages should list each of them.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./repo#package=foo"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The directory `repo` contains multiple packages named `foo` (at: `repo/packages/foo`, `repo/src/foo`); refer to one by its path instead
+    "###
+    );
+
+    Ok(())
+}
+
+/// Layer additional requirements on top of a requirements file with `--with`, resolving them
+/// jointly.
+#[test]
+fn install_with() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let registry = TestRegistry::new()
+        .release(Release::new("foo", "1.0.0")?.requires("bar<2"))
+        .release(Release::new("bar", "1.0.0")?)
+        .release(Release::new("bar", "2.0.0")?)
+        .release(Release::new("baz", "1.0.0")?.requires("bar"))
+        .release(Release::new("qux", "1.0.0")?)
+        .start()?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("foo")?;
+
+    // `baz` would otherwise select the latest `bar`, but is resolved jointly with `foo`.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--with")
+        .arg("baz,qux")
+        .arg("--index-url")
+        .arg(registry.index_url().as_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + bar==1.0.0
+     + baz==1.0.0
+     + foo==1.0.0
+     + qux==1.0.0
+    "###
+    );
+
+    // The requirements file is left untouched.
+    requirements_txt.assert("foo");
+
+    Ok(())
+}
+
+/// Reinstall packages based on where they were installed from, with `--reinstall-local` and
+/// `--reinstall-vcs`.
+#[test]
+fn reinstall_by_provenance() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a local package.
+    let example = context.temp_dir.child("example");
+    example.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "example"
+        version = "0.0.0"
+        dependencies = []
+        requires-python = ">=3.8"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("example @ ./example")
+        .arg("iniconfig==2.0.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + example==0.0.0 (from file://[TEMP_DIR]/example)
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // Only the local package is reinstalled.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("example @ ./example")
+        .arg("iniconfig==2.0.0")
+        .arg("--reinstall-local"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ example==0.0.0 (from file://[TEMP_DIR]/example)
+    "###
+    );
+
+    // Nothing was installed from a VCS, so nothing is reinstalled.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("example @ ./example")
+        .arg("iniconfig==2.0.0")
+        .arg("--reinstall-vcs"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 2 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Enforce `--max-package-size` and `--max-total-download-size` before downloading anything.
+#[test]
+fn install_size_budget() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let registry = TestRegistry::new()
+        .release(Release::new("foo", "1.0.0")?.requires("bar"))
+        .release(Release::new("bar", "1.0.0")?)
+        .start()?;
+
+    let mut filters = context.filters();
+    filters.push((r"\(\d+\.\d(B|KiB)\)", "([SIZE])"));
+
+    // The distribution exceeds a one-byte budget.
+    uv_snapshot!(filters, context.pip_install()
+        .arg("bar")
+        .arg("--max-package-size")
+        .arg("1B")
+        .arg("--index-url")
+        .arg(registry.index_url().as_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: 1 distribution exceeds the maximum package size (1B):
+      bar==1.0.0 ([SIZE])
+    "###
+    );
+
+    uv_snapshot!(filters, context.pip_install()
+        .arg("bar")
+        .arg("--max-total-download-size")
+        .arg("1B")
+        .arg("--index-url")
+        .arg(registry.index_url().as_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: The total download size ([SIZE]) exceeds the maximum total download size (1B). The largest distributions are:
+      bar==1.0.0 ([SIZE])
+    "###
+    );
+
+    // Nothing was installed.
+    context.assert_command("import bar").failure();
+
+    // A generous budget is satisfied.
+    uv_snapshot!(filters, context.pip_install()
+        .arg("foo")
+        .arg("--max-package-size")
+        .arg("1MB")
+        .arg("--max-total-download-size")
+        .arg("1MB")
+        .arg("--index-url")
+        .arg(registry.index_url().as_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + bar==1.0.0
+     + foo==1.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Create a virtual environment for the project with `--create-venv` if none is found.
+#[test]
+fn install_create_venv() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.11", "3.12"]);
+    let registry = TestRegistry::new()
+        .release(Release::new("foo", "1.0.0")?)
+        .start()?;
+
+    // Remove the virtual environment.
+    fs_err::remove_dir_all(&context.venv)?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    // Without `--create-venv`, the installation fails.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("foo")
+        .arg("--index-url")
+        .arg(registry.index_url().as_str())
+        .env_remove(EnvVars::VIRTUAL_ENV), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No virtual environment found; run `uv venv` to create an environment, or pass `--system` to install into a non-virtual environment
+    "###
+    );
+
+    // With `--create-venv`, a virtual environment is created, respecting `requires-python`.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("foo")
+        .arg("--index-url")
+        .arg(registry.index_url().as_str())
+        .arg("--create-venv")
+        .env_remove(EnvVars::VIRTUAL_ENV), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo==1.0.0
+    "###
+    );
+
+    // Subsequent installations use the existing environment.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("foo")
+        .arg("--index-url")
+        .arg(registry.index_url().as_str())
+        .arg("--create-venv")
+        .env_remove(EnvVars::VIRTUAL_ENV), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Install into a PEP 582-style `__pypackages__` directory, and run with it on `sys.path`.
+#[test]
+fn install_pypackages() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("tqdm==4.66.2")
+        .arg("--no-deps")
+        .arg("--pypackages"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/python
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + tqdm==4.66.2
+    "###
+    );
+
+    // Ensure that the packages are present in the `__pypackages__` directory.
+    let pypackages = context.temp_dir.child("__pypackages__").child("3.12");
+    assert!(pypackages.child("lib").child("iniconfig").is_dir());
+    assert!(pypackages.child("lib").child("tqdm").is_dir());
+
+    // Ensure that scripts are installed alongside the `lib` directory, rather than within it.
+    assert!(pypackages
+        .child("bin")
+        .child(format!("tqdm{}", std::env::consts::EXE_SUFFIX))
+        .is_file());
+    assert!(!pypackages.child("lib").child("bin").exists());
+
+    // Ensure that we can't import the package from the environment.
+    context.assert_command("import iniconfig").failure();
+
+    // Ensure that we can import the package with `uv run --pypackages`.
+    context
+        .run()
+        .arg("--no-project")
+        .arg("--pypackages")
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig")
+        .assert()
+        .success();
+
+    // With `--project`, the `__pypackages__` directory should be resolved relative to the
+    // project directory, by both `uv pip install` and `uv run`.
+    context.temp_dir.child("project").create_dir_all()?;
+
+    context
+        .pip_install()
+        .arg("anyio==4.3.0")
+        .arg("--no-deps")
+        .arg("--pypackages")
+        .arg("--project")
+        .arg("project")
+        .assert()
+        .success();
+
+    assert!(context
+        .temp_dir
+        .child("project")
+        .child("__pypackages__")
+        .child("3.12")
+        .child("lib")
+        .child("anyio")
+        .is_dir());
+
+    context
+        .run()
+        .arg("--no-project")
+        .arg("--project")
+        .arg("project")
+        .arg("--pypackages")
+        .arg("python")
+        .arg("-c")
+        .arg("import anyio")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+/// Requesting an extra that doesn't exist should warn by default, and fail with `--strict`.
+#[test]
+fn install_missing_extra_strict() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig[typo]==2.0.0")
+        .arg("--strict"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Requested extras are not provided by the resolved distributions:
+      - The package `iniconfig==2.0.0` does not have an extra named `typo`
+
+    hint: Extras are validated because `--strict` was provided
+    "###
+    );
+
+    context.assert_command("import iniconfig").failure();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig[typo]==2.0.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    warning: The package `iniconfig==2.0.0` does not have an extra named `typo`
+    "###
+    );
+
+    // Extras should be validated even if the environment already satisfies the requirements.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig[typo]==2.0.0")
+        .arg("--strict"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Requested extras are not provided by the resolved distributions:
+      - The package `iniconfig==2.0.0` does not have an extra named `typo`
+
+    hint: Extras are validated because `--strict` was provided
+    "###
+    );
+}
+
+/// With `--check-abi`, reject wheels whose `WHEEL` file declares tags that aren't supported by the
+/// interpreter, even if their filename is compatible.
+#[test]
+fn install_check_abi() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let registry = TestRegistry::new()
+        .release(Release::new("foo", "1.0.0")?.wheel_tag("cp312-cp312t-any"))
+        .release(Release::new("bar", "1.0.0")?)
+        .start()?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("foo")
+        .arg("--check-abi")
+        .arg("--index-url")
+        .arg(registry.index_url().as_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    error: Some wheels are incompatible with the target interpreter:
+      - `foo-1.0.0-py3-none-any.whl` is built for `cp312-cp312t-any`, which is not supported by the interpreter
+
+    hint: Wheels are inspected because `--check-abi` was provided
+    "###
+    );
+
+    context.assert_command("import foo").failure();
+
+    // Compatible wheels are installed as usual.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("bar")
+        .arg("--check-abi")
+        .arg("--index-url")
+        .arg(registry.index_url().as_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + bar==1.0.0
+    "###
+    );
+
+    // Without `--check-abi`, only the filename is considered.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("foo")
+        .arg("--index-url")
+        .arg(registry.index_url().as_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo==1.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Emit warnings for pip options that match uv's behavior, and errors (with a suggested
+/// alternative) for those that don't.
+#[test]
+fn install_pip_compat_args() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--disable-pip-version-check")
+        .arg("--use-feature=2020-resolver")
+        .arg("--no-warn-script-location"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: pip's `--disable-pip-version-check` has no effect
+    warning: pip's `--use-feature=2020-resolver` has no effect (uv always backtracks)
+    warning: pip's `--no-warn-script-location` has no effect (uv doesn't warn about script locations)
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--use-deprecated=legacy-resolver"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: pip's `--use-deprecated=legacy-resolver` is unsupported (uv always backtracks)
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--timeout")
+        .arg("60"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: pip's `--timeout` is unsupported (set `UV_HTTP_TIMEOUT` instead)
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--progress-bar")
+        .arg("off"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: pip's `--progress-bar` is unsupported (use `--no-progress` to hide progress bars)
+    "###
+    );
+}
@@ -0,0 +1,155 @@
+use anyhow::Result;
+use assert_fs::fixture::FileWriteStr;
+use assert_fs::fixture::PathChild;
+use indoc::indoc;
+
+use crate::common::uv_snapshot;
+use crate::common::TestContext;
+
+#[test]
+fn lint_clean() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio>=4.0.0
+        idna>=3.6,<4
+    "})?;
+
+    uv_snapshot!(context.pip_lint().arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Linted 2 requirements in [TIME]
+    No issues found
+    "###
+    );
+
+    Ok(())
+}
+
+#[test]
+fn lint_issues() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio>=4.0.0
+        anyio>=4.0.0
+        idna>=3.6
+        idna<3
+        sniffio
+        iniconfig>=2 ; python_version < '3.0' and python_version >= '3.0'
+    "})?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("flask<3")?;
+
+    uv_snapshot!(context.pip_lint()
+        .arg("requirements.txt")
+        .arg("-c")
+        .arg("constraints.txt"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Skipping the unused constraint check, as the requirements could not be resolved
+    Linted 6 requirements in [TIME]
+    Found 5 issues
+    The requirement `anyio` is declared more than once (in: requirements.txt, requirements.txt)
+    The requirements for `idna` are contradictory: `idna>=3.6` and `idna<3` can never both be satisfied
+    requirements.txt: The markers on `iniconfig` can never be satisfied
+    requirements.txt: The requirement `idna<3` is missing a lower bound
+    requirements.txt: The requirement `sniffio` is missing a lower bound
+    "###
+    );
+
+    Ok(())
+}
+
+/// Constraints on transitive dependencies are used, even if they don't match any requirement.
+#[test]
+fn lint_unused_constraint() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio>=4.0.0")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str(indoc! {r"
+        idna<4
+        flask<3
+    "})?;
+
+    uv_snapshot!(context.pip_lint()
+        .arg("requirements.txt")
+        .arg("-c")
+        .arg("constraints.txt"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Linted 3 requirements in [TIME]
+    Found 1 issue
+    constraints.txt: The constraint on `flask` does not apply to any package in the resolved requirements
+    "###
+    );
+
+    Ok(())
+}
+
+#[test]
+fn lint_unsatisfiable_specifiers() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio>4,<1")?;
+
+    uv_snapshot!(context.pip_lint().arg("requirements.txt"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Linted 1 requirement in [TIME]
+    Found 1 issue
+    requirements.txt: The version specifiers on `anyio>4, <1` can never be satisfied
+    "###
+    );
+
+    Ok(())
+}
+
+#[test]
+fn lint_fix() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio>=4.0.0
+        anyio>=4.0.0
+        iniconfig>=2 ; python_version < '3.0' and python_version >= '3.0'
+    "})?;
+
+    uv_snapshot!(context.pip_lint()
+        .arg("requirements.txt")
+        .arg("--fix"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Fixed 2 issues
+    Linted 1 requirement in [TIME]
+    No issues found
+    "###
+    );
+
+    assert_eq!(fs_err::read_to_string(requirements_txt)?, "anyio>=4.0.0\n");
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anyhow::Result;
+use assert_fs::fixture::FileWriteStr;
+use assert_fs::fixture::PathChild;
+use indoc::indoc;
+
+use crate::common::uv_snapshot;
+use crate::common::TestContext;
+
+#[test]
+fn merge() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let base_txt = context.temp_dir.child("base.txt");
+    base_txt.write_str(indoc! {r"
+        anyio>=3.0
+        idna>=3.6
+        iniconfig ; sys_platform == 'linux'
+    "})?;
+
+    let dev_txt = context.temp_dir.child("dev.txt");
+    dev_txt.write_str(indoc! {r"
+        anyio[trio]<5
+        iniconfig ; sys_platform == 'win32'
+        pytest
+    "})?;
+
+    uv_snapshot!(context.pip_merge()
+        .arg("base.txt")
+        .arg("dev.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio[trio]>=3.0, <5
+        # via
+        #   -r base.txt
+        #   -r dev.txt
+    idna>=3.6
+        # via -r base.txt
+    iniconfig ; sys_platform == 'linux'
+        # via -r base.txt
+    iniconfig ; sys_platform == 'win32'
+        # via -r dev.txt
+    pytest
+        # via -r dev.txt
+
+    ----- stderr -----
+    Merged 6 requirements into 5 requirements in [TIME]
+    "###
+    );
+
+    // Write the merged requirements to a file.
+    uv_snapshot!(context.pip_merge()
+        .arg("base.txt")
+        .arg("dev.txt")
+        .arg("-o")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Merged 6 requirements into 5 requirements in [TIME]
+    "###
+    );
+
+    assert!(context.temp_dir.child("requirements.txt").exists());
+
+    Ok(())
+}
+
+#[test]
+fn merge_empty_intersection() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let base_txt = context.temp_dir.child("base.txt");
+    base_txt.write_str("idna>=3.6")?;
+
+    let dev_txt = context.temp_dir.child("dev.txt");
+    dev_txt.write_str("idna<3")?;
+
+    uv_snapshot!(context.pip_merge()
+        .arg("base.txt")
+        .arg("dev.txt"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The requirements `idna>=3.6` (base.txt) and `idna<3` (dev.txt) have no versions in common
+    "###
+    );
+
+    Ok(())
+}
@@ -0,0 +1,207 @@
+use anyhow::Result;
+use assert_fs::fixture::{FileWriteStr, PathChild, PathCreateDir};
+use indoc::indoc;
+
+use uv_test_registry::{Release, TestRegistry};
+
+use crate::common::{uv_snapshot, venv_bin_path, TestContext};
+
+/// Refreshing an editable package should pick up newly-added entry points.
+#[test]
+fn refresh_editables_entry_points() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create an editable package without any entry points.
+    let editable_dir = context.temp_dir.child("editable");
+    editable_dir.create_dir_all()?;
+    let pyproject_toml = editable_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "example"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--editable")
+        .arg(editable_dir.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + example==0.1.0 (from file://[TEMP_DIR]/editable)
+    "###
+    );
+
+    let script =
+        venv_bin_path(&context.venv).join(format!("example{}", std::env::consts::EXE_SUFFIX));
+    assert!(!script.exists());
+
+    // Add an entry point.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "example"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [project.scripts]
+        example = "example:main"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_refresh_editables(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ example==0.1.0 (from file://[TEMP_DIR]/editable)
+    "###
+    );
+
+    assert!(script.exists());
+
+    Ok(())
+}
+
+/// Only the requested editable packages should be refreshed.
+#[test]
+fn refresh_editables_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    for name in ["first", "second"] {
+        let editable_dir = context.temp_dir.child(name);
+        editable_dir.create_dir_all()?;
+        editable_dir.child("pyproject.toml").write_str(&format!(
+            indoc! {r#"
+                [project]
+                name = "{}"
+                version = "0.1.0"
+                requires-python = ">=3.12"
+            "#},
+            name
+        ))?;
+    }
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--editable")
+        .arg("./first")
+        .arg("--editable")
+        .arg("./second"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + first==0.1.0 (from file://[TEMP_DIR]/first)
+     + second==0.1.0 (from file://[TEMP_DIR]/second)
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_refresh_editables()
+        .arg("second"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ second==0.1.0 (from file://[TEMP_DIR]/second)
+    "###
+    );
+
+    Ok(())
+}
+
+/// Refreshing an editable package should install any newly-added dependencies.
+#[test]
+fn refresh_editables_new_dependency() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let registry = TestRegistry::new()
+        .release(Release::new("foo", "1.0.0")?)
+        .start()?;
+
+    let editable_dir = context.temp_dir.child("editable");
+    editable_dir.create_dir_all()?;
+    let pyproject_toml = editable_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "example"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--editable")
+        .arg(editable_dir.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + example==0.1.0 (from file://[TEMP_DIR]/editable)
+    "###
+    );
+
+    // Add a dependency.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "example"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["foo"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_refresh_editables()
+        .arg("--index-url")
+        .arg(registry.index_url().as_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 2 packages in [TIME]
+     ~ example==0.1.0 (from file://[TEMP_DIR]/editable)
+     + foo==1.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// If there are no editable packages, the command should be a no-op.
+#[test]
+fn refresh_editables_none() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_refresh_editables()
+        .arg("example"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `example` is not installed as an editable package
+    No editable packages to refresh
+    "###
+    );
+}
@@ -555,3 +555,22 @@ fn dry_run_uninstall_egg_info() -> Result<()> {
 
     Ok(())
 }
+
+/// Container interpreters are only supported by `uv pip install`.
+#[test]
+fn uninstall_container_python() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("--python")
+        .arg("podman://devcontainer#/usr/bin/python3")
+        .arg("iniconfig"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `uv pip uninstall` is not supported with a container Python interpreter (`podman://devcontainer#/usr/bin/python3`)
+    "###
+    );
+}
@@ -0,0 +1,134 @@
+use anyhow::Result;
+
+use uv_test_registry::{Release, TestRegistry};
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// Verify an installed package, then repair it after its files are modified.
+#[test]
+fn verify_and_repair() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let registry = TestRegistry::new()
+        .release(Release::new("foo", "1.0.0")?)
+        .start()?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("foo")
+        .arg("--index-url")
+        .arg(registry.index_url().as_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo==1.0.0
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_verify(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+    All installed files match their records
+    "###
+    );
+
+    // Modify a recorded file, and add an unrecorded one.
+    let site_packages = context.site_packages();
+    fs_err::write(site_packages.join("foo").join("__init__.py"), "tampered\n")?;
+    fs_err::write(site_packages.join("foo").join("extra.py"), "")?;
+
+    uv_snapshot!(context.filters(), context.pip_verify(), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+    Found 1 package with integrity issues
+    foo==1.0.0
+      modified: foo/__init__.py
+      extra: foo/extra.py
+    "###
+    );
+
+    // Only packages with missing or modified files are reinstalled, and unrecorded files are left
+    // in place.
+    uv_snapshot!(context.filters(), context.pip_verify()
+        .arg("--repair")
+        .arg("--index-url")
+        .arg(registry.index_url().as_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+    Found 1 package with integrity issues
+    foo==1.0.0
+      modified: foo/__init__.py
+      extra: foo/extra.py
+    Unrecorded files were left in place; pass `--remove-extra` to remove them
+    Resolved 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ foo==1.0.0
+    "###
+    );
+
+    assert!(site_packages.join("foo").join("extra.py").exists());
+
+    // A package with only unrecorded files isn't reinstalled.
+    uv_snapshot!(context.filters(), context.pip_verify()
+        .arg("--repair")
+        .arg("--index-url")
+        .arg(registry.index_url().as_str()), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+    Found 1 package with integrity issues
+    foo==1.0.0
+      extra: foo/extra.py
+    Unrecorded files were left in place; pass `--remove-extra` to remove them
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_verify()
+        .arg("--repair")
+        .arg("--remove-extra")
+        .arg("--index-url")
+        .arg(registry.index_url().as_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+    Found 1 package with integrity issues
+    foo==1.0.0
+      extra: foo/extra.py
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_verify(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+    All installed files match their records
+    "###
+    );
+
+    Ok(())
+}
@@ -663,3 +663,43 @@ fn python_find_venv_invalid() {
     ----- stderr -----
     "###);
 }
+
+#[test]
+fn python_inspect() {
+    let context = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    let output = context.python_inspect().arg("3.12").output().unwrap();
+    assert!(output.status.success());
+
+    let data: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(data["markers"]["python_version"], "3.12");
+    assert!(data["version"].as_str().unwrap().starts_with("3.12."));
+    assert!(!data["tags"].as_array().unwrap().is_empty());
+    assert!(data["scheme"]["purelib"].is_string());
+    assert_eq!(data["is_virtualenv"], false);
+
+    // The `sysconfig` variables are reported as-is.
+    let sysconfig_vars = &data["sysconfig_vars"];
+    assert!(sysconfig_vars["EXT_SUFFIX"]
+        .as_str()
+        .unwrap()
+        .contains("312"));
+    assert!(sysconfig_vars["INCLUDEPY"].is_string());
+
+    // The reported executable matches `uv python find`.
+    let find = context.python_find().arg("3.12").output().unwrap();
+    assert_eq!(
+        data["executable"].as_str().unwrap(),
+        String::from_utf8(find.stdout).unwrap().trim()
+    );
+
+    // A missing interpreter is an error.
+    uv_snapshot!(context.filters(), context.python_inspect().arg("3.9"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found for Python 3.9 in virtual environments, managed installations, or search path
+    "###);
+}
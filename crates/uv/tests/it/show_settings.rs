@@ -74,6 +74,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -94,6 +97,20 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -102,6 +119,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [
@@ -133,6 +151,10 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                 ],
                 flat_index: [],
@@ -176,10 +198,27 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: LowestDirect,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -190,19 +229,31 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: true,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -246,6 +297,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -266,6 +320,20 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -274,6 +342,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [
@@ -305,6 +374,10 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                 ],
                 flat_index: [],
@@ -348,10 +421,27 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -362,19 +452,31 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: true,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -419,6 +521,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -439,6 +544,20 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -447,6 +566,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [
@@ -478,6 +598,10 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                 ],
                 flat_index: [],
@@ -521,10 +645,27 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -535,19 +676,31 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -624,6 +777,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -644,6 +800,20 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -652,6 +822,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [
@@ -683,6 +854,10 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                 ],
                 flat_index: [],
@@ -726,10 +901,27 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: LowestDirect,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -740,19 +932,31 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: true,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -798,6 +1002,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -818,6 +1025,20 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -826,6 +1047,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -870,10 +1092,27 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -884,19 +1123,31 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -951,6 +1202,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -971,6 +1225,20 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -979,6 +1247,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [
@@ -1010,6 +1279,10 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                 ],
                 flat_index: [],
@@ -1053,10 +1326,27 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: LowestDirect,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1067,19 +1357,31 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: true,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -1148,6 +1450,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -1168,6 +1473,20 @@ fn resolve_index_url() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1176,6 +1495,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [
@@ -1207,6 +1527,10 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                     Index {
                         name: None,
@@ -1236,6 +1560,10 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                 ],
                 flat_index: [],
@@ -1279,10 +1607,27 @@ fn resolve_index_url() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1293,19 +1638,31 @@ fn resolve_index_url() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -1351,6 +1708,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -1371,6 +1731,20 @@ fn resolve_index_url() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1379,6 +1753,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [
@@ -1412,6 +1787,10 @@ fn resolve_index_url() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                     Index {
                         name: None,
@@ -1441,6 +1820,10 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                     Index {
                         name: None,
@@ -1470,6 +1853,10 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                 ],
                 flat_index: [],
@@ -1513,10 +1900,27 @@ fn resolve_index_url() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1527,19 +1931,31 @@ fn resolve_index_url() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -1608,6 +2024,9 @@ fn resolve_find_links() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -1628,6 +2047,20 @@ fn resolve_find_links() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1636,6 +2069,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -1668,6 +2102,10 @@ fn resolve_find_links() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                 ],
                 no_index: true,
@@ -1710,10 +2148,27 @@ fn resolve_find_links() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1724,19 +2179,31 @@ fn resolve_find_links() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -1804,6 +2271,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -1824,6 +2294,20 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1832,6 +2316,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -1876,10 +2361,27 @@ fn resolve_top_level() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: LowestDirect,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1890,19 +2392,31 @@ fn resolve_top_level() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -1963,6 +2477,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -1983,6 +2500,20 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1991,6 +2522,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [
@@ -2022,6 +2554,10 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                     Index {
                         name: None,
@@ -2051,6 +2587,10 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                 ],
                 flat_index: [],
@@ -2094,10 +2634,27 @@ fn resolve_top_level() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2108,19 +2665,31 @@ fn resolve_top_level() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -2164,6 +2733,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -2184,6 +2756,20 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2192,6 +2778,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [
@@ -2223,6 +2810,10 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                     Index {
                         name: None,
@@ -2252,6 +2843,10 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                 ],
                 flat_index: [],
@@ -2295,10 +2890,27 @@ fn resolve_top_level() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: LowestDirect,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2309,19 +2921,31 @@ fn resolve_top_level() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -2389,6 +3013,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -2409,6 +3036,20 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2417,6 +3058,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -2461,10 +3103,27 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: LowestDirect,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2475,19 +3134,31 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -2538,6 +3209,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -2558,6 +3232,20 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2566,6 +3254,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -2610,10 +3299,27 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: LowestDirect,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2624,19 +3330,31 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: true,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -2687,6 +3405,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -2707,6 +3428,20 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2715,6 +3450,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -2759,10 +3495,27 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2773,19 +3526,31 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -2838,6 +3603,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -2858,6 +3626,20 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2866,6 +3648,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -2910,10 +3693,27 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: LowestDirect,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2924,19 +3724,31 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -3008,6 +3820,9 @@ fn resolve_tool() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -3032,6 +3847,7 @@ fn resolve_tool() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         options: ResolverInstallerOptions {
             index: None,
             index_url: None,
@@ -3043,13 +3859,19 @@ fn resolve_tool() -> anyhow::Result<()> {
             resolution: Some(
                 LowestDirect,
             ),
+            resolution_package: None,
             prerelease: None,
+            prerelease_package: Some(
+                [],
+            ),
             fork_strategy: None,
             dependency_metadata: None,
             config_settings: None,
+            build_env: None,
             no_build_isolation: None,
             no_build_isolation_package: None,
             exclude_newer: None,
+            exclude_newer_package: None,
             link_mode: Some(
                 Clone,
             ),
@@ -3073,7 +3895,11 @@ fn resolve_tool() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             resolution: LowestDirect,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -3081,9 +3907,18 @@ fn resolve_tool() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             no_build_isolation: false,
             no_build_isolation_package: [],
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             link_mode: Clone,
             compile_bytecode: false,
             sources: Enabled,
@@ -3167,6 +4002,9 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -3187,6 +4025,20 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3195,6 +4047,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -3239,10 +4092,27 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: LowestDirect,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3253,19 +4123,31 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -3344,6 +4226,9 @@ fn resolve_both() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -3364,6 +4249,20 @@ fn resolve_both() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3372,6 +4271,7 @@ fn resolve_both() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [
@@ -3403,6 +4303,10 @@ fn resolve_both() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                 ],
                 flat_index: [],
@@ -3446,10 +4350,27 @@ fn resolve_both() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: LowestDirect,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3460,19 +4381,31 @@ fn resolve_both() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: true,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -3639,6 +4572,9 @@ fn resolve_config_file() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -3659,6 +4595,20 @@ fn resolve_config_file() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3667,6 +4617,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [
@@ -3698,6 +4649,10 @@ fn resolve_config_file() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                 ],
                 flat_index: [],
@@ -3741,10 +4696,27 @@ fn resolve_config_file() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: LowestDirect,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3755,19 +4727,31 @@ fn resolve_config_file() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: true,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -3812,7 +4796,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
       |
     1 | [project]
       |  ^^^^^^^
-    unknown field `project`, expected one of `required-version`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `no-build-isolation`, `no-build-isolation-package`, `exclude-newer`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `python-install-mirror`, `pypy-install-mirror`, `publish-url`, `trusted-publishing`, `check-url`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dev-dependencies`, `build-backend`
+    unknown field `project`, expected one of `required-version`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `build-env`, `no-build-isolation`, `no-build-isolation-package`, `exclude-newer`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `python-install-mirror`, `pypy-install-mirror`, `publish-url`, `trusted-publishing`, `check-url`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dev-dependencies`, `build-backend`
     "###
     );
 
@@ -3912,6 +4896,9 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -3932,6 +4919,20 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3940,6 +4941,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -3984,10 +4986,27 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: LowestDirect,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3998,19 +5017,31 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -4064,6 +5095,9 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -4084,6 +5118,20 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4092,6 +5140,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -4136,10 +5185,27 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4150,19 +5216,31 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -4235,6 +5313,9 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -4255,6 +5336,20 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4263,6 +5358,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -4307,10 +5403,27 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4321,19 +5434,31 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -4398,6 +5523,9 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -4418,6 +5546,20 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4426,6 +5568,7 @@ fn index_priority() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [
@@ -4459,6 +5602,10 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                     Index {
                         name: None,
@@ -4488,6 +5635,10 @@ fn index_priority() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                 ],
                 flat_index: [],
@@ -4531,10 +5682,27 @@ fn index_priority() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4545,19 +5713,31 @@ fn index_priority() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -4601,6 +5781,9 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -4621,6 +5804,20 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4629,6 +5826,7 @@ fn index_priority() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [
@@ -4662,6 +5860,10 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                     Index {
                         name: None,
@@ -4691,6 +5893,10 @@ fn index_priority() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                 ],
                 flat_index: [],
@@ -4734,10 +5940,27 @@ fn index_priority() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4748,19 +5971,31 @@ fn index_priority() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -4810,6 +6045,9 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -4830,6 +6068,20 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4838,6 +6090,7 @@ fn index_priority() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [
@@ -4871,6 +6124,10 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                     Index {
                         name: None,
@@ -4900,6 +6157,10 @@ fn index_priority() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                 ],
                 flat_index: [],
@@ -4943,10 +6204,27 @@ fn index_priority() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4957,19 +6235,31 @@ fn index_priority() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -5014,6 +6304,9 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -5034,6 +6327,20 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5042,6 +6349,7 @@ fn index_priority() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [
@@ -5075,6 +6383,10 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                     Index {
                         name: None,
@@ -5104,6 +6416,10 @@ fn index_priority() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                 ],
                 flat_index: [],
@@ -5147,10 +6463,27 @@ fn index_priority() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5161,19 +6494,31 @@ fn index_priority() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -5225,6 +6570,9 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -5245,6 +6593,20 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5253,6 +6615,7 @@ fn index_priority() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [
@@ -5286,6 +6649,10 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                     Index {
                         name: None,
@@ -5315,6 +6682,10 @@ fn index_priority() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                 ],
                 flat_index: [],
@@ -5358,10 +6729,27 @@ fn index_priority() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5372,19 +6760,31 @@ fn index_priority() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -5429,6 +6829,9 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -5449,6 +6852,20 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        format: RequirementsTxt,
+        output_per_extra: false,
+        verify_determinism: false,
+        seed: None,
+        resume: false,
+        allowed_packages: None,
+        minimal_changes: false,
+        report_bounds: false,
+        normalize_specifiers: false,
+        show_derivation_tree: false,
+        explain: false,
+        constraint_from_env: false,
+        sign: None,
+        python_versions: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5457,6 +6874,7 @@ fn index_priority() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [
@@ -5490,6 +6908,10 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                     Index {
                         name: None,
@@ -5519,6 +6941,10 @@ fn index_priority() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        cache: IndexCacheControl {
+                            metadata_ttl: None,
+                        },
+                        hash_provider: None,
                     },
                 ],
                 flat_index: [],
@@ -5562,10 +6988,27 @@ fn index_priority() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5576,19 +7019,31 @@ fn index_priority() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -5646,6 +7101,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -5659,10 +7117,15 @@ fn verify_hashes() -> anyhow::Result<()> {
             "requirements.in",
         ],
         editables: [],
+        with: [],
         constraints: [],
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        build_log_dir: None,
+        optimize_wheels: [],
+        show_derivation_tree: false,
+        explain: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -5675,6 +7138,16 @@ fn verify_hashes() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
+        environment: None,
+        create_venv: false,
+        reinstall_vcs: false,
+        reinstall_local: false,
+        pypackages: false,
+        install_mirrors: PythonInstallMirrors {
+            python_install_mirror: None,
+            pypy_install_mirror: None,
+        },
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -5719,10 +7192,27 @@ fn verify_hashes() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5733,19 +7223,31 @@ fn verify_hashes() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -5789,6 +7291,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -5802,10 +7307,15 @@ fn verify_hashes() -> anyhow::Result<()> {
             "requirements.in",
         ],
         editables: [],
+        with: [],
         constraints: [],
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        build_log_dir: None,
+        optimize_wheels: [],
+        show_derivation_tree: false,
+        explain: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -5818,6 +7328,16 @@ fn verify_hashes() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
+        environment: None,
+        create_venv: false,
+        reinstall_vcs: false,
+        reinstall_local: false,
+        pypackages: false,
+        install_mirrors: PythonInstallMirrors {
+            python_install_mirror: None,
+            pypy_install_mirror: None,
+        },
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -5862,10 +7382,27 @@ fn verify_hashes() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5876,19 +7413,31 @@ fn verify_hashes() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -5930,6 +7479,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -5943,10 +7495,15 @@ fn verify_hashes() -> anyhow::Result<()> {
             "requirements.in",
         ],
         editables: [],
+        with: [],
         constraints: [],
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        build_log_dir: None,
+        optimize_wheels: [],
+        show_derivation_tree: false,
+        explain: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -5959,6 +7516,16 @@ fn verify_hashes() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
+        environment: None,
+        create_venv: false,
+        reinstall_vcs: false,
+        reinstall_local: false,
+        pypackages: false,
+        install_mirrors: PythonInstallMirrors {
+            python_install_mirror: None,
+            pypy_install_mirror: None,
+        },
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -6003,10 +7570,27 @@ fn verify_hashes() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6017,19 +7601,31 @@ fn verify_hashes() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -6073,6 +7669,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -6086,10 +7685,15 @@ fn verify_hashes() -> anyhow::Result<()> {
             "requirements.in",
         ],
         editables: [],
+        with: [],
         constraints: [],
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        build_log_dir: None,
+        optimize_wheels: [],
+        show_derivation_tree: false,
+        explain: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -6102,6 +7706,16 @@ fn verify_hashes() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
+        environment: None,
+        create_venv: false,
+        reinstall_vcs: false,
+        reinstall_local: false,
+        pypackages: false,
+        install_mirrors: PythonInstallMirrors {
+            python_install_mirror: None,
+            pypy_install_mirror: None,
+        },
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -6146,10 +7760,27 @@ fn verify_hashes() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6160,19 +7791,31 @@ fn verify_hashes() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -6214,6 +7857,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -6227,10 +7873,15 @@ fn verify_hashes() -> anyhow::Result<()> {
             "requirements.in",
         ],
         editables: [],
+        with: [],
         constraints: [],
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        build_log_dir: None,
+        optimize_wheels: [],
+        show_derivation_tree: false,
+        explain: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -6243,6 +7894,16 @@ fn verify_hashes() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
+        environment: None,
+        create_venv: false,
+        reinstall_vcs: false,
+        reinstall_local: false,
+        pypackages: false,
+        install_mirrors: PythonInstallMirrors {
+            python_install_mirror: None,
+            pypy_install_mirror: None,
+        },
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -6287,10 +7948,27 @@ fn verify_hashes() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6301,19 +7979,31 @@ fn verify_hashes() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -6356,6 +8046,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        print_downloads: None,
+        max_requests_per_second: None,
+        stats: false,
     }
     CacheSettings {
         no_cache: false,
@@ -6369,10 +8062,15 @@ fn verify_hashes() -> anyhow::Result<()> {
             "requirements.in",
         ],
         editables: [],
+        with: [],
         constraints: [],
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        build_log_dir: None,
+        optimize_wheels: [],
+        show_derivation_tree: false,
+        explain: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -6385,6 +8083,16 @@ fn verify_hashes() -> anyhow::Result<()> {
                 },
             ),
         ),
+        rebuild: [],
+        environment: None,
+        create_venv: false,
+        reinstall_vcs: false,
+        reinstall_local: false,
+        pypackages: false,
+        install_mirrors: PythonInstallMirrors {
+            python_install_mirror: None,
+            pypy_install_mirror: None,
+        },
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -6429,10 +8137,27 @@ fn verify_hashes() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            check_abi: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: ResolutionPackage(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             fork_strategy: RequiresPython,
+            embedded_index_strategy: Merge,
+            invalid_metadata: Skip,
+            yanked: AllowIfPinned,
+            index_unavailable: Error,
+            wheel_preference: [],
+            local_version_preference: [],
+            resolution_timeout: None,
+            max_backtracks: None,
+            source_policy: [],
+            provided_by: [],
+            max_total_download_size: None,
+            max_package_size: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6443,19 +8168,31 @@ fn verify_hashes() -> anyhow::Result<()> {
             no_header: false,
             custom_compile_command: None,
             generate_hashes: false,
+            only_hashed: false,
             config_setting: ConfigSettings(
                 {},
             ),
+            config_setting_package: PackageConfigSettings(
+                {},
+            ),
+            build_env: PackageBuildEnv(
+                {},
+            ),
             python_version: None,
             python_platform: None,
+            features: [],
             universal: false,
             exclude_newer: None,
+            exclude_newer_package: ExcludeNewerPackage(
+                {},
+            ),
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_requires_python: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
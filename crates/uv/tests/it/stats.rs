@@ -0,0 +1,89 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use uv_static::EnvVars;
+use uv_test_registry::{Release, TestRegistry};
+
+use crate::common::TestContext;
+
+/// Record statistics for each invocation when `UV_STATS` is set, and aggregate them by command.
+#[test]
+fn stats_record() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let data_home = context.temp_dir.child("data");
+    let registry = TestRegistry::new()
+        .release(Release::new("foo", "1.0.0")?)
+        .start()?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("foo")?;
+
+    // Nothing is recorded by default.
+    let output = context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(registry.index_url().as_str())
+        .env(EnvVars::XDG_DATA_HOME, data_home.path())
+        .output()?;
+    assert!(output.status.success());
+
+    let output = context
+        .stats()
+        .env(EnvVars::XDG_DATA_HOME, data_home.path())
+        .output()?;
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stderr.contains("No statistics have been recorded"));
+
+    // Record two invocations.
+    for _ in 0..2 {
+        let output = context
+            .pip_compile()
+            .arg("requirements.in")
+            .arg("--index-url")
+            .arg(registry.index_url().as_str())
+            .env(EnvVars::UV_STATS, "1")
+            .env(EnvVars::XDG_DATA_HOME, data_home.path())
+            .output()?;
+        assert!(output.status.success());
+    }
+    data_home
+        .child("uv")
+        .child("stats")
+        .child("stats.jsonl")
+        .assert(predicates::path::is_file());
+
+    // The invocations are aggregated under a single command; `uv stats` itself isn't recorded.
+    let output = context
+        .stats()
+        .env(EnvVars::UV_STATS, "1")
+        .env(EnvVars::XDG_DATA_HOME, data_home.path())
+        .output()?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout)?;
+    let mut lines = stdout.lines();
+    assert!(lines.next().is_some_and(|line| line.starts_with("command")));
+    let row = lines.next().unwrap().split_whitespace().collect::<Vec<_>>();
+    assert_eq!(row[..3], ["pip", "compile", "2"]);
+    assert_eq!(lines.next(), None);
+
+    // Clear the recorded statistics.
+    let output = context
+        .stats()
+        .arg("--clear")
+        .env(EnvVars::XDG_DATA_HOME, data_home.path())
+        .output()?;
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stderr.contains("Removed statistics at:"));
+
+    let output = context
+        .stats()
+        .env(EnvVars::XDG_DATA_HOME, data_home.path())
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stderr.contains("No statistics have been recorded"));
+
+    Ok(())
+}
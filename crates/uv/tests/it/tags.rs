@@ -0,0 +1,64 @@
+use crate::common::{uv_snapshot, TestContext};
+
+/// Display the tags for a target platform and Python version.
+#[test]
+fn tags_python_platform() {
+    let context = TestContext::new("3.12");
+
+    let output = context
+        .tags()
+        .arg("--python-platform")
+        .arg("x86_64-unknown-linux-gnu")
+        .arg("--python-version")
+        .arg("3.11")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // Tags are displayed from highest to lowest priority.
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let tags = stdout.lines().collect::<Vec<_>>();
+    assert_eq!(tags.first(), Some(&"cp311-cp311-manylinux_2_17_x86_64"));
+    assert_eq!(tags.last(), Some(&"py30-none-any"));
+    assert!(tags.contains(&"cp311-abi3-manylinux_2_17_x86_64"));
+    assert!(!tags.iter().any(|tag| tag.starts_with("cp312")));
+
+    // The JSON output contains the same tags, in the same order.
+    let output = context
+        .tags()
+        .arg("--python-platform")
+        .arg("x86_64-unknown-linux-gnu")
+        .arg("--python-version")
+        .arg("3.11")
+        .arg("--output-format")
+        .arg("json")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json: Vec<String> = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json, tags);
+}
+
+/// Display the tags for the discovered interpreter.
+#[test]
+fn tags_interpreter() {
+    let context = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    let output = context.tags().arg("--python").arg("3.12").output().unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.lines().next().unwrap().starts_with("cp312-cp312-"));
+    assert!(stdout.lines().any(|tag| tag == "py3-none-any"));
+
+    // A missing interpreter is an error.
+    uv_snapshot!(context.filters(), context.tags().arg("--python").arg("3.9"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found for Python 3.9 in virtual environments, managed installations, or search path
+    "###);
+}
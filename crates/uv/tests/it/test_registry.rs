@@ -0,0 +1,84 @@
+use std::path::Path;
+
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use uv_test_registry::{Release, TestRegistry};
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// Resolve against an in-process registry, without network access.
+#[test]
+fn compile_test_registry() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let registry = TestRegistry::new()
+        .release(Release::new("foo", "1.0.0")?.requires("bar>=1"))
+        .release(Release::new("bar", "0.9.0")?)
+        .release(Release::new("bar", "1.0.0")?)
+        .release(Release::new("bar", "2.0.0")?.requires_python(">=3.13"))
+        .release(Release::new("bar", "1.1.0")?.yanked())
+        .start()?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("foo")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(registry.index_url().as_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    bar==1.0.0
+        # via foo
+    foo==1.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    // The metadata is served separately, so no wheels should have been downloaded.
+    assert!(registry.requests().contains(&"/simple/foo/".to_string()));
+    assert!(!registry.requests().iter().any(|path| {
+        Path::new(path)
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("whl"))
+    }));
+
+    Ok(())
+}
+
+/// Install from an in-process registry, without network access.
+#[test]
+fn install_test_registry() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let registry = TestRegistry::new()
+        .release(Release::new("foo", "1.0.0")?.requires("bar>=1"))
+        .release(Release::new("bar", "1.0.0")?)
+        .start()?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("foo")
+        .arg("--index-url")
+        .arg(registry.index_url().as_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + bar==1.0.0
+     + foo==1.0.0
+    "###
+    );
+
+    context.assert_command("import foo, bar").success();
+
+    Ok(())
+}
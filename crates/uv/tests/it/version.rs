@@ -0,0 +1,114 @@
+use crate::common::{uv_snapshot, TestContext};
+
+#[test]
+fn version_compare_specifier() {
+    let context = TestContext::new_with_versions(&[]);
+
+    uv_snapshot!(context.filters(), context.version().arg("compare").arg("1.2.3").arg("<2.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    true
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("compare").arg("2.0.0rc1").arg(">=1.0,<2.0"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    false
+
+    ----- stderr -----
+    "###);
+}
+
+#[test]
+fn version_compare_version() {
+    let context = TestContext::new_with_versions(&[]);
+
+    uv_snapshot!(context.filters(), context.version().arg("compare").arg("1.2.3").arg("1.10"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    <
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("compare").arg("1.0").arg("1.0.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ==
+
+    ----- stderr -----
+    "###);
+}
+
+#[test]
+fn version_sort() {
+    let context = TestContext::new_with_versions(&[]);
+
+    uv_snapshot!(context.filters(), context.version().arg("sort").arg("1.10").arg("1.2").arg("1.2rc1").arg("1.2.post1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    1.2rc1
+    1.2
+    1.2.post1
+    1.10
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("sort").arg("--reverse").arg("--filter").arg("<1.10").arg("1.10").arg("1.2").arg("1.9"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    1.9
+    1.2
+
+    ----- stderr -----
+    "###);
+}
+
+/// `--output-format` applies to the `compare` and `sort` subcommands.
+#[test]
+fn version_output_format_json() {
+    let context = TestContext::new_with_versions(&[]);
+
+    uv_snapshot!(context.filters(), context.version().arg("--output-format").arg("json").arg("compare").arg("1.2.3").arg("1.10"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "ordering": "<"
+    }
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--output-format").arg("json").arg("compare").arg("2.0.0rc1").arg(">=1.0,<2.0"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    {
+      "satisfied": false
+    }
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--output-format").arg("json").arg("sort").arg("1.10").arg("1.2"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [
+      "1.2",
+      "1.10"
+    ]
+
+    ----- stderr -----
+    "###);
+}